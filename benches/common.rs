@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::process::Command;
+
+// A spread of what the test cases look like: calls, deep stacks, pointers,
+// long running arithmetic. Checked in as .ll since the text format outlives
+// an llvm upgrade way better than bitcode does.
+pub const FIXTURES: &[&str] = &[
+	"hello_world_o0",
+	"calls_o0",
+	"print_fib_o0",
+	"load_store_pointer_up_stack_o0",
+	"call_lots_of_args_o1",
+	"mod_o1",
+];
+
+// bitcode for a fixture, fresh out of llvm-as
+pub fn assemble(name: &str) -> PathBuf {
+	let dir = env::temp_dir().join(format!("bfcc-bench-{}", process::id()));
+	fs::create_dir_all(&dir).unwrap();
+
+	let ll = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("fixtures")
+		.join(format!("{}.ll", name));
+	let bc = dir.join(format!("{}.bc", name));
+
+	let out = Command::new("llvm-as")
+		.arg(&ll)
+		.arg("-o")
+		.arg(&bc)
+		.output()
+		.expect("failed to run llvm-as (is it installed and on PATH?)");
+	assert!(
+		out.status.success(),
+		"llvm-as {}: {}",
+		ll.display(),
+		String::from_utf8_lossy(&out.stderr)
+	);
+
+	bc
+}
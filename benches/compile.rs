@@ -0,0 +1,53 @@
+// How long bfcc itself takes per fixture, with and without the passes that
+// reshape the module before codegen. The size of what comes out is printed
+// alongside since criterion only tracks time.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::{BenchmarkId, Criterion};
+
+#[allow(dead_code)]
+#[path = "../bfcc.rs"]
+mod bfcc;
+mod common;
+
+fn option_sets() -> Vec<(&'static str, bfcc::Options)> {
+	vec![
+		("default", bfcc::Options::default()),
+		(
+			"no-inline",
+			bfcc::Options {
+				inline_threshold: 0,
+				..bfcc::Options::default()
+			},
+		),
+	]
+}
+
+fn compile(c: &mut Criterion) {
+	let mut group = c.benchmark_group("compile");
+
+	for name in common::FIXTURES.iter() {
+		let bc = common::assemble(name);
+
+		for (label, opts) in option_sets() {
+			let out = bfcc::compile(&bc, &opts).unwrap();
+			println!(
+				"{}/{}: {} bytes of bf",
+				label,
+				name,
+				out.code.chars().filter(|c| "+-<>[].,".contains(*c)).count()
+			);
+
+			group.bench_with_input(BenchmarkId::new(label, name), &bc, |b, bc| {
+				b.iter(|| bfcc::compile(bc, &opts).unwrap())
+			});
+		}
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, compile);
+criterion_main!(benches);
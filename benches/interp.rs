@@ -0,0 +1,46 @@
+// Throughput of the harness's interpreter over what bfcc makes of the
+// fixtures. Each program's step count is its throughput so criterion reports
+// ops per second, comparable across interpreter changes even as the programs
+// themselves get shorter. The plain COps executor runs alongside as the
+// baseline the packed one has to beat.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+
+#[allow(dead_code)]
+#[path = "../bfcc.rs"]
+mod bfcc;
+mod common;
+#[allow(dead_code)]
+#[path = "../interp.rs"]
+mod interp;
+
+fn interp(c: &mut Criterion) {
+	let mut group = c.benchmark_group("interp");
+
+	for name in common::FIXTURES.iter() {
+		let code = bfcc::compile(&common::assemble(name), &bfcc::Options::default())
+			.unwrap()
+			.code;
+		let eof = interp::eof_of(&code);
+		let ops = interp::bf_bytecode(&code);
+
+		let steps = interp::exec(ops.clone(), &[], eof, None).unwrap().steps;
+		println!("{}: {} steps", name, steps);
+
+		group.throughput(Throughput::Elements(steps as u64));
+		group.bench_with_input(BenchmarkId::new("packed", name), &ops, |b, ops| {
+			b.iter(|| interp::exec(ops.clone(), &[], eof, None).unwrap())
+		});
+		group.bench_with_input(BenchmarkId::new("reference", name), &ops, |b, ops| {
+			b.iter(|| interp::exec_ops(ops.clone(), &[], eof, None).unwrap())
+		});
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, interp);
+criterion_main!(benches);
@@ -1,227 +1,3165 @@
 extern crate llvm_ir;
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use llvm_ir::HasDebugLoc;
 
 use std::fmt;
 
 use std::cell::RefCell;
 use std::fmt::Write;
 use std::ops::Deref;
+use std::env;
+use std::fs;
 use std::path::Path;
+use std::process;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use std::convert::TryFrom;
 use std::convert::TryInto;
 
-// Split all blocks at calls. This should result in all calls treated sorta like
-// terminator instructions being the last instruction of their block before a
-// unconditional branch.
-//
-// This makes it way easier to generate brainfuck control flow as calls
-// use the same control flow mechanism as blocks. A call/branch combo sets
-// up the current and next frames then switches to the next frame. Since it
-// always makes up the end of a block we'll re-enter the main loop and
-// continue into the next frame. Upon returning the branch will have setup
-// frame to resume right into the right block.
-// what a deal!
-fn calls_terminate_blocks(module: &mut llvm_ir::Module) {
-	for func in module.functions.iter_mut() {
-		let mut block = 0;
-		while block < func.basic_blocks.len() {
-			let mut instr = 0;
-			while instr < func.basic_blocks[block].instrs.len() {
-				if let llvm_ir::Instruction::Call(_) =
-					func.basic_blocks[block].instrs[instr]
-				{
-				} else {
-					instr += 1;
-					continue;
+#[derive(Debug)]
+pub enum CompileError {
+	// a function wants more cells than a frame can address. Pointers into a
+	// frame are just cell values so anything past FRAME_CELLS would silently
+	// wrap around into somebody else's frame.
+	FrameOverflow {
+		func: String,
+		value: String,
+		required: usize,
+	},
+	// stuff normalize couldn't massage into something we can compile
+	Unsupported(Vec<Diagnostic>),
+	// llvm couldn't make sense of the input in the first place
+	BadModule {
+		path: String,
+		why: String,
+	},
+}
+
+impl fmt::Display for CompileError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			CompileError::FrameOverflow {
+				func,
+				value,
+				required,
+			} => write!(
+				f,
+				"frame overflow in {}: {} lands past the last addressable \
+				 cell, frame needs {} cells but only {} fit",
+				func, value, required, FRAME_CELLS
+			),
+			CompileError::Unsupported(diags) => {
+				write!(f, "unsupported constructs:")?;
+				for d in diags.iter() {
+					write!(f, "\n  {}", d)?;
 				}
+				Ok(())
+			}
+			CompileError::BadModule { path, why } => {
+				write!(f, "couldn't load {}: {}", path, why)
+			}
+		}
+	}
+}
 
-				// so when we get to a call
+// something wrong with a specific spot in the input
+#[derive(Debug)]
+pub struct Diagnostic {
+	pub func: String,
+	pub block: String,
+	pub msg: String,
+	// file:line of the C behind it, only there when it was built with -g
+	pub loc: Option<String>,
+}
 
-				let nextn = llvm_ir::Name::Name(Box::new(format!(
-					"call_term_for_{}",
-					block
-				)));
+impl fmt::Display for Diagnostic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} in {}/{}", self.msg, self.func, self.block)?;
+		if let Some(loc) = &self.loc {
+			write!(f, " ({})", loc)?;
+		}
+		Ok(())
+	}
+}
 
-				let last_instr =
-					instr == func.basic_blocks[block].instrs.len() - 1;
+fn loc_of(debugloc: &Option<llvm_ir::DebugLoc>) -> Option<String> {
+	debugloc
+		.as_ref()
+		.map(|d| format!("{}:{}", d.filename, d.line))
+}
 
-				// even if it's the last instr that's a call we still split the
-				// block and sprinkle in an uncond br to make things ez
-				if last_instr {
-					let splitn = llvm_ir::BasicBlock {
-						name: nextn.clone(),
-						instrs: vec![],
-						term: func.basic_blocks[block].term.clone(),
-					};
-					func.basic_blocks.insert(block + 1, splitn);
+// Why a block the compiler made up exists at all
+#[derive(Debug, Clone)]
+pub enum BlockOrigin {
+	CallContinuation,
+	EntryShim,
+	SwitchCase,
+}
 
-					func.basic_blocks[block].term =
-						llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
-							debugloc: None,
-							dest: nextn.clone(),
-						});
-				} else {
-					let splitn = llvm_ir::BasicBlock {
-						name: nextn.clone(),
-						instrs: func.basic_blocks[block]
-							.instrs
-							.split_off(instr + 1),
-						term: func.basic_blocks[block].term.clone(),
-					};
-					func.basic_blocks.insert(block + 1, splitn);
+impl fmt::Display for BlockOrigin {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{}",
+			match self {
+				BlockOrigin::CallContinuation => "call continuation",
+				BlockOrigin::EntryShim => "entry shim",
+				BlockOrigin::SwitchCase => "switch case",
+			}
+		)
+	}
+}
 
-					func.basic_blocks[block].term =
-						llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
-							debugloc: None,
-							dest: nextn.clone(),
-						});
-				}
+// Every block minted by the module surgery passes gets one of these so it can
+// be traced back to a block that actually exists in the source ir.
+#[derive(Debug, Clone)]
+pub struct Provenance {
+	pub func: String,
+	pub block: llvm_ir::Name,
+	pub from: llvm_ir::Name,
+	// for a call continuation, which of the source block's calls it picks up
+	// after, counting from 0
+	pub call: Option<usize>,
+	pub reason: BlockOrigin,
+}
 
-				// update all the phi nodes too. Since our block now has a
-				// unconditional succ we'll need to rename any phis naming this
-				// block to instead name the succ.
-				//
-				// man... maybe i shoulda done this in cpp cause like... man
-				// llvm has all this stufffff
-				let orig_block = func.basic_blocks[block].name.clone();
-				for bblock in &mut func.basic_blocks {
-					for instr in &mut bblock.instrs {
-						match instr {
-							llvm_ir::Instruction::Phi(phi) => {
-								for inval in &mut phi.incoming_values {
-									if inval.1 == orig_block {
-										inval.1 = nextn.clone();
-									}
-								}
+impl Provenance {
+	// where it came from without saying what it is, for --dump-ir's labels
+	fn source(&self) -> String {
+		match self.call {
+			Some(n) => format!("{} after call {}", self.from, n),
+			None => format!("{}", self.from),
+		}
+	}
+}
+
+impl fmt::Display for Provenance {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{}/{} from {} ({})",
+			self.func,
+			self.block,
+			self.source(),
+			self.reason
+		)
+	}
+}
+
+// the source block a (possibly made up) block came from
+fn origin_of(
+	provenance: &[Provenance],
+	func: &String,
+	block: &llvm_ir::Name,
+) -> llvm_ir::Name {
+	provenance
+		.iter()
+		.find(|p| &p.func == func && &p.block == block)
+		.map_or(block.clone(), |p| p.from.clone())
+}
+
+// Who calls whom. Only functions defined in the module count, intrinsics like
+// putchar never get a frame so they don't show up here.
+#[derive(Debug, Clone)]
+pub struct CallGraph {
+	pub funcs: Vec<CallNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CallNode {
+	pub name: String,
+	pub calls: Vec<String>,
+	// most frames this function can have stacked up at once including its
+	// own, None when some recursion underneath makes it unbounded
+	pub depth: Option<usize>,
+	pub recursive: bool,
+}
+
+impl CallGraph {
+	pub fn build(module: &llvm_ir::Module) -> CallGraph {
+		let defined = module
+			.functions
+			.iter()
+			.map(|f| f.name.clone())
+			.collect::<Vec<_>>();
+
+		let mut funcs = module
+			.functions
+			.iter()
+			.map(|f| {
+				let mut calls = vec![];
+				for block in f.basic_blocks.iter() {
+					for instr in block.instrs.iter() {
+						let callee = match instr {
+							llvm_ir::Instruction::Call(c) => callee_of(c),
+							_ => None,
+						};
+
+						match callee {
+							Some(c) if defined.contains(&c) && !calls.contains(&c) => {
+								calls.push(c)
 							}
 							_ => {}
 						}
 					}
 				}
 
-				instr += 1;
-			}
+				CallNode {
+					name: f.name.clone(),
+					calls,
+					depth: None,
+					recursive: false,
+				}
+			})
+			.collect::<Vec<_>>();
 
-			block += 1;
+		for i in 0..funcs.len() {
+			let name = funcs[i].name.clone();
+			let recursive = funcs[i]
+				.calls
+				.iter()
+				.any(|c| reaches(&funcs, c, &name, &mut vec![]));
+			funcs[i].recursive = recursive;
+		}
+
+		for i in 0..funcs.len() {
+			let depth = depth_of(&funcs, &funcs[i].name, &mut vec![]);
+			funcs[i].depth = depth;
 		}
+
+		CallGraph { funcs }
+	}
+
+	pub fn get(&self, name: &str) -> Option<&CallNode> {
+		self.funcs.iter().find(|f| f.name == name)
+	}
+
+	// deepest the stack can get starting from main
+	pub fn max_depth(&self) -> Option<usize> {
+		self.get("main").and_then(|m| m.depth)
 	}
 }
 
-// TODO: this isn't really the move tbh
-// function calls always call into block 0. Thing is, if we're making a call
-// from block 0 into another block 0 we could end up end up setting everything
-// up for the callee but then then re-entering block 0 in the caller instead of
-// the target. This is really just a quirk of control flow using one massive
-// loop with masks for functions and blocks. Moving control flow between
-// blocks/functions involes reentering the block or function level loop with the
-// proper mask set. Since functions share the same space for block control flow
-// mask space the transition between functions temporarily involves executing
-// in the calling function with the callee's mask set.
-fn calls_never_in_first_block(module: &mut llvm_ir::Module) {
-	for func in module.functions.iter_mut() {
-		let hascall = func.basic_blocks[0].instrs.iter().any(|i| match i {
-			llvm_ir::Instruction::Call(_) => true,
-			_ => false,
-		});
+fn reaches(
+	funcs: &Vec<CallNode>,
+	from: &String,
+	to: &String,
+	seen: &mut Vec<String>,
+) -> bool {
+	if from == to {
+		return true;
+	}
 
-		if !hascall {
-			continue;
-		}
+	if seen.contains(from) {
+		return false;
+	}
+	seen.push(from.clone());
 
-		let nextn = llvm_ir::Name::Name(Box::new(format!(
-			"no_block0_call_for_{}",
-			func.name
-		)));
+	funcs
+		.iter()
+		.find(|f| &f.name == from)
+		.is_some_and(|f| f.calls.iter().any(|c| reaches(funcs, c, to, seen)))
+}
 
-		func.basic_blocks.insert(
-			0,
-			llvm_ir::BasicBlock {
-				name: nextn,
-				instrs: vec![],
-				term: llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
-					debugloc: None,
-					dest: func.basic_blocks[0].name.clone(),
-				}),
-			},
-		);
+fn depth_of(
+	funcs: &Vec<CallNode>,
+	name: &String,
+	path: &mut Vec<String>,
+) -> Option<usize> {
+	if path.contains(name) {
+		return None;
+	}
+
+	let node = funcs.iter().find(|f| &f.name == name)?;
+
+	path.push(name.clone());
+	let mut deepest = 0;
+	for c in node.calls.iter() {
+		deepest = deepest.max(depth_of(funcs, c, path)?);
 	}
+	path.pop();
+
+	Some(deepest + 1)
 }
 
-#[derive(Debug, Clone)]
-struct Addr {
-	v: Rc<RefCell<Addrt>>,
+impl fmt::Display for CallNode {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.name)?;
+
+		if !self.calls.is_empty() {
+			write!(f, " calls {}", self.calls.join(", "))?;
+		}
+
+		match self.depth {
+			Some(d) => write!(f, " (depth {}", d)?,
+			None => write!(f, " (depth unbounded")?,
+		}
+
+		if self.recursive {
+			write!(f, ", recursive")?;
+		}
+
+		write!(f, ")")
+	}
 }
 
-impl fmt::Display for Addr {
+impl fmt::Display for CallGraph {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, ".{:?}", self.v.borrow())
+		for node in self.funcs.iter() {
+			writeln!(f, "{}", node)?;
+		}
+
+		match self.max_depth() {
+			Some(d) => write!(f, "max depth from main: {}", d),
+			None => write!(f, "max depth from main: unbounded"),
+		}
 	}
 }
 
+// Knobs for how a module gets compiled
 #[derive(Debug, Clone)]
-enum Addrt {
-	Fixed(usize),
-	Offset(Addr, i64),
-	Named(llvm_ir::Name),
+pub struct Options {
+	// callees with at most this many instructions get inlined, 0 turns it off
+	pub inline_threshold: usize,
+	// what a cell reads as once input runs dry, has to match the interpreter.
+	// Either 0 or 255, nothing else is a convention anybody uses
+	pub eof_value: u8,
+	// how many times the cost estimate assumes a loop goes round
+	pub loop_weight: usize,
+	// calls this deep print STACK_OVERFLOW_MARKER and end the program instead
+	// of running off the tape, 0 leaves the guard out entirely
+	pub stack_limit: usize,
+	// arithmetic that wraps in C goes around 255 by hand instead of counting
+	// on the interpreter to, so no cell ever leaves 0..=255 even for a moment.
+	// On by default, turning it off is a whole lot faster but only right on
+	// interpreters that wrap
+	pub wrap_safe: bool,
+	// a constant that doesn't fit in a cell is an error instead of a warning
+	// about it getting truncated, for code that's meant to be all 8 bit
+	pub strict_consts: bool,
+	// after every instruction each scratch cell it borrowed gets an empty loop
+	// on it. Free on a clean cell, a dirty one hangs a real interpreter and
+	// the verify interpreter calls it out as a loop that can never exit
+	pub check_scratch: bool,
+	// just the eight ops, none of the comments, tags or indentation. A whole
+	// lot smaller but there's nothing left for a debugger to go on
+	pub no_comments: bool,
+	// cancel out ops that undo each other once the code is rendered, see
+	// peephole
+	pub peephole: bool,
+	// loads take the value last stored instead of reading the alloca back,
+	// see forward_stores. Off by default so -O0 code still does every
+	// store and load it says it does when stepping through it
+	pub opt: bool,
 }
 
-// type Addr = Rc<RefCell<Addrt>>;
-
-fn offset(s: Addr, i: i64) -> Addr {
-	Addr {
-		v: Rc::new(RefCell::new(Addrt::Offset(s, i))),
+impl Default for Options {
+	fn default() -> Options {
+		Options {
+			inline_threshold: 8,
+			eof_value: 0,
+			loop_weight: 8,
+			stack_limit: 0,
+			wrap_safe: true,
+			strict_consts: false,
+			check_scratch: false,
+			no_comments: false,
+			peephole: true,
+			opt: false,
+		}
 	}
 }
 
-fn resaddr(s: Addr) -> usize {
-	match s.v.deref().borrow().clone() {
-		Addrt::Fixed(u) => u,
-		Addrt::Offset(a, o) => (resaddr(a) as i64 + o) as usize,
-		Addrt::Named(_) => panic!("named address cant be resolved"),
-	}
+// Everything we learned about a module while beating it into shape
+pub struct Meta {
+	pub provenance: Vec<Provenance>,
+	pub calls: CallGraph,
 }
 
-fn fixed_addr(a: usize) -> Addr {
-	Addr {
-		v: Rc::new(RefCell::new(Addrt::Fixed(a))),
+// Run all the module surgery in order. Functions and blocks are always walked
+// front to back so the made up names come out the same every time.
+// With an entry only what it can call is kept, see drop_unreachable.
+fn prepare(
+	module: &mut llvm_ir::Module,
+	opts: &Options,
+	entry: Option<&str>,
+) -> Result<Meta, CompileError> {
+	let mut provenance = vec![];
+
+	normalize(module, &mut provenance).map_err(CompileError::Unsupported)?;
+	resolve_eof(module, opts.eof_value);
+
+	inline_leaves(module, opts.inline_threshold);
+	merge_straight_blocks(module);
+	if opts.opt {
+		forward_stores(module);
+	}
+	reuse_loads(module);
+	drop_dead_values(module);
+	merge_putchars(module);
+	if let Some(entry) = entry {
+		drop_unreachable(module, entry);
 	}
+	calls_terminate_blocks(module, &mut provenance);
+	calls_never_in_first_block(module, &mut provenance);
+
+	Ok(Meta {
+		provenance,
+		calls: CallGraph::build(module),
+	})
 }
 
-#[derive(Debug, Clone)]
-enum BfOp {
-	// kinda actual brainfuck, these do fucky stuff to the instr pointer
-	// future passes can't understand
-	Right(usize),
-	Left(usize),
+// -O0 ends a block and starts the next one all over the place with nothing
+// but a br between them. Every block is a mask cell in every frame and a trip
+// around the main loop to get into, so a block whose only way in is a br from
+// the one before gets glued onto the end of it. Its phis only have the one
+// incoming value then so they're just that value. Calls get split back out
+// later by calls_terminate_blocks.
+fn merge_straight_blocks(module: &mut llvm_ir::Module) {
+	for func in module.functions.iter_mut() {
+		loop {
+			let mut preds: Vec<(llvm_ir::Name, usize)> = vec![];
+			for block in func.basic_blocks.iter() {
+				let dests = match &block.term {
+					llvm_ir::Terminator::Br(br) => vec![br.dest.clone()],
+					llvm_ir::Terminator::CondBr(cbr) => {
+						vec![cbr.true_dest.clone(), cbr.false_dest.clone()]
+					}
+					_ => vec![],
+				};
+				for d in dests {
+					match preds.iter_mut().find(|(n, _)| n == &d) {
+						Some((_, count)) => *count += 1,
+						None => preds.push((d, 1)),
+					}
+				}
+			}
 
-	// extra spooky! loop enters and exits on different addresses. so like the
-	// instr pointer is now split between both addrs after this op, statically
-	// we'll assume it enters on the former address and  exists on the later
-	// addr so uh... glhf
-	//
-	// If the addr is truthy the PC will be at the latter addr on exit
-	// If the addr is falsey the PC will be at the former addr on exit
-	Loop2(Addr, Addr, Vec<BfOp>),
+			// the entry has a way in nobody branches from
+			let found = (0..func.basic_blocks.len()).find_map(|p| {
+				let to = match &func.basic_blocks[p].term {
+					llvm_ir::Terminator::Br(br) => &br.dest,
+					_ => return None,
+				};
+				let s = func.basic_blocks.iter().position(|b| &b.name == to)?;
+				let only = preds.iter().any(|(n, c)| n == to && *c == 1);
+				match s != 0 && s != p && only {
+					true => Some((p, s)),
+					false => None,
+				}
+			});
 
-	AddI(Addr, u8), // *a + n -> *a : a must be less than 255
-	SubI(Addr, u8), // *a - n -> *a : a msut be greater than 0
-	Dup(Addr, Addr, Addr), /* *a -> *b, *c : a will be zeroed, b and c must
-	                 * be zero */
-	//DupThru(Addr, Addr, Addr), /* *a -> *b (c is a tmp) : a will be zeroed,
-	//                           * b and c must be zero */
+			let (p, s) = match found {
+				Some(f) => f,
+				None => break,
+			};
 
-	// the only architecture with a real mov instruction
-	Mov(Addr, Addr), // *a -> *b : a will be zeroed, b must be zero
-	Putch(Addr),
-	Zero(Addr),
-	Loop(Addr, Vec<BfOp>),
+			let mut succ = func.basic_blocks.remove(s);
+			let p = if s < p { p - 1 } else { p };
+			let pred_name = func.basic_blocks[p].name.clone();
 
-	// debug
-	Tag(Addr, String), // tag address with name in debugger
-	Comment(String),   // if you see something say something
+			let phis = succ
+				.instrs
+				.iter()
+				.filter_map(|i| match i {
+					llvm_ir::Instruction::Phi(phi) => {
+						Some((phi.dest.clone(), phi.incoming_values[0].0.clone()))
+					}
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+			succ.instrs
+				.retain(|i| !matches!(i, llvm_ir::Instruction::Phi(_)));
 
-	Nop,
+			func.basic_blocks[p].instrs.extend(succ.instrs);
+			func.basic_blocks[p].term = succ.term;
+
+			for (dest, v) in phis.iter() {
+				for block in func.basic_blocks.iter_mut() {
+					replace_uses_in_block(block, dest, v);
+				}
+			}
+
+			// whatever it went on to now comes from the merged block
+			for block in func.basic_blocks.iter_mut() {
+				for instr in block.instrs.iter_mut() {
+					if let llvm_ir::Instruction::Phi(phi) = instr {
+						for inval in phi.incoming_values.iter_mut() {
+							if inval.1 == succ.name {
+								inval.1 = pred_name.clone();
+							}
+						}
+					}
+				}
+			}
+		}
+	}
+}
+
+// Every function costs a mask cell in every frame and its whole body in the
+// main loop whether or not anything calls it, so whatever entry can't reach
+// through calls or by taking its address goes. Runs after inlining so
+// helpers that got pasted everywhere go too.
+fn drop_unreachable(module: &mut llvm_ir::Module, entry: &str) {
+	let graph = CallGraph::build(module);
+
+	let mut keep = vec![entry.to_string()];
+	let mut i = 0;
+	while i < keep.len() {
+		let mut next = graph
+			.get(&keep[i])
+			.map_or(vec![], |n| n.calls.clone());
+
+		if let Some(f) = module.functions.iter_mut().find(|f| f.name == keep[i]) {
+			for block in f.basic_blocks.iter_mut() {
+				for instr in block.instrs.iter_mut() {
+					for op in operands_mut(instr) {
+						next.extend(func_ref(op));
+					}
+				}
+			}
+		}
+
+		for n in next {
+			if !keep.contains(&n) {
+				keep.push(n);
+			}
+		}
+		i += 1;
+	}
+
+	module.functions.retain(|f| keep.contains(&f.name));
+}
+
+// Rewrite the bits of llvm we don't want to deal with in codegen into the
+// bits we do, so nobody has to remember magic flags like -lowerswitch when
+// running clang. Anything that can't be rewritten is reported all at once
+// rather than dying on the first one.
+fn normalize(
+	module: &mut llvm_ir::Module,
+	provenance: &mut Vec<Provenance>,
+) -> Result<(), Vec<Diagnostic>> {
+	strip_hints(module);
+	spell_out_zeros(module);
+	alias_getc(module);
+	lower_exit(module);
+	lower_switch(module, provenance);
+	lower_mem_copies(module);
+	uncast_putchar(module);
+	lower_puts(module);
+	lower_printf(module);
+	split_local_arrays(module);
+	narrow_i16(module);
+
+	let diags = reject_unnormalizable(module);
+	if !diags.is_empty() {
+		return Err(diags);
+	}
+
+	Ok(())
+}
+
+// A call costs a whole frame: args copied up, masks flipped, the frame torn
+// down again. For tiny helpers that's way more than their body so paste the
+// body into the caller instead. Only single block functions that don't call
+// anything of ours (intrinsics are fine), never have their address taken and
+// don't go through pointers qualify, so there's no recursion or control flow
+// to untangle.
+fn inline_leaves(module: &mut llvm_ir::Module, threshold: usize) {
+	if threshold == 0 {
+		return;
+	}
+
+	let graph = CallGraph::build(module);
+
+	let mut taken = vec![];
+	for func in module.functions.iter_mut() {
+		for block in func.basic_blocks.iter_mut() {
+			for instr in block.instrs.iter_mut() {
+				for op in operands_mut(instr) {
+					if let Some(name) = func_ref(op) {
+						taken.push(name);
+					}
+				}
+			}
+		}
+	}
+
+	let leaves = module
+		.functions
+		.iter()
+		.filter(|f| {
+			f.name != "main"
+				&& !f.is_var_arg
+				&& f.basic_blocks.len() == 1
+				&& f.basic_blocks[0].instrs.len() <= threshold
+				&& !taken.contains(&f.name)
+				&& graph.get(&f.name).is_some_and(|n| n.calls.is_empty())
+				&& !rides_train(f)
+				&& matches!(f.basic_blocks[0].term, llvm_ir::Terminator::Ret(_))
+		})
+		.cloned()
+		.collect::<Vec<_>>();
+
+	if leaves.is_empty() {
+		return;
+	}
+
+	let types = &module.types;
+	for func in module.functions.iter_mut() {
+		let mut site = 0;
+
+		for b in 0..func.basic_blocks.len() {
+			let mut i = 0;
+			while i < func.basic_blocks[b].instrs.len() {
+				let callee = match &func.basic_blocks[b].instrs[i] {
+					llvm_ir::Instruction::Call(c) => callee_of(c)
+						.and_then(|n| leaves.iter().find(|l| l.name == n)),
+					_ => None,
+				};
+
+				let callee = match callee {
+					Some(callee) => callee,
+					None => {
+						i += 1;
+						continue;
+					}
+				};
+
+				let call = match func.basic_blocks[b].instrs.remove(i) {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => unreachable!(),
+				};
+
+				let (body, ret) = inline_body(types, callee, &call, site);
+				site += 1;
+
+				let len = body.len();
+				for (j, instr) in body.into_iter().enumerate() {
+					func.basic_blocks[b].instrs.insert(i + j, instr);
+				}
+
+				if let (Some(dest), Some(ret)) = (&call.dest, ret) {
+					for block in func.basic_blocks.iter_mut() {
+						replace_uses_in_block(block, dest, &ret);
+					}
+				}
+
+				i += len;
+			}
+		}
+	}
+}
+
+// -O0 gives every local and every argument an alloca whether anything reads
+// it back or not, and each one costs a cell plus a clear and a store per
+// frame. Allocas that are only ever stored to go away along with their
+// stores, then anything whose result nobody uses. An alloca used any other
+// way, say its address getting passed along or stored somewhere, could be
+// read through a pointer so it stays. Volatile loads and stores always stay.
+fn drop_dead_values(module: &mut llvm_ir::Module) {
+	for func in module.functions.iter_mut() {
+		let allocas = func
+			.basic_blocks
+			.iter()
+			.flat_map(|b| b.instrs.iter())
+			.filter_map(|i| match i {
+				llvm_ir::Instruction::Alloca(a) => Some(a.dest.clone()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		loop {
+			// storing into an alloca doesn't count as using it, everything
+			// else does
+			let mut used = vec![];
+			for block in func.basic_blocks.iter_mut() {
+				for instr in block.instrs.iter_mut() {
+					if let llvm_ir::Instruction::Store(s) = instr {
+						if !s.volatile {
+							used.extend(local_name(&s.value));
+							used.extend(
+								local_name(&s.address)
+									.filter(|n| !allocas.contains(n)),
+							);
+							continue;
+						}
+					}
+
+					for op in operands_mut(instr) {
+						used.extend(local_name(op));
+					}
+				}
+
+				for op in term_operands_mut(&mut block.term) {
+					used.extend(local_name(op));
+				}
+			}
+
+			let write_only = |n: &llvm_ir::Name| {
+				allocas.contains(n) && !used.contains(n)
+			};
+
+			let mut changed = false;
+			for block in func.basic_blocks.iter_mut() {
+				let before = block.instrs.len();
+
+				block.instrs.retain(|i| match i {
+					llvm_ir::Instruction::Store(s) => {
+						s.volatile
+							|| local_name(&s.address).is_none_or(|n| !write_only(&n))
+					}
+					llvm_ir::Instruction::Call(_) => true,
+					llvm_ir::Instruction::Load(l) if l.volatile => true,
+					_ => match i.try_get_result() {
+						Some(r) => used.contains(r),
+						None => true,
+					},
+				});
+
+				changed |= block.instrs.len() != before;
+			}
+
+			if !changed {
+				break;
+			}
+		}
+	}
+}
+
+// -O0 stores every value to its variable and loads it right back for the next
+// statement. Within a block a load of an alloca just takes the value last
+// stored to it, for as long as nothing could have written it since, same
+// rules as reuse_loads. drop_dead_values then gets rid of the stores nobody
+// loads anymore. Only integers get forwarded, a pointer coming back out of
+// an alloca stays a load.
+fn forward_stores(module: &mut llvm_ir::Module) {
+	const PURE_CALLS: &[&str] = &["putchar", "getchar"];
+	let types = &module.types;
+
+	for func in module.functions.iter_mut() {
+		let allocas = func
+			.basic_blocks
+			.iter()
+			.flat_map(|b| b.instrs.iter())
+			.filter_map(|i| match i {
+				llvm_ir::Instruction::Alloca(a) => Some(a.dest.clone()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		let mut forwarded: Vec<(llvm_ir::Name, llvm_ir::Operand)> = vec![];
+
+		for block in func.basic_blocks.iter_mut() {
+			// alloca -> what was last stored to it
+			let mut stored: Vec<(llvm_ir::Name, llvm_ir::Operand)> = vec![];
+
+			block.instrs.retain(|i| match i {
+				llvm_ir::Instruction::Load(l) if !l.volatile => {
+					let v = local_name(&l.address).and_then(|a| {
+						stored.iter().find(|(s, _)| s == &a).map(|(_, v)| v)
+					});
+
+					match v {
+						Some(v) => {
+							forwarded.push((l.dest.clone(), v.clone()));
+							false
+						}
+						None => true,
+					}
+				}
+				llvm_ir::Instruction::Store(s) => {
+					match local_name(&s.address) {
+						Some(a) if !s.volatile && allocas.contains(&a) => {
+							stored.retain(|(l, _)| l != &a);
+							if let llvm_ir::Type::IntegerType { .. } =
+								types.type_of(&s.value).as_ref()
+							{
+								stored.push((a, s.value.clone()));
+							}
+						}
+						_ => stored.clear(),
+					}
+					true
+				}
+				llvm_ir::Instruction::Call(c) => {
+					let pure = callee_of(c)
+						.is_some_and(|n| PURE_CALLS.contains(&n.as_str()));
+					if !pure {
+						stored.clear();
+					}
+					true
+				}
+				_ => true,
+			});
+		}
+
+		// the stored value might've been a load that got forwarded too
+		for k in 0..forwarded.len() {
+			while let Some(n) = local_name(&forwarded[k].1) {
+				match forwarded.iter().find(|(d, _)| d == &n) {
+					Some((_, v)) => forwarded[k].1 = v.clone(),
+					None => break,
+				}
+			}
+		}
+
+		for (name, with) in forwarded.iter() {
+			for block in func.basic_blocks.iter_mut() {
+				replace_uses_in_block(block, name, with);
+			}
+		}
+	}
+}
+
+// -O0 loads a variable fresh before every single use and each of those loads is
+// a whole dup through scratch. Within a block point later loads of an alloca at
+// the first one for as long as nothing could have written it since. A store to
+// the alloca only spoils that alloca, anything we can't see the target of
+// (stores through pointers, volatile stores, calls) spoils all of them. The
+// intrinsics never touch memory so they're let through.
+fn reuse_loads(module: &mut llvm_ir::Module) {
+	const PURE_CALLS: &[&str] = &["putchar", "getchar"];
+	let types = &module.types;
+
+	for func in module.functions.iter_mut() {
+		let allocas = func
+			.basic_blocks
+			.iter()
+			.flat_map(|b| b.instrs.iter())
+			.filter_map(|i| match i {
+				llvm_ir::Instruction::Alloca(a) => Some(a.dest.clone()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		let mut reused = vec![];
+
+		for block in func.basic_blocks.iter_mut() {
+			// alloca -> the load that last read it
+			let mut loaded: Vec<(llvm_ir::Name, llvm_ir::Operand)> = vec![];
+
+			block.instrs.retain(|i| match i {
+				llvm_ir::Instruction::Load(l) if !l.volatile => {
+					let addr = match local_name(&l.address) {
+						Some(a) if allocas.contains(&a) => a,
+						_ => return true,
+					};
+
+					match loaded.iter().find(|(a, _)| a == &addr) {
+						Some((_, v)) => {
+							reused.push((l.dest.clone(), v.clone()));
+							false
+						}
+						None => {
+							loaded.push((
+								addr,
+								llvm_ir::Operand::LocalOperand {
+									name: l.dest.clone(),
+									ty: types.type_of(l),
+								},
+							));
+							true
+						}
+					}
+				}
+				llvm_ir::Instruction::Store(s) => {
+					match local_name(&s.address) {
+						Some(a) if !s.volatile && allocas.contains(&a) => {
+							loaded.retain(|(l, _)| l != &a)
+						}
+						_ => loaded.clear(),
+					}
+					true
+				}
+				llvm_ir::Instruction::Call(c) => {
+					let pure = callee_of(c)
+						.is_some_and(|n| PURE_CALLS.contains(&n.as_str()));
+					if !pure {
+						loaded.clear();
+					}
+					true
+				}
+				_ => true,
+			});
+		}
+
+		for (name, with) in reused.iter() {
+			for block in func.basic_blocks.iter_mut() {
+				replace_uses_in_block(block, name, with);
+			}
+		}
+	}
+}
+
+fn local_name(op: &llvm_ir::Operand) -> Option<llvm_ir::Name> {
+	match op {
+		llvm_ir::Operand::LocalOperand { name, .. } => Some(name.clone()),
+		_ => None,
+	}
+}
+
+// Going through a pointer means a train ride whose length grows with the frame
+// it leaves from. Pasting the callee into its caller only makes that frame
+// wider, so the ride ends up costing more than the call ever did.
+fn rides_train(f: &llvm_ir::Function) -> bool {
+	let allocas = f.basic_blocks[0]
+		.instrs
+		.iter()
+		.filter_map(|i| match i {
+			llvm_ir::Instruction::Alloca(a) => Some(&a.dest),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+
+	let through_ptr = |op: &llvm_ir::Operand| match op {
+		llvm_ir::Operand::LocalOperand { name, .. } => !allocas.contains(&name),
+		_ => false,
+	};
+
+	f.basic_blocks[0].instrs.iter().any(|i| match i {
+		llvm_ir::Instruction::Load(l) => through_ptr(&l.address),
+		llvm_ir::Instruction::Store(s) => through_ptr(&s.address),
+		_ => false,
+	})
+}
+
+// a function named directly as a value rather than being called
+fn func_ref(op: &llvm_ir::Operand) -> Option<String> {
+	let c = match op {
+		llvm_ir::Operand::ConstantOperand(c) => c,
+		_ => return None,
+	};
+
+	match c.deref() {
+		llvm_ir::Constant::GlobalReference {
+			name: llvm_ir::Name::Name(n),
+			ty,
+		} => match ty.deref() {
+			llvm_ir::Type::FuncType { .. } => Some(*n.clone()),
+			_ => None,
+		},
+		llvm_ir::Constant::BitCast(bc) => {
+			func_ref(&llvm_ir::Operand::ConstantOperand(bc.operand.clone()))
+		}
+		_ => None,
+	}
+}
+
+// callee's instructions with everything renamed to be unique to this call site
+// and the params swapped for the call's args. Also hands back what the callee
+// returns.
+fn inline_body(
+	types: &llvm_ir::types::Types,
+	callee: &llvm_ir::Function,
+	call: &llvm_ir::instruction::Call,
+	site: usize,
+) -> (Vec<llvm_ir::Instruction>, Option<llvm_ir::Operand>) {
+	let mut block = callee.basic_blocks[0].clone();
+
+	let fresh = |old: &llvm_ir::Name| {
+		llvm_ir::Name::Name(Box::new(format!(
+			"{}.inl{}.{}",
+			callee.name,
+			site,
+			match old {
+				llvm_ir::Name::Name(n) => *n.clone(),
+				llvm_ir::Name::Number(n) => n.to_string(),
+			}
+		)))
+	};
+
+	// everything gets renamed before the args go in, otherwise an arg could
+	// share a name with one of the callee's values and get renamed right
+	// along with it
+	let renames = block
+		.instrs
+		.iter()
+		.filter_map(|i| i.try_get_result().map(|r| (r.clone(), types.type_of(i))))
+		.chain(callee.parameters.iter().map(|p| (p.name.clone(), p.ty.clone())))
+		.collect::<Vec<_>>();
+
+	for (old, ty) in renames.iter() {
+		let new = fresh(old);
+
+		replace_uses_in_block(
+			&mut block,
+			old,
+			&llvm_ir::Operand::LocalOperand {
+				name: new.clone(),
+				ty: ty.clone(),
+			},
+		);
+
+		for instr in block.instrs.iter_mut() {
+			if let Some(d) = dest_mut(instr) {
+				if d == old {
+					*d = new.clone();
+				}
+			}
+		}
+	}
+
+	for (p, a) in callee.parameters.iter().zip(call.arguments.iter()) {
+		replace_uses_in_block(&mut block, &fresh(&p.name), &a.0);
+	}
+
+	let ret = match block.term {
+		llvm_ir::Terminator::Ret(r) => r.return_operand,
+		_ => unreachable!(),
+	};
+
+	(block.instrs, ret)
+}
+
+// same as operands_mut but for where an instruction puts its result
+fn dest_mut(i: &mut llvm_ir::Instruction) -> Option<&mut llvm_ir::Name> {
+	match i {
+		llvm_ir::Instruction::Load(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Alloca(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Add(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Sub(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Mul(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::UDiv(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::SDiv(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::URem(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::SRem(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::And(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Or(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Xor(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Shl(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::LShr(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::AShr(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::ICmp(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::ZExt(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::SExt(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Trunc(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::IntToPtr(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::PtrToInt(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::BitCast(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::GetElementPtr(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Select(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Phi(i) => Some(&mut i.dest),
+		llvm_ir::Instruction::Call(i) => i.dest.as_mut(),
+		_ => None,
+	}
+}
+
+// Back to back putchar calls of constants are what every banner compiles to.
+// Each one would end up in its own block building its char up from zero, so
+// fold the run into a single putchar call taking all the chars as arguments
+// and let the intrinsic step between them instead.
+fn merge_putchars(module: &mut llvm_ir::Module) {
+	let const_putchar = |i: &llvm_ir::Instruction| match i {
+		llvm_ir::Instruction::Call(c) => {
+			callee_of(c).as_deref() == Some("putchar")
+				&& c.arguments
+					.iter()
+					.all(|a| matches!(&a.0, llvm_ir::Operand::ConstantOperand(_)))
+		}
+		_ => false,
+	};
+
+	for func in module.functions.iter_mut() {
+		for block in func.basic_blocks.iter_mut() {
+			let mut merged: Vec<llvm_ir::Instruction> = vec![];
+
+			for instr in block.instrs.drain(..) {
+				let prev = merged.last_mut().filter(|p| const_putchar(p));
+
+				match (prev, &instr) {
+					(
+						Some(llvm_ir::Instruction::Call(prev)),
+						llvm_ir::Instruction::Call(c),
+					) if const_putchar(&instr) => {
+						prev.arguments.extend(c.arguments.iter().cloned());
+					}
+					_ => merged.push(instr),
+				}
+			}
+
+			block.instrs = merged;
+		}
+	}
+}
+
+// name of whatever a call is calling, None for asm and calls through pointers
+fn callee_of(c: &llvm_ir::instruction::Call) -> Option<String> {
+	match c.function.as_ref().right()?.as_constant()? {
+		llvm_ir::Constant::GlobalReference { name, .. } => Some(n2nam(name)),
+		_ => None,
+	}
+}
+
+// Intrinsics that only exist to tell the optimizer things. We've got no use
+// for any of that so they're dropped before they'd turn into calls, except
+// llvm.expect which hands back its first argument so every use of its result
+// gets pointed at that instead.
+fn strip_hints(module: &mut llvm_ir::Module) {
+	const DROPPED: &[&str] = &[
+		"llvm.assume",
+		"llvm.prefetch",
+		"llvm.lifetime.",
+		"llvm.dbg.",
+		"llvm.experimental.noalias",
+	];
+
+	for func in module.functions.iter_mut() {
+		let mut aliases = vec![];
+
+		for block in func.basic_blocks.iter_mut() {
+			block.instrs.retain(|i| {
+				let c = match i {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => return true,
+				};
+
+				let callee = match callee_of(c) {
+					Some(callee) => callee,
+					None => return true,
+				};
+
+				if callee.starts_with("llvm.expect") {
+					if let Some(dest) = &c.dest {
+						aliases.push((dest.clone(), c.arguments[0].0.clone()));
+					}
+					return false;
+				}
+
+				!DROPPED.iter().any(|d| callee.starts_with(d))
+			});
+		}
+
+		for (name, with) in aliases.iter() {
+			for block in func.basic_blocks.iter_mut() {
+				replace_uses_in_block(block, name, with);
+			}
+		}
+	}
+}
+
+// An all zero array like puts("")'s string comes as a zeroinitializer rather
+// than a list of elements. Globals are laid out element by element so integer
+// ones get their zeros written out.
+fn spell_out_zeros(module: &mut llvm_ir::Module) {
+	for g in module.global_vars.iter_mut() {
+		let ty = match g.initializer.as_ref().map(|c| c.as_ref()) {
+			Some(llvm_ir::Constant::AggregateZero(ty)) => ty.clone(),
+			_ => continue,
+		};
+		let (element_type, n) = match ty.as_ref() {
+			llvm_ir::Type::ArrayType { element_type, num_elements } => {
+				(element_type.clone(), *num_elements)
+			}
+			_ => continue,
+		};
+		let bits = match element_type.as_ref() {
+			llvm_ir::Type::IntegerType { bits } => *bits,
+			_ => continue,
+		};
+
+		let zero = llvm_ir::ConstantRef::new(llvm_ir::Constant::Int {
+			bits,
+			value: 0,
+		});
+		g.initializer = Some(llvm_ir::ConstantRef::new(llvm_ir::Constant::Array {
+			element_type,
+			elements: vec![zero; n],
+		}));
+	}
+}
+
+// getc is just getchar reading from wherever it's told to, and there's only
+// the one input. The stream argument goes and the call becomes a getchar,
+// unless the module brought its own getc.
+fn alias_getc(module: &mut llvm_ir::Module) {
+	if module.functions.iter().any(|f| f.name == "getc") {
+		return;
+	}
+
+	for func in module.functions.iter_mut() {
+		for block in func.basic_blocks.iter_mut() {
+			for instr in block.instrs.iter_mut() {
+				let c = match instr {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => continue,
+				};
+
+				if callee_of(c).as_deref() != Some("getc") {
+					continue;
+				}
+
+				if let Some(llvm_ir::Operand::ConstantOperand(f)) =
+					c.function.as_mut().right()
+				{
+					let ty = match f.as_ref() {
+						llvm_ir::Constant::GlobalReference { ty, .. } => ty.clone(),
+						_ => continue,
+					};
+					*f = llvm_ir::ConstantRef::new(
+						llvm_ir::Constant::GlobalReference {
+							name: llvm_ir::Name::Name(Box::new("getchar".to_string())),
+							ty,
+						},
+					);
+				}
+				c.arguments.clear();
+			}
+		}
+	}
+}
+
+// exit ends the program from wherever it's called the same way reaching
+// unreachable does (see build_unreachable), so the call and whatever was
+// left of its block become just that. The status has nowhere to go.
+fn lower_exit(module: &mut llvm_ir::Module) {
+	if module.functions.iter().any(|f| f.name == "exit") {
+		return;
+	}
+
+	for func in module.functions.iter_mut() {
+		let mut cut = vec![];
+
+		for block in func.basic_blocks.iter_mut() {
+			let at = block.instrs.iter().position(|i| match i {
+				llvm_ir::Instruction::Call(c) => callee_of(c).as_deref() == Some("exit"),
+				_ => false,
+			});
+
+			let call = match at {
+				Some(at) => block.instrs.drain(at..).next(),
+				None => continue,
+			};
+			let debugloc = match call {
+				Some(llvm_ir::Instruction::Call(c)) => c.debugloc,
+				_ => unreachable!(),
+			};
+
+			block.term = llvm_ir::Terminator::Unreachable(llvm_ir::terminator::Unreachable {
+				debugloc,
+			});
+			cut.push(block.name.clone());
+		}
+
+		// it doesn't go on to anywhere anymore
+		for block in func.basic_blocks.iter_mut() {
+			for instr in block.instrs.iter_mut() {
+				if let llvm_ir::Instruction::Phi(phi) = instr {
+					phi.incoming_values.retain(|(_, from)| !cut.contains(from));
+				}
+			}
+		}
+	}
+}
+
+// EOF in stdfuck.h is a call to __bfcc_eof so the C never has to know which
+// convention it's being built for. Swap those calls for the actual value, at
+// 0 the comparisons then get the cheap zero test.
+fn resolve_eof(module: &mut llvm_ir::Module, eof: u8) {
+	for func in module.functions.iter_mut() {
+		let mut aliases = vec![];
+
+		for block in func.basic_blocks.iter_mut() {
+			block.instrs.retain(|i| {
+				let c = match i {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => return true,
+				};
+
+				if callee_of(c).as_deref() != Some("__bfcc_eof") {
+					return true;
+				}
+
+				if let Some(dest) = &c.dest {
+					aliases.push((
+						dest.clone(),
+						llvm_ir::Operand::ConstantOperand(llvm_ir::ConstantRef::new(
+							llvm_ir::Constant::Int {
+								bits: 8,
+								value: eof as u64,
+							},
+						)),
+					));
+				}
+				false
+			});
+		}
+
+		for (name, with) in aliases.iter() {
+			for block in func.basic_blocks.iter_mut() {
+				replace_uses_in_block(block, name, with);
+			}
+		}
+	}
+}
+
+fn replace_uses_in_block(
+	block: &mut llvm_ir::BasicBlock,
+	name: &llvm_ir::Name,
+	with: &llvm_ir::Operand,
+) {
+	for instr in block.instrs.iter_mut() {
+		for op in operands_mut(instr) {
+			replace_use(op, name, with);
+		}
+	}
+
+	for op in term_operands_mut(&mut block.term) {
+		replace_use(op, name, with);
+	}
+}
+
+fn term_operands_mut(t: &mut llvm_ir::Terminator) -> Vec<&mut llvm_ir::Operand> {
+	match t {
+		llvm_ir::Terminator::Ret(r) => r.return_operand.iter_mut().collect(),
+		llvm_ir::Terminator::CondBr(cbr) => vec![&mut cbr.condition],
+		llvm_ir::Terminator::Switch(sw) => vec![&mut sw.operand],
+		_ => vec![],
+	}
+}
+
+fn replace_use(
+	op: &mut llvm_ir::Operand,
+	name: &llvm_ir::Name,
+	with: &llvm_ir::Operand,
+) {
+	let hit = match op {
+		llvm_ir::Operand::LocalOperand { name: n, .. } => n == name,
+		_ => false,
+	};
+
+	if hit {
+		*op = with.clone();
+	}
+}
+
+// same as instr_opers but for rewriting them
+fn operands_mut(i: &mut llvm_ir::Instruction) -> Vec<&mut llvm_ir::Operand> {
+	match i {
+		llvm_ir::Instruction::Store(i) => vec![&mut i.value, &mut i.address],
+		llvm_ir::Instruction::Load(i) => vec![&mut i.address],
+		llvm_ir::Instruction::Add(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::Sub(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::Mul(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::UDiv(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::SDiv(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::URem(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::SRem(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::And(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::Or(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::Xor(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::Shl(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::LShr(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::AShr(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::ICmp(i) => vec![&mut i.operand0, &mut i.operand1],
+		llvm_ir::Instruction::ZExt(i) => vec![&mut i.operand],
+		llvm_ir::Instruction::SExt(i) => vec![&mut i.operand],
+		llvm_ir::Instruction::Trunc(i) => vec![&mut i.operand],
+		llvm_ir::Instruction::IntToPtr(i) => vec![&mut i.operand],
+		llvm_ir::Instruction::PtrToInt(i) => vec![&mut i.operand],
+		llvm_ir::Instruction::BitCast(i) => vec![&mut i.operand],
+		llvm_ir::Instruction::GetElementPtr(i) => {
+			let mut ops = vec![&mut i.address];
+			ops.extend(i.indices.iter_mut());
+			ops
+		}
+		llvm_ir::Instruction::Select(i) => {
+			vec![&mut i.condition, &mut i.true_value, &mut i.false_value]
+		}
+		llvm_ir::Instruction::Phi(i) => {
+			i.incoming_values.iter_mut().map(|(o, _)| o).collect()
+		}
+		llvm_ir::Instruction::Call(i) => {
+			i.arguments.iter_mut().map(|a| &mut a.0).collect()
+		}
+		_ => vec![],
+	}
+}
+
+// switch -> a chain of icmp eq + condbr blocks, one case per block:
+//
+// %b:   %sw_b_0 = icmp eq %v, c0 ; br %sw_b_0, %d0, %b.sw.1
+// %b.sw.1: %sw_b_1 = icmp eq %v, c1 ; br %sw_b_1, %d1, %default
+//
+// an empty case list is just a br to the default.
+fn lower_switch(
+	module: &mut llvm_ir::Module,
+	provenance: &mut Vec<Provenance>,
+) {
+	let i1 = module.types.bool();
+
+	for func in module.functions.iter_mut() {
+		let mut block = 0;
+		while block < func.basic_blocks.len() {
+			let sw = match &func.basic_blocks[block].term {
+				llvm_ir::Terminator::Switch(sw) => sw.clone(),
+				_ => {
+					block += 1;
+					continue;
+				}
+			};
+
+			let orig = func.basic_blocks[block].name.clone();
+			let base = format!("{}", orig).trim_start_matches('%').to_string();
+
+			// the first compare stays in the switch's own block, the rest get
+			// one each
+			let prefix = format!("{}.sw.", base);
+			let mut fresh = 1;
+			let mut names = vec![orig.clone()];
+			for _ in 1..sw.dests.len() {
+				let n = fresh_block_name(func, &prefix, &mut fresh);
+				provenance.push(Provenance {
+					func: func.name.clone(),
+					block: n.clone(),
+					from: orig.clone(),
+					call: None,
+					reason: BlockOrigin::SwitchCase,
+				});
+				names.push(n);
+			}
+
+			// every block in the chain and where it can end up, so phis in
+			// the destinations can be pointed at their new predecessors
+			let mut edges: Vec<(llvm_ir::Name, llvm_ir::Name)> = vec![];
+
+			if sw.dests.is_empty() {
+				func.basic_blocks[block].term =
+					llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
+						debugloc: sw.debugloc.clone(),
+						dest: sw.default_dest.clone(),
+					});
+				edges.push((orig.clone(), sw.default_dest.clone()));
+			}
+
+			let mut chain = vec![];
+			for (i, (val, dest)) in sw.dests.iter().enumerate() {
+				let here = names[i].clone();
+				let otherwise = match names.get(i + 1) {
+					Some(next) => next.clone(),
+					None => sw.default_dest.clone(),
+				};
+
+				let cmp =
+					llvm_ir::Name::Name(Box::new(format!("sw_{}_{}", base, i)));
+
+				let icmp =
+					llvm_ir::Instruction::ICmp(llvm_ir::instruction::ICmp {
+						predicate: llvm_ir::IntPredicate::EQ,
+						operand0: sw.operand.clone(),
+						operand1: llvm_ir::Operand::ConstantOperand(val.clone()),
+						dest: cmp.clone(),
+						debugloc: sw.debugloc.clone(),
+					});
+
+				let term =
+					llvm_ir::Terminator::CondBr(llvm_ir::terminator::CondBr {
+						condition: llvm_ir::Operand::LocalOperand {
+							name: cmp,
+							ty: i1.clone(),
+						},
+						true_dest: dest.clone(),
+						false_dest: otherwise.clone(),
+						debugloc: sw.debugloc.clone(),
+					});
+
+				edges.push((here.clone(), dest.clone()));
+				if i == sw.dests.len() - 1 {
+					edges.push((here.clone(), otherwise));
+				}
+
+				if i == 0 {
+					func.basic_blocks[block].instrs.push(icmp);
+					func.basic_blocks[block].term = term;
+				} else {
+					chain.push(llvm_ir::BasicBlock {
+						name: here,
+						instrs: vec![icmp],
+						term,
+					});
+				}
+			}
+
+			for bblock in func.basic_blocks.iter_mut() {
+				for instr in bblock.instrs.iter_mut() {
+					if let llvm_ir::Instruction::Phi(phi) = instr {
+						let mut incoming = vec![];
+						for (val, from) in phi.incoming_values.iter() {
+							if from != &orig {
+								incoming.push((val.clone(), from.clone()));
+								continue;
+							}
+
+							for (pred, to) in edges.iter() {
+								if to == &bblock.name
+									&& !incoming.iter().any(|(_, p)| p == pred)
+								{
+									incoming.push((val.clone(), pred.clone()));
+								}
+							}
+						}
+						phi.incoming_values = incoming;
+					}
+				}
+			}
+
+			let n = chain.len();
+			for (i, b) in chain.into_iter().enumerate() {
+				func.basic_blocks.insert(block + 1 + i, b);
+			}
+
+			block += 1 + n;
+		}
+	}
+}
+
+// llvm.memcpy and llvm.memmove with a constant length become a load and store
+// per byte. memcpy's ranges can't overlap so it just goes front to back.
+// memmove's can, shifting an array right copies each byte onto the next one
+// before that one's been read. When both ends are in the same global we know
+// which way round is safe: back to front when the destination's above the
+// source. Otherwise every byte is read before any gets written.
+fn lower_mem_copies(module: &mut llvm_ir::Module) {
+	let types = &module.types;
+	let i8t = types.i8();
+
+	for func in module.functions.iter_mut() {
+		let mut copies = 0;
+
+		for block in func.basic_blocks.iter_mut() {
+			let mut instrs = vec![];
+
+			for instr in block.instrs.drain(..) {
+				let c = match &instr {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => {
+						instrs.push(instr);
+						continue;
+					}
+				};
+
+				let overlaps = match callee_of(c) {
+					Some(n) if n.starts_with("llvm.memmove") => true,
+					Some(n) if n.starts_with("llvm.memcpy") => false,
+					_ => {
+						instrs.push(instr);
+						continue;
+					}
+				};
+
+				let len = match c.arguments[2].0.as_constant() {
+					Some(llvm_ir::Constant::Int { value, .. }) => *value,
+					// left for reject_unnormalizable to complain about
+					_ => {
+						instrs.push(instr);
+						continue;
+					}
+				};
+
+				let volatile = match c.arguments[3].0.as_constant() {
+					Some(llvm_ir::Constant::Int { value, .. }) => *value != 0,
+					_ => false,
+				};
+
+				let dst = &c.arguments[0].0;
+				let src = &c.arguments[1].0;
+
+				let backwards = match (global_cell(dst), global_cell(src)) {
+					(Some((d, doff)), Some((s, soff))) => {
+						Some(d == s && doff > soff)
+					}
+					_ => None,
+				};
+
+				let n = copies;
+				copies += 1;
+				let name =
+					|i: u64, what: &str| llvm_ir::Name::Name(Box::new(format!(
+						"copy_{}_{}_{}",
+						n, i, what
+					)));
+
+				let mut loads = vec![];
+				let mut stores = vec![];
+				for i in 0..len {
+					let (mut at, from) = byte_at(types, src, i, name(i, "src"));
+					let (mut to_at, to) = byte_at(types, dst, i, name(i, "dst"));
+
+					let byte = name(i, "byte");
+					at.push(llvm_ir::Instruction::Load(llvm_ir::instruction::Load {
+						address: from,
+						dest: byte.clone(),
+						volatile,
+						atomicity: None,
+						alignment: 1,
+						debugloc: c.debugloc.clone(),
+					}));
+					to_at.push(llvm_ir::Instruction::Store(
+						llvm_ir::instruction::Store {
+							address: to,
+							value: llvm_ir::Operand::LocalOperand {
+								name: byte,
+								ty: i8t.clone(),
+							},
+							volatile,
+							atomicity: None,
+							alignment: 1,
+							debugloc: c.debugloc.clone(),
+						},
+					));
+
+					loads.push(at);
+					stores.push(to_at);
+				}
+
+				match (overlaps, backwards) {
+					(true, None) => {
+						instrs.extend(loads.into_iter().flatten());
+						instrs.extend(stores.into_iter().flatten());
+					}
+					(true, Some(true)) => {
+						for (l, s) in loads.into_iter().zip(stores).rev() {
+							instrs.extend(l);
+							instrs.extend(s);
+						}
+					}
+					_ => {
+						for (l, s) in loads.into_iter().zip(stores) {
+							instrs.extend(l);
+							instrs.extend(s);
+						}
+					}
+				}
+			}
+
+			block.instrs = instrs;
+		}
+	}
+}
+
+// the bytes of a constant string up to its terminating 0
+fn string_constant(module: &llvm_ir::Module, op: &llvm_ir::Operand) -> Option<Vec<u8>> {
+	let (g, off) = global_cell(op)?;
+	let name = match g.as_ref() {
+		llvm_ir::Constant::GlobalReference { name, .. } => name,
+		_ => return None,
+	};
+	let g = module.global_vars.iter().find(|v| &v.name == name)?;
+	if !g.is_constant {
+		return None;
+	}
+	let elements = match g.initializer.as_ref()?.as_ref() {
+		llvm_ir::Constant::Array { elements, .. } => elements,
+		_ => return None,
+	};
+
+	let mut bytes = vec![];
+	for e in elements.iter().skip(off as usize) {
+		match e.as_ref() {
+			llvm_ir::Constant::Int { value: 0, .. } => return Some(bytes),
+			llvm_ir::Constant::Int { value, .. } => bytes.push(*value as u8),
+			_ => return None,
+		}
+	}
+	None
+}
+
+// a putchar of byte standing in for the call like
+fn putchar_call(
+	types: &llvm_ir::types::Types,
+	like: &llvm_ir::instruction::Call,
+	byte: llvm_ir::Operand,
+) -> llvm_ir::Instruction {
+	let mut p = like.clone();
+	if let Some(f) = p.function.as_mut().right() {
+		*f = llvm_ir::Operand::ConstantOperand(llvm_ir::ConstantRef::new(
+			llvm_ir::Constant::GlobalReference {
+				name: llvm_ir::Name::Name(Box::new("putchar".to_string())),
+				ty: types.func_type(types.void(), vec![types.i8()], false),
+			},
+		));
+	}
+	p.arguments = vec![(byte, vec![])];
+	p.dest = None;
+	llvm_ir::Instruction::Call(p)
+}
+
+fn byte_const(v: u8) -> llvm_ir::Operand {
+	llvm_ir::Operand::ConstantOperand(llvm_ir::ConstantRef::new(
+		llvm_ir::Constant::Int { bits: 8, value: v as u64 },
+	))
+}
+
+// what puts and printf give back is just "it worked"
+fn zero_result(
+	types: &llvm_ir::types::Types,
+	func: &mut llvm_ir::Function,
+	call: &llvm_ir::instruction::Call,
+) {
+	let dest = match &call.dest {
+		Some(dest) => dest,
+		None => return,
+	};
+	let bits = match types.type_of(call).as_ref() {
+		llvm_ir::Type::IntegerType { bits } => *bits,
+		_ => 32,
+	};
+
+	for block in func.basic_blocks.iter_mut() {
+		for instr in block.instrs.iter_mut() {
+			for op in operands_mut(instr) {
+				if local_name(op).as_ref() == Some(dest) {
+					*op = llvm_ir::Operand::ConstantOperand(llvm_ir::ConstantRef::new(
+						llvm_ir::Constant::Int { bits, value: 0 },
+					));
+				}
+			}
+		}
+	}
+}
+
+// -O1 turns puts("") and friends into a putchar('\n') of its own, which calls
+// our putchar through a bitcast to the int putchar(int) it had in mind. With
+// a constant char that's just a plain putchar of the byte.
+fn uncast_putchar(module: &mut llvm_ir::Module) {
+	let types = &module.types;
+	for func in module.functions.iter_mut() {
+		let mut casted = vec![];
+		for (b, block) in func.basic_blocks.iter().enumerate() {
+			for (i, instr) in block.instrs.iter().enumerate() {
+				let c = match instr {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => continue,
+				};
+				let callee =
+					c.function.as_ref().right().and_then(|f| f.as_constant());
+				let putchar = match callee {
+					Some(llvm_ir::Constant::BitCast(cast)) => matches!(
+						cast.operand.as_ref(),
+						llvm_ir::Constant::GlobalReference { name, .. }
+							if n2nam(name) == "putchar"
+					),
+					_ => false,
+				};
+				let arg = match c.arguments.as_slice() {
+					[(llvm_ir::Operand::ConstantOperand(a), _)] => a.as_ref(),
+					_ => continue,
+				};
+				match arg {
+					llvm_ir::Constant::Int { value, .. } if putchar => {
+						casted.push((b, i, *value as u8))
+					}
+					_ => {}
+				}
+			}
+		}
+
+		for (b, i, byte) in casted {
+			let call = match &func.basic_blocks[b].instrs[i] {
+				llvm_ir::Instruction::Call(c) => c.clone(),
+				_ => unreachable!(),
+			};
+			func.basic_blocks[b].instrs[i] =
+				putchar_call(types, &call, byte_const(byte));
+			zero_result(types, func, &call);
+		}
+	}
+}
+
+// puts of a string constant is known byte for byte at compile time so it
+// turns into a putchar each, which merge_putchars then runs together. Strings
+// only known at runtime are left for reject_unnormalizable.
+fn lower_puts(module: &mut llvm_ir::Module) {
+	if module.functions.iter().any(|f| f.name == "puts") {
+		return;
+	}
+
+	let mut lowered = vec![];
+	for (f, func) in module.functions.iter().enumerate() {
+		for (b, block) in func.basic_blocks.iter().enumerate() {
+			for (i, instr) in block.instrs.iter().enumerate() {
+				let c = match instr {
+					llvm_ir::Instruction::Call(c) => c,
+					_ => continue,
+				};
+				if callee_of(c).as_deref() != Some("puts") || c.arguments.len() != 1 {
+					continue;
+				}
+				if let Some(s) = string_constant(module, &c.arguments[0].0) {
+					lowered.push((f, b, i, s));
+				}
+			}
+		}
+	}
+
+	// back to front so the indices of the ones still to go stay put
+	for (f, b, i, s) in lowered.into_iter().rev() {
+		let types = &module.types;
+		let func = &mut module.functions[f];
+		let call = match func.basic_blocks[b].instrs.remove(i) {
+			llvm_ir::Instruction::Call(c) => c,
+			_ => unreachable!(),
+		};
+
+		let chars = s
+			.iter()
+			.chain(Some(&b'\n'))
+			.map(|&byte| putchar_call(types, &call, byte_const(byte)));
+		let at = &mut func.basic_blocks[b].instrs;
+		let rest = at.split_off(i);
+		at.extend(chars);
+		at.extend(rest);
+
+		zero_result(types, func, &call);
+	}
+}
+
+enum PrintfPiece {
+	Text(Vec<u8>),
+	Char(llvm_ir::Operand),
+	Dec(llvm_ir::Operand),
+}
+
+// A printf's format split into what's known now and what's printed from its
+// args. The error is why it can't be lowered, for reject_unnormalizable.
+fn printf_pieces(
+	module: &llvm_ir::Module,
+	c: &llvm_ir::instruction::Call,
+) -> Result<Vec<PrintfPiece>, String> {
+	let format = c
+		.arguments
+		.first()
+		.and_then(|a| string_constant(module, &a.0))
+		.ok_or("printf only takes a string constant as its format".to_string())?;
+	let mut args = c.arguments.iter().skip(1).map(|a| &a.0);
+
+	let mut pieces = vec![];
+	let mut text = vec![];
+	let mut bytes = format.into_iter();
+	while let Some(b) = bytes.next() {
+		if b != b'%' {
+			text.push(b);
+			continue;
+		}
+
+		let spec = match bytes.next() {
+			Some(s) => s as char,
+			None => return Err("printf format ends in a lone %".to_string()),
+		};
+		if spec == '%' {
+			text.push(b'%');
+			continue;
+		}
+
+		let arg = match spec {
+			'c' | 's' | 'd' => match args.next() {
+				Some(a) => a.clone(),
+				None => return Err(format!("printf is missing the argument for %{}", spec)),
+			},
+			_ => return Err(format!("printf can't do %{}", spec)),
+		};
+
+		if spec == 's' {
+			match string_constant(module, &arg) {
+				Some(s) => text.extend(s),
+				None => return Err("printf %s only takes string constants".to_string()),
+			}
+			continue;
+		}
+
+		if !text.is_empty() {
+			pieces.push(PrintfPiece::Text(text.split_off(0)));
+		}
+		pieces.push(match spec {
+			'c' => PrintfPiece::Char(arg),
+			_ => PrintfPiece::Dec(arg),
+		});
+	}
+	if !text.is_empty() {
+		pieces.push(PrintfPiece::Text(text));
+	}
+
+	Ok(pieces)
+}
+
+// printf with a constant format. The text goes out like puts, %c is a
+// putchar of its arg and %d splits the block so the leading zeros can be
+// skipped:
+//
+// %b:        x/100, x/10, x/10%10, x%10 ; br x/100 != 0, %hundreds, %tens?
+// %hundreds: putchar '0' + x/100 ; br %tens
+// %tens?:    br x/10 != 0, %tens, %ones
+// %tens:     putchar '0' + x/10%10 ; br %ones
+// %ones:     putchar '0' + x%10 ; the rest of %b
+//
+// with the new blocks really named %b.printf.<call>.<piece>.<what>.
+// Anything wider than a cell only has its low byte printed.
+//
+// print_u8(x) is the same as printf("%d", x).
+fn lower_printf(module: &mut llvm_ir::Module) {
+	let ours = |name: &str| module.functions.iter().any(|f| f.name == name);
+	let (printf, print_u8) = (!ours("printf"), !ours("print_u8"));
+
+	let i1 = module.types.bool();
+	let i8t = module.types.i8();
+
+	let mut n = 0;
+	loop {
+		let mut found = None;
+		'search: for (f, func) in module.functions.iter().enumerate() {
+			for (b, block) in func.basic_blocks.iter().enumerate() {
+				for (i, instr) in block.instrs.iter().enumerate() {
+					let c = match instr {
+						llvm_ir::Instruction::Call(c) => c,
+						_ => continue,
+					};
+					let pieces = match callee_of(c).as_deref() {
+						Some("printf") if printf => printf_pieces(module, c),
+						Some("print_u8") if print_u8 && c.arguments.len() == 1 => {
+							Ok(vec![PrintfPiece::Dec(c.arguments[0].0.clone())])
+						}
+						_ => continue,
+					};
+					if let Ok(pieces) = pieces {
+						found = Some((f, b, i, pieces));
+						break 'search;
+					}
+				}
+			}
+		}
+
+		let (f, b, i, pieces) = match found {
+			Some(found) => found,
+			None => return,
+		};
+
+		let types = &module.types;
+		let func = &mut module.functions[f];
+		let orig = func.basic_blocks[b].name.clone();
+		let base = format!("{}", orig).trim_start_matches('%').to_string();
+
+		let mut after = func.basic_blocks[b].instrs.split_off(i);
+		let call = match after.remove(0) {
+			llvm_ir::Instruction::Call(c) => c,
+			_ => unreachable!(),
+		};
+		let debugloc = call.debugloc.clone();
+
+		let label =
+			|k: usize, what: &str| llvm_ir::Name::Name(Box::new(format!("{}.printf.{}.{}.{}", base, n, k, what)));
+		let value =
+			|k: usize, what: &str| llvm_ir::Name::Name(Box::new(format!("printf_{}_{}_{}", n, k, what)));
+		let local = |name: &llvm_ir::Name, ty: &llvm_ir::TypeRef| llvm_ir::Operand::LocalOperand {
+			name: name.clone(),
+			ty: ty.clone(),
+		};
+		let br = |dest: llvm_ir::Name| {
+			llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
+				dest,
+				debugloc: debugloc.clone(),
+			})
+		};
+
+		let mut split = vec![];
+		let mut here = orig.clone();
+		let mut cur = func.basic_blocks[b].instrs.split_off(0);
+		for (k, piece) in pieces.into_iter().enumerate() {
+			let arg = match piece {
+				PrintfPiece::Text(bytes) => {
+					for byte in bytes {
+						cur.push(putchar_call(types, &call, byte_const(byte)));
+					}
+					continue;
+				}
+				PrintfPiece::Char(arg) => {
+					let byte = as_byte(types, arg, value(k, "byte"), &mut cur, &debugloc);
+					cur.push(putchar_call(types, &call, byte));
+					continue;
+				}
+				PrintfPiece::Dec(arg) => arg,
+			};
+			let x = as_byte(types, arg, value(k, "byte"), &mut cur, &debugloc);
+
+			let udiv = |a: llvm_ir::Operand, v: u8, dest: &llvm_ir::Name| {
+				llvm_ir::Instruction::UDiv(llvm_ir::instruction::UDiv {
+					operand0: a,
+					operand1: byte_const(v),
+					dest: dest.clone(),
+					debugloc: debugloc.clone(),
+				})
+			};
+			let urem = |a: llvm_ir::Operand, v: u8, dest: &llvm_ir::Name| {
+				llvm_ir::Instruction::URem(llvm_ir::instruction::URem {
+					operand0: a,
+					operand1: byte_const(v),
+					dest: dest.clone(),
+					debugloc: debugloc.clone(),
+				})
+			};
+			let nonzero = |a: &llvm_ir::Name, dest: &llvm_ir::Name| {
+				llvm_ir::Instruction::ICmp(llvm_ir::instruction::ICmp {
+					predicate: llvm_ir::IntPredicate::NE,
+					operand0: local(a, &i8t),
+					operand1: byte_const(0),
+					dest: dest.clone(),
+					debugloc: debugloc.clone(),
+				})
+			};
+
+			let (hundreds, over9, tens, ones) =
+				(value(k, "hundreds"), value(k, "over9"), value(k, "tens"), value(k, "ones"));
+			cur.push(udiv(x.clone(), 100, &hundreds));
+			cur.push(udiv(x.clone(), 10, &over9));
+			cur.push(urem(local(&over9, &i8t), 10, &tens));
+			cur.push(urem(x, 10, &ones));
+
+			let digit = |cur: &mut Vec<llvm_ir::Instruction>, of: &llvm_ir::Name, what: &str| {
+				let d = value(k, what);
+				cur.push(llvm_ir::Instruction::Add(llvm_ir::instruction::Add {
+					operand0: local(of, &i8t),
+					operand1: byte_const(b'0'),
+					dest: d.clone(),
+					debugloc: debugloc.clone(),
+				}));
+				cur.push(putchar_call(types, &call, local(&d, &i8t)));
+			};
+
+			let any = value(k, "any_hundreds");
+			cur.push(nonzero(&hundreds, &any));
+			split.push(llvm_ir::BasicBlock {
+				name: here,
+				instrs: cur,
+				term: llvm_ir::Terminator::CondBr(llvm_ir::terminator::CondBr {
+					condition: local(&any, &i1),
+					true_dest: label(k, "hundreds"),
+					false_dest: label(k, "tens?"),
+					debugloc: debugloc.clone(),
+				}),
+			});
+
+			let mut h = vec![];
+			digit(&mut h, &hundreds, "hundreds_digit");
+			split.push(llvm_ir::BasicBlock {
+				name: label(k, "hundreds"),
+				instrs: h,
+				term: br(label(k, "tens")),
+			});
+
+			let any = value(k, "any_tens");
+			split.push(llvm_ir::BasicBlock {
+				name: label(k, "tens?"),
+				instrs: vec![nonzero(&over9, &any)],
+				term: llvm_ir::Terminator::CondBr(llvm_ir::terminator::CondBr {
+					condition: local(&any, &i1),
+					true_dest: label(k, "tens"),
+					false_dest: label(k, "ones"),
+					debugloc: debugloc.clone(),
+				}),
+			});
+
+			let mut t = vec![];
+			digit(&mut t, &tens, "tens_digit");
+			split.push(llvm_ir::BasicBlock {
+				name: label(k, "tens"),
+				instrs: t,
+				term: br(label(k, "ones")),
+			});
+
+			here = label(k, "ones");
+			cur = vec![];
+			digit(&mut cur, &ones, "ones_digit");
+		}
+
+		cur.extend(after);
+		let last = here.clone();
+		let term = func.basic_blocks[b].term.clone();
+		split.push(llvm_ir::BasicBlock {
+			name: here,
+			instrs: cur,
+			term,
+		});
+		func.basic_blocks.splice(b..b + 1, split);
+
+		// whatever came after the printf now does from the last block
+		if last != orig {
+			for block in func.basic_blocks.iter_mut() {
+				for instr in block.instrs.iter_mut() {
+					if let llvm_ir::Instruction::Phi(phi) = instr {
+						for (_, from) in phi.incoming_values.iter_mut() {
+							if from == &orig {
+								*from = last.clone();
+							}
+						}
+					}
+				}
+			}
+		}
+
+		zero_result(types, func, &call);
+		n += 1;
+	}
+}
+
+// op cut down to a cell, printf's args come in at least as wide as an int
+fn as_byte(
+	types: &llvm_ir::types::Types,
+	op: llvm_ir::Operand,
+	dest: llvm_ir::Name,
+	instrs: &mut Vec<llvm_ir::Instruction>,
+	debugloc: &Option<llvm_ir::DebugLoc>,
+) -> llvm_ir::Operand {
+	let i8t = types.i8();
+	if types.type_of(&op) == i8t {
+		return op;
+	}
+
+	instrs.push(llvm_ir::Instruction::Trunc(llvm_ir::instruction::Trunc {
+		operand: op,
+		to_type: i8t.clone(),
+		dest: dest.clone(),
+		debugloc: debugloc.clone(),
+	}));
+	llvm_ir::Operand::LocalOperand { name: dest, ty: i8t }
+}
+
+
+// The global a constant pointer lands in and how many cells into it
+fn global_cell(op: &llvm_ir::Operand) -> Option<(llvm_ir::ConstantRef, u64)> {
+	let c = op.as_constant()?;
+	match c {
+		llvm_ir::Constant::GlobalReference { .. } => {
+			Some((llvm_ir::ConstantRef::new(c.clone()), 0))
+		}
+		llvm_ir::Constant::BitCast(bc) => global_cell(
+			&llvm_ir::Operand::ConstantOperand(bc.operand.clone()),
+		),
+		llvm_ir::Constant::GetElementPtr(gep) => {
+			let (g, off) = global_cell(&llvm_ir::Operand::ConstantOperand(
+				gep.address.clone(),
+			))?;
+			let indices = gep
+				.indices
+				.iter()
+				.map(|i| match i.as_ref() {
+					llvm_ir::Constant::Int { value, .. } => Some(*value),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>()?;
+
+			match indices[..] {
+				[e] | [0, e] => Some((g, off + e)),
+				_ => None,
+			}
+		}
+		_ => None,
+	}
+}
+
+// a pointer to the i'th byte after ptr, plus whatever it takes to get it
+fn byte_at(
+	types: &llvm_ir::types::Types,
+	ptr: &llvm_ir::Operand,
+	i: u64,
+	dest: llvm_ir::Name,
+) -> (Vec<llvm_ir::Instruction>, llvm_ir::Operand) {
+	let i64c = |v: u64| {
+		llvm_ir::ConstantRef::new(llvm_ir::Constant::Int { bits: 64, value: v })
+	};
+
+	if let Some((g, off)) = global_cell(ptr) {
+		let indices = match g.as_ref() {
+			llvm_ir::Constant::GlobalReference { ty, .. } => match ty.as_ref() {
+				llvm_ir::Type::ArrayType { .. } => vec![i64c(0), i64c(off + i)],
+				_ => vec![i64c(off + i)],
+			},
+			_ => unreachable!(),
+		};
+
+		let gep = llvm_ir::Constant::GetElementPtr(llvm_ir::constant::GetElementPtr {
+			address: g,
+			indices,
+			in_bounds: true,
+		});
+		return (
+			vec![],
+			llvm_ir::Operand::ConstantOperand(llvm_ir::ConstantRef::new(gep)),
+		);
+	}
+
+	if i == 0 {
+		return (vec![], ptr.clone());
+	}
+
+	let ty = types.type_of(ptr);
+	let gep = llvm_ir::Instruction::GetElementPtr(llvm_ir::instruction::GetElementPtr {
+		address: ptr.clone(),
+		indices: vec![llvm_ir::Operand::ConstantOperand(i64c(i))],
+		dest: dest.clone(),
+		in_bounds: true,
+		debugloc: None,
+	});
+
+	(vec![gep], llvm_ir::Operand::LocalOperand { name: dest, ty })
+}
+
+// A local array at -O0 is one alloca and a gep for every `buf[2]`. Ones only
+// ever indexed by constants get an alloca per element instead, each one its
+// own cell like any other local, and the geps turn into plain uses of the
+// element's alloca. Anything else, a runtime index or the array's address
+// going somewhere, leaves the array whole, laid out a cell per element.
+fn split_local_arrays(module: &mut llvm_ir::Module) {
+	let types = &module.types;
+
+	for func in module.functions.iter_mut() {
+		let arrays = func
+			.basic_blocks
+			.iter()
+			.flat_map(|b| b.instrs.iter())
+			.filter_map(|i| match i {
+				llvm_ir::Instruction::Alloca(a) => match a.allocated_type.as_ref() {
+					llvm_ir::Type::ArrayType { num_elements, .. } => {
+						Some((a.clone(), *num_elements))
+					}
+					_ => None,
+				},
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		for (array, len) in arrays {
+			let elem_ty = match array.allocated_type.as_ref() {
+				llvm_ir::Type::ArrayType { element_type, .. } => element_type.clone(),
+				_ => unreachable!(),
+			};
+
+			// gep dest -> the element it points at
+			let mut geps = vec![];
+			let mut escapes = false;
+
+			for block in func.basic_blocks.iter_mut() {
+				for instr in block.instrs.iter_mut() {
+					if let llvm_ir::Instruction::GetElementPtr(g) = instr {
+						if local_name(&g.address).as_ref() == Some(&array.dest) {
+							match constant_element(g) {
+								Some(k) if k < len => geps.push((
+									g.dest.clone(),
+									k,
+									types.type_of(&*g),
+								)),
+								_ => escapes = true,
+							}
+							continue;
+						}
+					}
+
+					for op in operands_mut(instr) {
+						escapes |= local_name(op).as_ref() == Some(&array.dest);
+					}
+				}
+
+				for op in term_operands_mut(&mut block.term) {
+					escapes |= local_name(op).as_ref() == Some(&array.dest);
+				}
+			}
+
+			if escapes {
+				continue;
+			}
+
+			let base = format!("{}", array.dest).trim_start_matches('%').to_string();
+			let element = |k: usize| {
+				llvm_ir::Name::Name(Box::new(format!("{}.{}", base, k)))
+			};
+
+			let mut used = geps.iter().map(|(_, k, _)| *k).collect::<Vec<_>>();
+			used.sort();
+			used.dedup();
+
+			let allocas = used
+				.iter()
+				.map(|k| {
+					llvm_ir::Instruction::Alloca(llvm_ir::instruction::Alloca {
+						allocated_type: elem_ty.clone(),
+						num_elements: array.num_elements.clone(),
+						dest: element(*k),
+						alignment: array.alignment,
+						debugloc: array.debugloc.clone(),
+					})
+				})
+				.collect::<Vec<_>>();
+
+			for block in func.basic_blocks.iter_mut() {
+				let at = block.instrs.iter().position(|i| match i {
+					llvm_ir::Instruction::Alloca(a) => a.dest == array.dest,
+					_ => false,
+				});
+
+				if let Some(at) = at {
+					block.instrs.splice(at..at + 1, allocas.clone());
+				}
+
+				block.instrs.retain(|i| match i {
+					llvm_ir::Instruction::GetElementPtr(g) => {
+						!geps.iter().any(|(d, _, _)| d == &g.dest)
+					}
+					_ => true,
+				});
+
+				for (dest, k, ty) in geps.iter() {
+					let with = llvm_ir::Operand::LocalOperand {
+						name: element(*k),
+						ty: ty.clone(),
+					};
+					replace_uses_in_block(block, dest, &with);
+				}
+			}
+		}
+	}
+}
+
+// i16 gets two cells next to each other, low byte first, and is the only
+// thing wider than a cell that does. The high cell is named after the value.
+fn is_i16(ty: &llvm_ir::TypeRef) -> bool {
+	**ty == llvm_ir::Type::IntegerType { bits: 16 }
+}
+
+fn operand_i16(op: &llvm_ir::Operand) -> bool {
+	match op {
+		llvm_ir::Operand::LocalOperand { ty, .. } => is_i16(ty),
+		llvm_ir::Operand::ConstantOperand(c) => {
+			matches!(c.as_ref(), llvm_ir::Constant::Int { bits: 16, .. })
+		}
+		_ => false,
+	}
+}
+
+fn high_cell(n: &llvm_ir::Name) -> llvm_ir::Name {
+	llvm_ir::Name::Name(Box::new(format!(
+		"{}.hi",
+		format!("{}", n).trim_start_matches('%')
+	)))
+}
+
+// whether the value an instruction gives takes two cells
+fn i16_result(i: &llvm_ir::Instruction) -> bool {
+	match i {
+		llvm_ir::Instruction::Add(a) => operand_i16(&a.operand0),
+		llvm_ir::Instruction::Sub(s) => operand_i16(&s.operand0),
+		llvm_ir::Instruction::ZExt(z) => is_i16(&z.to_type),
+		llvm_ir::Instruction::SExt(z) => is_i16(&z.to_type),
+		llvm_ir::Instruction::Trunc(t) => is_i16(&t.to_type),
+		llvm_ir::Instruction::Load(l) => match &l.address {
+			llvm_ir::Operand::LocalOperand { ty, .. } => match ty.as_ref() {
+				llvm_ir::Type::PointerType { pointee_type, .. } => {
+					is_i16(pointee_type)
+				}
+				_ => false,
+			},
+			_ => false,
+		},
+		_ => false,
+	}
+}
+
+// C does arithmetic on shorts in int, so -O0 hands us
+// `trunc (add (ext i16 %a), (ext i16 %b)) to i16` with the add in i32, and an
+// i32 only ever gets a cell. When the add is only there for the trunc do it in
+// i16 instead where the carry has somewhere to go. drop_dead_values takes care
+// of the add and exts left behind.
+fn narrow_i16(module: &mut llvm_ir::Module) {
+	for func in module.functions.iter_mut() {
+		let mut defs = vec![];
+		let mut used = vec![];
+		for block in func.basic_blocks.iter_mut() {
+			for instr in block.instrs.iter_mut() {
+				defs.push(instr.clone());
+				for op in operands_mut(instr) {
+					used.extend(local_name(op));
+				}
+			}
+
+			for op in term_operands_mut(&mut block.term) {
+				used.extend(local_name(op));
+			}
+		}
+
+		let def = |n: &llvm_ir::Name| defs.iter().find(|i| i.try_get_result() == Some(n));
+
+		// the i16 an operand of the wide add started out as
+		let narrow = |op: &llvm_ir::Operand| match op {
+			llvm_ir::Operand::ConstantOperand(c) => match c.as_ref() {
+				llvm_ir::Constant::Int { value, .. } => {
+					Some(llvm_ir::Operand::ConstantOperand(llvm_ir::ConstantRef::new(
+						llvm_ir::Constant::Int {
+							bits: 16,
+							value: value & 0xffff,
+						},
+					)))
+				}
+				_ => None,
+			},
+			llvm_ir::Operand::LocalOperand { name, .. } => match def(name) {
+				Some(llvm_ir::Instruction::ZExt(z)) if operand_i16(&z.operand) => {
+					Some(z.operand.clone())
+				}
+				Some(llvm_ir::Instruction::SExt(z)) if operand_i16(&z.operand) => {
+					Some(z.operand.clone())
+				}
+				_ => None,
+			},
+			_ => None,
+		};
+
+		for block in func.basic_blocks.iter_mut() {
+			for instr in block.instrs.iter_mut() {
+				let t = match instr {
+					llvm_ir::Instruction::Trunc(t) if is_i16(&t.to_type) => t,
+					_ => continue,
+				};
+
+				let wide = match local_name(&t.operand) {
+					Some(n) if used.iter().filter(|u| **u == n).count() == 1 => n,
+					_ => continue,
+				};
+
+				let narrowed = match def(&wide) {
+					Some(llvm_ir::Instruction::Add(a)) => {
+						match (narrow(&a.operand0), narrow(&a.operand1)) {
+							(Some(x), Some(y)) => {
+								llvm_ir::Instruction::Add(llvm_ir::instruction::Add {
+									operand0: x,
+									operand1: y,
+									dest: t.dest.clone(),
+									debugloc: t.debugloc.clone(),
+								})
+							}
+							_ => continue,
+						}
+					}
+					Some(llvm_ir::Instruction::Sub(a)) => {
+						match (narrow(&a.operand0), narrow(&a.operand1)) {
+							(Some(x), Some(y)) => {
+								llvm_ir::Instruction::Sub(llvm_ir::instruction::Sub {
+									operand0: x,
+									operand1: y,
+									dest: t.dest.clone(),
+									debugloc: t.debugloc.clone(),
+								})
+							}
+							_ => continue,
+						}
+					}
+					_ => continue,
+				};
+
+				*instr = narrowed;
+			}
+		}
+	}
+}
+
+// how many cells a flat array of integers takes up, one per element
+fn cell_array_len(ty: &llvm_ir::TypeRef) -> Option<usize> {
+	match ty.as_ref() {
+		llvm_ir::Type::ArrayType {
+			element_type,
+			num_elements,
+		} => match element_type.as_ref() {
+			llvm_ir::Type::IntegerType { .. } => Some(*num_elements),
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+// `gep [N x iM]* %array, 0, %i`, an element of a flat array at whatever index
+fn indexes_cell_array(g: &llvm_ir::instruction::GetElementPtr) -> bool {
+	let array = match &g.address {
+		llvm_ir::Operand::LocalOperand { ty, .. } => match ty.as_ref() {
+			llvm_ir::Type::PointerType { pointee_type, .. } => pointee_type,
+			_ => return false,
+		},
+		_ => return false,
+	};
+
+	let leading_zero = match g.indices.first() {
+		Some(llvm_ir::Operand::ConstantOperand(c)) => matches!(
+			c.deref(),
+			llvm_ir::constant::Constant::Int { value: 0, .. }
+		),
+		_ => false,
+	};
+
+	g.indices.len() == 2 && leading_zero && cell_array_len(array).is_some()
+}
+
+// which element `gep [N x T]* %array, 0, k` picks out, when it's constant
+fn constant_element(g: &llvm_ir::instruction::GetElementPtr) -> Option<usize> {
+	let index = |op: &llvm_ir::Operand| match op {
+		llvm_ir::Operand::ConstantOperand(c) => match c.deref() {
+			llvm_ir::constant::Constant::Int { value, .. } => Some(*value as usize),
+			_ => None,
+		},
+		_ => None,
+	};
+
+	match &g.indices[..] {
+		[first, k] if index(first) == Some(0) => index(k),
+		_ => None,
+	}
+}
+
+// whatever's left that codegen has no hope of handling
+// Whether op can be shown to never have its sign bit set: constants that
+// don't, zexts of something narrower and signed div, rem or shift of things
+// that don't either. Anything that came out of memory or a phi might.
+fn non_negative(func: &llvm_ir::Function, op: &llvm_ir::Operand) -> bool {
+	let name = match op {
+		llvm_ir::Operand::ConstantOperand(c) => {
+			return match c.as_ref() {
+				llvm_ir::Constant::Int { bits, value } => {
+					*bits <= 64 && value >> (bits - 1) & 1 == 0
+				}
+				_ => false,
+			};
+		}
+		_ => match local_name(op) {
+			Some(n) => n,
+			None => return false,
+		},
+	};
+
+	let def = func
+		.basic_blocks
+		.iter()
+		.flat_map(|b| b.instrs.iter())
+		.find(|i| i.try_get_result() == Some(&name));
+
+	match def {
+		Some(llvm_ir::Instruction::ZExt(_)) => true,
+		Some(llvm_ir::Instruction::SDiv(i)) => {
+			non_negative(func, &i.operand0) && non_negative(func, &i.operand1)
+		}
+		Some(llvm_ir::Instruction::SRem(i)) => {
+			non_negative(func, &i.operand0) && non_negative(func, &i.operand1)
+		}
+		Some(llvm_ir::Instruction::AShr(i)) => non_negative(func, &i.operand0),
+		_ => false,
+	}
+}
+
+fn reject_unnormalizable(module: &llvm_ir::Module) -> Vec<Diagnostic> {
+	let mut diags = vec![];
+
+	// globals get laid out cell by cell before main starts
+	for g in module.global_vars.iter() {
+		let flat = match g.initializer.as_ref().map(|c| c.as_ref()) {
+			Some(llvm_ir::Constant::Array { elements, .. }) => elements
+				.iter()
+				.all(|e| matches!(e.as_ref(), llvm_ir::Constant::Int { .. })),
+			_ => false,
+		};
+
+		if !flat {
+			diags.push(Diagnostic {
+				func: "global".to_string(),
+				block: format!("{}", g.name),
+				msg: "only arrays of integers can be globals".to_string(),
+				loc: None,
+			});
+		}
+	}
+
+	for func in module.functions.iter() {
+		let allocas = func
+			.basic_blocks
+			.iter()
+			.flat_map(|b| b.instrs.iter())
+			.filter_map(|i| match i {
+				llvm_ir::Instruction::Alloca(a) => Some(a.dest.clone()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		// args are a cell each on the way in
+		for p in func.parameters.iter().filter(|p| is_i16(&p.ty)) {
+			diags.push(Diagnostic {
+				func: func.name.clone(),
+				block: format!("{}", func.basic_blocks[0].name),
+				msg: format!("i16 can't be passed as an argument: {}", p.name),
+				loc: None,
+			});
+		}
+
+		for block in func.basic_blocks.iter() {
+			let why = match &block.term {
+				llvm_ir::Terminator::Ret(r)
+					if r.return_operand.as_ref().is_some_and(operand_i16) =>
+				{
+					Some("i16 can't be returned")
+				}
+				llvm_ir::Terminator::IndirectBr(_) => {
+					Some("indirect branches can't be lowered to block masks")
+				}
+				llvm_ir::Terminator::Invoke(_)
+				| llvm_ir::Terminator::Resume(_)
+				| llvm_ir::Terminator::CleanupRet(_)
+				| llvm_ir::Terminator::CatchRet(_)
+				| llvm_ir::Terminator::CatchSwitch(_) => {
+					Some("exceptions aren't supported, try -fno-exceptions")
+				}
+				llvm_ir::Terminator::CallBr(_) => {
+					Some("asm goto isn't supported")
+				}
+				_ => None,
+			};
+
+			if let Some(why) = why {
+				diags.push(Diagnostic {
+					func: func.name.clone(),
+					block: format!("{}", block.name),
+					msg: format!("{}: {}", why, block.term),
+					loc: loc_of(block.term.get_debug_loc()),
+				});
+			}
+
+			for instr in block.instrs.iter() {
+				let copy = match instr {
+					llvm_ir::Instruction::Call(c) => callee_of(c).filter(|n| {
+						n.starts_with("llvm.memmove") || n.starts_with("llvm.memcpy")
+					}),
+					_ => None,
+				};
+
+				// lower_mem_copies took every one it could
+				if copy.is_some() {
+					diags.push(Diagnostic {
+						func: func.name.clone(),
+						block: format!("{}", block.name),
+						msg: format!(
+							"only copies of a constant length can be lowered: {}",
+							instr
+						),
+						loc: loc_of(instr.get_debug_loc()),
+					});
+				}
+
+				// lower_puts took every string it could read
+				let puts = match instr {
+					llvm_ir::Instruction::Call(c) => callee_of(c).as_deref() == Some("puts"),
+					_ => false,
+				};
+				if puts && !module.functions.iter().any(|f| f.name == "puts") {
+					diags.push(Diagnostic {
+						func: func.name.clone(),
+						block: format!("{}", block.name),
+						msg: format!("puts only takes string constants: {}", instr),
+						loc: loc_of(instr.get_debug_loc()),
+					});
+				}
+
+				// and lower_printf every format it could split
+				if let llvm_ir::Instruction::Call(c) = instr {
+					let printf = callee_of(c).as_deref() == Some("printf")
+						&& !module.functions.iter().any(|f| f.name == "printf");
+					if let (true, Err(why)) = (printf, printf_pieces(module, c)) {
+						diags.push(Diagnostic {
+							func: func.name.clone(),
+							block: format!("{}", block.name),
+							msg: format!("{}: {}", why, instr),
+							loc: loc_of(instr.get_debug_loc()),
+						});
+					}
+				}
+
+				// split_local_arrays took the ones it could
+				if let llvm_ir::Instruction::GetElementPtr(g) = instr {
+					let local = local_name(&g.address).is_some();
+					if local && g.indices.len() != 1 && !indexes_cell_array(g) {
+						diags.push(Diagnostic {
+							func: func.name.clone(),
+							block: format!("{}", block.name),
+							msg: format!(
+								"only flat arrays of integers can be indexed: {}",
+								instr
+							),
+							loc: loc_of(instr.get_debug_loc()),
+						});
+					}
+				}
+
+				// only these know about the second cell
+				let mut probe = instr.clone();
+				let touches_i16 = is_i16(&module.types.type_of(instr))
+					|| operands_mut(&mut probe).into_iter().any(|op| operand_i16(op));
+				let handles_i16 = match instr {
+					llvm_ir::Instruction::Alloca(_)
+					| llvm_ir::Instruction::Add(_)
+					| llvm_ir::Instruction::Sub(_)
+					| llvm_ir::Instruction::ZExt(_)
+					| llvm_ir::Instruction::Trunc(_) => true,
+					llvm_ir::Instruction::SExt(x) => operand_i16(&x.operand),
+					llvm_ir::Instruction::Load(l) => {
+						local_name(&l.address).is_some_and(|n| allocas.contains(&n))
+					}
+					llvm_ir::Instruction::Store(st) => {
+						local_name(&st.address).is_some_and(|n| allocas.contains(&n))
+					}
+					_ => false,
+				};
+
+				if touches_i16 && !handles_i16 {
+					diags.push(Diagnostic {
+						func: func.name.clone(),
+						block: format!("{}", block.name),
+						msg: format!(
+							"i16 only goes through add, sub, zext, trunc and loads and \
+							 stores of locals: {}",
+							instr
+						),
+						loc: loc_of(instr.get_debug_loc()),
+					});
+				}
+
+				// a frame's cells are all handed out at compile time
+				if let llvm_ir::Instruction::Alloca(a) = instr {
+					if let llvm_ir::Operand::LocalOperand { .. } = a.num_elements {
+						diags.push(Diagnostic {
+							func: func.name.clone(),
+							block: format!("{}", block.name),
+							msg: format!(
+								"variable length arrays aren't supported: {}",
+								instr
+							),
+							loc: loc_of(instr.get_debug_loc()),
+						});
+					}
+				}
+
+				let builds = match instr {
+					llvm_ir::Instruction::Alloca(_)
+					| llvm_ir::Instruction::Phi(_) => true,
+					_ => lookup_instr(instr).is_some(),
+				};
+
+				if !builds {
+					diags.push(Diagnostic {
+						func: func.name.clone(),
+						block: format!("{}", block.name),
+						msg: format!("unsupported instruction '{}'", instr),
+						loc: loc_of(instr.get_debug_loc()),
+					});
+				}
+
+				let divisor = match instr {
+					llvm_ir::Instruction::UDiv(i) => Some(&i.operand1),
+					llvm_ir::Instruction::SDiv(i) => Some(&i.operand1),
+					llvm_ir::Instruction::URem(i) => Some(&i.operand1),
+					llvm_ir::Instruction::SRem(i) => Some(&i.operand1),
+					_ => None,
+				};
+
+				// the subtract loop would never get anywhere, better now than
+				// an interpreter spinning forever
+				if let Some(llvm_ir::Operand::ConstantOperand(c)) = divisor {
+					if let llvm_ir::Constant::Int { value: 0, .. } = c.as_ref() {
+						diags.push(Diagnostic {
+							func: func.name.clone(),
+							block: format!("{}", block.name),
+							msg: format!("division by constant zero: {}", instr),
+							loc: loc_of(instr.get_debug_loc()),
+						});
+					}
+				}
+
+				// the div, rem and shift gadgets only know about unsigned cells,
+				// a negative value would come out as a huge positive one. Shift
+				// amounts can't be negative anyway so only the value counts.
+				let signed = match instr {
+					llvm_ir::Instruction::SDiv(i) => vec![&i.operand0, &i.operand1],
+					llvm_ir::Instruction::SRem(i) => vec![&i.operand0, &i.operand1],
+					llvm_ir::Instruction::AShr(i) => vec![&i.operand0],
+					_ => vec![],
+				};
+
+				if !signed.iter().all(|op| non_negative(func, op)) {
+					diags.push(Diagnostic {
+						func: func.name.clone(),
+						block: format!("{}", block.name),
+						msg: format!(
+							"signed div, rem and shift only work on values that \
+							 can't be negative, try unsigned: {}",
+							instr
+						),
+						loc: loc_of(instr.get_debug_loc()),
+					});
+				}
+			}
+		}
+	}
+
+	diags
+}
+
+pub fn load_module(path: &Path) -> Result<llvm_ir::Module, CompileError> {
+	let module = path
+		.canonicalize()
+		.map_err(|e| e.to_string())
+		.and_then(llvm_ir::Module::from_bc_path);
+
+	module.map_err(|e| CompileError::BadModule {
+		path: path.display().to_string(),
+		why: e,
+	})
+}
+
+// llvm-ir only reads bitcode out of a file, so bitcode from anywhere else
+// like a pipe takes a detour through a temp one
+pub fn load_bytes(bytes: &[u8]) -> Result<llvm_ir::Module, CompileError> {
+	static LOADS: AtomicUsize = AtomicUsize::new(0);
+
+	let tmp = env::temp_dir().join(format!(
+		"bfcc-{}-{}.bc",
+		process::id(),
+		LOADS.fetch_add(1, Ordering::SeqCst)
+	));
+
+	let module = fs::write(&tmp, bytes)
+		.map_err(|e| e.to_string())
+		.and_then(|_| llvm_ir::Module::from_bc_path(&tmp));
+	fs::remove_file(&tmp).ok();
+
+	module.map_err(|e| CompileError::BadModule {
+		path: "bitcode in memory".to_string(),
+		why: e,
+	})
+}
+
+// The ir exactly as the code generator is gonna see it, after all our own
+// passes have had their way with it.
+pub fn dump_ir(path: &Path, opts: &Options) -> Result<String, CompileError> {
+	dump_module_ir(load_module(path)?, opts)
+}
+
+pub fn dump_module_ir(
+	mut module: llvm_ir::Module,
+	opts: &Options,
+) -> Result<String, CompileError> {
+	let meta = prepare(&mut module, opts, None)?;
+
+	let mut out = String::new();
+	for func in module.functions.iter() {
+		writeln!(out, "define @{} {{", func.name).unwrap();
+		for block in func.basic_blocks.iter() {
+			let origin = meta
+				.provenance
+				.iter()
+				.find(|p| p.func == func.name && p.block == block.name);
+			match origin {
+				Some(p) => writeln!(
+					out,
+					"{}: ; from {} ({})",
+					block.name,
+					p.source(),
+					p.reason
+				),
+				None => writeln!(out, "{}:", block.name),
+			}
+			.unwrap();
+
+			for instr in block.instrs.iter() {
+				writeln!(out, "  {}", instr).unwrap();
+			}
+			writeln!(out, "  {}", block.term).unwrap();
+		}
+		writeln!(out, "}}").unwrap();
+	}
+
+	Ok(out)
+}
+
+// A block name func doesn't have yet, prefix followed by a number. fresh only
+// ever counts up so names handed out before that aren't in func yet don't
+// come back either.
+fn fresh_block_name(
+	func: &llvm_ir::Function,
+	prefix: &str,
+	fresh: &mut usize,
+) -> llvm_ir::Name {
+	loop {
+		let n = llvm_ir::Name::Name(Box::new(format!("{}{}", prefix, fresh)));
+		*fresh += 1;
+		if func.basic_blocks.iter().all(|b| b.name != n) {
+			return n;
+		}
+	}
+}
+
+// Split all blocks at calls. This should result in all calls treated sorta like
+// terminator instructions being the last instruction of their block before a
+// unconditional branch.
+//
+// This makes it way easier to generate brainfuck control flow as calls
+// use the same control flow mechanism as blocks. A call/branch combo sets
+// up the current and next frames then switches to the next frame. Since it
+// always makes up the end of a block we'll re-enter the main loop and
+// continue into the next frame. Upon returning the branch will have setup
+// frame to resume right into the right block.
+// what a deal!
+//
+// putchar and getchar never leave the frame so they stay where they are.
+fn calls_terminate_blocks(
+	module: &mut llvm_ir::Module,
+	provenance: &mut Vec<Provenance>,
+) {
+	for func in module.functions.iter_mut() {
+		// across the whole function, see fresh_block_name
+		let mut fresh = 0;
+
+		let mut block = 0;
+		while block < func.basic_blocks.len() {
+			let mut instr = 0;
+			while instr < func.basic_blocks[block].instrs.len() {
+				// intrinsics are built right where they are, see build_call
+				let real_call = match &func.basic_blocks[block].instrs[instr] {
+					llvm_ir::Instruction::Call(c) => !is_intrinsic(c),
+					_ => false,
+				};
+				if !real_call {
+					instr += 1;
+					continue;
+				}
+
+				// so when we get to a call
+
+				let nextn = fresh_block_name(func, "call_term_for_", &mut fresh);
+
+				let last_instr =
+					instr == func.basic_blocks[block].instrs.len() - 1;
+
+				// splitting a continuation again still came from the same
+				// source block
+				let from = origin_of(
+					provenance,
+					&func.name,
+					&func.basic_blocks[block].name,
+				);
+				// and the calls before this one all split it already
+				let call = provenance
+					.iter()
+					.filter(|p| p.func == func.name && p.from == from)
+					.filter(|p| p.call.is_some())
+					.count();
+				provenance.push(Provenance {
+					func: func.name.clone(),
+					block: nextn.clone(),
+					from,
+					call: Some(call),
+					reason: BlockOrigin::CallContinuation,
+				});
+
+				// even if it's the last instr that's a call we still split the
+				// block and sprinkle in an uncond br to make things ez
+				if last_instr {
+					let splitn = llvm_ir::BasicBlock {
+						name: nextn.clone(),
+						instrs: vec![],
+						term: func.basic_blocks[block].term.clone(),
+					};
+					func.basic_blocks.insert(block + 1, splitn);
+
+					func.basic_blocks[block].term =
+						llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
+							debugloc: None,
+							dest: nextn.clone(),
+						});
+				} else {
+					let splitn = llvm_ir::BasicBlock {
+						name: nextn.clone(),
+						instrs: func.basic_blocks[block]
+							.instrs
+							.split_off(instr + 1),
+						term: func.basic_blocks[block].term.clone(),
+					};
+					func.basic_blocks.insert(block + 1, splitn);
+
+					func.basic_blocks[block].term =
+						llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
+							debugloc: None,
+							dest: nextn.clone(),
+						});
+				}
+
+				// update all the phi nodes too. Since our block now has a
+				// unconditional succ we'll need to rename any phis naming this
+				// block to instead name the succ.
+				//
+				// man... maybe i shoulda done this in cpp cause like... man
+				// llvm has all this stufffff
+				let orig_block = func.basic_blocks[block].name.clone();
+				for bblock in &mut func.basic_blocks {
+					for instr in &mut bblock.instrs {
+						match instr {
+							llvm_ir::Instruction::Phi(phi) => {
+								for inval in &mut phi.incoming_values {
+									if inval.1 == orig_block {
+										inval.1 = nextn.clone();
+									}
+								}
+							}
+							_ => {}
+						}
+					}
+				}
+
+				instr += 1;
+			}
+
+			block += 1;
+		}
+	}
+}
+
+// Function calls always call into block 0. Thing is, if we're making a call
+// from block 0 into another block 0 we could end up end up setting everything
+// up for the callee but then then re-entering block 0 in the caller instead of
+// the target. This is really just a quirk of control flow using one massive
+// loop with masks for functions and blocks. Moving control flow between
+// blocks/functions involes reentering the block or function level loop with the
+// proper mask set. Since functions share the same space for block control flow
+// mask space the transition between functions temporarily involves executing
+// in the calling function with the callee's mask set.
+//
+// So a function that calls from its first block gets an empty one in front
+// that just branches there. Masks are looked up by name and nothing can branch
+// to an entry block or name it in a phi (LLVM won't have it), so no other
+// block notices. Intrinsics never leave the frame and don't count, and since
+// the new block 0 calls nothing running this again changes nothing.
+fn calls_never_in_first_block(
+	module: &mut llvm_ir::Module,
+	provenance: &mut Vec<Provenance>,
+) {
+	for func in module.functions.iter_mut() {
+		let hascall = func.basic_blocks[0].instrs.iter().any(|i| match i {
+			llvm_ir::Instruction::Call(c) => !is_intrinsic(c),
+			_ => false,
+		});
+
+		if !hascall {
+			continue;
+		}
+
+		let nextn = llvm_ir::Name::Name(Box::new(format!(
+			"no_block0_call_for_{}",
+			func.name
+		)));
+
+		provenance.push(Provenance {
+			func: func.name.clone(),
+			block: nextn.clone(),
+			from: func.basic_blocks[0].name.clone(),
+			call: None,
+			reason: BlockOrigin::EntryShim,
+		});
+
+		func.basic_blocks.insert(
+			0,
+			llvm_ir::BasicBlock {
+				name: nextn,
+				instrs: vec![],
+				term: llvm_ir::Terminator::Br(llvm_ir::terminator::Br {
+					debugloc: None,
+					dest: func.basic_blocks[0].name.clone(),
+				}),
+			},
+		);
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Addr {
+	v: Rc<RefCell<Addrt>>,
+}
+
+impl fmt::Display for Addr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, ".{:?}", self.v.borrow())
+	}
+}
+
+#[derive(Debug, Clone)]
+enum Addrt {
+	Fixed(usize),
+	Offset(Addr, i64),
+	Named(llvm_ir::Name),
+}
+
+// type Addr = Rc<RefCell<Addrt>>;
+
+fn offset(s: Addr, i: i64) -> Addr {
+	Addr {
+		v: Rc::new(RefCell::new(Addrt::Offset(s, i))),
+	}
+}
+
+fn resaddr(s: Addr) -> usize {
+	match s.v.deref().borrow().clone() {
+		Addrt::Fixed(u) => u,
+		Addrt::Offset(a, o) => (resaddr(a) as i64 + o) as usize,
+		Addrt::Named(_) => panic!("named address cant be resolved"),
+	}
+}
+
+fn fixed_addr(a: usize) -> Addr {
+	Addr {
+		v: Rc::new(RefCell::new(Addrt::Fixed(a))),
+	}
+}
+
+// What every builder hands back. Nothing is text until printast renders the
+// whole program at the very end, so anything that wants to look over or
+// rewrite the code does it on these. --emit=ops dumps them as OpJson.
+#[derive(Debug, Clone)]
+enum BfOp {
+	// kinda actual brainfuck, these do fucky stuff to the instr pointer
+	// future passes can't understand
+	Right(usize),
+	Left(usize),
+
+	// extra spooky! loop enters and exits on different addresses. so like the
+	// instr pointer is now split between both addrs after this op, statically
+	// we'll assume it enters on the former address and  exists on the later
+	// addr so uh... glhf
+	//
+	// If the addr is truthy the PC will be at the latter addr on exit
+	// If the addr is falsey the PC will be at the former addr on exit
+	Loop2(Addr, Addr, Vec<BfOp>),
+
+	AddI(Addr, u8), // *a + n -> *a : a must be less than 255
+	SubI(Addr, u8), // *a - n -> *a : a msut be greater than 0
+	Dup(Addr, Addr, Addr), /* *a -> *b, *c : a will be zeroed, b and c must
+	                 * be zero */
+	//DupThru(Addr, Addr, Addr), /* *a -> *b (c is a tmp) : a will be zeroed,
+	//                           * b and c must be zero */
+
+	// the only architecture with a real mov instruction
+	Mov(Addr, Addr), // *a -> *b : a will be zeroed, b must be zero
+	Putch(Addr),
+	Getch(Addr), // input -> *a : a must be zero, eof leaves Options::eof_value
+	Zero(Addr),
+	Loop(Addr, Vec<BfOp>),
+
+	// debug
+	Tag(Addr, String), // tag address with name in debugger
+	Comment(String),   // if you see something say something
+	// the ops from here until the next one are for this instruction, see
+	// SrcSpan. Renders to nothing
+	Src(Loc),
+
+	Nop,
 }
 
 // have to promise to give registers before you take them otherwise
@@ -252,10 +3190,9 @@ fn give_reg<'a>(
 fn op_unwrap_ptr_all(op: &llvm_ir::Operand) -> llvm_ir::Operand {
 	let mut op = op.clone();
 	while match &op {
-		llvm_ir::Operand::LocalOperand { ty, .. } => match ty.deref() {
-			llvm_ir::Type::PointerType { .. } => true,
-			_ => false,
-		},
+		llvm_ir::Operand::LocalOperand { ty, .. } => {
+			matches!(ty.deref(), llvm_ir::Type::PointerType { .. })
+		}
 		_ => false,
 	} {
 		op = op_unwrap_ptr(&op);
@@ -417,9 +3354,14 @@ fn op_to_reg<'a>(ctx: &'a mut Ctx, op: &llvm_ir::Operand) -> (Addr, Vec<BfOp>) {
 
 			_ => unimplemented!("meta?"),
 		},
+		// Building a constant from zero is v +s every time, even in a loop.
+		// Hoisting it into a cell filled in before the loop doesn't pay off:
+		// getting it back out is a dup, which takes several steps per unit
+		// where the +s take one, and the dup's code is no shorter either.
+		// Tried on the suite it made every loop slower and bigger.
 		llvm_ir::Operand::ConstantOperand(_) => {
 			let tmp = borrow_reg(ctx, 1);
-			let v = uncop(ctx, op);
+			let v = cell_const(ctx, op);
 			(
 				tmp.clone(),
 				vec![
@@ -427,8 +3369,10 @@ fn op_to_reg<'a>(ctx: &'a mut Ctx, op: &llvm_ir::Operand) -> (Addr, Vec<BfOp>) {
 						"op_to_reg storing const value in temp address"
 					)),
 					BfOp::Tag(tmp.clone(), format!("constop_{}", v)),
-					BfOp::AddI(tmp.clone(), v as u8),
-				],
+				]
+				.into_iter()
+				.chain(build_const(ctx, &tmp, v))
+				.collect(),
 			)
 		}
 
@@ -436,6 +3380,53 @@ fn op_to_reg<'a>(ctx: &'a mut Ctx, op: &llvm_ir::Operand) -> (Addr, Vec<BfOp>) {
 	}
 }
 
+// Constants up to this many +s are always just the +s, past it the loop in
+// build_const can come out shorter
+const SHORT_CONST: u8 = 16;
+
+// v into dest, which might already hold something. Big ones go as a*b + c:
+// a into a scratch cell, then a loop adding b to dest until the scratch is
+// back to 0, then c more. That's fewer ops in the code but more steps to run,
+// each time round the loop walks to dest and back.
+fn build_const(ctx: &mut Ctx, dest: &Addr, v: u8) -> Vec<BfOp> {
+	if v <= SHORT_CONST {
+		return vec![BfOp::AddI(dest.clone(), v)];
+	}
+
+	let tmp = borrow_reg(ctx, 1);
+	let (t, d) = (resaddr(tmp.clone()), resaddr(dest.clone()));
+	let dist = t.abs_diff(d);
+
+	match const_factors(v, dist) {
+		Some((a, b, c)) => {
+			let mut ops = vec![
+				BfOp::AddI(tmp.clone(), a),
+				BfOp::Loop(
+					tmp.clone(),
+					vec![BfOp::SubI(tmp, 1), BfOp::AddI(dest.clone(), b)],
+				),
+			];
+			if c > 0 {
+				ops.push(BfOp::AddI(dest.clone(), c));
+			}
+			ops
+		}
+		None => vec![BfOp::AddI(dest.clone(), v)],
+	}
+}
+
+// the a, b, c with a*b + c = v that takes the fewest ops, counting the
+// brackets, the - and the walks between cells dist apart. None when v +s
+// is still shorter.
+fn const_factors(v: u8, dist: usize) -> Option<(u8, u8, u8)> {
+	let cost = |(a, b, c): (u8, u8, u8)| a as usize + b as usize + c as usize;
+
+	(2..=v / 2)
+		.map(|a| (a, v / a, v % a))
+		.min_by_key(|f| cost(*f))
+		.filter(|f| cost(*f) + 3 + 3 * dist < v as usize)
+}
+
 // borrow a register for scratch space.you just have to really
 // really really promise to not leave any junk beyond the lifetime
 // of the instruction. Otherwise absolute chaos ensues since
@@ -447,10 +3438,7 @@ fn borrow_reg(ctx: &mut Ctx, contig: usize) -> Addr {
 		for j in 0..contig {
 			let c = &ctx.layout[i + j];
 
-			if !match c {
-				Cell::Free => true,
-				_ => false,
-			} {
+			if !matches!(c, Cell::Free) {
 				break;
 			}
 
@@ -656,6 +3644,59 @@ type Layout = Vec<Cell>;
 
 const STACK_PTR_W: usize = 1;
 
+// calls build_call does right there instead of setting up a frame
+const INTRINSICS: &[&str] = &["putchar", "getchar"];
+
+fn is_intrinsic(c: &llvm_ir::instruction::Call) -> bool {
+	callee_of(c).is_some_and(|n| INTRINSICS.contains(&n.as_str()))
+}
+
+// the last thing a program guarded by Options::stack_limit prints when it
+// calls too deep
+pub const STACK_OVERFLOW_MARKER: &str = "STACKOVERFLOW";
+
+// Past the deepest frame we allow sits a wall of cells holding 1, 2, 3...
+// Every call first peeks at where the callee's frame would end, zero means
+// there's room and the wall's value says how far into it we'd have gone.
+#[derive(Debug, Clone)]
+struct StackGuard {
+	// measured frame width of every function
+	widths: Vec<(String, usize)>,
+	// where the wall starts counting from where main's ret parks the cursor
+	reach: usize,
+}
+
+// addresses are stored in a single cell so that's as far as a frame can reach
+const FRAME_CELLS: usize = 256;
+
+// make sure every named cell in the layout is actually addressable. Scratch
+// gets borrowed later on so it's not accounted for here.
+fn check_frame(
+	func: &llvm_ir::Function,
+	layout: &Layout,
+) -> Result<(), CompileError> {
+	if layout.len() <= FRAME_CELLS {
+		return Ok(());
+	}
+
+	let value = layout
+		.iter()
+		.skip(FRAME_CELLS)
+		.find_map(|c| match c {
+			Cell::Alloc(n) => Some(format!("{}", n)),
+			Cell::Reg { n, .. } => Some(format!("{}", n)),
+			Cell::BlockMask(n) => Some(format!("block {}", n)),
+			_ => None,
+		})
+		.unwrap_or(format!("cell {}", FRAME_CELLS));
+
+	Err(CompileError::FrameOverflow {
+		func: func.name.clone(),
+		value,
+		required: layout.len(),
+	})
+}
+
 // How do you deref an address when you're lost in a sea of tape?
 fn build_ptr_train(
 	ctx: &mut Ctx,
@@ -882,6 +3923,7 @@ fn build_ptr_train(
 struct GlobalMap {
 	name: llvm_ir::Name,
 	addr: u8,
+	len: u8,
 }
 
 #[derive(Debug)]
@@ -894,6 +3936,37 @@ struct Ctx {
 	retpad_addr: Option<Addr>,
 	ownfid: Option<usize>,
 	globals: Vec<GlobalMap>,
+	loc: Option<Loc>,
+	warnings: Vec<Diagnostic>,
+	// regs and allocas we know the value of at this point in the block
+	known: Vec<(llvm_ir::Name, u8)>,
+	stack_guard: Option<StackGuard>,
+	wrap_safe: bool,
+	strict_consts: bool,
+	// constants that didn't fit with strict_consts on, build_func turns these
+	// into an error once it's done
+	too_wide: Vec<Diagnostic>,
+	check_scratch: bool,
+	// every function's ret_cell
+	ret_cells: Vec<(String, usize)>,
+	// only for compile_function, the return value goes this far left of cell
+	// 0 instead of nowhere
+	ret_to: Option<usize>,
+	// the tape address the outermost frame's ret leaves the cursor on
+	park: usize,
+	// block locals and the cell each one gets, see block_locals. Cells here
+	// are handed from one value to the next as their instructions come up.
+	shared: Vec<(llvm_ir::Name, usize)>,
+}
+
+// where codegen is at right now, for diagnostics
+#[derive(Debug, Clone)]
+struct Loc {
+	func: String,
+	block: String,
+	instr: String,
+	// file:line of the C behind it, see loc_of
+	src: Option<String>,
 }
 
 enum RetMeta {
@@ -962,17 +4035,23 @@ fn build_sub(
 
 	let dest = ret.unwrap();
 
+	let step = match ctx.wrap_safe {
+		true => {
+			let scratch = borrow_reg(ctx, 2);
+			wrap_dec(&dest, &offset(scratch.clone(), 0), &offset(scratch, 1))
+		}
+		false => vec![BfOp::SubI(dest.clone(), 1)],
+	};
+
 	vec![]
 		.into_iter()
 		.chain(o0)
 		.chain(o1)
-		.chain(vec![
-			BfOp::Mov(op0.clone(), dest.clone()),
-			BfOp::Loop(
-				op1.clone(),
-				vec![BfOp::SubI(op1.clone(), 1), BfOp::SubI(dest.clone(), 1)],
-			),
-		])
+		.chain(vec![BfOp::Mov(op0.clone(), dest.clone())])
+		.chain(vec![BfOp::Loop(
+			op1.clone(),
+			vec![BfOp::SubI(op1.clone(), 1)].into_iter().chain(step).collect(),
+		)])
 		.collect()
 }
 
@@ -1262,6 +4341,34 @@ fn build_shiftl(
 
 	let scratch = borrow_reg(ctx, 1);
 
+	// every doubling starts over from dest being 0
+	let double = match ctx.wrap_safe {
+		true => {
+			let wrap = borrow_reg(ctx, 3);
+			let comp = offset(wrap.clone(), 0);
+			let t = offset(wrap.clone(), 1);
+			let f = offset(wrap.clone(), 2);
+			let inc = wrap_inc(&dest, &comp, &t, &f);
+
+			vec![BfOp::AddI(comp.clone(), 255)]
+				.into_iter()
+				.chain(vec![BfOp::Loop(
+					op0.clone(),
+					vec![BfOp::SubI(op0.clone(), 1)]
+						.into_iter()
+						.chain(inc.clone())
+						.chain(inc)
+						.collect(),
+				)])
+				.chain(vec![BfOp::Zero(comp)])
+				.collect()
+		}
+		false => vec![BfOp::Loop(op0.clone(), vec![
+			BfOp::SubI(op0.clone(), 1),
+			BfOp::AddI(dest.clone(), 2),
+		])],
+	};
+
 	vec![]
 		.into_iter()
 		.chain(o0)
@@ -1269,15 +4376,17 @@ fn build_shiftl(
 		.chain(vec![
 			BfOp::AddI(scratch.clone(), 1),
 
-			BfOp::Loop(op1.clone(), vec![
-				BfOp::SubI(op1.clone(), 1),
-				BfOp::Mov(dest.clone(), op0.clone()),
-				BfOp::Loop(op0.clone(), vec![
-					BfOp::SubI(op0.clone(), 1),
-					BfOp::AddI(dest.clone(), 2),
-				]),
-				BfOp::Zero(scratch.clone()),
-			]),
+			BfOp::Loop(
+				op1.clone(),
+				vec![
+					BfOp::SubI(op1.clone(), 1),
+					BfOp::Mov(dest.clone(), op0.clone()),
+				]
+				.into_iter()
+				.chain(double)
+				.chain(vec![BfOp::Zero(scratch.clone())])
+				.collect(),
+			),
 
 			BfOp::Loop(scratch.clone(), vec![
 				BfOp::SubI(scratch.clone(), 1),
@@ -1403,6 +4512,38 @@ fn build_mul(
 	let (op1, o1) = builder_args_to_consumable_reg(ctx, &args[1]);
 
 	let dest = ret.unwrap();
+
+	if ctx.wrap_safe {
+		let scratch = borrow_reg(ctx, 4);
+		let comp = offset(scratch.clone(), 0);
+		let t = offset(scratch.clone(), 1);
+		let f = offset(scratch.clone(), 2);
+		let k = offset(scratch.clone(), 3);
+
+		// dest starts out 0
+		return vec![]
+			.into_iter()
+			.chain(o0)
+			.chain(o1)
+			.chain(vec![
+				BfOp::AddI(comp.clone(), 255),
+				BfOp::Loop(op1.clone(), vec![
+					BfOp::SubI(op1.clone(), 1),
+					BfOp::Mov(op0.clone(), k.clone()),
+					BfOp::Loop(
+						k.clone(),
+						vec![BfOp::SubI(k.clone(), 1), BfOp::AddI(op0.clone(), 1)]
+							.into_iter()
+							.chain(wrap_inc(&dest, &comp, &t, &f))
+							.collect(),
+					),
+				]),
+				BfOp::Zero(op0.clone()),
+				BfOp::Zero(comp),
+			])
+			.collect();
+	}
+
 	let scratch = borrow_reg(ctx, 1);
 
 	vec![]
@@ -1545,6 +4686,60 @@ fn build_bitwise_xor(
 	])
 }
 
+// The wrap safe way of counting a cell down and up by one for
+// Options::wrap_safe. t and f are scratch, zero going in and coming out.
+//
+// dest - 1, or 255 when it's 0
+fn wrap_dec(dest: &Addr, t: &Addr, f: &Addr) -> Vec<BfOp> {
+	vec![
+		BfOp::AddI(f.clone(), 1),
+		// only goes round once, it's an if
+		BfOp::Loop(dest.clone(), vec![
+			BfOp::SubI(dest.clone(), 1),
+			BfOp::Mov(dest.clone(), t.clone()),
+			BfOp::SubI(f.clone(), 1),
+		]),
+		BfOp::Mov(t.clone(), dest.clone()),
+		BfOp::Loop(f.clone(), vec![
+			BfOp::SubI(f.clone(), 1),
+			BfOp::AddI(dest.clone(), 255),
+		]),
+	]
+}
+
+// dest + 1, or 0 when it's 255. Spotting 255 means keeping 255 - dest around
+// in comp, which stays that way.
+fn wrap_inc(dest: &Addr, comp: &Addr, t: &Addr, f: &Addr) -> Vec<BfOp> {
+	vec![
+		BfOp::AddI(f.clone(), 1),
+		BfOp::Loop(comp.clone(), vec![
+			BfOp::SubI(comp.clone(), 1),
+			BfOp::Mov(comp.clone(), t.clone()),
+			BfOp::AddI(dest.clone(), 1),
+			BfOp::SubI(f.clone(), 1),
+		]),
+		BfOp::Mov(t.clone(), comp.clone()),
+		BfOp::Loop(f.clone(), vec![
+			BfOp::SubI(f.clone(), 1),
+			BfOp::Zero(dest.clone()),
+			BfOp::AddI(comp.clone(), 255),
+		]),
+	]
+}
+
+// 255 - dest into comp for wrap_inc, dest is left alone
+fn wrap_comp(dest: &Addr, comp: &Addr, t: &Addr, u: &Addr) -> Vec<BfOp> {
+	vec![
+		BfOp::AddI(comp.clone(), 255),
+		BfOp::Dup(dest.clone(), t.clone(), u.clone()),
+		BfOp::Loop(t.clone(), vec![
+			BfOp::SubI(t.clone(), 1),
+			BfOp::SubI(comp.clone(), 1),
+		]),
+		BfOp::Mov(u.clone(), dest.clone()),
+	]
+}
+
 fn build_add(
 	ctx: &mut Ctx,
 	i: &llvm_ir::Instruction,
@@ -1557,6 +4752,32 @@ fn build_add(
 	let (op0, o0) = builder_args_to_consumable_reg(ctx, &args[0]);
 	let (op1, o1) = builder_args_to_consumable_reg(ctx, &args[1]);
 
+	if ctx.wrap_safe {
+		let scratch = borrow_reg(ctx, 4);
+		let comp = offset(scratch.clone(), 0);
+		let t = offset(scratch.clone(), 1);
+		let f = offset(scratch.clone(), 2);
+		let u = offset(scratch.clone(), 3);
+
+		return vec![]
+			.into_iter()
+			.chain(o0)
+			.chain(o1)
+			.chain(vec![BfOp::Mov(op0.clone(), dest.clone())])
+			.chain(wrap_comp(&dest, &comp, &t, &u))
+			.chain(vec![
+				BfOp::Loop(
+					op1.clone(),
+					vec![BfOp::SubI(op1.clone(), 1)]
+						.into_iter()
+						.chain(wrap_inc(&dest, &comp, &t, &f))
+						.collect(),
+				),
+				BfOp::Zero(comp),
+			])
+			.collect();
+	}
+
 	vec![]
 		.into_iter()
 		.chain(o0)
@@ -1607,6 +4828,47 @@ fn build_nop_move(
 		.collect()
 }
 
+// Everything's a cell wide so extending is just a move, except an i1 going
+// signed where true is all ones.
+fn build_sext(
+	ctx: &mut Ctx,
+	i: &llvm_ir::Instruction,
+	block: &llvm_ir::BasicBlock,
+	args: &[BuilderArgs],
+	ret: Option<Addr>,
+) -> Vec<BfOp> {
+	if !sext_of_bool(i) {
+		return build_nop_move(ctx, i, block, args, ret);
+	}
+
+	let (op0, o0) = builder_args_to_consumable_reg(ctx, &args[0]);
+	let dest = ret.unwrap();
+
+	o0.into_iter()
+		.chain(vec![BfOp::Loop(
+			op0.clone(),
+			vec![BfOp::SubI(op0.clone(), 1), BfOp::AddI(dest, 255)],
+		)])
+		.collect()
+}
+
+fn sext_of_bool(i: &llvm_ir::Instruction) -> bool {
+	let operand = match i {
+		llvm_ir::Instruction::SExt(s) => &s.operand,
+		_ => return false,
+	};
+
+	match operand {
+		llvm_ir::Operand::LocalOperand { ty, .. } => {
+			**ty == llvm_ir::Type::IntegerType { bits: 1 }
+		}
+		llvm_ir::Operand::ConstantOperand(c) => {
+			matches!(c.as_ref(), llvm_ir::Constant::Int { bits: 1, .. })
+		}
+		_ => false,
+	}
+}
+
 fn build_icmp_instr(
 	ctx: &mut Ctx,
 	i: &llvm_ir::Instruction,
@@ -1614,15 +4876,51 @@ fn build_icmp_instr(
 	args: &[BuilderArgs],
 	ret: Option<Addr>,
 ) -> Vec<BfOp> {
+	let i: llvm_ir::instruction::ICmp = i.clone().try_into().unwrap();
+
+	// against 0 there's nothing to subtract, whether the other side is
+	// nonzero already is the answer
+	let against_zero = match (&args[0], &args[1]) {
+		(BuilderArgs::Const(0), other) | (other, BuilderArgs::Const(0)) => {
+			Some(other)
+		}
+		_ => None,
+	};
+
+	match (i.predicate, against_zero) {
+		(llvm_ir::IntPredicate::EQ, Some(other))
+		| (llvm_ir::IntPredicate::NE, Some(other)) => {
+			let (v, o) = builder_args_to_consumable_reg(ctx, other);
+			return o
+				.into_iter()
+				.chain(build_zero_test(i.predicate, v, ret.unwrap()))
+				.collect();
+		}
+		_ => {}
+	}
+
 	let (op0, o0) = builder_args_to_consumable_reg(ctx, &args[0]);
 	let (op1, o1) = builder_args_to_consumable_reg(ctx, &args[1]);
 
-	let i: llvm_ir::instruction::ICmp = i.clone().try_into().unwrap();
+	// build_icmp only knows unsigned order, flipping the sign bit of both
+	// sides first gets the signed order out of it
+	let (op0, op1, bias) = match i.predicate {
+		llvm_ir::IntPredicate::SLT
+		| llvm_ir::IntPredicate::SLE
+		| llvm_ir::IntPredicate::SGT
+		| llvm_ir::IntPredicate::SGE => {
+			let (b0, op0) = build_signed_bias(ctx, op0);
+			let (b1, op1) = build_signed_bias(ctx, op1);
+			(op0, op1, b0.into_iter().chain(b1).collect())
+		}
+		_ => (op0, op1, vec![]),
+	};
 
 	vec![]
 		.into_iter()
 		.chain(o0)
 		.chain(o1)
+		.chain(bias)
 		.chain(build_icmp(
 			ctx,
 			i.predicate,
@@ -1633,6 +4931,59 @@ fn build_icmp_instr(
 		.collect()
 }
 
+// v ^ 0x80 without wrapping the cell, so -128..=127 lands on 0..=255 in the
+// same order. Consumes v, the biased value is left in the returned address.
+//
+// v - 128 with the underflow counted tells the two halves apart, below 128
+// the difference is 0 and the underflow is 128 - v, so the answer is
+// 128 + (128 - underflow).
+fn build_signed_bias(ctx: &mut Ctx, v: Addr) -> (Vec<BfOp>, Addr) {
+	let underflow = borrow_reg(ctx, 1);
+	let half = borrow_reg(ctx, 1);
+
+	let (sub, diff) = subnu(ctx, v, half.clone(), Some(underflow.clone()));
+
+	(
+		vec![BfOp::AddI(half, 128)]
+			.into_iter()
+			.chain(sub)
+			.chain(vec![BfOp::Loop(
+				underflow.clone(),
+				vec![
+					BfOp::AddI(diff.clone(), 128),
+					BfOp::Loop(
+						underflow.clone(),
+						vec![
+							BfOp::SubI(underflow.clone(), 1),
+							BfOp::SubI(diff.clone(), 1),
+						],
+					),
+					BfOp::AddI(diff.clone(), 128),
+				],
+			)])
+			.collect(),
+		diff,
+	)
+}
+
+// dest = v != 0 or dest = v == 0, consumes v
+fn build_zero_test(pred: llvm_ir::IntPredicate, v: Addr, dest: Addr) -> Vec<BfOp> {
+	match pred {
+		llvm_ir::IntPredicate::NE => vec![BfOp::Loop(
+			v.clone(),
+			vec![BfOp::Zero(v.clone()), BfOp::AddI(dest.clone(), 1)],
+		)],
+		llvm_ir::IntPredicate::EQ => vec![
+			BfOp::AddI(dest.clone(), 1),
+			BfOp::Loop(
+				v.clone(),
+				vec![BfOp::Zero(v.clone()), BfOp::Zero(dest.clone())],
+			),
+		],
+		_ => unreachable!("only eq/ne against 0"),
+	}
+}
+
 fn builder_args_to_consumable_reg<'a>(
 	ctx: &mut Ctx,
 	ba: &BuilderArgs,
@@ -1688,8 +5039,10 @@ fn builder_args_to_consumable_reg<'a>(
 						"op_to_reg storing const value in temp address"
 					)),
 					BfOp::Tag(tmp.clone(), format!("constop_{}", v)),
-					BfOp::AddI(tmp.clone(), *v as u8),
-				],
+				]
+				.into_iter()
+				.chain(build_const(ctx, &tmp, *v as u8))
+				.collect(),
 			)
 		}
 	}
@@ -1702,6 +5055,15 @@ fn build_store(
 	args: &[BuilderArgs], // order is [value, address]
 	ret: Option<Addr>,
 ) -> Vec<BfOp> {
+	// constant straight into an alloca, no need to go through a temp
+	if let (BuilderArgs::Const(v), BuilderArgs::Alloc(addr)) =
+		(&args[0], &args[1])
+	{
+		let mut ops = vec![BfOp::Zero(addr.clone())];
+		ops.append(&mut build_const(ctx, addr, *v as u8));
+		return ops;
+	}
+
 	let (val, mut o0) = builder_args_to_consumable_reg(ctx, &args[0]);
 
 	if let BuilderArgs::Alloc(addr) = &args[1] {
@@ -1722,6 +5084,212 @@ fn build_store(
 	}
 }
 
+// A copy of an i16 operand in two fresh cells, low byte first, that can be
+// used up.
+fn i16_operand(
+	ctx: &mut Ctx,
+	op: &llvm_ir::Operand,
+	arg: &BuilderArgs,
+) -> (Addr, Vec<BfOp>) {
+	let pair = borrow_reg(ctx, 2);
+	let hi = offset(pair.clone(), 1);
+
+	// BuilderArgs::Const only has the low byte
+	if let llvm_ir::Operand::ConstantOperand(c) = op {
+		let v = match c.as_ref() {
+			llvm_ir::Constant::Int { value, .. } => *value,
+			c => unimplemented!("i16 constant {}", c),
+		};
+
+		let mut ops = build_const(ctx, &pair, v as u8);
+		ops.append(&mut build_const(ctx, &hi, (v >> 8) as u8));
+		return (pair, ops);
+	}
+
+	let from = match arg {
+		BuilderArgs::Alloc(a)
+		| BuilderArgs::PreservedReg(a)
+		| BuilderArgs::ConsumedReg(a) => a.clone(),
+		BuilderArgs::Const(_) => unreachable!("known values are never i16"),
+	};
+	let tmp = borrow_reg(ctx, 1);
+
+	(
+		pair.clone(),
+		vec![
+			BfOp::Dup(from.clone(), tmp.clone(), pair.clone()),
+			BfOp::Mov(tmp.clone(), from.clone()),
+			BfOp::Dup(offset(from.clone(), 1), tmp.clone(), hi),
+			BfOp::Mov(tmp, offset(from, 1)),
+		],
+	)
+}
+
+// Everything that knows an i16 is two cells, reject_unnormalizable keeps
+// anything else away from them. Past 65535 the high cell runs off the end
+// like any other cell would.
+fn build_i16(
+	ctx: &mut Ctx,
+	i: &llvm_ir::Instruction,
+	args: &[BuilderArgs],
+	ret: Option<Addr>,
+) -> Vec<BfOp> {
+	let mut ops = vec![];
+
+	if i16_result(i) {
+		ops.push(BfOp::Zero(offset(ret.clone().unwrap(), 1)));
+	}
+
+	let cast = match i {
+		llvm_ir::Instruction::ZExt(c) => Some(&c.operand),
+		llvm_ir::Instruction::SExt(c) => Some(&c.operand),
+		llvm_ir::Instruction::Trunc(c) => Some(&c.operand),
+		_ => None,
+	};
+
+	match i {
+		llvm_ir::Instruction::Store(st) => {
+			let to = match &args[1] {
+				BuilderArgs::Alloc(a) => a.clone(),
+				_ => unreachable!("i16 only gets stored to locals"),
+			};
+
+			let (v, o) = i16_operand(ctx, &st.value, &args[0]);
+			ops.extend(o);
+			ops.extend(vec![
+				BfOp::Zero(to.clone()),
+				BfOp::Zero(offset(to.clone(), 1)),
+				BfOp::Mov(v.clone(), to.clone()),
+				BfOp::Mov(offset(v, 1), offset(to, 1)),
+			]);
+		}
+		llvm_ir::Instruction::Load(l) => {
+			let r = ret.unwrap();
+			let (v, o) = i16_operand(ctx, &l.address, &args[0]);
+			ops.extend(o);
+			ops.push(BfOp::Mov(v.clone(), r.clone()));
+			ops.push(BfOp::Mov(offset(v, 1), offset(r, 1)));
+		}
+		// the high bytes are just added, the low ones count up one at a time
+		// with room keeping track of how far off 255 we are so a carry can
+		// happen without the cell ever wrapping
+		llvm_ir::Instruction::Add(x) => {
+			let r = ret.unwrap();
+			let (a, oa) = i16_operand(ctx, &x.operand0, &args[0]);
+			let (b, ob) = i16_operand(ctx, &x.operand1, &args[1]);
+			let room = borrow_reg(ctx, 1);
+			let tmp = borrow_reg(ctx, 1);
+			let carry = borrow_reg(ctx, 1);
+
+			ops.extend(oa);
+			ops.extend(ob);
+			ops.extend(vec![
+				BfOp::Mov(offset(a.clone(), 1), offset(r.clone(), 1)),
+				BfOp::Mov(offset(b.clone(), 1), offset(r.clone(), 1)),
+				BfOp::AddI(room.clone(), 255),
+				BfOp::Loop(
+					a.clone(),
+					vec![
+						BfOp::SubI(a.clone(), 1),
+						BfOp::SubI(room.clone(), 1),
+						BfOp::AddI(r.clone(), 1),
+					],
+				),
+				BfOp::Loop(
+					b.clone(),
+					vec![
+						BfOp::SubI(b.clone(), 1),
+						BfOp::AddI(carry.clone(), 1),
+						BfOp::Loop(
+							room.clone(),
+							vec![
+								BfOp::SubI(room.clone(), 1),
+								BfOp::AddI(r.clone(), 1),
+								BfOp::Zero(carry.clone()),
+								BfOp::Mov(room.clone(), tmp.clone()),
+							],
+						),
+						BfOp::Mov(tmp.clone(), room.clone()),
+						BfOp::Loop(
+							carry.clone(),
+							vec![
+								BfOp::SubI(carry.clone(), 1),
+								BfOp::Zero(r.clone()),
+								BfOp::AddI(room.clone(), 255),
+								BfOp::AddI(offset(r.clone(), 1), 1),
+							],
+						),
+					],
+				),
+				BfOp::Zero(room),
+			]);
+		}
+		// same again counting down, a low byte at 0 borrows from the high one
+		llvm_ir::Instruction::Sub(x) => {
+			let r = ret.unwrap();
+			let (a, oa) = i16_operand(ctx, &x.operand0, &args[0]);
+			let (b, ob) = i16_operand(ctx, &x.operand1, &args[1]);
+			let tmp = borrow_reg(ctx, 1);
+			let borrow = borrow_reg(ctx, 1);
+			let bhi = offset(b.clone(), 1);
+
+			ops.extend(oa);
+			ops.extend(ob);
+			ops.extend(vec![
+				BfOp::Mov(a.clone(), r.clone()),
+				BfOp::Mov(offset(a, 1), offset(r.clone(), 1)),
+				BfOp::Loop(
+					bhi.clone(),
+					vec![
+						BfOp::SubI(bhi.clone(), 1),
+						BfOp::SubI(offset(r.clone(), 1), 1),
+					],
+				),
+				BfOp::Loop(
+					b.clone(),
+					vec![
+						BfOp::SubI(b.clone(), 1),
+						BfOp::AddI(borrow.clone(), 1),
+						BfOp::Loop(
+							r.clone(),
+							vec![
+								BfOp::SubI(r.clone(), 1),
+								BfOp::Zero(borrow.clone()),
+								BfOp::Mov(r.clone(), tmp.clone()),
+							],
+						),
+						BfOp::Mov(tmp.clone(), r.clone()),
+						BfOp::Loop(
+							borrow.clone(),
+							vec![
+								BfOp::SubI(borrow.clone(), 1),
+								BfOp::AddI(r.clone(), 255),
+								BfOp::SubI(offset(r.clone(), 1), 1),
+							],
+						),
+					],
+				),
+			]);
+		}
+		// widening, the high byte stays 0
+		_ if i16_result(i) => {
+			let (v, o) = builder_args_to_consumable_reg(ctx, &args[0]);
+			ops.extend(o);
+			ops.push(BfOp::Mov(v, ret.unwrap()));
+		}
+		// narrowing, or widening into something that's a cell anyways, keeps
+		// just the low byte
+		_ => {
+			let (v, o) = i16_operand(ctx, cast.unwrap(), &args[0]);
+			ops.extend(o);
+			ops.push(BfOp::Mov(v.clone(), ret.unwrap()));
+			ops.push(BfOp::Zero(offset(v, 1)));
+		}
+	}
+
+	ops
+}
+
 fn build_select(
 	ctx: &mut Ctx,
 	i: &llvm_ir::Instruction,
@@ -1788,6 +5356,39 @@ fn build_load(
 	}
 }
 
+// Where a function's ret leaves what it returns, counted from its own frame.
+// After the ret the cursor is back in the caller's ret pad but the callee's
+// block and function loops still close on their masks from there, so this has
+// to sit past all of them.
+fn ret_cell(ctx: &Ctx, func: &str) -> usize {
+	ctx.ret_cells
+		.iter()
+		.find(|(n, _)| n == func)
+		.map(|(_, at)| *at)
+		.unwrap()
+}
+
+// The call a block continues on from, when that call returns something. The
+// continuation moves the value out of the callee's ret_cell once we're back.
+fn returned_into<'a>(
+	func: &'a llvm_ir::Function,
+	block: &llvm_ir::Name,
+) -> Option<&'a llvm_ir::instruction::Call> {
+	func.basic_blocks.iter().find_map(|b| match (&b.term, b.instrs.last()) {
+		(
+			llvm_ir::Terminator::Br(br),
+			Some(llvm_ir::Instruction::Call(c)),
+		) if &br.dest == block
+			&& c.dest.is_some()
+			&& callee_of(c).is_some()
+			&& !is_intrinsic(c) =>
+		{
+			Some(c)
+		}
+		_ => None,
+	})
+}
+
 fn build_call(
 	ctx: &mut Ctx,
 	i: &llvm_ir::Instruction,
@@ -1795,17 +5396,11 @@ fn build_call(
 	args: &[BuilderArgs],
 	ret: Option<Addr>,
 ) -> Vec<BfOp> {
-	assert!(ret.is_none());
 	let c = match i {
 		llvm_ir::Instruction::Call(c) => c,
 		_ => panic!("ohnoonono"),
 	};
 
-	let br = match &block.term {
-		llvm_ir::Terminator::Br(br) => &br.dest,
-		_ => unreachable!("terminator of call block must be branch"),
-	};
-
 	let callee_name =
 		match c.function.as_ref().unwrap_right().as_constant().unwrap() {
 			llvm_ir::Constant::GlobalReference { name, .. } => n2nam(&name),
@@ -1816,49 +5411,56 @@ fn build_call(
 			),
 		};
 
-	// TODO(turbio): even an instric call will end in a
-	// branch lol that could be a lil better
-
-	// after the call returns branch to this block. Earlier
-	// we made sure all calls are at the end of a block
-	// which always ends in an unconditional branch.
-	let brto = ctx
-		.layout
-		.iter()
-		.position(|c| match c {
-			Cell::BlockMask(n) => n == br,
-			_ => false,
-		})
-		.unwrap();
-
 	let mut callops = vec![];
 
-	let ret_pad_width = ctx.ret_pad_width.unwrap();
-	let stack_width = ctx.stack_width.unwrap();
-
-	let retpad_addr = ctx.retpad_addr.clone().unwrap();
-	let entry_block_addr = ctx.entry_block_addr.unwrap();
-
-	let own_func_name = "caller";
-
-	callops.push(BfOp::Comment(
-		"enable next block when we return".to_string(),
-	));
-	callops.push(BfOp::Tag(
-		fixed_addr(brto),
-		format!("{}/{}", own_func_name, br),
-	));
-	callops.push(BfOp::AddI(fixed_addr(brto), 1));
+	// intrinsics lol, these are done in place and the block just carries on
+	// (calls_terminate_blocks leaves them be)
+	if callee_name == "getchar" {
+		callops.push(BfOp::Comment("getchar intrinsic".to_string()));
+
+		// nobody reading it is fine too, the char still has to be eaten
+		match ret {
+			Some(reg) => callops.push(BfOp::Getch(reg)),
+			None => {
+				let reg = borrow_reg(ctx, 1);
+				callops.push(BfOp::Getch(reg.clone()));
+				callops.push(BfOp::Zero(reg));
+			}
+		}
 
-	if callee_name.starts_with("llvm.lifetime") {
-		callops.push(BfOp::Comment(format!("ignoring llvm intrinsic")));
 		return callops;
 	}
 
-	// intrinsics lol
+	// anything returned is left behind by the callee's ret and only picked up
+	// once we're back, see returned_into
 	if callee_name == "putchar" {
 		assert!(c.dest.is_none(), "putchar returns nothing");
-		assert!(c.arguments.len() == 1, "putchar expects one argument");
+
+		// a run of constant chars merged by merge_putchars, just walk the
+		// one cell from char to char
+		if args.len() > 1 {
+			callops.push(BfOp::Comment("putchar run intrinsic".to_string()));
+
+			let reg = borrow_reg(ctx, 1);
+			let mut at: u8 = 0;
+			for a in args.iter() {
+				let v = match a {
+					BuilderArgs::Const(v) => *v as u8,
+					_ => panic!("putchar runs are all constants"),
+				};
+
+				if v > at {
+					callops.append(&mut build_const(ctx, &reg, v - at));
+				} else if v < at {
+					callops.push(BfOp::SubI(reg.clone(), at - v));
+				}
+				callops.push(BfOp::Putch(reg.clone()));
+				at = v;
+			}
+			callops.push(BfOp::Zero(reg.clone()));
+
+			return callops;
+		}
 
 		//let val = uncop(&c.arguments[0].0);
 
@@ -1888,9 +5490,47 @@ fn build_call(
 		return callops;
 	}
 
+	// after the call returns branch to this block. Earlier
+	// we made sure all calls are at the end of a block
+	// which always ends in an unconditional branch.
+	let br = match &block.term {
+		llvm_ir::Terminator::Br(br) => &br.dest,
+		_ => unreachable!("terminator of call block must be branch"),
+	};
+	let brto = ctx
+		.layout
+		.iter()
+		.position(|c| match c {
+			Cell::BlockMask(n) => n == br,
+			_ => false,
+		})
+		.unwrap();
+
+	let ret_pad_width = ctx.ret_pad_width.unwrap();
+	let stack_width = ctx.stack_width.unwrap();
+
+	let retpad_addr = ctx.retpad_addr.clone().unwrap();
+	let entry_block_addr = ctx.entry_block_addr.unwrap();
+
+	let own_func_name = "caller";
+
+	callops.push(BfOp::Comment(
+		"enable next block when we return".to_string(),
+	));
+	callops.push(BfOp::Tag(
+		fixed_addr(brto),
+		format!("{}/{}", own_func_name, br),
+	));
+	callops.push(BfOp::AddI(fixed_addr(brto), 1));
+
 	callops.push(BfOp::Comment(format!("stack_width {}", stack_width)));
 	callops.push(BfOp::Comment(format!("ret_pad_width {}", ret_pad_width)));
 
+	// with a stack guard everything from here on only happens behind a flag
+	// the guard knocks down when the callee won't fit
+	let guard_flag = ctx.stack_guard.is_some().then(|| borrow_reg(ctx, 1));
+	let guarded_from = callops.len();
+
 	for (i, ar) in args.iter().enumerate() {
 		callops.push(BfOp::Comment(format!("copy up arg {}", i)));
 
@@ -1915,9 +5555,11 @@ fn build_call(
 
 	callops.push(BfOp::Comment(format!("give callee a stack pointer")));
 	callops.push(BfOp::Tag(callee_st_ptr.clone(), format!("stack_ptr")));
+	// the stack pointer is where it sits itself, our own plus however far
+	// right of our frame it is
 	callops.push(BfOp::AddI(
 		callee_st_ptr.clone(),
-		(stack_width + ret_pad_width + 3) as u8,
+		(resaddr(callee_st_ptr.clone()) + 1) as u8,
 	));
 	callops.push(BfOp::Left(1)); // forbidden territory
 	callops.push(BfOp::Dup(
@@ -1981,9 +5623,100 @@ fn build_call(
 	));
 	callops.push(BfOp::AddI(fixed_addr(entry_block_addr), 1));
 
+	if let Some(flag) = guard_flag {
+		// the loop closes on the same cell in the callee's frame, which is
+		// still untouched
+		let mut call = vec![BfOp::SubI(flag.clone(), 1)];
+		call.append(&mut callops.split_off(guarded_from));
+
+		callops.push(BfOp::Comment("stack guard".to_string()));
+		callops.push(BfOp::AddI(flag.clone(), 1));
+		callops.append(&mut stack_guard_ops(
+			ctx,
+			&callee_name,
+			c.arguments.len(),
+		));
+		callops.push(BfOp::Loop(flag, call));
+	}
+
 	callops
 }
 
+// Hitting the wall walks all the way back down to where main's ret would have
+// left us, carrying a count of how far that is and wiping everything on the
+// way. The marker gets printed from there and the main loop finds nothing but
+// zeros so the program ends like it would have anyway.
+fn stack_guard_ops(ctx: &Ctx, callee: &str, nargs: usize) -> Vec<BfOp> {
+	let guard = ctx.stack_guard.as_ref().unwrap();
+	let callee_width = guard
+		.widths
+		.iter()
+		.find(|(n, _)| n == callee)
+		.map_or(0, |(_, w)| *w);
+
+	let end = ctx.stack_width.unwrap()
+		+ ctx.ret_pad_width.unwrap()
+		+ 1 + nargs + STACK_PTR_W
+		+ callee_width;
+
+	let mut bail = vec![
+		BfOp::AddI(fixed_addr(end), (guard.reach - 1) as u8),
+		BfOp::Loop(
+			fixed_addr(end),
+			vec![
+				BfOp::Zero(fixed_addr(end - 1)),
+				BfOp::Mov(fixed_addr(end), fixed_addr(end - 1)),
+				BfOp::Left(1),
+				BfOp::SubI(fixed_addr(end), 1),
+			],
+		),
+		// the count ran out right where main's ret would have parked us
+		BfOp::Right(end),
+	];
+
+	let mut at = 0;
+	for ch in STACK_OVERFLOW_MARKER.bytes() {
+		if ch > at {
+			bail.push(BfOp::AddI(fixed_addr(0), ch - at));
+		} else if ch < at {
+			bail.push(BfOp::SubI(fixed_addr(0), at - ch));
+		}
+		bail.push(BfOp::Putch(fixed_addr(0)));
+		at = ch;
+	}
+	bail.push(BfOp::Zero(fixed_addr(0)));
+
+	vec![BfOp::Loop(fixed_addr(end), bail)]
+}
+
+// Nothing is supposed to get here but ending the program like main's ret
+// would beats spinning forever. A frame's stack ptr is the address of its
+// cell 0 less one so it says how far back down to walk, wiping everything on
+// the way like the stack guard's bail does.
+fn build_unreachable(ctx: &mut Ctx, ownfid: usize) -> Vec<BfOp> {
+	let mut ops = vec![BfOp::Comment("unreachable, ending it all".to_string())];
+	ops.append(&mut zero_frame(ctx));
+
+	ops.append(&mut vec![
+		BfOp::Zero(fixed_addr(ownfid)),
+		BfOp::Zero(fixed_addr(0)),
+		// the walk counts from the stack ptr
+		BfOp::Left(2),
+		BfOp::SubI(fixed_addr(1), ctx.park as u8 + 1),
+		BfOp::Loop(
+			fixed_addr(1),
+			vec![
+				BfOp::Zero(fixed_addr(0)),
+				BfOp::Mov(fixed_addr(1), fixed_addr(0)),
+				BfOp::Left(1),
+				BfOp::SubI(fixed_addr(1), 1),
+			],
+		),
+	]);
+
+	ops
+}
+
 fn instr_consumes<'i>(
 	ctx: &Ctx,
 	i: &'i llvm_ir::Instruction,
@@ -2008,6 +5741,133 @@ fn instr_consumes<'i>(
 		.collect()
 }
 
+// The values that are made and used up inside the one block, with the index
+// of the last instr that reads each (the terminator is one past the last
+// instr). A block runs start to finish before any other block of the same
+// frame gets a turn so these can share cells with other blocks' locals, and
+// with each other once they're done. Anything a phi reads, a call's result
+// (filled in by the block after, see returned_into) and i16s with their
+// second cell are left out.
+fn block_locals(func: &llvm_ir::Function) -> Vec<(llvm_ir::Name, usize)> {
+	let mut defs: Vec<(llvm_ir::Name, usize, Option<usize>)> = vec![];
+	for (bid, block) in func.basic_blocks.iter().enumerate() {
+		for instr in block.instrs.iter() {
+			match instr {
+				llvm_ir::Instruction::Alloca(_)
+				| llvm_ir::Instruction::Phi(_)
+				| llvm_ir::Instruction::Call(_) => continue,
+				_ if i16_result(instr) => continue,
+				_ => {}
+			}
+			if let Some(ret) = instr.try_get_result() {
+				defs.push((ret.clone(), bid, Some(0)));
+			}
+		}
+	}
+
+	for (bid, block) in func.basic_blocks.iter().enumerate() {
+		let mut uses = vec![];
+		for (iid, instr) in block.instrs.iter().enumerate() {
+			let phi = matches!(instr, llvm_ir::Instruction::Phi(_));
+			let mut instr = instr.clone();
+			for op in operands_mut(&mut instr) {
+				uses.push((local_name(op), iid, phi));
+			}
+		}
+		let mut term = block.term.clone();
+		for op in term_operands_mut(&mut term) {
+			uses.push((local_name(op), block.instrs.len(), false));
+		}
+
+		for (name, iid, phi) in uses {
+			let def = name.and_then(|n| defs.iter_mut().find(|(d, _, _)| d == &n));
+			if let Some((_, def_bid, last)) = def {
+				*last = match *def_bid == bid && !phi {
+					true => last.map(|l| l.max(iid)),
+					false => None,
+				};
+			}
+		}
+	}
+
+	defs.into_iter()
+		.filter_map(|(n, _, last)| last.map(|l| (n, l)))
+		.collect()
+}
+
+// Fill in the phis at the top of `to` for the edge coming from `from`, right
+// before the branch takes it. The phis all read their incoming values before
+// any of them writes, like llvm says, so with more than one every value is
+// parked in scratch first. Otherwise a phi whose value is another one's dest
+// (think fib's `a, b = b, a + b`) would see it already overwritten.
+fn build_phi_copies(
+	ctx: &mut Ctx,
+	to: &llvm_ir::BasicBlock,
+	from: &llvm_ir::Name,
+	multi_use: &Vec<&llvm_ir::Name>,
+) -> Vec<BfOp> {
+	let (mut ops, moves) = read_phis(ctx, to, from, multi_use, false);
+	ops.append(&mut write_phis(moves));
+	ops
+}
+
+// The reading half of build_phi_copies, gives back each value's cell and the
+// phi it goes to. A cond br reads for both its edges before branching and only
+// writes the taken one's, so there everything is parked no matter how many
+// phis: an edge's values can't be sitting in cells the other edge writes.
+fn read_phis(
+	ctx: &mut Ctx,
+	to: &llvm_ir::BasicBlock,
+	from: &llvm_ir::Name,
+	multi_use: &Vec<&llvm_ir::Name>,
+	park_all: bool,
+) -> (Vec<BfOp>, Vec<(Addr, Addr)>) {
+	let phis = to
+		.instrs
+		.iter()
+		.filter_map(|i| llvm_ir::instruction::Phi::try_from((*i).clone()).ok())
+		.collect::<Vec<_>>();
+
+	let mut ops = vec![];
+	let mut moves = vec![];
+
+	for phi in phis.iter() {
+		let our_branch = phi
+			.incoming_values
+			.iter()
+			.find(|pair| &pair.1 == from)
+			.unwrap();
+
+		ops.push(BfOp::Comment(format!("doing phi stuff")));
+		ops.push(BfOp::Comment(format!("{}", phi)));
+
+		let (brval, mut o) = consumed_op_to_reg(ctx, &our_branch.0, multi_use);
+		ops.append(&mut o);
+
+		let brval = match phis.len() == 1 && !park_all {
+			true => brval,
+			false => {
+				let parked = borrow_reg(ctx, 1);
+				ops.push(BfOp::Mov(brval, parked.clone()));
+				parked
+			}
+		};
+
+		moves.push((brval, take_reg(ctx, &phi.dest)));
+	}
+
+	(ops, moves)
+}
+
+fn write_phis(moves: Vec<(Addr, Addr)>) -> Vec<BfOp> {
+	let mut ops = vec![];
+	for (brval, dest) in moves {
+		ops.push(BfOp::Zero(dest.clone()));
+		ops.push(BfOp::Mov(brval, dest));
+	}
+	ops
+}
+
 fn consumed_op_to_reg(
 	ctx: &mut Ctx,
 	operand: &llvm_ir::Operand,
@@ -2020,7 +5880,7 @@ fn consumed_op_to_reg(
 				let consumable = borrow_reg(ctx, 1);
 
 				let (nonconsumable, o) = op_to_reg(ctx, operand);
-				assert!(o.len() == 0, "{:?}", o);
+				assert!(o.is_empty(), "{:?}", o);
 
 				(
 					consumable.clone(),
@@ -2069,9 +5929,21 @@ fn instr_opers<'i>(
 		llvm_ir::Instruction::Mul(i) => vec![&i.operand0, &i.operand1],
 		llvm_ir::Instruction::BitCast(i) => vec![&i.operand],
 		llvm_ir::Instruction::GetElementPtr(i) => {
-			assert!(i.indices.len() == 1);
-
-			vec![&i.address, &i.indices[0]]
+			match (&i.address, i.indices.len()) {
+				(_, 1) => vec![&i.address, &i.indices[0]],
+				// indexing a global array, the leading index just steps
+				// through the pointer to the array and check_global_gep makes
+				// sure it's 0
+				(llvm_ir::Operand::ConstantOperand(_), 2) => {
+					vec![&i.address, &i.indices[1]]
+				}
+				// same for a local one, reject_unnormalizable already made
+				// sure of it
+				(llvm_ir::Operand::LocalOperand { .. }, 2) => {
+					vec![&i.address, &i.indices[1]]
+				}
+				_ => unimplemented!("gep {}", i),
+			}
 		},
 		llvm_ir::Instruction::Select(i) => {
 			vec![&i.condition, &i.true_value, &i.false_value]
@@ -2087,8 +5959,10 @@ fn instr_opers<'i>(
 	}
 }
 
-fn lookup_instr(i: &llvm_ir::Instruction) -> &'static InstrMeta<'static> {
-	match i {
+// None for anything there's no builder for, reject_unnormalizable turns those
+// away before any building starts
+fn lookup_instr(i: &llvm_ir::Instruction) -> Option<&'static InstrMeta<'static>> {
+	Some(match i {
 		llvm_ir::Instruction::Select(_) => &InstrMeta {
 			builders: &[(RetMeta::Addr, build_select)],
 		},
@@ -2150,38 +6024,164 @@ fn lookup_instr(i: &llvm_ir::Instruction) -> &'static InstrMeta<'static> {
 		| llvm_ir::Instruction::IntToPtr(_)
 		| llvm_ir::Instruction::BitCast(_)
 		| llvm_ir::Instruction::PtrToInt(_)
-		| llvm_ir::Instruction::Trunc(_)
-		| llvm_ir::Instruction::SExt(_) => &InstrMeta {
+		| llvm_ir::Instruction::Trunc(_) => &InstrMeta {
 			builders: &[
 				(RetMeta::Addr, build_nop_move),
 				//(&[ArgsMeta::InPlaceReg], RetMeta::InPlace, build_nop),
 				//(&[ArgsMeta::Const], RetMeta::Addr, build_nop),
 			],
 		},
-		_ => unimplemented!("lookup for {}", i),
+		llvm_ir::Instruction::SExt(_) => &InstrMeta {
+			builders: &[(RetMeta::Addr, build_sext)],
+		},
+		_ => return None,
+	})
+}
+
+fn known_value(ctx: &Ctx, name: &llvm_ir::Name) -> Option<u8> {
+	ctx.known.iter().find(|(n, _)| n == name).map(|(_, v)| *v)
+}
+
+fn set_known(ctx: &mut Ctx, name: &llvm_ir::Name, v: Option<u8>) {
+	ctx.known.retain(|(n, _)| n != name);
+	if let Some(v) = v {
+		ctx.known.push((name.clone(), v));
 	}
 }
 
-fn build_func(
-	globals: &Vec<GlobalMap>,
-	playout: &Layout,
+// anything could've been written through a pointer so only the regs are safe
+fn forget_allocs(ctx: &mut Ctx) {
+	let layout = &ctx.layout;
+	ctx.known.retain(|(n, _)| {
+		layout.iter().all(|c| match c {
+			Cell::Alloc(a) => a != n,
+			_ => true,
+		})
+	});
+}
+
+fn alloc_name(ctx: &Ctx, addr: &Addr) -> llvm_ir::Name {
+	match &ctx.layout[resaddr(addr.clone())] {
+		Cell::Alloc(n) => n.clone(),
+		c => panic!("{:?} isn't an alloca", c),
+	}
+}
+
+// Work out what an instruction gives if every input is already known. This
+// has to agree with what the builder would've done at runtime, so anything
+// that would over/underflow a cell is left alone and the signed compares read
+// the cells as two's complement like build_signed_bias does.
+fn fold_known(
+	ctx: &Ctx,
+	i: &llvm_ir::Instruction,
+	args: &[BuilderArgs],
+) -> Option<u8> {
+	if let llvm_ir::Instruction::Load(_) = i {
+		return match &args[0] {
+			BuilderArgs::Alloc(addr) => known_value(ctx, &alloc_name(ctx, addr)),
+			_ => None,
+		};
+	}
+
+	let consts = args
+		.iter()
+		.map(|a| match a {
+			BuilderArgs::Const(v) => Some(*v as u8),
+			_ => None,
+		})
+		.collect::<Option<Vec<u8>>>()?;
+
+	match i {
+		// a cell wraps like an i8 does, so these come out the same as the
+		// builder would've left them
+		llvm_ir::Instruction::Add(_) => Some(consts[0].wrapping_add(consts[1])),
+		llvm_ir::Instruction::Sub(_) => Some(consts[0].wrapping_sub(consts[1])),
+		llvm_ir::Instruction::Mul(_) => Some(consts[0].wrapping_mul(consts[1])),
+		llvm_ir::Instruction::UDiv(_) => consts[0].checked_div(consts[1]),
+		llvm_ir::Instruction::URem(_) => consts[0].checked_rem(consts[1]),
+		llvm_ir::Instruction::And(_) => Some(consts[0] & consts[1]),
+		llvm_ir::Instruction::Or(_) => Some(consts[0] | consts[1]),
+		llvm_ir::Instruction::Xor(_) => Some(consts[0] ^ consts[1]),
+		llvm_ir::Instruction::Shl(_) => consts[0].checked_shl(consts[1] as u32),
+		llvm_ir::Instruction::LShr(_) => {
+			consts[0].checked_shr(consts[1] as u32)
+		}
+		llvm_ir::Instruction::SExt(_) if sext_of_bool(i) => Some(consts[0] * 255),
+		llvm_ir::Instruction::ZExt(_)
+		| llvm_ir::Instruction::SExt(_)
+		| llvm_ir::Instruction::Trunc(_)
+		| llvm_ir::Instruction::BitCast(_)
+		| llvm_ir::Instruction::IntToPtr(_)
+		| llvm_ir::Instruction::PtrToInt(_) => Some(consts[0]),
+		llvm_ir::Instruction::ICmp(c) => {
+			let (a, b) = (consts[0], consts[1]);
+			Some(match c.predicate {
+				llvm_ir::IntPredicate::EQ => a == b,
+				llvm_ir::IntPredicate::NE => a != b,
+				llvm_ir::IntPredicate::ULT => a < b,
+				llvm_ir::IntPredicate::ULE => a <= b,
+				llvm_ir::IntPredicate::UGT => a > b,
+				llvm_ir::IntPredicate::UGE => a >= b,
+				llvm_ir::IntPredicate::SLT => (a as i8) < (b as i8),
+				llvm_ir::IntPredicate::SLE => (a as i8) <= (b as i8),
+				llvm_ir::IntPredicate::SGT => (a as i8) > (b as i8),
+				llvm_ir::IntPredicate::SGE => (a as i8) >= (b as i8),
+			} as u8)
+		}
+		llvm_ir::Instruction::GetElementPtr(_) => consts[0].checked_add(consts[1]),
+		llvm_ir::Instruction::Select(_) => match consts[0] {
+			0 => Some(consts[2]),
+			_ => Some(consts[1]),
+		},
+		_ => None,
+	}
+}
+
+// What every function in a module gets built against, only the stack guard
+// changes once the frame widths it needs are measured
+struct Frames<'a> {
+	globals: &'a [GlobalMap],
+	layout: &'a [Cell],
 	ret_pad_width: usize,
+	ret_cells: &'a [(String, usize)],
+	stack_guard: Option<StackGuard>,
+	ret_to: Option<usize>,
+	park: usize,
+}
+
+fn build_func(
+	frames: &Frames,
+	opts: &Options,
 	stack_width: usize,
 	func: &llvm_ir::Function,
-) -> (Vec<BfOp>, usize) {
+	warnings: &mut Vec<Diagnostic>,
+) -> Result<(FuncOps, usize), CompileError> {
 	// returns the stack width too
+	let ret_pad_width = frames.ret_pad_width;
 	let ret_landing_pad =
 		llvm_ir::Name::Name(Box::new("ret_lading_pad".to_string()));
 
 	let mut ctx = Ctx {
-		layout: playout.clone(),
+		layout: frames.layout.to_vec(),
 		addrs: Vec::<Addr>::new(),
 		ret_pad_width: Some(ret_pad_width),
 		stack_width: Some(stack_width),
 		entry_block_addr: None,
 		retpad_addr: None,
 		ownfid: None,
-		globals: globals.clone(),
+		globals: frames.globals.to_vec(),
+		loc: None,
+		warnings: vec![],
+		known: vec![],
+		stack_guard: frames.stack_guard.clone(),
+		wrap_safe: opts.wrap_safe,
+		strict_consts: opts.strict_consts,
+		check_scratch: opts.check_scratch,
+		too_wide: vec![],
+		ret_cells: frames.ret_cells.to_vec(),
+		ret_to: frames.ret_to,
+		park: frames.park,
+		shared: vec![],
 	};
 
 	for (i, block) in func.basic_blocks.iter().enumerate() {
@@ -2204,6 +6204,22 @@ fn build_func(
 			match instr {
 				llvm_ir::Instruction::Alloca(a) => {
 					ctx.layout.push(Cell::Alloc(a.dest.clone()));
+
+					if is_i16(&a.allocated_type) {
+						ctx.layout.push(Cell::Alloc(high_cell(&a.dest)));
+					}
+
+					// the rest of an array indexed at runtime right behind
+					// its first element, a cell each
+					for k in 1..cell_array_len(&a.allocated_type).unwrap_or(1) {
+						ctx.layout.push(Cell::Alloc(llvm_ir::Name::Name(
+							Box::new(format!(
+								"{}[{}]",
+								format!("{}", a.dest).trim_start_matches('%'),
+								k
+							)),
+						)));
+					}
 				}
 				_ => {}
 			}
@@ -2213,28 +6229,66 @@ fn build_func(
 	let mut name_uses: Vec<&llvm_ir::Name> = vec![];
 	let mut multi_use = vec![];
 	for block in func.basic_blocks.iter() {
-		for instr in block.instrs.iter() {
-			let uses = instr_consumes(&ctx, instr);
-			for u in uses {
-				if name_uses.contains(&u) && !multi_use.contains(&u) {
-					multi_use.push(&u);
-					continue;
-				}
+		// a branch only reads its condition but an instruction in front of it
+		// using the same value mustn't use it up
+		let cond = match &block.term {
+			llvm_ir::Terminator::CondBr(llvm_ir::terminator::CondBr {
+				condition: llvm_ir::Operand::LocalOperand { name, .. },
+				..
+			}) => Some(name),
+			_ => None,
+		};
 
-				name_uses.push(u);
+		let uses = block
+			.instrs
+			.iter()
+			.flat_map(|instr| instr_consumes(&ctx, instr))
+			.chain(cond);
+		for u in uses {
+			if name_uses.contains(&u) && !multi_use.contains(&u) {
+				multi_use.push(&u);
+				continue;
 			}
+
+			name_uses.push(u);
 		}
 	}
 
-	// and then all those regs (aka not allocas)
-	// TODO: register reuse when single_use
+	// and then all those regs (aka not allocas). Block locals go in the
+	// lowest cell another block local is done with, everything else gets its
+	// own.
+	let locals = block_locals(func);
+	let mut shared_cells: Vec<usize> = vec![];
 	for block in func.basic_blocks.iter() {
-		for instr in block.instrs.iter() {
+		// cells in use by this block's locals and the last instr reading each
+		let mut busy: Vec<(usize, usize)> = vec![];
+		for (iid, instr) in block.instrs.iter().enumerate() {
 			match instr {
 				llvm_ir::Instruction::Alloca(_) => {}
 				_ => {
 					let ret = instr.try_get_result();
-					if ret.is_some() {
+					let last = ret.and_then(|r| {
+						locals.iter().find(|(n, _)| n == r).map(|(_, l)| *l)
+					});
+					if let (Some(ret), Some(last)) = (ret, last) {
+						// the instr that reads a value for the last time
+						// still needs it there while it writes its own
+						busy.retain(|(_, l)| *l >= iid);
+						let free = shared_cells
+							.iter()
+							.find(|c| !busy.iter().any(|(b, _)| b == *c))
+							.cloned();
+						let cell = match free {
+							Some(c) => c,
+							None => {
+								let c = resaddr(give_reg(&mut ctx, ret, true));
+								shared_cells.push(c);
+								c
+							}
+						};
+						busy.push((cell, last));
+						ctx.shared.push((ret.clone(), cell));
+					} else if ret.is_some() {
 						let ret = ret.unwrap();
 						// TODO this is unsound this can only be false when a
 						// register is ONCE through all flows of execution after
@@ -2245,6 +6299,10 @@ fn build_func(
 						// register around only as long as needed. idk ill deal
 						// w walking the cfg later
 						give_reg(&mut ctx, &ret, true);
+
+						if i16_result(instr) {
+							give_reg(&mut ctx, &high_cell(ret), true);
+						}
 					}
 				}
 			}
@@ -2268,7 +6326,9 @@ fn build_func(
 	let mut first_block_prelude = Vec::<BfOp>::new();
 	first_block_prelude.push(BfOp::Comment(format!("copy up args")));
 	for (i, p) in func.parameters.iter().enumerate() {
-		let pdest = give_reg(&mut ctx, &p.name, false);
+		// args stick around for the whole call like any other reg, they can be
+		// used more than once and zero_frame has to clean them up on ret
+		let pdest = give_reg(&mut ctx, &p.name, true);
 		first_block_prelude
 			.push(BfOp::Tag(pdest.clone(), format!("arg_{}", p.name)));
 
@@ -2281,6 +6341,8 @@ fn build_func(
 		first_block_prelude.push(BfOp::Right(i + STACK_PTR_W + 1));
 	}
 
+	check_frame(func, &ctx.layout)?;
+
 	// worth noting everone's ret pad and first block have the same address
 	let retpad_addr = ctx
 		.layout
@@ -2306,6 +6368,7 @@ fn build_func(
 	ctx.entry_block_addr = Some(entry_block_addr.clone());
 
 	let mut funcloop: Vec<BfOp> = vec![];
+	let mut blocks: Vec<BlockOps> = vec![];
 
 	// the ret landing pad needs to be before any ret instructions so we
 	// can't fall into our own landing pad.
@@ -2384,11 +6447,34 @@ fn build_func(
 
 		blockloop.push(BfOp::SubI(fixed_addr(bid), 1));
 
+		// who knows where we came from
+		ctx.known.clear();
+
 		// first block gets prepended with some fancy stuff
 		if i == 0 {
 			blockloop.append(&mut first_block_prelude);
 		}
 
+		if let Some(c) = returned_into(func, &block.name) {
+			let callee = callee_of(c).unwrap();
+			let left = fixed_addr(
+				stack_width
+					+ ret_pad_width + 1 + c.arguments.len()
+					+ STACK_PTR_W + ret_cell(&ctx, &callee),
+			);
+			let dest = c.dest.as_ref().unwrap();
+
+			blockloop.push(BfOp::Comment(format!("returned {}", dest)));
+			match ctx.layout.iter().any(|c| match c {
+				Cell::Reg { n, .. } => n == dest,
+				_ => false,
+			}) {
+				true => blockloop.push(BfOp::Mov(left, take_reg(&ctx, dest))),
+				// nobody wants it but it still has to go
+				false => blockloop.push(BfOp::Zero(left)),
+			}
+		}
+
 		for (iid, instr) in block.instrs.iter().enumerate() {
 			blockloop.push(BfOp::Comment(instr.to_string()));
 
@@ -2402,6 +6488,16 @@ fn build_func(
 				continue;
 			}
 
+			// whoever had this cell before is done with it
+			if let Some(ret) = instr.try_get_result() {
+				if let Some((_, c)) = ctx.shared.iter().find(|(n, _)| n == ret) {
+					ctx.layout[*c] = Cell::Reg {
+						n: ret.clone(),
+						multi_use: true,
+					};
+				}
+			}
+
 			/*
 			for cell in &ctx.layout {
 				eprintln!("{:?}", cell);
@@ -2430,9 +6526,32 @@ fn build_func(
 				.collect::<Vec<BuilderArgs>>();
 				*/
 
+			ctx.loc = Some(Loc {
+				func: func.name.clone(),
+				block: format!("{}", block.name),
+				instr: instr.to_string(),
+				src: loc_of(instr.get_debug_loc()),
+			});
+			blockloop.push(BfOp::Src(ctx.loc.clone().unwrap()));
+
+			if let llvm_ir::Instruction::GetElementPtr(gep) = instr {
+				check_global_gep(&mut ctx, gep);
+			}
+
+			let consts = instr_opers(&ctx, &instr)
+				.iter()
+				.map(|op| match op {
+					llvm_ir::Operand::ConstantOperand(_) => {
+						Some(cell_const(&mut ctx, op))
+					}
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+
 			let oper_addrs = instr_opers(&ctx, &instr)
 				.iter()
-				.map(|op| match op {
+				.zip(consts)
+				.map(|(op, cv)| match op {
 					llvm_ir::Operand::LocalOperand { name, ty } => ctx
 						.layout
 						.iter()
@@ -2449,14 +6568,17 @@ fn build_func(
 								multi_use: false,
 							} => BuilderArgs::ConsumedReg(fixed_addr(i)),
 							Cell::Reg { n, multi_use: true } => {
-								BuilderArgs::PreservedReg(fixed_addr(i))
+								match known_value(&ctx, n) {
+									Some(v) => BuilderArgs::Const(v as usize),
+									None => BuilderArgs::PreservedReg(fixed_addr(i)),
+								}
 							}
 							_ => panic!(),
 						})
 						.next()
 						.unwrap(),
 					llvm_ir::Operand::ConstantOperand(_) => {
-						BuilderArgs::Const(uncop(&ctx, op) as usize)
+						BuilderArgs::Const(cv.unwrap() as usize)
 					}
 
 					_ => unimplemented!("lol cucked"),
@@ -2481,459 +6603,1327 @@ fn build_func(
 				}
 			}
 
-			let ret = ret.and_then(|i| Some(take_reg(&mut ctx, &i)));
-
-			let instrmeta = lookup_instr(instr);
-			let builder = &instrmeta.builders[0];
-			blockloop.append(&mut builder.1(
-				&mut ctx,
-				instr,
-				block,
-				oper_addrs.as_slice(),
-				ret,
-			));
-
-			ctx.layout = ctx
-				.layout
-				.into_iter()
-				.map(|c| match c {
-					Cell::Borrowed(c2) => *c2,
-					_ => c,
-				})
-				.collect();
+			let retname = ret.cloned();
+			let ret = ret.and_then(|i| Some(take_reg(&mut ctx, &i)));
+
+			// known values only ever track the one cell
+			let wide = i16_result(instr)
+				|| instr_opers(&ctx, instr).into_iter().any(operand_i16);
+
+			let folded = match wide {
+				true => None,
+				false => fold_known(&ctx, instr, &oper_addrs),
+			};
+
+			match (instr, &oper_addrs[..]) {
+				_ if wide => {
+					blockloop.append(&mut build_i16(&mut ctx, instr, &oper_addrs, ret));
+				}
+				// storing what's already there
+				(
+					llvm_ir::Instruction::Store(_),
+					[BuilderArgs::Const(v), BuilderArgs::Alloc(a)],
+				) if known_value(&ctx, &alloc_name(&ctx, a))
+					== Some(*v as u8) =>
+				{
+					blockloop.push(BfOp::Comment(format!(
+						"already holds {}",
+						v
+					)));
+				}
+				_ if folded.is_some() && ret.is_some() => {
+					let v = folded.unwrap();
+					blockloop.push(BfOp::Comment(format!("known to be {}", v)));
+					blockloop.append(&mut build_const(
+						&mut ctx,
+						ret.as_ref().unwrap(),
+						v,
+					));
+				}
+				_ => {
+					let instrmeta = lookup_instr(instr).unwrap();
+					let builder = &instrmeta.builders[0];
+					blockloop.append(&mut builder.1(
+						&mut ctx,
+						instr,
+						block,
+						oper_addrs.as_slice(),
+						ret,
+					));
+				}
+			}
+
+			if let Some(n) = &retname {
+				set_known(&mut ctx, n, folded);
+			}
+
+			match (instr, &oper_addrs[..]) {
+				(llvm_ir::Instruction::Store(_), [v, BuilderArgs::Alloc(a)]) => {
+					let v = match v {
+						BuilderArgs::Const(v) if !wide => Some(*v as u8),
+						_ => None,
+					};
+					let n = alloc_name(&ctx, a);
+					set_known(&mut ctx, &n, v);
+				}
+				(llvm_ir::Instruction::Store(_), _)
+				| (llvm_ir::Instruction::Call(_), _) => forget_allocs(&mut ctx),
+				_ => {}
+			}
+
+			// a call leaves us in some other frame, nothing to check from here
+			if ctx.check_scratch
+				&& llvm_ir::instruction::Call::try_from(instr.clone()).is_err()
+			{
+				let dirty = ctx
+					.layout
+					.iter()
+					.enumerate()
+					.filter(|(_, c)| matches!(c, Cell::Borrowed(_)))
+					.map(|(i, _)| BfOp::Loop(fixed_addr(i), vec![]))
+					.collect::<Vec<_>>();
+
+				if !dirty.is_empty() {
+					blockloop.push(BfOp::Comment(format!("scratch must be clean")));
+					blockloop.extend(dirty);
+				}
+			}
+
+			ctx.layout = ctx
+				.layout
+				.into_iter()
+				.map(|c| match c {
+					Cell::Borrowed(c2) => *c2,
+					_ => c,
+				})
+				.collect();
+		}
+
+		// if it handled a call we know that: the block ended in a call and
+		// the terminator was a unconditional branch. These are both
+		// rolled into the call instruction builder. Intrinsics don't count.
+
+		let ends_in_call = match block.instrs.last() {
+			Some(llvm_ir::Instruction::Call(c)) => !is_intrinsic(c),
+			_ => false,
+		};
+		if !ends_in_call {
+			blockloop.push(BfOp::Comment(block.term.to_string()));
+
+			ctx.loc = Some(Loc {
+				func: func.name.clone(),
+				block: format!("{}", block.name),
+				instr: block.term.to_string(),
+				src: loc_of(block.term.get_debug_loc()),
+			});
+			blockloop.push(BfOp::Src(ctx.loc.clone().unwrap()));
+
+			match &block.term {
+				llvm_ir::Terminator::Br(br) => {
+					let toblock = func
+						.basic_blocks
+						.iter()
+						.find(|bb| br.dest == bb.name)
+						.unwrap();
+
+					blockloop.append(&mut build_phi_copies(
+						&mut ctx,
+						toblock,
+						&block.name,
+						&multi_use,
+					));
+
+					let brto = ctx
+						.layout
+						.iter()
+						.position(|c| match c {
+							Cell::BlockMask(n) => n == &br.dest,
+							_ => false,
+						})
+						.unwrap();
+
+					let brto = fixed_addr(brto);
+
+					//blockloop.push(BfOp::Tag(
+					//	brto.clone(),
+					//	format!("B:{}/{}", func.name, br.dest),
+					//));
+					blockloop.push(BfOp::AddI(brto.clone(), 1));
+				}
+
+				llvm_ir::Terminator::CondBr(cbr) => {
+					// branch off a copy, whatever comes after the join or the
+					// next time around a loop may still want the condition
+					let src = take_reg(&mut ctx, &unlop(&cbr.condition));
+					let cond = borrow_reg(&mut ctx, 1);
+					let tmp = borrow_reg(&mut ctx, 1);
+					blockloop.push(BfOp::Dup(src.clone(), tmp.clone(), cond.clone()));
+					blockloop.push(BfOp::Mov(tmp, src));
+
+					let tru = ctx
+						.layout
+						.iter()
+						.position(|c| match c {
+							Cell::BlockMask(n) => n == &cbr.true_dest,
+							_ => false,
+						})
+						.unwrap();
+					let tru = fixed_addr(tru);
+
+					let fals = ctx
+						.layout
+						.iter()
+						.position(|c| match c {
+							Cell::BlockMask(n) => n == &cbr.false_dest,
+							_ => false,
+						})
+						.unwrap();
+					let fals = fixed_addr(fals);
+
+					// both edges read before either writes, each edge's phis
+					// only get written once it's known to be the one taken
+					let totrublock = func
+						.basic_blocks
+						.iter()
+						.find(|bb| cbr.true_dest == bb.name)
+						.unwrap();
+					let (mut o, tru_moves) = read_phis(
+						&mut ctx,
+						totrublock,
+						&block.name,
+						&multi_use,
+						true,
+					);
+					blockloop.append(&mut o);
+
+					let tofalsblock = func
+						.basic_blocks
+						.iter()
+						.find(|bb| cbr.false_dest == bb.name)
+						.unwrap();
+					let (mut o, fals_moves) = read_phis(
+						&mut ctx,
+						tofalsblock,
+						&block.name,
+						&multi_use,
+						true,
+					);
+					blockloop.append(&mut o);
+
+					// the edge not taken still has to give its parked values
+					// back
+					let tru_drop = fals_moves
+						.iter()
+						.map(|(parked, _)| BfOp::Zero(parked.clone()))
+						.collect::<Vec<_>>();
+					let fals_drop = tru_moves
+						.iter()
+						.map(|(parked, _)| BfOp::Zero(parked.clone()))
+						.collect::<Vec<_>>();
+
+					// TODO(turbio): hacky but well we're using the ret pad
+					// block mask as scratch cause like we'll never need it lol.
+					let temp0 = retpad_addr.clone();
+					blockloop.push(BfOp::AddI(temp0.clone(), 1));
+
+					blockloop.append(&mut vec![
+						// if truethy
+						BfOp::Loop(
+							cond.clone(),
+							vec![
+								BfOp::SubI(cond.clone(), 1),
+								BfOp::SubI(temp0.clone(), 1),
+								BfOp::AddI(tru.clone(), 1),
+								BfOp::Tag(
+									tru.clone(),
+									format!(
+										"B:{}/{}_true",
+										func.name, cbr.true_dest
+									),
+								),
+							]
+							.into_iter()
+							.chain(write_phis(tru_moves))
+							.chain(tru_drop)
+							.collect(),
+						),
+						// if falsey
+						BfOp::Loop(
+							temp0.clone(),
+							vec![
+								BfOp::SubI(temp0.clone(), 1),
+								BfOp::AddI(fals.clone(), 1),
+								BfOp::Tag(
+									fals.clone(),
+									format!(
+										"B:{}/{}_false",
+										func.name, cbr.false_dest
+									),
+								),
+							]
+							.into_iter()
+							.chain(write_phis(fals_moves))
+							.chain(fals_drop)
+							.collect(),
+						),
+					]);
+				}
+
+				llvm_ir::Terminator::Ret(r) => {
+					if let (Some(back), Some(op)) = (ctx.ret_to, &r.return_operand) {
+						let (v, mut o) =
+							consumed_op_to_reg(&mut ctx, op, &multi_use);
+						blockloop.append(&mut o);
+						blockloop.push(BfOp::Left(back));
+						blockloop.push(BfOp::Tag(fixed_addr(0), "ret".to_string()));
+						blockloop.push(BfOp::Mov(
+							offset(v, back as i64),
+							fixed_addr(0),
+						));
+						blockloop.push(BfOp::Right(back));
+					}
+
+					// out of the way while the frame gets zeroed, then left
+					// behind for the caller, see returned_into
+					let returned = match (&r.return_operand, ctx.ret_to) {
+						(Some(op), None) if func.name != "main" => {
+							let (v, mut o) =
+								consumed_op_to_reg(&mut ctx, op, &multi_use);
+							blockloop.append(&mut o);
+							let keep = borrow_reg(&mut ctx, 1);
+							blockloop.push(BfOp::Mov(v, keep.clone()));
+							Some(keep)
+						}
+						_ => None,
+					};
+
+					blockloop.append(&mut zero_frame(&mut ctx));
+
+					if let Some(keep) = returned {
+						let at = ret_cell(&ctx, &func.name);
+						blockloop.push(BfOp::Mov(keep, fixed_addr(at)));
+					}
+
+					blockloop.push(BfOp::SubI(fixed_addr(0), 1));
+					blockloop.push(BfOp::Tag(
+						fixed_addr(0),
+						"dead_frame".to_string(),
+					));
+
+					blockloop.push(BfOp::SubI(fixed_addr(ownfid), 1));
+
+					blockloop.push(BfOp::Left(1));
+					blockloop.push(BfOp::Zero(fixed_addr(0)));
+					blockloop.push(BfOp::Right(1));
+
+					// TODO(turbio): well relying on fixed arg lengths is prolly
+					// not gonna work out. Prolly aught to copy args right into
+					// the stack and have an entry block lay em out right.
+					blockloop.push(BfOp::Left(
+						func.parameters.len() + ret_pad_width + 1 + STACK_PTR_W,
+					));
+				}
+				llvm_ir::Terminator::Unreachable(_) => {
+					blockloop.append(&mut build_unreachable(&mut ctx, ownfid));
+				}
+				_ => unimplemented!("soon? {:?}", block.term),
+			};
+		}
+
+		blocks.push(BlockOps {
+			name: format!("{}", block.name),
+			mask: bid,
+			ops: blockloop,
+		});
+	}
+
+	let mut i = ctx.layout.len() + 10;
+
+	for a in ctx.addrs.iter() {
+		if matches!(a.v.borrow().clone(), Addrt::Named(_)) {
+			a.v.replace(Addrt::Fixed(i));
+			i += 1;
+		}
+	}
+
+	if !ctx.too_wide.is_empty() {
+		return Err(CompileError::Unsupported(ctx.too_wide));
+	}
+
+	warnings.append(&mut ctx.warnings);
+
+	return Ok((
+		FuncOps {
+			name: func.name.clone(),
+			mask: ownfid,
+			layout: ctx.layout.clone(),
+			prologue: funcloop,
+			blocks,
+		},
+		ctx.layout.len(),
+	));
+}
+
+// what comes out the other end
+pub struct Compiled {
+	pub code: String,
+	pub warnings: Vec<Diagnostic>,
+	pub calls: CallGraph,
+	// the blocks bfcc made up and where they came from
+	pub provenance: Vec<Provenance>,
+	pub costs: Vec<Cost>,
+	pub ops: OpsDump,
+	pub map: CellMap,
+	// which instruction every op of code came from, in order
+	pub src_map: Vec<SrcSpan>,
+	// how many ops there were before and after Options::peephole
+	pub peephole: (usize, usize),
+}
+
+// Ops start..end, counting only the eight ops and none of the comments, are
+// what bfcc built for instr. What --emit=bfmap writes out
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SrcSpan {
+	pub start: usize,
+	pub end: usize,
+	pub func: String,
+	pub block: String,
+	pub instr: String,
+}
+
+impl fmt::Display for SrcSpan {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}/{}: {}", self.func, self.block, self.instr)
+	}
+}
+
+// the span op falls in, op counting the same way SrcSpan does
+pub fn src_at(map: &[SrcSpan], op: usize) -> Option<&SrcSpan> {
+	map.iter().find(|s| s.start <= op && op < s.end)
+}
+
+pub fn compile(path: &Path, opts: &Options) -> Result<Compiled, CompileError> {
+	compile_module(load_module(path)?, opts)
+}
+
+pub fn compile_module(
+	mut module: llvm_ir::Module,
+	opts: &Options,
+) -> Result<Compiled, CompileError> {
+	const RET_LANDING_PAD: usize = 1;
+
+	if module.get_func_by_name("main").is_none() {
+		return Err(CompileError::Unsupported(vec![Diagnostic {
+			func: "main".to_string(),
+			block: "-".to_string(),
+			msg: "no such function".to_string(),
+			loc: None,
+		}]));
+	}
+
+	let meta = prepare(&mut module, opts, Some("main"))?;
+
+	let funcns = module.functions.len();
+
+	let mut root: Vec<BfOp> = vec![];
+
+	// the interpreter has to agree on this one so it goes first
+	root.push(BfOp::Comment(format!("eof: {}", opts.eof_value)));
+
+	root.push(BfOp::Comment("blocks made up by bfcc:".to_string()));
+	for p in meta.provenance.iter() {
+		root.push(BfOp::Comment(format!("  {}", p)));
+	}
+
+	root.push(BfOp::Comment("call graph:".to_string()));
+	for line in meta.calls.to_string().lines() {
+		root.push(BfOp::Comment(format!("  {}", line)));
+	}
+
+	// filled in once the functions are built
+	let costs_at = root.len();
+
+	let ret_pad_width = 1 + funcns + RET_LANDING_PAD;
+
+	let mut layout: Layout = vec![Cell::MainLoop];
+
+	let mut ctx = Ctx {
+		// TODO
+		layout: layout.clone(),
+		addrs: Vec::<Addr>::new(),
+		ret_pad_width: None,
+		stack_width: None,
+		retpad_addr: None,
+		entry_block_addr: None,
+		ownfid: None,
+		globals: Vec::<GlobalMap>::new(),
+		loc: None,
+		warnings: vec![],
+		known: vec![],
+		stack_guard: None,
+		wrap_safe: opts.wrap_safe,
+		strict_consts: opts.strict_consts,
+		check_scratch: opts.check_scratch,
+		too_wide: vec![],
+		ret_cells: vec![],
+		ret_to: None,
+		park: 0,
+		shared: vec![],
+	};
+
+	let mut global_addr_at: u8 = 0; 
+
+	// cell 0 is left empty so no global ever ends up looking like null
+	if !module.global_vars.is_empty() {
+		root.push(BfOp::Right(1));
+		global_addr_at = 1;
+	}
+
+	// load globals into beginning of address space
+	for g in module.global_vars.iter() {
+		let mut len = 0;
+		match g.initializer.as_ref().unwrap().deref() {
+			llvm_ir::constant::Constant::Array{element_type, elements} => {
+				len = elements.len() as u8;
+				for e in elements.iter() {
+					ctx.loc = Some(Loc {
+						func: "global".to_string(),
+						block: format!("{}", g.name),
+						instr: format!("{}", e),
+						src: None,
+					});
+
+					match e.deref() {
+						llvm_ir::constant::Constant::Int{..} => {
+							let v = cell_const(
+								&mut ctx,
+								&llvm_ir::Operand::ConstantOperand(e.clone()),
+							);
+							root.push(BfOp::AddI(fixed_addr(0), v));
+							root.push(BfOp::Right(1));
+						},
+						_ => unimplemented!("lol")
+					}
+				}
+			}
+
+			_ => unimplemented!("o {:?}", g.initializer),
 		}
 
-		// if it handled a call we know that: the block ended in a call and
-		// the terminator was a unconditional branch. These are both
-		// rolled into the call instruction builder.
+		ctx.globals.push(GlobalMap{ name: g.name.clone(), addr: global_addr_at, len });
+		global_addr_at += len;
+	}
 
-		let last = block.instrs.last();
-		if last.is_none()
-			|| llvm_ir::instruction::Call::try_from(last.unwrap().clone())
-				.is_err()
-		{
-			blockloop.push(BfOp::Comment(block.term.to_string()));
+	// pointers are plain tape addresses so the stack base has to count the
+	// globals sitting in front of it
+	root.push(BfOp::Right(ret_pad_width + STACK_PTR_W));
+	root.push(BfOp::AddI(
+		fixed_addr(0),
+		global_addr_at + ret_pad_width as u8 + STACK_PTR_W as u8,
+	)); // stack base address
+	root.push(BfOp::Right(1));
+	root.push(BfOp::Comment("runtime init:".to_string()));
+	root.push(BfOp::Tag(fixed_addr(0), "===TOP FRAME".to_string()));
+	root.push(BfOp::AddI(fixed_addr(0), 1));
 
-			match &block.term {
-				llvm_ir::Terminator::Br(br) => {
-					let toblock = func
-						.basic_blocks
-						.iter()
-						.find(|bb| br.dest == bb.name)
-						.unwrap();
+	// stacks frames are laid out as:
+	// <main loop bit> | <func mask> | <block mask> | <registers> | <scratch>
+	// the main loop bit: is always `1` and part of the runtime's flow control
+	// func/block masks: control the current block of execution
+	//
+	// all the allocas live for the lifetime of the function. Sorta are actually
+	// memory addresses but like that's tricky.
+	// let mut allocs: Vec<&llvm_ir::Name> = vec![];
+	//
+	// live registers available on the stack produced by an instruction and
+	// yet to be consumed by a following instruction. Used as a map for
+	// allocations where each cell holds a llvm register name.
+	//
+	// TODO(turbio): for now onstack registers only live between the
+	// instruction producing them and the instruction consuming them.
+	// Multiple uses is unimplemented.
+	for func in module.functions.iter() {
+		layout.push(Cell::FuncMask(func.name.to_string()))
+	}
 
-					let tophis = toblock
-						.instrs
-						.iter()
-						.filter_map(|i| {
-							llvm_ir::instruction::Phi::try_from((*i).clone())
-								.ok()
-						})
-						.collect::<Vec<_>>();
+	// right past the block masks, the ret landing pad's included
+	let ret_cells = module
+		.functions
+		.iter()
+		.map(|f| (f.name.clone(), layout.len() + f.basic_blocks.len() + 1))
+		.collect::<Vec<_>>();
 
-					if tophis.len() > 1 {
-						panic!("idk how to do multiple phis");
-					}
+	let mainfid = layout
+		.iter()
+		.position(|c| match c {
+			Cell::FuncMask(n) => n == "main",
+			_ => false,
+		})
+		.unwrap();
 
-					if tophis.len() == 1 {
-						let tophis = tophis[0].clone();
-						let our_branch = tophis
-							.incoming_values
-							.iter()
-							.find(|pair| pair.1 == block.name)
-							.unwrap();
+	root.push(BfOp::Tag(fixed_addr(mainfid), "main".to_string()));
+	root.push(BfOp::AddI(fixed_addr(mainfid), 1));
+	root.push(BfOp::Tag(fixed_addr(1 + funcns), "main/b0".to_string()));
+	root.push(BfOp::AddI(fixed_addr(1 + funcns), 1));
 
-						blockloop
-							.push(BfOp::Comment(format!("doing phi stuff")));
-						blockloop.push(BfOp::Comment(format!("{}", tophis)));
+	let mut funcs: Vec<FuncOps> = vec![];
+	let mut costs = vec![];
+	let mut cursor = 0;
 
-						match &our_branch.0 {
-							llvm_ir::Operand::LocalOperand { name, ty } => {
-								if multi_use.contains(&name) {
-									panic!("yeek");
-								}
-							}
-							_ => {}
-						}
+	// ret pad is always the same width with: main loop + function masks +
+	// landing pad mask
+	let ret_pad_width = 1 + funcns + RET_LANDING_PAD;
 
-						let (brval, mut o) = consumed_op_to_reg(
-							&mut ctx,
-							&our_branch.0,
-							&multi_use,
-						);
-						blockloop.append(&mut o);
+	// first go is just to measure, it'll say everything twice otherwise. The
+	// guard takes up room too so it has to be there already
+	let mut frames = Frames {
+		globals: &ctx.globals,
+		layout: &layout,
+		ret_pad_width,
+		ret_cells: &ret_cells,
+		stack_guard: match opts.stack_limit {
+			0 => None,
+			_ => Some(StackGuard {
+				widths: vec![],
+				reach: 1,
+			}),
+		},
+		ret_to: None,
+		park: global_addr_at as usize,
+	};
 
-						let dest = take_reg(&mut ctx, &tophis.dest);
-						blockloop.push(BfOp::Zero(dest.clone()));
-						blockloop.push(BfOp::Mov(brval, dest.clone()));
-					}
+	let mut widths = vec![];
+	for func in module.functions.iter() {
+		let (_, st_width) = build_func(&frames, opts, 0, func, &mut vec![])?;
+		widths.push((func.name.clone(), st_width));
+	}
 
-					let brto = ctx
-						.layout
-						.iter()
-						.position(|c| match c {
-							Cell::BlockMask(n) => n == &br.dest,
-							_ => false,
-						})
-						.unwrap();
+	let stack_guard = match opts.stack_limit {
+		0 => None,
+		limit => build_wall(
+			&module,
+			&meta.calls,
+			&widths,
+			limit,
+			global_addr_at as usize,
+			ret_pad_width,
+			&mut ctx.warnings,
+		),
+	};
 
-					let brto = fixed_addr(brto);
+	if let Some((_, at, len)) = &stack_guard {
+		let top = global_addr_at as usize + ret_pad_width + STACK_PTR_W + 1;
+		root.push(BfOp::Comment("stack guard wall:".to_string()));
+		for k in 0..*len {
+			root.push(BfOp::AddI(fixed_addr(at - top + k), k as u8 + 1));
+		}
+	}
 
-					//blockloop.push(BfOp::Tag(
-					//	brto.clone(),
-					//	format!("B:{}/{}", func.name, br.dest),
-					//));
-					blockloop.push(BfOp::AddI(brto.clone(), 1));
-				}
+	frames.stack_guard = stack_guard.as_ref().map(|(g, _, _)| g.clone());
+	for (func, (_, st_width)) in module.functions.iter().zip(widths.iter()) {
+		let (code, _) =
+			build_func(&frames, opts, *st_width, func, &mut ctx.warnings)?;
 
-				llvm_ir::Terminator::CondBr(cbr) => {
-					let cond = take_reg(&mut ctx, &unlop(&cbr.condition));
+		// rendered on its own for the estimate, picking up where the
+		// previous function leaves the cursor so the travel comes out the same
+		let mut rendered = String::new();
+		cursor = printasti(&mut rendered, code.flatten(), cursor, 0, &mut vec![]);
+		costs.push(estimate_cost(&func.name, &rendered, opts.loop_weight));
 
-					let tru = ctx
-						.layout
-						.iter()
-						.position(|c| match c {
-							Cell::BlockMask(n) => n == &cbr.true_dest,
-							_ => false,
-						})
-						.unwrap();
-					let tru = fixed_addr(tru);
+		funcs.push(code);
+	}
 
-					let fals = ctx
-						.layout
-						.iter()
-						.position(|c| match c {
-							Cell::BlockMask(n) => n == &cbr.false_dest,
-							_ => false,
-						})
-						.unwrap();
-					let fals = fixed_addr(fals);
+	let mut header = vec![BfOp::Comment(format!(
+		"cost (loops go round {} times):",
+		opts.loop_weight
+	))];
+	for c in costs.iter() {
+		header.push(BfOp::Comment(format!("  {}", c)));
+	}
+	header.push(BfOp::Comment("frame widths:".to_string()));
+	for (name, w) in widths.iter() {
+		header.push(BfOp::Comment(format!("  {}: {} cells", name, w)));
+	}
+	root.splice(costs_at..costs_at, header);
+
+	// main's ret leaves us parked right after the globals. Wipe them so the
+	// tape ends up as clean as we found it.
+	let mut footer = vec![];
+	if global_addr_at > 0 {
+		footer.push(BfOp::Comment("clear globals:".to_string()));
+		footer.push(BfOp::Left(global_addr_at as usize));
+		for i in 1..global_addr_at as usize {
+			footer.push(BfOp::Zero(fixed_addr(i)));
+		}
+	}
+	if let Some((_, at, len)) = &stack_guard {
+		footer.push(BfOp::Comment("clear stack guard wall:".to_string()));
+		for k in 0..*len {
+			footer.push(BfOp::Zero(fixed_addr(at + k)));
+		}
+	}
 
-					{
-						let totrublock = func
-							.basic_blocks
-							.iter()
-							.find(|bb| cbr.true_dest == bb.name)
-							.unwrap();
+	let ops = OpsDump {
+		version: OPS_VERSION,
+		header: root.iter().map(op_to_json).collect(),
+		functions: funcs.iter().map(|f| f.to_json()).collect(),
+		footer: footer.iter().map(op_to_json).collect(),
+		peephole: opts.peephole,
+	};
 
-						let totruphis = totrublock
-							.instrs
-							.iter()
-							.filter_map(|i| {
-								llvm_ir::instruction::Phi::try_from(
-									(*i).clone(),
-								)
-								.ok()
-							})
-							.collect::<Vec<_>>();
-
-						if totruphis.len() > 1 {
-							panic!("idk how to do multiple phis");
-						}
+	let mainloop = funcs.iter().flat_map(|f| f.flatten()).collect();
+	root.push(BfOp::Loop(fixed_addr(0), mainloop));
+	root.append(&mut footer);
 
-						if totruphis.len() == 1 {
-							let totruphis = totruphis[0].clone();
-							let our_branch = totruphis
-								.incoming_values
-								.iter()
-								.find(|pair| pair.1 == block.name)
-								.unwrap();
+	let map = CellMap {
+		version: MAP_VERSION,
+		globals: ctx
+			.globals
+			.iter()
+			.map(|g| GlobalJson {
+				name: g.name.to_string(),
+				at: g.addr as usize,
+				len: g.len as usize,
+			})
+			.collect(),
+		main_frame_at: global_addr_at as usize + ret_pad_width + STACK_PTR_W + 1,
+		frames: funcs
+			.iter()
+			.zip(widths.iter())
+			.map(|(f, (_, width))| f.to_map(*width))
+			.collect(),
+	};
 
-							blockloop.push(BfOp::Comment(format!(
-								"doing phi stuff"
-							)));
-							blockloop
-								.push(BfOp::Comment(format!("{}", totruphis)));
+	let mut out = String::from("");
+	let marks = printast(&mut out, root);
+	let mut src_map = src_spans(&out, marks);
+
+	let before = out.chars().filter(|c| BF_OPS.contains(*c)).count();
+	if opts.peephole {
+		let (code, moved) = peephole(&out);
+		out = code;
+		src_map = src_map
+			.into_iter()
+			.map(|s| SrcSpan {
+				start: moved[s.start],
+				end: moved[s.end],
+				..s
+			})
+			.filter(|s| s.start < s.end)
+			.collect();
+	}
+	let after = out.chars().filter(|c| BF_OPS.contains(*c)).count();
 
-							let (brval, mut o) = consumed_op_to_reg(
-								&mut ctx,
-								&our_branch.0,
-								&multi_use,
-							);
-							blockloop.append(&mut o);
+	if opts.no_comments {
+		out = strip_comments(&out);
+	}
+	Ok(Compiled {
+		code: out,
+		warnings: ctx.warnings,
+		calls: meta.calls,
+		provenance: meta.provenance,
+		costs,
+		ops,
+		map,
+		src_map,
+		peephole: (before, after),
+	})
+}
 
-							let dest = take_reg(&mut ctx, &totruphis.dest);
-							blockloop.push(BfOp::Zero(dest.clone()));
-							blockloop.push(BfOp::Mov(brval, dest.clone()));
-						}
-					}
+// Byte offsets from printast into op indices, each span running up to where
+// the next one starts
+fn src_spans(code: &str, marks: Vec<(usize, Loc)>) -> Vec<SrcSpan> {
+	let mut ops = 0;
+	let mut at = 0;
+	let mut starts = vec![];
+	for (offset, loc) in marks {
+		ops += code[at..offset].chars().filter(|c| BF_OPS.contains(*c)).count();
+		at = offset;
+		starts.push((ops, loc));
+	}
+	let total = ops + code[at..].chars().filter(|c| BF_OPS.contains(*c)).count();
 
-					{
-						let tofalsblock = func
-							.basic_blocks
-							.iter()
-							.find(|bb| cbr.false_dest == bb.name)
-							.unwrap();
+	let ends = starts.iter().skip(1).map(|(s, _)| *s).chain(Some(total));
+	starts
+		.iter()
+		.zip(ends)
+		.filter(|((start, _), end)| start < end)
+		.map(|((start, loc), end)| SrcSpan {
+			start: *start,
+			end,
+			func: loc.func.clone(),
+			block: loc.block.clone(),
+			instr: loc.instr.clone(),
+		})
+		.collect()
+}
 
-						let tofalsphis = tofalsblock
-							.instrs
-							.iter()
-							.filter_map(|i| {
-								llvm_ir::instruction::Phi::try_from(
-									(*i).clone(),
-								)
-								.ok()
-							})
-							.collect::<Vec<_>>();
-
-						if tofalsphis.len() > 1 {
-							panic!("idk how to do multiple phis");
-						}
+// One function on its own, for poking at a new instruction without a main and
+// all the call machinery around it. Whatever's on the tape in the args' cells
+// going in is what it gets called with, and it runs on a tape laid out as
+//
+// <ret> | <landing> | <ret pad> | <args, last first> | <stack ptr> | <frame>
+//
+// Its ret leaves it parked on the landing like it would any caller, with
+// the return value in cell 0 and the rest zeroed. Calls to anything but the
+// intrinsics have nowhere to go so those get turned away.
+pub struct FunctionBf {
+	pub code: String,
+	// the cell each argument goes in
+	pub args: Vec<usize>,
+	// where the return value ends up, if there is one
+	pub ret: Option<usize>,
+	pub warnings: Vec<Diagnostic>,
+}
 
-						if tofalsphis.len() == 1 {
-							let tofalsphis = tofalsphis[0].clone();
-							let our_branch = tofalsphis
-								.incoming_values
-								.iter()
-								.find(|pair| pair.1 == block.name)
-								.unwrap();
+pub fn compile_function(
+	path: &Path,
+	name: &str,
+	opts: &Options,
+) -> Result<FunctionBf, CompileError> {
+	const RET_LANDING_PAD: usize = 1;
 
-							blockloop.push(BfOp::Comment(format!(
-								"doing phi stuff"
-							)));
-							blockloop
-								.push(BfOp::Comment(format!("{}", tofalsphis)));
+	let mut module = load_module(path)?;
+	prepare(&mut module, opts, None)?;
 
-							let (brval, mut o) = consumed_op_to_reg(
-								&mut ctx,
-								&our_branch.0,
-								&multi_use,
-							);
-							blockloop.append(&mut o);
+	let unsupported = |msg: String| {
+		CompileError::Unsupported(vec![Diagnostic {
+			func: name.to_string(),
+			block: "-".to_string(),
+			msg,
+			loc: None,
+		}])
+	};
 
-							let dest = take_reg(&mut ctx, &tofalsphis.dest);
-							blockloop.push(BfOp::Zero(dest.clone()));
-							blockloop.push(BfOp::Mov(brval, dest.clone()));
-						}
-					}
+	let func = module
+		.functions
+		.iter()
+		.find(|f| f.name == name)
+		.ok_or_else(|| unsupported("no such function".to_string()))?;
 
-					// TODO(turbio): hacky but well we're using the ret pad
-					// block mask as scratch cause like we'll never need it lol.
-					let temp0 = retpad_addr.clone();
-					blockloop.push(BfOp::AddI(temp0.clone(), 1));
+	for block in func.basic_blocks.iter() {
+		for instr in block.instrs.iter() {
+			let callee = match instr {
+				llvm_ir::Instruction::Call(c) => callee_of(c),
+				_ => None,
+			};
 
-					blockloop.append(&mut vec![
-						// if truethy
-						BfOp::Loop(
-							cond.clone(),
-							vec![
-								BfOp::SubI(cond.clone(), 1),
-								BfOp::SubI(temp0.clone(), 1),
-								BfOp::AddI(tru.clone(), 1),
-								BfOp::Tag(
-									tru.clone(),
-									format!(
-										"B:{}/{}_true",
-										func.name,
-										n2usize(&cbr.true_dest)
-									),
-								),
-							],
-						),
-						// if falsey
-						BfOp::Loop(
-							temp0.clone(),
-							vec![
-								BfOp::SubI(temp0.clone(), 1),
-								BfOp::AddI(fals.clone(), 1),
-								BfOp::Tag(
-									fals.clone(),
-									format!(
-										"B:{}/{}_false",
-										func.name,
-										n2usize(&cbr.false_dest)
-									),
-								),
-							],
-						),
-					]);
+			match callee {
+				Some(c) if !INTRINSICS.contains(&c.as_str()) => {
+					return Err(unsupported(format!(
+						"calls {} but a function on its own can't call anything",
+						c
+					)))
 				}
+				_ => {}
+			}
+		}
+	}
 
-				llvm_ir::Terminator::Ret(r) => {
-					if r.return_operand.is_some() {
-						// TODO(turbio): figure out where to put ret args
-						match &r.return_operand.as_ref().unwrap() {
-							llvm_ir::Operand::LocalOperand { name, .. } => {
-								take_reg(&mut ctx, &name);
-								// blockloop.push(BfOp::Mov(0, 0));
-							}
-							llvm_ir::Operand::ConstantOperand(_) => {
-								// blockloop.push(BfOp::AddI(0, v as u8));
-							}
+	// only ever the one function around
+	let ret_pad_width = 1 + 1 + RET_LANDING_PAD;
+	let layout = vec![Cell::MainLoop, Cell::FuncMask(name.to_string())];
+	let nargs = func.parameters.len();
+
+	let landing = 1;
+	let top = landing + ret_pad_width + nargs + STACK_PTR_W + 1;
+
+	let frames = Frames {
+		globals: &[],
+		layout: &layout,
+		ret_pad_width,
+		ret_cells: &[],
+		stack_guard: None,
+		ret_to: Some(top),
+		park: landing,
+	};
 
-							_ => unimplemented!("ignoring meta?"),
-						};
-					}
+	let mut warnings = vec![];
+	let (_, st_width) = build_func(&frames, opts, 0, func, &mut vec![])?;
+	let (code, _) = build_func(&frames, opts, st_width, func, &mut warnings)?;
+
+	let root = vec![
+		BfOp::Comment(format!("eof: {}", opts.eof_value)),
+		BfOp::Right(top - 1),
+		BfOp::AddI(fixed_addr(0), (top - 1) as u8), // stack ptr
+		BfOp::Right(1),
+		BfOp::AddI(fixed_addr(0), 1),
+		BfOp::AddI(fixed_addr(code.mask), 1),
+		BfOp::AddI(fixed_addr(2), 1), // b0
+		BfOp::Loop(fixed_addr(0), code.flatten()),
+	];
+
+	let mut out = String::new();
+	printast(&mut out, root);
 
-					blockloop.append(&mut zero_frame(&mut ctx));
+	Ok(FunctionBf {
+		code: out,
+		args: (0..nargs).map(|i| top - 2 - i).collect(),
+		ret: match func.return_type.as_ref() {
+			llvm_ir::Type::VoidType => None,
+			_ => Some(0),
+		},
+		warnings,
+	})
+}
+
+// Put the wall far enough out that `limit` calls deep still fits in front of
+// it, or as deep as will if the tape runs out first. Only what can actually be
+// called counts, the helpers in stdfuck.h are wide and usually left alone.
+// Gives back the guard along with where the wall starts and how many cells it
+// takes.
+fn build_wall(
+	module: &llvm_ir::Module,
+	calls: &CallGraph,
+	widths: &[(String, usize)],
+	limit: usize,
+	global_addr_at: usize,
+	ret_pad_width: usize,
+	warnings: &mut Vec<Diagnostic>,
+) -> Option<(StackGuard, usize, usize)> {
+	let width_of = |name: &str| {
+		widths.iter().find(|(n, _)| n == name).map_or(0, |(_, w)| *w)
+	};
+	let params_of = |name: &str| {
+		module
+			.functions
+			.iter()
+			.find(|f| f.name == name)
+			.map_or(0, |f| f.parameters.len())
+	};
+
+	// how much further out a call puts the callee's frame, tops
+	let stride = calls
+		.funcs
+		.iter()
+		.flat_map(|f| {
+			f.calls.iter().map(move |c| {
+				width_of(&f.name) + ret_pad_width + 1 + params_of(c) + STACK_PTR_W
+			})
+		})
+		.max()?;
+	let widest = calls
+		.funcs
+		.iter()
+		.flat_map(|f| f.calls.iter())
+		.map(|c| width_of(c))
+		.chain(std::iter::once(width_of("main")))
+		.max()
+		.unwrap();
+
+	let top = global_addr_at + ret_pad_width + STACK_PTR_W + 1;
+	let first = top + widest + 1;
+
+	// every cell up to the far end of the wall has to be reachable by a
+	// pointer, the way back down counts it out in a single cell too
+	let room = match FRAME_CELLS.checked_sub(first + stride) {
+		Some(room) => room / stride,
+		None => {
+			warnings.push(Diagnostic {
+				func: "stack".to_string(),
+				block: "limit".to_string(),
+				msg: "no room left on the tape for a stack guard, leaving it out"
+					.to_string(),
+				loc: None,
+			});
+			return None;
+		}
+	};
+
+	let limit = match limit > room {
+		true => {
+			warnings.push(Diagnostic {
+				func: "stack".to_string(),
+				block: "limit".to_string(),
+				msg: format!(
+					"only room for {} calls deep, guarding at that instead of {}",
+					room, limit
+				),
+				loc: None,
+			});
+			room
+		}
+		false => limit,
+	};
 
-					blockloop.push(BfOp::SubI(fixed_addr(0), 1));
-					blockloop.push(BfOp::Tag(
-						fixed_addr(0),
-						"dead_frame".to_string(),
-					));
+	let at = first + limit * stride;
+	Some((
+		StackGuard {
+			widths: widths.to_vec(),
+			reach: at - global_addr_at,
+		},
+		at,
+		stride,
+	))
+}
 
-					blockloop.push(BfOp::SubI(fixed_addr(ownfid), 1));
+// A function's code before it's folded into the main loop. The prologue has
+// the ret landing pad and tags, then each block sits behind its own mask.
+struct FuncOps {
+	name: String,
+	mask: usize,
+	layout: Layout,
+	prologue: Vec<BfOp>,
+	blocks: Vec<BlockOps>,
+}
 
-					blockloop.push(BfOp::Left(1));
-					blockloop.push(BfOp::Zero(fixed_addr(0)));
-					blockloop.push(BfOp::Right(1));
+struct BlockOps {
+	name: String,
+	mask: usize,
+	ops: Vec<BfOp>,
+}
 
-					// TODO(turbio): well relying on fixed arg lengths is prolly
-					// not gonna work out. Prolly aught to copy args right into
-					// the stack and have an entry block lay em out right.
-					blockloop.push(BfOp::Left(
-						func.parameters.len() + ret_pad_width + 1 + STACK_PTR_W,
-					));
-				}
-				_ => unimplemented!("soon? {:?}", block.term),
-			};
+impl FuncOps {
+	// Blocks go in the order llvm had them, so a br to the next one is its
+	// mask getting set before this one's ] and its [ coming straight up, no
+	// lap around the function loop. Only branching backwards costs a lap.
+	// Blocks with nothing but a br from the one before are already merged
+	// away by merge_straight_blocks. That leaves the call continuations, and
+	// those keep their mask so the callee's ret can find them.
+	fn flatten(&self) -> Vec<BfOp> {
+		let mut funcloop = self.prologue.clone();
+		for b in self.blocks.iter() {
+			funcloop.push(BfOp::Tag(fixed_addr(b.mask), format!("B:{}", b.name)));
+			funcloop.push(BfOp::Loop(fixed_addr(b.mask), b.ops.clone()));
 		}
 
-		funcloop.push(BfOp::Tag(fixed_addr(bid), format!("B:{}", block.name)));
-		funcloop.push(BfOp::Loop(fixed_addr(bid), blockloop));
+		vec![
+			BfOp::Tag(fixed_addr(self.mask), self.name.clone()),
+			BfOp::Loop(fixed_addr(self.mask), funcloop),
+		]
 	}
 
-	let mut i = ctx.layout.len() + 10;
+	fn to_map(&self, width: usize) -> FrameJson {
+		let free = self.layout.iter().enumerate().filter_map(|(i, c)| match c {
+			Cell::Free => Some(i),
+			_ => None,
+		});
 
-	for a in ctx.addrs.iter() {
-		if match a.v.borrow().clone() {
-			Addrt::Named(_) => true,
-			_ => false,
-		} {
-			a.v.replace(Addrt::Fixed(i));
-			i += 1;
+		FrameJson {
+			name: self.name.clone(),
+			mask: self.mask,
+			width,
+			cells: self.layout.iter().map(cell_to_json).collect(),
+			scratch: free.chain(self.layout.len()..width).collect(),
 		}
 	}
 
-	return (
-		vec![
-			BfOp::Tag(fixed_addr(ownfid), func.name.clone()),
-			BfOp::Loop(fixed_addr(ownfid), funcloop),
-		],
-		ctx.layout.len(),
-	);
+	fn to_json(&self) -> FuncJson {
+		FuncJson {
+			name: self.name.clone(),
+			mask: self.mask,
+			cells: self.layout.iter().map(cell_to_json).collect(),
+			prologue: self.prologue.iter().map(op_to_json).collect(),
+			blocks: self
+				.blocks
+				.iter()
+				.map(|b| BlockJson {
+					name: b.name.clone(),
+					mask: b.mask,
+					ops: b.ops.iter().map(op_to_json).collect(),
+				})
+				.collect(),
+		}
+	}
 }
 
-pub fn compile(path: &Path) -> String {
-	const RET_LANDING_PAD: usize = 1;
+// Bump whenever the shape of OpsDump changes in a way readers would notice
+pub const OPS_VERSION: u32 = 3;
+
+// Same deal for CellMap
+pub const MAP_VERSION: u32 = 1;
+
+// Which tape cell holds what, for anybody staring at a running program. Frame
+// offsets count from the frame's cell 0, its main loop flag. Everything here
+// is what --emit=map writes out.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CellMap {
+	pub version: u32,
+	// tape cells, cell 0 is kept empty so none of them look like null
+	pub globals: Vec<GlobalJson>,
+	// tape cell main's frame starts at, the frames of whatever it calls pile
+	// up to the right of it
+	pub main_frame_at: usize,
+	pub frames: Vec<FrameJson>,
+}
 
-	let path = path.canonicalize().unwrap();
-	let mut module = llvm_ir::Module::from_bc_path(path).unwrap();
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GlobalJson {
+	pub name: String,
+	pub at: usize,
+	pub len: usize,
+}
 
-	calls_terminate_blocks(&mut module);
-	calls_never_in_first_block(&mut module);
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FrameJson {
+	pub name: String,
+	// the function's own mask cell
+	pub mask: usize,
+	// every cell the frame takes up, named or not
+	pub width: usize,
+	// indexed by cell, anything past the end is scratch
+	pub cells: Vec<CellJson>,
+	// cells only ever borrowed for the length of one instruction
+	pub scratch: Vec<usize>,
+}
 
-	let funcns = module.functions.len();
+// Everything codegen produced, laid out the way it gets rendered: the header
+// up to and including the runtime init, every function in main loop order,
+// then whatever cleans up after the main loop. All cells are resolved to
+// frame offsets so this is exactly what --emit=ops writes out.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OpsDump {
+	pub version: u32,
+	pub header: Vec<OpJson>,
+	pub functions: Vec<FuncJson>,
+	pub footer: Vec<OpJson>,
+	// rendered code goes through peephole too
+	pub peephole: bool,
+}
 
-	let mut root: Vec<BfOp> = vec![];
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FuncJson {
+	pub name: String,
+	pub mask: usize,
+	// what each cell of the frame holds, indexed by cell
+	pub cells: Vec<CellJson>,
+	pub prologue: Vec<OpJson>,
+	pub blocks: Vec<BlockJson>,
+}
 
-	let ret_pad_width = 1 + funcns + RET_LANDING_PAD;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BlockJson {
+	pub name: String,
+	pub mask: usize,
+	pub ops: Vec<OpJson>,
+}
 
-	let mut layout: Layout = vec![Cell::MainLoop];
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CellJson {
+	MainLoop,
+	FuncMask { name: String },
+	BlockMask { name: String },
+	Alloc { name: String },
+	Reg { name: String, multi_use: bool },
+	Free,
+}
 
-	let mut ctx = Ctx {
-		// TODO
-		layout: layout.clone(),
-		addrs: Vec::<Addr>::new(),
-		ret_pad_width: None,
-		stack_width: None,
-		retpad_addr: None,
-		entry_block_addr: None,
-		ownfid: None,
-		globals: Vec::<GlobalMap>::new(),
-	};
+// BfOp with every address resolved to its cell
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum OpJson {
+	Right { n: usize },
+	Left { n: usize },
+	Loop2 { cell: usize, exit: usize, body: Vec<OpJson> },
+	AddI { cell: usize, n: u8 },
+	SubI { cell: usize, n: u8 },
+	Dup { from: usize, to: (usize, usize) },
+	Mov { from: usize, to: usize },
+	Putch { cell: usize },
+	Getch { cell: usize },
+	Zero { cell: usize },
+	Loop { cell: usize, body: Vec<OpJson> },
+	Tag { cell: usize, name: String },
+	Comment { text: String },
+	Src { func: String, block: String, instr: String },
+	Nop,
+}
 
-	let mut global_addr_at: u8 = 0; 
-	// load globals into beginning of address space
-	for g in module.global_vars.iter() {
-		let mut len = 0;
-		match g.initializer.as_ref().unwrap().deref() {
-			llvm_ir::constant::Constant::Array{element_type, elements} => {
-				len = elements.len() as u8;
-				for e in elements.iter() {
-					match e.deref() {
-						llvm_ir::constant::Constant::Int{bits, value} => {
-							root.push(BfOp::AddI(fixed_addr(0), *value as u8));
-							root.push(BfOp::Right(1));
-						},
-						_ => unimplemented!("lol")
-					}
-				}
-			}
+fn cell_to_json(c: &Cell) -> CellJson {
+	match c {
+		Cell::MainLoop => CellJson::MainLoop,
+		Cell::FuncMask(n) => CellJson::FuncMask { name: n.clone() },
+		Cell::BlockMask(n) => CellJson::BlockMask { name: n.to_string() },
+		Cell::Alloc(n) => CellJson::Alloc { name: n.to_string() },
+		Cell::Reg { n, multi_use } => CellJson::Reg {
+			name: n.to_string(),
+			multi_use: *multi_use,
+		},
+		Cell::Borrowed(c) => cell_to_json(c),
+		Cell::Free => CellJson::Free,
+	}
+}
 
-			_ => unimplemented!("o {:?}", g.initializer),
-		}
+fn op_to_json(op: &BfOp) -> OpJson {
+	let a = |a: &Addr| resaddr(a.clone());
+	let body = |ops: &Vec<BfOp>| ops.iter().map(op_to_json).collect();
 
-		ctx.globals.push(GlobalMap{ name: g.name.clone(), addr: global_addr_at });
-		global_addr_at += len;
+	match op {
+		BfOp::Right(n) => OpJson::Right { n: *n },
+		BfOp::Left(n) => OpJson::Left { n: *n },
+		BfOp::Loop2(a1, a2, ops) => OpJson::Loop2 {
+			cell: a(a1),
+			exit: a(a2),
+			body: body(ops),
+		},
+		BfOp::AddI(c, n) => OpJson::AddI { cell: a(c), n: *n },
+		BfOp::SubI(c, n) => OpJson::SubI { cell: a(c), n: *n },
+		BfOp::Dup(f, t1, t2) => OpJson::Dup {
+			from: a(f),
+			to: (a(t1), a(t2)),
+		},
+		BfOp::Mov(f, t) => OpJson::Mov { from: a(f), to: a(t) },
+		BfOp::Putch(c) => OpJson::Putch { cell: a(c) },
+		BfOp::Getch(c) => OpJson::Getch { cell: a(c) },
+		BfOp::Zero(c) => OpJson::Zero { cell: a(c) },
+		BfOp::Loop(c, ops) => OpJson::Loop {
+			cell: a(c),
+			body: body(ops),
+		},
+		BfOp::Tag(c, name) => OpJson::Tag {
+			cell: a(c),
+			name: name.clone(),
+		},
+		BfOp::Comment(text) => OpJson::Comment { text: text.clone() },
+		BfOp::Src(l) => OpJson::Src {
+			func: l.func.clone(),
+			block: l.block.clone(),
+			instr: l.instr.clone(),
+		},
+		BfOp::Nop => OpJson::Nop,
 	}
+}
 
-	root.push(BfOp::Right(ret_pad_width + STACK_PTR_W));
-	root.push(BfOp::AddI(
-		fixed_addr(0),
-		ret_pad_width as u8 + STACK_PTR_W as u8,
-	)); // stack base address
-	root.push(BfOp::Right(1));
-	root.push(BfOp::Comment("runtime init:".to_string()));
-	root.push(BfOp::Tag(fixed_addr(0), "===TOP FRAME".to_string()));
-	root.push(BfOp::AddI(fixed_addr(0), 1));
+fn op_from_json(op: &OpJson) -> BfOp {
+	let body = |ops: &Vec<OpJson>| ops.iter().map(op_from_json).collect();
 
-	// stacks frames are laid out as:
-	// <main loop bit> | <func mask> | <block mask> | <registers> | <scratch>
-	// the main loop bit: is always `1` and part of the runtime's flow control
-	// func/block masks: control the current block of execution
-	//
-	// all the allocas live for the lifetime of the function. Sorta are actually
-	// memory addresses but like that's tricky.
-	// let mut allocs: Vec<&llvm_ir::Name> = vec![];
-	//
-	// live registers available on the stack produced by an instruction and
-	// yet to be consumed by a following instruction. Used as a map for
-	// allocations where each cell holds a llvm register name.
-	//
-	// TODO(turbio): for now onstack registers only live between the
-	// instruction producing them and the instruction consuming them.
-	// Multiple uses is unimplemented.
-	for func in module.functions.iter() {
-		layout.push(Cell::FuncMask(func.name.to_string()))
+	match op {
+		OpJson::Right { n } => BfOp::Right(*n),
+		OpJson::Left { n } => BfOp::Left(*n),
+		OpJson::Loop2 { cell, exit, body: b } => {
+			BfOp::Loop2(fixed_addr(*cell), fixed_addr(*exit), body(b))
+		}
+		OpJson::AddI { cell, n } => BfOp::AddI(fixed_addr(*cell), *n),
+		OpJson::SubI { cell, n } => BfOp::SubI(fixed_addr(*cell), *n),
+		OpJson::Dup { from, to } => BfOp::Dup(
+			fixed_addr(*from),
+			fixed_addr(to.0),
+			fixed_addr(to.1),
+		),
+		OpJson::Mov { from, to } => {
+			BfOp::Mov(fixed_addr(*from), fixed_addr(*to))
+		}
+		OpJson::Putch { cell } => BfOp::Putch(fixed_addr(*cell)),
+		OpJson::Getch { cell } => BfOp::Getch(fixed_addr(*cell)),
+		OpJson::Zero { cell } => BfOp::Zero(fixed_addr(*cell)),
+		OpJson::Loop { cell, body: b } => BfOp::Loop(fixed_addr(*cell), body(b)),
+		OpJson::Tag { cell, name } => BfOp::Tag(fixed_addr(*cell), name.clone()),
+		OpJson::Comment { text } => BfOp::Comment(text.clone()),
+		OpJson::Src { func, block, instr } => BfOp::Src(Loc {
+			func: func.clone(),
+			block: block.clone(),
+			instr: instr.clone(),
+			src: None,
+		}),
+		OpJson::Nop => BfOp::Nop,
 	}
+}
 
-	let mainfid = layout
+// Brainfuck for a dump, the same bytes compile would have produced for it
+pub fn render_ops(dump: &OpsDump) -> Result<String, String> {
+	if dump.version != OPS_VERSION {
+		return Err(format!(
+			"ops dump is version {}, this bfcc reads version {}",
+			dump.version, OPS_VERSION
+		));
+	}
+
+	let mut root: Vec<BfOp> = dump.header.iter().map(op_from_json).collect();
+
+	let mainloop = dump
+		.functions
 		.iter()
-		.position(|c| match c {
-			Cell::FuncMask(n) => n == "main",
-			_ => false,
+		.flat_map(|f| {
+			FuncOps {
+				name: f.name.clone(),
+				mask: f.mask,
+				layout: vec![],
+				prologue: f.prologue.iter().map(op_from_json).collect(),
+				blocks: f
+					.blocks
+					.iter()
+					.map(|b| BlockOps {
+						name: b.name.clone(),
+						mask: b.mask,
+						ops: b.ops.iter().map(op_from_json).collect(),
+					})
+					.collect(),
+			}
+			.flatten()
 		})
-		.unwrap();
+		.collect();
+	root.push(BfOp::Loop(fixed_addr(0), mainloop));
+	root.extend(dump.footer.iter().map(op_from_json));
 
-	root.push(BfOp::Tag(fixed_addr(mainfid), "main".to_string()));
-	root.push(BfOp::AddI(fixed_addr(mainfid), 1));
-	root.push(BfOp::Tag(fixed_addr(1 + funcns), "main/b0".to_string()));
-	root.push(BfOp::AddI(fixed_addr(1 + funcns), 1));
+	let mut out = String::from("");
+	printast(&mut out, root);
+	if dump.peephole {
+		out = peephole(&out).0;
+	}
+	Ok(out)
+}
 
-	let mut mainloop: Vec<BfOp> = vec![];
+// Guess at what a function's code costs without running it. ops is every bf
+// instruction it compiles to, pointer travel included. steps is one visit to
+// each of its blocks with every loop in there going round loop_weight times,
+// nested ones multiplying. The function and block masks are loops too but
+// they only ever go round once per visit so they're not weighted.
+#[derive(Debug, Clone)]
+pub struct Cost {
+	pub func: String,
+	pub ops: usize,
+	pub steps: usize,
+}
 
-	// ret pad is always the same width with: main loop + function masks +
-	// landing pad mask
-	let ret_pad_width = 1 + funcns + RET_LANDING_PAD;
+impl fmt::Display for Cost {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}: {} ops ~{} steps", self.func, self.ops, self.steps)
+	}
+}
 
-	for func in module.functions.iter() {
-		let (_, st_width) = build_func(&ctx.globals, &layout, ret_pad_width, 0, func);
-		let (mut code, _) = build_func(&ctx.globals, &layout, ret_pad_width, st_width, func);
+fn estimate_cost(func: &str, code: &str, loop_weight: usize) -> Cost {
+	const MASK_DEPTH: u32 = 2;
 
-		mainloop.append(&mut code);
-	}
+	let weight = |depth: u32| {
+		loop_weight.saturating_pow(depth.saturating_sub(MASK_DEPTH))
+	};
 
-	root.push(BfOp::Loop(fixed_addr(0), mainloop));
+	let mut depth = 0;
+	let mut ops = 0;
+	let mut steps: usize = 0;
+	for c in code.chars() {
+		// a [ is checked once on the way in, its ] every time around
+		match c {
+			'[' => {
+				steps = steps.saturating_add(weight(depth));
+				depth += 1;
+			}
+			']' => {
+				steps = steps.saturating_add(weight(depth));
+				depth -= 1;
+			}
+			'+' | '-' | '<' | '>' | '.' | ',' => {
+				steps = steps.saturating_add(weight(depth));
+			}
+			_ => continue,
+		}
 
-	let mut out = String::from("");
-	printast(&mut out, root);
-	out
+		ops += 1;
+	}
+
+	Cost {
+		func: func.to_string(),
+		ops,
+		steps,
+	}
 }
 
-fn printast(out: &mut String, ast: Vec<BfOp>) {
-	printasti(out, ast, 0, 0);
+// gives back where in out each Src landed, as a byte offset
+fn printast(out: &mut String, ast: Vec<BfOp>) -> Vec<(usize, Loc)> {
+	let mut marks = vec![];
+	printasti(out, ast, 0, 0, &mut marks);
+	marks
 }
 
 fn printasti(
@@ -2941,16 +7931,28 @@ fn printasti(
 	ast: Vec<BfOp>,
 	cstart: usize,
 	i: usize,
+	marks: &mut Vec<(usize, Loc)>,
 ) -> usize {
 	let mut cursor = cstart;
 	for ins in ast {
-		cursor = printinstri(out, ins, cursor, i);
+		cursor = printinstri(out, ins, cursor, i, marks);
 	}
 
 	cursor
 }
 
-fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
+// cursor is the cell the head is on relative to the frame, every op only
+// walks from there to the cell it wants. A loop always leaves the head back on
+// its own cell (or the latter one for Loop2) so whatever follows the ] knows
+// where it is no matter how many times the body ran. Right/Left move the
+// frame and not the head, so they leave cursor alone.
+fn printinstri(
+	out: &mut String,
+	ins: BfOp,
+	cstart: usize,
+	i: usize,
+	marks: &mut Vec<(usize, Loc)>,
+) -> usize {
 	let mut cursor = cstart;
 
 	let ind = "    ".repeat(i);
@@ -2984,6 +7986,11 @@ fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
 			cursor = resaddr(addr.clone());
 		}
 
+		BfOp::Getch(addr) => {
+			write!(out, "{},", cmov(cursor, resaddr(addr.clone()))).unwrap();
+			cursor = resaddr(addr.clone());
+		}
+
 		BfOp::AddI(addr, n) => {
 			write!(
 				out,
@@ -3006,17 +8013,8 @@ fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
 		}
 
 		BfOp::Tag(addr, s) => {
-			let s: String = s
-				.chars()
-				.map(|x| match x {
-					'+' | '-' | '.' | ',' | ' ' => '_',
-					'<' => '(',
-					'>' => ')',
-					'[' => '{',
-					']' => '}',
-					_ => x,
-				})
-				.collect();
+			let s = bfsan(s).replace(' ', "_");
+			assert!(!s.contains(|c| BF_OPS.contains(c)), "op in tag: {}", s);
 			write!(out, "{}#{}", cmov(cursor, resaddr(addr.clone())), s)
 				.unwrap();
 			cursor = resaddr(addr.clone());
@@ -3064,7 +8062,9 @@ fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
 			cursor = from_a;
 		}
 		BfOp::Comment(s) => {
-			write!(out, "{}", bfsan(s)).unwrap();
+			let s = bfsan(s);
+			assert!(!s.contains(|c| BF_OPS.contains(c)), "op in comment: {}", s);
+			write!(out, "{}", s).unwrap();
 		}
 
 		BfOp::Loop(addr, ops) => {
@@ -3072,7 +8072,7 @@ fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
 			cursor = resaddr(addr.clone());
 
 			write!(out, "{}[\n", m).unwrap();
-			cursor = printasti(out, ops, cursor, i + 1);
+			cursor = printasti(out, ops, cursor, i + 1, marks);
 
 			let m = cmov(cursor, resaddr(addr.clone()));
 			cursor = resaddr(addr.clone());
@@ -3085,7 +8085,7 @@ fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
 			cursor = resaddr(addr1.clone());
 
 			write!(out, "{}[\n", m).unwrap();
-			cursor = printasti(out, ops, cursor, i + 1);
+			cursor = printasti(out, ops, cursor, i + 1, marks);
 
 			let m = cmov(cursor, resaddr(addr2.clone()));
 			cursor = resaddr(addr2.clone());
@@ -3093,6 +8093,7 @@ fn printinstri(out: &mut String, ins: BfOp, cstart: usize, i: usize) -> usize {
 			write!(out, "{}{}]", ind, m).unwrap();
 		}
 
+		BfOp::Src(loc) => marks.push((out.len(), loc)),
 		BfOp::Nop => {}
 	}
 
@@ -3109,18 +8110,181 @@ fn unlop(op: &llvm_ir::Operand) -> &llvm_ir::Name {
 	}
 }
 
+// Every constant that ends up in a cell goes through here. Cells are 8 bits so
+// wider constants get chopped down. Stuff like i32 -1 wraps around to the same
+// byte it would've been as an i8 so that's fine, anything else that changes
+// value on the way down gets a warning pointing at the instruction.
+fn cell_const(ctx: &mut Ctx, op: &llvm_ir::Operand) -> u8 {
+	let (bits, v) = match op {
+		llvm_ir::Operand::ConstantOperand(c) => match c.deref() {
+			llvm_ir::constant::Constant::Int { bits, value } => (*bits, *value),
+			llvm_ir::constant::Constant::GlobalReference { .. }
+			| llvm_ir::constant::Constant::GetElementPtr(_)
+			| llvm_ir::constant::Constant::BitCast(_) => {
+				return global_addr(ctx, c);
+			}
+			_ => (64, uncop(ctx, op)),
+		},
+		_ => (64, uncop(ctx, op)),
+	};
+
+	let signed = if bits < 64 && (v >> (bits - 1)) & 1 == 1 {
+		(v | (!0u64 << bits)) as i64
+	} else {
+		v as i64
+	};
+
+	let cell = v as u8;
+
+	// an i16's high byte goes in a cell of its own
+	if bits != 16 && v > 255 && !(-128..0).contains(&signed) {
+		let loc = ctx.loc.clone().unwrap_or(Loc {
+			func: "?".to_string(),
+			block: "?".to_string(),
+			instr: "?".to_string(),
+			src: None,
+		});
+
+		if ctx.strict_consts {
+			ctx.too_wide.push(Diagnostic {
+				func: loc.func,
+				block: loc.block,
+				msg: format!(
+					"constant {} doesn't fit in a cell in `{}`",
+					v, loc.instr
+				),
+				loc: loc.src,
+			});
+		} else {
+			ctx.warnings.push(Diagnostic {
+				func: loc.func,
+				block: loc.block,
+				msg: format!(
+					"constant {} doesn't fit in a cell, truncated to {} in `{}`",
+					v, cell, loc.instr
+				),
+				loc: loc.src,
+			});
+		}
+	}
+
+	cell
+}
+
+// Where a global, or a constant gep/bitcast of one, lives on the tape. The
+// globals are laid out one cell per element from the very start of the tape so
+// this is all known at compile time.
+fn global_addr(ctx: &mut Ctx, c: &llvm_ir::constant::Constant) -> u8 {
+	match c {
+		llvm_ir::constant::Constant::GlobalReference { name, .. } => {
+			match ctx.globals.iter().find(|g| &g.name == name) {
+				Some(g) => g.addr,
+				None => unimplemented!("pointer to {}, which isn't a global", name),
+			}
+		}
+		llvm_ir::constant::Constant::BitCast(bc) => global_addr(ctx, &bc.operand),
+		llvm_ir::constant::Constant::GetElementPtr(gep) => {
+			let base = global_addr(ctx, &gep.address);
+			let indices = gep
+				.indices
+				.iter()
+				.map(|i| match i.deref() {
+					llvm_ir::constant::Constant::Int { value, .. } => *value,
+					_ => unimplemented!("gep index {}", i),
+				})
+				.collect::<Vec<_>>();
+
+			let len = global_len(ctx, &gep.address);
+			let at = global_index(ctx, len, &indices);
+			base.wrapping_add(at as u8)
+		}
+		_ => unimplemented!("how tf we gonna point at {}", c),
+	}
+}
+
+fn global_len(ctx: &Ctx, c: &llvm_ir::constant::Constant) -> Option<u8> {
+	match c {
+		llvm_ir::constant::Constant::GlobalReference { name, .. } => {
+			ctx.globals.iter().find(|g| &g.name == name).map(|g| g.len)
+		}
+		_ => None,
+	}
+}
+
+// Cell offset of a constant gep into a global. `indices` follows llvm so the
+// first one steps over whole arrays and the second picks the element. Anything
+// landing past the end (one past is fine, that's just an end pointer) gets
+// warned about since it'll be reading or trampling whatever's next door.
+fn global_index(ctx: &mut Ctx, len: Option<u8>, indices: &[u64]) -> u64 {
+	let len = len.unwrap_or(1) as u64;
+	let at = match indices {
+		[a] => a.wrapping_mul(len),
+		[a, e] => a.wrapping_mul(len).wrapping_add(*e),
+		_ => unimplemented!("gep with {} indices", indices.len()),
+	};
+
+	if at > len {
+		let loc = ctx.loc.clone().unwrap_or(Loc {
+			func: "?".to_string(),
+			block: "?".to_string(),
+			instr: "?".to_string(),
+			src: None,
+		});
+
+		ctx.warnings.push(Diagnostic {
+			func: loc.func,
+			block: loc.block,
+			msg: format!(
+				"index {} is out of bounds of a {} element global in `{}`",
+				at as i64, len, loc.instr
+			),
+			loc: loc.src,
+		});
+	}
+
+	at
+}
+
+// runtime indexed geps into a global get the constant bits checked up front,
+// the rest is just pointer math at runtime
+fn check_global_gep(ctx: &mut Ctx, gep: &llvm_ir::instruction::GetElementPtr) {
+	let c = match &gep.address {
+		llvm_ir::Operand::ConstantOperand(c) => c,
+		_ => return,
+	};
+
+	let indices = gep
+		.indices
+		.iter()
+		.map(|i| match i {
+			llvm_ir::Operand::ConstantOperand(c) => match c.deref() {
+				llvm_ir::constant::Constant::Int { value, .. } => Some(*value),
+				_ => None,
+			},
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+
+	let len = global_len(ctx, c);
+
+	match indices[..] {
+		[Some(a), None] if a != 0 => {
+			unimplemented!("leading gep index {} into a global", a as i64)
+		}
+		_ if indices.iter().all(|i| i.is_some()) => {
+			let known = indices.iter().map(|i| i.unwrap()).collect::<Vec<_>>();
+			global_index(ctx, len, &known);
+		}
+		_ => {}
+	}
+}
+
 // un constant operand
 fn uncop(ctx: &Ctx, op: &llvm_ir::Operand) -> u64 {
 	match op {
 		llvm_ir::Operand::ConstantOperand(c) => match c.deref() {
 			llvm_ir::constant::Constant::Int { value, .. } => *value,
 			llvm_ir::constant::Constant::Null { .. } => 0,
-			llvm_ir::constant::Constant::GetElementPtr(gep) => {
-				eprintln!("{:?}",gep.address);
-				eprintln!("{:?}",gep.indices);
-				//ctx.globals.iter().find(|g| g.name == gep.address.)
-				0
-			},
 			_ => unimplemented!("how tf we gonna store that {:?}", c.deref()),
 		},
 		_ => {
@@ -3129,27 +8293,90 @@ fn uncop(ctx: &Ctx, op: &llvm_ir::Operand) -> u64 {
 	}
 }
 
+// unnamed globals and functions come numbered, same as llvm prints them
 fn n2nam(n: &llvm_ir::Name) -> String {
 	match n {
 		llvm_ir::Name::Name(n) => *n.clone(),
-		llvm_ir::Name::Number(_) => {
-			unimplemented!("we only deal in names here")
-		}
+		llvm_ir::Name::Number(n) => n.to_string(),
 	}
 }
 
-fn n2usize(n: &llvm_ir::Name) -> usize {
-	match n {
-		llvm_ir::Name::Number(n) => *n,
-		_ => unimplemented!("we only deal in usize"),
+const BF_OPS: &str = "+-<>[].,";
+
+// Comments and tags went through bfsan so anything in BF_OPS is code
+pub fn strip_comments(code: &str) -> String {
+	code.chars().filter(|c| BF_OPS.contains(*c)).collect()
+}
+
+// What's left of code once ops that undo each other are gone: a + right
+// against a -, a > against a <, and an empty loop right after another loop
+// closed, since the cell it checks is 0 by then. Comments in between don't
+// count as being in the way but every other op does, brackets included, so
+// nothing ever cancels across a loop boundary. Also gives back where each op
+// of code ended up, anything removed pointing at whatever op came next, plus
+// one more for the end.
+fn peephole(code: &str) -> (String, Vec<usize>) {
+	let chars: Vec<char> = code.chars().collect();
+	let mut gone = vec![false; chars.len()];
+	// surviving ops so far, as indices into chars
+	let mut kept: Vec<usize> = vec![];
+
+	for (i, c) in chars.iter().enumerate() {
+		if !BF_OPS.contains(*c) {
+			continue;
+		}
+
+		let top = kept.last().map(|t| chars[*t]);
+		let undoes = matches!(
+			(top, c),
+			(Some('+'), '-')
+				| (Some('-'), '+')
+				| (Some('>'), '<')
+				| (Some('<'), '>')
+		);
+		let dead_loop = *c == ']'
+			&& top == Some('[')
+			&& kept.len() >= 2
+			&& chars[kept[kept.len() - 2]] == ']';
+
+		if undoes || dead_loop {
+			gone[kept.pop().unwrap()] = true;
+			gone[i] = true;
+		} else {
+			kept.push(i);
+		}
 	}
+
+	let mut out = String::new();
+	let mut moved = vec![];
+	let mut at = 0;
+	for (c, gone) in chars.iter().zip(gone.iter()) {
+		if BF_OPS.contains(*c) {
+			moved.push(at);
+			if !gone {
+				at += 1;
+			}
+		}
+		if !gone {
+			out.push(*c);
+		}
+	}
+	moved.push(at);
+
+	(out, moved)
 }
 
+// comments can say anything as long as it isn't one of the eight ops, the
+// brackets at least keep looking like brackets
 fn bfsan(s: String) -> String {
-	s.replace(",", "_")
-		.replace("[", "_")
-		.replace("]", "_")
-		.replace(".", "_")
-		.replace("+", "_")
-		.replace("-", "_")
+	s.chars()
+		.map(|c| match c {
+			'<' => '(',
+			'>' => ')',
+			'[' => '{',
+			']' => '}',
+			'+' | '-' | '.' | ',' => '_',
+			_ => c,
+		})
+		.collect()
 }
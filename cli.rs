@@ -1,17 +1,185 @@
 use std::env;
+use std::fs;
+use std::io;
+use std::io::Read;
 use std::process;
 use std::path::Path;
+use std::time;
 
-mod bfcc;
+extern crate brainfuq;
+extern crate serde_json;
+
+use brainfuq::bfcc;
 
 fn main() {
-	if env::args().len() != 2 {
-		eprintln!("expected a single argument: path to llvm bytecode");
+	let mut dump_ir = false;
+	let mut stats = false;
+	// Some(None) is ops to stdout instead of the code, Some(Some(path)) is
+	// the code as usual with the ops written off to the side
+	let mut emit_ops: Option<Option<String>> = None;
+	// where to write which cell is what, see bfcc::CellMap
+	let mut emit_map: Option<String> = None;
+	// where to write which instruction each op came from, see bfcc::SrcSpan
+	let mut emit_bfmap: Option<String> = None;
+	// where whatever would've gone to stdout goes instead
+	let mut output: Option<String> = None;
+	let mut verbose = false;
+	let mut opts = bfcc::Options::default();
+	let mut paths = vec![];
+
+	let mut args = env::args().skip(1);
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"-o" => {
+				output = Some(args.next().unwrap_or_else(|| {
+					eprintln!("-o takes a path");
+					process::exit(1);
+				}))
+			}
+			"-v" | "--verbose" => verbose = true,
+			"--dump-ir" => dump_ir = true,
+			"--stats" => stats = true,
+			"--no-wrap-safe" => opts.wrap_safe = false,
+			"--strict-consts" => opts.strict_consts = true,
+			"--check-scratch" => opts.check_scratch = true,
+			"--no-comments" => opts.no_comments = true,
+			"--no-peephole" => opts.peephole = false,
+			"--opt" => opts.opt = true,
+			"--emit=ops" => emit_ops = Some(None),
+			_ if arg.starts_with("--emit=ops:") => {
+				emit_ops = Some(Some(arg["--emit=ops:".len()..].to_string()))
+			}
+			_ if arg.starts_with("--emit=map:") => {
+				emit_map = Some(arg["--emit=map:".len()..].to_string())
+			}
+			_ if arg.starts_with("--emit=bfmap:") => {
+				emit_bfmap = Some(arg["--emit=bfmap:".len()..].to_string())
+			}
+			_ if arg.starts_with("--inline-threshold=") => {
+				let n = &arg["--inline-threshold=".len()..];
+				opts.inline_threshold = n.parse().unwrap_or_else(|_| {
+					eprintln!("bad inline threshold: {}", n);
+					process::exit(1);
+				});
+			}
+			_ if arg.starts_with("--loop-weight=") => {
+				let n = &arg["--loop-weight=".len()..];
+				opts.loop_weight = n.parse().unwrap_or_else(|_| {
+					eprintln!("bad loop weight: {}", n);
+					process::exit(1);
+				});
+			}
+			_ if arg.starts_with("--stack-limit=") => {
+				let n = &arg["--stack-limit=".len()..];
+				opts.stack_limit = n.parse().unwrap_or_else(|_| {
+					eprintln!("bad stack limit: {}", n);
+					process::exit(1);
+				});
+			}
+			_ if arg.starts_with("--eof=") => {
+				opts.eof_value = match &arg["--eof=".len()..] {
+					"0" => 0,
+					"255" => 255,
+					v => {
+						eprintln!("eof has to be 0 or 255, not {}", v);
+						process::exit(1);
+					}
+				};
+			}
+			_ => paths.push(arg),
+		}
+	}
+
+	if paths.len() != 1 {
+		eprintln!("usage: bfcc [-o path] [-v] [--dump-ir] [--stats] [--inline-threshold=N] [--eof=0|255] [--loop-weight=N] [--stack-limit=N] [--no-wrap-safe] [--strict-consts] [--check-scratch] [--no-comments] [--no-peephole] [--opt] [--emit=ops[:path]] [--emit=map:path] [--emit=bfmap:path] <path to llvm bytecode, - for stdin>");
 		process::exit(1);
 	}
 
-	let bcpath = env::args().nth(1).unwrap();
+	let started = time::Instant::now();
+
+	let module = match paths[0].as_str() {
+		// clang -emit-llvm -c -o - foo.c | bfcc -
+		"-" => {
+			let mut bytes = vec![];
+			io::stdin().read_to_end(&mut bytes).unwrap_or_else(|e| {
+				eprintln!("couldn't read stdin: {}", e);
+				process::exit(1);
+			});
+			bfcc::load_bytes(&bytes)
+		}
+		path => bfcc::load_module(Path::new(path)),
+	};
+
+	let res = module.and_then(|module| match dump_ir {
+		true => bfcc::dump_module_ir(module, &opts),
+		false => bfcc::compile_module(module, &opts).map(|out| {
+			for w in out.warnings.iter() {
+				eprintln!("warning: {}", w);
+			}
+			if verbose {
+				eprintln!(
+					"compiled {} in {:?}: {} brainfuck instructions, {} warnings",
+					paths[0],
+					started.elapsed(),
+					out.code.chars().filter(|c| "+-<>[].,".contains(*c)).count(),
+					out.warnings.len()
+				);
+			}
+			let ops = || serde_json::to_string_pretty(&out.ops).unwrap();
 
-	let bfcode = bfcc::compile(Path::new(&bcpath));
-	println!("{}", bfcode);
+			if let Some(Some(path)) = &emit_ops {
+				fs::write(path, ops()).unwrap_or_else(|e| {
+					eprintln!("couldn't write ops to {}: {}", path, e);
+					process::exit(1);
+				});
+			}
+
+			if let Some(path) = &emit_map {
+				let map = serde_json::to_string_pretty(&out.map).unwrap();
+				fs::write(path, map).unwrap_or_else(|e| {
+					eprintln!("couldn't write the cell map to {}: {}", path, e);
+					process::exit(1);
+				});
+			}
+
+			if let Some(path) = &emit_bfmap {
+				let map = serde_json::to_string_pretty(&out.src_map).unwrap();
+				fs::write(path, map).unwrap_or_else(|e| {
+					eprintln!("couldn't write the source map to {}: {}", path, e);
+					process::exit(1);
+				});
+			}
+
+			match stats {
+				_ if emit_ops == Some(None) => ops(),
+				true => format!(
+					"{}\n{}",
+					out.calls,
+					out.costs
+						.iter()
+						.map(|c| c.to_string())
+						.collect::<Vec<_>>()
+						.join("\n")
+				),
+				false => out.code,
+			}
+		}),
+	});
+
+	match (res, output) {
+		(Ok(out), None) => println!("{}", out),
+		(Ok(out), Some(path)) => {
+			fs::write(&path, format!("{}\n", out)).unwrap_or_else(|e| {
+				eprintln!("couldn't write {}: {}", path, e);
+				process::exit(1);
+			});
+			if verbose {
+				eprintln!("wrote {}", path);
+			}
+		}
+		(Err(e), _) => {
+			eprintln!("{}", e);
+			process::exit(1);
+		}
+	}
 }
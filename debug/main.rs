@@ -1,8 +1,11 @@
 use std::env;
 use std::fs;
 
+extern crate brainfuq;
 extern crate ncurses;
 
+use brainfuq::bf as interp;
+
 enum Color {
 	Normal,
 	Comment,
@@ -614,11 +617,44 @@ impl<'a> Debugger<'a> {
 	}
 }
 
+// Straight through without the ui, the trace gets the nearest comment in the
+// code added to each record on its way to the file
+fn run_traced(code: &str, input: &[u8], config: &interp::TraceConfig, path: &str) {
+	let ops = interp::bf_bytecode(code);
+	let mut records = vec![];
+	let res = interp::trace(
+		ops,
+		input,
+		interp::eof_of(code),
+		None,
+		config,
+		&mut records,
+	);
+
+	let records = String::from_utf8_lossy(&records);
+	if let Err(e) = fs::write(path, interp::annotate_trace(code, &records)) {
+		println!("couldn't write the trace to {}: {}", path, e);
+	}
+
+	match res {
+		Ok(r) => print!("{}", r.output),
+		Err(e) => println!("{}", e),
+	}
+}
+
 fn main() {
 	let mut pathstr = "".to_owned();
 	let mut bpa: String;
 	let mut input: String = "\0".to_string();
 	let mut bp: Option<&str> = None;
+	// off unless asked, ! is just another comment char otherwise
+	let mut bang_input = false;
+	// run headless writing a trace here instead of opening the debugger
+	let mut trace_path: Option<String> = None;
+	let mut trace = interp::TraceConfig {
+		on: interp::TraceOn::Putchar,
+		max_records: 100_000,
+	};
 
 	let mut skip = false;
 	for (i, arg) in env::args().enumerate().skip(1).by_ref() {
@@ -640,6 +676,41 @@ fn main() {
 		} else if arg == "-i" || arg == "-input" || arg == "--input" {
 			input = env::args().nth(i + 1).unwrap();
 			skip = true;
+		} else if arg == "--bang-input" {
+			bang_input = true;
+		} else if arg == "--trace" {
+			trace_path = env::args().nth(i + 1);
+			skip = true;
+		} else if arg == "--trace-every" {
+			let n = env::args().nth(i + 1).unwrap();
+			trace.on = match n.parse() {
+				Ok(n) if n > 0 => interp::TraceOn::Every(n),
+				_ => {
+					println!("bad trace interval: {}", n);
+					return;
+				}
+			};
+			skip = true;
+		} else if arg == "--trace-on" {
+			trace.on = match env::args().nth(i + 1).unwrap().as_str() {
+				"putchar" => interp::TraceOn::Putchar,
+				"jmpifz" => interp::TraceOn::JmpIfZ,
+				on => {
+					println!("can only trace on putchar or jmpifz, not {}", on);
+					return;
+				}
+			};
+			skip = true;
+		} else if arg == "--trace-max" {
+			let n = env::args().nth(i + 1).unwrap();
+			trace.max_records = match n.parse() {
+				Ok(n) => n,
+				_ => {
+					println!("bad trace cap: {}", n);
+					return;
+				}
+			};
+			skip = true;
 		} else {
 			pathstr = arg;
 		}
@@ -650,13 +721,26 @@ fn main() {
 		return;
 	}
 
-	let code = fs::read_to_string(pathstr).unwrap();
+	let mut code = fs::read_to_string(pathstr).unwrap();
+
+	if bang_input {
+		let (c, i) = interp::split_input(&code);
+		if let Some(i) = i.filter(|i| !i.is_empty()) {
+			input = i.to_string();
+		}
+		code = c.to_string();
+	}
 
 	if code.find(INSTRUCTS).is_none() {
 		println!("the given file doesn't have any code");
 		return;
 	}
 
+	if let Some(path) = trace_path {
+		run_traced(&code, input.as_bytes(), &trace, &path);
+		return;
+	}
+
 	// technically incorrect but at least it won't break rendering
 	let code = code.replace('\t', "    ");
 
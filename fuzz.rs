@@ -0,0 +1,342 @@
+// Random little C programs for verify's --fuzz. Everything is a uint8_t and
+// programs never read input, so the generator runs each statement as it
+// writes it and throws out anything that would step outside 0..=255 along the
+// way. That keeps the programs free of wrapping, dividing by 0 and the like
+// so whatever the native build prints is the right answer.
+//
+// The grammar only covers what bfcc handles, grow it along with bfcc.
+
+// xorshift, plenty random for this and the same everywhere for a given seed
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Rng {
+		// splitmix the seed so neighbouring seeds don't start out alike
+		let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		Rng((z ^ (z >> 31)) | 1)
+	}
+
+	pub fn next(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	// somewhere in lo..hi
+	pub fn range(&mut self, lo: usize, hi: usize) -> usize {
+		lo + (self.next() % (hi - lo) as u64) as usize
+	}
+
+	pub fn chance(&mut self, one_in: usize) -> bool {
+		self.range(0, one_in) == 0
+	}
+}
+
+const VARS: usize = 4;
+const MAX_DEPTH: usize = 2;
+const MAX_BLOCK: usize = 4;
+const TOP_STMTS: usize = 10;
+// give up on a statement that keeps stepping out of range after this many
+const TRIES: usize = 20;
+
+#[derive(Clone, Copy)]
+enum Op {
+	Add,
+	Sub,
+	Mul,
+	Div,
+	Rem,
+	And,
+	Or,
+	Xor,
+	Shl,
+	Shr,
+	Eq,
+	Ne,
+	Lt,
+	Gt,
+	Le,
+	Ge,
+}
+
+const OPS: &[(Op, &str)] = &[
+	(Op::Add, "+"),
+	(Op::Sub, "-"),
+	(Op::Mul, "*"),
+	(Op::Div, "/"),
+	(Op::Rem, "%"),
+	(Op::And, "&"),
+	(Op::Or, "|"),
+	(Op::Xor, "^"),
+	(Op::Shl, "<<"),
+	(Op::Shr, ">>"),
+	(Op::Eq, "=="),
+	(Op::Ne, "!="),
+	(Op::Lt, "<"),
+	(Op::Gt, ">"),
+	(Op::Le, "<="),
+	(Op::Ge, ">="),
+];
+
+enum Expr {
+	Var(usize),
+	Counter(usize),
+	Const(u8),
+	Bin(usize, Box<Expr>, Box<Expr>), // index into OPS
+}
+
+enum Stmt {
+	Assign(usize, Expr),
+	Putchar(Expr),
+	If(Expr, Vec<Stmt>, Vec<Stmt>),
+	// counter, times around, body. The body never assigns the counter
+	While(usize, u8, Vec<Stmt>),
+}
+
+#[derive(Clone)]
+struct State {
+	vars: Vec<u8>,
+	counters: Vec<u8>,
+}
+
+fn eval(e: &Expr, st: &State) -> Option<u8> {
+	let (op, a, b) = match e {
+		Expr::Var(v) => return Some(st.vars[*v]),
+		Expr::Counter(c) => return Some(st.counters[*c]),
+		Expr::Const(c) => return Some(*c),
+		Expr::Bin(op, a, b) => (OPS[*op].0, eval(a, st)? as u32, eval(b, st)? as u32),
+	};
+
+	let v = match op {
+		Op::Add => a + b,
+		Op::Sub => a.checked_sub(b)?,
+		Op::Mul => a * b,
+		Op::Div => a.checked_div(b)?,
+		Op::Rem => a.checked_rem(b)?,
+		Op::And => a & b,
+		Op::Or => a | b,
+		Op::Xor => a ^ b,
+		Op::Shl if b < 8 => a << b,
+		Op::Shr if b < 8 => a >> b,
+		Op::Shl | Op::Shr => return None,
+		Op::Eq => (a == b) as u32,
+		Op::Ne => (a != b) as u32,
+		Op::Lt => (a < b) as u32,
+		Op::Gt => (a > b) as u32,
+		Op::Le => (a <= b) as u32,
+		Op::Ge => (a >= b) as u32,
+	};
+
+	match v {
+		0..=255 => Some(v as u8),
+		_ => None,
+	}
+}
+
+fn exec(stmts: &[Stmt], st: &mut State) -> Option<()> {
+	for s in stmts.iter() {
+		match s {
+			Stmt::Assign(v, e) => st.vars[*v] = eval(e, st)?,
+			Stmt::Putchar(e) => {
+				eval(e, st)?;
+			}
+			Stmt::If(c, t, f) => match eval(c, st)? {
+				0 => exec(f, st)?,
+				_ => exec(t, st)?,
+			},
+			Stmt::While(c, n, body) => {
+				st.counters[*c] = *n;
+				while st.counters[*c] > 0 {
+					st.counters[*c] -= 1;
+					exec(body, st)?;
+				}
+			}
+		}
+	}
+
+	Some(())
+}
+
+struct Gen {
+	rng: Rng,
+	// counters of the loops we're inside of, they can be read but not written
+	live_counters: Vec<usize>,
+	counters: usize,
+}
+
+impl Gen {
+	fn expr(&mut self, depth: usize) -> Expr {
+		if depth == 0 || self.rng.chance(3) {
+			return match self.rng.range(0, 5) {
+				0 => Expr::Const(self.small()),
+				1 if !self.live_counters.is_empty() => {
+					let i = self.rng.range(0, self.live_counters.len());
+					Expr::Counter(self.live_counters[i])
+				}
+				_ => Expr::Var(self.rng.range(0, VARS)),
+			};
+		}
+
+		let op = self.rng.range(0, OPS.len());
+		Expr::Bin(
+			op,
+			Box::new(self.expr(depth - 1)),
+			Box::new(self.expr(depth - 1)),
+		)
+	}
+
+	// mostly small so the arithmetic has a chance of staying in range
+	fn small(&mut self) -> u8 {
+		match self.rng.range(0, 4) {
+			0 => self.rng.range(0, 256) as u8,
+			_ => self.rng.range(0, 16) as u8,
+		}
+	}
+
+	// an expression that's in range for st, a plain variable if none turns up
+	fn expr_for(&mut self, st: &State) -> Expr {
+		for _ in 0..TRIES {
+			let e = self.expr(2);
+			if eval(&e, st).is_some() {
+				return e;
+			}
+		}
+
+		Expr::Var(self.rng.range(0, VARS))
+	}
+
+	fn stmt(&mut self, st: &State, depth: usize) -> Stmt {
+		match self.rng.range(0, 6) {
+			0 if depth < MAX_DEPTH => {
+				let c = self.expr_for(st);
+				Stmt::If(c, self.block(st, depth + 1), self.block(st, depth + 1))
+			}
+			1 if depth < MAX_DEPTH => {
+				let c = self.counters;
+				self.counters += 1;
+				let n = self.rng.range(1, 5) as u8;
+
+				// as it looks on the first time around
+				let mut inner = st.clone();
+				inner.counters.resize(self.counters, 0);
+				inner.counters[c] = n - 1;
+
+				self.live_counters.push(c);
+				let body = self.block(&inner, depth + 1);
+				self.live_counters.pop();
+
+				Stmt::While(c, n, body)
+			}
+			2 | 3 => Stmt::Putchar(self.expr_for(st)),
+			_ => Stmt::Assign(self.rng.range(0, VARS), self.expr_for(st)),
+		}
+	}
+
+	// st is only a guess at what things will look like in here, exec has the
+	// final say once the whole statement is built
+	fn block(&mut self, st: &State, depth: usize) -> Vec<Stmt> {
+		(0..self.rng.range(1, MAX_BLOCK + 1))
+			.map(|_| self.stmt(st, depth))
+			.collect()
+	}
+}
+
+fn write_expr(out: &mut String, e: &Expr) {
+	match e {
+		Expr::Var(v) => out.push_str(&format!("v{}", v)),
+		Expr::Counter(c) => out.push_str(&format!("c{}", c)),
+		Expr::Const(c) => out.push_str(&c.to_string()),
+		Expr::Bin(op, a, b) => {
+			out.push('(');
+			write_expr(out, a);
+			out.push_str(&format!(" {} ", OPS[*op].1));
+			write_expr(out, b);
+			out.push(')');
+		}
+	}
+}
+
+fn write_block(out: &mut String, stmts: &[Stmt], indent: usize) {
+	let pad = "  ".repeat(indent);
+
+	for s in stmts.iter() {
+		out.push_str(&pad);
+		match s {
+			Stmt::Assign(v, e) => {
+				out.push_str(&format!("v{} = ", v));
+				write_expr(out, e);
+				out.push_str(";\n");
+			}
+			Stmt::Putchar(e) => {
+				out.push_str("putchar(");
+				write_expr(out, e);
+				out.push_str(");\n");
+			}
+			Stmt::If(c, t, f) => {
+				out.push_str("if (");
+				write_expr(out, c);
+				out.push_str(") {\n");
+				write_block(out, t, indent + 1);
+				out.push_str(&format!("{}}} else {{\n", pad));
+				write_block(out, f, indent + 1);
+				out.push_str(&format!("{}}}\n", pad));
+			}
+			Stmt::While(c, n, body) => {
+				out.push_str(&format!("c{} = {};\n", c, n));
+				out.push_str(&format!("{}while (c{}) {{\n", pad, c));
+				out.push_str(&format!("{}  c{} = c{} - 1;\n", pad, c, c));
+				write_block(out, body, indent + 1);
+				out.push_str(&format!("{}}}\n", pad));
+			}
+		}
+	}
+}
+
+// the C source of program number `seed`
+pub fn program(seed: u64) -> String {
+	let mut gen = Gen {
+		rng: Rng::new(seed),
+		live_counters: vec![],
+		counters: 0,
+	};
+
+	let mut st = State {
+		vars: (0..VARS).map(|_| gen.small()).collect(),
+		counters: vec![],
+	};
+	let init = st.vars.clone();
+
+	let mut stmts = vec![];
+	for _ in 0..TOP_STMTS {
+		for _ in 0..TRIES {
+			let s = gen.stmt(&st, 0);
+
+			let mut next = st.clone();
+			next.counters.resize(gen.counters, 0);
+			if exec(std::slice::from_ref(&s), &mut next).is_some() {
+				stmts.push(s);
+				st = next;
+				break;
+			}
+		}
+	}
+
+	let mut out = String::new();
+	out.push_str("#include \"stdfuck.h\"\n\n");
+	out.push_str(&format!("// generated by verify --fuzz from seed {}\n", seed));
+	out.push_str("int main() {\n");
+	for (i, v) in init.iter().enumerate() {
+		out.push_str(&format!("  uint8_t v{} = {};\n", i, v));
+	}
+	for c in 0..gen.counters {
+		out.push_str(&format!("  uint8_t c{} = 0;\n", c));
+	}
+	out.push('\n');
+	write_block(&mut out, &stmts, 1);
+	out.push_str("};\n");
+
+	out
+}
@@ -0,0 +1,742 @@
+// The strict brainfuck interpreter the harness checks bfcc's output with.
+// Anything a real interpreter might let slide, like wrapping a cell or
+// leaving memory dirty at exit, is an error here.
+
+use std::fmt;
+use std::io;
+
+#[derive(Debug, PartialEq)]
+pub enum InterpErr {
+	IntOverflow,
+	IntUnderflow,
+	MemOverflow,
+	MemUnderflow,
+	ExitMemNonZero,
+	// gave up after this many steps, with the tail end of whatever it had
+	// printed by then to tell where it was going around
+	TooManySteps { steps: usize, tail: String },
+	// went all the way around the loop opening at this op and came back with
+	// nothing changed, it's never getting out
+	ProvablyInfinite { op_index: usize },
+}
+
+impl fmt::Display for InterpErr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if let InterpErr::ProvablyInfinite { op_index } = self {
+			return write!(f, "loop at op {} can never exit", op_index);
+		}
+
+		if let InterpErr::TooManySteps { steps, tail } = self {
+			return write!(
+				f,
+				"ran past the step limit of {}, last output {:?}",
+				steps, tail
+			);
+		}
+
+		write!(
+			f,
+			"{}",
+			match self {
+				InterpErr::IntOverflow => "cell value overflow (undefined behavior)",
+				InterpErr::IntUnderflow => "cell value underflow (undefined behavior)",
+				InterpErr::MemOverflow => "ran out of memory",
+				InterpErr::MemUnderflow =>
+					"decrement memory pointer past first cell (undefined behavior)",
+				InterpErr::ExitMemNonZero => "all memory must be zeroed at program exit",
+				InterpErr::TooManySteps { .. } | InterpErr::ProvablyInfinite { .. } => {
+					unreachable!()
+				}
+			}
+		)
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExecResult {
+	pub output: String,
+	pub steps: usize,
+	// steps as the source spells them, before runs got combined. What
+	// bfcc's cost estimate is guessing at
+	pub chars: usize,
+	// times a cell went past 0 or 255 and came around the other side, only
+	// ever anything but 0 from exec_wrapping
+	pub wraps: usize,
+	// how much of the tape the program got to, one past the rightmost cell
+	// the pointer was ever on
+	pub cells: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum COps {
+	Add(i32),
+	Mov(i64),
+	Putchar,
+	Getchar,
+	JmpIfZ(u64),
+	JmpIfNZ(u64),
+	//Loop(Vec<COps>)
+}
+
+pub fn bf_bytecode(code: &str) -> Vec<COps> {
+	let mut opsout = Vec::<COps>::new();
+
+	let chars: Vec<char> = code.chars().collect();
+
+	for c in chars.iter() {
+		opsout.push(match c {
+			'+' => COps::Add(1),
+			'-' => COps::Add(-1),
+			'>' => COps::Mov(1),
+			'<' => COps::Mov(-1),
+			'[' => COps::JmpIfZ(0),
+			']' => COps::JmpIfNZ(0),
+			'.' => COps::Putchar,
+			',' => COps::Getchar,
+			_ => continue,
+		})
+	}
+
+	// combine similar
+	let mut into = vec![opsout[0]];
+	for op in opsout.iter().skip(1) {
+		let repl = match (into[into.len() - 1], op) {
+			(COps::Add(a), COps::Add(b)) => Some(COps::Add(a + b)),
+			(COps::Mov(a), COps::Mov(b)) => Some(COps::Mov(a + b)),
+			_ => None,
+		};
+
+		if repl.is_some() {
+			let l = into.len();
+			into[l - 1] = repl.unwrap();
+		} else {
+			into.push(*op);
+		}
+	}
+	let mut opsout = into;
+
+	// actually resolve ops
+	opsout = opsout
+		.iter()
+		.enumerate()
+		.map(|(i, op)| match op {
+			COps::JmpIfZ(_) => {
+				let mut d = 1;
+				for j in (i + 1)..opsout.len() {
+					d += match opsout[j] {
+						COps::JmpIfZ(_) => 1,
+						COps::JmpIfNZ(_) => -1,
+						_ => 0,
+					};
+
+					if d == 0 && matches!(opsout[j], COps::JmpIfNZ(_)) {
+						return COps::JmpIfZ(j as u64);
+					}
+				}
+
+				panic!("unbalanced?");
+			}
+			COps::JmpIfNZ(_) => {
+				let mut d = 1;
+				for j in (0..i).rev() {
+					d += match opsout[j] {
+						COps::JmpIfNZ(_) => 1,
+						COps::JmpIfZ(_) => -1,
+						_ => 0,
+					};
+
+					if d == 0 && matches!(opsout[j], COps::JmpIfZ(_)) {
+						return COps::JmpIfNZ(j as u64);
+					}
+				}
+
+				panic!("unbalanced?");
+			}
+			_ => *op,
+		})
+		.collect();
+
+	opsout
+}
+
+// The single file convention: everything after the first ! outside of any
+// loop is the input rather than code. A ! inside brackets is still a comment,
+// that's where the usual comment loop at the top of a program lives.
+pub fn split_input(source: &str) -> (&str, Option<&str>) {
+	let mut depth = 0;
+	for (i, c) in source.char_indices() {
+		match c {
+			'[' => depth += 1,
+			']' => depth -= 1,
+			'!' if depth == 0 => return (&source[..i], Some(&source[i + 1..])),
+			_ => {}
+		}
+	}
+
+	(source, None)
+}
+
+// bfcc says up top what it expects a read past the end of input to give
+pub fn eof_of(code: &str) -> u8 {
+	code.lines()
+		.take_while(|l| !l.starts_with("runtime init:"))
+		.find_map(|l| l.strip_prefix("eof: "))
+		.and_then(|v| v.trim().parse().ok())
+		.unwrap_or(0)
+}
+
+// Loops that leave every cell and the pointer exactly as they found them.
+// Once one of those goes around a single time it'll go around forever, so
+// instead of burning through the step limit the executors stop right there.
+// A loop in there or reading input could change things so those never count.
+// Gives back the indices of the opening brackets.
+pub fn infinite_loops(ops: &[COps]) -> Vec<usize> {
+	let mut found = vec![];
+
+	'next: for (i, op) in ops.iter().enumerate() {
+		let end = match op {
+			COps::JmpIfZ(end) => *end as usize,
+			_ => continue,
+		};
+
+		// net change per cell, keyed by offset from the loop's cell
+		let mut net: Vec<(i64, i64)> = vec![];
+		let mut at: i64 = 0;
+		for op in ops[i + 1..end].iter() {
+			match op {
+				COps::Add(n) => match net.iter_mut().find(|(o, _)| *o == at) {
+					Some((_, d)) => *d += *n as i64,
+					None => net.push((at, *n as i64)),
+				},
+				COps::Mov(n) => at += n,
+				COps::Putchar => {}
+				COps::Getchar | COps::JmpIfZ(_) | COps::JmpIfNZ(_) => {
+					continue 'next
+				}
+			}
+		}
+
+		if at == 0 && net.iter().all(|(_, d)| *d == 0) {
+			found.push(i);
+		}
+	}
+
+	found
+}
+
+pub fn exec(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, InterpErr> {
+	run(&pack(&ops), input, eof, limit)
+}
+
+// exec with the tape stopping at max_cells instead of MAX_CELLS
+pub fn exec_within(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+	max_cells: usize,
+) -> Result<ExecResult, InterpErr> {
+	run_within(&pack(&ops), input, eof, limit, max_cells).map_err(|(e, _)| e)
+}
+
+// Packed bytecode, one u32 per COp: the opcode in the low bits and its
+// operand, signed, in the rest. Jumps point straight at the index of their
+// matching bracket just like COps do.
+const OP_BITS: u32 = 3;
+const OP_MASK: u32 = (1 << OP_BITS) - 1;
+
+const OP_ADD: u32 = 0;
+const OP_MOV: u32 = 1;
+const OP_PUTCHAR: u32 = 2;
+const OP_GETCHAR: u32 = 3;
+const OP_JMP_IF_Z: u32 = 4;
+const OP_JMP_IF_NZ: u32 = 5;
+// closes one of infinite_loops, the operand still points at the opening
+const OP_JMP_IF_NZ_FOREVER: u32 = 6;
+
+pub fn pack(ops: &[COps]) -> Vec<u32> {
+	let word = |op: u32, arg: i64| {
+		assert!(
+			arg >= i32::MIN as i64 >> OP_BITS && arg <= i32::MAX as i64 >> OP_BITS,
+			"operand {} too big to pack",
+			arg
+		);
+		((arg as i32) << OP_BITS) as u32 | op
+	};
+
+	let forever = infinite_loops(ops);
+
+	ops.iter()
+		.map(|op| match *op {
+			COps::JmpIfNZ(a) if forever.contains(&(a as usize)) => {
+				word(OP_JMP_IF_NZ_FOREVER, a as i64)
+			}
+			COps::Add(n) => word(OP_ADD, n as i64),
+			COps::Mov(n) => word(OP_MOV, n),
+			COps::Putchar => word(OP_PUTCHAR, 0),
+			COps::Getchar => word(OP_GETCHAR, 0),
+			COps::JmpIfZ(a) => word(OP_JMP_IF_Z, a as i64),
+			COps::JmpIfNZ(a) => word(OP_JMP_IF_NZ, a as i64),
+		})
+		.collect()
+}
+
+// Same checks and the same step counting as exec_ops, just over packed words
+pub fn run(
+	code: &[u32],
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, InterpErr> {
+	run_at(code, input, eof, limit).map_err(|(e, _)| e)
+}
+
+// exec that also says which op it was on when it went wrong, one past the last
+// if it was only at the end
+pub fn exec_at(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, (InterpErr, usize)> {
+	run_at(&pack(&ops), input, eof, limit)
+}
+
+fn run_at(
+	code: &[u32],
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, (InterpErr, usize)> {
+	run_within(code, input, eof, limit, MAX_CELLS)
+}
+
+fn run_within(
+	code: &[u32],
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+	max_cells: usize,
+) -> Result<ExecResult, (InterpErr, usize)> {
+	let mut pc = 0;
+	let mut input = input.iter();
+	let mut mp: usize = 0;
+	let mut steps = 0;
+	let mut chars = 0;
+	let mut reach = 0;
+	let limit = limit.unwrap_or(usize::MAX);
+
+	let mut mem = vec![0u8; START_CELLS.min(max_cells)];
+
+	let mut output: Vec<char> = vec![];
+
+	while pc < code.len() {
+		let w = code[pc];
+		let arg = (w as i32) >> OP_BITS;
+
+		match w & OP_MASK {
+			OP_ADD => {
+				let v = mem[mp] as i32 + arg;
+				if v > 255 {
+					return Err((InterpErr::IntOverflow, pc));
+				} else if v < 0 {
+					return Err((InterpErr::IntUnderflow, pc));
+				}
+				mem[mp] = v as u8;
+				chars += arg.unsigned_abs() as usize;
+			}
+
+			OP_MOV => {
+				let to = mp as isize + arg as isize;
+				if to >= max_cells as isize {
+					return Err((InterpErr::MemOverflow, pc));
+				}
+
+				if to < 0 {
+					return Err((InterpErr::MemUnderflow, pc));
+				}
+
+				grow(&mut mem, to as usize, max_cells);
+
+				mp = to as usize;
+				reach = reach.max(mp);
+				chars += arg.unsigned_abs() as usize;
+			}
+
+			OP_PUTCHAR => {
+				output.push(mem[mp] as char);
+				chars += 1;
+			}
+
+			OP_GETCHAR => {
+				mem[mp] = *input.next().unwrap_or(&eof);
+				chars += 1;
+			}
+
+			OP_JMP_IF_Z => {
+				if mem[mp] == 0 {
+					pc = arg as usize;
+				}
+				chars += 1;
+			}
+
+			OP_JMP_IF_NZ => {
+				if mem[mp] != 0 {
+					pc = arg as usize;
+				}
+				chars += 1;
+			}
+
+			_ => {
+				if mem[mp] != 0 {
+					let e = InterpErr::ProvablyInfinite {
+						op_index: arg as usize,
+					};
+					return Err((e, pc));
+				}
+				chars += 1;
+			}
+		}
+
+		pc += 1;
+		steps += 1;
+
+		if steps > limit {
+			return Err((too_many_steps(limit, &output), pc));
+		}
+	}
+
+	if mem.iter().any(|c| *c != 0) {
+		return Err((InterpErr::ExitMemNonZero, pc));
+	}
+
+	Ok(ExecResult {
+		output: output.iter().collect(),
+		steps,
+		chars,
+		wraps: 0,
+		cells: reach + 1,
+	})
+}
+
+// how much of the output a TooManySteps keeps
+const OUTPUT_TAIL: usize = 40;
+
+fn too_many_steps(limit: usize, output: &[char]) -> InterpErr {
+	InterpErr::TooManySteps {
+		steps: limit,
+		tail: output[output.len().saturating_sub(OUTPUT_TAIL)..].iter().collect(),
+	}
+}
+
+// The tape starts out this long and doubles whenever the pointer walks off
+// the end, up to MAX_CELLS
+const START_CELLS: usize = 10000;
+pub const MAX_CELLS: usize = 1 << 20;
+
+fn grow(mem: &mut Vec<u8>, to: usize, max_cells: usize) {
+	if to >= mem.len() {
+		let len = (mem.len() * 2).max(to + 1).min(max_cells);
+		mem.resize(len, 0);
+	}
+}
+
+// When a trace writes a record, always before the op at pc runs
+pub enum TraceOn {
+	// every this many steps, starting with the first
+	Every(usize),
+	Putchar,
+	JmpIfZ,
+}
+
+pub struct TraceConfig {
+	pub on: TraceOn,
+	// a long run could fill the disk otherwise, a last line says when it
+	// stopped short
+	pub max_records: usize,
+}
+
+struct Tracer<'a> {
+	config: &'a TraceConfig,
+	out: &'a mut dyn io::Write,
+	records: usize,
+}
+
+impl<'a> Tracer<'a> {
+	fn record(&mut self, op: &COps, pc: usize, mp: usize, cell: u8, steps: usize) {
+		let due = match (&self.config.on, op) {
+			(TraceOn::Every(n), _) => steps.is_multiple_of((*n).max(1)),
+			(TraceOn::Putchar, COps::Putchar) => true,
+			(TraceOn::JmpIfZ, COps::JmpIfZ(_)) => true,
+			_ => false,
+		};
+
+		let max = self.config.max_records;
+		if !due || self.records > max {
+			return;
+		}
+
+		let line = match self.records == max {
+			true => format!("capped at {} records\n", max),
+			false => format!("step={} pc={} mp={} cell={}\n", steps, pc, mp, cell),
+		};
+		self.records += 1;
+
+		// a trace is a nice to have, losing the rest of it is no reason to
+		// stop the program
+		if self.out.write_all(line.as_bytes()).is_err() {
+			self.records = max + 1;
+		}
+	}
+}
+
+// The original executor walking COps directly. Slower, but simple enough to
+// trust, so the harness checks run against it.
+pub fn exec_ops(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, InterpErr> {
+	fresh(ops, input, eof, limit, false, None)
+}
+
+// What most interpreters out there do: cells go around from 255 to 0 and
+// back instead of that being an error. Every time one does gets counted, bfcc's
+// code should never need to so any at all is a bug.
+pub fn exec_wrapping(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, InterpErr> {
+	fresh(ops, input, eof, limit, true, None)
+}
+
+// exec_ops writing a record of pc, mp, the current cell and the step count
+// to out whenever config says to. See annotate_trace to make sense of it.
+pub fn trace(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+	config: &TraceConfig,
+	out: &mut dyn io::Write,
+) -> Result<ExecResult, InterpErr> {
+	let tracer = Tracer {
+		config,
+		out,
+		records: 0,
+	};
+
+	fresh(ops, input, eof, limit, false, Some(tracer))
+}
+
+// exec_ops on a tape that starts out however the caller likes and is left
+// however the program leaves it, nothing says it has to end up zeroed. For
+// running a bit of code that expects things already laid out for it, like
+// bfcc's compile_function. The tape grows as the program walks off it.
+pub fn exec_tape(
+	ops: Vec<COps>,
+	tape: &mut Vec<u8>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<ExecResult, InterpErr> {
+	walk(ops, input, eof, limit, false, None, tape)
+}
+
+// a clean tape in, and it has to come out that way too
+fn fresh(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+	wrapping: bool,
+	tracer: Option<Tracer>,
+) -> Result<ExecResult, InterpErr> {
+	let mut mem = vec![0; START_CELLS];
+	let res = walk(ops, input, eof, limit, wrapping, tracer, &mut mem)?;
+
+	if mem.iter().any(|c| *c != 0) {
+		return Err(InterpErr::ExitMemNonZero);
+	}
+
+	Ok(res)
+}
+
+fn walk(
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+	wrapping: bool,
+	mut tracer: Option<Tracer>,
+	mem: &mut Vec<u8>,
+) -> Result<ExecResult, InterpErr> {
+	let mut pc = 0;
+	let mut input = input.iter();
+	let mut mp = 0;
+	let mut steps = 0;
+	let mut chars = 0;
+	let mut wraps = 0;
+	let mut reach = 0;
+
+	let forever = infinite_loops(&ops);
+
+	let mut output: Vec<char> = vec![];
+
+	while pc < ops.len() {
+		if let Some(t) = &mut tracer {
+			t.record(&ops[pc], pc, mp, mem[mp], steps);
+		}
+
+		match ops[pc] {
+			COps::Putchar => output.push(mem[mp] as char),
+			COps::Getchar => mem[mp] = *input.next().unwrap_or(&eof),
+
+			COps::Add(n) => {
+				let v = mem[mp] as isize + n as isize;
+				if wrapping && !(0..=255).contains(&v) {
+					wraps += 1;
+				} else if v > 255 {
+					return Err(InterpErr::IntOverflow);
+				} else if v < 0 {
+					return Err(InterpErr::IntUnderflow);
+				}
+				mem[mp] = v.rem_euclid(256) as u8;
+			}
+
+			COps::Mov(n) => {
+				let to = mp as isize + n as isize;
+				if to >= MAX_CELLS as isize {
+					return Err(InterpErr::MemOverflow);
+				}
+
+				if to < 0 {
+					return Err(InterpErr::MemUnderflow);
+				}
+
+				grow(mem, to as usize, MAX_CELLS);
+
+				mp = to as usize;
+				reach = reach.max(mp);
+			}
+
+			COps::JmpIfZ(a) => {
+				if mem[mp] == 0 {
+					pc = a as usize;
+				}
+			}
+
+			COps::JmpIfNZ(a)
+				if mem[mp] != 0 && forever.contains(&(a as usize)) =>
+			{
+				return Err(InterpErr::ProvablyInfinite {
+					op_index: a as usize,
+				});
+			}
+
+			COps::JmpIfNZ(a) => {
+				if mem[mp] != 0 {
+					pc = a as usize;
+				}
+			}
+		};
+
+		chars += match ops[pc] {
+			COps::Add(n) => n.unsigned_abs() as usize,
+			COps::Mov(n) => n.unsigned_abs() as usize,
+			_ => 1,
+		};
+
+		pc += 1;
+		steps += 1;
+
+		if let Some(l) = limit.filter(|l| steps > *l) {
+			return Err(too_many_steps(l, &output));
+		}
+	}
+
+	Ok(ExecResult {
+		output: output.iter().collect(),
+		steps,
+		chars,
+		wraps,
+		cells: reach + 1,
+	})
+}
+
+// Where each op bf_bytecode gives back starts in the source, as a byte offset.
+// Runs it combined point at their first char.
+pub fn op_offsets(code: &str) -> Vec<usize> {
+	let mut offsets = vec![];
+	let mut last = None;
+
+	for (i, c) in code.char_indices() {
+		let kind = match c {
+			'+' | '-' => '+',
+			'>' | '<' => '>',
+			'[' | ']' | '.' | ',' => c,
+			_ => continue,
+		};
+
+		match (last, kind) {
+			(Some('+'), '+') | (Some('>'), '>') => {}
+			_ => offsets.push(i),
+		}
+		last = Some(kind);
+	}
+
+	offsets
+}
+
+// Tacks onto every record of a trace the closest comment or tag bfcc left
+// above the op it was taken at, which is about as close to the ir as the code
+// gets.
+pub fn annotate_trace(code: &str, trace: &str) -> String {
+	let offsets = op_offsets(code);
+	let lines: Vec<&str> = code.lines().collect();
+
+	// the text of a line without any of the code on it
+	let text = |l: &str| {
+		l.chars()
+			.filter(|c| !"+-<>[].,".contains(*c))
+			.collect::<String>()
+			.trim()
+			.to_string()
+	};
+
+	let mut out = String::new();
+	for record in trace.lines() {
+		out.push_str(record);
+
+		let at = record
+			.split(' ')
+			.find_map(|f| f.strip_prefix("pc="))
+			.and_then(|pc| pc.parse::<usize>().ok())
+			.and_then(|pc| offsets.get(pc));
+
+		if let Some(at) = at {
+			let line = code[..*at].matches('\n').count();
+			let note = lines[..=line]
+				.iter()
+				.rev()
+				.map(|l| text(l))
+				.find(|t| !t.is_empty());
+
+			if let Some(note) = note {
+				out.push_str(&format!(" at {}", note));
+			}
+		}
+
+		out.push('\n');
+	}
+
+	out
+}
@@ -0,0 +1,32 @@
+// bfcc as a library. The bins are thin wrappers over this and anybody else can
+// compile bitcode or run brainfuck without going through them.
+
+extern crate llvm_ir;
+
+use std::path::Path;
+
+pub mod bfcc;
+
+// the strict interpreter verify checks bfcc's output with
+#[path = "interp.rs"]
+pub mod bf;
+
+// verify's status lines, colored with the color feature and plain without
+pub mod term;
+
+pub use bfcc::CompileError;
+
+// the brainfuck for the bitcode at path, built with the default Options
+pub fn compile_path(path: &Path) -> Result<String, CompileError> {
+	bfcc::compile(path, &bfcc::Options::default()).map(|c| c.code)
+}
+
+// same thing for a module somebody already has in hand
+pub fn compile_module(module: llvm_ir::Module) -> Result<String, CompileError> {
+	bfcc::compile_module(module, &bfcc::Options::default()).map(|c| c.code)
+}
+
+// and for bitcode that never was a file, say piped in
+pub fn compile_bytes(bytes: &[u8]) -> Result<String, CompileError> {
+	compile_module(bfcc::load_bytes(bytes)?)
+}
@@ -5,21 +5,45 @@
 void putchar(uint8_t c);
 uint8_t getchar(void);
 
+// whatever getchar gives once input runs out, bfcc fills it in to match the
+// eof convention it was told to build for
+uint8_t __bfcc_eof(void);
+#define EOF __bfcc_eof()
+
+// the lengths have to be constants, bfcc turns these into a load and store
+// per byte
+void *memcpy(void *dst, const void *src, unsigned long n);
+void *memmove(void *dst, const void *src, unsigned long n);
+
+// only for string literals, bfcc prints them straight from the constant
+int puts(const char *s);
+
+// the format has to be a string literal and only %c, %s (of a literal), %d
+// and %% are understood
+int printf(const char *format, ...);
+
+// x in decimal, without pulling in a division of our own
+void print_u8(uint8_t x);
+
+// ends the program right there, the status goes nowhere
+void exit(int status);
+
 void putstr(char *s) {
-	while(s) {
-		putchar(s);
+	while(*s) {
+		putchar(*s);
 		s++;
 	}
 }
 
-void putdec(int n) {
+// unsigned since a cell past 127 reads as negative to the signed compares
+void putdec(unsigned int n) {
   if (!n) {
     putchar('0');
     return;
   }
 
-  int digs = 1;
-  while(n / digs > 10) digs *= 10;
+  unsigned int digs = 1;
+  while(n / digs >= 10) digs *= 10;
 
   while(digs) {
     putchar('0' + ((n / digs)%10));
@@ -0,0 +1,87 @@
+// All the pretty status line stuff for the test harness lives here. termion
+// doesn't build everywhere (looking at you windows) so the colors are behind
+// the `color` feature and without it everything degrades to plain text with
+// the exact same api. The plain one is always built so it can be tested either
+// way.
+
+pub enum Status {
+	Section,
+	Test,
+	Skip,
+	Pass,
+	Fail,
+	// bfcc turned the case down instead of getting it wrong
+	Unsupported,
+}
+
+impl Status {
+	fn label(&self) -> &'static str {
+		match self {
+			Status::Section => "section",
+			Status::Test => "test",
+			Status::Skip => "skip",
+			Status::Pass => "pass",
+			Status::Fail => "fail",
+			Status::Unsupported => "unsupported",
+		}
+	}
+}
+
+#[cfg(feature = "color")]
+mod fmt {
+	extern crate termion;
+	use self::termion::{color, style};
+
+	use super::Status;
+
+	pub fn status(s: Status, msg: &str) -> String {
+		let fg = match s {
+			Status::Section => color::Fg(color::Blue).to_string(),
+			Status::Test => color::Fg(color::LightYellow).to_string(),
+			Status::Skip | Status::Unsupported => color::Fg(color::Yellow).to_string(),
+			Status::Pass => color::Fg(color::Green).to_string(),
+			Status::Fail => color::Fg(color::Red).to_string(),
+		};
+
+		format!(
+			"{}{} {} {} {}",
+			fg,
+			style::Invert,
+			s.label(),
+			style::Reset,
+			msg
+		)
+	}
+
+	pub fn dim(msg: &str) -> String {
+		format!("{}{}{}", color::Fg(color::LightBlack), msg, style::Reset)
+	}
+
+	// we've got a real terminal so the pending "test" line can be drawn over
+	pub fn rewind() -> &'static str {
+		"\r"
+	}
+}
+
+pub mod plain {
+	use super::Status;
+
+	pub fn status(s: Status, msg: &str) -> String {
+		format!("[{}] {}", s.label(), msg)
+	}
+
+	pub fn dim(msg: &str) -> String {
+		msg.to_string()
+	}
+
+	// no escape codes means no promises about what a \r does, just leave the
+	// pending line alone and start a fresh one
+	pub fn rewind() -> &'static str {
+		"\n"
+	}
+}
+
+#[cfg(not(feature = "color"))]
+use self::plain as fmt;
+
+pub use self::fmt::{dim, rewind, status};
@@ -0,0 +1,804 @@
+// The library api the way another crate would use it, on bitcode checked in
+// under tests/fixtures so nothing here needs clang.
+
+extern crate brainfuq;
+extern crate llvm_ir;
+
+use std::path::{Path, PathBuf};
+
+use brainfuq::bf::{
+	annotate_trace, bf_bytecode, eof_of, exec, exec_ops, exec_tape,
+	exec_within, exec_wrapping, split_input, trace, InterpErr, TraceConfig,
+	TraceOn,
+};
+use brainfuq::term::{self, Status};
+use brainfuq::{bfcc, CompileError};
+
+fn fixture(name: &str) -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("tests")
+		.join("fixtures")
+		.join(format!("{}.bc", name))
+}
+
+fn run(code: &str, input: &str) -> String {
+	exec(bf_bytecode(code), input.as_bytes(), eof_of(code), None)
+		.unwrap()
+		.output
+}
+
+#[test]
+fn compile_path_runs() {
+	let code = brainfuq::compile_path(&fixture("shout")).unwrap();
+	assert_eq!(run(&code, ""), "Q!");
+}
+
+#[test]
+fn compile_module_runs() {
+	let module = llvm_ir::Module::from_bc_path(fixture("pick")).unwrap();
+	let code = brainfuq::compile_module(module).unwrap();
+	assert_eq!(run(&code, ""), "cz");
+}
+
+#[test]
+fn same_either_way() {
+	let module = llvm_ir::Module::from_bc_path(fixture("pick")).unwrap();
+	assert_eq!(
+		brainfuq::compile_path(&fixture("pick")).unwrap(),
+		brainfuq::compile_module(module).unwrap()
+	);
+}
+
+#[test]
+fn unsupported_is_an_error() {
+	match brainfuq::compile_path(&fixture("float_add")) {
+		Err(CompileError::Unsupported(diags)) => {
+			assert!(diags.iter().all(|d| d.func == "main"));
+			assert!(diags[0].msg.contains("unsupported instruction"));
+		}
+		Err(e) => panic!("wrong error: {}", e),
+		Ok(_) => panic!("compiled floating point"),
+	}
+}
+
+#[test]
+fn missing_file_is_an_error() {
+	match brainfuq::compile_path(&fixture("nope")) {
+		Err(CompileError::BadModule { .. }) => {}
+		Err(e) => panic!("wrong error: {}", e),
+		Ok(_) => panic!("compiled nothing"),
+	}
+}
+
+#[test]
+fn compile_bytes_runs() {
+	let bytes = std::fs::read(fixture("pick")).unwrap();
+	let code = brainfuq::compile_bytes(&bytes).unwrap();
+	assert_eq!(code, brainfuq::compile_path(&fixture("pick")).unwrap());
+	assert_eq!(run(&code, ""), "cz");
+}
+
+#[test]
+fn no_comments_is_just_the_ops() {
+	let opts = bfcc::Options {
+		no_comments: true,
+		..bfcc::Options::default()
+	};
+	let full = bfcc::compile(&fixture("pick"), &bfcc::Options::default()).unwrap();
+	let min = bfcc::compile(&fixture("pick"), &opts).unwrap();
+
+	assert!(min.code.chars().all(|c| "+-<>[].,".contains(c)));
+	assert!(min.code.len() < full.code.len());
+	assert_eq!(run(&min.code, ""), run(&full.code, ""));
+}
+
+#[test]
+fn map_has_every_frame() {
+	let out = bfcc::compile(&fixture("pick"), &bfcc::Options::default()).unwrap();
+	let map = out.map;
+
+	assert_eq!(map.globals.len(), 1);
+	assert_eq!((map.globals[0].at, map.globals[0].len), (1, 3));
+
+	let mut names = map.frames.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+	names.sort();
+	assert_eq!(names, ["main", "pick"]);
+
+	for f in map.frames.iter() {
+		assert_eq!(f.cells[0], bfcc::CellJson::MainLoop);
+		assert!(f.cells.len() <= f.width);
+		assert!(f.scratch.iter().all(|c| *c < f.width));
+	}
+}
+
+#[test]
+fn src_map_finds_the_putchar() {
+	let out = bfcc::compile(&fixture("shout"), &bfcc::Options::default()).unwrap();
+
+	for pair in out.src_map.windows(2) {
+		assert!(pair[0].end <= pair[1].start);
+	}
+
+	let ops = out.code.chars().filter(|c| "+-<>[].,".contains(*c));
+	let first = ops.clone().position(|c| c == '.').unwrap();
+	let span = bfcc::src_at(&out.src_map, first).unwrap();
+	assert_eq!(span.func, "main");
+	assert!(span.instr.contains("@putchar"), "{}", span);
+}
+
+#[test]
+fn peephole_only_shrinks() {
+	let plain = bfcc::Options {
+		peephole: false,
+		..bfcc::Options::default()
+	};
+	let full = bfcc::compile(&fixture("pick"), &plain).unwrap();
+	let small = bfcc::compile(&fixture("pick"), &bfcc::Options::default()).unwrap();
+
+	let ops = |code: &str| code.chars().filter(|c| "+-<>[].,".contains(*c)).count();
+	assert_eq!(full.peephole.0, ops(&full.code));
+	assert_eq!(small.peephole, (ops(&full.code), ops(&small.code)));
+	assert!(small.peephole.1 < small.peephole.0);
+
+	assert_eq!(run(&small.code, ""), run(&full.code, ""));
+	assert!(small.src_map.iter().all(|s| s.end <= small.peephole.1));
+}
+
+#[test]
+fn head_lands_where_expected_through_loops() {
+	let plain = bfcc::Options {
+		peephole: false,
+		..bfcc::Options::default()
+	};
+	let code = bfcc::compile(&fixture("nest"), &plain).unwrap().code;
+	assert_eq!(run(&code, ""), "0 e1 eo2 eoe3 ");
+}
+
+#[test]
+fn big_constants_come_out_right() {
+	let code = brainfuq::compile_path(&fixture("consts")).unwrap();
+	assert_eq!(run(&code, ""), "zA");
+
+	// the wrap safe add would spell out a 255 of its own
+	let plain = bfcc::Options {
+		wrap_safe: false,
+		..bfcc::Options::default()
+	};
+	let f = bfcc::compile_function(&fixture("consts"), "f", &plain).unwrap();
+	assert!(f.code.matches('+').count() < 120);
+	for a in [0u8, 1, 100].iter() {
+		let mut tape = vec![0; f.args[0] + 1];
+		tape[f.args[0]] = *a;
+		exec_tape(bf_bytecode(&f.code), &mut tape, &[], 0, None).unwrap();
+
+		let ret = f.ret.unwrap();
+		assert_eq!(tape[ret], a + 120);
+		tape[ret] = 0;
+		assert!(tape.iter().all(|c| *c == 0));
+	}
+}
+
+// args to @f and what it should give back
+const FUNCTION_CASES: &[(&[u8], u8)] = &[
+	(&[0, 0], 0),
+	(&[1, 2], 7),
+	(&[10, 0], 30),
+	(&[20, 5], 70),
+];
+
+#[test]
+fn compiled_functions_run_off_a_tape() {
+	let opts = bfcc::Options::default();
+	let f = bfcc::compile_function(&fixture("function"), "f", &opts).unwrap();
+
+	for (args, expected) in FUNCTION_CASES.iter() {
+		let mut tape = vec![0; f.args.iter().max().unwrap() + 1];
+		for (cell, v) in f.args.iter().zip(args.iter()) {
+			tape[*cell] = *v;
+		}
+		exec_tape(bf_bytecode(&f.code), &mut tape, &[], 0, None).unwrap();
+
+		let ret = f.ret.unwrap();
+		assert_eq!(tape[ret], *expected, "f{:?}", args);
+		tape[ret] = 0;
+		assert!(tape.iter().all(|c| *c == 0), "f{:?}", args);
+	}
+}
+
+#[test]
+fn done_values_give_their_cells_back() {
+	let out = bfcc::compile(&fixture("chain"), &bfcc::Options::default()).unwrap();
+	assert_eq!(run(&out.code, ""), "qj");
+
+	let main = out.map.frames.iter().find(|f| f.name == "main").unwrap();
+	let regs = main
+		.cells
+		.iter()
+		.filter(|c| matches!(c, bfcc::CellJson::Reg { .. }))
+		.count();
+	// %a plus two cells taking turns for everything else
+	assert_eq!(regs, 3);
+}
+
+#[test]
+fn opt_skips_the_round_trips() {
+	let plain = bfcc::compile(&fixture("stores"), &bfcc::Options::default()).unwrap();
+	let opt = bfcc::Options {
+		opt: true,
+		..bfcc::Options::default()
+	};
+	let fast = bfcc::compile(&fixture("stores"), &opt).unwrap();
+
+	assert_eq!(run(&plain.code, ""), "bb");
+	assert_eq!(run(&fast.code, ""), "bb");
+	assert!(fast.peephole.1 < plain.peephole.1);
+
+	let ir = bfcc::dump_ir(&fixture("stores"), &opt).unwrap();
+	assert_eq!(ir.matches(" = load ").count(), 0);
+}
+
+#[test]
+fn constant_expressions_fold() {
+	let out = bfcc::compile(&fixture("fold"), &bfcc::Options::default()).unwrap();
+	assert_eq!(run(&out.code, ""), ",1");
+
+	// the add comes down to a clear and building the one value, no putting
+	// the operands together at runtime
+	let add = out
+		.src_map
+		.iter()
+		.find(|s| s.instr.starts_with("%c = add"))
+		.unwrap();
+	let ops: String = out
+		.code
+		.chars()
+		.filter(|c| "+-<>[].,".contains(*c))
+		.skip(add.start)
+		.take(add.end - add.start)
+		.collect();
+	assert!(!ops.contains(|c| ".,".contains(c)), "{}", ops);
+	assert!(ops.len() < 44, "{}", ops);
+}
+
+#[test]
+fn uncalled_functions_are_left_out() {
+	let opts = bfcc::Options {
+		inline_threshold: 0,
+		..bfcc::Options::default()
+	};
+	let out = bfcc::compile(&fixture("three"), &opts).unwrap();
+	assert_eq!(run(&out.code, ""), "hi");
+
+	let mut names = out.map.frames.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+	names.sort();
+	assert_eq!(names, ["main", "used"]);
+	assert!(!out.code.contains("unused"));
+}
+
+#[test]
+fn no_main_is_an_error() {
+	match brainfuq::compile_path(&fixture("nomain")) {
+		Err(CompileError::Unsupported(diags)) => {
+			assert_eq!(diags[0].func, "main");
+		}
+		Err(e) => panic!("wrong error: {}", e),
+		Ok(_) => panic!("compiled without a main"),
+	}
+}
+
+#[test]
+fn straight_blocks_merge() {
+	let out = bfcc::compile(&fixture("straight"), &bfcc::Options::default()).unwrap();
+	assert_eq!(run(&out.code, ""), "c");
+
+	let ir = bfcc::dump_ir(&fixture("straight"), &bfcc::Options::default()).unwrap();
+	assert!(!ir.contains("phi"), "{}", ir);
+	assert!(!ir.contains("last"), "{}", ir);
+}
+
+#[test]
+fn getc_reads_like_getchar() {
+	let code = brainfuq::compile_path(&fixture("getc")).unwrap();
+	assert_eq!(run(&code, "a"), "b");
+	assert_eq!(run(&code, "y"), "z");
+}
+
+#[test]
+fn puts_prints_string_constants() {
+	let code = brainfuq::compile_path(&fixture("puts")).unwrap();
+	assert_eq!(run(&code, ""), "hi\nyo!\n");
+}
+
+#[test]
+fn printf_prints_numbers_and_text() {
+	let code = brainfuq::compile_path(&fixture("printf")).unwrap();
+	assert_eq!(run(&code, "a5"), "a=150, ok 5%\n");
+	assert_eq!(run(&code, "05"), "0=101, ok 5%\n");
+	assert_eq!(run(&code, "(2"), "(=90, ok 2%\n");
+}
+
+#[test]
+fn printf_names_what_it_cant_do() {
+	match brainfuq::compile_path(&fixture("badprintf")) {
+		Err(CompileError::Unsupported(diags)) => {
+			assert!(diags[0].msg.contains("%x"), "{}", diags[0].msg);
+		}
+		Err(e) => panic!("wrong error: {}", e),
+		Ok(_) => panic!("compiled a %x"),
+	}
+}
+
+#[test]
+fn print_u8_prints_decimal() {
+	let code = brainfuq::compile_path(&fixture("print_u8")).unwrap();
+	assert_eq!(run(&code, "\u{0}"), "0 0 7 42 255 0");
+	assert_eq!(run(&code, "a"), "97 0 7 42 255 97");
+}
+
+#[test]
+fn exit_ends_it_from_anywhere() {
+	let code = brainfuq::compile_path(&fixture("exit")).unwrap();
+	assert_eq!(run(&code, "ab"), "ab.");
+	assert_eq!(run(&code, "abxc"), "ab");
+	assert_eq!(run(&code, "aqc"), "a!");
+}
+
+#[test]
+fn debug_info_and_hints_are_ignored() {
+	let out = bfcc::compile(&fixture("hints"), &bfcc::Options::default()).unwrap();
+	assert_eq!(run(&out.code, "a"), "b");
+
+	let ir = bfcc::dump_ir(&fixture("hints"), &bfcc::Options::default()).unwrap();
+	assert!(!ir.contains("llvm."), "{}", ir);
+}
+
+#[test]
+fn intrinsics_dont_split_blocks() {
+	let out = bfcc::compile(&fixture("echo"), &bfcc::Options::default()).unwrap();
+	assert_eq!(run(&out.code, "ab"), "bbd");
+
+	// and the ret landing pad every frame has, no entry shim needed either
+	let main = out.map.frames.iter().find(|f| f.name == "main").unwrap();
+	let blocks = main
+		.cells
+		.iter()
+		.filter(|c| matches!(c, bfcc::CellJson::BlockMask { .. }))
+		.count();
+	assert_eq!(blocks, 2, "{:?}", main.cells);
+}
+
+#[test]
+fn split_blocks_get_their_own_names() {
+	let opts = bfcc::Options {
+		inline_threshold: 0,
+		..bfcc::Options::default()
+	};
+	let out = bfcc::compile(&fixture("twocalls"), &opts).unwrap();
+	assert_eq!(run(&out.code, "q"), "hi!");
+	assert_eq!(run(&out.code, "a"), "hi?!");
+
+	let ir = bfcc::dump_ir(&fixture("twocalls"), &opts).unwrap();
+	let mut labels = ir
+		.lines()
+		.filter(|l| !l.starts_with(' ') && l.contains(':'))
+		.map(|l| l.split(':').next().unwrap())
+		.collect::<Vec<_>>();
+	let all = labels.len();
+	labels.sort();
+	labels.dedup();
+	assert_eq!(labels.len(), all, "{}", ir);
+	assert!(all >= 5, "{}", ir);
+}
+
+#[test]
+fn calls_from_the_first_block() {
+	let opts = bfcc::Options {
+		inline_threshold: 0,
+		..bfcc::Options::default()
+	};
+	let out = bfcc::compile(&fixture("firstcall"), &opts).unwrap();
+	assert_eq!(run(&out.code, ""), ">321");
+
+	// only main calls out of its first block, and gets the one shim
+	let ir = bfcc::dump_ir(&fixture("firstcall"), &opts).unwrap();
+	assert_eq!(ir.matches("(entry shim)").count(), 1, "{}", ir);
+}
+
+// Golden files and diffs of the output are only any good if building the same
+// thing twice gives the same thing back, down to the order of every frame and
+// cell. Anything iterating a HashMap on the way would show up here.
+#[test]
+fn same_bitcode_same_output() {
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures");
+	let mut checked = 0;
+
+	for entry in std::fs::read_dir(dir).unwrap() {
+		let path = entry.unwrap().path();
+		if path.extension().is_none_or(|e| e != "bc") {
+			continue;
+		}
+
+		let first = match bfcc::compile(&path, &bfcc::Options::default()) {
+			Ok(out) => out,
+			Err(_) => continue,
+		};
+		let again = bfcc::compile(&path, &bfcc::Options::default()).unwrap();
+
+		let name = path.display();
+		assert!(first.code == again.code, "{} came out different", name);
+		assert!(first.map == again.map, "{} got a different map", name);
+		assert!(first.ops == again.ops, "{} got different ops", name);
+		assert!(first.src_map == again.src_map, "{} got a different src map", name);
+		assert_eq!(
+			bfcc::dump_ir(&path, &bfcc::Options::default()).unwrap(),
+			bfcc::dump_ir(&path, &bfcc::Options::default()).unwrap()
+		);
+		checked += 1;
+	}
+
+	assert!(checked > 10);
+}
+
+#[test]
+fn unsupported_says_where() {
+	let err = match brainfuq::compile_path(&fixture("located")) {
+		Err(e) => e,
+		Ok(_) => panic!("compiled floating point"),
+	};
+	match &err {
+		CompileError::Unsupported(diags) => {
+			assert_eq!(diags[0].loc.as_deref(), Some("foo.c:12"));
+		}
+		e => panic!("wrong error: {}", e),
+	}
+
+	let msg = err.to_string();
+	assert!(
+		msg.contains("unsupported instruction '%f = fadd float 1"),
+		"{}",
+		msg
+	);
+	assert!(msg.contains("in main/%entry (foo.c:12)"), "{}", msg);
+}
+
+#[test]
+fn signed_division_needs_values_that_cant_be_negative() {
+	match brainfuq::compile_path(&fixture("signed")) {
+		Err(CompileError::Unsupported(diags)) => {
+			assert_eq!(diags.len(), 1, "{:?}", diags);
+			assert_eq!(diags[0].loc.as_deref(), Some("foo.c:12"));
+			assert!(diags[0].msg.contains("%b = sdiv i8 %a"), "{}", diags[0].msg);
+		}
+		Err(e) => panic!("wrong error: {}", e),
+		Ok(_) => panic!("compiled a signed division of a loaded cell"),
+	}
+}
+
+#[test]
+fn tape_grows_past_ten_thousand_cells() {
+	let code = format!("{}+.-{}", ">".repeat(25000), "<".repeat(25000));
+	let result = exec(bf_bytecode(&code), b"", 0, None).unwrap();
+	assert_eq!(result.output, "\u{1}");
+	assert_eq!(result.cells, 25001);
+
+	let capped = exec_within(bf_bytecode(&code), b"", 0, None, 20000);
+	assert_eq!(capped.err(), Some(InterpErr::MemOverflow));
+
+	let under = exec_within(bf_bytecode("<"), b"", 0, None, 20000);
+	assert_eq!(under.err(), Some(InterpErr::MemUnderflow));
+}
+
+#[test]
+fn step_limit_keeps_the_last_output() {
+	let code = brainfuq::compile_path(&fixture("spin")).unwrap();
+	let result = exec(bf_bytecode(&code), b"", eof_of(&code), Some(1_000_000));
+	assert_eq!(
+		result.err(),
+		Some(InterpErr::TooManySteps {
+			steps: 1_000_000,
+			tail: "hi".to_string(),
+		})
+	);
+}
+
+#[test]
+fn adding_200_counts_up() {
+	let code = brainfuq::compile_path(&fixture("add200")).unwrap();
+	assert_eq!(run(&code, "\u{5}"), "\u{cd}\u{c8}");
+}
+
+#[test]
+fn switch_becomes_a_compare_chain() {
+	let code = brainfuq::compile_path(&fixture("switch")).unwrap();
+	assert_eq!(run(&code, "a"), "a");
+	assert_eq!(run(&code, "b"), "b");
+	assert_eq!(run(&code, "x"), "2");
+	assert_eq!(run(&code, "q"), "3");
+	assert_eq!(run(&code, ""), "3");
+
+	let ir = bfcc::dump_ir(&fixture("switch"), &bfcc::Options::default()).unwrap();
+	assert!(!ir.contains("switch i8"), "{}", ir);
+	// the shared block hears from both compares that can send it there, once
+	// each, and the default from the last one in the chain
+	assert!(
+		ir.contains("%v = phi i8 [ i8 %c, %entry ], [ i8 %c, %entry.sw.1 ]"),
+		"{}",
+		ir
+	);
+	assert!(ir.contains("label %x, label %other"), "{}", ir);
+}
+
+#[test]
+fn switch_with_only_a_default() {
+	let code = brainfuq::compile_path(&fixture("switch_default")).unwrap();
+	assert_eq!(run(&code, ""), "d");
+
+	let ir = bfcc::dump_ir(&fixture("switch_default"), &bfcc::Options::default())
+		.unwrap();
+	assert!(!ir.contains("switch i8"), "{}", ir);
+	assert!(!ir.contains("icmp"), "{}", ir);
+}
+
+#[test]
+fn switch_blocks_get_fresh_names_and_say_where_from() {
+	let opts = bfcc::Options::default();
+	let out = bfcc::compile(&fixture("switch_names"), &opts).unwrap();
+	assert_eq!(run(&out.code, ""), "b");
+
+	let made = out.provenance.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+	assert_eq!(made, ["main/%entry.sw.2 from %entry (switch case)"]);
+	// dots are ops, the comment can't have them
+	assert!(out.code.contains("main/%entry_sw_2 from %entry (switch case)"));
+}
+
+#[test]
+fn indirect_branches_are_rejected() {
+	match brainfuq::compile_path(&fixture("indirectbr")) {
+		Err(CompileError::Unsupported(diags)) => {
+			assert_eq!(diags.len(), 1);
+			assert_eq!(diags[0].func, "main");
+			assert_eq!(diags[0].block, "%entry");
+			assert!(diags[0].msg.starts_with("indirect branches"), "{}", diags[0]);
+		}
+		Err(e) => panic!("wrong error: {}", e),
+		Ok(_) => panic!("compiled an indirectbr"),
+	}
+}
+
+#[test]
+fn split_blocks_say_which_call_they_follow() {
+	let opts = bfcc::Options {
+		inline_threshold: 0,
+		..bfcc::Options::default()
+	};
+	let out = bfcc::compile(&fixture("twocalls"), &opts).unwrap();
+
+	let made = out
+		.provenance
+		.iter()
+		.filter(|p| p.func == "main")
+		.map(|p| (p.block.to_string(), p.from.to_string(), p.call))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		made,
+		[
+			("%call_term_for_1", "%entry", Some(0)),
+			("%call_term_for_2", "%entry", Some(1)),
+			("%call_term_for_3", "%other", Some(0)),
+			("%call_term_for_4", "%call_term_for_0", Some(0)),
+			("%no_block0_call_for_main", "%entry", None),
+		]
+		.iter()
+		.map(|(b, f, c)| (b.to_string(), f.to_string(), *c))
+		.collect::<Vec<_>>()
+	);
+	assert!(out
+		.code
+		.contains("main/%call_term_for_2 from %entry after call 1 (call continuation)"));
+
+	let ir = bfcc::dump_ir(&fixture("twocalls"), &opts).unwrap();
+	assert!(
+		ir.contains("%call_term_for_2: ; from %entry after call 1 (call continuation)"),
+		"{}",
+		ir
+	);
+}
+
+#[test]
+fn plain_status_lines_have_no_escapes() {
+	let diag = match brainfuq::compile_path(&fixture("float_add")) {
+		Err(CompileError::Unsupported(diags)) => diags[0].to_string(),
+		_ => panic!("compiled floating point"),
+	};
+
+	let line = term::plain::status(Status::Unsupported, &diag);
+	assert_eq!(
+		line,
+		"[unsupported] unsupported instruction '%s = fadd float %v, float 2.5' in main/%0"
+	);
+	assert!(!line.contains('\u{1b}'), "{:?}", line);
+
+	assert_eq!(term::plain::status(Status::Pass, "hi"), "[pass] hi");
+	assert_eq!(term::plain::dim(&diag), diag);
+	assert_eq!(term::plain::rewind(), "\n");
+}
+
+#[test]
+fn loads_stay_after_stores_through_pointers() {
+	let code = brainfuq::compile_path(&fixture("reuse_pointer")).unwrap();
+	assert_eq!(run(&code, ""), "AB");
+
+	let ir = bfcc::dump_ir(&fixture("reuse_pointer"), &bfcc::Options::default()).unwrap();
+	assert!(ir.contains("%y = load i8* %a"), "{}", ir);
+}
+
+#[test]
+fn loads_stay_after_calls() {
+	let opts = bfcc::Options {
+		inline_threshold: 0,
+		..bfcc::Options::default()
+	};
+	let out = bfcc::compile(&fixture("reuse_call"), &opts).unwrap();
+	assert_eq!(run(&out.code, ""), "!AA");
+
+	let ir = bfcc::dump_ir(&fixture("reuse_call"), &opts).unwrap();
+	assert!(ir.contains("%y = load i8* %a"), "{}", ir);
+}
+
+#[test]
+fn loads_stay_after_stores_in_other_blocks() {
+	let code = brainfuq::compile_path(&fixture("reuse_blocks")).unwrap();
+	assert_eq!(run(&code, "q"), "ABB");
+	assert_eq!(run(&code, "a"), "AAA");
+
+	let ir = bfcc::dump_ir(&fixture("reuse_blocks"), &bfcc::Options::default()).unwrap();
+	assert!(ir.contains("%y = load i8* %a"), "{}", ir);
+	assert!(!ir.contains("%z = load"), "{}", ir);
+}
+
+#[test]
+fn cond_br_only_writes_the_taken_edges_phis() {
+	let code = brainfuq::compile_path(&fixture("lost")).unwrap();
+	assert_eq!(run(&code, ""), "EF");
+}
+
+// Hand written programs for the interpreter: code, input and what running it
+// should give.
+const INTERP_CASES: &[(&str, &str, Result<&str, InterpErr>)] = &[
+	("+[-]", "", Ok("")),
+	("++++++++[>++++++++<-]>+.[-]", "", Ok("A")),
+	("+[]", "", Err(InterpErr::ProvablyInfinite { op_index: 1 })),
+	("+[><]", "", Err(InterpErr::ProvablyInfinite { op_index: 1 })),
+	("+.[.]", "", Err(InterpErr::ProvablyInfinite { op_index: 2 })),
+	// never entered, it's only a problem when the cell is nonzero
+	("[]", "", Ok("")),
+	// looks empty but reading can change the cell
+	("+[,]", "", Ok("")),
+	(",[.,]", "hi", Ok("hi")),
+	// two reads, two cells, put back out in order
+	(",>,<.>.[-]<[-]", "ab", Ok("ab")),
+	// input runs dry and the cell reads 0
+	(",.,[-]", "a", Ok("a")),
+	// add i32 x, 200 on a byte read as 5 counts up to 205, counting down to
+	// it instead runs out at 0
+	(",>++++++++++[<++++++++++++++++++++>-]<.[-]", "\u{5}", Ok("\u{cd}")),
+	(",>+++++++[<-------->-]<", "\u{5}", Err(InterpErr::IntUnderflow)),
+	// same net change on the loop cell but its neighbour keeps growing
+	("+[>+<]", "", Err(InterpErr::IntOverflow)),
+	("-", "", Err(InterpErr::IntUnderflow)),
+	// 16 * 16, the last + is one past 255
+	("++++++++++++++++[>++++++++++++++++<-]>", "", Err(InterpErr::IntOverflow)),
+	("<", "", Err(InterpErr::MemUnderflow)),
+	("+", "", Err(InterpErr::ExitMemNonZero)),
+];
+
+// Whole single file programs with their input after a !, split apart the same
+// way the debugger's --bang-input does before running.
+const BANG_CASES: &[(&str, Result<&str, InterpErr>)] = &[
+	// the comment loop's ! isn't the divider, and the input is all opcodes
+	("[echo it back!],[.,]!<+>.[]", Ok("<+>.[]")),
+	("+[-]", Ok("")),
+	("+[-!]", Ok("")),
+];
+
+#[test]
+fn interpreter_runs_hand_written_programs() {
+	let bang = BANG_CASES.iter().map(|(source, expected)| {
+		let (code, input) = split_input(source);
+		(code, input.unwrap_or(""), expected)
+	});
+
+	for (code, input, expected) in INTERP_CASES
+		.iter()
+		.map(|(code, input, expected)| (*code, *input, expected))
+		.chain(bang)
+	{
+		let ops = bf_bytecode(code);
+		let result = exec(ops.clone(), input.as_bytes(), 0, None);
+		let got = result.as_ref().map(|r| r.output.as_str());
+		assert_eq!(got, expected.as_ref().copied(), "{}", code);
+
+		// the packed ops and the plain reference have to agree on everything
+		let reference = exec_ops(ops, input.as_bytes(), 0, None);
+		assert_eq!(result, reference, "{}", code);
+	}
+}
+
+// Programs run the way a wrapping interpreter would, with the output and how
+// many times a cell went around
+const WRAP_CASES: &[(&str, &str, Result<usize, InterpErr>)] = &[
+	("+[-]", "", Ok(0)),
+	// combined into nothing before it ever runs
+	("-+", "", Ok(0)),
+	("-.+", "\u{ff}", Ok(2)),
+	("-[+]", "", Ok(2)),
+	("++++++++[>++++++++<-]>+.[-]", "A", Ok(0)),
+	// - on a zero cell and + on 255, kept apart by the move so they can't be
+	// combined
+	("-[>+<-]>+.", "\u{0}", Ok(2)),
+	// 16 * 16 lands back on 0
+	("++++++++++++++++[>++++++++++++++++<-]>", "", Ok(1)),
+	// only cells go around, the tape doesn't
+	("<", "", Err(InterpErr::MemUnderflow)),
+];
+
+#[test]
+fn wrapping_counts_every_time_a_cell_goes_around() {
+	for (code, output, wraps) in WRAP_CASES.iter() {
+		let res = exec_wrapping(bf_bytecode(code), &[], 0, None);
+		let got = res.as_ref().map(|r| (r.output.as_str(), r.wraps));
+		let want = wraps.as_ref().map(|w| (*output, *w));
+		assert_eq!(got, want, "{}", code);
+	}
+}
+
+// Short programs and every record their trace should come out with
+const TRACE_CASES: &[(&str, TraceConfig, &str)] = &[
+	(
+		"++[-]",
+		TraceConfig { on: TraceOn::Every(2), max_records: 10 },
+		"step=0 pc=0 mp=0 cell=0\n\
+		 step=2 pc=2 mp=0 cell=2\n\
+		 step=4 pc=2 mp=0 cell=1\n",
+	),
+	(
+		"++[-]",
+		TraceConfig { on: TraceOn::JmpIfZ, max_records: 10 },
+		"step=1 pc=1 mp=0 cell=2\n",
+	),
+	(
+		"++++++++[>++++++++<-]>+.[-]",
+		TraceConfig { on: TraceOn::Putchar, max_records: 10 },
+		"step=44 pc=9 mp=1 cell=65\n",
+	),
+	(
+		"++[-]",
+		TraceConfig { on: TraceOn::Every(1), max_records: 2 },
+		"step=0 pc=0 mp=0 cell=0\n\
+		 step=1 pc=1 mp=0 cell=2\n\
+		 capped at 2 records\n",
+	),
+];
+
+// records pick up the comment above where they were taken
+const ANNOTATE_CODE: &str = "zero it\n+\n[-]\nprint\n.";
+const ANNOTATE_CASES: &[(&str, &str)] = &[
+	("step=0 pc=0 mp=0 cell=0", "step=0 pc=0 mp=0 cell=0 at zero it\n"),
+	("step=2 pc=2 mp=0 cell=1", "step=2 pc=2 mp=0 cell=1 at zero it\n"),
+	("step=4 pc=4 mp=0 cell=0", "step=4 pc=4 mp=0 cell=0 at print\n"),
+	("capped at 2 records", "capped at 2 records\n"),
+];
+
+#[test]
+fn trace_records_what_it_was_asked_for() {
+	for (code, config, expected) in TRACE_CASES.iter() {
+		let mut records = vec![];
+		let res = trace(bf_bytecode(code), &[], 0, None, config, &mut records);
+		assert!(res.is_ok(), "{}", code);
+		assert_eq!(String::from_utf8_lossy(&records), *expected, "{}", code);
+	}
+
+	for (record, expected) in ANNOTATE_CASES.iter() {
+		assert_eq!(annotate_trace(ANNOTATE_CODE, record), *expected);
+	}
+}
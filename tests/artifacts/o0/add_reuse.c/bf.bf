@@ -0,0 +1,229 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 727 ops ~3191 steps
+frame widths:
+  main: 14 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l14 <<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_volatile_i8*_%1__align_1(mult)
+        #%7_=_zext_i8_%6_to_i32(mult)
+        #%8_=_add_i32_%7__i32_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store volatile i8 65_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++
+            >>>>>>>]
+            <<<<<<<+
+            %5 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 65
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            <<<<+
+            store i8 %5_ i8* %2_ align 1
+            
+            <<<[-]
+            >>>>>>++++++++
+            [
+                -
+                <<<<<<++++++++
+            >>>>>>]
+            <<<<<<+
+            %6 = load i8* %2_ align 1
+            
+            >>>[-]
+            known to be 65
+            >>>++++++++
+            [
+                -
+                <<<++++++++
+            >>>]
+            <<<+
+            %7 = zext i8 %6 to i32
+            
+            >[-]
+            known to be 65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            %8 = add i32 %7_ i32 1
+            
+            >[-]
+            known to be 66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            %9 = trunc i32 %8 to i8
+            
+            <<[-]
+            known to be 66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            store i8 %9_ i8* %3_ align 1
+            
+            <<<<<[-]
+            >>>>>++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %11 = zext i8 %6 to i32
+            
+            <<[-]
+            known to be 65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            %12 = add i32 %11_ i32 2
+            
+            >[-]
+            known to be 67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            %13 = trunc i32 %12 to i8
+            
+            <[-]
+            known to be 67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            store i8 %13_ i8* %4_ align 1
+            
+            <<[-]
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            %14 = load i8* %3_ align 1
+            
+            >>[-]
+            known to be 66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            %15 = load i8* %4_ align 1
+            
+            <<[-]
+            dup7/9/11 <<[->>+>>+<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %15)
+            
+            putchar intrinsic
+            dup9/11/12 <<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_65
+            ++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
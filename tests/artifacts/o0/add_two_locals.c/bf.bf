@@ -0,0 +1,296 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 911 ops ~3809 steps
+frame widths:
+  main: 17 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l17 <<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_volatile_i8*_%1__align_1(mult)
+        #%9_=_zext_i8_%8_to_i32(mult)
+        #%10_=_load_i8*_%4__align_1(mult)
+        #%11_=_zext_i8_%10_to_i32(mult)
+        #%12_=_add_i32_%9__i32_%11(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            store volatile i8 2_ i8* %1_ align 1
+            
+            >>[-]
+            ++
+            store volatile i8 65_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<++++++++
+            >>>>>>>>>]
+            <<<<<<<<<+
+            %6 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 2
+            ++
+            store i8 %6_ i8* %3_ align 1
+            
+            <<<[-]
+            ++
+            %7 = load volatile i8* %2_ align 1
+            
+            >>>[-]
+            known to be 65
+            >>>>>>++++++++
+            [
+                -
+                <<<<<<++++++++
+            >>>>>>]
+            <<<<<<+
+            store i8 %7_ i8* %4_ align 1
+            
+            <<[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++
+            >>>>>>>]
+            <<<<<<<+
+            %8 = load i8* %3_ align 1
+            
+            >>[-]
+            known to be 2
+            ++
+            %9 = zext i8 %8 to i32
+            
+            >[-]
+            known to be 2
+            ++
+            %10 = load i8* %4_ align 1
+            
+            >[-]
+            known to be 65
+            >>>++++++++
+            [
+                -
+                <<<++++++++
+            >>>]
+            <<<+
+            %11 = zext i8 %10 to i32
+            
+            >[-]
+            known to be 65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            %12 = add i32 %9_ i32 %11
+            
+            >[-]
+            known to be 67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            %13 = trunc i32 %12 to i8
+            
+            <<<[-]
+            known to be 67
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            store i8 %13_ i8* %5_ align 1
+            
+            <<[-]
+            >>>>>>++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            <<<<<<+
+            %14 = load i8* %5_ align 1
+            
+            >>[-]
+            known to be 67
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>#constop_67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            %16 = zext i8 %8 to i32
+            
+            <<<<[-]
+            known to be 2
+            ++
+            %17 = add i32 48_ i32 %16
+            
+            >>[-]
+            known to be 50
+            >>+++++
+            [
+                -
+                <<++++++++++
+            >>]
+            %18 = trunc i32 %17 to i8
+            
+            <<<<[-]
+            known to be 50
+            >>>>+++++
+            [
+                -
+                <<<<++++++++++
+            >>>>]
+            call @putchar(i8 %18)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_50
+            >+++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            [-]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            %21 = zext i8 %10 to i32
+            
+            <<<<[-]
+            known to be 65
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            <<<<+
+            %23 = zext i8 %8 to i32
+            
+            >[-]
+            known to be 2
+            ++
+            %24 = add i32 %21_ i32 %23
+            
+            <<[-]
+            known to be 67
+            >>>>>++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            <<<<<+
+            %25 = trunc i32 %24 to i8
+            
+            >[-]
+            known to be 67
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            call @putchar(i8 %25)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>#constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,489 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3194 ops ~52929 steps
+frame widths:
+  main: 25 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_volatile_i8*_%1__align_1(mult)
+        #%7_=_load_i8*_%6__align_1(mult)
+        #%9_=_load_i8**_%8__align_8(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8*_ align 8
+            %4 = alloca i8**_ align 8
+            store volatile i8 113_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++++++++
+            >>>>>>>]
+            <<<<<<<+
+            %5 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 113
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++++
+            >>>>]
+            <<<<+
+            store i8 %5_ i8* %2_ align 1
+            
+            <<<[-]
+            >>>>>>++++++++
+            [
+                -
+                <<<<<<++++++++++++++
+            >>>>>>]
+            <<<<<<+
+            store i8* %2_ i8** %3_ align 8
+            
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/12/14 <<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            mov14/0 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            r1 
+            <<++++++
+            <<<<<[-]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            store i8** %3_ i8*** %4_ align 8
+            
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/12/13 <<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            <<<<[-]
+            mov11/7 >>>>[-<<<<+>>>>]
+            %6 = load i8** %3_ align 8
+            
+            <<<[-]
+            dup6/8/11 <<[->>+>>>+<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            %7 = load i8* %6_ align 1
+            
+            <<[-]
+            dup8/11/12 <[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/14/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<]
+            mov14/0 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            r1 >
+            >>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov19/16 [-<<<+>>>]
+                <<<++++++++++++++
+            >>>]
+            <[
+                if neg
+                <<++++++++++++++
+                >>[
+                    -
+                    <<-
+                >>]
+            ]
+            drive left! choo choo
+            <<[
+                mov15/14 <[-<+>]
+                mov16/15 >[-<+>]
+                
+                mov13/16 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup13/14/16 <<<[->+>>+<<<]
+            mov14/13 >[-<+>]
+            >[
+                mov17/14 >>[-<<<+>>>]
+                mov16/17 <[->+<]
+                mov15/16 <[->+<]
+                r1 >
+                -
+            ]
+            mov16/9 >[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup9/11/12 <<<<<<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            %8 = load i8*** %4_ align 8
+            
+            <<<[-]
+            dup7/9/11 <<[->>+>>+<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            %9 = load i8** %8_ align 8
+            
+            <[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/22 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<[
+                -
+                >>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov22/15 [-<<<<<<<+>>>>>>>]
+                <<<<<<<+++++++++++++
+            >>>>>>>]
+            <<<<[
+                if neg
+                <<<+++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            >[
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
+                r1 >
+                -
+            ]
+            mov15/10 >[-<<<<<+>>>>>]
+            %10 = load i8* %9_ align 1
+            
+            <<<<<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            >[
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
+                r1 >
+                -
+            ]
+            mov15/9 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup9/11/12 <<<<<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            %11 = load i8* %2_ align 1
+            
+            <<<[-]
+            dup5/9/11 <<<<[->>>>+>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %12 = zext i8 %11 to i32
+            
+            <[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            mov12/10 >[-<<+>>]
+            %13 = add i32 %12_ i32 1
+            
+            <<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov12/9 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/15/17 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov17/9 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov14/15 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov15/14 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %14 = trunc i32 %13 to i8
+            
+            <<<<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            mov12/10 >[-<<+>>]
+            store i8 %14_ i8* %2_ align 1
+            
+            dup10/11/12 <<[->+>+<<]
+            mov11/10 >[-<+>]
+            <<<<<<[-]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %16 = load i8* %6_ align 1
+            
+            <<<[-]
+            dup8/11/12 <[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            >[
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
+                r1 >
+                -
+            ]
+            mov15/9 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            dup9/11/12 <<<<<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,705 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 7153 ops ~110102 steps
+frame widths:
+  main: 39 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%9
+        #B:%20
+        #B:%21
+        #B:%25
+        #B:%33
+        #alloc_%1
+        #alloc_%2
+        #alloc_%2{1}
+        #alloc_%2{2}
+        #alloc_%2{3}
+        #alloc_%3
+        #alloc_%4
+        #%6_=_load_i8*_%3__align_1(mult)
+        #%7_=_zext_i8_%6_to_i32(mult)
+        #%12_=_add_i32_97__i32_%11(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca {4 x i8}_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>[-]
+            
+            store i8 0_ i8* %3_ align 1
+            
+            >>>>>[-]
+            
+            br label %5
+            
+            <<<<<<<<<<<+
+        <<]
+        >>#B:%5
+        [
+            -
+            %6 = load i8* %3_ align 1
+            
+            >>>>>>>>>>>>>[-]
+            dup15/17/20 <<[->>+>>>+<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            %7 = zext i8 %6 to i32
+            
+            <<[-]
+            dup17/21/20 <[->>>>+<+<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            mov20/18 <[-<<+>>]
+            %8 = icmp slt i32 %7_ i32 4
+            
+            <<<[-]
+            dup18/20/22 >[->>+>>+<<<<]
+            mov20/18 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >#constop_4
+            ++++
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/25 <<[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/30 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/33 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
+            br i1 %8_ label %9_ label %20
+            
+            dup17/21/20 <<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%9_true
+            >>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%20_false
+            <<<]
+        >]
+        >#B:%9
+        [
+            -
+            %10 = load i8* %3_ align 1
+            
+            >>>>>>>>>>>>[-]
+            dup15/17/22 <<[->>+>>>>>+<<<<<<<]
+            mov22/15 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/18 >[-<<<+>>>]
+            %12 = add i32 97_ i32 %11
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            dup18/22/23 <<[->>>>+>+<<<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            mov20/19 <<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup19/25/27 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov27/19 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov24/25 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov25/24 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %13 = trunc i32 %12 to i8
+            
+            <<<<<<[-]
+            dup19/20/21 >[->+>+<<]
+            mov20/19 >[-<+>]
+            mov21/18 >[-<<<+>>>]
+            %15 = zext i8 %10 to i64
+            
+            <<[-]
+            dup17/20/21 <<[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/19 >[-<<+>>]
+            %16 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 %15
+            
+            <<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/21/22 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++++++
+            dup19/22/23 <[->>>+>+<<<<]
+            mov22/19 >>>[-<<<+>>>]
+            mov20/17 <<[-<<<+>>>]
+            >>>[
+                -
+                <<<<<<+
+            >>>>>>]
+            store i8 %13_ i8* %16_ align 1
+            
+            dup18/20/21 <<<<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            dup17/22/23 <<<[->>>>>+>+<<<<<<]
+            mov22/17 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/29/30 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/31 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov31/26 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov21/27 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov25/24 <[-<+>]
+                mov26/25 >[-<+>]
+                mov27/26 >[-<+>]
+                mov23/27 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov27/23 >>>>[-<<<<+>>>>]
+            <<[
+                mov28/24 >>>[-<<<<+>>>>]
+                
+                mov25/26 <<<[->+<]
+                r1 >
+                -
+            ]
+            %18 = load i8* %3_ align 1
+            
+            <<<<<<<<[-]
+            dup15/17/20 <<[->>+>>>+<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            %19 = add i8 %18_ i8 1
+            
+            <<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/18 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup18/24/26 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov26/18 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %19_ i8* %3_ align 1
+            
+            dup18/20/21 <<<<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            <<<<<[-]
+            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
+            br label %5
+            
+            <<<<<<<<<<<<<<<<<+
+        >]
+        >#B:%20
+        [
+            -
+            store i8 0_ i8* %4_ align 1
+            
+            >>>>>>>>>>[-]
+            
+            br label %21
+            
+            <<<<<<<<<+
+        <]
+        >#B:%21
+        [
+            -
+            %22 = load i8* %4_ align 1
+            
+            >>>>>>>>>>[-]
+            dup16/17/20 <[->+>>>+<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            %23 = zext i8 %22 to i32
+            
+            <<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/18 >[-<<<+>>>]
+            %24 = icmp slt i32 %23_ i32 4
+            
+            <<<<[-]
+            dup18/20/21 >[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_4
+            ++++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/25 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/30 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/36 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                
+                >>>>>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %24_ label %25_ label %33
+            
+            dup17/21/20 <<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%25_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%33_false
+            <<<<<<]
+        >>>>]
+        >#B:%25
+        [
+            -
+            %26 = load i8* %4_ align 1
+            
+            >>>>>>>>>[-]
+            dup16/17/22 <[->+>>>>>+<<<<<<]
+            mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            %27 = zext i8 %26 to i64
+            
+            <<<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/18 >[-<<<+>>>]
+            %28 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 %27
+            
+            <<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/21/22 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++++++
+            dup18/22/23 <<[->>>>+>+<<<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            mov20/19 <<[-<+>]
+            >>>[
+                -
+                <<<<+
+            >>>>]
+            %29 = load i8* %28_ align 1
+            
+            <<<<<[-]
+            dup19/20/21 >[->+>+<<]
+            mov20/19 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/26/27 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov26/0 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/28 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov28/24 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                
+                mov21/24 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup21/22/24 <<<[->+>>+<<<]
+            mov22/21 >[-<+>]
+            >[
+                mov25/22 >>[-<<<+>>>]
+                mov24/25 <[->+<]
+                mov23/24 <[->+<]
+                r1 >
+                -
+            ]
+            mov24/18 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %29)
+            
+            putchar intrinsic
+            dup18/20/21 <<<<<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            >.
+            [-]
+            %32 = add i8 %26_ i8 1
+            
+            <<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/18 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup18/24/26 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov26/18 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %32_ i8* %4_ align 1
+            
+            dup18/20/21 <<<<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            <<<<[-]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            br label %21
+            
+            <<<<<<<<<<<<<<+
+        >]
+        >#B:%33
+        [
+            -
+            %34 = load i32* %1_ align 4
+            
+            >>>>>>>>[-]
+            dup10/17/20 <<<<<<<[->>>>>>>+>>>+<<<<<<<<<<]
+            mov20/10 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            ret i32 %34
+            
+            zero all function allocs
+            <<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+    <<<<<<<<]
+<]
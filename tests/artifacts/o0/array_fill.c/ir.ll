@@ -0,0 +1,167 @@
+; ModuleID = './tests/artifacts/o0/array_fill.c/ir.bc'
+source_filename = "./tests/cases/array_fill.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @main() #0 {
+  %1 = alloca i32, align 4
+  %2 = alloca [4 x i8], align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  store i32 0, i32* %1, align 4
+  store i8 0, i8* %3, align 1
+  br label %5
+
+5:                                                ; preds = %17, %0
+  %6 = load i8, i8* %3, align 1
+  %7 = zext i8 %6 to i32
+  %8 = icmp slt i32 %7, 4
+  br i1 %8, label %9, label %20
+
+9:                                                ; preds = %5
+  %10 = load i8, i8* %3, align 1
+  %11 = zext i8 %10 to i32
+  %12 = add nsw i32 97, %11
+  %13 = trunc i32 %12 to i8
+  %14 = load i8, i8* %3, align 1
+  %15 = zext i8 %14 to i64
+  %16 = getelementptr inbounds [4 x i8], [4 x i8]* %2, i64 0, i64 %15
+  store i8 %13, i8* %16, align 1
+  br label %17
+
+17:                                               ; preds = %9
+  %18 = load i8, i8* %3, align 1
+  %19 = add i8 %18, 1
+  store i8 %19, i8* %3, align 1
+  br label %5, !llvm.loop !10
+
+20:                                               ; preds = %5
+  store i8 0, i8* %4, align 1
+  br label %21
+
+21:                                               ; preds = %30, %20
+  %22 = load i8, i8* %4, align 1
+  %23 = zext i8 %22 to i32
+  %24 = icmp slt i32 %23, 4
+  br i1 %24, label %25, label %33
+
+25:                                               ; preds = %21
+  %26 = load i8, i8* %4, align 1
+  %27 = zext i8 %26 to i64
+  %28 = getelementptr inbounds [4 x i8], [4 x i8]* %2, i64 0, i64 %27
+  %29 = load i8, i8* %28, align 1
+  call void @putchar(i8 noundef zeroext %29)
+  br label %30
+
+30:                                               ; preds = %25
+  %31 = load i8, i8* %4, align 1
+  %32 = add i8 %31, 1
+  store i8 %32, i8* %4, align 1
+  br label %21, !llvm.loop !11
+
+33:                                               ; preds = %21
+  %34 = load i32, i32* %1, align 4
+  ret i32 %34
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
+!10 = distinct !{!10, !7}
+!11 = distinct !{!11, !7}
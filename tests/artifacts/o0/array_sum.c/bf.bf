@@ -0,0 +1,807 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 7233 ops ~103042 steps
+frame widths:
+  main: 37 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l37 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%9
+        #B:%13
+        #B:%26
+        #alloc_%1
+        #alloc_%2
+        #alloc_%2{1}
+        #alloc_%2{2}
+        #alloc_%2{3}
+        #alloc_%3
+        #alloc_%4
+        #%5_=_getelementptr_inbounds_{4_x_i8}*_%2__i64_0__i64_0(mult)
+        #%11_=_zext_i8_%10_to_i32(mult)
+        #%16_=_getelementptr_inbounds_{4_x_i8}*_%2__i64_0__i64_%15(mult)
+        #%20_=_zext_i8_%19_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca {4 x i8}_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            %5 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 0
+            
+            >>>>>>>[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/19/20 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov18/14 [-<<<<+>>>>]
+            >>[
+                -
+                <<<<<<+
+            >>>>>>]
+            store i8 10_ i8* %5_ align 1
+            
+            op_to_reg storing const value in temp address
+            <<#constop_10
+            ++++++++++
+            dup14/19/21 <<<<[->>>>>+>>+<<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/21/27 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            >>>>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/28 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov28/24 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            mov18/25 <<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                mov25/24 >[-<+>]
+                mov21/25 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<[
+                mov26/22 >>>[-<<<<+>>>>]
+                
+                mov23/24 <<<[->+<]
+                r1 >
+                -
+            ]
+            %6 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 1
+            
+            <<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/19/20 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov18/14 <<[-<<<<+>>>>]
+            >>[
+                -
+                <<<<<<+
+            >>>>>>]
+            store i8 20_ i8* %6_ align 1
+            
+            op_to_reg storing const value in temp address
+            <#constop_20
+            ++++
+            [
+                -
+                <+++++
+            >]
+            dup14/20/21 <<<<<[->>>>>>+>+<<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov27/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/31 [->>>>+<<<<]
+            >>>>>>+
+            <<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov31/24 [-<<<<<<<+>>>>>>>]
+                <<<<<<<++++++++++++++++++++++
+            >>>>>>>]
+            <<<[
+                if neg
+                <<<<++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov18/25 <<<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                mov25/24 >[-<+>]
+                mov21/25 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<[
+                mov26/22 >>>[-<<<<+>>>>]
+                
+                mov23/24 <<<[->+<]
+                r1 >
+                -
+            ]
+            %7 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 2
+            
+            <<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/19/20 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            mov18/14 <<[-<<<<+>>>>]
+            >>[
+                -
+                <<<<<<+
+            >>>>>>]
+            store i8 30_ i8* %7_ align 1
+            
+            op_to_reg storing const value in temp address
+            <#constop_30
+            +++++
+            [
+                -
+                <++++++
+            >]
+            dup14/20/21 <<<<<[->>>>>>+>+<<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov27/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/29 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov29/24 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov18/25 <<<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                mov25/24 >[-<+>]
+                mov21/25 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<[
+                mov26/22 >>>[-<<<<+>>>>]
+                
+                mov23/24 <<<[->+<]
+                r1 >
+                -
+            ]
+            %8 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 3
+            
+            <<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/19/20 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            op_to_reg storing const value in temp address
+            >>#constop_3
+            +++
+            mov18/14 <<[-<<<<+>>>>]
+            >>[
+                -
+                <<<<<<+
+            >>>>>>]
+            store i8 5_ i8* %8_ align 1
+            
+            op_to_reg storing const value in temp address
+            <<#constop_5
+            +++++
+            dup14/19/20 <<<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/26/27 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov26/0 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/28 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov28/23 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov18/24 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov22/21 <[-<+>]
+                mov23/22 >[-<+>]
+                mov24/23 >[-<+>]
+                mov20/24 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov24/20 >>>>[-<<<<+>>>>]
+            <<[
+                mov25/21 >>>[-<<<<+>>>>]
+                
+                mov22/23 <<<[->+<]
+                r1 >
+                -
+            ]
+            store i8 0_ i8* %3_ align 1
+            
+            <<<<<<<<<<[-]
+            
+            store i8 0_ i8* %4_ align 1
+            
+            >[-]
+            
+            br label %9
+            
+            <<<<<<<<<+
+        <<]
+        >>#B:%9
+        [
+            -
+            %10 = load i8* %4_ align 1
+            
+            >>>>>>>>>>[-]
+            dup13/14/18 <[->+>>>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<[-]
+            dup14/18/19 <[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov19/15 >[-<<<<+>>>>]
+            %12 = icmp slt i32 %11_ i32 4
+            
+            <<<<<[-]
+            dup15/18/19 >[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_4
+            ++++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/23 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/28 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/34 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                
+                >>>>>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %12_ label %13_ label %26
+            
+            dup14/19/18 <<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%13_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%26_false
+            <<<]
+        >]
+        >#B:%13
+        [
+            -
+            %14 = load i8* %4_ align 1
+            
+            >>>>>>>>>[-]
+            dup13/14/20 <[->+>>>>>>+<<<<<<<]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %15 = zext i8 %14 to i64
+            
+            <<<<<[-]
+            dup14/18/19 <[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov19/15 >[-<<<<+>>>>]
+            %16 = getelementptr inbounds {4 x i8}* %2_ i64 0_ i64 %15
+            
+            <<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/19/20 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            dup15/20/21 <<<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov18/16 <<[-<<+>>]
+            >>>[
+                -
+                <<<<<+
+            >>>>>]
+            %17 = load i8* %16_ align 1
+            
+            <<<<<<[-]
+            dup16/18/19 >[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/24/25 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov24/0 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/26 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov26/22 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov21/20 <[-<+>]
+                mov22/21 >[-<+>]
+                
+                mov19/22 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup19/20/22 <<<[->+>>+<<<]
+            mov20/19 >[-<+>]
+            >[
+                mov23/20 >>[-<<<+>>>]
+                mov22/23 <[->+<]
+                mov21/22 <[->+<]
+                r1 >
+                -
+            ]
+            mov22/15 >[-<<<<<<<+>>>>>>>]
+            %18 = zext i8 %17 to i32
+            
+            <<<<<<[-]
+            dup15/18/19 <[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            mov19/16 >[-<<<+>>>]
+            %19 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup12/15/18 <<<[->>>+>>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            %20 = zext i8 %19 to i32
+            
+            <[-]
+            dup15/18/19 <<[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            mov19/17 >[-<<+>>]
+            %21 = add i32 %20_ i32 %18
+            
+            <<<<[-]
+            dup17/18/19 >>[->+>+<<]
+            mov18/17 >[-<+>]
+            dup16/20/21 <<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            mov19/15 <[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/23/25 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov25/15 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov22/23 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov23/22 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %22 = trunc i32 %21 to i8
+            
+            <<<<<<[-]
+            dup15/18/19 <[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            mov19/16 >[-<<<+>>>]
+            store i8 %22_ i8* %3_ align 1
+            
+            dup16/18/19 <<<[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            <<<<<<[-]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %25 = add i8 %14_ i8 1
+            
+            <<<<[-]
+            dup14/18/19 <[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov19/15 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/22/24 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov24/15 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %25_ i8* %4_ align 1
+            
+            dup15/18/19 <<<<<<[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            br label %9
+            
+            <<<<<<<<<<<<<<<+
+        >]
+        >#B:%26
+        [
+            -
+            %27 = load i8* %3_ align 1
+            
+            >>>>>>>>[-]
+            dup12/14/18 <<[->>+>>>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            call @putchar(i8 %27)
+            
+            putchar intrinsic
+            dup14/18/19 <<<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %28 = load i32* %1_ align 4
+            
+            <<<<<[-]
+            dup7/14/18 <<<<<<<[->>>>>>>+>>>>+<<<<<<<<<<<]
+            mov18/7 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            ret i32 %28
+            
+            zero all function allocs
+            <<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
@@ -1,358 +1,166 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %6 after call 0 (call continuation)
+  main/%call_term_for_1 from %16 after call 0 (call continuation)
+call graph:
+  putdec (depth 1)
+  main calls putdec (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  putdec: 4216 ops ~275319 steps
+  main: 4660 ops ~445898 steps
+frame widths:
+  putdec: 32 cells
+  main: 31 cells
+r5 >>>>>
++++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
+<<<[
     >#putdec
     [
         >>>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<<-
             #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            >-
+            #dead_fn_pad/putdec
+            >>>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        #mainloop_putdec
+        #F:putdec
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%7
+        #B:%8
+        #B:%13
+        #B:%16
+        #B:%17
+        #B:%20
+        #B:%29
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i32*_%2__align_4(mult)
+        #%5_=_icmp_ne_i32_%4__i32_0(mult)
+        #%11_=_udiv_i32_%9__i32_%10(mult)
+        #%0(mult)
+        <#B:%1
         [
             -
             copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            #arg_%0
+            l2 
+            mov0/20 <<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
             r2 >>
             %2 = alloca i32_ align 4
             %3 = alloca i32_ align 4
             store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            
+            dup18/19/20 >>>>>>>>>>>>>>>>>>[->+>+<<]
+            mov19/18 >[-<+>]
+            <<<<<<[-]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
             %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<<<[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
             %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
+            <<<[-]
+            dup15/19/21 <[->>>>+>>+<<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
+                <<<<<+
+            >>>>>]
             br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
+            
+            dup16/20/19 <<<<<[->>>>+<+<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<-
+                >>+
+                #B:putdec/%7_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<[
                 -
                 >+
-                #B:putdec/6_false
+                #B:putdec/%6_false
             <]
         <]
         >>#B:%6
         [
             -
             call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>#constop_48
+            ++++++
+            [
+                -
+                >++++++++
+            <]
+            >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
             br label %29
-            >>>>>>>>+
-        <<<<<<<<]
+            
+            <<<<<<<<<<+
+        <<<<<<<]
         >#B:%7
         [
             -
             store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
+            
+            >>>>>>>>[-]
             +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
             br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<+
         <]
         >#B:%8
         [
             -
             %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
             %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
+            
+            <<<[-]
+            dup14/16/19 <<[->>+>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %11 = udiv i32 %9_ i32 %10
+            
+            <<[-]
+            dup15/19/20 <<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            dup16/21/23 <<<[->>>>>+>>+<<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            dup23/24/25 >>[->+>+<<]
+            mov25/23 >>[-<<+>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/26 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
                     -
-                    <+
+                    <<+
                     >>>>[
                         -
                         <<<<-
@@ -363,35 +171,36 @@ runtime init:
                         l1 <
                     ]
                     l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
+                <<]
+                >>>>-
+                <<<<<<<<<<<+
+                >>>>>[
                     [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+                    <<<<<-
+                >>>>>]
+                mov26/20 >>>>[-<<<<<<+>>>>>>]
+                dup23/24/25 <<<[->+>+<<]
+                mov25/23 >>[-<<+>>]
+            <<<<<]
+            >>>[-]
+            >[-]
+            %12 = icmp uge i32 %11_ i32 10
+            
+            <<<<<<<<<[-]
+            dup17/19/20 >>[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/22 [->+<]
+            >>>+
+            <<<<[
                 -
                 
-                >[
+                >>[
                     -
                     
                     r1 >
@@ -401,184 +210,193 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
-            <<[
+            <<]
+            >>>>-
+            <<<<<<<<<+
+            >>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
+                <<<<<<<[-]
+            >>>>>>>]
             br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
+            
+            dup15/20/19 <<<<<<<[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<-
+                >>>>+
+                #B:putdec/%13_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<<[
                 -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
+                >>>>>+
+                #B:putdec/%16_false
+            <<<<<]
+        >>>]
         >#B:%13
         [
             -
             %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>[-]
+            dup14/15/21 <[->+>>>>>>+<<<<<<<]
+            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
             %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            
+            <<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            [
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
                 -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
+                mov20/25 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov22/23 [->+<]
+                        <<<<<<+
+                        >>>>>>>>-
+                    <<]
+                    mov23/22 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<[-]
+                        >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
             <[-]
+            >>[-]
             store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup16/19/20 <<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
             br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<<<<<<<+
         >]
         >#B:%16
         [
             -
             br label %17
+            
             >+
         <]
         >#B:%17
         [
             -
             %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>[-]
+            dup14/15/19 <[->+>>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
             %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >[
                 [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
+                <<<<+
+            >>>>]
             br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup16/20/19 <<<<[->>>>+<+<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:putdec/%20_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
+                >>>>>>>>+
+                #B:putdec/%29_false
+            <<<<<<<<]
+        >>>>>>]
         >#B:%20
         [
             -
             %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>[-]
+            dup13/15/21 <<[->>+>>>>>>+<<<<<<<<]
+            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
             %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
+            
+            <<<<<[-]
+            dup14/16/19 <<[->>+>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %23 = udiv i32 %21_ i32 %22
+            
+            <<[-]
+            dup15/19/20 <<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            dup22/24/25 >[->>+>+<<<]
+            mov25/22 >>>[-<<<+>>>]
             <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/29 [->>>>>>>>>+<<<<<<<<<]
+                >>>>>>>>>>>+
+                <<<<<<<[
                     -
                     <+
-                    >>>[
+                    >>>>>>[
                         -
-                        <<<-
+                        <<<<<<-
                         r1 >
-                    >>>]
+                    >>>>>>]
                     r1 >
                     [
                         l1 <
                     ]
                     l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
+                <<<<<]
+                >>>>>>>-
+                <<<<<<<<<<<<<<+
+                >>>>>>[
                     [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
+                    <<<<<<-
+                >>>>>>]
+                mov29/20 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                dup22/24/25 <<<<<<<[->>+>+<<<]
+                mov25/22 >>>[-<<<+>>>]
             <<<<<]
             >>[-]
             >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %24 = urem i32 %23_ i32 10
+            
+            <<<<<<<<<[-]
+            dup17/19/20 >>[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
+            dup21/23/24 [->>+>+<<<]
+            mov24/21 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
             <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/25 [->>>>>+<<<<<]
                 >>>>>>>+
                 <<<<[
                     -
@@ -595,69 +413,86 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
+                mov25/20 <<[-<<<<<+>>>>>]
+                dup21/23/24 <<<<[->>+>+<<<]
+                mov24/21 >>>[-<<<+>>>]
             <<<<]
             >>#neg
             [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
+                mov23/15 >[-<<<<<<<<+>>>>>>>>]
                 <[
                     -
-                    <<<<<<<<<-
-                >>>>>>>>>]
+                    <<<<<<<-
+                >>>>>>>]
             ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
+            mov22/15 [-<<<<<<<+>>>>>>>]
             <[-]
             >>[-]
             %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
+            
+            <<<<<<[-]
             op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
+            >>#constop_48
+            >++++++
+            [
                 -
-                <<<<<<<+
-            >>>>>>>]
+                <++++++++
+            >]
+            dup15/21/22 <<<<<[->>>>>>+>+<<<<<<<]
+            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
+            mov19/17 <<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/24/26 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov26/17 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
+            
+            <<<<<<<<[-]
+            dup17/19/20 >>[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/15 >[-<<<<<+>>>>>]
             call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
+            
             putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
+            dup15/19/20 <<<<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
+            %28 = udiv i32 %22_ i32 10
+            
+            <<<<<[-]
+            dup16/19/20 >[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
+            dup21/23/24 [->>+>+<<<]
+            mov24/21 >>>[-<<<+>>>]
             <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/25 [->>>>>+<<<<<]
                 >>>>>>>+
                 <<<<[
                     -
@@ -674,29 +509,32 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                <<<<<<<<<<+
-                >>>>>[
+                <<<<<<<<<<<<+
+                >>>>>>>[
                     [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
+                    <<<<<<<-
+                >>>>>>>]
+                mov25/20 >>>[-<<<<<+>>>>>]
+                dup21/23/24 <<<<[->>+>+<<<]
+                mov24/21 >>>[-<<<+>>>]
             <<<<]
             >[-]
             >>[-]
             store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup15/19/20 <<<<<<<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            <<<<<[-]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
             br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
+            
+            <<<<<<<<<<+
+        >]
         >#B:%29
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
@@ -704,110 +542,82 @@ runtime init:
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<<<<-
             #dead_frame
-            >>-
+            >-
             l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
+            <[-]
+            r1 
+            l7 
+        >>>>>>]
+    <<<<<<<<<<<]
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<<-
             #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l31 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%9
-        >#B:%12
-        >#B:%13
-        >#B:%16
-        >#B:%call_term_for_8
-        >#B:%call_term_for_9
-        >#B:%19
-        >#B:%22
-        >#alloc_%1
-        >#alloc_%2
-        >#%4_=_load_i32*_%1__align_4(mult)
-        >#%5_=_icmp_ult_i32_%4__i32_8(mult)
-        >#%7_=_load_i32*_%1__align_4(mult)
-        >#%8_=_shl_i32_1__i32_%7(mult)
-        >#%10_=_load_i32*_%1__align_4(mult)
-        >#%11_=_add_i32_%10__i32_1(mult)
-        >#%14_=_load_i32*_%2__align_4(mult)
-        >#%15_=_icmp_ult_i32_%14__i32_8(mult)
-        >#%17_=_load_i32*_%2__align_4(mult)
-        >#%18_=_ashr_i32_128__i32_%17(mult)
-        >#%20_=_load_i32*_%2__align_4(mult)
-        >#%21_=_add_i32_%20__i32_1(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%0
+        #mainloop_main
+        #F:putdec
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%6
+        #B:%call_term_for_0
+        #B:%12
+        #B:%13
+        #B:%16
+        #B:%call_term_for_1
+        #B:%22
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i32*_%1__align_4(mult)
+        #%5_=_icmp_ult_i32_%4__i32_8(mult)
+        <#B:%0
         [
             -
             copy up args
             %1 = alloca i32_ align 4
             %2 = alloca i32_ align 4
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<[-]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            >>>>>>>>>>[-]
+            
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<<+
         <<]
         >>#B:%3
         [
             -
             %4 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup18/20/33 <<[->>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
-            mov33/18 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>[-]
+            dup13/15/17 <<[->>+>>+<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
             %5 = icmp ult i32 %4_ i32 8
-            <<<<<<<<<<<<[-]
-            dup20/32/34 <[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            <[-]
+            dup15/18/17 <[->>>+<+<<]
+            mov18/15 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >#constop_8
             ++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<[
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/20 [->+<]
+            >>>+
+            <<<<<[
                 -
                 
-                >[
+                >>>[
                     -
                     
                     r1 >
@@ -817,169 +627,219 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
+            <<<]
+            >>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
+                <<<<+
+            >>>>]
             br i1 %5_ label %6_ label %12
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
+            
+            dup16/18/17 <<<<[->>+<+<]
+            mov18/16 >>[-<<+>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<-
                 >>+
-                #B:main/6_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
+                #B:main/%6_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<[
                 -
-                >>>>>>+
-                #B:main/12_false
-            <<<<<<]
+                >>>>+
+                #B:main/%12_false
+            <<<<]
         >]
         >#B:%6
         [
             -
             %7 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup18/22/32 <<<<[->>>>+>>>>>>>>>>+<<<<<<<<<<<<<<]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
             %8 = shl i32 1_ i32 %7
-            <<<<<<<<<[-]
+            
+            <<<[-]
             op_to_reg storing const value in temp address
-            >>>>>>>>>#constop_1
+            >#constop_1
             +
-            dup22/33/34 <<<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov33/22 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            dup15/18/19 <<[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
             >>+
             <[
                 -
-                mov23/32 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>[
+                mov16/17 <<<[->+<]
+                >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<<[
                     -
-                    <<<<<<<<<++
-                >>>>>>>>>]
-                >>>[-]
+                    >>>>>>>>+
+                    <<[
+                        -
+                        mov23/24 [->+<]
+                        <<<<<<<+
+                        >>>>>>>>>-
+                    <<]
+                    mov24/23 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<[-]
+                        >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                    +
+                    <<[
+                        -
+                        mov23/24 [->+<]
+                        <<<<<<<+
+                        >>>>>>>>>-
+                    <<]
+                    mov24/23 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<[-]
+                        >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                <<<<<<<<]
+                >>>>>>[-]
+                <<<[-]
             <]
             >[
                 -
-                mov32/23 <<<[-<<<<<<<<<+>>>>>>>>>]
+                mov17/16 <<<[-<+>]
             >>>]
             call @putdec(i32 %8)
+            
             enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_2
+            <<<<<<<<<<<<<#caller/%call_term_for_0
             +
-            stack_width 41
-            ret_pad_width 5
+            stack_width 31
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup23/32/33 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            mov33/47 >[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>#arg_0
+            dup16/17/18 [->+>+<<]
+            mov17/16 >[-<+>]
+            mov18/36 >[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/49/50 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov50/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r41 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/38/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r31 
+            <<<<<<<#JUMP_PAD
             +
-            >>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
+            r7 >>>
+            #===FRAME_putdec
             +
-            >>#putdec
+            >#putdec
             +
             >>#putdec/b0
             +
         >>>]
-        >#B:%call_term_for_2
+        >#B:%call_term_for_0
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_3
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
-            br label %9
-            >+
-        <]
-        >#B:%9
-        [
-            -
             %10 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup18/24/32 <<<<<<[->>>>>>+>>>>>>>>+<<<<<<<<<<<<<<]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup13/15/17 <<[->>+>>+<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
             %11 = add i32 %10_ i32 1
-            <<<<<<<[-]
-            dup24/32/33 <[->>>>>>>>+>+<<<<<<<<<]
-            mov32/24 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            
+            <[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov33/25 <[-<<<<<<<<+>>>>>>>>]
-            >[
+            mov18/16 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/21/23 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov23/16 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
                 -
-                <<<<<<<<<+
-            >>>>>>>>>]
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %11_ i32* %1_ align 4
-            dup25/32/33 <<<<<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<<<[-]
-            mov33/18 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            dup16/17/18 <<<<[->+>+<<]
+            mov17/16 >[-<+>]
+            <<<<[-]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>]
+            
+            <<<<<<<<<<<<<+
+        >>]
         >#B:%12
         [
             -
             store i32 0_ i32* %2_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<[-]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >>>>>>[-]
+            
             br label %13
-            <<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<+
         <]
         >#B:%13
         [
             -
             %14 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup19/26/32 <<<<<<<[->>>>>>>+>>>>>>+<<<<<<<<<<<<<]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            
+            >>>>>>[-]
+            dup14/15/17 <[->+>>+<<<]
+            mov17/14 >>>[-<<<+>>>]
             %15 = icmp ult i32 %14_ i32 8
-            <<<<<[-]
-            dup26/32/33 <[->>>>>>+>+<<<<<<<]
-            mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+            
+            <[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_8
             ++++++++
-            >>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/38 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<<<<[
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/20 [->+<]
+            >>>+
+            <<<<[
                 -
                 
-                >>>>>[
+                >>[
                     -
                     
                     r1 >
@@ -989,162 +849,179 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<<<]
-            >>>>>>>-
+            <<]
+            >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<+
-            >>>>>>>>>>>]
+                <<<<+
+            >>>>]
             br i1 %15_ label %16_ label %22
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup16/18/17 <<<<[->>+<+<]
+            mov18/16 >>[-<<+>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:main/16_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%16_true
+            >>>>>>>]
+            <<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>+
-                #B:main/22_false
-            <<<<<<<<<<<<]
-        >>>>>>>]
+                >>>>>>>>+
+                #B:main/%22_false
+            <<<<<<<<]
+        >>>>>]
         >#B:%16
         [
             -
             %17 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup19/28/32 <<<<<<<<<[->>>>>>>>>+>>>>+<<<<<<<<<<<<<]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            
+            >>>>>[-]
+            dup14/15/19 <[->+>>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
             %18 = ashr i32 128_ i32 %17
+            
             <<<[-]
             op_to_reg storing const value in temp address
-            >>>#constop_128
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            dup28/33/34 <<<<[->>>>>+>+<<<<<<]
-            mov33/28 >>>>>[-<<<<<+>>>>>]
-            mov32/29 <[-<<<+>>>]
-            >>>>>+
-            <<<[
+            >#constop_128
+            >++++++++
+            [
                 -
-                mov29/35 <<<<<[->>>>>>+<<<<<<]
-                >>>>>>[
+                <++++++++++++++++
+            >]
+            dup15/19/20 <<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov17/16 <<[-<+>]
+            >>>>>>>>>>>+
+            <<<<<<<<[
+                -
+                mov16/26 <<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >>>>>>>>>>[
                     -
                     [
                         -
-                        <<<<<<+
-                    >>>>>>>]
+                        <<<<<<<<<<+
+                    >>>>>>>>>>>]
                     >[
                     <]
                 <]
-            <]
-            >>>[-]
+            <<<<<<]
+            >>>>>>>>[-]
             call @putdec(i32 %18)
+            
             enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
+            <<<<<<<<<<<<<<<<<#caller/%call_term_for_1
             +
-            stack_width 41
-            ret_pad_width 5
+            stack_width 31
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup29/32/33 <<<<<<<<<<<<<<<<<<[->>>+>+<<<<]
-            mov32/29 >>>[-<<<+>>>]
-            mov33/47 >[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            >>>>>#arg_0
+            dup16/17/18 [->+>+<<]
+            mov17/16 >[-<+>]
+            mov18/36 >[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/49/50 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov50/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r41 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/38/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r31 
+            <<<<<<<#JUMP_PAD
             +
-            >>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
+            r7 >>>
+            #===FRAME_putdec
             +
-            >>#putdec
+            >#putdec
             +
             >>#putdec/b0
             +
-        >>>>>>>>>]
-        >#B:%call_term_for_8
+        >>>>>>>]
+        >#B:%call_term_for_1
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_9
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_9
-        [
-            -
-            br label %19
-            >+
-        <]
-        >#B:%19
-        [
-            -
             %20 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup19/30/32 <<<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup14/15/17 <[->+>>+<<<]
+            mov17/14 >>>[-<<<+>>>]
             %21 = add i32 %20_ i32 1
+            
             <[-]
-            dup30/32/33 <[->>+>+<<<]
-            mov32/30 >>[-<<+>>]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov33/31 <[-<<+>>]
-            >[
+            mov18/16 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/21/23 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
                 -
-                <<<+
-            >>>]
+                <-
+            >]
+            mov23/16 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %21_ i32* %2_ align 4
-            dup31/32/33 <<<[->+>+<<]
-            mov32/31 >[-<+>]
-            <<<<<<<<<<<<<[-]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            
+            dup16/17/18 <<<<[->+>+<<]
+            mov17/16 >[-<+>]
+            <<<[-]
+            mov18/14 >>>>[-<<<<+>>>>]
             br label %13
-            <<<<<<<<<<<<<<<<<<<<<+
-        >>>>]
+            
+            <<<<<<<<<+
+        >>]
         >#B:%22
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >>-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<]
-<<<]
+            <<[-]
+            r1 
+            l6 
+        >>>>>>>]
+    <<<<<<<<<<]
+<<]
@@ -0,0 +1,757 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4750 ops ~277015 steps
+frame widths:
+  main: 34 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l34 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_i8*_%1__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%5_=_and_i32_%4__i32_1(mult)
+        #%10_=_and_i32_%9__i32_1(mult)
+        #%24_=_xor_i32_%21__i32_%23(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store i8 67_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>++++++
+            [
+                -
+                <<<<<<<+++++++++++
+            >>>>>>>]
+            <<<<<<<+
+            store i8 6_ i8* %2_ align 1
+            
+            >[-]
+            ++++++
+            %3 = load i8* %1_ align 1
+            
+            >[-]
+            known to be 67
+            >>>>>>++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            <<<<<<+
+            %4 = zext i8 %3 to i32
+            
+            >[-]
+            known to be 67
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            %5 = and i32 %4_ i32 1
+            
+            >[-]
+            known to be 1
+            +
+            %6 = add i32 48_ i32 %5
+            
+            <[-]
+            known to be 49
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++
+            >>>>]
+            %7 = trunc i32 %6 to i8
+            
+            <<<[-]
+            known to be 49
+            >>>+++++++
+            [
+                -
+                <<<+++++++
+            >>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_49
+            >>+++++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<.
+            [-]
+            %8 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/7/11 <<[->>+>>>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %9 = zext i8 %8 to i32
+            
+            <<<[-]
+            dup7/11/12 <[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            mov12/8 >[-<<<<+>>>>]
+            %10 = and i32 %9_ i32 1
+            
+            <<<[-]
+            dup8/11/12 <[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>>#constop_1
+            +
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov12/19 <<[->>>>>>>+<<<<<<<]
+            mov14/24 >>[->>>>>>>>>>+<<<<<<<<<<]
+            >>>>>>>+
+            >>>>>+
+            <<<<<<<<<<<<<+
+            [
+                [-]
+                >>>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup23/20/19 >>>>[-<<<+<+>>>>]
+                mov20/23 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup28/25/24 >>>>[-<<<+<+>>>>]
+                mov25/28 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov27/15 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup18/16/17 <<<<<<<<<[-<<+>+>]
+                mov16/18 <<[->>+<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov16/15 >[-<+>]
+                >]
+                mov15/9 <<[-<<<<<<+>>>>>>]
+                continue?
+                dup19/15/16 >>>>[-<<<<+>+>>>]
+                mov15/19 <<<<[->>>>+<<<<]
+                >[
+                    [-]
+                    <<<+
+                >>>]
+                dup24/15/16 >>>>>>>>[-<<<<<<<<<+>+>>>>>>>>]
+                mov15/24 <<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+                >[
+                    [-]
+                    <<<+
+                >>>]
+                >>+
+            <<<<<]
+            >>>>>>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            %11 = add i32 48_ i32 %10
+            
+            <<<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup9/13/14 <<<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov11/8 <<[-<<<+>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/16/18 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov18/8 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %12 = trunc i32 %11 to i8
+            
+            <<<<<<[-]
+            dup8/11/12 <[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov12/9 >[-<<<+>>>]
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            dup9/11/12 <<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            %14 = zext i8 %3 to i32
+            
+            <<<<[-]
+            known to be 67
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %15 = or i32 %14_ i32 128
+            
+            >[-]
+            known to be 195
+            >>+++++++++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            %16 = trunc i32 %15 to i8
+            
+            <<<[-]
+            known to be 195
+            >>>+++++++++++++
+            [
+                -
+                <<<+++++++++++++++
+            >>>]
+            %17 = zext i8 %16 to i32
+            
+            <<[-]
+            known to be 195
+            >>+++++++++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            %18 = sub i32 %17_ i32 128
+            
+            <<<[-]
+            known to be 67
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %19 = trunc i32 %18 to i8
+            
+            >[-]
+            known to be 67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            call @putchar(i8 %19)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>#constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            %21 = zext i8 %3 to i32
+            
+            <<<[-]
+            known to be 67
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %23 = zext i8 %8 to i32
+            
+            >[-]
+            dup7/11/12 <<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            mov12/9 >[-<<<+>>>]
+            %24 = xor i32 %21_ i32 %23
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            dup9/13/14 <<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov11/20 <<[->>>>>>>>>+<<<<<<<<<]
+            mov14/29 >>>[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            >>>>>>>>+
+            >>>>>>>>>+
+            <<<<<<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup24/21/20 >>>>[-<<<+<+>>>>]
+                mov21/24 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup33/30/29 >>>>[-<<<+<+>>>>]
+                mov30/33 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<<<<<[
+                    -
+                    <<<<<<<<+
+                    >>>>>>>>>>>>>>>>>[
+                        -
+                        <<<<<<<<<<<<<<<<<-
+                    >>>>>>>>>>>>>>>>>]
+                <<<<<<<<<]
+                >>>>>>>>>[
+                    -
+                    <<<<<<<<<<<<<<<<<+
+                    >>>>>>>>[
+                        -
+                        <<<<<<<<-
+                    >>>>>>>>]
+                >>>>>>>>>]
+                dup19/16/17 <<<<<<<<<<<<<[-<<<+>+>>]
+                mov16/19 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov16/15 >[-<+>]
+                >]
+                mov15/10 <<[-<<<<<+>>>>>]
+                continue?
+                dup20/15/16 >>>>>[-<<<<<+>+>>>>]
+                mov15/20 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup29/15/16 >>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>]
+                mov15/29 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>>>>-
+            <<<<<<<<<<<<[-]
+            %25 = trunc i32 %24 to i8
+            
+            <<<<<<<<<<<[-]
+            dup10/11/12 >>[->+>+<<]
+            mov11/10 >[-<+>]
+            mov12/8 >[-<<<<+>>>>]
+            call @putchar(i8 %25)
+            
+            putchar intrinsic
+            dup8/11/12 <<<<[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            >.
+            [-]
+            %27 = zext i8 %3 to i32
+            
+            <<<<[-]
+            known to be 67
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %29 = zext i8 %8 to i32
+            
+            <<[-]
+            dup7/11/12 >[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            mov12/6 >[-<<<<<<+>>>>>>]
+            %30 = xor i32 %27_ i32 %29
+            
+            <<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            dup6/13/14 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov11/20 <<[->>>>>>>>>+<<<<<<<<<]
+            mov14/25 >>>[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>+
+            >>>>>+
+            <<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup24/21/20 >>>>[-<<<+<+>>>>]
+                mov21/24 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup29/26/25 >>>>[-<<<+<+>>>>]
+                mov26/29 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    <<<<<<<<+
+                    >>>>>>>>>>>>>[
+                        -
+                        <<<<<<<<<<<<<-
+                    >>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[
+                    -
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>[
+                        -
+                        <<<<<<<<-
+                    >>>>>>>>]
+                >>>>>]
+                dup19/16/17 <<<<<<<<<[-<<<+>+>>]
+                mov16/19 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov16/15 >[-<+>]
+                >]
+                mov15/9 <<[-<<<<<<+>>>>>>]
+                continue?
+                dup20/15/16 >>>>>[-<<<<<+>+>>>>]
+                mov15/20 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup25/15/16 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov15/25 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            %32 = zext i8 %8 to i32
+            
+            <<<<<<<<<<<<<[-]
+            dup7/11/12 >[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            mov12/6 >[-<<<<<<+>>>>>>]
+            %33 = xor i32 %30_ i32 %32
+            
+            <<<<<[-]
+            dup9/11/12 >>[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            dup6/13/14 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov12/20 <[->>>>>>>>+<<<<<<<<]
+            mov14/25 >>[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>+
+            >>>>>+
+            <<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup24/21/20 >>>>[-<<<+<+>>>>]
+                mov21/24 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup29/26/25 >>>>[-<<<+<+>>>>]
+                mov26/29 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    <<<<<<<<+
+                    >>>>>>>>>>>>>[
+                        -
+                        <<<<<<<<<<<<<-
+                    >>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[
+                    -
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>[
+                        -
+                        <<<<<<<<-
+                    >>>>>>>>]
+                >>>>>]
+                dup19/16/17 <<<<<<<<<[-<<<+>+>>]
+                mov16/19 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov16/15 >[-<+>]
+                >]
+                mov15/7 <<[-<<<<<<<<+>>>>>>>>]
+                continue?
+                dup20/15/16 >>>>>[-<<<<<+>+>>>>]
+                mov15/20 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup25/15/16 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov15/25 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            %34 = trunc i32 %33 to i8
+            
+            <<<<<<<<<<<<<[-]
+            dup7/11/12 >[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            mov12/6 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %34)
+            
+            putchar intrinsic
+            dup6/11/12 <<<<<<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -1,918 +1,116 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4140 ops ~142523 steps
+frame widths:
+  main: 36 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
+        >>#B:%ret_lading_pad
         [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
+            <<<-
             #dead_frame
             >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
+            #dead_fn_pad/main
             >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            l36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%4
+        #B:%7
+        #B:%8
+        #B:%11
+        #B:%20
+        #B:%24
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%5_=_load_i32*_%2__align_4(mult)
+        #%6_=_icmp_ult_i32_%5__i32_20(mult)
+        #%14_=_or_i32_%12__i32_%13(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
+            %1 = alloca i32_ align 4
             %2 = alloca i32_ align 4
             %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
+            store i32 0_ i32* %1_ align 4
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            >>>>>>>>[-]
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
+            call @putchar(i8 99_ i8 99_ i8 99_ i8 101_ i8 102_ i8 58)
+            
+            putchar run intrinsic
+            >>>>>>>+++++++++
             [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
                 -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l60 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%4
-        >#B:%7
-        >#B:%8
-        >#B:%11
-        >#B:%call_term_for_10
-        >#B:%call_term_for_11
-        >#B:%17
-        >#B:%20
-        >#B:%21
-        >#B:%24
-        >#alloc_%1
-        >#alloc_%2
-        >#alloc_%3
-        >#%5_=_load_i32*_%2__align_4(mult)
-        >#%6_=_icmp_ult_i32_%5__i32_20(mult)
-        >#%9_=_load_i32*_%3__align_4(mult)
-        >#%10_=_icmp_ult_i32_%9__i32_20(mult)
-        >#%12_=_load_i32*_%2__align_4(mult)
-        >#%13_=_load_i32*_%3__align_4(mult)
-        >#%14_=_or_i32_%12__i32_%13(mult)
-        >#%15_=_add_i32_65__i32_%14(mult)
-        >#%16_=_trunc_i32_%15_to_i8(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_add_i32_%18__i32_1(mult)
-        >#%22_=_load_i32*_%2__align_4(mult)
-        >#%23_=_add_i32_%22__i32_1(mult)
-        >#%25_=_load_i32*_%1__align_4(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_main
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            %1 = alloca i32_ align 4
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
-            
-            <<<<<<<<<<<<<<<<<[-]
-            mov40/23 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            call @putchar(i8 99)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_99
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
-            call @putchar(i8 99)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_99
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <+++++++++++
+            >]
+            <.
             .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            call @putchar(i8 99)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_99
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
             .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            call @putchar(i8 101)
-            enable next block when we return
-            >#caller/%call_term_for_3
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            ++
             .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
-            call @putchar(i8 102)
-            enable next block when we return
-            >#caller/%call_term_for_4
             +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_102
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
             .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
-            call @putchar(i8 58)
-            enable next block when we return
-            >#caller/%call_term_for_5
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_58
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            --------------------------------------------
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_5
-        [
-            -
             store i32 0_ i32* %2_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<[-]
-            mov40/24 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            <<<<<[-]
+            
             br label %4
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%4
+            
+            <<<<<<<+
+        <<]
+        >>#B:%4
         [
             -
             %5 = load i32* %2_ align 4
-            >>>>>>>>>>>>>[-]
-            dup24/26/40 <<[->>+>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            mov40/24 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>[-]
+            dup11/13/16 <<[->>+>>>+<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
             %6 = icmp ult i32 %5_ i32 20
-            <<<<<<<<<<<<<[-]
-            dup26/40/42 <[->>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<]
-            mov40/26 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup13/16/18 <[->>>+>>+<<<<<]
+            mov16/13 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >#constop_20
-            ++++++++++++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov41/43 <<<<[->>+<<]
-            >>>>+
-            <<<[
+            >>++++
+            [
+                -
+                <<+++++
+            >>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/20 <<[->>>+<<<]
+            >>>>>+
+            <<<<[
                 -
                 
-                >[
+                >>[
                     -
                     
                     r1 >
@@ -922,61 +120,69 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
+            <<]
+            >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>]
+                <<<<<<+
+            >>>>>>]
             br i1 %6_ label %7_ label %24
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup14/17/16 <<<<<<[->>>+<+<<]
+            mov17/14 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>+
-                #B:main/7_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<-
+                >>+
+                #B:main/%7_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>+
-                #B:main/24_false
-            <<<<<<<<<<<<<<<<<]
-        >>>>>>>>]
+                >>>>>>+
+                #B:main/%24_false
+            <<<<<<]
+        >]
         >#B:%7
         [
             -
             store i32 0_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<[-]
-            mov40/25 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            >>>>>>>[-]
+            
             br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<+
         <]
         >#B:%8
         [
             -
             %9 = load i32* %3_ align 4
-            >>>>>>>>>>>>>[-]
-            dup25/28/40 <<<[->>>+>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
-            mov40/25 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            >>>>>>>[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
             %10 = icmp ult i32 %9_ i32 20
-            <<<<<<<<<<<[-]
-            dup28/40/41 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov40/28 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            <<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_20
-            ++++++++++++++++++++
-            >>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov42/46 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<<<<[
+            >++++
+            [
+                -
+                <+++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/23 <[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<[
                 -
                 
-                >>>>>[
+                >>>>>>[
                     -
                     
                     r1 >
@@ -986,60 +192,66 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<<<]
-            >>>>>>>-
+            <<<<<<]
+            >>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
+                <<<<<<<<<+
+            >>>>>>>>>]
             br i1 %10_ label %11_ label %20
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup14/17/16 <<<<<<<<<[->>>+<+<<]
+            mov17/14 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>+
-                #B:main/11_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%11_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>+
-                #B:main/20_false
-            <<<<<<<<<<<<<<<]
-        >>>>>>>>>>]
+                >>>>>+
+                #B:main/%20_false
+            <<<<<]
+        >>>]
         >#B:%11
         [
             -
             %12 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup24/30/40 <<<<<<[->>>>>>+>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            mov40/24 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            >>>>>>[-]
+            dup11/13/18 <<[->>+>>>>>+<<<<<<<]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
             %13 = load i32* %3_ align 4
-            <<<<<<<<<[-]
-            dup25/31/40 <<<<<<[->>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<]
-            mov40/25 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            <<<<[-]
+            dup12/14/16 <<[->>+>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
             %14 = or i32 %12_ i32 %13
-            <<<<<<<<[-]
-            dup30/40/41 <<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov40/30 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup31/42/43 <<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov42/31 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >>#dub_scratch
-            >>>#loop_ctrl
-            >>#nth
-            >#op0div
-            >>>>>#op1div
-            <<<<<#op0_v
-            >#op0_0
-            >#op0_1
-            >#op0_h
-            >#op0_l
-            >#op1_v
-            >#op1_0
-            >#op1_1
-            >#op1_h
-            >#op1_l
-            mov41/50 <<<<<<<<<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-            mov43/55 >>[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            
+            <[-]
+            dup13/16/17 <<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            dup14/18/19 <<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov17/26 <[->>>>>>>>>+<<<<<<<<<]
+            mov19/31 >>[->>>>>>>>>>>>+<<<<<<<<<<<<]
             >>>>>>>>>+
             >>>>>+
             <<<<<<<<<<+
@@ -1055,8 +267,8 @@ runtime init:
                     >[
                     <]
                 <]
-                dup54/51/50 >>>>[-<<<+<+>>>>]
-                mov51/54 <<<[->>>+<<<]
+                dup30/27/26 >>>>[-<<<+<+>>>>]
+                mov27/30 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
@@ -1071,188 +283,229 @@ runtime init:
                     >[
                     <]
                 <]
-                dup59/56/55 >>>>[-<<<+<+>>>>]
-                mov56/59 <<<[->>>+<<<]
+                dup35/32/31 >>>>[-<<<+<+>>>>]
+                mov32/35 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
                 <<<<<<[
-                    mov53/44 [-<<<<<<<<<+>>>>>>>>>]
+                    mov29/20 [-<<<<<<<<<+>>>>>>>>>]
                     >>>>>[-]
                 <<<<<]
                 >>>>>[
-                    mov58/44 [-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                    mov34/20 [-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
                 ]
-                dup49/45/46 <<<<<<<<<[-<<<<+>+>>>]
-                mov45/49 <<<<[->>>>+<<<<]
+                dup24/21/22 <<<<<<<<<<[-<<<+>+>>]
+                mov21/24 <<<[->>>+<<<]
                 >[
                     -
                     <<[
                         -
                         >++
                     <]
-                    mov45/44 >[-<+>]
+                    mov21/20 >[-<+>]
                 >]
-                mov44/32 <<[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                mov20/15 <<[-<<<<<+>>>>>]
                 continue?
-                dup50/44/45 >>>>>>[-<<<<<<+>+>>>>>]
-                mov44/50 <<<<<<[->>>>>>+<<<<<<]
+                dup26/20/21 >>>>>>[-<<<<<<+>+>>>>>]
+                mov20/26 <<<<<<[->>>>>>+<<<<<<]
                 >[
                     [-]
                     >>+
                 <<]
-                dup55/44/45 >>>>>>>>>>[-<<<<<<<<<<<+>+>>>>>>>>>>]
-                mov44/55 <<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
+                dup31/20/21 >>>>>>>>>>[-<<<<<<<<<<<+>+>>>>>>>>>>]
+                mov20/31 <<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
                 >[
                     [-]
                     >>+
                 <<]
-                >>>>+
-            <<]
+                >>>+
+            <]
             >>>>>-
             >>>>>-
-            <<<<<<<<[-]
+            <<<<<<<<<[-]
             %15 = add i32 65_ i32 %14
-            <<<<<<<<<<<<<<<<[-]
+            
+            <<<<<<<<<<<[-]
             op_to_reg storing const value in temp address
-            >>>>>>>#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            dup32/41/42 <<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov41/32 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            mov40/33 <[-<<<<<<<+>>>>>>>]
-            >>[
+            >>>#constop_65
+            >++++++++
+            [
                 -
-                <<<<<<<<<+
-            >>>>>>>>>]
+                <++++++++
+            >]
+            <+
+            dup15/18/19 <[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            mov16/13 <<[-<<<+>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/21/23 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov23/13 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             %16 = trunc i32 %15 to i8
-            <<<<<<<<[-]
-            dup33/40/41 <[->>>>>>>+>+<<<<<<<<]
-            mov40/33 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov41/34 >[-<<<<<<<+>>>>>>>]
+            
+            <<<<<[-]
+            dup13/16/17 <<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/15 >[-<<+>>]
             call @putchar(i8 %16)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_10
-            +
+            
             putchar intrinsic
-            dup34/40/41 >>>>>>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
-            mov40/34 >>>>>>[-<<<<<<+>>>>>>]
+            dup15/16/17 <<[->+>+<<]
+            mov16/15 >[-<+>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_10
-        [
-            -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_11
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            #constop_44
+            ++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_11
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            dup25/35/40 <<<<<<<<<<[->>>>>>>>>>+>>>>>+<<<<<<<<<<<<<<<]
-            mov40/25 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            %19 = add i32 %18_ i32 1
-            <<<<[-]
-            dup35/40/41 <[->>>>>+>+<<<<<<]
-            mov40/35 >>>>>[-<<<<<+>>>>>]
+            %19 = add i32 %13_ i32 1
+            
+            <<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov41/36 <[-<<<<<+>>>>>]
-            >[
+            mov17/13 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/20/22 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
                 -
-                <<<<<<+
-            >>>>>>]
+                <-
+            >]
+            mov22/13 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %19_ i32* %3_ align 4
-            dup36/40/41 <<<<<<[->>>>+>+<<<<<]
-            mov40/36 >>>>[-<<<<+>>>>]
-            <<<<<<<<<<<<<<<[-]
-            mov41/25 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            dup13/16/17 <<<<<<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            <<<<[-]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
             br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>]
+            
+            <<<<<<<<<<<+
+        >]
         >#B:%20
-        [
-            -
-            br label %21
-            >+
-        <]
-        >#B:%21
         [
             -
             %22 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            dup24/37/40 <<<<<<<<<<<<<[->>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<]
-            mov40/24 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            >>>>>[-]
+            dup11/13/16 <<[->>+>>>+<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
             %23 = add i32 %22_ i32 1
+            
             <<[-]
-            dup37/40/41 <[->>>+>+<<<<]
-            mov40/37 >>>[-<<<+>>>]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov41/38 <[-<<<+>>>]
-            >[
+            mov17/14 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/20/22 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov22/14 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
                 -
-                <<<<+
-            >>>>]
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %23_ i32* %2_ align 4
-            dup38/40/41 <<<<[->>+>+<<<]
-            mov40/38 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<[-]
-            mov41/24 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            
+            dup14/16/17 <<<<<[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            <<<<<[-]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
             br label %4
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>>]
+            
+            <<<<<<<<<<<<<+
+        >>>>]
         >#B:%24
         [
             -
             %25 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>[-]
-            dup23/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
-            mov40/23 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            
+            >>>>[-]
+            dup10/13/16 <<<[->>>+>>>+<<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
             ret i32 %25
+            
             zero all function allocs
-            <<<<<<<<<<<<<<<<<[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
+            <<<<<<[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<<<<]
-<<<]
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+    <<<<<<<<]
+<]
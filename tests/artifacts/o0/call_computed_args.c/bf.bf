@@ -0,0 +1,483 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  both (depth 1)
+  main calls both (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  both: 2059 ops ~60187 steps
+  main: 1567 ops ~6481 steps
+frame widths:
+  both: 22 cells
+  main: 20 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#both
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/both
+            >>>-
+            l22 <<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_both
+        #F:both
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_i8*_%3__align_1(mult)
+        #%6_=_load_i8*_%4__align_1(mult)
+        #%8_=_zext_i8_%5_to_i32(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/12 <<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/14 <[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            r3 >>>
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i8 %0_ i8* %3_ align 1
+            
+            dup10/12/13 >>>>>>>>>>[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            <<<<<<<[-]
+            mov13/5 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            store i8 %1_ i8* %4_ align 1
+            
+            dup11/12/14 <<[->+>>+<<<]
+            mov12/11 >[-<+>]
+            <<<<<<[-]
+            mov14/6 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %5 = load i8* %3_ align 1
+            
+            <<<<<<<[-]
+            dup5/7/12 <<[->>+>>>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup7/12/13 <<<<<[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            %6 = load i8* %4_ align 1
+            
+            <<<<<[-]
+            dup6/8/12 <<[->>+>>>>+<<<<<<]
+            mov12/6 >>>>>>[-<<<<<<+>>>>>>]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup8/12/13 <<<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %8 = zext i8 %5 to i32
+            
+            <<<<[-]
+            dup7/12/13 <<[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            %10 = zext i8 %6 to i32
+            
+            <<<<<<[-]
+            dup8/12/13 >[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/7 >[-<<<<<<+>>>>>>]
+            %11 = sub i32 %8_ i32 %10
+            
+            <<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            dup7/15/14 <<<<<[->>>>>>>>+<+<<<<<<<]
+            mov15/7 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            mov13/8 <<[-<<<<<+>>>>>]
+            >[
+                -
+                >>>+
+                <<<<<<<<<[
+                    -
+                    mov8/16 [->>>>>>>>+<<<<<<<<]
+                    >>>>>>>>>-
+                <<<<<<<<<]
+                mov16/8 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>]
+            <<<]
+            %12 = add i32 %11_ i32 48
+            
+            <<<<<<<[-]
+            dup8/12/13 >[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov13/7 <<[-<<<<<<+>>>>>>]
+            >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/19/21 <<<<<<<<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov21/7 >>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            <<<<<<<[
+                -
+                >>>>>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<<<<<+
+                    >>>>>>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<]
+            >>>>[-]
+            %13 = trunc i32 %12 to i8
+            
+            <<<<<<<<<<[-]
+            dup7/12/13 <[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            mov13/8 >[-<<<<<+>>>>>]
+            call @putchar(i8 %13)
+            
+            putchar intrinsic
+            dup8/12/13 <<<<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l8 <<<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:both
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%show_inl0_2
+        #%4_=_load_volatile_i8*_%1__align_1(mult)
+        #%6_=_zext_i8_%5_to_i32(mult)
+        #%7_=_add_i32_%6__i32_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store volatile i8 66_ i8* %1_ align 1
+            
+            >>>[-]
+            >>>>>>>++++++
+            [
+                -
+                <<<<<<<+++++++++++
+            >>>>>>>]
+            %4 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 66
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            store i8 %4_ i8* %2_ align 1
+            
+            <<<<<<<[-]
+            >>>>>>++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            %5 = load i8* %2_ align 1
+            
+            <<<[-]
+            known to be 66
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %6 = zext i8 %5 to i32
+            
+            <<[-]
+            known to be 66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %7 = add i32 %6_ i32 1
+            
+            <[-]
+            known to be 67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            %8 = trunc i32 %7 to i8
+            
+            <[-]
+            known to be 67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            store i8 %8_ i8* %3_ align 1
+            
+            <<<[-]
+            >>>>>++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            <<<<<+
+            %9 = load i8* %3_ align 1
+            
+            >>>[-]
+            known to be 67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            %show_inl0_2 = alloca i8_ align 1
+            store i8 %9_ i8* %show_inl0_2_ align 1
+            
+            <<[-]
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            %show_inl0_3 = load i8* %show_inl0_2_ align 1
+            
+            >>>[-]
+            known to be 67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            call @putchar(i8 %show_inl0_3)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >#constop_67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            call @both(i8 %9_ i8 %5)
+            
+            enable next block when we return
+            <<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 20
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            mov15/26 [->>>>>>>>>>>+<<<<<<<<<<<]
+            copy up arg 1
+            >>#arg_1
+            op_to_reg storing const value in temp address
+            #constop_66
+            ++++++
+            [
+                -
+                >+++++++++++
+            <]
+            mov18/25 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r20 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_both
+            +
+            >#both
+            +
+            >>#both/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %12 = load i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup9/12/15 <<<[->>>+>>>+<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            call @both(i8 %12_ i8 65)
+            
+            enable next block when we return
+            <<<<<<<<#caller/%call_term_for_1
+            +
+            stack_width 20
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            dup12/15/16 [->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            mov16/26 >[->>>>>>>>>>+<<<<<<<<<<]
+            copy up arg 1
+            >#arg_1
+            op_to_reg storing const value in temp address
+            #constop_65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            mov17/25 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r20 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_both
+            +
+            >#both
+            +
+            >>#both/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
@@ -0,0 +1,780 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%call_term_for_3 from %0 after call 3 (call continuation)
+  main/%call_term_for_4 from %0 after call 4 (call continuation)
+  main/%call_term_for_5 from %0 after call 5 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  add (depth 1)
+  shout (depth 1)
+  main calls add_ shout (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  add: 1495 ops ~28459 steps
+  shout: 1486 ops ~28352 steps
+  main: 4817 ops ~19888 steps
+frame widths:
+  add: 21 cells
+  shout: 20 cells
+  main: 29 cells
+r6 >>>>>>
+++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>>#main
++
+>#main/b0
++
+<<<<[
+    >#add
+    [
+        >>>>#B:%ret_lading_pad
+        [
+            <<<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/add
+            >>>>-
+            l21 <<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_add
+        #F:add
+        #F:shout
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_i8*_%3__align_1(mult)
+        #%6_=_zext_i8_%5_to_i32(mult)
+        #%8_=_zext_i8_%7_to_i32(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/13 <<<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/15 <[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            r3 >>>
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i8 %0_ i8* %3_ align 1
+            
+            dup11/13/14 >>>>>>>>>>>[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            <<<<<<<[-]
+            mov14/6 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            store i8 %1_ i8* %4_ align 1
+            
+            dup12/13/15 <<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            <<<<<<[-]
+            mov15/7 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %5 = load i8* %3_ align 1
+            
+            <<<<<<<[-]
+            dup6/8/13 <<[->>+>>>>>+<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %6 = zext i8 %5 to i32
+            
+            <<<<[-]
+            dup8/13/14 <[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            mov14/9 >[-<<<<<+>>>>>]
+            %7 = load i8* %4_ align 1
+            
+            <<<<<<[-]
+            dup7/8/13 <[->+>>>>>+<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            %8 = zext i8 %7 to i32
+            
+            <<<[-]
+            dup8/13/14 <<[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            mov14/10 >[-<<<<+>>>>]
+            %9 = add i32 %6_ i32 %8
+            
+            <<<<<<[-]
+            dup9/13/14 >[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            dup10/16/15 <<<[->>>>>>+<+<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            mov14/8 <<[-<<<<<<+>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/18/20 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov20/8 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %10 = trunc i32 %9 to i8
+            
+            <<<<<<<<[-]
+            dup8/13/14 <[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            mov14/9 >[-<<<<<+>>>>>]
+            ret i8 %10
+            
+            mov9/13 <<<<<[->>>>+<<<<]
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            mov13/6 >[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l9 <<<<
+        ]
+    <<<]
+    >#shout
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/shout
+            >>>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_shout
+        #F:add
+        #F:shout
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%7
+        #alloc_%2
+        #%3_=_load_i8*_%2__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/13 <<<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            store i8 %0_ i8* %2_ align 1
+            
+            dup11/12/13 >>>>>>>>>>>[->+>+<<]
+            mov12/11 >[-<+>]
+            <<<<[-]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            %3 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup8/9/12 <[->+>>>+<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            %4 = zext i8 %3 to i32
+            
+            <<[-]
+            dup9/12/14 <[->>>+>>+<<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov14/10 >>[-<<<<+>>>>]
+            %5 = icmp eq i32 %4_ i32 63
+            
+            <<<<<[-]
+            dup10/12/13 >[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_63
+            +++++++
+            [
+                -
+                >+++++++++
+            <]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/17 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            br i1 %5_ label %6_ label %7
+            
+            dup9/13/12 <<<<<<<[->>>>+<+<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<<<<+
+            >>>>>>>[
+                -
+                <<<<<<<-
+                >+
+                #B:shout/%6_true
+            >>>>>>]
+            <<<<<<<[
+                -
+                >>+
+                #B:shout/%7_false
+            <<]
+        <]
+        >>#B:%6
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %7
+            
+            <<<<<<<+
+        <]
+        >#B:%7
+        [
+            -
+            %8 = load i8* %2_ align 1
+            
+            >>[-]
+            dup8/9/12 <[->+>>>+<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            %9 = zext i8 %8 to i32
+            
+            <<[-]
+            dup9/12/13 <[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/10 >[-<<<+>>>]
+            %10 = add i32 %9_ i32 1
+            
+            <<<<[-]
+            dup10/12/13 >[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov13/9 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/16/18 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov18/9 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %11 = trunc i32 %10 to i8
+            
+            <<<<<[-]
+            dup9/12/13 <[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/10 >[-<<<+>>>]
+            ret i8 %11
+            
+            mov10/12 <<<[->>+<<]
+            zero all function allocs
+            <<[-]
+            >[-]
+            >[-]
+            >[-]
+            mov12/8 >[-<<<<+>>>>]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l8 
+        ]
+    <<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<<-
+            #dead_frame
+            >>>-
+            #dead_fn_pad/main
+            >>-
+            l29 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:add
+        #F:shout
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #B:%call_term_for_3
+        #B:%call_term_for_4
+        #B:%call_term_for_5
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%4_=_call_@add(i8_30__i8_35)(mult)
+        #%5_=_load_i8*_%3__align_1(mult)
+        #%6_=_load_volatile_i8*_%1__align_1(mult)
+        #%8_=_call_@add(i8_%6__i8_%5)(mult)
+        #%9_=_call_@add(i8_%8__i8_1)(mult)
+        #%11_=_call_@shout(i8_%10)(mult)
+        #%12_=_call_@shout(i8_97)(mult)
+        #%13_=_call_@shout(i8_%12)(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store volatile i8 1_ i8* %1_ align 1
+            
+            >>>>>>>[-]
+            +
+            store volatile i8 63_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<+++++++++
+            >>>>>>>>>>]
+            %4 = call @add(i8 30_ i8 35)
+            
+            <<<<<<<<[-]
+            enable next block when we return
+            <<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 29
+            ret_pad_width 5
+            copy up arg 0
+            >>>>>>>>>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_30
+            +++++
+            [
+                -
+                >++++++
+            <]
+            mov25/36 >[->>>>>>>>>>>+<<<<<<<<<<<]
+            copy up arg 1
+            >#arg_1
+            op_to_reg storing const value in temp address
+            #constop_35
+            >+++++
+            [
+                -
+                <+++++++
+            >]
+            mov26/35 <[->>>>>>>>>+<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/38/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<<<#JUMP_PAD
+            +
+            >>>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r9 >>>>
+            #===FRAME_add
+            +
+            >#add
+            +
+            >>>#add/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %4
+            mov44/16 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            store i8 %4_ i8* %3_ align 1
+            
+            dup16/24/25 <<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov24/16 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            <<<<<<<<<[-]
+            mov25/15 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %5 = load i8* %3_ align 1
+            
+            <<<<<<<<[-]
+            dup15/17/24 <<[->>+>>>>>>>+<<<<<<<<<]
+            mov24/15 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup17/24/25 <<<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov24/17 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >.
+            [-]
+            %6 = load volatile i8* %1_ align 1
+            
+            <<<<<<<[-]
+            dup13/18/24 <<<<<[->>>>>+>>>>>>+<<<<<<<<<<<]
+            mov24/13 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %8 = call @add(i8 %6_ i8 %5)
+            
+            <<<<<[-]
+            enable next block when we return
+            <<<<<<<<<<<#caller/%call_term_for_1
+            +
+            stack_width 29
+            ret_pad_width 5
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            dup18/24/25 [->>>>>>+>+<<<<<<<]
+            mov24/18 >>>>>>[-<<<<<<+>>>>>>]
+            mov25/36 >[->>>>>>>>>>>+<<<<<<<<<<<]
+            copy up arg 1
+            #arg_1
+            dup17/26/28 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            mov26/17 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov28/35 >>[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/38/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<<<#JUMP_PAD
+            +
+            >>>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r9 >>>>
+            #===FRAME_add
+            +
+            >#add
+            +
+            >>>#add/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            returned %8
+            mov44/19 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %9 = call @add(i8 %8_ i8 1)
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            enable next block when we return
+            <<<<<<<<<<<#caller/%call_term_for_2
+            +
+            stack_width 29
+            ret_pad_width 5
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            dup19/24/25 [->>>>>+>+<<<<<<]
+            mov24/19 >>>>>[-<<<<<+>>>>>]
+            mov25/36 >[->>>>>>>>>>>+<<<<<<<<<<<]
+            copy up arg 1
+            >#arg_1
+            op_to_reg storing const value in temp address
+            #constop_1
+            +
+            mov26/35 [->>>>>>>>>+<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/38/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<<<#JUMP_PAD
+            +
+            >>>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r9 >>>>
+            #===FRAME_add
+            +
+            >#add
+            +
+            >>>#add/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            returned %9
+            mov44/20 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            dup20/24/25 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>+<<<<<]
+            mov24/20 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %10 = load volatile i8* %2_ align 1
+            
+            <<<<<<<<[-]
+            dup14/17/24 <<<[->>>+>>>>>>>+<<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %11 = call @shout(i8 %10)
+            
+            <<<[-]
+            enable next block when we return
+            <<<<<<<<<<<#caller/%call_term_for_3
+            +
+            stack_width 29
+            ret_pad_width 5
+            copy up arg 0
+            >>>>>>>#arg_0
+            dup17/24/25 [->>>>>>>+>+<<<<<<<<]
+            mov24/17 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov25/35 >[->>>>>>>>>>+<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/37/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov38/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>
+            #===FRAME_shout
+            +
+            >>#shout
+            +
+            >>#shout/b0
+            +
+        >>>>>]
+        >#B:%call_term_for_3
+        [
+            -
+            returned %11
+            mov45/21 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            dup21/24/25 <<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            >.
+            [-]
+            %12 = call @shout(i8 97)
+            
+            <<<[-]
+            enable next block when we return
+            <<<<<<<<<<<#caller/%call_term_for_4
+            +
+            stack_width 29
+            ret_pad_width 5
+            copy up arg 0
+            >>>>>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov24/35 [->>>>>>>>>>>+<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/37/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov38/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>
+            #===FRAME_shout
+            +
+            >>#shout
+            +
+            >>#shout/b0
+            +
+        >>>>>>]
+        >#B:%call_term_for_4
+        [
+            -
+            returned %12
+            mov45/22 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %13 = call @shout(i8 %12)
+            
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            enable next block when we return
+            <<<<<<<<<<<#caller/%call_term_for_5
+            +
+            stack_width 29
+            ret_pad_width 5
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            dup22/24/25 [->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            mov25/35 >[->>>>>>>>>>+<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/37/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov38/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>
+            #===FRAME_shout
+            +
+            >>#shout
+            +
+            >>#shout/b0
+            +
+        >>>>>>>]
+        >#B:%call_term_for_5
+        [
+            -
+            returned %13
+            mov45/23 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %13)
+            
+            putchar intrinsic
+            dup23/24/25 <<<<<<<<<<<<<<<<<<<<<<[->+>+<<]
+            mov24/23 >[-<+>]
+            >.
+            [-]
+            %14 = load i8* %3_ align 1
+            
+            <<<<<<<<[-]
+            dup15/17/24 <<[->>+>>>>>>>+<<<<<<<<<]
+            mov24/15 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            dup17/24/25 <<<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov24/17 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>>-
+            l1 <
+            <<<[-]
+            r1 
+            l7 
+        >>>>>>]
+    <<<<<<<<<]
+<<<]
@@ -0,0 +1,531 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %12 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  g (depth 1)
+  main calls g (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  g: 103 ops ~229 steps
+  main: 3844 ops ~40818 steps
+frame widths:
+  g: 7 cells
+  main: 34 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#g
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/g
+            >>>-
+            l7 <<<<<<<
+        ]
+        #mainloop_g
+        #F:g
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        <#B:%0
+        [
+            -
+            copy up args
+            call @putchar(i8 103_ i8 102)
+            
+            putchar run intrinsic
+            >>>++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
+            .
+            -
+            .
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l6 <<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l34 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:g
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%3
+        #B:%7
+        #B:%11
+        #B:%12
+        #B:%call_term_for_1
+        #B:%13
+        #B:%17
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i8*_%2__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            call @g()
+            
+            enable next block when we return
+            <<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 34
+            ret_pad_width 4
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov41/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r6 >>
+            #===FRAME_g
+            +
+            >#g
+            +
+            >>#g/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>#constop_102
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<++
+            .
+            [-]
+            store volatile i8 121_ i8* %1_ align 1
+            
+            <<<<[-]
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            store i8 0_ i8* %2_ align 1
+            
+            <<<[-]
+            
+            br label %3
+            
+            <<<<<<<<+
+        <]
+        >#B:%3
+        [
+            -
+            %4 = load i8* %2_ align 1
+            
+            >>>>>>>>>[-]
+            dup15/16/18 <[->+>>+<<<]
+            mov18/15 >>>[-<<<+>>>]
+            %5 = zext i8 %4 to i32
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            mov19/17 >[-<<+>>]
+            %6 = icmp slt i32 %5_ i32 2
+            
+            <<<[-]
+            dup17/18/19 >[->+>+<<]
+            mov18/17 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>>#constop_2
+            ++
+            >++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/23 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <<+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<<[
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<[
+                    -
+                    >>>-
+                <<<]
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<]
+            >>>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/28 <<<<<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/31 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>]
+            br i1 %6_ label %7_ label %17
+            
+            dup16/19/18 <<<<<<<<<<<<<<<[->>>+<+<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%7_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>+
+                #B:main/%17_false
+            <<<<<<<<<]
+        >>>]
+        >#B:%7
+        [
+            -
+            %8 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>[-]
+            dup14/16/20 <<[->>+>>>>+<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            %9 = zext i8 %8 to i32
+            
+            <<<[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            mov19/17 >[-<<+>>]
+            %10 = icmp eq i32 %9_ i32 121
+            
+            <<<[-]
+            dup17/18/19 >[->+>+<<]
+            mov18/17 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/23 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            <[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %10_ label %11_ label %12
+            
+            dup16/19/18 <<<<<<[->>>+<+<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%11_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%12_false
+            <<<<<<]
+        >>>>]
+        >#B:%11
+        [
+            -
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            br label %13
+            
+            <<<<<<<<+
+        <<<]
+        >#B:%12
+        [
+            -
+            call @g()
+            
+            enable next block when we return
+            >#caller/%call_term_for_1
+            +
+            stack_width 34
+            ret_pad_width 4
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov41/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r6 >>
+            #===FRAME_g
+            +
+            >#g
+            +
+            >>#g/b0
+            +
+        >>>>>>>]
+        >#B:%call_term_for_1
+        [
+            -
+            br label %13
+            
+            >+
+        <]
+        >#B:%13
+        [
+            -
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            store volatile i8 110_ i8* %1_ align 1
+            
+            <<<<[-]
+            >>>>++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %15 = load i8* %2_ align 1
+            
+            <<[-]
+            dup15/16/18 <[->+>>+<<<]
+            mov18/15 >>>[-<<<+>>>]
+            %16 = add i8 %15_ i8 1
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov19/17 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/22/24 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov24/17 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %16_ i8* %2_ align 1
+            
+            dup17/18/19 <<<<[->+>+<<]
+            mov18/17 >[-<+>]
+            <<<[-]
+            mov19/15 >>>>[-<<<<+>>>>]
+            br label %3
+            
+            <<<<<<<<<<<<+
+        >>>>>]
+        >#B:%17
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>>>>>]
+    <<<<<<<<<<<]
+<<]
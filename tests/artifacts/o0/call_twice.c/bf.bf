@@ -0,0 +1,309 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  show (depth 1)
+  main calls show (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  show: 1575 ops ~28637 steps
+  main: 767 ops ~2797 steps
+frame widths:
+  show: 21 cells
+  main: 12 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#show
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/show
+            >>>-
+            l21 <<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_show
+        #F:show
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_i8*_%3__align_1(mult)
+        #%6_=_zext_i8_%5_to_i32(mult)
+        #%7_=_load_i8*_%4__align_1(mult)
+        #%8_=_zext_i8_%7_to_i32(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/13 <<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/15 <[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            r3 >>>
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i8 %0_ i8* %3_ align 1
+            
+            dup11/13/14 >>>>>>>>>>>[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            <<<<<<<<[-]
+            mov14/5 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            store i8 %1_ i8* %4_ align 1
+            
+            dup12/13/15 <<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            <<<<<<<[-]
+            mov15/6 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %5 = load i8* %3_ align 1
+            
+            <<<<<<<<[-]
+            dup5/7/13 <<[->>+>>>>>>+<<<<<<<<]
+            mov13/5 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %6 = zext i8 %5 to i32
+            
+            <<<<<[-]
+            dup7/13/14 <[->>>>>>+>+<<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            mov14/8 >[-<<<<<<+>>>>>>]
+            %7 = load i8* %4_ align 1
+            
+            <<<<<[-]
+            dup6/9/13 <<<[->>>+>>>>+<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %8 = zext i8 %7 to i32
+            
+            <<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov14/10 >[-<<<<+>>>>]
+            %9 = add i32 %6_ i32 %8
+            
+            <<<<<[-]
+            dup8/13/14 <[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            dup10/16/15 <<<[->>>>>>+<+<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            mov14/9 <<[-<<<<<+>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/18/20 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov20/9 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %10 = trunc i32 %9 to i8
+            
+            <<<<<<<<<[-]
+            dup9/13/14 >[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov14/8 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup8/13/14 <<<<<<[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup7/13/14 <<<<<<<[->>>>>>+>+<<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l8 <<<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l12 <<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:show
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            call @show(i8 64_ i8 1)
+            
+            enable next block when we return
+            >#caller/%call_term_for_0
+            +
+            stack_width 12
+            ret_pad_width 4
+            copy up arg 0
+            >>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_64
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            mov8/18 <[->>>>>>>>>>+<<<<<<<<<<]
+            copy up arg 1
+            >>#arg_1
+            op_to_reg storing const value in temp address
+            #constop_1
+            +
+            mov10/17 [->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r12 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_show
+            +
+            >#show
+            +
+            >>#show/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            call @show(i8 96_ i8 2)
+            
+            enable next block when we return
+            >#caller/%call_term_for_1
+            +
+            stack_width 12
+            ret_pad_width 4
+            copy up arg 0
+            >#arg_0
+            op_to_reg storing const value in temp address
+            #constop_96
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            mov8/18 <[->>>>>>>>>>+<<<<<<<<<<]
+            copy up arg 1
+            >>>#arg_1
+            op_to_reg storing const value in temp address
+            #constop_2
+            ++
+            mov11/17 [->>>>>>+<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r12 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_show
+            +
+            >#show
+            +
+            >>#show/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
@@ -1,407 +1,127 @@
-r9 >>>>>>>>>
-+++++++++
+eof: 0
+blocks made up by bfcc:
+  a/%call_term_for_0 from %5 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  b (depth 1)
+  a calls b (depth 2)
+  main calls a (depth 3)
+  max depth from main: 3
+cost (loops go round 8 times):
+  b: 2165 ops ~32937 steps
+  a: 2547 ops ~34327 steps
+  main: 286 ops ~797 steps
+frame widths:
+  b: 28 cells
+  a: 29 cells
+  main: 8 cells
+r6 >>>>>>
+++++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>>>#main
+>>>#main
 +
 >#main/b0
 +
-<<<<<<<[
-    >#putstr
+<<<<[
+    >#b
     [
-        >>>>>>>#B:%ret_lading_pad
+        >>>>#B:%ret_lading_pad
         [
-            <<<<<<<<-
+            <<<<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>>>>-
+            #dead_fn_pad/b
+            >>>>-
             l28 <<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
+        #mainloop_b
+        #F:b
+        #F:a
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%2
+        #B:%5
+        #B:%9
+        #alloc_%1
+        #%3_=_load_i32*_%1__align_4(mult)
+        #%4_=_icmp_slt_i32_%3__i32_2(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>>>>[-]
-            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
+            %1 = alloca i32_ align 4
+            store i32 0_ i32* %1_ align 4
+            
             >>>>>[-]
-            dup13/14/21 <[->+>>>>>>>+<<<<<<<<]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup14/22/21 <[->>>>>>>>+<+<<<<<<<]
-            mov22/14 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov21/25 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup13/16/21 <<<[->>>+>>>>>+<<<<<<<<]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup16/21/22 <[->>>>>+>+<<<<<<]
-            mov21/16 >>>>>[-<<<<<+>>>>>]
-            mov22/17 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup17/21/22 >>>>>>[->>>>+>+<<<<<]
-            mov21/17 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup13/18/21 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup18/21/22 <[->>>+>+<<<<]
-            mov21/18 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov22/19 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup19/21/22 <<<<[->>+>+<<<]
-            mov21/19 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov22/13 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
+            br label %2
+            
+            <<<+
+        <<]
+        >>#B:%2
         [
             -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
+            %3 = load i32* %1_ align 4
+            
+            >>>>[-]
+            dup9/10/12 <[->+>>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            %4 = icmp slt i32 %3_ i32 2
+            
             <[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>>>]
-    <<<<<<<<<<<]
-    >#putdec
-    [
-        >>>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>>>-
-            l51 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>[-]
-            mov39/19 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup19/21/40 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov40/19 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup21/41/40 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov41/21 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            dup10/13/12 <[->>>+<+<<]
+            mov13/10 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/44 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
+            >#constop_2
+            ++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/17 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
                 -
-                >+
-                >[
+                <+
+                >>[
                     -
-                    <-
+                    <<-
                     r1 >
-                >]
+                >>]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
             <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup19/23/40 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov40/19 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup20/24/40 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup23/40/41 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov40/23 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup24/42/43 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov42/24 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup43/45/47 >[->>+>>+<<<<]
-            mov47/43 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov41/48 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
                     -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov48/41 >>>>[-<<<<<<<+>>>>>>>]
-                dup43/45/47 <<<<<[->>+>>+<<<<]
-                mov47/43 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup25/40/41 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov40/25 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov41/43 <<<<[->>+<<]
-            >>>>+
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/22 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
-                ]
+                >>]
                 r1 >
                 [
                     l1 <
@@ -409,458 +129,204 @@ runtime init:
                 l1 <
             <]
             >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup20/27/40 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup27/40/41 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov40/27 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup41/28/43 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov43/41 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup28/40/41 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov40/28 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup20/29/40 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup29/40/41 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov40/29 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
             >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov41/44 <<<<<[->>>+<<<]
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/25 [->>>+<<<]
             >>>>>+
-            <<<<[
+            <<<<<<<<<<[
                 -
-                >+
-                >[
+                
+                >>>>>>>>[
                     -
-                    <-
+                    
                     r1 >
-                >]
+                ]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
                 <<<<<<<<<<<<<<+
             >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
+            br i1 %4_ label %5_ label %9
+            
+            dup11/13/12 <<<<<<<<<<<<<<[->>+<+<]
+            mov13/11 >>[-<<+>>]
+            <<<<<<<<+
+            >>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<-
+                >>+
+                #B:b/%5_true
+            >>>>>]
+            <<<<<<<[
                 -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
+                >>>+
+                #B:b/%9_false
+            <<<]
+        >]
+        >#B:%5
         [
             -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup19/31/40 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov40/19 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup20/32/40 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup31/40/41 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov40/31 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup32/42/43 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov42/32 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup43/45/46 >[->>+>+<<<]
-            mov46/43 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov41/47 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov47/41 >>>[-<<<<<<+>>>>>>]
-                dup43/45/46 <<<<[->>+>+<<<]
-                mov46/43 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup33/40/41 <[->>>>>>>+>+<<<<<<<<]
-            mov40/33 >>>>>>>[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 46)
+            
+            putchar intrinsic
             op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup42/44/45 [->>+>+<<<]
-            mov45/42 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov41/46 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov46/41 <<[-<<<<<+>>>>>]
-                dup42/44/45 <<<<[->>+>+<<<]
-                mov45/42 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
+            >>>>>>>#constop_46
+            >+++++
             [
-                mov44/34 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov43/34 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup34/41/42 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov41/34 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov40/35 <[-<<<<<+>>>>>]
-            >>[
                 -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup35/40/41 <[->>>>>+>+<<<<<<]
-            mov40/35 >>>>>[-<<<<<+>>>>>]
-            mov41/36 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup36/40/41 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov40/36 >>>>[-<<<<+>>>>]
-            >.
+                <+++++++++
+            >]
+            <+
+            .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup20/37/40 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup37/40/41 <[->>>+>+<<<<]
-            mov40/37 >>>[-<<<+>>>]
+            %7 = load i32* %1_ align 4
+            
+            <<<<[-]
+            dup9/10/12 <[->+>>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            %8 = add i32 %7_ i32 1
+            
+            <[-]
+            dup10/12/13 <[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
             op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup42/44/45 [->>+>+<<<]
-            mov45/42 >>>[-<<<+>>>]
+            >>#constop_1
+            +
+            mov13/11 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup11/16/18 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov18/11 >>[-<<<<<<<+>>>>>>>]
             <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov41/46 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
+                -
+                >>>+
+                <<[
                     -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
+                    mov15/16 [->+<]
+                    <<<<+
+                    >>>>>>-
                 <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov46/41 >>>[-<<<<<+>>>>>]
-                dup42/44/45 <<<<[->>+>+<<<]
-                mov45/42 >>>[-<<<+>>>]
-            <<<<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
             >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup38/40/41 <<<<<<[->>+>+<<<]
-            mov40/38 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
+            store i32 %8_ i32* %1_ align 4
+            
+            dup11/12/13 <<<<[->+>+<<]
+            mov12/11 >[-<+>]
+            <<<[-]
+            mov13/9 >>>>[-<<<<+>>>>]
+            br label %2
+            
+            <<<<<<<+
+        >]
+        >#B:%9
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<]
-    >#c
-    [
-        >>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
+            <<<<<<<<<<<-
             #dead_frame
-            >>>-
-            <<<#dead_fn_pad/c
-            >>>>>>>>-
-            l12 <<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_c
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%no_block0_call_for_c
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        <<<#B:%no_block0_call_for_c
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            call @putchar(i8 46)
-            enable next block when we return
-            >#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >#constop_46
-            ++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<]
-        >#B:%call_term_for_0
-        [
-            -
-            ret void
-            zero all function allocs
-            <<<<<<<<<<-
-            #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>]
+            <[-]
+            r1 
+            l7 
+        >>]
     <<<<<<<]
-    >#b
+    >#a
     [
-        >>>>#B:%ret_lading_pad
+        >>>#B:%ret_lading_pad
         [
-            <<<<<<<<-
+            <<<<<-
             #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/b
-            >>>>>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+            >>-
+            #dead_fn_pad/a
+            >>>-
+            l29 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<<<#mainloop_b
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%2
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#alloc_%1
-        >#%3_=_load_i32*_%1__align_4(mult)
-        >#%4_=_icmp_slt_i32_%3__i32_2(mult)
-        >#%7_=_load_i32*_%1__align_4(mult)
-        >#%8_=_add_i32_%7__i32_1(mult)
-        <<<<<<<<<<<#B:%0
+        #mainloop_a
+        #F:b
+        #F:a
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%2
+        #B:%5
+        #B:%call_term_for_0
+        #B:%9
+        #alloc_%1
+        #%3_=_load_i32*_%1__align_4(mult)
+        #%4_=_icmp_slt_i32_%3__i32_2(mult)
+        <#B:%0
         [
             -
             copy up args
             %1 = alloca i32_ align 4
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>#constop_0
             
-            <<<<<[-]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
+            >>>>>>[-]
+            
             br label %2
-            <<<<<<<<<<+
+            
+            <<<<+
         <<]
         >>#B:%2
         [
             -
             %3 = load i32* %1_ align 4
-            >>>>>>[-]
-            dup14/15/20 <[->+>>>>>+<<<<<<]
-            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            
+            >>>>>[-]
+            dup10/11/13 <[->+>>+<<<]
+            mov13/10 >>>[-<<<+>>>]
             %4 = icmp slt i32 %3_ i32 2
-            <<<<[-]
-            dup15/19/21 <[->>>>+>>+<<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
+            
+            <[-]
+            dup11/14/13 <[->>>+<+<<]
+            mov14/11 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >#constop_2
             ++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov20/22 <<<<[->>+<<]
-            >>>>+
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/18 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
-                ]
+                >>]
                 r1 >
                 [
                     l1 <
@@ -868,174 +334,52 @@ runtime init:
                 l1 <
             <]
             >>>-
-            <<[
-                [-]
-                <<<<<<+
-            >>>>>>]
-            br i1 %4_ label %5_ label %9
-            <<<<<<<<<<<<<<+
-            >>>>>>>>[
-                -
-                <<<<<<<<-
-                >>+
-                #B:b/5_true
-            >>>>>>]
-            <<<<<<<<[
-                -
-                >>>>>+
-                #B:b/9_false
-            <<<<<]
-        >]
-        >#B:%5
-        [
-            -
-            call @c()
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            stack_width 25
-            ret_pad_width 8
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/35/36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r25 >>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>>>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<<<#===FRAME_c
-            +
-            >>>#c
-            +
-            >>>>#c/b0
-            +
-        >>>]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %6
-            >+
-        <]
-        >#B:%6
-        [
-            -
-            %7 = load i32* %1_ align 4
-            >>>>>[-]
-            dup14/17/19 <<<[->>>+>>+<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            %8 = add i32 %7_ i32 1
-            <[-]
-            dup17/19/20 <[->>+>+<<<]
-            mov19/17 >>[-<<+>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov20/18 <[-<<+>>]
-            >[
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/23 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
                 -
-                <<<+
-            >>>]
-            store i32 %8_ i32* %1_ align 4
-            dup18/19/20 <<<[->+>+<<]
-            mov19/18 >[-<+>]
-            <<<<<[-]
-            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
-            br label %2
-            <<<<<<<<<<<+
-        >>>]
-        >#B:%9
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>>-
-            l1 <
-            <<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>>>>]
-    <<<<<<<<<]
-    >#a
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>>-
-            <<<<<#dead_fn_pad/a
-            >>>>>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_a
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%2
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#alloc_%1
-        >#%3_=_load_i32*_%1__align_4(mult)
-        >#%4_=_icmp_slt_i32_%3__i32_2(mult)
-        >#%7_=_load_i32*_%1__align_4(mult)
-        >#%8_=_add_i32_%7__i32_1(mult)
-        <<<<<<<<<<<#B:%0
-        [
-            -
-            copy up args
-            %1 = alloca i32_ align 4
-            store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>#constop_0
-            
-            <<<<<[-]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            br label %2
-            <<<<<<<<<<+
-        <<]
-        >>#B:%2
-        [
-            -
-            %3 = load i32* %1_ align 4
-            >>>>>>[-]
-            dup14/15/20 <[->+>>>>>+<<<<<<]
-            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
-            %4 = icmp slt i32 %3_ i32 2
-            <<<<[-]
-            dup15/19/21 <[->>>>+>>+<<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_2
-            ++
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
             >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov20/22 <<<<[->>+<<]
-            >>>>+
-            <<<[
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/26 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
                 -
                 
-                >[
+                >>>>>>>>[
                     -
                     
                     r1 >
@@ -1045,165 +389,185 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<+
-            >>>>>>]
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
             br i1 %4_ label %5_ label %9
-            <<<<<<<<<<<<<<+
+            
+            dup12/14/13 <<<<<<<<<<<<<<[->>+<+<]
+            mov14/12 >>[-<<+>>]
+            <<<<<<<<<+
             >>>>>>>>[
                 -
                 <<<<<<<<-
                 >>+
-                #B:a/5_true
+                #B:a/%5_true
             >>>>>>]
             <<<<<<<<[
                 -
-                >>>>>+
-                #B:a/9_false
-            <<<<<]
+                >>>>+
+                #B:a/%9_false
+            <<<<]
         >]
         >#B:%5
         [
             -
             call @b()
+            
             enable next block when we return
-            >#caller/%call_term_for_2
+            >#caller/%call_term_for_0
             +
-            stack_width 25
-            ret_pad_width 8
+            stack_width 29
+            ret_pad_width 5
             give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
             ++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/35/36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r25 >>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov37/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r29 
+            <<<<<<<#JUMP_PAD
             +
-            >>>>>#caller
+            >>#caller
             +
             >>>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<<<#===FRAME_b
+            r7 >>
+            #===FRAME_b
             +
-            >>>>#b
+            >#b
             +
             >>>#b/b0
             +
         >>>]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %6
-            >+
-        <]
-        >#B:%6
+        >#B:%call_term_for_0
         [
             -
             %7 = load i32* %1_ align 4
-            >>>>>[-]
-            dup14/17/19 <<<[->>>+>>+<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
+            
+            >>>[-]
+            dup10/11/13 <[->+>>+<<<]
+            mov13/10 >>>[-<<<+>>>]
             %8 = add i32 %7_ i32 1
+            
             <[-]
-            dup17/19/20 <[->>+>+<<<]
-            mov19/17 >>[-<<+>>]
+            dup11/13/14 <[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov20/18 <[-<<+>>]
-            >[
+            mov14/12 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/17/19 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov19/12 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
                 -
-                <<<+
-            >>>]
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %8_ i32* %1_ align 4
-            dup18/19/20 <<<[->+>+<<]
-            mov19/18 >[-<+>]
-            <<<<<[-]
-            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            
+            dup12/13/14 <<<<[->+>+<<]
+            mov13/12 >[-<+>]
+            <<<[-]
+            mov14/10 >>>>[-<<<<+>>>>]
             br label %2
-            <<<<<<<<<<<+
-        >>>]
+            
+            <<<<<<<<+
+        >>]
         >#B:%9
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<-
             #dead_frame
-            >>>>>-
+            >>-
             l1 <
-            <<<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>>>>]
-    <<<<<<<<]
+            <<[-]
+            r1 
+            l7 
+        >>>]
+    <<<<<<<]
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<<<<-
+            <<<<<-
             #dead_frame
-            >>>>>>-
-            <<<<<<#dead_fn_pad/main
-            >>>>>>>>-
-            l11 <<<<<<<<<<<
+            >>>-
+            #dead_fn_pad/main
+            >>-
+            l8 <<<<<<<<
         ]
-        <<<<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        <<<#B:%no_block0_call_for_main
+        #mainloop_main
+        #F:b
+        #F:a
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        <#B:%no_block0_call_for_main
         [
             -
             copy up args
             br label %0
+            
             >>+
         <<]
         >>#B:%0
         [
             -
             call @a()
+            
             enable next block when we return
             >#caller/%call_term_for_0
             +
-            stack_width 11
-            ret_pad_width 8
+            stack_width 8
+            ret_pad_width 5
             give callee a stack pointer
-            >>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++
+            >>>>>>>#stack_ptr
+            +++++++++++++++
             l1 <
-            dup0/21/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
-            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r11 >>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/15/16 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov16/0 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            r1 
+            r8 
+            <<<<<<<#JUMP_PAD
             +
-            >>>>>>#caller
+            >>>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<<<#===FRAME_a
+            r7 >>
+            #===FRAME_a
             +
-            >>>>>#a
+            >>#a
             +
             >>#a/b0
             +
@@ -1212,14 +576,15 @@ runtime init:
         [
             -
             ret void
+            
             zero all function allocs
-            <<<<<<<<<<-
+            <<<<<<<-
             #dead_frame
-            >>>>>>-
+            >>>-
             l1 <
-            <<<<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>]
+            <<<[-]
+            r1 
+            l7 
+        >]
     <<<<]
-<<<<<<]
+<<<]
@@ -0,0 +1,503 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  pick (depth 1)
+  main calls pick (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  pick: 2542 ops ~64275 steps
+  main: 969 ops ~3601 steps
+frame widths:
+  pick: 24 cells
+  main: 15 cells
+r1 >
++
+r1 >
+++
+r1 >
++++
+r1 >
+r5 >>>>>
++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#pick
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/pick
+            >>>-
+            l24 <<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_pick
+        #F:pick
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%7
+        #B:%8
+        #B:%18
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i8*_%3__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/14 <<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store i8 %0_ i8* %3_ align 1
+            
+            dup12/13/14 >>>>>>>>>>>>[->+>+<<]
+            mov13/12 >[-<+>]
+            <<<<[-]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            %4 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup9/10/13 <[->+>>>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            %5 = zext i8 %4 to i32
+            
+            <<[-]
+            dup10/13/15 <[->>>+>>+<<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov15/11 >>[-<<<<+>>>>]
+            %6 = icmp eq i32 %5_ i32 0
+            
+            <<<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            <<<+
+            >>>>[
+                [-]
+                <<<<[-]
+            >>>>]
+            br i1 %6_ label %7_ label %8
+            
+            dup10/14/13 <<<<[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<+
+            >>>>>>>>>[
+                -
+                <<<<<<<<<-
+                >+
+                #B:pick/%7_true
+            >>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>+
+                #B:pick/%8_false
+            <<]
+        <]
+        >>#B:%7
+        [
+            -
+            store i8 122_ i8* %2_ align 1
+            
+            >>>[-]
+            >>>>>>>>+++++++++++
+            [
+                -
+                <<<<<<<<+++++++++++
+            >>>>>>>>]
+            <<<<<<<<+
+            br label %18
+            
+            <+
+        <<]
+        >#B:%8
+        [
+            -
+            %9 = load i8* %3_ align 1
+            
+            >>>>[-]
+            dup9/10/13 <[->+>>>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            %10 = zext i8 %9 to i32
+            
+            <<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/11 >[-<<<+>>>]
+            %11 = sub i32 %10_ i32 1
+            
+            <<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov14/10 <[-<<<<+>>>>]
+            >[
+                -
+                >>>+
+                <<<<<<<<[
+                    -
+                    mov10/17 [->>>>>>>+<<<<<<<]
+                    >>>>>>>>-
+                <<<<<<<<]
+                mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>]
+            <<<]
+            %12 = sext i32 %11 to i64
+            
+            <<<<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/11 >[-<<<+>>>]
+            %13 = getelementptr inbounds {3 x i8}* @counts_ i64 0_ i64 %12
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_1
+            +
+            dup11/14/15 <<[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov13/10 <[-<<<+>>>]
+            >>[
+                -
+                <<<<<+
+            >>>>>]
+            %14 = load i8* %13_ align 1
+            
+            <<<<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/20/19 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/21 <[->>>+<<<]
+            >>>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov21/17 [-<<<<+>>>>]
+                <<<<+++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov16/15 <[-<+>]
+                mov17/16 >[-<+>]
+                
+                mov14/17 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup14/15/17 <<<[->+>>+<<<]
+            mov15/14 >[-<+>]
+            >[
+                mov18/15 >>[-<<<+>>>]
+                mov17/18 <[->+<]
+                mov16/17 <[->+<]
+                r1 >
+                -
+            ]
+            mov17/11 >[-<<<<<<+>>>>>>]
+            %15 = zext i8 %14 to i32
+            
+            <<<<<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            mov14/10 >[-<<<<+>>>>]
+            %16 = add i32 %15_ i32 97
+            
+            <<<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov14/11 <[-<<<+>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup11/18/20 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov20/11 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %17 = trunc i32 %16 to i8
+            
+            <<<<<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            mov14/10 >[-<<<<+>>>>]
+            store i8 %17_ i8* %2_ align 1
+            
+            dup10/13/14 <<<<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            br label %18
+            
+            <<<<<<<+
+        <]
+        >#B:%18
+        [
+            -
+            %19 = load i8* %2_ align 1
+            
+            >>>[-]
+            dup8/10/13 <<[->>+>>>+<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            ret i8 %19
+            
+            mov10/13 <<<[->>>+<<<]
+            zero all function allocs
+            <<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            mov13/8 >[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:pick
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%2__align_1(mult)
+        #%4_=_call_@pick(i8_%3)(mult)
+        #%5_=_call_@pick(i8_0)(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %2 = alloca i8_ align 1
+            store volatile i8 2_ i8* %2_ align 1
+            
+            >>>[-]
+            ++
+            %3 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 2
+            ++
+            %4 = call @pick(i8 %3)
+            
+            >[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_0
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_2
+            ++
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_pick
+            +
+            >#pick
+            +
+            >>#pick/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %4
+            mov30/10 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup10/13/12 <<<<<<<<<<<<<<<<<<<<[->>>+<+<<]
+            mov13/10 >>>[-<<<+>>>]
+            <.
+            [-]
+            %5 = call @pick(i8 0)
+            
+            <[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_1
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_pick
+            +
+            >#pick
+            +
+            >>#pick/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            returned %5
+            mov30/11 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup11/12/14 <<<<<<<<<<<<<<<<<<<[->+>>+<<<]
+            mov12/11 >[-<+>]
+            >>.
+            [-]
+            ret i32 7
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
+clear globals:
+l4 <<<
+[-]
+>[-]
+>[-]
@@ -0,0 +1,619 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4816 ops ~51702 steps
+frame widths:
+  main: 36 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%9
+        #B:%17
+        #B:%18
+        #B:%21
+        #B:%22
+        #B:%34
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #%6_=_load_i8*_%3__align_1(mult)
+        #%7_=_zext_i8_%6_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>>[-]
+            
+            store volatile i8 65_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++
+            >>>>>]
+            <<<<<+
+            store i8 0_ i8* %3_ align 1
+            
+            >[-]
+            
+            br label %5
+            
+            <<<<<<<<<+
+        <<]
+        >>#B:%5
+        [
+            -
+            %6 = load i8* %3_ align 1
+            
+            >>>>>>>>>>>[-]
+            dup13/15/18 <<[->>+>>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            %7 = zext i8 %6 to i32
+            
+            <<[-]
+            dup15/17/19 <[->>+>>+<<<<]
+            mov17/15 >>[-<<+>>]
+            mov19/16 >>[-<<<+>>>]
+            %8 = icmp slt i32 %7_ i32 2
+            
+            <<<<[-]
+            dup16/17/18 >[->+>+<<]
+            mov17/16 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>>#constop_2
+            ++
+            >++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/22 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <<+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<<[
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<[
+                    -
+                    >>>-
+                <<<]
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<]
+            >>>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/27 <<<<<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/30 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>]
+            br i1 %8_ label %9_ label %34
+            
+            dup15/18/17 <<<<<<<<<<<<<<<[->>>+<+<<]
+            mov18/15 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>+
+                #B:main/%9_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%34_false
+            <<<<<<<]
+        >]
+        >#B:%9
+        [
+            -
+            %10 = load volatile i8* %2_ align 1
+            
+            >>>>>>>>>>[-]
+            dup12/15/19 <<<[->>>+>>>>+<<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            mov18/16 >[-<<+>>]
+            %12 = icmp slt i32 %11_ i32 97
+            
+            <<<[-]
+            dup16/17/18 >[->+>+<<]
+            mov17/16 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/23 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/28 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/33 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<[
+                -
+                
+                >>>>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            %13 = zext i1 %12 to i32
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            mov18/16 >[-<<+>>]
+            %14 = trunc i32 %13 to i8
+            
+            <<<[-]
+            dup16/17/18 >[->+>+<<]
+            mov17/16 >[-<+>]
+            mov18/15 >[-<<<+>>>]
+            store i8 %14_ i8* %4_ align 1
+            
+            dup15/17/18 <<<[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            <<<[-]
+            mov18/14 >>>>[-<<<<+>>>>]
+            %15 = load i8* %4_ align 1
+            
+            <<<[-]
+            dup14/15/17 <[->+>>+<<<]
+            mov17/14 >>>[-<<<+>>>]
+            %16 = icmp ne i8 %15_ i8 0
+            
+            <[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            >[
+                [-]
+                <<+
+            >>]
+            br i1 %16_ label %17_ label %18
+            
+            dup16/18/17 <<[->>+<+<]
+            mov18/16 >>[-<<+>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%17_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%18_false
+            <<<<]
+        >>]
+        >#B:%17
+        [
+            -
+            call @putchar(i8 84)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_84
+            >+++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
+            [-]
+            br label %18
+            
+            <<<<<<<<<<<<+
+        <]
+        >#B:%18
+        [
+            -
+            call @putchar(i8 45)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_45
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            %19 = load i8* %4_ align 1
+            
+            <<[-]
+            dup14/15/17 <[->+>>+<<<]
+            mov17/14 >>>[-<<<+>>>]
+            %20 = icmp ne i8 %19_ i8 0
+            
+            <[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            >[
+                [-]
+                <<+
+            >>]
+            br i1 %20_ label %21_ label %22
+            
+            dup16/18/17 <<[->>+<+<]
+            mov18/16 >>[-<<+>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%21_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%22_false
+            <<<<<<]
+        >>>>]
+        >#B:%21
+        [
+            -
+            call @putchar(i8 85)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_85
+            >+++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %22
+            
+            <<<<<<<<<<+
+        <]
+        >#B:%22
+        [
+            -
+            %23 = load i8* %4_ align 1
+            
+            >>>>>>[-]
+            dup14/15/17 <[->+>>+<<<]
+            mov17/14 >>>[-<<<+>>>]
+            %24 = zext i8 %23 to i32
+            
+            <[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            mov18/16 >[-<<+>>]
+            %25 = icmp ne i32 %24_ i32 0
+            
+            <<<[-]
+            dup16/17/18 >[->+>+<<]
+            mov17/16 >[-<+>]
+            >[
+                [-]
+                <<<+
+            >>>]
+            %27 = select i1 %25_ i32 86_ i32 87
+            
+            <<[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_86
+            >+++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <++
+            op_to_reg storing const value in temp address
+            >>#constop_87
+            >+++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+++
+            >>+
+            <<<<<[
+                mov19/16 >[-<<<+>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov21/16 <<[-<<<<<+>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            %28 = trunc i32 %27 to i8
+            
+            <<<<<<<<[-]
+            dup16/17/18 >[->+>+<<]
+            mov17/16 >[-<+>]
+            mov18/15 >[-<<<+>>>]
+            call @putchar(i8 %28)
+            
+            putchar intrinsic
+            dup15/17/18 <<<[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            >.
+            [-]
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            ++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            store volatile i8 97_ i8* %2_ align 1
+            
+            <<<<<[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            %30 = load i8* %3_ align 1
+            
+            >>>[-]
+            dup13/15/17 <<[->>+>>+<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            %31 = zext i8 %30 to i32
+            
+            <[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            mov18/16 >[-<<+>>]
+            %32 = add i32 %31_ i32 1
+            
+            <<<[-]
+            dup16/17/18 >[->+>+<<]
+            mov17/16 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov18/15 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/21/23 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov23/15 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %33 = trunc i32 %32 to i8
+            
+            <<<<[-]
+            dup15/17/18 <[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            mov18/16 >[-<<+>>]
+            store i8 %33_ i8* %3_ align 1
+            
+            dup16/17/18 <<[->+>+<<]
+            mov17/16 >[-<+>]
+            <<<<[-]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            br label %5
+            
+            <<<<<<<<<<<<<<+
+        >>>>>]
+        >#B:%34
+        [
+            -
+            %35 = load i32* %1_ align 4
+            
+            >>>>>[-]
+            dup11/15/17 <<<<[->>>>+>>+<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            ret i32 %35
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>]
+    <<<<<<<<<]
+<]
@@ -1,1060 +1,318 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2225 ops ~57280 steps
+frame widths:
+  main: 22 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
+<<[
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<-
             #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            >-
+            #dead_fn_pad/main
+            >>-
+            l22 <<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%14
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%15
-        >#B:%16
-        >#B:%21
-        >#alloc_%1
-        >#alloc_%2
-        >#%4_=_load_i8*_%2__align_1(mult)
-        >#%5_=_icmp_ne_i8_%4__i8_0(mult)
-        >#%7_=_load_i8*_%2__align_1(mult)
-        >#%8_=_zext_i8_%7_to_i32(mult)
-        >#%9_=_add_i32_48__i32_%8(mult)
-        >#%10_=_trunc_i32_%9_to_i8(mult)
-        >#%11_=_load_i8*_%2__align_1(mult)
-        >#%12_=_zext_i8_%11_to_i32(mult)
-        >#%13_=_icmp_ne_i32_%12__i32_1(mult)
-        >#%17_=_load_i8*_%2__align_1(mult)
-        >#%18_=_zext_i8_%17_to_i32(mult)
-        >#%19_=_sub_i32_%18__i32_1(mult)
-        >#%20_=_trunc_i32_%19_to_i8(mult)
-        >#%22_=_load_i32*_%1__align_4(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%0
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%6
+        #B:%14
+        #B:%15
+        #B:%21
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i8*_%2__align_1(mult)
+        #%5_=_icmp_ne_i8_%4__i8_0(mult)
+        #%9_=_add_i32_48__i32_%8(mult)
+        <#B:%0
         [
             -
             copy up args
             %1 = alloca i32_ align 4
             %2 = alloca i8_ align 1
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<[-]
-            mov31/15 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >>>>>>>[-]
+            
             store i8 9_ i8* %2_ align 1
-            op_to_reg storing const value in temp address
-            >#constop_9
+            
+            >[-]
             +++++++++
-            <<<<<<<<<<<<<<<<[-]
-            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<+
         <<]
         >>#B:%3
         [
             -
             %4 = load i8* %2_ align 1
-            >>>>>>>>>>>[-]
-            dup16/17/31 <[->+>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            >>>>>>>[-]
+            dup10/11/14 <[->+>>>+<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
             %5 = icmp ne i8 %4_ i8 0
-            <<<<<<<<<<<<<[-]
-            dup17/31/33 <[->>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<]
-            mov31/17 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
+            <<[-]
+            dup11/15/14 <[->>>>+<+<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>]
+                <<+
+            >>]
             br i1 %5_ label %6_ label %21
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>[
+            
+            dup12/16/14 <<[->>>>+<<+<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<-
+                <<<<<<<<<<<-
                 >>+
-                #B:main/6_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<[
+                #B:main/%6_true
+            >>>>>>>>>]
+            <<<<<<<<<<<[
                 -
-                >>>>>>>>>+
-                #B:main/21_false
-            <<<<<<<<<]
+                >>>>>+
+                #B:main/%21_false
+            <<<<<]
         >]
         >#B:%6
         [
             -
             %7 = load i8* %2_ align 1
-            >>>>>>>>>>>>[-]
-            dup16/19/31 <<<[->>>+>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            >>>>>>[-]
+            dup10/11/15 <[->+>>>>+<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
             %8 = zext i8 %7 to i32
-            <<<<<<<<<<<[-]
-            dup19/31/32 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            mov32/20 >[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            <<<[-]
+            dup11/14/15 <[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov15/12 >[-<<<+>>>]
             %9 = add i32 48_ i32 %8
-            <<<<<<<<<<<[-]
+            
+            <<[-]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup20/32/33 <<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            mov31/21 <[-<<<<<<<<<<+>>>>>>>>>>]
-            >>[
+            >#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup12/17/16 <<<[->>>>>+<+<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            mov14/13 <<<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/19/21 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
                 -
-                <<<<<<<<<<<<+
-            >>>>>>>>>>>>]
+                <-
+            >]
+            mov21/13 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
             %10 = trunc i32 %9 to i8
-            <<<<<<<<<<<[-]
-            dup21/31/32 <[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov31/21 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            mov32/22 >[-<<<<<<<<<<+>>>>>>>>>>]
+            
+            <<<<<<[-]
+            dup13/14/15 >[->+>+<<]
+            mov14/13 >[-<+>]
+            mov15/12 >[-<<<+>>>]
             call @putchar(i8 %10)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
-            dup22/31/32 >>>>>>>>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            dup12/14/15 <<<[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %11 = load i8* %2_ align 1
-            >>>>>>>>>>>>>>>[-]
-            dup16/23/31 <<<<<<<[->>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            %12 = zext i8 %11 to i32
-            <<<<<<<[-]
-            dup23/31/32 <[->>>>>>>>+>+<<<<<<<<<]
-            mov31/23 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov32/24 >[-<<<<<<<<+>>>>>>>>]
+            %12 = zext i8 %7 to i32
+            
+            <<<[-]
+            dup11/14/15 <[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov15/12 >[-<<<+>>>]
             %13 = icmp ne i32 %12_ i32 1
-            <<<<<<<[-]
-            dup24/31/32 <[->>>>>>>+>+<<<<<<<<]
-            mov31/24 >>>>>>>[-<<<<<<<+>>>>>>>]
+            
+            <<<<[-]
+            dup12/14/15 >[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            >>>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/38 <<<<<<<<[->>>>>>+<<<<<<]
-            >>>>>>>>+
-            <<<<<<<[
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/18 <[->>>+<<<]
+            >>>>>+
+            <<<<[
                 -
                 >+
-                >>>>[
+                >[
                     -
-                    <<<<-
+                    <-
                     r1 >
-                >>>>]
+                >]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <<<<<]
-            >>>>>>>-
+            <<]
+            >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            <<<<[
+                <<<<<<<+
+            >>>>>>>]
+            <[
                 [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
+                <<<<<<+
+            >>>>>>]
             br i1 %13_ label %14_ label %15
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>[
+            
+            dup11/15/14 <<<<<<[->>>>+<+<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<+
+            >>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<-
-                >>>>+
-                #B:main/14_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<-
+                >>>+
+                #B:main/%14_true
+            >>>>>>>>]
+            <<<<<<<<<<<[
                 -
-                >>>>>>>+
-                #B:main/15_false
-            <<<<<<<]
-        >>>]
+                >>>>+
+                #B:main/%15_false
+            <<<<]
+        >>]
         >#B:%14
         [
             -
-            call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_4
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
-            call @putchar(i8 32)
-            enable next block when we return
-            >#caller/%call_term_for_5
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>#constop_32
-            ++++++++++++++++++++++++++++++++
+            call @putchar(i8 44_ i8 32)
+            
+            putchar run intrinsic
+            >>>>>>>>>>>++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            ------------
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_5
-        [
-            -
             br label %15
-            >+
+            
+            <<<<<<<<<+
         <]
         >#B:%15
-        [
-            -
-            br label %16
-            >+
-        <]
-        >#B:%16
         [
             -
             %17 = load i8* %2_ align 1
-            >>>>>>>>>>>>>[-]
-            dup16/26/31 <<<<<<<<<<[->>>>>>>>>>+>>>>>+<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            
+            >>>>[-]
+            dup10/11/14 <[->+>>>+<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
             %18 = zext i8 %17 to i32
-            <<<<[-]
-            dup26/31/32 <[->>>>>+>+<<<<<<]
-            mov31/26 >>>>>[-<<<<<+>>>>>]
-            mov32/27 >[-<<<<<+>>>>>]
+            
+            <<[-]
+            dup11/14/15 <[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov15/12 >[-<<<+>>>]
             %19 = sub i32 %18_ i32 1
+            
             <<<<[-]
-            dup27/31/32 <[->>>>+>+<<<<<]
-            mov31/27 >>>>[-<<<<+>>>>]
+            dup12/14/15 >[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov32/28 <[-<<<<+>>>>]
+            mov15/11 <[-<<<<+>>>>]
             >[
                 -
-                <<<<<-
-            >>>>>]
+                >>+
+                <<<<<<<[
+                    -
+                    mov11/17 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
             %20 = trunc i32 %19 to i8
+            
             <<<<[-]
-            dup28/31/32 <[->>>+>+<<<<]
-            mov31/28 >>>[-<<<+>>>]
-            mov32/29 >[-<<<+>>>]
+            dup11/14/15 <[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov15/12 >[-<<<+>>>]
             store i8 %20_ i8* %2_ align 1
-            dup29/31/32 <<<[->>+>+<<<]
-            mov31/29 >>[-<<+>>]
-            <<<<<<<<<<<<<<<[-]
-            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            dup12/14/15 <<<[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
+            <<<<[-]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>]
+            
+            <<<<<<<<<<<+
+        >>>]
         >#B:%21
         [
             -
             %22 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            dup15/30/31 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/15 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            >>>[-]
+            dup9/11/14 <<[->>+>>>+<<<<<]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
             ret i32 %22
+            
             zero all function allocs
-            <<<<<<<<<<<<<<<<[-]
-            >[-]
-            >[-]
-            >[-]
+            <<<<<[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<<<<]
-<<<]
+            <[-]
+            r1 
+            l5 
+        >>>>]
+    <<<<<<<]
+<]
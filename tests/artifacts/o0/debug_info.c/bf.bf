@@ -0,0 +1,681 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 6834 ops ~112177 steps
+frame widths:
+  main: 38 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%9
+        #B:%17
+        #B:%18
+        #B:%22
+        #B:%32
+        #alloc_%2
+        #alloc_%2{1}
+        #alloc_%2{2}
+        #alloc_%3
+        #alloc_%4
+        #%6_=_load_i8*_%3__align_1_(with_debugloc)(mult)
+        #%7_=_zext_i8_%6_to_i32_(with_debugloc)(mult)
+        #%10_=_call_@getchar()_(with_debugloc)(mult)
+        #%25_=_sub_i32_%24__i32_1_(with_debugloc)(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = alloca {3 x i8}_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i8 0_ i8* %3_ align 1 (with debugloc)
+            
+            >>>>>>>>>>>[-]
+            
+            br label %5 (with debugloc)
+            
+            <<<<<<<<<+
+        <<]
+        >>#B:%5
+        [
+            -
+            %6 = load i8* %3_ align 1 (with debugloc)
+            
+            >>>>>>>>>>>[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %7 = zext i8 %6 to i32 (with debugloc)
+            
+            <<<[-]
+            dup15/20/19 <[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov19/16 <[-<<<+>>>]
+            %8 = icmp slt i32 %7_ i32 3 (with debugloc)
+            
+            <<<<[-]
+            dup16/19/21 >[->>>+>>+<<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >#constop_3
+            +++
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/24 <<[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/29 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/32 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            br i1 %8_ label %9_ label %17 (with debugloc)
+            
+            dup15/20/19 <<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%9_true
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%17_false
+            <<<]
+        >]
+        >#B:%9
+        [
+            -
+            %10 = call @getchar() (with debugloc)
+            
+            >>>>>>>>>>>>[-]
+            getchar intrinsic
+            ,
+            %11 = load i8* %3_ align 1 (with debugloc)
+            
+            <<[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %12 = zext i8 %11 to i64 (with debugloc)
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %13 = getelementptr inbounds {3 x i8}* %2_ i64 0_ i64 %12 (with debugloc)
+            
+            <<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++++
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            mov19/15 <<[-<<<<+>>>>]
+            >>>[
+                -
+                <<<<<<<+
+            >>>>>>>]
+            store i8 %10_ i8* %13_ align 1 (with debugloc)
+            
+            dup17/19/20 <<<<<[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            dup15/21/22 <<<<[->>>>>>+>+<<<<<<<]
+            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/30 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov30/25 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov20/26 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov24/23 <[-<+>]
+                mov25/24 >[-<+>]
+                mov26/25 >[-<+>]
+                mov22/26 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov26/22 >>>>[-<<<<+>>>>]
+            <<[
+                mov27/23 >>>[-<<<<+>>>>]
+                
+                mov24/25 <<<[->+<]
+                r1 >
+                -
+            ]
+            %15 = load i8* %3_ align 1 (with debugloc)
+            
+            <<<<<<<<<[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %16 = add i8 %15_ i8 1 (with debugloc)
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov20/16 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/23/25 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov25/16 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov22/23 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov23/22 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %16_ i8* %3_ align 1 (with debugloc)
+            
+            dup16/19/20 <<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<[-]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            br label %5 (with debugloc)
+            
+            <<<<<<<<<<<<<<<<+
+        >]
+        >#B:%17
+        [
+            -
+            store i8 3_ i8* %4_ align 1 (with debugloc)
+            
+            >>>>>>>>[-]
+            +++
+            br label %18 (with debugloc)
+            
+            <<<<<<<+
+        <]
+        >#B:%18
+        [
+            -
+            %19 = load i8* %4_ align 1 (with debugloc)
+            
+            >>>>>>>>[-]
+            dup14/15/19 <[->+>>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %20 = zext i8 %19 to i32 (with debugloc)
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %21 = icmp sgt i32 %20_ i32 0 (with debugloc)
+            
+            <<<<<[-]
+            dup16/19/20 >[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/24 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/29 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/35 <<<[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>>>>>>+
+            <<<<<<<<[
+                -
+                
+                >>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<]
+            >>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %21_ label %22_ label %32 (with debugloc)
+            
+            dup15/20/19 <<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%22_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%32_false
+            <<<<<<]
+        >>>>]
+        >#B:%22
+        [
+            -
+            %23 = load i8* %4_ align 1 (with debugloc)
+            
+            >>>>>>>[-]
+            dup14/15/21 <[->+>>>>>>+<<<<<<<]
+            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %24 = zext i8 %23 to i32 (with debugloc)
+            
+            <<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %25 = sub i32 %24_ i32 1 (with debugloc)
+            
+            <<[-]
+            dup16/19/20 <<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov20/18 <[-<<+>>]
+            >[
+                -
+                >>+
+                <<<<<[
+                    -
+                    mov18/22 [->>>>+<<<<]
+                    >>>>>-
+                <<<<<]
+                mov22/18 >>>>[-<<<<+>>>>]
+                >[
+                    -
+                    <<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>]
+            <<]
+            %26 = sext i32 %25 to i64 (with debugloc)
+            
+            <<<<<[-]
+            dup18/19/20 >>[->+>+<<]
+            mov19/18 >[-<+>]
+            mov20/16 >[-<<<<+>>>>]
+            %27 = getelementptr inbounds {3 x i8}* %2_ i64 0_ i64 %26 (with debugloc)
+            
+            <<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++++
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            mov19/18 <<[-<+>]
+            >>>[
+                -
+                <<<<+
+            >>>>]
+            %28 = load i8* %27_ align 1 (with debugloc)
+            
+            <<<<<<[-]
+            dup18/19/20 >>[->+>+<<]
+            mov19/18 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/23 [-<<<<+>>>>]
+                <<<<+++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov22/21 <[-<+>]
+                mov23/22 >[-<+>]
+                
+                mov20/23 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup20/21/23 <<<[->+>>+<<<]
+            mov21/20 >[-<+>]
+            >[
+                mov24/21 >>[-<<<+>>>]
+                mov23/24 <[->+<]
+                mov22/23 <[->+<]
+                r1 >
+                -
+            ]
+            mov23/16 >[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %28) (with debugloc)
+            
+            putchar intrinsic
+            dup16/19/20 <<<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            >.
+            [-]
+            %31 = add i8 %23_ i8 255 (with debugloc)
+            
+            <<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov20/16 <<[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/24/26 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov26/16 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            store i8 %31_ i8* %4_ align 1 (with debugloc)
+            
+            dup16/19/20 <<<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            br label %18 (with debugloc)
+            
+            <<<<<<<<<<<<<+
+        >]
+        >#B:%32
+        [
+            -
+            ret i32 0 (with debugloc)
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+    <<<<<<<<]
+<]
@@ -0,0 +1,281 @@
+; ModuleID = './tests/artifacts/o0/debug_info.c/ir.bc'
+source_filename = "./tests/cases/debug_info.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 !dbg !10 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  call void @llvm.dbg.declare(metadata i8** %2, metadata !17, metadata !DIExpression()), !dbg !18
+  br label %3, !dbg !19
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8, !dbg !20
+  %5 = load i8, i8* %4, align 1, !dbg !21
+  %6 = icmp ne i8 %5, 0, !dbg !19
+  br i1 %6, label %7, label %12, !dbg !19
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8, !dbg !22
+  %9 = load i8, i8* %8, align 1, !dbg !24
+  call void @putchar(i8 noundef zeroext %9), !dbg !25
+  %10 = load i8*, i8** %2, align 8, !dbg !26
+  %11 = getelementptr inbounds i8, i8* %10, i32 1, !dbg !26
+  store i8* %11, i8** %2, align 8, !dbg !26
+  br label %3, !dbg !19, !llvm.loop !27
+
+12:                                               ; preds = %3
+  ret void, !dbg !30
+}
+
+; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
+declare void @llvm.dbg.declare(metadata, metadata, metadata) #1
+
+declare void @putchar(i8 noundef zeroext) #2
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 !dbg !31 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  call void @llvm.dbg.declare(metadata i32* %2, metadata !35, metadata !DIExpression()), !dbg !36
+  %4 = load i32, i32* %2, align 4, !dbg !37
+  %5 = icmp ne i32 %4, 0, !dbg !37
+  br i1 %5, label %7, label %6, !dbg !39
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48), !dbg !40
+  br label %29, !dbg !42
+
+7:                                                ; preds = %1
+  call void @llvm.dbg.declare(metadata i32* %3, metadata !43, metadata !DIExpression()), !dbg !44
+  store i32 1, i32* %3, align 4, !dbg !44
+  br label %8, !dbg !45
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4, !dbg !46
+  %10 = load i32, i32* %3, align 4, !dbg !47
+  %11 = udiv i32 %9, %10, !dbg !48
+  %12 = icmp uge i32 %11, 10, !dbg !49
+  br i1 %12, label %13, label %16, !dbg !45
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4, !dbg !50
+  %15 = mul i32 %14, 10, !dbg !50
+  store i32 %15, i32* %3, align 4, !dbg !50
+  br label %8, !dbg !45, !llvm.loop !51
+
+16:                                               ; preds = %8
+  br label %17, !dbg !53
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4, !dbg !54
+  %19 = icmp ne i32 %18, 0, !dbg !53
+  br i1 %19, label %20, label %29, !dbg !53
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4, !dbg !55
+  %22 = load i32, i32* %3, align 4, !dbg !57
+  %23 = udiv i32 %21, %22, !dbg !58
+  %24 = urem i32 %23, 10, !dbg !59
+  %25 = add i32 48, %24, !dbg !60
+  %26 = trunc i32 %25 to i8, !dbg !61
+  call void @putchar(i8 noundef zeroext %26), !dbg !62
+  %27 = load i32, i32* %3, align 4, !dbg !63
+  %28 = udiv i32 %27, 10, !dbg !63
+  store i32 %28, i32* %3, align 4, !dbg !63
+  br label %17, !dbg !53, !llvm.loop !64
+
+29:                                               ; preds = %17, %6
+  ret void, !dbg !66
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @main() #0 !dbg !67 {
+  %1 = alloca i32, align 4
+  %2 = alloca [3 x i8], align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  store i32 0, i32* %1, align 4
+  call void @llvm.dbg.declare(metadata [3 x i8]* %2, metadata !72, metadata !DIExpression()), !dbg !77
+  call void @llvm.dbg.declare(metadata i8* %3, metadata !78, metadata !DIExpression()), !dbg !80
+  store i8 0, i8* %3, align 1, !dbg !80
+  br label %5, !dbg !81
+
+5:                                                ; preds = %14, %0
+  %6 = load i8, i8* %3, align 1, !dbg !82
+  %7 = zext i8 %6 to i32, !dbg !82
+  %8 = icmp slt i32 %7, 3, !dbg !84
+  br i1 %8, label %9, label %17, !dbg !85
+
+9:                                                ; preds = %5
+  %10 = call zeroext i8 @getchar(), !dbg !86
+  %11 = load i8, i8* %3, align 1, !dbg !88
+  %12 = zext i8 %11 to i64, !dbg !89
+  %13 = getelementptr inbounds [3 x i8], [3 x i8]* %2, i64 0, i64 %12, !dbg !89
+  store i8 %10, i8* %13, align 1, !dbg !90
+  br label %14, !dbg !91
+
+14:                                               ; preds = %9
+  %15 = load i8, i8* %3, align 1, !dbg !92
+  %16 = add i8 %15, 1, !dbg !92
+  store i8 %16, i8* %3, align 1, !dbg !92
+  br label %5, !dbg !93, !llvm.loop !94
+
+17:                                               ; preds = %5
+  call void @llvm.dbg.declare(metadata i8* %4, metadata !96, metadata !DIExpression()), !dbg !98
+  store i8 3, i8* %4, align 1, !dbg !98
+  br label %18, !dbg !99
+
+18:                                               ; preds = %29, %17
+  %19 = load i8, i8* %4, align 1, !dbg !100
+  %20 = zext i8 %19 to i32, !dbg !100
+  %21 = icmp sgt i32 %20, 0, !dbg !102
+  br i1 %21, label %22, label %32, !dbg !103
+
+22:                                               ; preds = %18
+  %23 = load i8, i8* %4, align 1, !dbg !104
+  %24 = zext i8 %23 to i32, !dbg !104
+  %25 = sub nsw i32 %24, 1, !dbg !106
+  %26 = sext i32 %25 to i64, !dbg !107
+  %27 = getelementptr inbounds [3 x i8], [3 x i8]* %2, i64 0, i64 %26, !dbg !107
+  %28 = load i8, i8* %27, align 1, !dbg !107
+  call void @putchar(i8 noundef zeroext %28), !dbg !108
+  br label %29, !dbg !109
+
+29:                                               ; preds = %22
+  %30 = load i8, i8* %4, align 1, !dbg !110
+  %31 = add i8 %30, -1, !dbg !110
+  store i8 %31, i8* %4, align 1, !dbg !110
+  br label %18, !dbg !111, !llvm.loop !112
+
+32:                                               ; preds = %18
+  ret i32 0, !dbg !114
+}
+
+declare zeroext i8 @getchar() #2
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { nofree nosync nounwind readnone speculatable willreturn }
+attributes #2 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!2, !3, !4, !5, !6, !7, !8}
+!llvm.ident = !{!9}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "Debian clang version 14.0.6", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, splitDebugInlining: false, nameTableKind: None)
+!1 = !DIFile(filename: "tests/cases/debug_info.c", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "e5079375f93b388a0692332ff725c732")
+!2 = !{i32 7, !"Dwarf Version", i32 5}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = !{i32 1, !"wchar_size", i32 4}
+!5 = !{i32 7, !"PIC Level", i32 2}
+!6 = !{i32 7, !"PIE Level", i32 2}
+!7 = !{i32 7, !"uwtable", i32 1}
+!8 = !{i32 7, !"frame-pointer", i32 2}
+!9 = !{!"Debian clang version 14.0.6"}
+!10 = distinct !DISubprogram(name: "putstr", scope: !11, file: !11, line: 31, type: !12, scopeLine: 31, flags: DIFlagPrototyped, spFlags: DISPFlagDefinition, unit: !0, retainedNodes: !16)
+!11 = !DIFile(filename: "./stdfuck.h", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "b67b4bb6984323629ac25b8a7f940293")
+!12 = !DISubroutineType(types: !13)
+!13 = !{null, !14}
+!14 = !DIDerivedType(tag: DW_TAG_pointer_type, baseType: !15, size: 64)
+!15 = !DIBasicType(name: "char", size: 8, encoding: DW_ATE_signed_char)
+!16 = !{}
+!17 = !DILocalVariable(name: "s", arg: 1, scope: !10, file: !11, line: 31, type: !14)
+!18 = !DILocation(line: 31, column: 19, scope: !10)
+!19 = !DILocation(line: 32, column: 2, scope: !10)
+!20 = !DILocation(line: 32, column: 9, scope: !10)
+!21 = !DILocation(line: 32, column: 8, scope: !10)
+!22 = !DILocation(line: 33, column: 12, scope: !23)
+!23 = distinct !DILexicalBlock(scope: !10, file: !11, line: 32, column: 12)
+!24 = !DILocation(line: 33, column: 11, scope: !23)
+!25 = !DILocation(line: 33, column: 3, scope: !23)
+!26 = !DILocation(line: 34, column: 4, scope: !23)
+!27 = distinct !{!27, !19, !28, !29}
+!28 = !DILocation(line: 35, column: 2, scope: !10)
+!29 = !{!"llvm.loop.mustprogress"}
+!30 = !DILocation(line: 36, column: 1, scope: !10)
+!31 = distinct !DISubprogram(name: "putdec", scope: !11, file: !11, line: 39, type: !32, scopeLine: 39, flags: DIFlagPrototyped, spFlags: DISPFlagDefinition, unit: !0, retainedNodes: !16)
+!32 = !DISubroutineType(types: !33)
+!33 = !{null, !34}
+!34 = !DIBasicType(name: "unsigned int", size: 32, encoding: DW_ATE_unsigned)
+!35 = !DILocalVariable(name: "n", arg: 1, scope: !31, file: !11, line: 39, type: !34)
+!36 = !DILocation(line: 39, column: 26, scope: !31)
+!37 = !DILocation(line: 40, column: 8, scope: !38)
+!38 = distinct !DILexicalBlock(scope: !31, file: !11, line: 40, column: 7)
+!39 = !DILocation(line: 40, column: 7, scope: !31)
+!40 = !DILocation(line: 41, column: 5, scope: !41)
+!41 = distinct !DILexicalBlock(scope: !38, file: !11, line: 40, column: 11)
+!42 = !DILocation(line: 42, column: 5, scope: !41)
+!43 = !DILocalVariable(name: "digs", scope: !31, file: !11, line: 45, type: !34)
+!44 = !DILocation(line: 45, column: 16, scope: !31)
+!45 = !DILocation(line: 46, column: 3, scope: !31)
+!46 = !DILocation(line: 46, column: 9, scope: !31)
+!47 = !DILocation(line: 46, column: 13, scope: !31)
+!48 = !DILocation(line: 46, column: 11, scope: !31)
+!49 = !DILocation(line: 46, column: 18, scope: !31)
+!50 = !DILocation(line: 46, column: 30, scope: !31)
+!51 = distinct !{!51, !45, !52, !29}
+!52 = !DILocation(line: 46, column: 33, scope: !31)
+!53 = !DILocation(line: 48, column: 3, scope: !31)
+!54 = !DILocation(line: 48, column: 9, scope: !31)
+!55 = !DILocation(line: 49, column: 21, scope: !56)
+!56 = distinct !DILexicalBlock(scope: !31, file: !11, line: 48, column: 15)
+!57 = !DILocation(line: 49, column: 25, scope: !56)
+!58 = !DILocation(line: 49, column: 23, scope: !56)
+!59 = !DILocation(line: 49, column: 30, scope: !56)
+!60 = !DILocation(line: 49, column: 17, scope: !56)
+!61 = !DILocation(line: 49, column: 13, scope: !56)
+!62 = !DILocation(line: 49, column: 5, scope: !56)
+!63 = !DILocation(line: 50, column: 10, scope: !56)
+!64 = distinct !{!64, !53, !65, !29}
+!65 = !DILocation(line: 51, column: 3, scope: !31)
+!66 = !DILocation(line: 52, column: 1, scope: !31)
+!67 = distinct !DISubprogram(name: "main", scope: !68, file: !68, line: 6, type: !69, scopeLine: 6, spFlags: DISPFlagDefinition, unit: !0, retainedNodes: !16)
+!68 = !DIFile(filename: "./tests/cases/debug_info.c", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "e5079375f93b388a0692332ff725c732")
+!69 = !DISubroutineType(types: !70)
+!70 = !{!71}
+!71 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!72 = !DILocalVariable(name: "buf", scope: !67, file: !68, line: 7, type: !73)
+!73 = !DICompositeType(tag: DW_TAG_array_type, baseType: !74, size: 24, elements: !75)
+!74 = !DIBasicType(name: "unsigned char", size: 8, encoding: DW_ATE_unsigned_char)
+!75 = !{!76}
+!76 = !DISubrange(count: 3)
+!77 = !DILocation(line: 7, column: 11, scope: !67)
+!78 = !DILocalVariable(name: "i", scope: !79, file: !68, line: 8, type: !74)
+!79 = distinct !DILexicalBlock(scope: !67, file: !68, line: 8, column: 3)
+!80 = !DILocation(line: 8, column: 16, scope: !79)
+!81 = !DILocation(line: 8, column: 8, scope: !79)
+!82 = !DILocation(line: 8, column: 23, scope: !83)
+!83 = distinct !DILexicalBlock(scope: !79, file: !68, line: 8, column: 3)
+!84 = !DILocation(line: 8, column: 25, scope: !83)
+!85 = !DILocation(line: 8, column: 3, scope: !79)
+!86 = !DILocation(line: 9, column: 14, scope: !87)
+!87 = distinct !DILexicalBlock(scope: !83, file: !68, line: 8, column: 35)
+!88 = !DILocation(line: 9, column: 9, scope: !87)
+!89 = !DILocation(line: 9, column: 5, scope: !87)
+!90 = !DILocation(line: 9, column: 12, scope: !87)
+!91 = !DILocation(line: 10, column: 3, scope: !87)
+!92 = !DILocation(line: 8, column: 31, scope: !83)
+!93 = !DILocation(line: 8, column: 3, scope: !83)
+!94 = distinct !{!94, !85, !95, !29}
+!95 = !DILocation(line: 10, column: 3, scope: !79)
+!96 = !DILocalVariable(name: "i", scope: !97, file: !68, line: 11, type: !74)
+!97 = distinct !DILexicalBlock(scope: !67, file: !68, line: 11, column: 3)
+!98 = !DILocation(line: 11, column: 16, scope: !97)
+!99 = !DILocation(line: 11, column: 8, scope: !97)
+!100 = !DILocation(line: 11, column: 23, scope: !101)
+!101 = distinct !DILexicalBlock(scope: !97, file: !68, line: 11, column: 3)
+!102 = !DILocation(line: 11, column: 25, scope: !101)
+!103 = !DILocation(line: 11, column: 3, scope: !97)
+!104 = !DILocation(line: 12, column: 17, scope: !105)
+!105 = distinct !DILexicalBlock(scope: !101, file: !68, line: 11, column: 35)
+!106 = !DILocation(line: 12, column: 19, scope: !105)
+!107 = !DILocation(line: 12, column: 13, scope: !105)
+!108 = !DILocation(line: 12, column: 5, scope: !105)
+!109 = !DILocation(line: 13, column: 3, scope: !105)
+!110 = !DILocation(line: 11, column: 31, scope: !101)
+!111 = !DILocation(line: 11, column: 3, scope: !101)
+!112 = distinct !{!112, !103, !113, !29}
+!113 = !DILocation(line: 13, column: 3, scope: !97)
+!114 = !DILocation(line: 14, column: 3, scope: !67)
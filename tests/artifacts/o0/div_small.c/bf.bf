@@ -0,0 +1,417 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3510 ops ~117981 steps
+frame widths:
+  main: 20 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #%2_=_load_i8*_%1__align_1(mult)
+        #%3_=_zext_i8_%2_to_i32(mult)
+        #%4_=_sdiv_i32_%3__i32_3(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store i8 47_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>+++++
+            [
+                -
+                <<<<+++++++++
+            >>>>]
+            <<<<++
+            %2 = load i8* %1_ align 1
+            
+            >[-]
+            known to be 47
+            >>>>+++++
+            [
+                -
+                <<<<+++++++++
+            >>>>]
+            <<<<++
+            %3 = zext i8 %2 to i32
+            
+            >[-]
+            known to be 47
+            >>+++++
+            [
+                -
+                <<+++++++++
+            >>]
+            <<++
+            %4 = sdiv i32 %3_ i32 3
+            
+            >[-]
+            op_to_reg storing const value in temp address
+            >#constop_47
+            >>+++++
+            [
+                -
+                <<+++++++++
+            >>]
+            <<++
+            op_to_reg storing const value in temp address
+            >#constop_3
+            +++
+            dup9/12/13 [->>>+>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov8/14 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<+
+                >>>>[
+                    [-]
+                    <<<<-
+                >>>>]
+                mov14/8 >>>[-<<<<<<+>>>>>>]
+                dup9/12/13 <<<<<[->>>+>+<<<<]
+                mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            %5 = add i32 65_ i32 %4
+            
+            <<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov8/6 <<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup6/13/15 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov15/6 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov12/13 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov13/12 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %6 = trunc i32 %5 to i8
+            
+            <<<<<[-]
+            dup6/8/9 <[->>+>+<<<]
+            mov8/6 >>[-<<+>>]
+            mov9/7 >[-<<+>>]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup7/8/9 <<[->+>+<<]
+            mov8/7 >[-<+>]
+            >.
+            [-]
+            %8 = zext i8 %2 to i32
+            
+            <<<[-]
+            known to be 47
+            >>+++++
+            [
+                -
+                <<+++++++++
+            >>]
+            <<++
+            %9 = srem i32 %8_ i32 10
+            
+            >[-]
+            op_to_reg storing const value in temp address
+            >#constop_47
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <++
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup10/12/13 [->>+>+<<<]
+            mov13/10 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov8/17 [->>>>>>>>>+<<<<<<<<<]
+                >>>>>>>>>>>+
+                <<<<<<<[
+                    -
+                    <+
+                    >>>>>>[
+                        -
+                        <<<<<<-
+                        r1 >
+                    >>>>>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<<<<]
+                >>>>>>>-
+                mov17/8 <<[-<<<<<<<<<+>>>>>>>>>]
+                dup10/12/13 <<<<<<<[->>+>+<<<]
+                mov13/10 >>>[-<<<+>>>]
+            <<<<<]
+            >>>#neg
+            [
+                mov12/7 >[-<<<<<+>>>>>]
+                <[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov11/7 [-<<<<+>>>>]
+            <[-]
+            >>[-]
+            %10 = add i32 48_ i32 %9
+            
+            <<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup7/10/11 <<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov8/6 <<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup6/13/15 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov15/6 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov12/13 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov13/12 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %11 = trunc i32 %10 to i8
+            
+            <<<<<[-]
+            dup6/8/9 <[->>+>+<<<]
+            mov8/6 >>[-<<+>>]
+            mov9/7 >[-<<+>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            dup7/8/9 <<[->+>+<<]
+            mov8/7 >[-<+>]
+            >.
+            [-]
+            %13 = zext i8 %2 to i32
+            
+            <<<[-]
+            known to be 47
+            >>+++++
+            [
+                -
+                <<+++++++++
+            >>]
+            <<++
+            %14 = sdiv i32 %13_ i32 100
+            
+            <[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_47
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <++
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov8/15 [->>>>>>>+<<<<<<<]
+                >>>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<+
+                >>>>>>>[
+                    [-]
+                    <<<<<<<-
+                >>>>>>>]
+                mov15/8 >>>[-<<<<<<<+>>>>>>>]
+                dup10/13/14 <<<<<[->>>+>+<<<<]
+                mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<]
+            >>[-]
+            >>>[-]
+            %15 = add i32 65_ i32 %14
+            
+            <<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup5/10/11 <<<[->>>>>+>+<<<<<<]
+            mov10/5 >>>>>[-<<<<<+>>>>>]
+            mov8/6 <<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup6/13/15 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov15/6 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov12/13 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov13/12 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %16 = trunc i32 %15 to i8
+            
+            <<<<<<<[-]
+            dup6/8/9 >[->>+>+<<<]
+            mov8/6 >>[-<<+>>]
+            mov9/5 >[-<<<<+>>>>]
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            dup5/8/9 <<<<[->>>+>+<<<<]
+            mov8/5 >>>[-<<<+>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,155 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 579 ops ~2658 steps
+frame widths:
+  main: 17 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l17 <<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%9
+        #B:%11
+        #alloc_%1
+        #alloc_%2
+        #%4_=_call_@getchar()(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%7_=_zext_i8_0_to_i32(mult)
+        #%8_=_icmp_ne_i32_%5__i32_%7(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            br label %3
+            
+            <<<+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = call @getchar()
+            
+            >>>>>[-]
+            getchar intrinsic
+            ,
+            store i8 %4_ i8* %2_ align 1
+            
+            dup9/13/14 [->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<[-]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            %5 = zext i8 %4 to i32
+            
+            <<<<[-]
+            dup9/13/15 <[->>>>+>>+<<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov15/10 >>[-<<<<<+>>>>>]
+            %7 = zext i8 0 to i32
+            
+            <<<<[-]
+            known to be 0
+            
+            %8 = icmp ne i32 %5_ i32 %7
+            
+            >[-]
+            dup10/13/14 <<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<+
+            >>]
+            br i1 %8_ label %9_ label %11
+            
+            dup12/14/13 <<[->>+<+<]
+            mov14/12 >>[-<<+>>]
+            <<<<<<<<<<<+
+            >>>>>>>>>>[
+                -
+                <<<<<<<<<<-
+                >>+
+                #B:main/%9_true
+            >>>>>>>>]
+            <<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%11_false
+            <<<]
+        >]
+        >#B:%9
+        [
+            -
+            %10 = load i8* %2_ align 1
+            
+            >>>>>[-]
+            dup8/10/16 <<[->>+>>>>>>+<<<<<<<<]
+            mov16/8 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup10/13/14 <<<<<<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            >.
+            [-]
+            br label %3
+            
+            <<<<<<<<<<+
+        >]
+        >#B:%11
+        [
+            -
+            %12 = load i32* %1_ align 4
+            
+            >>>>[-]
+            dup7/10/13 <<<[->>>+>>>+<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            ret i32 %12
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
@@ -0,0 +1,93 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 125 ops ~349 steps
+frame widths:
+  main: 7 cells
+r1 >
+
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+
+r1 >
+r4 >>>>
++++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l7 <<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        <#B:%0
+        [
+            -
+            copy up args
+            call @putchar(i8 10_ i8 91_ i8 93_ i8 120)
+            
+            putchar run intrinsic
+            >>++++++++++
+            .
+            >+++++++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            ++
+            .
+            >>+++
+            [
+                -
+                <<+++++++++
+            >>]
+            <<.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
+clear globals:
+l7 <<<<<<
+[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
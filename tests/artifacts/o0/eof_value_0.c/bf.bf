@@ -0,0 +1,139 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 312 ops ~1040 steps
+frame widths:
+  main: 14 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l14 <<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%6
+        #B:%7
+        #alloc_%1
+        #%3_=_zext_i8_0_to_i32(mult)
+        #%4_=_icmp_eq_i32_%3__i32_0(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            %3 = zext i8 0 to i32
+            
+            >[-]
+            known to be 0
+            
+            %4 = icmp eq i32 %3_ i32 0
+            
+            >[-]
+            known to be 1
+            +
+            br i1 %4_ label %5_ label %6
+            
+            dup9/11/10 [->>+<+<]
+            mov11/9 >>[-<<+>>]
+            <<<<<<<<+
+            >>>>>>>[
+                -
+                <<<<<<<-
+                >+
+                #B:main/%5_true
+            >>>>>>]
+            <<<<<<<[
+                -
+                >>+
+                #B:main/%6_false
+            <<]
+        <]
+        >>#B:%5
+        [
+            -
+            call @putchar(i8 48)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            br label %7
+            
+            <<<<<<+
+        <<]
+        >#B:%6
+        [
+            -
+            call @putchar(i8 63)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>#constop_63
+            >+++++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            br label %7
+            
+            <<<<+
+        <]
+        >#B:%7
+        [
+            -
+            %8 = load i32* %1_ align 4
+            
+            >>[-]
+            dup7/8/10 <[->+>>+<<<]
+            mov10/7 >>>[-<<<+>>>]
+            ret i32 %8
+            
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
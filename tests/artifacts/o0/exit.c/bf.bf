@@ -0,0 +1,239 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 918 ops ~5622 steps
+frame widths:
+  main: 21 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l21 <<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%6
+        #B:%10
+        #B:%11
+        #B:%13
+        #alloc_%2
+        #%4_=_call_@getchar()(mult)
+        #%5_=_icmp_ne_i8_%4__i8_0(mult)
+        #%8_=_zext_i8_%7_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = alloca i8_ align 1
+            br label %3
+            
+            >>+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = call @getchar()
+            
+            >>>>>>[-]
+            getchar intrinsic
+            ,
+            store i8 %4_ i8* %2_ align 1
+            
+            dup10/13/14 [->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            <<<<[-]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            %5 = icmp ne i8 %4_ i8 0
+            
+            <<<[-]
+            dup10/13/15 <[->>>+>>+<<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            >>[
+                [-]
+                <<<<+
+            >>>>]
+            br i1 %5_ label %6_ label %13
+            
+            dup11/14/13 <<<<[->>>+<+<<]
+            mov14/11 >>>[-<<<+>>>]
+            <<<<<<<<<<<+
+            >>>>>>>>>>[
+                -
+                <<<<<<<<<<-
+                >>+
+                #B:main/%6_true
+            >>>>>>>>]
+            <<<<<<<<<<[
+                -
+                >>>>>+
+                #B:main/%13_false
+            <<<<<]
+        >]
+        >#B:%6
+        [
+            -
+            %7 = load i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup9/11/16 <<[->>+>>>>>+<<<<<<<]
+            mov16/9 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %8 = zext i8 %7 to i32
+            
+            <<<<[-]
+            dup11/13/14 <[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            mov14/12 >[-<<+>>]
+            %9 = icmp eq i32 %8_ i32 120
+            
+            <<<[-]
+            dup12/13/14 >[->+>+<<]
+            mov13/12 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_120
+            >>++++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/18 <<[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            <<[
+                [-]
+                <<<<<[-]
+            >>>>>]
+            br i1 %9_ label %10_ label %11
+            
+            dup11/14/13 <<<<<[->>>+<+<<]
+            mov14/11 >>>[-<<<+>>>]
+            <<<<<<<<<<<+
+            >>>>>>>>>>[
+                -
+                <<<<<<<<<<-
+                >>>+
+                #B:main/%10_true
+            >>>>>>>]
+            <<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%11_false
+            <<<<]
+        >>]
+        >#B:%10
+        [
+            -
+            unreachable
+            
+            unreachable_ ending it all
+            zero all function allocs
+            >>>[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<[-]
+            <[-]
+            l2 <
+            -
+            [
+                <[-]
+                mov1/0 >[-<+>]
+                l1 <
+                -
+            ]
+        >>>>>]
+        >#B:%11
+        [
+            -
+            %12 = load i8* %2_ align 1
+            
+            >>>>[-]
+            dup9/11/15 <<[->>+>>>>+<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            dup11/13/14 <<<<[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            >.
+            [-]
+            br label %3
+            
+            <<<<<<<<<<+
+        >>>]
+        >#B:%13
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>]
+    <<<<<<<]
+<]
@@ -0,0 +1,349 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1928 ops ~11420 steps
+frame widths:
+  main: 30 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%9
+        #B:%10
+        #B:%11
+        #B:%18
+        #B:%19
+        #B:%20
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%2__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>[-]
+            
+            store volatile i8 3_ i8* %2_ align 1
+            
+            >[-]
+            +++
+            %3 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 3
+            +++
+            %4 = zext i8 %3 to i32
+            
+            >[-]
+            known to be 3
+            +++
+            %5 = icmp eq i32 %4_ i32 3
+            
+            <[-]
+            known to be 1
+            +
+            %6 = zext i1 %5 to i32
+            
+            >[-]
+            known to be 1
+            +
+            %7 = sext i32 %6 to i64
+            
+            <[-]
+            known to be 1
+            +
+            %8 = icmp ne i64 %7_ i64 0
+            
+            >[-]
+            known to be 1
+            +
+            br i1 %8_ label %9_ label %10
+            
+            dup13/15/14 [->>+<+<]
+            mov15/13 >>[-<<+>>]
+            <<<<<<<<<<<<+
+            >>>>>>>>>>>[
+                -
+                <<<<<<<<<<<-
+                >+
+                #B:main/%9_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%10_false
+            <<]
+        <]
+        >>#B:%9
+        [
+            -
+            call @putchar(i8 121)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            br label %11
+            
+            <<<<<<<<<<+
+        <<]
+        >#B:%10
+        [
+            -
+            call @putchar(i8 120)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_120
+            >++++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
+            [-]
+            br label %11
+            
+            <<<<<<<<+
+        <]
+        >#B:%11
+        [
+            -
+            %12 = load volatile i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup11/12/14 <[->+>>+<<<]
+            mov14/11 >>>[-<<<+>>>]
+            %13 = zext i8 %12 to i32
+            
+            <[-]
+            dup12/14/15 <[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
+            mov15/13 >[-<<+>>]
+            %14 = icmp sgt i32 %13_ i32 5
+            
+            <<<[-]
+            dup13/14/15 >[->+>+<<]
+            mov14/13 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_5
+            +++++
+            >++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/19 <<[->>>>+<<<<]
+            >>>>>>+
+            <<<<[
+                -
+                >+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<<[
+                >++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <[
+                    -
+                    >-
+                <]
+                >++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <]
+            >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/24 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/27 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>]
+            %15 = zext i1 %14 to i32
+            
+            <<<<<<<<<<<<<<[-]
+            dup12/14/15 <[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
+            mov15/13 >[-<<+>>]
+            %16 = sext i32 %15 to i64
+            
+            <<<[-]
+            dup13/14/15 >[->+>+<<]
+            mov14/13 >[-<+>]
+            mov15/12 >[-<<<+>>>]
+            %17 = icmp ne i64 %16_ i64 0
+            
+            <<[-]
+            dup12/14/15 <[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
+            >[
+                [-]
+                <<+
+            >>]
+            br i1 %17_ label %18_ label %19
+            
+            dup13/15/14 <<[->>+<+<]
+            mov15/13 >>[-<<+>>]
+            <<<<<<<<<<<<+
+            >>>>>>>>>>>[
+                -
+                <<<<<<<<<<<-
+                >>>>+
+                #B:main/%18_true
+            >>>>>>>]
+            <<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:main/%19_false
+            <<<<<]
+        >>>]
+        >#B:%18
+        [
+            -
+            call @putchar(i8 120)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_120
+            >++++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
+            [-]
+            br label %20
+            
+            <<<<<<<+
+        <<]
+        >#B:%19
+        [
+            -
+            call @putchar(i8 110)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            br label %20
+            
+            <<<<<+
+        <]
+        >#B:%20
+        [
+            -
+            %21 = load i32* %1_ align 4
+            
+            >>>[-]
+            dup10/12/14 <<[->>+>>+<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            ret i32 %21
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+    <<<<<<<<]
+<]
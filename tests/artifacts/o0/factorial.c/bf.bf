@@ -0,0 +1,588 @@
+eof: 0
+blocks made up by bfcc:
+  fact/%call_term_for_0 from %8 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  fact calls fact (depth unbounded_ recursive)
+  main calls fact (depth unbounded)
+  max depth from main: unbounded
+cost (loops go round 8 times):
+  fact: 4064 ops ~237094 steps
+  main: 2070 ops ~28943 steps
+frame widths:
+  fact: 32 cells
+  main: 21 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#fact
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/fact
+            >>>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_fact
+        #F:fact
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%7
+        #B:%8
+        #B:%call_term_for_0
+        #B:%19
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i8*_%3__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%10_=_zext_i8_%9_to_i32(mult)
+        #%15_=_call_@fact(i8_%14)(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/17 <<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store i8 %0_ i8* %3_ align 1
+            
+            dup15/16/17 >>>>>>>>>>>>>>>[->+>+<<]
+            mov16/15 >[-<+>]
+            <<<<<<[-]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %4 = load i8* %3_ align 1
+            
+            <<<<<<[-]
+            dup10/11/16 <[->+>>>>>+<<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            %5 = zext i8 %4 to i32
+            
+            <<<<[-]
+            dup11/16/18 <[->>>>>+>>+<<<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            mov18/12 >>[-<<<<<<+>>>>>>]
+            %6 = icmp slt i32 %5_ i32 2
+            
+            <<<<<<<[-]
+            dup12/16/17 >[->>>>+>+<<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>>#constop_2
+            ++
+            >++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/21 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <<+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<<[
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<[
+                    -
+                    >>>-
+                <<<]
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<]
+            >>>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/26 <<<<<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/29 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            br i1 %6_ label %7_ label %8
+            
+            dup11/17/16 <<<<<<<<<<<<<<<<<<[->>>>>>+<+<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >+
+                #B:fact/%7_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >>+
+                #B:fact/%8_false
+            <<]
+        <]
+        >>#B:%7
+        [
+            -
+            store i8 1_ i8* %2_ align 1
+            
+            >>>>[-]
+            +
+            br label %19
+            
+            <+
+        <<<]
+        >#B:%8
+        [
+            -
+            %9 = load i8* %3_ align 1
+            
+            >>>>>[-]
+            dup10/11/16 <[->+>>>>>+<<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            %10 = zext i8 %9 to i32
+            
+            <<<[-]
+            dup11/16/17 <<[->>>>>+>+<<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            mov17/13 >[-<<<<+>>>>]
+            %12 = zext i8 %9 to i32
+            
+            <<<<<[-]
+            dup11/16/17 <[->>>>>+>+<<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            mov17/12 >[-<<<<<+>>>>>]
+            %13 = sub i32 %12_ i32 1
+            
+            <<<<<<[-]
+            dup12/16/17 >[->>>>+>+<<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov17/11 <[-<<<<<<+>>>>>>]
+            >[
+                -
+                >>+
+                <<<<<<<<<[
+                    -
+                    mov11/19 [->>>>>>>>+<<<<<<<<]
+                    >>>>>>>>>-
+                <<<<<<<<<]
+                mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>]
+            <<]
+            %14 = trunc i32 %13 to i8
+            
+            <<<<<<[-]
+            dup11/16/17 <[->>>>>+>+<<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            mov17/12 >[-<<<<<+>>>>>]
+            %15 = call @fact(i8 %14)
+            
+            <<<[-]
+            enable next block when we return
+            <<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 32
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            dup12/16/17 [->>>>+>+<<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            mov17/37 >[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/39/40 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r32 
+            <<<<<<<#JUMP_PAD
+            +
+            >#caller
+            +
+            >>>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_fact
+            +
+            >#fact
+            +
+            >>#fact/b0
+            +
+        >>>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %15
+            mov48/14 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %16 = zext i8 %15 to i32
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup14/16/17 >>>[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            mov17/11 >[-<<<<<<+>>>>>>]
+            %17 = mul i32 %10_ i32 %16
+            
+            <<<<<[-]
+            dup13/16/17 >[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            dup11/18/19 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov17/23 <<[->>>>>>+<<<<<<]
+                >>>>>>[
+                    -
+                    <<<<<<+
+                    >>>>>+
+                    <<[
+                        -
+                        mov20/21 [->+<]
+                        <<<<<<<<+
+                        >>>>>>>>>>-
+                    <<]
+                    mov21/20 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<[-]
+                        >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <<[-]
+            >>>[-]
+            %18 = trunc i32 %17 to i8
+            
+            <<<<<<<<<[-]
+            dup12/16/17 >[->>>>+>+<<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            mov17/11 >[-<<<<<<+>>>>>>]
+            store i8 %18_ i8* %2_ align 1
+            
+            dup11/16/17 <<<<<<[->>>>>+>+<<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<[-]
+            mov17/9 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            br label %19
+            
+            <<<<<<<<<+
+        <]
+        >#B:%19
+        [
+            -
+            %20 = load i8* %2_ align 1
+            
+            >>>[-]
+            dup9/11/16 <<[->>+>>>>>+<<<<<<<]
+            mov16/9 >>>>>>>[-<<<<<<<+>>>>>>>]
+            ret i8 %20
+            
+            mov11/16 <<<<<[->>>>>+<<<<<]
+            zero all function allocs
+            <<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            mov16/9 >[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >>]
+    <<<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l21 <<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:fact
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_call_@fact(i8_%2)(mult)
+        #%5_=_call_@fact(i8_%4)(mult)
+        #%7_=_add_i32_%6__i32_65(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            store volatile i8 5_ i8* %1_ align 1
+            
+            >>>[-]
+            +++++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 5
+            +++++
+            %3 = call @fact(i8 %2)
+            
+            >[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_0
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_5
+            +++++
+            mov13/26 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_fact
+            +
+            >#fact
+            +
+            >>#fact/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %3
+            mov37/10 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup10/14/13 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <.
+            [-]
+            store volatile i8 4_ i8* %1_ align 1
+            
+            <<<<<[-]
+            ++++
+            %4 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 4
+            ++++
+            %5 = call @fact(i8 %4)
+            
+            >>[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_1
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_4
+            ++++
+            mov13/26 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_fact
+            +
+            >#fact
+            +
+            >>#fact/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            returned %5
+            mov37/11 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %6 = zext i8 %5 to i32
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup11/13/15 >>[->>+>>+<<<<]
+            mov13/11 >>[-<<+>>]
+            mov15/9 >>[-<<<<<<+>>>>>>]
+            %7 = add i32 %6_ i32 65
+            
+            <<<[-]
+            dup9/13/14 <<<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            ++++++++
+            [
+                -
+                >++++++++
+            <]
+            >+
+            mov14/12 <<[-<<+>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/18/20 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov20/12 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %8 = trunc i32 %7 to i8
+            
+            <<<<<<<<[-]
+            dup12/13/14 >>>[->+>+<<]
+            mov13/12 >[-<+>]
+            mov14/9 >[-<<<<<+>>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup9/13/14 <<<<<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
@@ -0,0 +1,710 @@
+; ModuleID = './tests/artifacts/o0/frame_overflow.c/ir.bc'
+source_filename = "./tests/cases/frame_overflow.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @main() #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  %8 = alloca i8, align 1
+  %9 = alloca i8, align 1
+  %10 = alloca i8, align 1
+  %11 = alloca i8, align 1
+  %12 = alloca i8, align 1
+  %13 = alloca i8, align 1
+  %14 = alloca i8, align 1
+  %15 = alloca i8, align 1
+  %16 = alloca i8, align 1
+  %17 = alloca i8, align 1
+  %18 = alloca i8, align 1
+  %19 = alloca i8, align 1
+  %20 = alloca i8, align 1
+  %21 = alloca i8, align 1
+  %22 = alloca i8, align 1
+  %23 = alloca i8, align 1
+  %24 = alloca i8, align 1
+  %25 = alloca i8, align 1
+  %26 = alloca i8, align 1
+  %27 = alloca i8, align 1
+  %28 = alloca i8, align 1
+  %29 = alloca i8, align 1
+  %30 = alloca i8, align 1
+  %31 = alloca i8, align 1
+  %32 = alloca i8, align 1
+  %33 = alloca i8, align 1
+  %34 = alloca i8, align 1
+  %35 = alloca i8, align 1
+  %36 = alloca i8, align 1
+  %37 = alloca i8, align 1
+  %38 = alloca i8, align 1
+  %39 = alloca i8, align 1
+  %40 = alloca i8, align 1
+  %41 = alloca i8, align 1
+  %42 = alloca i8, align 1
+  %43 = alloca i8, align 1
+  %44 = alloca i8, align 1
+  %45 = alloca i8, align 1
+  %46 = alloca i8, align 1
+  %47 = alloca i8, align 1
+  %48 = alloca i8, align 1
+  %49 = alloca i8, align 1
+  %50 = alloca i8, align 1
+  %51 = alloca i8, align 1
+  %52 = alloca i8, align 1
+  %53 = alloca i8, align 1
+  %54 = alloca i8, align 1
+  %55 = alloca i8, align 1
+  %56 = alloca i8, align 1
+  %57 = alloca i8, align 1
+  %58 = alloca i8, align 1
+  %59 = alloca i8, align 1
+  %60 = alloca i8, align 1
+  %61 = alloca i8, align 1
+  %62 = alloca i8, align 1
+  %63 = alloca i8, align 1
+  %64 = alloca i8, align 1
+  %65 = alloca i8, align 1
+  %66 = alloca i8, align 1
+  %67 = alloca i8, align 1
+  %68 = alloca i8, align 1
+  %69 = alloca i8, align 1
+  %70 = alloca i8, align 1
+  %71 = alloca i8, align 1
+  %72 = alloca i8, align 1
+  %73 = alloca i8, align 1
+  %74 = alloca i8, align 1
+  %75 = alloca i8, align 1
+  %76 = alloca i8, align 1
+  %77 = alloca i8, align 1
+  %78 = alloca i8, align 1
+  %79 = alloca i8, align 1
+  %80 = alloca i8, align 1
+  %81 = alloca i8, align 1
+  %82 = alloca i8, align 1
+  %83 = alloca i8, align 1
+  %84 = alloca i8, align 1
+  %85 = alloca i8, align 1
+  %86 = alloca i8, align 1
+  %87 = alloca i8, align 1
+  %88 = alloca i8, align 1
+  %89 = alloca i8, align 1
+  %90 = alloca i8, align 1
+  %91 = alloca i8, align 1
+  %92 = alloca i8, align 1
+  %93 = alloca i8, align 1
+  %94 = alloca i8, align 1
+  %95 = alloca i8, align 1
+  %96 = alloca i8, align 1
+  %97 = alloca i8, align 1
+  %98 = alloca i8, align 1
+  %99 = alloca i8, align 1
+  %100 = alloca i8, align 1
+  %101 = alloca i8, align 1
+  %102 = alloca i8, align 1
+  %103 = alloca i8, align 1
+  %104 = alloca i8, align 1
+  %105 = alloca i8, align 1
+  %106 = alloca i8, align 1
+  %107 = alloca i8, align 1
+  %108 = alloca i8, align 1
+  %109 = alloca i8, align 1
+  %110 = alloca i8, align 1
+  %111 = alloca i8, align 1
+  %112 = alloca i8, align 1
+  %113 = alloca i8, align 1
+  %114 = alloca i8, align 1
+  %115 = alloca i8, align 1
+  %116 = alloca i8, align 1
+  %117 = alloca i8, align 1
+  %118 = alloca i8, align 1
+  %119 = alloca i8, align 1
+  %120 = alloca i8, align 1
+  %121 = alloca i8, align 1
+  %122 = alloca i8, align 1
+  %123 = alloca i8, align 1
+  %124 = alloca i8, align 1
+  %125 = alloca i8, align 1
+  %126 = alloca i8, align 1
+  %127 = alloca i8, align 1
+  %128 = alloca i8, align 1
+  %129 = alloca i8, align 1
+  %130 = alloca i8, align 1
+  %131 = alloca i8, align 1
+  %132 = alloca i8, align 1
+  %133 = alloca i8, align 1
+  %134 = alloca i8, align 1
+  %135 = alloca i8, align 1
+  %136 = alloca i8, align 1
+  %137 = alloca i8, align 1
+  %138 = alloca i8, align 1
+  %139 = alloca i8, align 1
+  %140 = alloca i8, align 1
+  %141 = alloca i8, align 1
+  %142 = alloca i8, align 1
+  %143 = alloca i8, align 1
+  %144 = alloca i8, align 1
+  %145 = alloca i8, align 1
+  %146 = alloca i8, align 1
+  %147 = alloca i8, align 1
+  %148 = alloca i8, align 1
+  %149 = alloca i8, align 1
+  %150 = alloca i8, align 1
+  %151 = alloca i8, align 1
+  %152 = alloca i8, align 1
+  %153 = alloca i8, align 1
+  %154 = alloca i8, align 1
+  %155 = alloca i8, align 1
+  %156 = alloca i8, align 1
+  %157 = alloca i8, align 1
+  %158 = alloca i8, align 1
+  %159 = alloca i8, align 1
+  %160 = alloca i8, align 1
+  %161 = alloca i8, align 1
+  %162 = alloca i8, align 1
+  %163 = alloca i8, align 1
+  %164 = alloca i8, align 1
+  %165 = alloca i8, align 1
+  %166 = alloca i8, align 1
+  %167 = alloca i8, align 1
+  %168 = alloca i8, align 1
+  %169 = alloca i8, align 1
+  %170 = alloca i8, align 1
+  %171 = alloca i8, align 1
+  %172 = alloca i8, align 1
+  %173 = alloca i8, align 1
+  %174 = alloca i8, align 1
+  %175 = alloca i8, align 1
+  %176 = alloca i8, align 1
+  %177 = alloca i8, align 1
+  %178 = alloca i8, align 1
+  %179 = alloca i8, align 1
+  %180 = alloca i8, align 1
+  %181 = alloca i8, align 1
+  %182 = alloca i8, align 1
+  %183 = alloca i8, align 1
+  %184 = alloca i8, align 1
+  %185 = alloca i8, align 1
+  %186 = alloca i8, align 1
+  %187 = alloca i8, align 1
+  %188 = alloca i8, align 1
+  %189 = alloca i8, align 1
+  %190 = alloca i8, align 1
+  %191 = alloca i8, align 1
+  %192 = alloca i8, align 1
+  %193 = alloca i8, align 1
+  %194 = alloca i8, align 1
+  %195 = alloca i8, align 1
+  %196 = alloca i8, align 1
+  %197 = alloca i8, align 1
+  %198 = alloca i8, align 1
+  %199 = alloca i8, align 1
+  %200 = alloca i8, align 1
+  %201 = alloca i8, align 1
+  %202 = alloca i8, align 1
+  %203 = alloca i8, align 1
+  %204 = alloca i8, align 1
+  %205 = alloca i8, align 1
+  %206 = alloca i8, align 1
+  %207 = alloca i8, align 1
+  %208 = alloca i8, align 1
+  %209 = alloca i8, align 1
+  %210 = alloca i8, align 1
+  %211 = alloca i8, align 1
+  %212 = alloca i8, align 1
+  %213 = alloca i8, align 1
+  %214 = alloca i8, align 1
+  %215 = alloca i8, align 1
+  %216 = alloca i8, align 1
+  %217 = alloca i8, align 1
+  %218 = alloca i8, align 1
+  %219 = alloca i8, align 1
+  %220 = alloca i8, align 1
+  %221 = alloca i8, align 1
+  %222 = alloca i8, align 1
+  %223 = alloca i8, align 1
+  %224 = alloca i8, align 1
+  %225 = alloca i8, align 1
+  %226 = alloca i8, align 1
+  %227 = alloca i8, align 1
+  %228 = alloca i8, align 1
+  %229 = alloca i8, align 1
+  %230 = alloca i8, align 1
+  %231 = alloca i8, align 1
+  %232 = alloca i8, align 1
+  %233 = alloca i8, align 1
+  %234 = alloca i8, align 1
+  %235 = alloca i8, align 1
+  %236 = alloca i8, align 1
+  %237 = alloca i8, align 1
+  %238 = alloca i8, align 1
+  %239 = alloca i8, align 1
+  %240 = alloca i8, align 1
+  %241 = alloca i8, align 1
+  %242 = alloca i8, align 1
+  %243 = alloca i8, align 1
+  %244 = alloca i8, align 1
+  %245 = alloca i8, align 1
+  %246 = alloca i8, align 1
+  %247 = alloca i8, align 1
+  %248 = alloca i8, align 1
+  %249 = alloca i8, align 1
+  %250 = alloca i8, align 1
+  %251 = alloca i8, align 1
+  %252 = alloca i8, align 1
+  %253 = alloca i8, align 1
+  %254 = alloca i8, align 1
+  %255 = alloca i8, align 1
+  %256 = alloca i8, align 1
+  %257 = alloca i8, align 1
+  %258 = alloca i8, align 1
+  %259 = alloca i8, align 1
+  %260 = alloca i8, align 1
+  %261 = alloca i8, align 1
+  %262 = alloca i8, align 1
+  %263 = alloca i8, align 1
+  %264 = alloca i8, align 1
+  %265 = alloca i8, align 1
+  %266 = alloca i8, align 1
+  %267 = alloca i8, align 1
+  %268 = alloca i8, align 1
+  %269 = alloca i8, align 1
+  %270 = alloca i8, align 1
+  %271 = alloca i8, align 1
+  %272 = alloca i8, align 1
+  %273 = alloca i8, align 1
+  %274 = alloca i8, align 1
+  %275 = alloca i8, align 1
+  %276 = alloca i8, align 1
+  %277 = alloca i8, align 1
+  %278 = alloca i8, align 1
+  %279 = alloca i8, align 1
+  %280 = alloca i8, align 1
+  %281 = alloca i8, align 1
+  %282 = alloca i8, align 1
+  %283 = alloca i8, align 1
+  %284 = alloca i8, align 1
+  %285 = alloca i8, align 1
+  %286 = alloca i8, align 1
+  %287 = alloca i8, align 1
+  %288 = alloca i8, align 1
+  %289 = alloca i8, align 1
+  %290 = alloca i8, align 1
+  %291 = alloca i8, align 1
+  %292 = alloca i8, align 1
+  %293 = alloca i8, align 1
+  %294 = alloca i8, align 1
+  %295 = alloca i8, align 1
+  %296 = alloca i8, align 1
+  %297 = alloca i8, align 1
+  %298 = alloca i8, align 1
+  %299 = alloca i8, align 1
+  %300 = alloca i8, align 1
+  store volatile i8 1, i8* %1, align 1
+  store volatile i8 1, i8* %2, align 1
+  store volatile i8 1, i8* %3, align 1
+  store volatile i8 1, i8* %4, align 1
+  store volatile i8 1, i8* %5, align 1
+  store volatile i8 1, i8* %6, align 1
+  store volatile i8 1, i8* %7, align 1
+  store volatile i8 1, i8* %8, align 1
+  store volatile i8 1, i8* %9, align 1
+  store volatile i8 1, i8* %10, align 1
+  store volatile i8 1, i8* %11, align 1
+  store volatile i8 1, i8* %12, align 1
+  store volatile i8 1, i8* %13, align 1
+  store volatile i8 1, i8* %14, align 1
+  store volatile i8 1, i8* %15, align 1
+  store volatile i8 1, i8* %16, align 1
+  store volatile i8 1, i8* %17, align 1
+  store volatile i8 1, i8* %18, align 1
+  store volatile i8 1, i8* %19, align 1
+  store volatile i8 1, i8* %20, align 1
+  store volatile i8 1, i8* %21, align 1
+  store volatile i8 1, i8* %22, align 1
+  store volatile i8 1, i8* %23, align 1
+  store volatile i8 1, i8* %24, align 1
+  store volatile i8 1, i8* %25, align 1
+  store volatile i8 1, i8* %26, align 1
+  store volatile i8 1, i8* %27, align 1
+  store volatile i8 1, i8* %28, align 1
+  store volatile i8 1, i8* %29, align 1
+  store volatile i8 1, i8* %30, align 1
+  store volatile i8 1, i8* %31, align 1
+  store volatile i8 1, i8* %32, align 1
+  store volatile i8 1, i8* %33, align 1
+  store volatile i8 1, i8* %34, align 1
+  store volatile i8 1, i8* %35, align 1
+  store volatile i8 1, i8* %36, align 1
+  store volatile i8 1, i8* %37, align 1
+  store volatile i8 1, i8* %38, align 1
+  store volatile i8 1, i8* %39, align 1
+  store volatile i8 1, i8* %40, align 1
+  store volatile i8 1, i8* %41, align 1
+  store volatile i8 1, i8* %42, align 1
+  store volatile i8 1, i8* %43, align 1
+  store volatile i8 1, i8* %44, align 1
+  store volatile i8 1, i8* %45, align 1
+  store volatile i8 1, i8* %46, align 1
+  store volatile i8 1, i8* %47, align 1
+  store volatile i8 1, i8* %48, align 1
+  store volatile i8 1, i8* %49, align 1
+  store volatile i8 1, i8* %50, align 1
+  store volatile i8 1, i8* %51, align 1
+  store volatile i8 1, i8* %52, align 1
+  store volatile i8 1, i8* %53, align 1
+  store volatile i8 1, i8* %54, align 1
+  store volatile i8 1, i8* %55, align 1
+  store volatile i8 1, i8* %56, align 1
+  store volatile i8 1, i8* %57, align 1
+  store volatile i8 1, i8* %58, align 1
+  store volatile i8 1, i8* %59, align 1
+  store volatile i8 1, i8* %60, align 1
+  store volatile i8 1, i8* %61, align 1
+  store volatile i8 1, i8* %62, align 1
+  store volatile i8 1, i8* %63, align 1
+  store volatile i8 1, i8* %64, align 1
+  store volatile i8 1, i8* %65, align 1
+  store volatile i8 1, i8* %66, align 1
+  store volatile i8 1, i8* %67, align 1
+  store volatile i8 1, i8* %68, align 1
+  store volatile i8 1, i8* %69, align 1
+  store volatile i8 1, i8* %70, align 1
+  store volatile i8 1, i8* %71, align 1
+  store volatile i8 1, i8* %72, align 1
+  store volatile i8 1, i8* %73, align 1
+  store volatile i8 1, i8* %74, align 1
+  store volatile i8 1, i8* %75, align 1
+  store volatile i8 1, i8* %76, align 1
+  store volatile i8 1, i8* %77, align 1
+  store volatile i8 1, i8* %78, align 1
+  store volatile i8 1, i8* %79, align 1
+  store volatile i8 1, i8* %80, align 1
+  store volatile i8 1, i8* %81, align 1
+  store volatile i8 1, i8* %82, align 1
+  store volatile i8 1, i8* %83, align 1
+  store volatile i8 1, i8* %84, align 1
+  store volatile i8 1, i8* %85, align 1
+  store volatile i8 1, i8* %86, align 1
+  store volatile i8 1, i8* %87, align 1
+  store volatile i8 1, i8* %88, align 1
+  store volatile i8 1, i8* %89, align 1
+  store volatile i8 1, i8* %90, align 1
+  store volatile i8 1, i8* %91, align 1
+  store volatile i8 1, i8* %92, align 1
+  store volatile i8 1, i8* %93, align 1
+  store volatile i8 1, i8* %94, align 1
+  store volatile i8 1, i8* %95, align 1
+  store volatile i8 1, i8* %96, align 1
+  store volatile i8 1, i8* %97, align 1
+  store volatile i8 1, i8* %98, align 1
+  store volatile i8 1, i8* %99, align 1
+  store volatile i8 1, i8* %100, align 1
+  store volatile i8 1, i8* %101, align 1
+  store volatile i8 1, i8* %102, align 1
+  store volatile i8 1, i8* %103, align 1
+  store volatile i8 1, i8* %104, align 1
+  store volatile i8 1, i8* %105, align 1
+  store volatile i8 1, i8* %106, align 1
+  store volatile i8 1, i8* %107, align 1
+  store volatile i8 1, i8* %108, align 1
+  store volatile i8 1, i8* %109, align 1
+  store volatile i8 1, i8* %110, align 1
+  store volatile i8 1, i8* %111, align 1
+  store volatile i8 1, i8* %112, align 1
+  store volatile i8 1, i8* %113, align 1
+  store volatile i8 1, i8* %114, align 1
+  store volatile i8 1, i8* %115, align 1
+  store volatile i8 1, i8* %116, align 1
+  store volatile i8 1, i8* %117, align 1
+  store volatile i8 1, i8* %118, align 1
+  store volatile i8 1, i8* %119, align 1
+  store volatile i8 1, i8* %120, align 1
+  store volatile i8 1, i8* %121, align 1
+  store volatile i8 1, i8* %122, align 1
+  store volatile i8 1, i8* %123, align 1
+  store volatile i8 1, i8* %124, align 1
+  store volatile i8 1, i8* %125, align 1
+  store volatile i8 1, i8* %126, align 1
+  store volatile i8 1, i8* %127, align 1
+  store volatile i8 1, i8* %128, align 1
+  store volatile i8 1, i8* %129, align 1
+  store volatile i8 1, i8* %130, align 1
+  store volatile i8 1, i8* %131, align 1
+  store volatile i8 1, i8* %132, align 1
+  store volatile i8 1, i8* %133, align 1
+  store volatile i8 1, i8* %134, align 1
+  store volatile i8 1, i8* %135, align 1
+  store volatile i8 1, i8* %136, align 1
+  store volatile i8 1, i8* %137, align 1
+  store volatile i8 1, i8* %138, align 1
+  store volatile i8 1, i8* %139, align 1
+  store volatile i8 1, i8* %140, align 1
+  store volatile i8 1, i8* %141, align 1
+  store volatile i8 1, i8* %142, align 1
+  store volatile i8 1, i8* %143, align 1
+  store volatile i8 1, i8* %144, align 1
+  store volatile i8 1, i8* %145, align 1
+  store volatile i8 1, i8* %146, align 1
+  store volatile i8 1, i8* %147, align 1
+  store volatile i8 1, i8* %148, align 1
+  store volatile i8 1, i8* %149, align 1
+  store volatile i8 1, i8* %150, align 1
+  store volatile i8 1, i8* %151, align 1
+  store volatile i8 1, i8* %152, align 1
+  store volatile i8 1, i8* %153, align 1
+  store volatile i8 1, i8* %154, align 1
+  store volatile i8 1, i8* %155, align 1
+  store volatile i8 1, i8* %156, align 1
+  store volatile i8 1, i8* %157, align 1
+  store volatile i8 1, i8* %158, align 1
+  store volatile i8 1, i8* %159, align 1
+  store volatile i8 1, i8* %160, align 1
+  store volatile i8 1, i8* %161, align 1
+  store volatile i8 1, i8* %162, align 1
+  store volatile i8 1, i8* %163, align 1
+  store volatile i8 1, i8* %164, align 1
+  store volatile i8 1, i8* %165, align 1
+  store volatile i8 1, i8* %166, align 1
+  store volatile i8 1, i8* %167, align 1
+  store volatile i8 1, i8* %168, align 1
+  store volatile i8 1, i8* %169, align 1
+  store volatile i8 1, i8* %170, align 1
+  store volatile i8 1, i8* %171, align 1
+  store volatile i8 1, i8* %172, align 1
+  store volatile i8 1, i8* %173, align 1
+  store volatile i8 1, i8* %174, align 1
+  store volatile i8 1, i8* %175, align 1
+  store volatile i8 1, i8* %176, align 1
+  store volatile i8 1, i8* %177, align 1
+  store volatile i8 1, i8* %178, align 1
+  store volatile i8 1, i8* %179, align 1
+  store volatile i8 1, i8* %180, align 1
+  store volatile i8 1, i8* %181, align 1
+  store volatile i8 1, i8* %182, align 1
+  store volatile i8 1, i8* %183, align 1
+  store volatile i8 1, i8* %184, align 1
+  store volatile i8 1, i8* %185, align 1
+  store volatile i8 1, i8* %186, align 1
+  store volatile i8 1, i8* %187, align 1
+  store volatile i8 1, i8* %188, align 1
+  store volatile i8 1, i8* %189, align 1
+  store volatile i8 1, i8* %190, align 1
+  store volatile i8 1, i8* %191, align 1
+  store volatile i8 1, i8* %192, align 1
+  store volatile i8 1, i8* %193, align 1
+  store volatile i8 1, i8* %194, align 1
+  store volatile i8 1, i8* %195, align 1
+  store volatile i8 1, i8* %196, align 1
+  store volatile i8 1, i8* %197, align 1
+  store volatile i8 1, i8* %198, align 1
+  store volatile i8 1, i8* %199, align 1
+  store volatile i8 1, i8* %200, align 1
+  store volatile i8 1, i8* %201, align 1
+  store volatile i8 1, i8* %202, align 1
+  store volatile i8 1, i8* %203, align 1
+  store volatile i8 1, i8* %204, align 1
+  store volatile i8 1, i8* %205, align 1
+  store volatile i8 1, i8* %206, align 1
+  store volatile i8 1, i8* %207, align 1
+  store volatile i8 1, i8* %208, align 1
+  store volatile i8 1, i8* %209, align 1
+  store volatile i8 1, i8* %210, align 1
+  store volatile i8 1, i8* %211, align 1
+  store volatile i8 1, i8* %212, align 1
+  store volatile i8 1, i8* %213, align 1
+  store volatile i8 1, i8* %214, align 1
+  store volatile i8 1, i8* %215, align 1
+  store volatile i8 1, i8* %216, align 1
+  store volatile i8 1, i8* %217, align 1
+  store volatile i8 1, i8* %218, align 1
+  store volatile i8 1, i8* %219, align 1
+  store volatile i8 1, i8* %220, align 1
+  store volatile i8 1, i8* %221, align 1
+  store volatile i8 1, i8* %222, align 1
+  store volatile i8 1, i8* %223, align 1
+  store volatile i8 1, i8* %224, align 1
+  store volatile i8 1, i8* %225, align 1
+  store volatile i8 1, i8* %226, align 1
+  store volatile i8 1, i8* %227, align 1
+  store volatile i8 1, i8* %228, align 1
+  store volatile i8 1, i8* %229, align 1
+  store volatile i8 1, i8* %230, align 1
+  store volatile i8 1, i8* %231, align 1
+  store volatile i8 1, i8* %232, align 1
+  store volatile i8 1, i8* %233, align 1
+  store volatile i8 1, i8* %234, align 1
+  store volatile i8 1, i8* %235, align 1
+  store volatile i8 1, i8* %236, align 1
+  store volatile i8 1, i8* %237, align 1
+  store volatile i8 1, i8* %238, align 1
+  store volatile i8 1, i8* %239, align 1
+  store volatile i8 1, i8* %240, align 1
+  store volatile i8 1, i8* %241, align 1
+  store volatile i8 1, i8* %242, align 1
+  store volatile i8 1, i8* %243, align 1
+  store volatile i8 1, i8* %244, align 1
+  store volatile i8 1, i8* %245, align 1
+  store volatile i8 1, i8* %246, align 1
+  store volatile i8 1, i8* %247, align 1
+  store volatile i8 1, i8* %248, align 1
+  store volatile i8 1, i8* %249, align 1
+  store volatile i8 1, i8* %250, align 1
+  store volatile i8 1, i8* %251, align 1
+  store volatile i8 1, i8* %252, align 1
+  store volatile i8 1, i8* %253, align 1
+  store volatile i8 1, i8* %254, align 1
+  store volatile i8 1, i8* %255, align 1
+  store volatile i8 1, i8* %256, align 1
+  store volatile i8 1, i8* %257, align 1
+  store volatile i8 1, i8* %258, align 1
+  store volatile i8 1, i8* %259, align 1
+  store volatile i8 1, i8* %260, align 1
+  store volatile i8 1, i8* %261, align 1
+  store volatile i8 1, i8* %262, align 1
+  store volatile i8 1, i8* %263, align 1
+  store volatile i8 1, i8* %264, align 1
+  store volatile i8 1, i8* %265, align 1
+  store volatile i8 1, i8* %266, align 1
+  store volatile i8 1, i8* %267, align 1
+  store volatile i8 1, i8* %268, align 1
+  store volatile i8 1, i8* %269, align 1
+  store volatile i8 1, i8* %270, align 1
+  store volatile i8 1, i8* %271, align 1
+  store volatile i8 1, i8* %272, align 1
+  store volatile i8 1, i8* %273, align 1
+  store volatile i8 1, i8* %274, align 1
+  store volatile i8 1, i8* %275, align 1
+  store volatile i8 1, i8* %276, align 1
+  store volatile i8 1, i8* %277, align 1
+  store volatile i8 1, i8* %278, align 1
+  store volatile i8 1, i8* %279, align 1
+  store volatile i8 1, i8* %280, align 1
+  store volatile i8 1, i8* %281, align 1
+  store volatile i8 1, i8* %282, align 1
+  store volatile i8 1, i8* %283, align 1
+  store volatile i8 1, i8* %284, align 1
+  store volatile i8 1, i8* %285, align 1
+  store volatile i8 1, i8* %286, align 1
+  store volatile i8 1, i8* %287, align 1
+  store volatile i8 1, i8* %288, align 1
+  store volatile i8 1, i8* %289, align 1
+  store volatile i8 1, i8* %290, align 1
+  store volatile i8 1, i8* %291, align 1
+  store volatile i8 1, i8* %292, align 1
+  store volatile i8 1, i8* %293, align 1
+  store volatile i8 1, i8* %294, align 1
+  store volatile i8 1, i8* %295, align 1
+  store volatile i8 1, i8* %296, align 1
+  store volatile i8 1, i8* %297, align 1
+  store volatile i8 1, i8* %298, align 1
+  store volatile i8 1, i8* %299, align 1
+  store volatile i8 1, i8* %300, align 1
+  %301 = load volatile i8, i8* %1, align 1
+  call void @putchar(i8 noundef zeroext %301)
+  ret void
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
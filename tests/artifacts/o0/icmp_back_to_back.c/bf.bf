@@ -0,0 +1,777 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 5942 ops ~297240 steps
+frame widths:
+  main: 29 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l29 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #alloc_%6
+        #alloc_%7
+        #alloc_%8
+        #%9_=_load_volatile_i8*_%1__align_1(mult)
+        #%12_=_zext_i8_%11_to_i32(mult)
+        #%13_=_load_i8*_%4__align_1(mult)
+        #%14_=_zext_i8_%13_to_i32(mult)
+        #%15_=_icmp_slt_i32_%12__i32_%14(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            %6 = alloca i8_ align 1
+            %7 = alloca i8_ align 1
+            %8 = alloca i8_ align 1
+            store volatile i8 97_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<+
+            scratch must be clean
+            >>>>>>>>>>>>>[
+            ]
+            store volatile i8 98_ i8* %2_ align 1
+            
+            <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>]
+            scratch must be clean
+            
+            
+            %9 = load volatile i8* %1_ align 1
+            
+            <<<<<<[-]
+            known to be 97
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            scratch must be clean
+            >>>>>[
+            ]
+            store i8 %9_ i8* %3_ align 1
+            
+            <<<<<<<<<<<[-]
+            >>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>]
+            <<<<<<<<<<<+
+            scratch must be clean
+            >>>>>>>>>>>[
+            ]
+            %10 = load volatile i8* %2_ align 1
+            
+            <<<<<[-]
+            known to be 98
+            >>>>>+++++++
+            [
+                -
+                <<<<<++++++++++++++
+            >>>>>]
+            scratch must be clean
+            
+            
+            store i8 %10_ i8* %4_ align 1
+            
+            <<<<<<<<<<[-]
+            >>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<++++++++++++++
+            >>>>>>>>>>]
+            scratch must be clean
+            
+            
+            %11 = load i8* %3_ align 1
+            
+            <<<<<[-]
+            known to be 97
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            scratch must be clean
+            >>>>>[
+            ]
+            %12 = zext i8 %11 to i32
+            
+            <<<<[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            scratch must be clean
+            >>>>[
+            ]
+            %13 = load i8* %4_ align 1
+            
+            <<<[-]
+            known to be 98
+            >>>+++++++
+            [
+                -
+                <<<++++++++++++++
+            >>>]
+            scratch must be clean
+            
+            
+            %14 = zext i8 %13 to i32
+            
+            <<[-]
+            known to be 98
+            >>+++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            scratch must be clean
+            
+            
+            %15 = icmp slt i32 %12_ i32 %14
+            
+            <[-]
+            known to be 1
+            +
+            %16 = zext i1 %15 to i32
+            
+            <<<[-]
+            known to be 1
+            +
+            %17 = trunc i32 %16 to i8
+            
+            >>[-]
+            known to be 1
+            +
+            store i8 %17_ i8* %5_ align 1
+            
+            <<<<<<<[-]
+            +
+            %19 = zext i8 %11 to i32
+            
+            >>>>>[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            scratch must be clean
+            >>>>[
+            ]
+            %21 = zext i8 %13 to i32
+            
+            <<[-]
+            known to be 98
+            >>+++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            scratch must be clean
+            
+            
+            %22 = icmp sgt i32 %19_ i32 %21
+            
+            <[-]
+            known to be 0
+            
+            %23 = zext i1 %22 to i32
+            
+            <<<[-]
+            known to be 0
+            
+            %24 = trunc i32 %23 to i8
+            
+            >>[-]
+            known to be 0
+            
+            store i8 %24_ i8* %6_ align 1
+            
+            <<<<<<[-]
+            
+            %26 = zext i8 %11 to i32
+            
+            >>>>[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            scratch must be clean
+            >>>>[
+            ]
+            %28 = zext i8 %13 to i32
+            
+            <<[-]
+            known to be 98
+            >>+++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            scratch must be clean
+            
+            
+            %29 = icmp eq i32 %26_ i32 %28
+            
+            <[-]
+            known to be 0
+            
+            %30 = zext i1 %29 to i32
+            
+            <<<[-]
+            known to be 0
+            
+            %31 = trunc i32 %30 to i8
+            
+            >>[-]
+            known to be 0
+            
+            store i8 %31_ i8* %7_ align 1
+            
+            <<<<<[-]
+            
+            %33 = zext i8 %13 to i32
+            
+            >>>[-]
+            known to be 98
+            >>>>+++++++
+            [
+                -
+                <<<<++++++++++++++
+            >>>>]
+            scratch must be clean
+            
+            
+            %35 = zext i8 %11 to i32
+            
+            <<<[-]
+            known to be 97
+            >>>++++++++
+            [
+                -
+                <<<++++++++++++
+            >>>]
+            <<<+
+            scratch must be clean
+            >>>[
+            ]
+            %36 = icmp sle i32 %33_ i32 %35
+            
+            <<<<<[-]
+            known to be 0
+            
+            %37 = zext i1 %36 to i32
+            
+            >[-]
+            known to be 0
+            
+            %38 = trunc i32 %37 to i8
+            
+            <[-]
+            known to be 0
+            
+            store i8 %38_ i8* %8_ align 1
+            
+            <[-]
+            
+            %39 = load i8* %5_ align 1
+            
+            >[-]
+            known to be 1
+            +
+            %40 = zext i8 %39 to i32
+            
+            >[-]
+            known to be 1
+            +
+            %41 = add i32 48_ i32 %40
+            
+            >[-]
+            known to be 49
+            >>>+++++++
+            [
+                -
+                <<<+++++++
+            >>>]
+            scratch must be clean
+            
+            
+            %42 = trunc i32 %41 to i8
+            
+            <<<<[-]
+            known to be 49
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++
+            >>>>]
+            scratch must be clean
+            
+            
+            call @putchar(i8 %42)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_49
+            >>+++++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<.
+            [-]
+            %43 = load i8* %6_ align 1
+            
+            <<<<[-]
+            dup9/13/17 <<<<[->>>>+>>>>+<<<<<<<<]
+            mov17/9 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            scratch must be clean
+            
+            
+            %44 = zext i8 %43 to i32
+            
+            <<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %45 = add i32 48_ i32 %44
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup14/20/19 <<<<[->>>>>>+<+<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            mov17/13 <<<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/22/24 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov24/13 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %46 = trunc i32 %45 to i8
+            
+            <<<<<<<<<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            call @putchar(i8 %46)
+            
+            putchar intrinsic
+            dup14/17/18 <<<<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            >.
+            [-]
+            %48 = zext i8 %39 to i32
+            
+            <<<<<[-]
+            known to be 1
+            +
+            %49 = load i8* %7_ align 1
+            
+            <[-]
+            dup10/12/17 <<[->>+>>>>>+<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            scratch must be clean
+            
+            
+            %50 = zext i8 %49 to i32
+            
+            <<<[-]
+            dup12/17/18 <<[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %51 = add i32 %48_ i32 %50
+            
+            <<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>>#constop_1
+            +
+            dup14/18/19 <<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov17/12 <[-<<<<<+>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/21/23 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov23/12 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %52 = mul i32 %51_ i32 2
+            
+            <<<<<<<<<<[-]
+            dup12/17/18 <[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov18/23 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov20/21 [->+<]
+                        <<<<<<<+
+                        >>>>>>>>>-
+                    <<]
+                    mov21/20 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<[-]
+                        >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <[-]
+            >>[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %53 = add i32 48_ i32 %52
+            
+            <<<<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup13/19/20 <<<<<[->>>>>>+>+<<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            mov17/12 <<[-<<<<<+>>>>>]
+            >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/26/28 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov28/12 >>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov25/26 [->+<]
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>-
+                <<]
+                mov26/25 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            scratch must be clean
+            <<<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >>>>>[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %54 = trunc i32 %53 to i8
+            
+            <<<<<<<<<<<<<<<[-]
+            dup12/17/18 <[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            mov18/13 >[-<<<<<+>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            call @putchar(i8 %54)
+            
+            putchar intrinsic
+            dup13/17/18 <<<<<[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %55 = load i8* %8_ align 1
+            
+            <<<<<<[-]
+            dup11/12/17 <[->+>>>>>+<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            scratch must be clean
+            
+            
+            %56 = zext i8 %55 to i32
+            
+            <<<<[-]
+            dup12/17/18 <[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            mov18/13 >[-<<<<<+>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %57 = add i32 48_ i32 %56
+            
+            <<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup13/19/20 <<<<<[->>>>>>+>+<<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            mov17/12 <<[-<<<<<+>>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/22/24 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov24/12 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %58 = trunc i32 %57 to i8
+            
+            <<<<<<<<<<<[-]
+            dup12/17/18 <[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            mov18/13 >[-<<<<<+>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            call @putchar(i8 %58)
+            
+            putchar intrinsic
+            dup13/17/18 <<<<<[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -1,871 +1,145 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 19222 ops ~160202 steps
+frame widths:
+  main: 57 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
+<<[
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<-
             #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l95 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%9
-        >#B:%call_term_for_3
-        >#B:%10
-        >#B:%13
-        >#B:%call_term_for_6
-        >#B:%14
-        >#B:%17
-        >#B:%call_term_for_9
-        >#B:%18
-        >#B:%21
-        >#B:%call_term_for_12
-        >#B:%22
-        >#B:%25
-        >#B:%call_term_for_15
-        >#B:%26
-        >#B:%29
-        >#B:%call_term_for_18
-        >#B:%30
-        >#B:%call_term_for_20
-        >#B:%31
-        >#B:%34
-        >#B:%35
-        >#B:%38
-        >#B:%41
-        >#B:%call_term_for_26
-        >#B:%42
-        >#B:%45
-        >#B:%call_term_for_29
-        >#B:%46
-        >#B:%49
-        >#B:%call_term_for_32
-        >#B:%50
-        >#B:%53
-        >#B:%call_term_for_35
-        >#B:%54
-        >#B:%57
-        >#B:%call_term_for_38
-        >#B:%58
-        >#B:%61
-        >#B:%call_term_for_41
-        >#B:%62
-        >#B:%call_term_for_43
-        >#B:%63
-        >#B:%66
-        >#alloc_%1
-        >#alloc_%2
-        >#%4_=_load_i32*_%1__align_4(mult)
-        >#%5_=_icmp_slt_i32_%4__i32_10(mult)
-        >#%7_=_load_i32*_%1__align_4(mult)
-        >#%8_=_icmp_slt_i32_%7__i32_5(mult)
-        >#%11_=_load_i32*_%1__align_4(mult)
-        >#%12_=_icmp_ne_i32_%11__i32_5(mult)
-        >#%15_=_load_i32*_%1__align_4(mult)
-        >#%16_=_icmp_eq_i32_%15__i32_5(mult)
-        >#%19_=_load_i32*_%1__align_4(mult)
-        >#%20_=_icmp_sgt_i32_%19__i32_5(mult)
-        >#%23_=_load_i32*_%1__align_4(mult)
-        >#%24_=_icmp_sle_i32_%23__i32_5(mult)
-        >#%27_=_load_i32*_%1__align_4(mult)
-        >#%28_=_icmp_sge_i32_%27__i32_5(mult)
-        >#%32_=_load_i32*_%1__align_4(mult)
-        >#%33_=_add_i32_%32__i32_1(mult)
-        >#%36_=_load_i32*_%2__align_4(mult)
-        >#%37_=_icmp_slt_i32_%36__i32_10(mult)
-        >#%39_=_load_i32*_%2__align_4(mult)
-        >#%40_=_icmp_slt_i32_5__i32_%39(mult)
-        >#%43_=_load_i32*_%2__align_4(mult)
-        >#%44_=_icmp_ne_i32_5__i32_%43(mult)
-        >#%47_=_load_i32*_%2__align_4(mult)
-        >#%48_=_icmp_eq_i32_5__i32_%47(mult)
-        >#%51_=_load_i32*_%2__align_4(mult)
-        >#%52_=_icmp_sgt_i32_5__i32_%51(mult)
-        >#%55_=_load_i32*_%2__align_4(mult)
-        >#%56_=_icmp_sle_i32_5__i32_%55(mult)
-        >#%59_=_load_i32*_%2__align_4(mult)
-        >#%60_=_icmp_sge_i32_5__i32_%59(mult)
-        >#%64_=_load_i32*_%2__align_4(mult)
-        >#%65_=_add_i32_%64__i32_1(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%0
+            >-
+            #dead_fn_pad/main
+            >>-
+            l57 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%6
+        #B:%9
+        #B:%10
+        #B:%13
+        #B:%14
+        #B:%17
+        #B:%18
+        #B:%21
+        #B:%22
+        #B:%25
+        #B:%26
+        #B:%29
+        #B:%30
+        #B:%34
+        #B:%35
+        #B:%38
+        #B:%41
+        #B:%42
+        #B:%45
+        #B:%46
+        #B:%49
+        #B:%50
+        #B:%53
+        #B:%54
+        #B:%57
+        #B:%58
+        #B:%61
+        #B:%62
+        #B:%66
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i32*_%1__align_4(mult)
+        #%5_=_icmp_slt_i32_%4__i32_10(mult)
+        <#B:%0
         [
             -
             copy up args
             %1 = alloca i32_ align 4
             %2 = alloca i32_ align 4
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
         <<]
         >>#B:%3
         [
             -
             %4 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/54/87 <<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov87/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %5 = icmp slt i32 %4_ i32 10
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup54/86/88 <[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/54 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/39/38 <[->>>+<+<<]
+            mov39/36 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >#constop_10
             ++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov87/89 <<<<[->>+<<]
-            >>>>+
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov38/43 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
                 ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
                 r1 >
                 [
                     l1 <
@@ -873,47 +147,138 @@ runtime init:
                 l1 <
             <]
             >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov48/51 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
             br i1 %5_ label %6_ label %34
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
                 >>+
-                #B:main/6_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                #B:main/%6_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/34_false
-            <<<<<<<<<<<<<<<<<<<<<<<]
+                >>>>>>>>>>>>>>>+
+                #B:main/%34_false
+            <<<<<<<<<<<<<<<]
         >]
         >#B:%6
         [
             -
             %7 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/56/86 <<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/40 <<[->>+>>>>+<<<<<<]
+            mov40/34 >>>>>>[-<<<<<<+>>>>>>]
             %8 = icmp slt i32 %7_ i32 5
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup56/86/87 <[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/56 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/92 <<<<<<[->>>>+<<<<]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov39/43 <<<[->>>>+<<<<]
             >>>>>>+
-            <<<<<<<[
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov48/54 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<<<<<[
                 -
                 
-                >>>>>[
+                >>>>>>>>>>>[
                     -
                     
                     r1 >
@@ -923,64 +288,68 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<<<]
-            >>>>>>>-
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
             br i1 %8_ label %9_ label %10
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
                 >>>+
-                #B:main/9_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                #B:main/%9_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>+
-                #B:main/10_false
-            <<<<<]
+                >>>>+
+                #B:main/%10_false
+            <<<<]
         >>]
         >#B:%9
         [
             -
             call @putchar(i8 108)
-            enable next block when we return
-            >#caller/%call_term_for_3
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
             br label %10
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%10
         [
             -
             %11 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/58/86 <<<<<<[->>>>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %12 = icmp ne i32 %11_ i32 5
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup58/86/87 <[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/58 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov87/90 <<<<<[->>>+<<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov39/42 <[->>>+<<<]
             >>>>>+
             <<<<[
                 -
@@ -999,64 +368,68 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<+
+            >>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<+
+            >>>>]
             br i1 %12_ label %13_ label %14
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>+
-                #B:main/13_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%13_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>+
-                #B:main/14_false
-            <<<<<<<<]
-        >>>>>]
+                >>>>>>+
+                #B:main/%14_false
+            <<<<<<]
+        >>>>]
         >#B:%13
         [
             -
             call @putchar(i8 110)
-            enable next block when we return
-            >#caller/%call_term_for_6
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_110
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
             br label %14
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%14
         [
             -
             %15 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/60/86 <<<<<<<<[->>>>>>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %16 = icmp eq i32 %15_ i32 5
-            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup60/86/87 <[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/60 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/90 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/42 [->>+<<]
             >>>>+
             <<<<<[
                 -
@@ -1073,76 +446,111 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            <<<<<<<+
+            >>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<[-]
+            >>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<[-]
+            >>>>]
             br i1 %16_ label %17_ label %18
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>+
-                #B:main/17_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%17_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>+
-                #B:main/18_false
-            <<<<<<<<<<<]
-        >>>>>>>>]
+                >>>>>>>>+
+                #B:main/%18_false
+            <<<<<<<<]
+        >>>>>>]
         >#B:%17
         [
             -
             call @putchar(i8 101)
-            enable next block when we return
-            >#caller/%call_term_for_9
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_101
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_9
-        [
-            -
             br label %18
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%18
         [
             -
             %19 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/62/86 <<<<<<<<<<[->>>>>>>>>>+>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %20 = icmp sgt i32 %19_ i32 5
-            <<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup62/86/87 <[->>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/62 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov87/89 <<<<[->>+<<]
-            >>>>+
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov39/43 <<<[->>>>+<<<<]
+            >>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
                 ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
                 r1 >
                 [
                     l1 <
@@ -1150,67 +558,158 @@ runtime init:
                 l1 <
             <]
             >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov43/51 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
             br i1 %20_ label %21_ label %22
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>+
-                #B:main/21_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%21_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>+
-                #B:main/22_false
-            <<<<<<<<<<<<<<]
-        >>>>>>>>>>>]
+                >>>>>>>>>>+
+                #B:main/%22_false
+            <<<<<<<<<<]
+        >>>>>>>>]
         >#B:%21
         [
             -
             call @putchar(i8 103)
-            enable next block when we return
-            >#caller/%call_term_for_12
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_103
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_103
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_12
-        [
-            -
             br label %22
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%22
         [
             -
             %23 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/64/86 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %24 = icmp sle i32 %23_ i32 5
-            <<<<<<<<<<<<<<<<<<<<<[-]
-            dup64/86/87 <[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/64 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov87/89 <<<<[->>+<<]
-            >>>>+
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov39/43 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
             <<<[
                 -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov43/51 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
                 
-                >[
+                >>>[
                     -
                     
                     r1 >
@@ -1220,71 +719,134 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>]
             br i1 %24_ label %25_ label %26
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>+
-                #B:main/25_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%25_true
+            >>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>+
-                #B:main/26_false
-            <<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>]
+                >>>>>>>>>>>>+
+                #B:main/%26_false
+            <<<<<<<<<<<<]
+        >>>>>>>>>>]
         >#B:%25
         [
             -
             call @putchar(i8 76)
-            enable next block when we return
-            >#caller/%call_term_for_15
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_76
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#constop_76
+            >+++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_15
-        [
-            -
             br label %26
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%26
         [
             -
             %27 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/66/86 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>>[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %28 = icmp sge i32 %27_ i32 5
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup66/86/87 <[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
-            mov86/66 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/89 <<<[->+<]
-            >>>+
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov39/43 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
             <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
                 -
-                
+                <+
                 >>[
                     -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov48/51 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
                     
                     r1 >
                 ]
@@ -1293,130 +855,208 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
-            <<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>]
             br i1 %28_ label %29_ label %30
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>+
-                #B:main/29_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>+
+                #B:main/%29_true
+            >>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>+
-                #B:main/30_false
-            <<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>+
+                #B:main/%30_false
+            <<<<<<<<<<<<<<]
+        >>>>>>>>>>>>]
         >#B:%29
         [
             -
             call @putchar(i8 71)
-            enable next block when we return
-            >#caller/%call_term_for_18
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_71
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>#constop_71
+            >+++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_18
-        [
-            -
             br label %30
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%30
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_20
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_20
-        [
-            -
-            br label %31
-            >+
-        <]
-        >#B:%31
-        [
-            -
             %32 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup52/68/86 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup34/36/38 <<[->>+>>+<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
             %33 = add i32 %32_ i32 1
-            <<<<<<<<<<<<<<<<<[-]
-            dup68/86/87 <[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov86/68 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov87/69 <[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            >[
+            mov39/37 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup37/42/44 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov44/37 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
                 -
-                <<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>]
+                >>>+
+                <<[
+                    -
+                    mov41/42 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov42/41 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %33_ i32* %1_ align 4
-            dup69/86/87 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov86/69 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov87/52 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup37/38/39 <<<<[->+>+<<]
+            mov38/37 >[-<+>]
+            <<<<[-]
+            mov39/34 >>>>>[-<<<<<+>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        >>>>>>>>>>>>>]
         >#B:%34
         [
             -
             store i32 0_ i32* %2_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>>>>>>>>>>>>>>>[-]
+            
             br label %35
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<<<<<<<<<<+
         <]
         >#B:%35
         [
             -
             %36 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/70/86 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %37 = icmp slt i32 %36_ i32 10
-            <<<<<<<<<<<<<<<[-]
-            dup70/86/87 <[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
-            mov86/70 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            <[-]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/89 <<<[->+<]
-            >>>+
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov39/43 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
             <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
                 -
-                
+                <+
                 >>[
                     -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov48/51 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
                     
                     r1 >
                 ]
@@ -1425,49 +1065,112 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
             br i1 %37_ label %38_ label %66
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/38_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/66_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>+
+                #B:main/%38_true
+            >>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%66_false
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>]
         >#B:%38
         [
             -
             %39 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/72/86 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>[-]
+            dup35/36/40 <[->+>>>>+<<<<<]
+            mov40/35 >>>>>[-<<<<<+>>>>>]
             %40 = icmp slt i32 5_ i32 %39
-            <<<<<<<<<<<<<[-]
+            
+            <<<[-]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>#constop_5
+            >#constop_5
             +++++
-            dup72/87/88 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov87/72 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            dup36/39/40 <<[->>>+>+<<<<]
+            mov39/36 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov38/43 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
             >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/89 <<<[->+<]
-            >>>+
-            <<<<<[
+            #subnu_tmp0
+            #subnu_tmp1
+            mov48/51 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
                 -
                 
-                >>>[
+                >>>>>>>>[
                     -
                     
                     r1 >
@@ -1477,64 +1180,68 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<]
-            >>>>>-
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
             br i1 %40_ label %41_ label %42
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/41_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>+
+                #B:main/%41_true
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/42_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>>>>>>+
+                #B:main/%42_false
+            <<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>]
         >#B:%41
         [
             -
             call @putchar(i8 108)
-            enable next block when we return
-            >#caller/%call_term_for_26
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_26
-        [
-            -
             br label %42
-            >+
+            
+            <<<<<<<<<<<<<<<<<<+
         <]
         >#B:%42
         [
             -
             %43 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/74/86 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %44 = icmp ne i32 5_ i32 %43
-            <<<<<<<<<<<[-]
+            
+            <[-]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>#constop_5
+            >#constop_5
             +++++
-            dup74/87/88 <<<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov87/74 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov86/90 <<<<<<[->>>>+<<<<]
+            dup36/39/40 <<[->>>+>+<<<<]
+            mov39/36 >>>[-<<<+>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov38/42 <[->>>>+<<<<]
             >>>>>>+
             <<<<[
                 -
@@ -1553,64 +1260,68 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>]
+                <<<<<+
+            >>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
+                <<<<+
+            >>>>]
             br i1 %44_ label %45_ label %46
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup37/39/38 <<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/45_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/46_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>+
+                #B:main/%45_true
+            >>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%46_false
+            <<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>]
         >#B:%45
         [
             -
             call @putchar(i8 110)
-            enable next block when we return
-            >#caller/%call_term_for_29
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_110
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_29
-        [
-            -
             br label %46
-            >+
+            
+            <<<<<<<<<<<<<<<<+
         <]
         >#B:%46
         [
             -
             %47 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/76/86 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %48 = icmp eq i32 5_ i32 %47
-            <<<<<<<<<[-]
+            
+            <[-]
             op_to_reg storing const value in temp address
-            >>>>>>>>>#constop_5
+            >#constop_5
             +++++
-            dup76/87/88 <<<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov87/76 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/90 <<<<[->>+<<]
+            dup36/39/40 <<[->>>+>+<<<<]
+            mov39/36 >>>[-<<<+>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/42 [->>+<<]
             >>>>+
             <<<<<<[
                 -
@@ -1627,76 +1338,111 @@ runtime init:
                 l1 <
             <<<<]
             >>>>>>-
-            <<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>[
+            <<<<<<<+
+            >>>>>[
                 [-]
-                <<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>]
+                <<<<<[-]
+            >>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<[-]
-            >>>>>>>>>>>>]
+                <<<<[-]
+            >>>>]
             br i1 %48_ label %49_ label %50
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/49_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/50_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup37/39/38 <<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%49_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%50_false
+            <<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>>]
         >#B:%49
         [
             -
             call @putchar(i8 101)
-            enable next block when we return
-            >#caller/%call_term_for_32
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>#constop_101
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_32
-        [
-            -
             br label %50
-            >+
+            
+            <<<<<<<<<<<<<<+
         <]
         >#B:%50
         [
             -
             %51 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/78/86 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %52 = icmp sgt i32 5_ i32 %51
-            <<<<<<<[-]
+            
+            <[-]
             op_to_reg storing const value in temp address
-            >>>>>>>#constop_5
+            >#constop_5
             +++++
-            dup78/87/88 <<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov87/78 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov86/89 <<<<<[->>>+<<<]
-            >>>>>+
+            dup36/39/40 <<[->>>+>+<<<<]
+            mov39/36 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov38/43 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
                 ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
                 r1 >
                 [
                     l1 <
@@ -1704,71 +1450,134 @@ runtime init:
                 l1 <
             <]
             >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov43/51 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
             <<[
                 [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
             br i1 %52_ label %53_ label %54
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/53_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/54_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%53_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%54_false
+            <<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>>>>]
         >#B:%53
         [
             -
             call @putchar(i8 103)
-            enable next block when we return
-            >#caller/%call_term_for_35
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_103
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>#constop_103
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_35
-        [
-            -
             br label %54
-            >+
+            
+            <<<<<<<<<<<<+
         <]
         >#B:%54
         [
             -
             %55 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/80/86 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %56 = icmp sle i32 5_ i32 %55
-            <<<<<[-]
+            
+            <[-]
             op_to_reg storing const value in temp address
-            >>>>>#constop_5
+            >#constop_5
             +++++
-            dup80/87/88 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov87/80 >>>>>>>[-<<<<<<<+>>>>>>>]
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov86/89 <<<<<[->>>+<<<]
-            >>>>>+
+            dup36/39/40 <<[->>>+>+<<<<]
+            mov39/36 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov38/43 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
                 ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
                 r1 >
                 [
                     l1 <
@@ -1776,68 +1585,159 @@ runtime init:
                 l1 <
             <]
             >>>-
-            <<<<<<<<<<+
-            >>>>>>>>[
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov43/51 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
                 [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
+                <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>]
             br i1 %56_ label %57_ label %58
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/57_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/58_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%57_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%58_false
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>>>>>>]
         >#B:%57
         [
             -
             call @putchar(i8 76)
-            enable next block when we return
-            >#caller/%call_term_for_38
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_76
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>#constop_76
+            >+++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_38
-        [
-            -
             br label %58
-            >+
+            
+            <<<<<<<<<<+
         <]
         >#B:%58
         [
             -
             %59 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/82/86 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %60 = icmp sge i32 5_ i32 %59
-            <<<[-]
+            
+            <[-]
             op_to_reg storing const value in temp address
-            >>>#constop_5
+            >#constop_5
             +++++
-            dup82/87/88 <<<<[->>>>>+>+<<<<<<]
-            mov87/82 >>>>>[-<<<<<+>>>>>]
+            dup36/39/40 <<[->>>+>+<<<<]
+            mov39/36 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov38/43 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov40/48 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
             >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov88/89 <<<[->+<]
-            >>>+
-            <<<<<[
+            #subnu_tmp0
+            #subnu_tmp1
+            mov48/51 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
                 -
                 
-                >>>[
+                >>>>>>>>[
                     -
                     
                     r1 >
@@ -1847,140 +1747,130 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<+
-            >>>>>>[
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
                 [-]
-                <<<<<<[-]
-            >>>>>>]
+                <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>]
             br i1 %60_ label %61_ label %62
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/61_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/62_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup37/39/38 <<<<<<<<<<<<<<[->>+<+<]
+            mov39/37 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%61_true
+            >>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%62_false
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>>>>>>>>]
         >#B:%61
         [
             -
             call @putchar(i8 71)
-            enable next block when we return
-            >#caller/%call_term_for_41
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_71
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>#constop_71
+            >+++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_41
-        [
-            -
             br label %62
-            >+
+            
+            <<<<<<<<+
         <]
         >#B:%62
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_43
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_43
-        [
-            -
-            br label %63
-            >+
-        <]
-        >#B:%63
-        [
-            -
             %64 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup53/84/86 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov86/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup35/36/38 <[->+>>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             %65 = add i32 %64_ i32 1
+            
             <[-]
-            dup84/86/87 <[->>+>+<<<]
-            mov86/84 >>[-<<+>>]
+            dup36/38/39 <[->>+>+<<<]
+            mov38/36 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov87/85 <[-<<+>>]
-            >[
+            mov39/37 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup37/42/44 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
                 -
-                <<<+
-            >>>]
+                <-
+            >]
+            mov44/37 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov41/42 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov42/41 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %65_ i32* %2_ align 4
-            dup85/86/87 <<<[->+>+<<]
-            mov86/85 >[-<+>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov87/53 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup37/38/39 <<<<[->+>+<<]
+            mov38/37 >[-<+>]
+            <<<[-]
+            mov39/35 >>>>[-<<<<+>>>>]
             br label %35
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<<<<<<<<<<<<<<<<<<+
+        >>>>>>>>>>>>>]
         >#B:%66
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-<<<]
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+<]
@@ -0,0 +1,481 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  check (depth 1)
+  main calls check (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  check: 1848 ops ~31255 steps
+  main: 972 ops ~3352 steps
+frame widths:
+  check: 24 cells
+  main: 13 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#check
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/check
+            >>>-
+            l24 <<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_check
+        #F:check
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%7
+        #B:%11
+        #B:%12
+        #alloc_%2
+        #%3_=_load_i8*_%2__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/14 <<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            store i8 %0_ i8* %2_ align 1
+            
+            dup12/13/14 >>>>>>>>>>>>[->+>+<<]
+            mov13/12 >[-<+>]
+            <<<<[-]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            %3 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup9/10/13 <[->+>>>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            %4 = zext i8 %3 to i32
+            
+            <<[-]
+            dup10/13/15 <[->>>+>>+<<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov15/11 >>[-<<<<+>>>>]
+            %5 = icmp eq i32 %4_ i32 3
+            
+            <<<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>>#constop_3
+            +++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/17 [->+<]
+            >>>+
+            <<<<<[
+                -
+                >+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            <<[
+                [-]
+                <<<<<[-]
+            >>>>>]
+            br i1 %5_ label %6_ label %7
+            
+            dup10/14/13 <<<<<[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<+
+            >>>>>>>>>[
+                -
+                <<<<<<<<<-
+                >+
+                #B:check/%6_true
+            >>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>+
+                #B:check/%7_false
+            <<]
+        <]
+        >>#B:%6
+        [
+            -
+            call @putchar(i8 61)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_61
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %7
+            
+            <<<<<<<<<+
+        <]
+        >#B:%7
+        [
+            -
+            %8 = load i8* %2_ align 1
+            
+            >>>>[-]
+            dup9/10/13 <[->+>>>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            %9 = zext i8 %8 to i32
+            
+            <<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/11 >[-<<<+>>>]
+            %10 = icmp ne i32 %9_ i32 0
+            
+            <<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            >[
+                [-]
+                <<<<+
+            >>>>]
+            br i1 %10_ label %11_ label %12
+            
+            dup10/14/13 <<<<[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<+
+            >>>>>>>>>[
+                -
+                <<<<<<<<<-
+                >>>+
+                #B:check/%11_true
+            >>>>>>]
+            <<<<<<<<<[
+                -
+                >>>>+
+                #B:check/%12_false
+            <<<<]
+        >>]
+        >#B:%11
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %12
+            
+            <<<<<<<+
+        <]
+        >#B:%12
+        [
+            -
+            %13 = load i8* %2_ align 1
+            
+            >>[-]
+            dup9/10/13 <[->+>>>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            %14 = zext i8 %13 to i32
+            
+            <<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/11 >[-<<<+>>>]
+            %15 = add i32 48_ i32 %14
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup11/15/16 <<<[->>>>+>+<<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            mov13/10 <<[-<<<+>>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/21/23 <<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov23/10 >>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<[
+                -
+                >>>>>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<<<<<+
+                    >>>>>>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<[-]
+                    >>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<]
+            >>>>[-]
+            %16 = trunc i32 %15 to i8
+            
+            <<<<<<<<<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/11 >[-<<<+>>>]
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            dup11/13/14 <<<[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >>]
+    <<<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l13 <<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:check
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            store volatile i8 3_ i8* %1_ align 1
+            
+            >>>>[-]
+            +++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 3
+            +++
+            call @check(i8 %2)
+            
+            enable next block when we return
+            <<<<#caller/%call_term_for_0
+            +
+            stack_width 13
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_3
+            +++
+            mov11/18 [->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r13 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_check
+            +
+            >#check
+            +
+            >>#check/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            store volatile i8 5_ i8* %1_ align 1
+            
+            >>>[-]
+            +++++
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 5
+            +++++
+            call @check(i8 %3)
+            
+            enable next block when we return
+            <<<#caller/%call_term_for_1
+            +
+            stack_width 13
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_5
+            +++++
+            mov12/18 [->>>>>>+<<<<<<]
+            give callee a stack pointer
+            >>>>>>>#stack_ptr
+            ++++++++++++++++++++
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r13 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_check
+            +
+            >#check
+            +
+            >>#check/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            store volatile i8 0_ i8* %1_ align 1
+            
+            >>[-]
+            
+            %4 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 0
+            
+            call @check(i8 %4)
+            
+            enable next block when we return
+            <<#caller/%call_term_for_2
+            +
+            stack_width 13
+            ret_pad_width 4
+            copy up arg 0
+            >>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov11/18 [->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r13 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_check
+            +
+            >#check
+            +
+            >>#check/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>]
+    <<<<<<]
+<<]
@@ -0,0 +1,1100 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %21 after call 0 (call continuation)
+  main/%call_term_for_1 from %21 after call 1 (call continuation)
+  main/%call_term_for_2 from %21 after call 2 (call continuation)
+  main/%call_term_for_3 from %21 after call 3 (call continuation)
+  main/%call_term_for_4 from %21 after call 4 (call continuation)
+call graph:
+  sign (depth 1)
+  main calls sign (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  sign: 4672 ops ~30845 steps
+  main: 6109 ops ~76781 steps
+frame widths:
+  sign: 36 cells
+  main: 36 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#sign
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/sign
+            >>>-
+            l36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_sign
+        #F:sign
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%7
+        #B:%8
+        #B:%12
+        #B:%13
+        #B:%17
+        #B:%18
+        #alloc_%2
+        #%3_=_load_i8*_%2__align_1(mult)
+        #%4_=_sext_i8_%3_to_i32(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/17 <<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            store i8 %0_ i8* %2_ align 1
+            
+            dup15/16/17 >>>>>>>>>>>>>>>[->+>+<<]
+            mov16/15 >[-<+>]
+            <<<<[-]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            %3 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %4 = sext i8 %3 to i32
+            
+            <<[-]
+            dup13/16/18 <[->>>+>>+<<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov18/14 >>[-<<<<+>>>>]
+            %5 = icmp sgt i32 %4_ i32 _1
+            
+            <<<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            +++++++++++++++
+            [
+                -
+                >+++++++++++++++++
+            <]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/22 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/27 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/30 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            br i1 %5_ label %6_ label %7
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >+
+                #B:sign/%6_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >>+
+                #B:sign/%7_false
+            <<]
+        <]
+        >>#B:%6
+        [
+            -
+            call @putchar(i8 43)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_43
+            >++++++
+            [
+                -
+                <+++++++
+            >]
+            <+
+            .
+            [-]
+            br label %8
+            
+            <<<<<<<<<<<+
+        <<]
+        >#B:%7
+        [
+            -
+            call @putchar(i8 45)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_45
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            br label %8
+            
+            <<<<<<<<<+
+        <]
+        >#B:%8
+        [
+            -
+            %9 = load i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %10 = sext i8 %9 to i32
+            
+            <<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %11 = icmp slt i32 %10_ i32 _100
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_156
+            >++++++++++++
+            [
+                -
+                <+++++++++++++
+            >]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/22 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/27 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/33 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                
+                >>>>>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %11_ label %12_ label %13
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>>>+
+                #B:sign/%12_true
+            >>>>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:sign/%13_false
+            <<<<<]
+        >>>]
+        >#B:%12
+        [
+            -
+            call @putchar(i8 60)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_60
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            [-]
+            br label %13
+            
+            <<<<<<<<<+
+        <]
+        >#B:%13
+        [
+            -
+            %14 = load i8* %2_ align 1
+            
+            >>>>[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %15 = sext i8 %14 to i32
+            
+            <<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %16 = icmp sge i32 %15_ i32 127
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_127
+            >+++++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            <+
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/22 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/27 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/30 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>]
+            br i1 %16_ label %17_ label %18
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>>>>>+
+                #B:sign/%17_true
+            >>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:sign/%18_false
+            <<<<<<<]
+        >>>>>]
+        >#B:%17
+        [
+            -
+            call @putchar(i8 62)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_62
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            br label %18
+            
+            <<<<<<<+
+        <]
+        >#B:%18
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >>>>>]
+    <<<<<<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:sign
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%10
+        #B:%21
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #B:%call_term_for_3
+        #B:%call_term_for_4
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_volatile_i8*_%2__align_1(mult)
+        #%8_=_sext_i8_%7_to_i32(mult)
+        #%13_=_add_i32_48__i32_%12(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>>>[-]
+            
+            store volatile i8 3_ i8* %2_ align 1
+            
+            >[-]
+            +++
+            store volatile i8 1_ i8* %3_ align 1
+            
+            >[-]
+            +
+            %5 = load volatile i8* %2_ align 1
+            
+            >>[-]
+            known to be 3
+            +++
+            store i8 %5_ i8* %4_ align 1
+            
+            <[-]
+            +++
+            br label %6
+            
+            <<<<<<<<<<<+
+        <<]
+        >>#B:%6
+        [
+            -
+            %7 = load i8* %4_ align 1
+            
+            >>>>>>>>>>>>[-]
+            dup16/17/20 <[->+>>>+<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            %8 = sext i8 %7 to i32
+            
+            <<[-]
+            dup17/21/20 <[->>>>+<+<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            mov20/18 <[-<<+>>]
+            %9 = icmp sgt i32 %8_ i32 0
+            
+            <<<[-]
+            dup18/20/22 >[->>+>>+<<<<]
+            mov20/18 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >#constop_0
+            
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/25 <<[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/30 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/33 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
+            br i1 %9_ label %10_ label %21
+            
+            dup17/21/20 <<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%10_true
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%21_false
+            <<<]
+        >]
+        >#B:%10
+        [
+            -
+            %11 = load i8* %4_ align 1
+            
+            >>>>>>>>>>>[-]
+            dup16/17/22 <[->+>>>>>+<<<<<<]
+            mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            %12 = sext i8 %11 to i32
+            
+            <<<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/18 >[-<<<+>>>]
+            %13 = add i32 48_ i32 %12
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup18/22/23 <<<[->>>>+>+<<<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            mov20/19 <<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup19/25/27 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov27/19 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov24/25 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov25/24 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %14 = trunc i32 %13 to i8
+            
+            <<<<<<[-]
+            dup19/20/21 >[->+>+<<]
+            mov20/19 >[-<+>]
+            mov21/18 >[-<<<+>>>]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            dup18/20/21 <<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            >.
+            [-]
+            %16 = sext i8 %11 to i32
+            
+            <<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/18 >[-<<<+>>>]
+            %17 = load volatile i8* %3_ align 1
+            
+            <<<<[-]
+            dup15/17/20 <<[->>+>>>+<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            %18 = zext i8 %17 to i32
+            
+            <[-]
+            dup17/20/21 <<[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/19 >[-<<+>>]
+            %19 = sub i32 %16_ i32 %18
+            
+            <<<<[-]
+            dup18/20/21 >[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            dup19/22/23 <[->>>+>+<<<<]
+            mov22/19 >>>[-<<<+>>>]
+            mov21/17 <[-<<<<+>>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<<[
+                    -
+                    mov17/24 [->>>>>>>+<<<<<<<]
+                    >>>>>>>>-
+                <<<<<<<<]
+                mov24/17 >>>>>>>[-<<<<<<<+>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>]
+            <<]
+            %20 = trunc i32 %19 to i8
+            
+            <<<<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/18 >[-<<<+>>>]
+            store i8 %20_ i8* %4_ align 1
+            
+            dup18/20/21 <<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            <<<<[-]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            br label %6
+            
+            <<<<<<<<<<<<<<<<+
+        >]
+        >#B:%21
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            call @sign(i8 255)
+            
+            enable next block when we return
+            <<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 36
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov20/41 <[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/43/44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov44/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r36 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>]
+        >#B:%call_term_for_0
+        [
+            -
+            call @sign(i8 5)
+            
+            enable next block when we return
+            >#caller/%call_term_for_1
+            +
+            stack_width 36
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_5
+            +++++
+            mov20/41 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/43/44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov44/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r36 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>]
+        >#B:%call_term_for_1
+        [
+            -
+            call @sign(i8 128)
+            
+            enable next block when we return
+            >#caller/%call_term_for_2
+            +
+            stack_width 36
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_128
+            >++++++++
+            [
+                -
+                <++++++++++++++++
+            >]
+            mov20/41 <[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/43/44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov44/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r36 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            call @sign(i8 0)
+            
+            enable next block when we return
+            >#caller/%call_term_for_3
+            +
+            stack_width 36
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov20/41 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/43/44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov44/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r36 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>>>]
+        >#B:%call_term_for_3
+        [
+            -
+            call @sign(i8 127)
+            
+            enable next block when we return
+            >#caller/%call_term_for_4
+            +
+            stack_width 36
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_127
+            >+++++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            <+
+            mov20/41 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/43/44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov44/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r36 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>>>>]
+        >#B:%call_term_for_4
+        [
+            -
+            %22 = load i32* %1_ align 4
+            
+            >>>>>[-]
+            dup13/17/20 <<<<[->>>>+>>>+<<<<<<<]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            ret i32 %22
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>>>>]
+    <<<<<<<<<<]
+<<]
@@ -0,0 +1,486 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2922 ops ~17762 steps
+frame widths:
+  main: 33 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%13
+        #B:%14
+        #B:%20
+        #B:%21
+        #B:%27
+        #B:%28
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i8*_%2__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%6_=_add_i32_%5__i32_5(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>[-]
+            
+            store i8 97_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            %4 = load i8* %2_ align 1
+            
+            >>[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            %5 = zext i8 %4 to i32
+            
+            >[-]
+            known to be 97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            %6 = add i32 %5_ i32 5
+            
+            >[-]
+            known to be 102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            %7 = trunc i32 %6 to i8
+            
+            <[-]
+            known to be 102
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<++
+            store i8 %7_ i8* %3_ align 1
+            
+            <<[-]
+            >>>>++++++++++
+            [
+                -
+                <<<<++++++++++
+            >>>>]
+            <<<<++
+            %9 = zext i8 %4 to i32
+            
+            >>[-]
+            known to be 97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            %10 = load i8* %3_ align 1
+            
+            <[-]
+            known to be 102
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            <<<++
+            %11 = zext i8 %10 to i32
+            
+            >>[-]
+            known to be 102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            %12 = icmp slt i32 %9_ i32 %11
+            
+            <<[-]
+            known to be 1
+            +
+            br i1 %12_ label %13_ label %14
+            
+            dup13/18/16 [->>>>>+<<+<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >+
+                #B:main/%13_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%14_false
+            <<]
+        <]
+        >>#B:%13
+        [
+            -
+            call @putchar(i8 60)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_60
+            >>++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<.
+            [-]
+            br label %14
+            
+            <<<<<<<<<<<<+
+        <]
+        >#B:%14
+        [
+            -
+            %15 = load i8* %3_ align 1
+            
+            >>>>>>>>[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %16 = zext i8 %15 to i32
+            
+            <<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %17 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup11/13/16 <<[->>+>>>+<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            %18 = zext i8 %17 to i32
+            
+            <[-]
+            dup13/16/17 <<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/15 >[-<<+>>]
+            %19 = icmp sgt i32 %16_ i32 %18
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            dup15/18/20 <[->>>+>>+<<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/22 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <<+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<<[
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<[
+                    -
+                    >>>-
+                <<<]
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<]
+            >>>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/27 <<<<<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/30 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            br i1 %19_ label %20_ label %21
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >>>+
+                #B:main/%20_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%21_false
+            <<<<]
+        >>]
+        >#B:%20
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %21
+            
+            <<<<<<<<<<<+
+        <]
+        >#B:%21
+        [
+            -
+            %22 = load i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup11/13/16 <<[->>+>>>+<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            %23 = zext i8 %22 to i32
+            
+            <<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %24 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %25 = zext i8 %24 to i32
+            
+            <[-]
+            dup13/16/17 <<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/15 >[-<<+>>]
+            %26 = icmp ne i32 %23_ i32 %25
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            dup15/18/19 <[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/21 <[->>>>+<<<<]
+            >>>>>>+
+            <<<<[
+                -
+                >+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<+
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<+
+            >>>>>>>]
+            br i1 %26_ label %27_ label %28
+            
+            dup13/17/16 <<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%27_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%28_false
+            <<<<<<]
+        >>>>]
+        >#B:%27
+        [
+            -
+            call @putchar(i8 61)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_61
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %28
+            
+            <<<<<<<<<+
+        <]
+        >#B:%28
+        [
+            -
+            %29 = load i8* %2_ align 1
+            
+            >>>>[-]
+            dup11/13/16 <<[->>+>>>+<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            call @putchar(i8 %29)
+            
+            putchar intrinsic
+            dup13/16/17 <<<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            >.
+            [-]
+            %30 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %30)
+            
+            putchar intrinsic
+            dup13/16/17 <<<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            >.
+            [-]
+            %31 = load i32* %1_ align 4
+            
+            <<<<[-]
+            dup10/13/16 <<<[->>>+>>>+<<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            ret i32 %31
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+    <<<<<<<<]
+<]
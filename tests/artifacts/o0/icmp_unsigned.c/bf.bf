@@ -1,842 +1,104 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 5753 ops ~70706 steps
+frame widths:
+  main: 37 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l70 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%7
-        >#B:%10
-        >#B:%14
-        >#B:%call_term_for_5
-        >#B:%15
-        >#B:%19
-        >#B:%call_term_for_8
-        >#B:%20
-        >#B:%24
-        >#B:%call_term_for_11
-        >#B:%25
-        >#B:%29
-        >#B:%call_term_for_14
-        >#B:%30
-        >#B:%34
-        >#B:%call_term_for_17
-        >#B:%35
-        >#B:%39
-        >#B:%call_term_for_20
-        >#B:%40
-        >#B:%call_term_for_22
-        >#B:%41
-        >#B:%44
-        >#B:%45
-        >#B:%48
-        >#alloc_%1
-        >#alloc_%2
-        >#%4_=_load_i32*_%1__align_4(mult)
-        >#%5_=_icmp_ult_i32_%4__i32_20(mult)
-        >#%8_=_load_i32*_%2__align_4(mult)
-        >#%9_=_icmp_ult_i32_%8__i32_20(mult)
-        >#%11_=_load_i32*_%1__align_4(mult)
-        >#%12_=_load_i32*_%2__align_4(mult)
-        >#%13_=_icmp_ult_i32_%11__i32_%12(mult)
-        >#%16_=_load_i32*_%1__align_4(mult)
-        >#%17_=_load_i32*_%2__align_4(mult)
-        >#%18_=_icmp_ne_i32_%16__i32_%17(mult)
-        >#%21_=_load_i32*_%1__align_4(mult)
-        >#%22_=_load_i32*_%2__align_4(mult)
-        >#%23_=_icmp_eq_i32_%21__i32_%22(mult)
-        >#%26_=_load_i32*_%1__align_4(mult)
-        >#%27_=_load_i32*_%2__align_4(mult)
-        >#%28_=_icmp_ugt_i32_%26__i32_%27(mult)
-        >#%31_=_load_i32*_%1__align_4(mult)
-        >#%32_=_load_i32*_%2__align_4(mult)
-        >#%33_=_icmp_ule_i32_%31__i32_%32(mult)
-        >#%36_=_load_i32*_%1__align_4(mult)
-        >#%37_=_load_i32*_%2__align_4(mult)
-        >#%38_=_icmp_uge_i32_%36__i32_%37(mult)
-        >#%42_=_load_i32*_%2__align_4(mult)
-        >#%43_=_add_i32_%42__i32_1(mult)
-        >#%46_=_load_i32*_%1__align_4(mult)
-        >#%47_=_add_i32_%46__i32_1(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%0
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l37 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%6
+        #B:%7
+        #B:%10
+        #B:%14
+        #B:%15
+        #B:%19
+        #B:%20
+        #B:%24
+        #B:%25
+        #B:%29
+        #B:%30
+        #B:%34
+        #B:%35
+        #B:%39
+        #B:%40
+        #B:%44
+        #B:%48
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i32*_%1__align_4(mult)
+        #%5_=_icmp_ult_i32_%4__i32_20(mult)
+        #%13_=_icmp_ult_i32_%11__i32_%12(mult)
+        <#B:%0
         [
             -
             copy up args
             %1 = alloca i32_ align 4
             %2 = alloca i32_ align 4
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>>>>>>>>>>>>>>>>>>[-]
+            
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<<<<<<<<<<<<+
         <<]
         >>#B:%3
         [
             -
             %4 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/35/62 <<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov62/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %5 = icmp ult i32 %4_ i32 20
-            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup35/61/63 <[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/35 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup24/28/27 <[->>>>+<+<<<]
+            mov28/24 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
             >#constop_20
-            ++++++++++++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov62/64 <<<<[->>+<<]
+            >++++
+            [
+                -
+                <+++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/31 <[->>+<<]
             >>>>+
-            <<<[
+            <<<<<<[
                 -
                 
-                >[
+                >>>>[
                     -
                     
                     r1 >
@@ -846,61 +108,69 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
+            <<<<]
+            >>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<+
+            >>>>>>]
             br i1 %5_ label %6_ label %48
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup25/28/27 <<<<<<[->>>+<+<<]
+            mov28/25 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<-
                 >>+
-                #B:main/6_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                #B:main/%6_true
+            >>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/48_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<]
+                >>>>>>>>>>>>>>>>>>+
+                #B:main/%48_false
+            <<<<<<<<<<<<<<<<<<]
         >]
         >#B:%6
         [
             -
             store i32 0_ i32* %2_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>>>>>>>>>>>>>>>>[-]
+            
             br label %7
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<<<<<<<<<<<+
         <]
         >#B:%7
         [
             -
             %8 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup34/37/61 <<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>[-]
+            dup23/24/27 <[->+>>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %9 = icmp ult i32 %8_ i32 20
-            <<<<<<<<<<<<<<<<<<<<<<<[-]
-            dup37/61/62 <[->>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/37 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup24/27/28 <[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_20
-            ++++++++++++++++++++
-            >>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov63/67 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<<<<[
+            >++++
+            [
+                -
+                <+++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/34 <[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<[
                 -
                 
-                >>>>>[
+                >>>>>>[
                     -
                     
                     r1 >
@@ -910,47 +180,53 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<<<]
-            >>>>>>>-
+            <<<<<<]
+            >>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<+
+            >>>>>>>>>]
             br i1 %9_ label %10_ label %44
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup25/28/27 <<<<<<<<<[->>>+<+<<]
+            mov28/25 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<-
                 >>>>+
-                #B:main/10_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                #B:main/%10_true
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/44_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<]
+                >>>>>>>>>>>>>>>>>+
+                #B:main/%44_false
+            <<<<<<<<<<<<<<<<<]
         >>>]
         >#B:%10
         [
             -
             %11 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/39/61 <<<<<<[->>>>>>+>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>[-]
+            dup22/24/29 <<[->>+>>>>>+<<<<<<<]
+            mov29/22 >>>>>>>[-<<<<<<<+>>>>>>>]
             %12 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<<<<[-]
-            dup34/40/61 <<<<<<[->>>>>>+>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<<[-]
+            dup23/25/27 <<[->>+>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %13 = icmp ult i32 %11_ i32 %12
-            <<<<<<<<<<<<<<<<<<<<[-]
-            dup39/61/62 <<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/39 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
-            dup40/63/64 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov63/40 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov64/65 <<<[->+<]
+            
+            <[-]
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            dup25/29/30 <<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/31 [->+<]
             >>>+
             <<<<<[
                 -
@@ -969,63 +245,68 @@ runtime init:
             >>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<+
+            >>>>>]
             br i1 %13_ label %14_ label %15
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup26/28/27 <<<<<[->>+<+<]
+            mov28/26 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<-
                 >>>>>+
-                #B:main/14_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                #B:main/%14_true
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>+
-                #B:main/15_false
-            <<<<<<<]
+                >>>>>>+
+                #B:main/%15_false
+            <<<<<<]
         >>>>]
         >#B:%14
         [
             -
             call @putchar(i8 108)
-            enable next block when we return
-            >#caller/%call_term_for_5
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_5
-        [
-            -
             br label %15
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%15
         [
             -
             %16 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/42/61 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %17 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup34/43/61 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup23/25/27 <<[->>+>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %18 = icmp ne i32 %16_ i32 %17
-            <<<<<<<<<<<<<<<<<[-]
-            dup42/61/62 <<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
-            mov61/42 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
-            dup43/63/64 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
-            mov63/43 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov62/66 <<<<<<[->>>>+<<<<]
+            
+            <[-]
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            dup25/29/30 <<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/32 <[->>>>+<<<<]
             >>>>>>+
             <<<<[
                 -
@@ -1044,67 +325,72 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<+
+            >>>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
+                <<<<<+
+            >>>>>]
             br i1 %18_ label %19_ label %20
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup26/28/27 <<<<<[->>+<+<]
+            mov28/26 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:main/19_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%19_true
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>+
-                #B:main/20_false
-            <<<<<<<<<<]
-        >>>>>>>]
+                >>>>>>>>+
+                #B:main/%20_false
+            <<<<<<<<]
+        >>>>>>]
         >#B:%19
         [
             -
             call @putchar(i8 110)
-            enable next block when we return
-            >#caller/%call_term_for_8
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_110
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
             br label %20
-            >+
+            
+            <<<<<<<<<<<<<<<<<<+
         <]
         >#B:%20
         [
             -
             %21 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/45/61 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %22 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<[-]
-            dup34/46/61 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup23/25/27 <<[->>+>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %23 = icmp eq i32 %21_ i32 %22
-            <<<<<<<<<<<<<<[-]
-            dup45/61/62 <<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
-            mov61/45 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            dup46/63/64 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov63/46 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov64/66 <<<<[->>+<<]
+            
+            <[-]
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            dup25/29/30 <<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/32 [->>+<<]
             >>>>+
             <<<<<<[
                 -
@@ -1121,70 +407,76 @@ runtime init:
                 l1 <
             <<<<]
             >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
+            <<<<<<<<+
+            >>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
+                <<<<<<[-]
+            >>>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
+                <<<<<[-]
+            >>>>>]
             br i1 %23_ label %24_ label %25
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup26/28/27 <<<<<[->>+<+<]
+            mov28/26 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>+
-                #B:main/24_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%24_true
+            >>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>+
-                #B:main/25_false
-            <<<<<<<<<<<<<]
-        >>>>>>>>>>]
+                >>>>>>>>>>+
+                #B:main/%25_false
+            <<<<<<<<<<]
+        >>>>>>>>]
         >#B:%24
         [
             -
             call @putchar(i8 101)
-            enable next block when we return
-            >#caller/%call_term_for_11
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>#constop_101
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_11
-        [
-            -
             br label %25
-            >+
+            
+            <<<<<<<<<<<<<<<<+
         <]
         >#B:%25
         [
             -
             %26 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/48/61 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %27 = load i32* %2_ align 4
-            <<<<<<<<<<<<[-]
-            dup34/49/61 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup23/25/27 <<[->>+>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %28 = icmp ugt i32 %26_ i32 %27
-            <<<<<<<<<<<[-]
-            dup48/61/62 <<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov61/48 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup49/63/64 <<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov63/49 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov62/65 <<<<<[->>>+<<<]
+            
+            <[-]
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            dup25/29/30 <<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/31 <[->>>+<<<]
             >>>>>+
             <<<[
                 -
@@ -1203,63 +495,69 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>]
+                <<<<<+
+            >>>>>]
             br i1 %28_ label %29_ label %30
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup26/28/27 <<<<<[->>+<+<]
+            mov28/26 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>+
-                #B:main/29_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%29_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>+
-                #B:main/30_false
-            <<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>]
+                >>>>>>>>>>>>+
+                #B:main/%30_false
+            <<<<<<<<<<<<]
+        >>>>>>>>>>]
         >#B:%29
         [
             -
             call @putchar(i8 103)
-            enable next block when we return
-            >#caller/%call_term_for_14
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_103
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>#constop_103
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_14
-        [
-            -
             br label %30
-            >+
+            
+            <<<<<<<<<<<<<<+
         <]
         >#B:%30
         [
             -
             %31 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/51/61 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %32 = load i32* %2_ align 4
-            <<<<<<<<<[-]
-            dup34/52/61 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup23/25/27 <<[->>+>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %33 = icmp ule i32 %31_ i32 %32
-            <<<<<<<<[-]
-            dup51/61/62 <<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov61/51 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup52/63/64 <<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov63/52 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov62/65 <<<<<[->>>+<<<]
+            
+            <[-]
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            dup25/29/30 <<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/31 <[->>>+<<<]
             >>>>>+
             <<<[
                 -
@@ -1276,66 +574,72 @@ runtime init:
                 l1 <
             <]
             >>>-
-            <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
+            <<<<<<<+
+            >>>>>[
                 [-]
-                <<<<<<<<<<<<[-]
-            >>>>>>>>>>>>]
+                <<<<<[-]
+            >>>>>]
             br i1 %33_ label %34_ label %35
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup26/28/27 <<<<<[->>+<+<]
+            mov28/26 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>+
-                #B:main/34_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>+
+                #B:main/%34_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>+
-                #B:main/35_false
-            <<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>+
+                #B:main/%35_false
+            <<<<<<<<<<<<<<]
+        >>>>>>>>>>>>]
         >#B:%34
         [
             -
             call @putchar(i8 76)
-            enable next block when we return
-            >#caller/%call_term_for_17
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_76
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>#constop_76
+            >+++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_17
-        [
-            -
             br label %35
-            >+
+            
+            <<<<<<<<<<<<+
         <]
         >#B:%35
         [
             -
             %36 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/54/61 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %37 = load i32* %2_ align 4
-            <<<<<<[-]
-            dup34/55/61 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup23/25/27 <<[->>+>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
             %38 = icmp uge i32 %36_ i32 %37
-            <<<<<[-]
-            dup54/61/62 <<[->>>>>>>+>+<<<<<<<<]
-            mov61/54 >>>>>>>[-<<<<<<<+>>>>>>>]
-            dup55/63/64 <<<<<<[->>>>>>>>+>+<<<<<<<<<]
-            mov63/55 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov64/65 <<<[->+<]
+            
+            <[-]
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            dup25/29/30 <<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/31 [->+<]
             >>>+
             <<<<<[
                 -
@@ -1352,165 +656,180 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
+            <<<<<<<+
+            >>>>>[
                 [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
+                <<<<<[-]
+            >>>>>]
             br i1 %38_ label %39_ label %40
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>+
-                #B:main/39_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+            
+            dup26/28/27 <<<<<[->>+<+<]
+            mov28/26 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>+
+                #B:main/%39_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/40_false
-            <<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>>>+
+                #B:main/%40_false
+            <<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>]
         >#B:%39
         [
             -
             call @putchar(i8 71)
-            enable next block when we return
-            >#caller/%call_term_for_20
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_71
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>#constop_71
+            >+++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_20
-        [
-            -
             br label %40
-            >+
+            
+            <<<<<<<<<<+
         <]
         >#B:%40
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_22
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_22
-        [
-            -
-            br label %41
-            >+
-        <]
-        >#B:%41
-        [
-            -
             %42 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup34/57/61 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            %43 = add i32 %42_ i32 1
+            
             <<<[-]
-            dup57/61/62 <[->>>>+>+<<<<<]
-            mov61/57 >>>>[-<<<<+>>>>]
+            dup23/24/27 <[->+>>>+<<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
+            %43 = add i32 %42_ i32 1
+            
+            <<[-]
+            dup24/27/28 <[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov62/58 <[-<<<<+>>>>]
-            >[
+            mov28/25 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup25/31/33 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov33/25 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
                 -
-                <<<<<+
-            >>>>>]
+                >>>+
+                <<[
+                    -
+                    mov30/31 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov31/30 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %43_ i32* %2_ align 4
-            dup58/61/62 <<<<<[->>>+>+<<<<]
-            mov61/58 >>>[-<<<+>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov62/34 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup25/27/28 <<<<<[->>+>+<<<]
+            mov27/25 >>[-<<+>>]
+            <<<<[-]
+            mov28/23 >>>>>[-<<<<<+>>>>>]
             br label %7
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<<<<<<<<<<<<<<<<<<<<+
+        >>>>>>>>>>>>>]
         >#B:%44
-        [
-            -
-            br label %45
-            >+
-        <]
-        >#B:%45
         [
             -
             %46 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup33/59/61 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov61/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>[-]
+            dup22/24/27 <<[->>+>>>+<<<<<]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
             %47 = add i32 %46_ i32 1
-            <[-]
-            dup59/61/62 <[->>+>+<<<]
-            mov61/59 >>[-<<+>>]
+            
+            <<[-]
+            dup24/27/28 <[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov62/60 <[-<<+>>]
-            >[
+            mov28/25 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup25/31/33 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov33/25 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
                 -
-                <<<+
-            >>>]
+                >>>+
+                <<[
+                    -
+                    mov30/31 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov31/30 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %47_ i32* %1_ align 4
-            dup60/61/62 <<<[->+>+<<]
-            mov61/60 >[-<+>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov62/33 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            dup25/27/28 <<<<<[->>+>+<<<]
+            mov27/25 >>[-<<+>>]
+            <<<<<[-]
+            mov28/22 >>>>>>[-<<<<<<+>>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<+
+        >>>>>>>>>>>>>>>>]
         >#B:%48
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-<<<]
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<<<]
+<]
@@ -0,0 +1,474 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3607 ops ~68168 steps
+frame widths:
+  main: 22 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l22 <<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_volatile_i8*_%1__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%33_=_zext_i8_%32_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store volatile i8 0_ i8* %1_ align 1
+            
+            >>[-]
+            
+            store volatile i8 7_ i8* %2_ align 1
+            
+            >[-]
+            +++++++
+            %4 = load volatile i8* %1_ align 1
+            
+            >>[-]
+            known to be 0
+            
+            %5 = zext i8 %4 to i32
+            
+            >[-]
+            known to be 0
+            
+            %6 = icmp eq i32 %5_ i32 0
+            
+            <[-]
+            known to be 1
+            +
+            %8 = select i1 %6_ i32 121_ i32 110
+            
+            >[-]
+            known to be 121
+            >>+++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %9 = trunc i32 %8 to i8
+            
+            <<<[-]
+            known to be 121
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_121
+            >+++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            %10 = load volatile i8* %2_ align 1
+            
+            <<<[-]
+            dup5/7/10 <<[->>+>>>+<<<<<]
+            mov10/5 >>>>>[-<<<<<+>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            %12 = icmp eq i32 %11_ i32 0
+            
+            <<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            <<<+
+            >>>>[
+                [-]
+                <<<<[-]
+            >>>>]
+            %14 = select i1 %12_ i32 121_ i32 110
+            
+            <<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            op_to_reg storing const value in temp address
+            >>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            >+
+            <<<<<[
+                mov13/8 >>[-<<<<<+>>>>>]
+                >[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov14/8 <<[-<<<<<<+>>>>>>]
+                <[-]
+                <<[-]
+            >>>>>]
+            %15 = trunc i32 %14 to i8
+            
+            <<<<<<<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            mov11/7 >[-<<<<+>>>>]
+            call @putchar(i8 %15)
+            
+            putchar intrinsic
+            dup7/10/11 <<<<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            >.
+            [-]
+            %16 = load volatile i8* %1_ align 1
+            
+            <<<<[-]
+            dup4/7/10 <<<[->>>+>>>+<<<<<<]
+            mov10/4 >>>>>>[-<<<<<<+>>>>>>]
+            %17 = zext i8 %16 to i32
+            
+            <<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            %18 = icmp ne i32 0_ i32 %17
+            
+            <<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            >[
+                [-]
+                <<<<+
+            >>>>]
+            %20 = select i1 %18_ i32 121_ i32 110
+            
+            <<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            op_to_reg storing const value in temp address
+            >#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            >>+
+            <<<<<<[
+                mov12/8 >[-<<<<+>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>>[-]
+            <<<<<<]
+            >>>>>>[
+                -
+                mov14/8 <<<[-<<<<<<+>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>>]
+            %21 = trunc i32 %20 to i8
+            
+            <<<<<<<<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            mov11/7 >[-<<<<+>>>>]
+            call @putchar(i8 %21)
+            
+            putchar intrinsic
+            dup7/10/11 <<<<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            >.
+            [-]
+            %22 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/7/10 <<[->>+>>>+<<<<<]
+            mov10/5 >>>>>[-<<<<<+>>>>>]
+            %23 = zext i8 %22 to i32
+            
+            <<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            %24 = icmp ne i32 0_ i32 %23
+            
+            <<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            >[
+                [-]
+                <<<<+
+            >>>>]
+            %26 = select i1 %24_ i32 121_ i32 110
+            
+            <<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            op_to_reg storing const value in temp address
+            >#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            >+
+            <<<<<[
+                mov12/8 >[-<<<<+>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov14/8 <<[-<<<<<<+>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            %27 = trunc i32 %26 to i8
+            
+            <<<<<<<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            mov11/7 >[-<<<<+>>>>]
+            call @putchar(i8 %27)
+            
+            putchar intrinsic
+            dup7/10/11 <<<<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            >.
+            [-]
+            %28 = load volatile i8* %1_ align 1
+            
+            <<<<[-]
+            dup4/7/10 <<<[->>>+>>>+<<<<<<]
+            mov10/4 >>>>>>[-<<<<<<+>>>>>>]
+            %29 = zext i8 %28 to i32
+            
+            <<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            %30 = icmp eq i32 %29_ i32 0
+            
+            <<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            <<<+
+            >>>>[
+                [-]
+                <<<<[-]
+            >>>>]
+            %31 = zext i1 %30 to i32
+            
+            <<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            %32 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/7/10 <<[->>+>>>+<<<<<]
+            mov10/5 >>>>>[-<<<<<+>>>>>]
+            %33 = zext i8 %32 to i32
+            
+            <[-]
+            dup7/10/11 <<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/9 >[-<<+>>]
+            %34 = icmp ne i32 %33_ i32 0
+            
+            <<<<[-]
+            dup9/10/11 >>[->+>+<<]
+            mov10/9 >[-<+>]
+            >[
+                [-]
+                <<<<+
+            >>>>]
+            %35 = zext i1 %34 to i32
+            
+            <<[-]
+            dup7/10/11 <<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/9 >[-<<+>>]
+            %36 = add i32 %31_ i32 %35
+            
+            <<<<[-]
+            dup8/10/11 >[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            dup9/12/13 <[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov11/7 <[-<<<<+>>>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/19/21 <<<<<<<<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov21/7 >>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<<<<<+
+                    >>>>>>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %37 = trunc i32 %36 to i8
+            
+            <<<<<<<<<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            store i8 %37_ i8* %3_ align 1
+            
+            dup8/10/11 <<<[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            <<<<[-]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            %38 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup6/7/10 <[->+>>>+<<<<]
+            mov10/6 >>>>[-<<<<+>>>>]
+            %39 = zext i8 %38 to i32
+            
+            <<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            %40 = add i32 48_ i32 %39
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup8/12/13 <<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov10/7 <<[-<<<+>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/15/17 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov17/7 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov14/15 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov15/14 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %41 = trunc i32 %40 to i8
+            
+            <<<<<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            call @putchar(i8 %41)
+            
+            putchar intrinsic
+            dup8/10/11 <<<[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,819 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 8731 ops ~374271 steps
+frame widths:
+  main: 35 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_volatile_i8*_%1__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%10_=_trunc_i32_%9_to_i8(mult)
+        #%21_=_zext_i8_%20_to_i32(mult)
+        #%23_=_zext_i8_%22_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i32_ align 4
+            store volatile i8 121_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>>+++++++++++
+            [
+                -
+                <<<<<<<<+++++++++++
+            >>>>>>>>]
+            store volatile i8 110_ i8* %2_ align 1
+            
+            <<<<<<<[-]
+            >>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<+++++++++++
+            >>>>>>>>]
+            %4 = load volatile i8* %1_ align 1
+            
+            <<<<<<[-]
+            known to be 121
+            >>>>>+++++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %5 = zext i8 %4 to i32
+            
+            <<<<[-]
+            known to be 121
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %6 = icmp eq i32 %5_ i32 121
+            
+            <<<<<[-]
+            known to be 1
+            +
+            %7 = zext i1 %6 to i32
+            
+            >[-]
+            known to be 1
+            +
+            store i32 %7_ i32* %3_ align 4
+            
+            <<[-]
+            +
+            %8 = load i32* %3_ align 4
+            
+            >[-]
+            known to be 1
+            +
+            %9 = add i32 48_ i32 %8
+            
+            >[-]
+            known to be 49
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++
+            >>>>]
+            %10 = trunc i32 %9 to i8
+            
+            <<<[-]
+            known to be 49
+            >>>+++++++
+            [
+                -
+                <<<+++++++
+            >>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_49
+            >>+++++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<.
+            [-]
+            %11 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/8/12 <<<[->>>+>>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %12 = zext i8 %11 to i32
+            
+            <<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            %13 = icmp eq i32 %12_ i32 121
+            
+            <<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/17 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<+
+            >>>>>>>>>[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            %14 = zext i1 %13 to i32
+            
+            <<<<<<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            %15 = mul i32 %14_ i32 3
+            
+            <<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_3
+            +++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov13/18 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov15/16 [->+<]
+                        <<<<<<<+
+                        >>>>>>>>>-
+                    <<]
+                    mov16/15 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<[-]
+                        >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <[-]
+            >>[-]
+            %16 = add i32 65_ i32 %15
+            
+            <<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup8/14/15 <<<<[->>>>>>+>+<<<<<<<]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            mov12/9 <<[-<<<+>>>]
+            >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/21/23 <<<<<<<<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov23/9 >>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<<<<<<+
+                    >>>>>>>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %17 = trunc i32 %16 to i8
+            
+            <<<<<<<<<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/8 >[-<<<<<+>>>>>]
+            call @putchar(i8 %17)
+            
+            putchar intrinsic
+            dup8/12/13 <<<<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %19 = add i32 65_ i32 %8
+            
+            <<<<<[-]
+            known to be 66
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %20 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            dup4/9/12 <<<<<[->>>>>+>>>+<<<<<<<<]
+            mov12/4 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %21 = zext i8 %20 to i32
+            
+            <<[-]
+            dup9/12/13 <[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/10 >[-<<<+>>>]
+            %22 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/9/12 <<<<[->>>>+>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %23 = zext i8 %22 to i32
+            
+            <[-]
+            dup9/12/13 <<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/11 >[-<<+>>]
+            %24 = icmp sgt i32 %21_ i32 %23
+            
+            <<<<[-]
+            dup10/12/13 >[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            dup11/14/15 <[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/18 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/24 <<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<<[
+                -
+                <+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<<<<[
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<[
+                    -
+                    >>>-
+                <<<]
+                >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/27 <<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            %25 = zext i1 %24 to i32
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup9/12/13 <[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/10 >[-<<<+>>>]
+            %26 = add i32 %19_ i32 %25
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            dup10/14/15 <<<[->>>>+>+<<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            mov12/9 <<[-<<<+>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/17/19 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov19/9 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %27 = load volatile i8* %2_ align 1
+            
+            <<<<<<<<[-]
+            dup5/8/12 <<<[->>>+>>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %28 = zext i8 %27 to i32
+            
+            <<[-]
+            dup8/12/13 <<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/10 >[-<<<+>>>]
+            %29 = icmp slt i32 %28_ i32 97
+            
+            <<<<<[-]
+            dup10/12/13 >>[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/18 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/23 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/26 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            %30 = zext i1 %29 to i32
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup8/12/13 <<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/10 >[-<<<+>>>]
+            %31 = add i32 %26_ i32 %30
+            
+            <<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            dup10/14/15 <<[->>>>+>+<<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            mov13/8 <[-<<<<<+>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/17/19 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov19/8 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %32 = trunc i32 %31 to i8
+            
+            <<<<<<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            call @putchar(i8 %32)
+            
+            putchar intrinsic
+            dup9/12/13 <<<<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            >.
+            [-]
+            %34 = load volatile i8* %2_ align 1
+            
+            <<<<<[-]
+            dup5/8/12 <<<[->>>+>>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %35 = zext i8 %34 to i32
+            
+            <<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            %36 = icmp eq i32 %35_ i32 121
+            
+            <<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/17 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<+
+            >>>>>>>>>[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            %37 = zext i1 %36 to i32
+            
+            <<<<<<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            %38 = and i32 %8_ i32 %37
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_1
+            +
+            dup9/13/14 <<<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov12/20 <[->>>>>>>>+<<<<<<<<]
+            mov14/30 >>[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>+
+            >>>>>>>>>>+
+            <<<<<<<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup24/21/20 >>>>[-<<<+<+>>>>]
+                mov21/24 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >>>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup34/31/30 >>>>[-<<<+<+>>>>]
+                mov31/34 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<<<<<<[
+                    -
+                    mov33/15 >>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<]
+                >>>>>>>>>>[-]
+                dup19/16/17 <<<<<<<<<<<<<<[-<<<+>+>>]
+                mov16/19 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov16/15 >[-<+>]
+                >]
+                mov15/8 <<[-<<<<<<<+>>>>>>>]
+                continue?
+                dup20/15/16 >>>>>[-<<<<<+>+>>>>]
+                mov15/20 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup30/15/16 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>>]
+                mov15/30 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<[-]
+            %39 = add i32 48_ i32 %38
+            
+            <<<<<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup8/14/15 <<<<<[->>>>>>+>+<<<<<<<]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            mov12/7 <<[-<<<<<+>>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/17/19 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov19/7 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %40 = trunc i32 %39 to i8
+            
+            <<<<<<<<[-]
+            dup7/12/13 <[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            mov13/8 >[-<<<<<+>>>>>]
+            call @putchar(i8 %40)
+            
+            putchar intrinsic
+            dup8/12/13 <<<<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,329 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3261 ops ~59226 steps
+frame widths:
+  main: 29 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l29 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%7
+        #B:%12
+        #alloc_%1
+        #alloc_%2
+        #alloc_%put_digit_inl0_2
+        #%4_=_load_i8*_%2__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%put_digit_inl0_4_=_zext_i8_%put_digit_inl0_3_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            store i8 0_ i8* %2_ align 1
+            
+            >[-]
+            
+            br label %3
+            
+            <<<<+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = load i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup8/10/13 <<[->>+>>>+<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            %5 = zext i8 %4 to i32
+            
+            <<[-]
+            dup10/14/13 <[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            mov13/11 <[-<<+>>]
+            %6 = icmp slt i32 %5_ i32 10
+            
+            <<<[-]
+            dup11/13/15 >[->>+>>+<<<<]
+            mov13/11 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >#constop_10
+            ++++++++++
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/18 <<[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/23 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/26 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
+            br i1 %6_ label %7_ label %12
+            
+            dup10/14/13 <<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<+
+            >>>>>>>>>>[
+                -
+                <<<<<<<<<<-
+                >>+
+                #B:main/%7_true
+            >>>>>>>>]
+            <<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%12_false
+            <<<]
+        >]
+        >#B:%7
+        [
+            -
+            %8 = load i8* %2_ align 1
+            
+            >>>>>[-]
+            dup8/10/15 <<[->>+>>>>>+<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %put_digit_inl0_2 = alloca i8_ align 1
+            store i8 %8_ i8* %put_digit_inl0_2_ align 1
+            
+            dup10/13/14 <<<<<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            <<<<[-]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            %put_digit_inl0_3 = load i8* %put_digit_inl0_2_ align 1
+            
+            <<<[-]
+            dup9/11/13 <<[->>+>>+<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            %put_digit_inl0_4 = zext i8 %put_digit_inl0_3 to i32
+            
+            <[-]
+            dup11/13/14 <[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            mov14/12 >[-<<+>>]
+            %put_digit_inl0_5 = add i32 48_ i32 %put_digit_inl0_4
+            
+            <<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup12/15/16 <<[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            mov13/11 <<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup11/18/20 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov20/11 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %put_digit_inl0_6 = trunc i32 %put_digit_inl0_5 to i8
+            
+            <<<<<[-]
+            dup11/13/14 <[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            mov14/12 >[-<<+>>]
+            call @putchar(i8 %put_digit_inl0_6)
+            
+            putchar intrinsic
+            dup12/13/14 <<[->+>+<<]
+            mov13/12 >[-<+>]
+            >.
+            [-]
+            %11 = add i8 %8_ i8 1
+            
+            <<<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov14/11 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup11/17/19 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov19/11 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %11_ i8* %2_ align 1
+            
+            dup11/13/14 <<<<<[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            <<<<<[-]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            br label %3
+            
+            <<<<<<<<<<+
+        >]
+        >#B:%12
+        [
+            -
+            %13 = load i32* %1_ align 4
+            
+            >>>>[-]
+            dup7/10/13 <<<[->>>+>>>+<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            ret i32 %13
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
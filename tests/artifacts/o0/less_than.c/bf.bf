@@ -1,403 +1,119 @@
-r7 >>>>>>>
-+++++++
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%call_term_for_3 from %0 after call 3 (call continuation)
+  main/%call_term_for_4 from %0 after call 4 (call continuation)
+  main/%call_term_for_5 from %0 after call 5 (call continuation)
+  main/%call_term_for_6 from %0 after call 6 (call continuation)
+  main/%call_term_for_7 from %0 after call 7 (call continuation)
+  main/%call_term_for_8 from %10 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  lt (depth 1)
+  main calls lt (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  lt: 1933 ops ~11838 steps
+  main: 11719 ops ~95432 steps
+frame widths:
+  lt: 32 cells
+  main: 45 cells
+r5 >>>>>
++++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>#main
+>>#main
 +
 >#main/b0
 +
-<<<<<[
-    >#putstr
+<<<[
+    >#lt
     [
-        >>>>>#B:%ret_lading_pad
+        >>>#B:%ret_lading_pad
         [
-            <<<<<<-
+            <<<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>>-
-            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/lt
+            >>>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
+        #mainloop_lt
+        #F:lt
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #B:%8
+        #B:%9
+        #B:%10
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_i32*_%3__align_4(mult)
+        #%6_=_load_i32*_%4__align_4(mult)
+        #%7_=_icmp_slt_i32_%5__i32_%6(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
         [
             -
             copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/20 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>>[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup11/12/19 <[->+>>>>>>>+<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
+            #arg_%0
+            l2 
+            mov0/15 <<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/17 <[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            r3 >>>
+            %3 = alloca i32_ align 4
+            %4 = alloca i32_ align 4
+            store i32 %0_ i32* %3_ align 4
+            
+            dup13/15/16 >>>>>>>>>>>>>[->>+>+<<<]
+            mov15/13 >>[-<<+>>]
+            <<<<<<<[-]
+            mov16/8 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            store i32 %1_ i32* %4_ align 4
+            
+            dup14/15/17 <<[->+>>+<<<]
+            mov15/14 >[-<+>]
             <<<<<<[-]
-            dup12/20/19 <[->>>>>>>>+<+<<<<<<<]
-            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
+            mov17/9 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %5 = load i32* %3_ align 4
+            
+            <<<<<<<[-]
+            dup8/10/15 <<[->>+>>>>>+<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %6 = load i32* %4_ align 4
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/23 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup11/14/19 <<<[->>>+>>>>>+<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
             <<<<[-]
-            dup14/19/20 <[->>>>>+>+<<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            mov20/15 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup15/19/20 >>>>>>[->>>>+>+<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup11/16/19 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup16/19/20 <[->>>+>+<<<<]
-            mov19/16 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov20/17 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup17/19/20 <<<<[->>+>+<<<]
-            mov19/17 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov20/11 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>]
-    <<<<<<<<<]
-    >#putdec
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>-
-            l49 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup17/19/38 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup19/39/38 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov39/19 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
+            dup9/11/15 <<[->>+>>>>+<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            %7 = icmp slt i32 %5_ i32 %6
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/42 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup17/21/38 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup18/22/38 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup21/38/39 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov38/21 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup22/40/41 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov40/22 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup41/43/45 >[->>+>>+<<<<]
-            mov45/41 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/46 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov46/39 >>>>[-<<<<<<<+>>>>>>>]
-                dup41/43/45 <<<<<[->>+>>+<<<<]
-                mov45/41 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup23/38/39 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov38/23 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov39/41 <<<<[->>+<<]
-            >>>>+
+            <<<[-]
+            dup10/15/16 <<[->>>>>+>+<<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            dup11/18/17 <<<<[->>>>>>>+<+<<<<<<]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/21 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
             <<<[
                 -
-                
-                >[
+                <+
+                >>[
                     -
-                    
+                    <<-
                     r1 >
-                ]
+                >>]
                 r1 >
                 [
                     l1 <
@@ -405,403 +121,52 @@ runtime init:
                 l1 <
             <]
             >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup18/25/38 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup25/38/39 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov38/25 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup39/26/41 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov41/39 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup26/38/39 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov39/18 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup18/27/38 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup27/38/39 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov39/42 <<<<<[->>>+<<<]
-            >>>>>+
             <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup17/29/38 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup18/30/38 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup29/38/39 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov38/29 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup30/40/41 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov40/30 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup41/43/44 >[->>+>+<<<]
-            mov44/41 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/45 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov45/39 >>>[-<<<<<<+>>>>>>]
-                dup41/43/44 <<<<[->>+>+<<<]
-                mov44/41 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup31/38/39 <[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup40/42/43 [->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/44 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
                     -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
+                    >>-
                 <<]
-                >>>>-
-                mov44/39 <<[-<<<<<+>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov42/32 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov41/32 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup32/39/40 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov39/32 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov38/33 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup33/38/39 <[->>>>>+>+<<<<<<]
-            mov38/33 >>>>>[-<<<<<+>>>>>]
-            mov39/34 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup34/38/39 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov38/34 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup18/35/38 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup35/38/39 <[->>>+>+<<<<]
-            mov38/35 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup40/42/43 [->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/44 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov44/39 >>>[-<<<<<+>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup36/38/39 <<<<<<[->>+>+<<<]
-            mov38/36 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov39/18 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<]
-    >#lt
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/lt
-            >>>>>>-
-            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_lt
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%2
-        >#B:%ret_lading_pad
-        >#B:%8
-        >#B:%call_term_for_1
-        >#B:%9
-        >#B:%call_term_for_3
-        >#B:%10
-        >#alloc_%3
-        >#alloc_%4
-        >#%5_=_load_i32*_%3__align_4(mult)
-        >#%6_=_load_i32*_%4__align_4(mult)
-        >#%7_=_icmp_slt_i32_%5__i32_%6(mult)
-        >#%0
-        >#%1
-        <<<<<<<<<<<<<#B:%2
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            >>>>>>>>>>>>>>>>>>#arg_%1
-            l3 <<<
-            mov0/21 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            r3 >>>
-            %3 = alloca i32_ align 4
-            %4 = alloca i32_ align 4
-            store i32 %0_ i32* %3_ align 4
-            >>>>>>>>>>>>[-]
-            mov17/12 >>>>>[-<<<<<+>>>>>]
-            store i32 %1_ i32* %4_ align 4
-            <<<<[-]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            %5 = load i32* %3_ align 4
-            <<<<[-]
-            dup12/14/19 <<[->>+>>>>>+<<<<<<<]
-            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
-            %6 = load i32* %4_ align 4
-            <<<<[-]
-            dup13/15/20 <<[->>+>>>>>+<<<<<<<]
-            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
-            %7 = icmp slt i32 %5_ i32 %6
-            <<<<[-]
-            dup14/19/21 <<[->>>>>+>>+<<<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            dup15/20/22 <<<<[->>>>>+>>+<<<<<<<]
-            mov20/15 >>>>>[-<<<<<+>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov22/23 <<<[->+<]
-            >>>+
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/26 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
             <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/29 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
                 -
                 
-                >>[
+                >>>>>>>>[
                     -
                     
                     r1 >
@@ -811,139 +176,132 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<+
-            >>>>>>>]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
             br i1 %7_ label %8_ label %9
-            <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
+            
+            dup12/16/15 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<+
+            >>>>>>>>>>>[
                 -
-                <<<<<<<<<<-
+                <<<<<<<<<<<-
                 >+
-                #B:lt/8_true
-            >>>>>>>>>]
-            <<<<<<<<<<[
+                #B:lt/%8_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<[
                 -
-                >>>+
-                #B:lt/9_false
-            <<<]
+                >>+
+                #B:lt/%9_false
+            <<]
         <]
         >>#B:%8
         [
             -
             call @putchar(i8 108)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
             br label %10
-            >>>+
-        <<<]
+            
+            <<<<<<<<<<+
+        <<]
         >#B:%9
         [
             -
             call @putchar(i8 114)
-            enable next block when we return
-            >#caller/%call_term_for_3
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
             >>>>>>>>>#constop_114
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            <++
             .
             [-]
-        <<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
             br label %10
-            >+
+            
+            <<<<<<<<+
         <]
         >#B:%10
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            <<<<<<<<<<<<<<<<-
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>>]
-    <<<<<<<<]
+            <[-]
+            r1 
+            l8 
+        ]
+    <<<<<<]
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<<-
+            <<<<-
             #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/main
-            >>>>>>-
+            >>-
+            #dead_fn_pad/main
+            >>-
             l45 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%call_term_for_6
-        >#B:%call_term_for_7
-        >#B:%call_term_for_8
-        >#B:%3
-        >#B:%6
-        >#B:%7
-        >#B:%10
-        >#B:%call_term_for_13
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#alloc_%1
-        >#alloc_%2
-        >#%4_=_load_i32*_%1__align_4(mult)
-        >#%5_=_icmp_slt_i32_%4__i32_30(mult)
-        >#%8_=_load_i32*_%2__align_4(mult)
-        >#%9_=_icmp_slt_i32_%8__i32_30(mult)
-        >#%11_=_load_i32*_%1__align_4(mult)
-        >#%12_=_load_i32*_%2__align_4(mult)
-        >#%14_=_load_i32*_%2__align_4(mult)
-        >#%15_=_add_i32_%14__i32_1(mult)
-        >#%18_=_load_i32*_%1__align_4(mult)
-        >#%19_=_add_i32_%18__i32_1(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_main
+        #mainloop_main
+        #F:lt
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #B:%call_term_for_3
+        #B:%call_term_for_4
+        #B:%call_term_for_5
+        #B:%call_term_for_6
+        #B:%call_term_for_7
+        #B:%3
+        #B:%6
+        #B:%7
+        #B:%10
+        #B:%call_term_for_8
+        #B:%16
+        #B:%20
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i32*_%1__align_4(mult)
+        #%5_=_icmp_slt_i32_%4__i32_30(mult)
+        <#B:%no_block0_call_for_main
         [
             -
             copy up args
             br label %0
+            
             >>+
         <<]
         >>#B:%0
@@ -952,41 +310,42 @@ runtime init:
             %1 = alloca i32_ align 4
             %2 = alloca i32_ align 4
             call @lt(i32 1_ i32 2)
+            
             enable next block when we return
             >#caller/%call_term_for_0
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_1
+            #constop_1
             +
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_2
+            #constop_2
             ++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -995,41 +354,42 @@ runtime init:
         [
             -
             call @lt(i32 2_ i32 1)
+            
             enable next block when we return
             >#caller/%call_term_for_1
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_2
+            #constop_2
             ++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >>#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<#constop_1
+            #constop_1
             +
-            mov40/52 [->>>>>>>>>>>>+<<<<<<<<<<<<]
+            mov27/50 [->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1038,41 +398,42 @@ runtime init:
         [
             -
             call @lt(i32 3_ i32 4)
+            
             enable next block when we return
             >#caller/%call_term_for_2
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_3
+            #constop_3
             +++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_4
+            #constop_4
             ++++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1081,41 +442,42 @@ runtime init:
         [
             -
             call @lt(i32 3_ i32 3)
+            
             enable next block when we return
             >#caller/%call_term_for_3
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_3
+            #constop_3
             +++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_3
+            #constop_3
             +++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1124,41 +486,42 @@ runtime init:
         [
             -
             call @lt(i32 4_ i32 5)
+            
             enable next block when we return
             >#caller/%call_term_for_4
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_4
+            #constop_4
             ++++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_5
+            #constop_5
             +++++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1167,41 +530,42 @@ runtime init:
         [
             -
             call @lt(i32 5_ i32 6)
+            
             enable next block when we return
             >#caller/%call_term_for_5
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_5
+            #constop_5
             +++++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_6
+            #constop_6
             ++++++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1210,41 +574,42 @@ runtime init:
         [
             -
             call @lt(i32 6_ i32 7)
+            
             enable next block when we return
             >#caller/%call_term_for_6
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_6
+            #constop_6
             ++++++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_7
+            #constop_7
             +++++++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1253,41 +618,42 @@ runtime init:
         [
             -
             call @lt(i32 7_ i32 8)
+            
             enable next block when we return
             >#caller/%call_term_for_7
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            >>>>>>>>>>>>#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<#constop_7
+            #constop_7
             +++++++
-            mov38/53 [->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov25/51 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>>#arg_1
+            >#arg_1
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_8
+            #constop_8
             ++++++++
-            mov39/52 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            mov26/50 [->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
@@ -1296,51 +662,108 @@ runtime init:
         [
             -
             call @putchar(i8 10)
-            enable next block when we return
-            >#caller/%call_term_for_8
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>#constop_10
+            >>>>>>>>>>>>#constop_10
             ++++++++++
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<[-]
-            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<[-]
+            
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<<+
         <]
         >#B:%3
         [
             -
             %4 = load i32* %1_ align 4
-            >>>>>>>>>>>[-]
-            dup26/28/38 <<[->>+>>>>>>>>>>+<<<<<<<<<<<<]
-            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            >>>>>>>>>[-]
+            dup21/23/25 <<[->>+>>+<<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
             %5 = icmp slt i32 %4_ i32 30
-            <<<<<<<<<[-]
-            dup28/38/39 <[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov38/28 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            
+            <[-]
+            dup23/25/26 <[->>+>+<<<]
+            mov25/23 >>[-<<+>>]
             op_to_reg storing const value in temp address
-            >>>#constop_30
-            ++++++++++++++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov41/42 <<<[->+<]
-            >>>+
-            <<<<<[
+            >>#constop_30
+            +++++
+            [
+                -
+                >++++++
+            <]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/31 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/36 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov36/39 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
                 -
                 
-                >>>[
+                >>>>>>>>[
                     -
                     
                     r1 >
@@ -1350,62 +773,128 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<]
-            >>>>>-
+            <<<<<<<<]
+            >>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>]
             br i1 %5_ label %6_ label %20
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/26/25 <<<<<<<<<<<<<<<[->>+<+<]
+            mov26/24 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>+
-                #B:main/6_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%6_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>+
-                #B:main/20_false
-            <<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>]
+                >>>>>>>>>>>>>>>>+
+                #B:main/%20_false
+            <<<<<<<<<<<<<<<<]
+        >>>>>>>>>>]
         >#B:%6
         [
             -
             store i32 0_ i32* %2_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<[-]
-            mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >>>>>>>[-]
+            
             br label %7
-            <<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<+
         <]
         >#B:%7
         [
             -
             %8 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup27/30/38 <<<[->>>+>>>>>>>>+<<<<<<<<<<<]
-            mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            
+            >>>>>>>[-]
+            dup22/23/25 <[->+>>+<<<]
+            mov25/22 >>>[-<<<+>>>]
             %9 = icmp slt i32 %8_ i32 30
-            <<<<<<<[-]
-            dup30/38/39 <[->>>>>>>>+>+<<<<<<<<<]
-            mov38/30 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            
+            <[-]
+            dup23/25/26 <[->>+>+<<<]
+            mov25/23 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_30
-            ++++++++++++++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/41 <<<[->+<]
-            >>>+
+            >+++++
+            [
+                -
+                <++++++
+            >]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/31 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
             <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/36 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
                 -
-                
+                <+
                 >>[
                     -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov36/42 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                
+                >>>>>>>>>>>[
+                    -
                     
                     r1 >
                 ]
@@ -1414,165 +903,200 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
             br i1 %9_ label %10_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/26/25 <<<<<<<<<<<<<<<<<<[->>+<+<]
+            mov26/24 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>+
-                #B:main/10_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>+
+                #B:main/%10_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>+
-                #B:main/16_false
-            <<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>>+
+                #B:main/%16_false
+            <<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>]
         >#B:%10
         [
             -
             %11 = load i32* %1_ align 4
-            >>>>>>>>>>>>[-]
-            dup26/32/38 <<<<<<[->>>>>>+>>>>>>+<<<<<<<<<<<<]
-            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            >>>>>>[-]
+            dup21/23/27 <<[->>+>>>>+<<<<<<]
+            mov27/21 >>>>>>[-<<<<<<+>>>>>>]
             %12 = load i32* %2_ align 4
-            <<<<<[-]
-            dup27/33/38 <<<<<<[->>>>>>+>>>>>+<<<<<<<<<<<]
-            mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            
+            <<<[-]
+            dup22/24/25 <<[->>+>+<<<]
+            mov25/22 >>>[-<<<+>>>]
             call @lt(i32 %11_ i32 %12)
+            
             enable next block when we return
-            <<<<<<<<<<<<<<<<<#caller/%call_term_for_13
+            <<<<<<<#caller/%call_term_for_8
             +
             stack_width 45
-            ret_pad_width 6
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup32/38/39 <<<<<<<<<<<<<<<<<<<<<[->>>>>>+>+<<<<<<<]
-            mov38/32 >>>>>>[-<<<<<<+>>>>>>]
-            mov39/53 >[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            >>>>>#arg_0
+            dup23/25/26 [->>+>+<<<]
+            mov25/23 >>[-<<+>>]
+            mov26/51 >[->>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]
             copy up arg 1
-            >>>>>>>>>>>>>#arg_1
-            dup33/40/41 <<<<<<<<<<<<<<<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov40/33 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov41/52 >[->>>>>>>>>>>+<<<<<<<<<<<]
+            #arg_1
+            dup24/27/28 <<[->>>+>+<<<<]
+            mov27/24 >>>[-<<<+>>>]
+            mov28/50 >[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++
             l1 <
-            dup0/55/56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov56/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r45 
+            <<<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
+            r8 >>>>
+            #===FRAME_lt
             +
-            >>>#lt
+            >#lt
             +
             >>#lt/b0
             +
-        >>>>>>>>>>>>>>>]
-        >#B:%call_term_for_13
-        [
-            -
-            br label %13
-            >+
-        <]
-        >#B:%13
+        >>>>>>>>>>>>>>]
+        >#B:%call_term_for_8
         [
             -
             %14 = load i32* %2_ align 4
-            >>>>>>>>>>>>[-]
-            dup27/34/38 <<<<<<<[->>>>>>>+>>>>+<<<<<<<<<<<]
-            mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            
+            >>>>>[-]
+            dup22/23/25 <[->+>>+<<<]
+            mov25/22 >>>[-<<<+>>>]
             %15 = add i32 %14_ i32 1
-            <<<[-]
-            dup34/38/39 <[->>>>+>+<<<<<]
-            mov38/34 >>>>[-<<<<+>>>>]
+            
+            <[-]
+            dup23/25/26 <[->>+>+<<<]
+            mov25/23 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov39/35 <[-<<<<+>>>>]
-            >[
+            mov26/24 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup24/29/31 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov31/24 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
                 -
-                <<<<<+
-            >>>>>]
+                >>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %15_ i32* %2_ align 4
-            dup35/38/39 <<<<<[->>>+>+<<<<]
-            mov38/35 >>>[-<<<+>>>]
-            <<<<<<<<<<<[-]
-            mov39/27 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            dup24/25/26 <<<<[->+>+<<]
+            mov25/24 >[-<+>]
+            <<<[-]
+            mov26/22 >>>>[-<<<<+>>>>]
             br label %7
-            <<<<<<<<<<<<<<<<<<<<+
-        >>>]
+            
+            <<<<<<<<<<+
+        >>]
         >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
         [
             -
             %18 = load i32* %1_ align 4
-            >>>>>>>>>>>>[-]
-            dup26/36/38 <<<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
-            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            >>>>[-]
+            dup21/23/25 <<[->>+>>+<<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
             %19 = add i32 %18_ i32 1
+            
             <[-]
-            dup36/38/39 <[->>+>+<<<]
-            mov38/36 >>[-<<+>>]
+            dup23/25/26 <[->>+>+<<<]
+            mov25/23 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov39/37 <[-<<+>>]
-            >[
+            mov26/24 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup24/29/31 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
                 -
-                <<<+
-            >>>]
+                <-
+            >]
+            mov31/24 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %19_ i32* %1_ align 4
-            dup37/38/39 <<<[->+>+<<]
-            mov38/37 >[-<+>]
-            <<<<<<<<<<<<[-]
-            mov39/26 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            
+            dup24/25/26 <<<<[->+>+<<]
+            mov25/24 >[-<+>]
+            <<<<[-]
+            mov26/21 >>>>>[-<<<<<+>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<+
-        >>>>>>>]
+            
+            <<<<<<<<<<<<+
+        >>>>>]
         >#B:%20
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<<<<<<<<<<-
             #dead_frame
-            >>>>-
+            >>-
             l1 <
-            <<<<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<<<<<<]
-<<<<]
+            <<[-]
+            r1 
+            l6 
+        >>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<]
+<<]
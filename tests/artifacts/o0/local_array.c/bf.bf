@@ -0,0 +1,131 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 369 ops ~1566 steps
+frame widths:
+  main: 11 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l11 <<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1_0
+        #alloc_%1_2
+        #%5_=_load_i8*_%1_2__align_1(mult)
+        #%7_=_load_i8*_%1_0__align_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1_0 = alloca i8_ align 1
+            %1_2 = alloca i8_ align 1
+            store i8 120_ i8* %1_2_ align 1
+            
+            >>>[-]
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++++
+            >>>]
+            store i8 97_ i8* %1_0_ align 1
+            
+            <<<<[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            %5 = load i8* %1_2_ align 1
+            
+            >>[-]
+            known to be 120
+            >>++++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_120
+            >>++++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<.
+            [-]
+            %7 = load i8* %1_0_ align 1
+            
+            <[-]
+            dup4/7/8 <<<[->>>+>+<<<<]
+            mov8/4 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup7/8/9 <[->+>+<<]
+            mov8/7 >[-<+>]
+            >.
+            [-]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_120
+            ++++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
+            [-]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup7/8/9 <[->+>+<<]
+            mov8/7 >[-<+>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,280 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1418 ops ~15593 steps
+frame widths:
+  main: 25 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%1{1}
+        #alloc_%1{2}
+        #alloc_%1{3}
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%2__align_1(mult)
+        #%4_=_zext_i8_%3_to_i64(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca {4 x i8}_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 2_ i8* %2_ align 1
+            
+            >>>>>>[-]
+            ++
+            %3 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 2
+            ++
+            %4 = zext i8 %3 to i64
+            
+            >[-]
+            known to be 2
+            ++
+            %5 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 %4
+            
+            <[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/12/13 <<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <+++++
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            mov11/9 <<[-<<+>>]
+            >>[
+                -
+                <<<<+
+            >>>>]
+            store i8 120_ i8* %5_ align 1
+            
+            op_to_reg storing const value in temp address
+            <#constop_120
+            ++++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            dup9/14/13 <<<[->>>>>+<+<<<<]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/20/21 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/22 [->>+<<]
+            >>>>+
+            <<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<]
+            >>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov22/17 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov11/18 <<<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov16/15 <[-<+>]
+                mov17/16 >[-<+>]
+                mov18/17 >[-<+>]
+                mov14/18 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov18/14 >>>>[-<<<<+>>>>]
+            <<[
+                mov19/15 >>>[-<<<<+>>>>]
+                
+                mov16/17 <<<[->+<]
+                r1 >
+                -
+            ]
+            %6 = load volatile i8* %2_ align 1
+            
+            <<<<<<<[-]
+            dup8/9/11 <[->+>>+<<<]
+            mov11/8 >>>[-<<<+>>>]
+            %7 = zext i8 %6 to i64
+            
+            <[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            mov12/10 >[-<<+>>]
+            %8 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 %7
+            
+            <<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/12/13 <<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <+++++
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov11/9 <<[-<<+>>]
+            >>>[
+                -
+                <<<<<+
+            >>>>>]
+            %9 = load i8* %8_ align 1
+            
+            <<<<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            >[
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
+                r1 >
+                -
+            ]
+            mov15/10 >[-<<<<<+>>>>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            dup10/11/12 <<<<<[->+>+<<]
+            mov11/10 >[-<+>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
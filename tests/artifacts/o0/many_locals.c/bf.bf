@@ -0,0 +1,1575 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  row (depth 1)
+  main calls row (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  row: 17344 ops ~610685 steps
+  main: 1203 ops ~4563 steps
+frame widths:
+  row: 39 cells
+  main: 15 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#row
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/row
+            >>>-
+            l39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_row
+        #F:row
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #alloc_%6
+        #alloc_%7
+        #alloc_%8
+        #alloc_%9
+        #alloc_%10
+        #alloc_%11
+        #alloc_%12
+        #alloc_%13
+        #alloc_%14
+        #alloc_%15
+        #alloc_%16
+        #alloc_%17
+        #alloc_%18
+        #alloc_%19
+        #alloc_%20
+        #alloc_%21
+        #alloc_%22
+        #%23_=_load_volatile_i8*_%3__align_1(mult)
+        #%24_=_zext_i8_%23_to_i32(mult)
+        #%26_=_zext_i8_%25_to_i32(mult)
+        #%27_=_sub_i32_%24__i32_%26(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/32 <<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            %6 = alloca i8_ align 1
+            %7 = alloca i8_ align 1
+            %8 = alloca i8_ align 1
+            %9 = alloca i8_ align 1
+            %10 = alloca i8_ align 1
+            %11 = alloca i8_ align 1
+            %12 = alloca i8_ align 1
+            %13 = alloca i8_ align 1
+            %14 = alloca i8_ align 1
+            %15 = alloca i8_ align 1
+            %16 = alloca i8_ align 1
+            %17 = alloca i8_ align 1
+            %18 = alloca i8_ align 1
+            %19 = alloca i8_ align 1
+            %20 = alloca i8_ align 1
+            %21 = alloca i8_ align 1
+            %22 = alloca i8_ align 1
+            store i8 %0_ i8* %2_ align 1
+            
+            dup30/31/32 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[->+>+<<]
+            mov31/30 >[-<+>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            mov32/5 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 97_ i8* %3_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            store volatile i8 98_ i8* %4_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 99_ i8* %5_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 100_ i8* %6_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 101_ i8* %7_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<+
+            store volatile i8 102_ i8* %8_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<++
+            store volatile i8 103_ i8* %9_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+++
+            store volatile i8 104_ i8* %10_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<+++++++++++++
+            >>>>>>>>>>>>>>>>>>]
+            store volatile i8 105_ i8* %11_ align 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 106_ i8* %12_ align 1
+            
+            <<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<+
+            store volatile i8 107_ i8* %13_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<++
+            store volatile i8 108_ i8* %14_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>]
+            store volatile i8 109_ i8* %15_ align 1
+            
+            <<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<+
+            store volatile i8 110_ i8* %16_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>]
+            store volatile i8 111_ i8* %17_ align 1
+            
+            <<<<<<<<<<<[-]
+            >>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>]
+            <<<<<<<<<<<+
+            store volatile i8 112_ i8* %18_ align 1
+            
+            >[-]
+            >>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<++++++++++++++
+            >>>>>>>>>>]
+            store volatile i8 113_ i8* %19_ align 1
+            
+            <<<<<<<<<[-]
+            >>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<++++++++++++++
+            >>>>>>>>>]
+            <<<<<<<<<+
+            store volatile i8 114_ i8* %20_ align 1
+            
+            >[-]
+            >>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<++++++++++++++
+            >>>>>>>>]
+            <<<<<<<<++
+            store volatile i8 115_ i8* %21_ align 1
+            
+            >[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++++++++
+            >>>>>>>]
+            <<<<<<<+++
+            store volatile i8 116_ i8* %22_ align 1
+            
+            >[-]
+            >>>>>>++++++++
+            [
+                -
+                <<<<<<++++++++++++++
+            >>>>>>]
+            <<<<<<++++
+            %23 = load volatile i8* %3_ align 1
+            
+            >[-]
+            known to be 97
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            %24 = zext i8 %23 to i32
+            
+            >[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            %25 = load i8* %2_ align 1
+            
+            <[-]
+            dup5/26/31 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov31/5 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %26 = zext i8 %25 to i32
+            
+            <<<[-]
+            dup26/31/33 <<[->>>>>+>>+<<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov33/28 >>[-<<<<<+>>>>>]
+            %27 = sub i32 %24_ i32 %26
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            dup28/34/33 <<<[->>>>>>+<+<<<<<]
+            mov34/28 >>>>>>[-<<<<<<+>>>>>>]
+            mov31/29 <<<[-<<+>>]
+            >>[
+                -
+                >>>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<<]
+            %28 = trunc i32 %27 to i8
+            
+            <<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %28)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %29 = load volatile i8* %4_ align 1
+            
+            <<<<<[-]
+            dup7/27/31 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov31/7 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            %30 = zext i8 %29 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %32 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %33 = sub i32 %30_ i32 %32
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>>>+
+                <<<<<<<<<[
+                    -
+                    mov29/37 [->>>>>>>>+<<<<<<<<]
+                    >>>>>>>>>-
+                <<<<<<<<<]
+                mov37/29 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>]
+            <<<<]
+            %34 = trunc i32 %33 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %34)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %35 = load volatile i8* %5_ align 1
+            
+            <<<<<[-]
+            dup8/27/31 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov31/8 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %36 = zext i8 %35 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %38 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %39 = sub i32 %36_ i32 %38
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %40 = trunc i32 %39 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %40)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %41 = load volatile i8* %6_ align 1
+            
+            <<<<<[-]
+            dup9/27/31 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov31/9 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            %42 = zext i8 %41 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %44 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %45 = sub i32 %42_ i32 %44
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %46 = trunc i32 %45 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %46)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %47 = load volatile i8* %7_ align 1
+            
+            <<<<<[-]
+            dup10/27/31 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            mov31/10 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            %48 = zext i8 %47 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %50 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %51 = sub i32 %48_ i32 %50
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %52 = trunc i32 %51 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %52)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %53 = load volatile i8* %8_ align 1
+            
+            <<<<<[-]
+            dup11/27/31 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<]
+            mov31/11 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            %54 = zext i8 %53 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %56 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %57 = sub i32 %54_ i32 %56
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %58 = trunc i32 %57 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %58)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %59 = load volatile i8* %9_ align 1
+            
+            <<<<<[-]
+            dup12/27/31 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<]
+            mov31/12 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            %60 = zext i8 %59 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %62 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %63 = sub i32 %60_ i32 %62
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %64 = trunc i32 %63 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %64)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %65 = load volatile i8* %10_ align 1
+            
+            <<<<<[-]
+            dup13/27/31 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<]
+            mov31/13 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            %66 = zext i8 %65 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %68 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %69 = sub i32 %66_ i32 %68
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %70 = trunc i32 %69 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %70)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %71 = load volatile i8* %11_ align 1
+            
+            <<<<<[-]
+            dup14/27/31 <<<<<<<<<<<<<[->>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<]
+            mov31/14 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %72 = zext i8 %71 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %74 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %75 = sub i32 %72_ i32 %74
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %76 = trunc i32 %75 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %76)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %77 = load volatile i8* %12_ align 1
+            
+            <<<<<[-]
+            dup15/27/31 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<]
+            mov31/15 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            %78 = zext i8 %77 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %80 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %81 = sub i32 %78_ i32 %80
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %82 = trunc i32 %81 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %82)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %83 = load volatile i8* %13_ align 1
+            
+            <<<<<[-]
+            dup16/27/31 <<<<<<<<<<<[->>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<]
+            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %84 = zext i8 %83 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %86 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %87 = sub i32 %84_ i32 %86
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %88 = trunc i32 %87 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %88)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %89 = load volatile i8* %14_ align 1
+            
+            <<<<<[-]
+            dup17/27/31 <<<<<<<<<<[->>>>>>>>>>+>>>>+<<<<<<<<<<<<<<]
+            mov31/17 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            %90 = zext i8 %89 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %92 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %93 = sub i32 %90_ i32 %92
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %94 = trunc i32 %93 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %94)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %95 = load volatile i8* %15_ align 1
+            
+            <<<<<[-]
+            dup18/27/31 <<<<<<<<<[->>>>>>>>>+>>>>+<<<<<<<<<<<<<]
+            mov31/18 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            %96 = zext i8 %95 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %98 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %99 = sub i32 %96_ i32 %98
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %100 = trunc i32 %99 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %100)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %101 = load volatile i8* %16_ align 1
+            
+            <<<<<[-]
+            dup19/27/31 <<<<<<<<[->>>>>>>>+>>>>+<<<<<<<<<<<<]
+            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            %102 = zext i8 %101 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %104 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %105 = sub i32 %102_ i32 %104
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %106 = trunc i32 %105 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %106)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %107 = load volatile i8* %17_ align 1
+            
+            <<<<<[-]
+            dup20/27/31 <<<<<<<[->>>>>>>+>>>>+<<<<<<<<<<<]
+            mov31/20 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %108 = zext i8 %107 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %110 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %111 = sub i32 %108_ i32 %110
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %112 = trunc i32 %111 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %112)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %113 = load volatile i8* %18_ align 1
+            
+            <<<<<[-]
+            dup21/27/31 <<<<<<[->>>>>>+>>>>+<<<<<<<<<<]
+            mov31/21 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %114 = zext i8 %113 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %116 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %117 = sub i32 %114_ i32 %116
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %118 = trunc i32 %117 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %118)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %119 = load volatile i8* %19_ align 1
+            
+            <<<<<[-]
+            dup22/27/31 <<<<<[->>>>>+>>>>+<<<<<<<<<]
+            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %120 = zext i8 %119 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %122 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %123 = sub i32 %120_ i32 %122
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %124 = trunc i32 %123 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %124)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %125 = load volatile i8* %20_ align 1
+            
+            <<<<<[-]
+            dup23/27/31 <<<<[->>>>+>>>>+<<<<<<<<]
+            mov31/23 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %126 = zext i8 %125 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %128 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %129 = sub i32 %126_ i32 %128
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %130 = trunc i32 %129 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %130)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %131 = load volatile i8* %21_ align 1
+            
+            <<<<<[-]
+            dup24/27/31 <<<[->>>+>>>>+<<<<<<<]
+            mov31/24 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %132 = zext i8 %131 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %134 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %135 = sub i32 %132_ i32 %134
+            
+            <<<[-]
+            dup28/31/32 <[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/29 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov29/35 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %136 = trunc i32 %135 to i8
+            
+            <<<<<<<[-]
+            dup29/31/32 >>[->>+>+<<<]
+            mov31/29 >>[-<<+>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %136)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %137 = load volatile i8* %22_ align 1
+            
+            <<<<<[-]
+            dup25/27/31 <<[->>+>>>>+<<<<<<]
+            mov31/25 >>>>>>[-<<<<<<+>>>>>>]
+            %138 = zext i8 %137 to i32
+            
+            <<<[-]
+            dup27/31/32 <[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            mov32/28 >[-<<<<+>>>>]
+            %140 = zext i8 %25 to i32
+            
+            <<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            %141 = sub i32 %138_ i32 %140
+            
+            <<<<<<[-]
+            dup28/31/32 >>[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            dup27/33/34 <<<<[->>>>>>+>+<<<<<<<]
+            mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            mov32/26 <[-<<<<<<+>>>>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<<<<[
+                    -
+                    mov26/35 [->>>>>>>>>+<<<<<<<<<]
+                    >>>>>>>>>>-
+                <<<<<<<<<<]
+                mov35/26 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>>]
+            <<]
+            %142 = trunc i32 %141 to i8
+            
+            <<<<<<<[-]
+            dup26/31/32 <[->>>>>+>+<<<<<<]
+            mov31/26 >>>>>[-<<<<<+>>>>>]
+            mov32/27 >[-<<<<<+>>>>>]
+            call @putchar(i8 %142)
+            
+            putchar intrinsic
+            dup27/31/32 <<<<<[->>>>+>+<<<<<]
+            mov31/27 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 <<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:row
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 10_ i8* %1_ align 1
+            
+            >>>>[-]
+            ++++++++++
+            store volatile i8 32_ i8* %2_ align 1
+            
+            >[-]
+            >>++++
+            [
+                -
+                <<++++++++
+            >>]
+            call @row(i8 0)
+            
+            enable next block when we return
+            <<<<<<#caller/%call_term_for_0
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov13/20 [->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_row
+            +
+            >#row
+            +
+            >>#row/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %3 = load volatile i8* %1_ align 1
+            
+            >>>>>[-]
+            dup9/11/12 <<[->>+>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup11/12/14 <[->+>>+<<<]
+            mov12/11 >[-<+>]
+            >>.
+            [-]
+            %4 = load volatile i8* %2_ align 1
+            
+            <<<[-]
+            dup10/11/12 <[->+>+<<]
+            mov12/10 >>[-<<+>>]
+            call @row(i8 %4)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_1
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>#arg_0
+            dup11/12/13 [->+>+<<]
+            mov12/11 >[-<+>]
+            mov13/20 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_row
+            +
+            >#row
+            +
+            >>#row/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            %5 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            dup9/11/12 <<[->>+>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup11/12/13 <[->+>+<<]
+            mov12/11 >[-<+>]
+            >.
+            [-]
+            call @row(i8 0)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_2
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_row
+            +
+            >#row
+            +
+            >>#row/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>]
+    <<<<<<]
+<<]
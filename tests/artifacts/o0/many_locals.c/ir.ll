@@ -0,0 +1,307 @@
+; ModuleID = './tests/artifacts/o0/many_locals.c/ir.bc'
+source_filename = "./tests/cases/many_locals.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @row(i8 noundef zeroext %0) #0 {
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  %8 = alloca i8, align 1
+  %9 = alloca i8, align 1
+  %10 = alloca i8, align 1
+  %11 = alloca i8, align 1
+  %12 = alloca i8, align 1
+  %13 = alloca i8, align 1
+  %14 = alloca i8, align 1
+  %15 = alloca i8, align 1
+  %16 = alloca i8, align 1
+  %17 = alloca i8, align 1
+  %18 = alloca i8, align 1
+  %19 = alloca i8, align 1
+  %20 = alloca i8, align 1
+  %21 = alloca i8, align 1
+  %22 = alloca i8, align 1
+  store i8 %0, i8* %2, align 1
+  store volatile i8 97, i8* %3, align 1
+  store volatile i8 98, i8* %4, align 1
+  store volatile i8 99, i8* %5, align 1
+  store volatile i8 100, i8* %6, align 1
+  store volatile i8 101, i8* %7, align 1
+  store volatile i8 102, i8* %8, align 1
+  store volatile i8 103, i8* %9, align 1
+  store volatile i8 104, i8* %10, align 1
+  store volatile i8 105, i8* %11, align 1
+  store volatile i8 106, i8* %12, align 1
+  store volatile i8 107, i8* %13, align 1
+  store volatile i8 108, i8* %14, align 1
+  store volatile i8 109, i8* %15, align 1
+  store volatile i8 110, i8* %16, align 1
+  store volatile i8 111, i8* %17, align 1
+  store volatile i8 112, i8* %18, align 1
+  store volatile i8 113, i8* %19, align 1
+  store volatile i8 114, i8* %20, align 1
+  store volatile i8 115, i8* %21, align 1
+  store volatile i8 116, i8* %22, align 1
+  %23 = load volatile i8, i8* %3, align 1
+  %24 = zext i8 %23 to i32
+  %25 = load i8, i8* %2, align 1
+  %26 = zext i8 %25 to i32
+  %27 = sub nsw i32 %24, %26
+  %28 = trunc i32 %27 to i8
+  call void @putchar(i8 noundef zeroext %28)
+  %29 = load volatile i8, i8* %4, align 1
+  %30 = zext i8 %29 to i32
+  %31 = load i8, i8* %2, align 1
+  %32 = zext i8 %31 to i32
+  %33 = sub nsw i32 %30, %32
+  %34 = trunc i32 %33 to i8
+  call void @putchar(i8 noundef zeroext %34)
+  %35 = load volatile i8, i8* %5, align 1
+  %36 = zext i8 %35 to i32
+  %37 = load i8, i8* %2, align 1
+  %38 = zext i8 %37 to i32
+  %39 = sub nsw i32 %36, %38
+  %40 = trunc i32 %39 to i8
+  call void @putchar(i8 noundef zeroext %40)
+  %41 = load volatile i8, i8* %6, align 1
+  %42 = zext i8 %41 to i32
+  %43 = load i8, i8* %2, align 1
+  %44 = zext i8 %43 to i32
+  %45 = sub nsw i32 %42, %44
+  %46 = trunc i32 %45 to i8
+  call void @putchar(i8 noundef zeroext %46)
+  %47 = load volatile i8, i8* %7, align 1
+  %48 = zext i8 %47 to i32
+  %49 = load i8, i8* %2, align 1
+  %50 = zext i8 %49 to i32
+  %51 = sub nsw i32 %48, %50
+  %52 = trunc i32 %51 to i8
+  call void @putchar(i8 noundef zeroext %52)
+  %53 = load volatile i8, i8* %8, align 1
+  %54 = zext i8 %53 to i32
+  %55 = load i8, i8* %2, align 1
+  %56 = zext i8 %55 to i32
+  %57 = sub nsw i32 %54, %56
+  %58 = trunc i32 %57 to i8
+  call void @putchar(i8 noundef zeroext %58)
+  %59 = load volatile i8, i8* %9, align 1
+  %60 = zext i8 %59 to i32
+  %61 = load i8, i8* %2, align 1
+  %62 = zext i8 %61 to i32
+  %63 = sub nsw i32 %60, %62
+  %64 = trunc i32 %63 to i8
+  call void @putchar(i8 noundef zeroext %64)
+  %65 = load volatile i8, i8* %10, align 1
+  %66 = zext i8 %65 to i32
+  %67 = load i8, i8* %2, align 1
+  %68 = zext i8 %67 to i32
+  %69 = sub nsw i32 %66, %68
+  %70 = trunc i32 %69 to i8
+  call void @putchar(i8 noundef zeroext %70)
+  %71 = load volatile i8, i8* %11, align 1
+  %72 = zext i8 %71 to i32
+  %73 = load i8, i8* %2, align 1
+  %74 = zext i8 %73 to i32
+  %75 = sub nsw i32 %72, %74
+  %76 = trunc i32 %75 to i8
+  call void @putchar(i8 noundef zeroext %76)
+  %77 = load volatile i8, i8* %12, align 1
+  %78 = zext i8 %77 to i32
+  %79 = load i8, i8* %2, align 1
+  %80 = zext i8 %79 to i32
+  %81 = sub nsw i32 %78, %80
+  %82 = trunc i32 %81 to i8
+  call void @putchar(i8 noundef zeroext %82)
+  %83 = load volatile i8, i8* %13, align 1
+  %84 = zext i8 %83 to i32
+  %85 = load i8, i8* %2, align 1
+  %86 = zext i8 %85 to i32
+  %87 = sub nsw i32 %84, %86
+  %88 = trunc i32 %87 to i8
+  call void @putchar(i8 noundef zeroext %88)
+  %89 = load volatile i8, i8* %14, align 1
+  %90 = zext i8 %89 to i32
+  %91 = load i8, i8* %2, align 1
+  %92 = zext i8 %91 to i32
+  %93 = sub nsw i32 %90, %92
+  %94 = trunc i32 %93 to i8
+  call void @putchar(i8 noundef zeroext %94)
+  %95 = load volatile i8, i8* %15, align 1
+  %96 = zext i8 %95 to i32
+  %97 = load i8, i8* %2, align 1
+  %98 = zext i8 %97 to i32
+  %99 = sub nsw i32 %96, %98
+  %100 = trunc i32 %99 to i8
+  call void @putchar(i8 noundef zeroext %100)
+  %101 = load volatile i8, i8* %16, align 1
+  %102 = zext i8 %101 to i32
+  %103 = load i8, i8* %2, align 1
+  %104 = zext i8 %103 to i32
+  %105 = sub nsw i32 %102, %104
+  %106 = trunc i32 %105 to i8
+  call void @putchar(i8 noundef zeroext %106)
+  %107 = load volatile i8, i8* %17, align 1
+  %108 = zext i8 %107 to i32
+  %109 = load i8, i8* %2, align 1
+  %110 = zext i8 %109 to i32
+  %111 = sub nsw i32 %108, %110
+  %112 = trunc i32 %111 to i8
+  call void @putchar(i8 noundef zeroext %112)
+  %113 = load volatile i8, i8* %18, align 1
+  %114 = zext i8 %113 to i32
+  %115 = load i8, i8* %2, align 1
+  %116 = zext i8 %115 to i32
+  %117 = sub nsw i32 %114, %116
+  %118 = trunc i32 %117 to i8
+  call void @putchar(i8 noundef zeroext %118)
+  %119 = load volatile i8, i8* %19, align 1
+  %120 = zext i8 %119 to i32
+  %121 = load i8, i8* %2, align 1
+  %122 = zext i8 %121 to i32
+  %123 = sub nsw i32 %120, %122
+  %124 = trunc i32 %123 to i8
+  call void @putchar(i8 noundef zeroext %124)
+  %125 = load volatile i8, i8* %20, align 1
+  %126 = zext i8 %125 to i32
+  %127 = load i8, i8* %2, align 1
+  %128 = zext i8 %127 to i32
+  %129 = sub nsw i32 %126, %128
+  %130 = trunc i32 %129 to i8
+  call void @putchar(i8 noundef zeroext %130)
+  %131 = load volatile i8, i8* %21, align 1
+  %132 = zext i8 %131 to i32
+  %133 = load i8, i8* %2, align 1
+  %134 = zext i8 %133 to i32
+  %135 = sub nsw i32 %132, %134
+  %136 = trunc i32 %135 to i8
+  call void @putchar(i8 noundef zeroext %136)
+  %137 = load volatile i8, i8* %22, align 1
+  %138 = zext i8 %137 to i32
+  %139 = load i8, i8* %2, align 1
+  %140 = zext i8 %139 to i32
+  %141 = sub nsw i32 %138, %140
+  %142 = trunc i32 %141 to i8
+  call void @putchar(i8 noundef zeroext %142)
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @main() #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  store volatile i8 10, i8* %1, align 1
+  store volatile i8 32, i8* %2, align 1
+  call void @row(i8 noundef zeroext 0)
+  %3 = load volatile i8, i8* %1, align 1
+  call void @putchar(i8 noundef zeroext %3)
+  %4 = load volatile i8, i8* %2, align 1
+  call void @row(i8 noundef zeroext %4)
+  %5 = load volatile i8, i8* %1, align 1
+  call void @putchar(i8 noundef zeroext %5)
+  call void @row(i8 noundef zeroext 0)
+  ret void
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
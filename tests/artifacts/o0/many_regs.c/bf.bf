@@ -0,0 +1,1254 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 10049 ops ~52301 steps
+frame widths:
+  main: 49 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l49 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%55
+        #B:%57
+        #B:%59
+        #B:%63
+        #B:%65
+        #B:%71
+        #B:%72
+        #B:%74
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #alloc_%6
+        #alloc_%7
+        #alloc_%8
+        #alloc_%9
+        #alloc_%10
+        #alloc_%11
+        #alloc_%12
+        #alloc_%13
+        #alloc_%14
+        #alloc_%15
+        #alloc_%16
+        #alloc_%17
+        #%18_=_load_volatile_i8*_%1__align_1(mult)
+        #%19_=_add_i8_%18__i8_1(mult)
+        #%53_=_zext_i8_%52_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            %6 = alloca i8_ align 1
+            %7 = alloca i8_ align 1
+            %8 = alloca i8_ align 1
+            %9 = alloca i8_ align 1
+            %10 = alloca i8_ align 1
+            %11 = alloca i8_ align 1
+            %12 = alloca i8_ align 1
+            %13 = alloca i8_ align 1
+            %14 = alloca i8_ align 1
+            %15 = alloca i8_ align 1
+            %16 = alloca i8_ align 1
+            %17 = alloca i8_ align 1
+            store volatile i8 97_ i8* %1_ align 1
+            
+            >>>>>>>>>>[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+
+            %18 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>>>>>>>[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            %19 = add i8 %18_ i8 1
+            
+            >[-]
+            known to be 98
+            >>+++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            store volatile i8 %19_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %18_ i8* %2_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %20 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>>>>>>[-]
+            known to be 98
+            >>>+++++++
+            [
+                -
+                <<<++++++++++++++
+            >>>]
+            %21 = add i8 %20_ i8 1
+            
+            <<[-]
+            known to be 99
+            >>+++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            store volatile i8 %21_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %20_ i8* %3_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>]
+            %22 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 99
+            >>>+++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %23 = add i8 %22_ i8 1
+            
+            <<[-]
+            known to be 100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            store volatile i8 %23_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %22_ i8* %4_ align 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>]
+            %24 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 100
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            %25 = add i8 %24_ i8 1
+            
+            <<[-]
+            known to be 101
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<+
+            store volatile i8 %25_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+
+            store i8 %24_ i8* %5_ align 1
+            
+            >>>>[-]
+            >>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>]
+            %26 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 101
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            <<<+
+            %27 = add i8 %26_ i8 1
+            
+            >[-]
+            known to be 102
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<++
+            store volatile i8 %27_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<++
+            store i8 %26_ i8* %6_ align 1
+            
+            >>>>>[-]
+            >>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<+
+            %28 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>>[-]
+            known to be 102
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            <<<++
+            %29 = add i8 %28_ i8 1
+            
+            >[-]
+            known to be 103
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<+++
+            store volatile i8 %29_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+++
+            store i8 %28_ i8* %7_ align 1
+            
+            >>>>>>[-]
+            >>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<++
+            %30 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>[-]
+            known to be 103
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            <<<+++
+            %31 = add i8 %30_ i8 1
+            
+            >[-]
+            known to be 104
+            >>++++++++
+            [
+                -
+                <<+++++++++++++
+            >>]
+            store volatile i8 %31_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %30_ i8* %8_ align 1
+            
+            <<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<+++
+            %32 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>[-]
+            known to be 104
+            >>>++++++++
+            [
+                -
+                <<<+++++++++++++
+            >>>]
+            %33 = add i8 %32_ i8 1
+            
+            <<[-]
+            known to be 105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            store volatile i8 %33_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %32_ i8* %9_ align 1
+            
+            <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<+++++++++++++
+            >>>>>>>>>>>>]
+            %34 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 105
+            >>>+++++++
+            [
+                -
+                <<<+++++++++++++++
+            >>>]
+            %35 = add i8 %34_ i8 1
+            
+            <<[-]
+            known to be 106
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            <<+
+            store volatile i8 %35_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+
+            store i8 %34_ i8* %10_ align 1
+            
+            >>>>>>>>>[-]
+            >>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>]
+            %36 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 106
+            >>>+++++++
+            [
+                -
+                <<<+++++++++++++++
+            >>>]
+            <<<+
+            %37 = add i8 %36_ i8 1
+            
+            >[-]
+            known to be 107
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            <<++
+            store volatile i8 %37_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<++
+            store i8 %36_ i8* %11_ align 1
+            
+            >>>>>>>>>>[-]
+            >>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>]
+            <<<<<<<<<<+
+            %38 = load volatile i8* %1_ align 1
+            
+            >>>>>>>[-]
+            known to be 107
+            >>>+++++++
+            [
+                -
+                <<<+++++++++++++++
+            >>>]
+            <<<++
+            %39 = add i8 %38_ i8 1
+            
+            >[-]
+            known to be 108
+            >>+++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            store volatile i8 %39_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %38_ i8* %12_ align 1
+            
+            <<<<<<<<<[-]
+            >>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<+++++++++++++++
+            >>>>>>>>>]
+            <<<<<<<<<++
+            %40 = load volatile i8* %1_ align 1
+            
+            >>>>>>[-]
+            known to be 108
+            >>>+++++++++
+            [
+                -
+                <<<++++++++++++
+            >>>]
+            %41 = add i8 %40_ i8 1
+            
+            <<[-]
+            known to be 109
+            >>+++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            store volatile i8 %41_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+
+            store i8 %40_ i8* %13_ align 1
+            
+            >>>>>>>>>>>>[-]
+            >>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<++++++++++++
+            >>>>>>>>]
+            %42 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 109
+            >>>+++++++++
+            [
+                -
+                <<<++++++++++++
+            >>>]
+            <<<+
+            %43 = add i8 %42_ i8 1
+            
+            >[-]
+            known to be 110
+            >>++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            store volatile i8 %43_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %42_ i8* %14_ align 1
+            
+            <<<<<<<[-]
+            >>>>>>>+++++++++
+            [
+                -
+                <<<<<<<++++++++++++
+            >>>>>>>]
+            <<<<<<<+
+            %44 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 110
+            >>>++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %45 = add i8 %44_ i8 1
+            
+            <<[-]
+            known to be 111
+            >>++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            store volatile i8 %45_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+
+            store i8 %44_ i8* %15_ align 1
+            
+            >>>>>>>>>>>>>>[-]
+            >>>>>>++++++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            %46 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 111
+            >>>++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %47 = add i8 %46_ i8 1
+            
+            >[-]
+            known to be 112
+            >>++++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            store volatile i8 %47_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store i8 %46_ i8* %16_ align 1
+            
+            <<<<<[-]
+            >>>>>++++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            <<<<<+
+            %48 = load volatile i8* %1_ align 1
+            
+            >>[-]
+            known to be 112
+            >>>++++++++
+            [
+                -
+                <<<++++++++++++++
+            >>>]
+            %49 = add i8 %48_ i8 1
+            
+            <<[-]
+            known to be 113
+            >>++++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            <<+
+            store volatile i8 %49_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<+
+            store i8 %48_ i8* %17_ align 1
+            
+            >>>>>>>>>>>>>>>>[-]
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++++
+            >>>>]
+            %50 = load i8* %5_ align 1
+            
+            <<<[-]
+            known to be 100
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            %51 = zext i8 %50 to i32
+            
+            <<[-]
+            known to be 100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            %52 = load i8* %4_ align 1
+            
+            <<<[-]
+            known to be 99
+            >>>+++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %53 = zext i8 %52 to i32
+            
+            <[-]
+            known to be 99
+            >+++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            %54 = icmp sgt i32 %51_ i32 %53
+            
+            <<<[-]
+            known to be 1
+            +
+            br i1 %54_ label %55_ label %57
+            
+            dup29/34/32 [->>>>>+<<+<<<]
+            mov34/29 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >+
+                #B:main/%55_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%57_false
+            <<]
+        <]
+        >>#B:%55
+        [
+            -
+            %56 = load i8* %17_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup28/29/33 <[->+>>>>+<<<<<]
+            mov33/28 >>>>>[-<<<<<+>>>>>]
+            call @putchar(i8 %56)
+            
+            putchar intrinsic
+            dup29/32/33 <<<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            br label %59
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%57
+        [
+            -
+            %58 = load i8* %2_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup13/29/32 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<]
+            mov32/13 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %58)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            br label %59
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%59
+        [
+            -
+            %60 = load i8* %9_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup20/29/32 <<<<<<<<<[->>>>>>>>>+>>>+<<<<<<<<<<<<]
+            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            %61 = zext i8 %60 to i32
+            
+            <<[-]
+            dup29/32/33 <[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            mov33/30 >[-<<<+>>>]
+            %62 = icmp eq i32 %61_ i32 104
+            
+            <<<<[-]
+            dup30/32/33 >[->>+>+<<<]
+            mov32/30 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_104
+            ++++++++
+            [
+                -
+                >+++++++++++++
+            <]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/37 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            br i1 %62_ label %63_ label %65
+            
+            dup29/33/32 <<<<<<<[->>>>+<+<<<]
+            mov33/29 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%63_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:main/%65_false
+            <<<<<]
+        >>>]
+        >#B:%63
+        [
+            -
+            %64 = load i8* %10_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>>>>[-]
+            dup21/29/34 <<<<<<<<[->>>>>>>>+>>>>>+<<<<<<<<<<<<<]
+            mov34/21 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            call @putchar(i8 %64)
+            
+            putchar intrinsic
+            dup29/32/33 <<<<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            br label %65
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%65
+        [
+            -
+            %66 = load i8* %12_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>>>[-]
+            dup23/29/32 <<<<<<[->>>>>>+>>>+<<<<<<<<<]
+            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %67 = zext i8 %66 to i32
+            
+            <<[-]
+            dup29/32/33 <[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            mov33/30 >[-<<<+>>>]
+            %68 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup14/29/32 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<]
+            mov32/14 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            %69 = zext i8 %68 to i32
+            
+            <[-]
+            dup29/32/33 <<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            mov33/31 >[-<<+>>]
+            %70 = icmp slt i32 %67_ i32 %69
+            
+            <<<<[-]
+            dup30/32/33 >[->>+>+<<<]
+            mov32/30 >>[-<<+>>]
+            dup31/34/35 <[->>>+>+<<<<]
+            mov34/31 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov33/40 <<<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<<<[
+                -
+                <+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<[
+                >>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<[
+                    -
+                    >>>>-
+                <<<<]
+                >>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/43 <<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<<[
+                -
+                <+
+                >>>>>[
+                    -
+                    <<<<<-
+                    r1 >
+                >>>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<[
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<[
+                    -
+                    >>>>>-
+                <<<<<]
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<]
+            >>>>>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov43/46 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<[
+                -
+                
+                >>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<]
+            >>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            br i1 %70_ label %71_ label %72
+            
+            dup29/33/32 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov33/29 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%71_true
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%72_false
+            <<<<<<<]
+        >>>>>]
+        >#B:%71
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %74
+            
+            <<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%72
+        [
+            -
+            %73 = load i8* %14_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>[-]
+            dup25/29/32 <<<<[->>>>+>>>+<<<<<<<]
+            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %73)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            br label %74
+            
+            <<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%74
+        [
+            -
+            %75 = load i8* %17_ align 1
+            
+            >>>>>>>>>>>>>>>>>>[-]
+            dup28/29/32 <[->+>>>+<<<<]
+            mov32/28 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %75)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %76 = load i8* %16_ align 1
+            
+            <<<<[-]
+            dup27/29/32 <<[->>+>>>+<<<<<]
+            mov32/27 >>>>>[-<<<<<+>>>>>]
+            call @putchar(i8 %76)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %77 = load i8* %15_ align 1
+            
+            <<<<[-]
+            dup26/29/32 <<<[->>>+>>>+<<<<<<]
+            mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+            call @putchar(i8 %77)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %78 = load i8* %14_ align 1
+            
+            <<<<[-]
+            dup25/29/32 <<<<[->>>>+>>>+<<<<<<<]
+            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %78)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %79 = load i8* %13_ align 1
+            
+            <<<<[-]
+            dup24/29/32 <<<<<[->>>>>+>>>+<<<<<<<<]
+            mov32/24 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %79)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %80 = load i8* %12_ align 1
+            
+            <<<<[-]
+            dup23/29/32 <<<<<<[->>>>>>+>>>+<<<<<<<<<]
+            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            call @putchar(i8 %80)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %81 = load i8* %11_ align 1
+            
+            <<<<[-]
+            dup22/29/32 <<<<<<<[->>>>>>>+>>>+<<<<<<<<<<]
+            mov32/22 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            call @putchar(i8 %81)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %82 = load i8* %10_ align 1
+            
+            <<<<[-]
+            dup21/29/32 <<<<<<<<[->>>>>>>>+>>>+<<<<<<<<<<<]
+            mov32/21 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            call @putchar(i8 %82)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %83 = load i8* %9_ align 1
+            
+            <<<<[-]
+            dup20/29/32 <<<<<<<<<[->>>>>>>>>+>>>+<<<<<<<<<<<<]
+            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            call @putchar(i8 %83)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %84 = load i8* %8_ align 1
+            
+            <<<<[-]
+            dup19/29/32 <<<<<<<<<<[->>>>>>>>>>+>>>+<<<<<<<<<<<<<]
+            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            call @putchar(i8 %84)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %85 = load i8* %7_ align 1
+            
+            <<<<[-]
+            dup18/29/32 <<<<<<<<<<<[->>>>>>>>>>>+>>>+<<<<<<<<<<<<<<]
+            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            call @putchar(i8 %85)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %86 = load i8* %6_ align 1
+            
+            <<<<[-]
+            dup17/29/32 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<]
+            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            call @putchar(i8 %86)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %87 = load i8* %5_ align 1
+            
+            <<<<[-]
+            dup16/29/32 <<<<<<<<<<<<<[->>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<]
+            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %87)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %88 = load i8* %4_ align 1
+            
+            <<<<[-]
+            dup15/29/32 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<]
+            mov32/15 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %88)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %89 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup14/29/32 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<]
+            mov32/14 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %89)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            %90 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup13/29/32 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<]
+            mov32/13 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %90)
+            
+            putchar intrinsic
+            dup29/32/33 <<<[->>>+>+<<<<]
+            mov32/29 >>>[-<<<+>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>]
+    <<<<<<<<<<]
+<]
@@ -0,0 +1,268 @@
+; ModuleID = './tests/artifacts/o0/many_regs.c/ir.bc'
+source_filename = "./tests/cases/many_regs.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @main() #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  %8 = alloca i8, align 1
+  %9 = alloca i8, align 1
+  %10 = alloca i8, align 1
+  %11 = alloca i8, align 1
+  %12 = alloca i8, align 1
+  %13 = alloca i8, align 1
+  %14 = alloca i8, align 1
+  %15 = alloca i8, align 1
+  %16 = alloca i8, align 1
+  %17 = alloca i8, align 1
+  store volatile i8 97, i8* %1, align 1
+  %18 = load volatile i8, i8* %1, align 1
+  %19 = add i8 %18, 1
+  store volatile i8 %19, i8* %1, align 1
+  store i8 %18, i8* %2, align 1
+  %20 = load volatile i8, i8* %1, align 1
+  %21 = add i8 %20, 1
+  store volatile i8 %21, i8* %1, align 1
+  store i8 %20, i8* %3, align 1
+  %22 = load volatile i8, i8* %1, align 1
+  %23 = add i8 %22, 1
+  store volatile i8 %23, i8* %1, align 1
+  store i8 %22, i8* %4, align 1
+  %24 = load volatile i8, i8* %1, align 1
+  %25 = add i8 %24, 1
+  store volatile i8 %25, i8* %1, align 1
+  store i8 %24, i8* %5, align 1
+  %26 = load volatile i8, i8* %1, align 1
+  %27 = add i8 %26, 1
+  store volatile i8 %27, i8* %1, align 1
+  store i8 %26, i8* %6, align 1
+  %28 = load volatile i8, i8* %1, align 1
+  %29 = add i8 %28, 1
+  store volatile i8 %29, i8* %1, align 1
+  store i8 %28, i8* %7, align 1
+  %30 = load volatile i8, i8* %1, align 1
+  %31 = add i8 %30, 1
+  store volatile i8 %31, i8* %1, align 1
+  store i8 %30, i8* %8, align 1
+  %32 = load volatile i8, i8* %1, align 1
+  %33 = add i8 %32, 1
+  store volatile i8 %33, i8* %1, align 1
+  store i8 %32, i8* %9, align 1
+  %34 = load volatile i8, i8* %1, align 1
+  %35 = add i8 %34, 1
+  store volatile i8 %35, i8* %1, align 1
+  store i8 %34, i8* %10, align 1
+  %36 = load volatile i8, i8* %1, align 1
+  %37 = add i8 %36, 1
+  store volatile i8 %37, i8* %1, align 1
+  store i8 %36, i8* %11, align 1
+  %38 = load volatile i8, i8* %1, align 1
+  %39 = add i8 %38, 1
+  store volatile i8 %39, i8* %1, align 1
+  store i8 %38, i8* %12, align 1
+  %40 = load volatile i8, i8* %1, align 1
+  %41 = add i8 %40, 1
+  store volatile i8 %41, i8* %1, align 1
+  store i8 %40, i8* %13, align 1
+  %42 = load volatile i8, i8* %1, align 1
+  %43 = add i8 %42, 1
+  store volatile i8 %43, i8* %1, align 1
+  store i8 %42, i8* %14, align 1
+  %44 = load volatile i8, i8* %1, align 1
+  %45 = add i8 %44, 1
+  store volatile i8 %45, i8* %1, align 1
+  store i8 %44, i8* %15, align 1
+  %46 = load volatile i8, i8* %1, align 1
+  %47 = add i8 %46, 1
+  store volatile i8 %47, i8* %1, align 1
+  store i8 %46, i8* %16, align 1
+  %48 = load volatile i8, i8* %1, align 1
+  %49 = add i8 %48, 1
+  store volatile i8 %49, i8* %1, align 1
+  store i8 %48, i8* %17, align 1
+  %50 = load i8, i8* %5, align 1
+  %51 = zext i8 %50 to i32
+  %52 = load i8, i8* %4, align 1
+  %53 = zext i8 %52 to i32
+  %54 = icmp sgt i32 %51, %53
+  br i1 %54, label %55, label %57
+
+55:                                               ; preds = %0
+  %56 = load i8, i8* %17, align 1
+  call void @putchar(i8 noundef zeroext %56)
+  br label %59
+
+57:                                               ; preds = %0
+  %58 = load i8, i8* %2, align 1
+  call void @putchar(i8 noundef zeroext %58)
+  br label %59
+
+59:                                               ; preds = %57, %55
+  %60 = load i8, i8* %9, align 1
+  %61 = zext i8 %60 to i32
+  %62 = icmp eq i32 %61, 104
+  br i1 %62, label %63, label %65
+
+63:                                               ; preds = %59
+  %64 = load i8, i8* %10, align 1
+  call void @putchar(i8 noundef zeroext %64)
+  br label %65
+
+65:                                               ; preds = %63, %59
+  %66 = load i8, i8* %12, align 1
+  %67 = zext i8 %66 to i32
+  %68 = load i8, i8* %3, align 1
+  %69 = zext i8 %68 to i32
+  %70 = icmp slt i32 %67, %69
+  br i1 %70, label %71, label %72
+
+71:                                               ; preds = %65
+  call void @putchar(i8 noundef zeroext 33)
+  br label %74
+
+72:                                               ; preds = %65
+  %73 = load i8, i8* %14, align 1
+  call void @putchar(i8 noundef zeroext %73)
+  br label %74
+
+74:                                               ; preds = %72, %71
+  %75 = load i8, i8* %17, align 1
+  call void @putchar(i8 noundef zeroext %75)
+  %76 = load i8, i8* %16, align 1
+  call void @putchar(i8 noundef zeroext %76)
+  %77 = load i8, i8* %15, align 1
+  call void @putchar(i8 noundef zeroext %77)
+  %78 = load i8, i8* %14, align 1
+  call void @putchar(i8 noundef zeroext %78)
+  %79 = load i8, i8* %13, align 1
+  call void @putchar(i8 noundef zeroext %79)
+  %80 = load i8, i8* %12, align 1
+  call void @putchar(i8 noundef zeroext %80)
+  %81 = load i8, i8* %11, align 1
+  call void @putchar(i8 noundef zeroext %81)
+  %82 = load i8, i8* %10, align 1
+  call void @putchar(i8 noundef zeroext %82)
+  %83 = load i8, i8* %9, align 1
+  call void @putchar(i8 noundef zeroext %83)
+  %84 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %84)
+  %85 = load i8, i8* %7, align 1
+  call void @putchar(i8 noundef zeroext %85)
+  %86 = load i8, i8* %6, align 1
+  call void @putchar(i8 noundef zeroext %86)
+  %87 = load i8, i8* %5, align 1
+  call void @putchar(i8 noundef zeroext %87)
+  %88 = load i8, i8* %4, align 1
+  call void @putchar(i8 noundef zeroext %88)
+  %89 = load i8, i8* %3, align 1
+  call void @putchar(i8 noundef zeroext %89)
+  %90 = load i8, i8* %2, align 1
+  call void @putchar(i8 noundef zeroext %90)
+  ret void
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
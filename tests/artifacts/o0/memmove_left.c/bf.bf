@@ -0,0 +1,817 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3580 ops ~59545 steps
+frame widths:
+  main: 19 cells
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+r4 >>>>
++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l19 <<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #%copy_0_0_byte_=_load_getelementptr_inbounds_({4_x_i8}*_@word__i64_0__i64_1)__align_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %copy_0_0_byte = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 1)_ align 1
+            
+            >>[-]
+            op_to_reg storing const value in temp address
+            >#constop_2
+            ++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            store i8 %copy_0_0_byte_ getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 0)_ align 1
+            
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/13/14 <<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/16 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<]
+            >>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov16/10 [-<<<<<<+>>>>>>]
+                <<<<<<++++++++
+            >>>>>>]
+            <[
+                if neg
+                <<<<<++++++++
+                >>>>>[
+                    -
+                    <<<<<-
+                >>>>>]
+            ]
+            mov6/11 <<<<<<<<<[->>>>>+<<<<<]
+            drive left! choo choo
+            >>>>[
+                mov9/8 <[-<+>]
+                mov10/9 >[-<+>]
+                mov11/10 >[-<+>]
+                mov7/11 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov11/7 >>>>[-<<<<+>>>>]
+            <<[
+                mov12/8 >>>[-<<<<+>>>>]
+                
+                mov9/10 <<<[->+<]
+                r1 >
+                -
+            ]
+            %copy_0_1_byte = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 2)_ align 1
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >#constop_3
+            +++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            store i8 %copy_0_1_byte_ getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 1)_ align 1
+            
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/13/14 <<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/15 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov15/10 [-<<<<<+>>>>>]
+                <<<<<++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov6/11 <<<<<<<<[->>>>>+<<<<<]
+            drive left! choo choo
+            >>>>[
+                mov9/8 <[-<+>]
+                mov10/9 >[-<+>]
+                mov11/10 >[-<+>]
+                mov7/11 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov11/7 >>>>[-<<<<+>>>>]
+            <<[
+                mov12/8 >>>[-<<<<+>>>>]
+                
+                mov9/10 <<<[->+<]
+                r1 >
+                -
+            ]
+            %copy_0_2_byte = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 3)_ align 1
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >#constop_4
+            ++++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            store i8 %copy_0_2_byte_ getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 2)_ align 1
+            
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_3
+            +++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/13/14 <<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/15 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov15/10 [-<<<<<+>>>>>]
+                <<<<<++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov6/11 <<<<<<<<[->>>>>+<<<<<]
+            drive left! choo choo
+            >>>>[
+                mov9/8 <[-<+>]
+                mov10/9 >[-<+>]
+                mov11/10 >[-<+>]
+                mov7/11 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov11/7 >>>>[-<<<<+>>>>]
+            <<[
+                mov12/8 >>>[-<<<<+>>>>]
+                
+                mov9/10 <<<[->+<]
+                r1 >
+                -
+            ]
+            %1 = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 0)_ align 1
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            call @putchar(i8 %1)
+            
+            putchar intrinsic
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            >.
+            [-]
+            %2 = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 1)_ align 1
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_2
+            ++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            call @putchar(i8 %2)
+            
+            putchar intrinsic
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            >.
+            [-]
+            %3 = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 2)_ align 1
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_3
+            +++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            >.
+            [-]
+            %4 = load getelementptr inbounds ({4 x i8}* @word_ i64 0_ i64 3)_ align 1
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_4
+            ++++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov10/12 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov12/8 [-<<<<+>>>>]
+                <<<<++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov7/6 <[-<+>]
+                mov8/7 >[-<+>]
+                
+                mov5/8 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup5/6/8 <<<[->+>>+<<<]
+            mov6/5 >[-<+>]
+            >[
+                mov9/6 >>[-<<<+>>>]
+                mov8/9 <[->+<]
+                mov7/8 <[->+<]
+                r1 >
+                -
+            ]
+            mov8/4 >[-<<<<+>>>>]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup4/5/6 <<<<[->+>+<<]
+            mov5/4 >[-<+>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<[-]
+            <<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
+clear globals:
+l5 <<<<
+[-]
+>[-]
+>[-]
+>[-]
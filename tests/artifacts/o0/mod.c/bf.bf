@@ -1,815 +1,92 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4603 ops ~129357 steps
+frame widths:
+  main: 26 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
+<<[
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<-
             #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            >-
+            #dead_fn_pad/main
+            >>-
+            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#B:%14
-        >#B:%15
-        >#B:%18
-        >#B:%call_term_for_7
-        >#B:%23
-        >#B:%26
-        >#alloc_%1
-        >#alloc_%2
-        >#%4_=_load_i32*_%1__align_4(mult)
-        >#%5_=_icmp_slt_i32_%4__i32_100(mult)
-        >#%7_=_load_i32*_%1__align_4(mult)
-        >#%8_=_srem_i32_%7__i32_26(mult)
-        >#%9_=_add_i32_65__i32_%8(mult)
-        >#%10_=_trunc_i32_%9_to_i8(mult)
-        >#%12_=_load_i32*_%1__align_4(mult)
-        >#%13_=_add_i32_%12__i32_1(mult)
-        >#%16_=_load_i32*_%2__align_4(mult)
-        >#%17_=_icmp_slt_i32_%16__i32_100(mult)
-        >#%19_=_load_i32*_%2__align_4(mult)
-        >#%20_=_srem_i32_26__i32_%19(mult)
-        >#%21_=_add_i32_65__i32_%20(mult)
-        >#%22_=_trunc_i32_%21_to_i8(mult)
-        >#%24_=_load_i32*_%2__align_4(mult)
-        >#%25_=_add_i32_%24__i32_1(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%0
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%6
+        #B:%14
+        #B:%15
+        #B:%18
+        #B:%26
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i32*_%1__align_4(mult)
+        #%5_=_icmp_ult_i32_%4__i32_100(mult)
+        #%9_=_add_i32_65__i32_%8(mult)
+        <#B:%0
         [
             -
             copy up args
             %1 = alloca i32_ align 4
             %2 = alloca i32_ align 4
             store i32 0_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            <<<<<<<<<<<<<<<<<<[-]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>>>>>>[-]
+            
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<<<+
         <<]
         >>#B:%3
         [
             -
             %4 = load i32* %1_ align 4
-            >>>>>>>>>>>>[-]
-            dup16/18/35 <<[->>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            mov35/16 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp slt i32 %4_ i32 100
-            <<<<<<<<<<<<<<<<[-]
-            dup18/34/36 <[->>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<]
-            mov34/18 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>[-]
+            dup10/12/15 <<[->>+>>>+<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            %5 = icmp ult i32 %4_ i32 100
+            
+            <<[-]
+            dup12/16/15 <[->>>>+<+<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
             >#constop_100
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov35/37 <<<<[->>+<<]
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 <[->>+<<]
             >>>>+
-            <<<[
+            <<<<<<[
                 -
                 
-                >[
+                >>>>[
                     -
                     
                     r1 >
@@ -819,51 +96,61 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
+            <<<<]
+            >>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>]
+                <<<<<<+
+            >>>>>>]
             br i1 %5_ label %6_ label %14
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
+            
+            dup13/16/15 <<<<<<[->>>+<+<<]
+            mov16/13 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<-
+                <<<<<<<<<<<<-
                 >>+
-                #B:main/6_true
-            >>>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
+                #B:main/%6_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<<[
                 -
-                >>>>>+
-                #B:main/14_false
-            <<<<<]
+                >>>+
+                #B:main/%14_false
+            <<<]
         >]
         >#B:%6
         [
             -
             %7 = load i32* %1_ align 4
-            >>>>>>>>>>>>>[-]
-            dup16/20/34 <<<<[->>>>+>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            %8 = srem i32 %7_ i32 26
-            <<<<<<<<<<<<<[-]
-            dup20/34/35 <[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov34/20 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            
+            >>>>>>>[-]
+            dup10/12/17 <<[->>+>>>>>+<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %8 = urem i32 %7_ i32 26
+            
+            <<<<[-]
+            dup12/15/16 <[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_26
-            ++++++++++++++++++++++++++
-            dup36/38/40 [->>+>>+<<<<]
-            mov40/36 >>>>[-<<<<+>>>>]
-            <<<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov35/41 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
+            >+++++
+            [
+                -
+                <+++++
+            >]
+            <+
+            dup17/20/22 [->>>+>>+<<<<<]
+            mov22/17 >>>>>[-<<<<<+>>>>>]
+            <<<#op0
+            #op1
+            #neg
+            <<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov16/23 [->>>>>>>+<<<<<<<]
+                >>>>>>>>>+
                 <<<<<[
                     -
                     <+
@@ -879,116 +166,155 @@ runtime init:
                     l1 <
                 <<<]
                 >>>>>-
-                mov41/35 <<[-<<<<<<+>>>>>>]
-                dup36/38/40 <<<<<[->>+>>+<<<<]
-                mov40/36 >>>>[-<<<<+>>>>]
-            <<<<<]
-            >>#neg
+                mov23/16 <<[-<<<<<<<+>>>>>>>]
+                dup17/20/22 <<<<<<[->>>+>>+<<<<<]
+                mov22/17 >>>>>[-<<<<<+>>>>>]
+            <<<<<<]
+            >>>#neg
             [
-                mov38/21 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                mov20/13 >[-<<<<<<<+>>>>>>>]
                 <[
                     -
-                    <<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>]
+                    <<<<<<-
+                >>>>>>]
             ]
-            mov37/21 [-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <[-]
-            >>[-]
+            mov19/13 [-<<<<<<+>>>>>>]
+            <<[-]
+            >>>[-]
             %9 = add i32 65_ i32 %8
-            <<<<<<<<<<<<<<<<[-]
+            
+            <<<<<<[-]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            dup21/35/36 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov35/21 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            mov34/22 <[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            >>[
+            >#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup13/17/18 <<[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov15/14 <<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/20/22 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov22/14 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
                 -
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             %10 = trunc i32 %9 to i8
-            <<<<<<<<<<<<<[-]
-            dup22/34/35 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov34/22 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            mov35/23 >[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            <<<<<<[-]
+            dup14/15/16 >[->+>+<<]
+            mov15/14 >[-<+>]
+            mov16/13 >[-<<<+>>>]
             call @putchar(i8 %10)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
-            dup23/34/35 >>>>>>>>>>>>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov34/23 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            dup13/15/16 <<<[->>+>+<<<]
+            mov15/13 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %11
-            >+
-        <]
-        >#B:%11
-        [
-            -
-            %12 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/24/34 <<<<<<<<[->>>>>>>>+>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            %13 = add i32 %12_ i32 1
-            <<<<<<<<<[-]
-            dup24/34/35 <[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov34/24 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %13 = add i32 %7_ i32 1
+            
+            <<<[-]
+            dup12/15/16 <[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov35/25 <[-<<<<<<<<<<+>>>>>>>>>>]
-            >[
+            mov16/13 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/19/21 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov21/13 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
                 -
-                <<<<<<<<<<<+
-            >>>>>>>>>>>]
+                >>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %13_ i32* %1_ align 4
-            dup25/34/35 <<<<<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov34/25 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[-]
-            mov35/16 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            
+            dup13/15/16 <<<<<[->>+>+<<<]
+            mov15/13 >>[-<<+>>]
+            <<<<<[-]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
             br label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>]
+            
+            <<<<<<<<<<<<+
+        >]
         >#B:%14
         [
             -
             store i32 1_ i32* %2_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>#constop_1
+            
+            >>>>>[-]
             +
-            <<<<<<<<<<<<<<<<<[-]
-            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             br label %15
-            <<<<<<<<<<<<<<<<<<<<<<<+
+            
+            <<<<+
         <]
         >#B:%15
         [
             -
             %16 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup17/26/34 <<<<<<<<<[->>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<]
-            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            %17 = icmp slt i32 %16_ i32 100
-            <<<<<<<[-]
-            dup26/34/35 <[->>>>>>>>+>+<<<<<<<<<]
-            mov34/26 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            
+            >>>>>[-]
+            dup11/12/15 <[->+>>>+<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            %17 = icmp ult i32 %16_ i32 100
+            
+            <<[-]
+            dup12/15/16 <[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_100
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov36/37 <<<[->+<]
-            >>>+
-            <<<<[
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 <[->>+<<]
+            >>>>+
+            <<<<<[
                 -
                 
-                >>[
+                >>>[
                     -
                     
                     r1 >
@@ -998,51 +324,61 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
+            <<<]
+            >>>>>-
             <<[
                 [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
+                <<<<<<+
+            >>>>>>]
             br i1 %17_ label %18_ label %26
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup13/16/15 <<<<<<[->>>+<+<<]
+            mov16/13 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>+
-                #B:main/18_true
-            >>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%18_true
+            >>>>>>>]
+            <<<<<<<<<<<<[
                 -
-                >>>>>>>>>>+
-                #B:main/26_false
-            <<<<<<<<<<]
-        >>>>>>]
+                >>>>>>+
+                #B:main/%26_false
+            <<<<<<]
+        >>>>]
         >#B:%18
         [
             -
             %19 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            dup17/28/34 <<<<<<<<<<<[->>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<]
-            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            %20 = srem i32 26_ i32 %19
-            <<<<<[-]
+            
+            >>>>[-]
+            dup11/12/17 <[->+>>>>>+<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            %20 = urem i32 26_ i32 %19
+            
+            <<<<[-]
             op_to_reg storing const value in temp address
-            >>>>>#constop_26
-            ++++++++++++++++++++++++++
-            dup28/35/36 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov35/28 >>>>>>>[-<<<<<<<+>>>>>>>]
-            dup36/38/39 >[->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<<#op0
-            >>#op1
-            >#neg
-            <<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov34/40 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
+            >>#constop_26
+            >+++++
+            [
+                -
+                <+++++
+            >]
+            <+
+            dup12/17/18 <<<[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            dup18/20/21 >[->>+>+<<<]
+            mov21/18 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov15/22 [->>>>>>>+<<<<<<<]
+                >>>>>>>>>+
                 <<<<[
                     -
                     <+
@@ -1058,111 +394,133 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                mov40/34 <<[-<<<<<<+>>>>>>]
-                dup36/38/39 <<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<<]
-            >>>#neg
+                mov22/15 <<[-<<<<<<<+>>>>>>>]
+                dup18/20/21 <<<<[->>+>+<<<]
+                mov21/18 >>>[-<<<+>>>]
+            <<<<<<]
+            >>>>#neg
             [
-                mov38/29 >[-<<<<<<<<<+>>>>>>>>>]
+                mov20/13 >[-<<<<<<<+>>>>>>>]
                 <[
                     -
-                    <<<<<<<<-
-                >>>>>>>>]
+                    <<<<<<-
+                >>>>>>]
             ]
-            mov37/29 [-<<<<<<<<+>>>>>>>>]
+            mov19/13 [-<<<<<<+>>>>>>]
             <[-]
             >>[-]
             %21 = add i32 65_ i32 %20
-            <<<<<<<<[-]
+            
+            <<<<<<[-]
             op_to_reg storing const value in temp address
-            >>>>#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            dup29/35/36 <<<<<[->>>>>>+>+<<<<<<<]
-            mov35/29 >>>>>>[-<<<<<<+>>>>>>]
-            mov34/30 <[-<<<<+>>>>]
-            >>[
+            >#constop_65
+            >++++++++
+            [
                 -
-                <<<<<<+
-            >>>>>>]
+                <++++++++
+            >]
+            <+
+            dup13/17/18 <<[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov15/14 <<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/20/22 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov22/14 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             %22 = trunc i32 %21 to i8
-            <<<<<[-]
-            dup30/34/35 <[->>>>+>+<<<<<]
-            mov34/30 >>>>[-<<<<+>>>>]
-            mov35/31 >[-<<<<+>>>>]
+            
+            <<<<<<[-]
+            dup14/15/16 >[->+>+<<]
+            mov15/14 >[-<+>]
+            mov16/13 >[-<<<+>>>]
             call @putchar(i8 %22)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_7
-            +
+            
             putchar intrinsic
-            dup31/34/35 >>>>>>>>>>>>>>>>>>[->>>+>+<<<<]
-            mov34/31 >>>[-<<<+>>>]
+            dup13/15/16 <<<[->>+>+<<<]
+            mov15/13 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_7
-        [
-            -
-            br label %23
-            >+
-        <]
-        >#B:%23
-        [
-            -
-            %24 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>>[-]
-            dup17/32/34 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<]
-            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            %25 = add i32 %24_ i32 1
-            <[-]
-            dup32/34/35 <[->>+>+<<<]
-            mov34/32 >>[-<<+>>]
+            %25 = add i32 %19_ i32 1
+            
+            <<<[-]
+            dup12/15/16 <[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov35/33 <[-<<+>>]
-            >[
+            mov16/13 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/19/21 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov21/13 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
                 -
-                <<<+
-            >>>]
+                >>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             store i32 %25_ i32* %2_ align 4
-            dup33/34/35 <<<[->+>+<<]
-            mov34/33 >[-<+>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov35/17 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            dup13/15/16 <<<<<[->>+>+<<<]
+            mov15/13 >>[-<<+>>]
+            <<<<[-]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
             br label %15
-            <<<<<<<<<<<<<<<<<<<<<<<<+
-        >>>]
+            
+            <<<<<<<<<+
+        >]
         >#B:%26
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<]
-<<<]
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+    <<<<<<<<]
+<]
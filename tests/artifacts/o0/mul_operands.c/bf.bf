@@ -0,0 +1,299 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3811 ops ~421536 steps
+frame widths:
+  main: 23 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l23 <<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_i8*_%1__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%5_=_load_i8*_%2__align_1(mult)
+        #%6_=_zext_i8_%5_to_i32(mult)
+        #%7_=_mul_i32_%4__i32_%6(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store i8 6_ i8* %1_ align 1
+            
+            >>[-]
+            ++++++
+            store i8 7_ i8* %2_ align 1
+            
+            >[-]
+            +++++++
+            call @putchar(i8 42)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_42
+            >++++++
+            [
+                -
+                <+++++++
+            >]
+            <.
+            [-]
+            %3 = load i8* %1_ align 1
+            
+            <<<<<[-]
+            dup4/6/11 <<[->>+>>>>>+<<<<<<<]
+            mov11/4 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %4 = zext i8 %3 to i32
+            
+            <<<<[-]
+            dup6/11/13 <[->>>>>+>>+<<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            mov13/7 >>[-<<<<<<+>>>>>>]
+            %5 = load i8* %2_ align 1
+            
+            <<<<<[-]
+            dup5/8/11 <<<[->>>+>>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %6 = zext i8 %5 to i32
+            
+            <<[-]
+            dup8/11/12 <[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov12/9 >[-<<<+>>>]
+            %7 = mul i32 %4_ i32 %6
+            
+            <<[-]
+            dup7/11/12 <<<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            dup9/14/13 <<[->>>>>+<+<<<<]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<[
+                -
+                mov12/18 <[->>>>>>+<<<<<<]
+                >>>>>>[
+                    -
+                    <<<<<<+
+                    >>>>>+
+                    <<[
+                        -
+                        mov15/16 [->+<]
+                        <<<<<+
+                        >>>>>>>-
+                    <<]
+                    mov16/15 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<[-]
+                        >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<<]
+            <[-]
+            >>>[-]
+            %8 = trunc i32 %7 to i8
+            
+            <<<<<<<<[-]
+            dup10/11/12 >>>[->+>+<<]
+            mov11/10 >[-<+>]
+            mov12/7 >[-<<<<<+>>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup7/11/12 <<<<<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %10 = zext i8 %3 to i32
+            
+            <<<<<[-]
+            dup6/11/12 <[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            mov12/7 >[-<<<<<+>>>>>]
+            %11 = mul i32 %10_ i32 7
+            
+            <<<[-]
+            dup7/11/12 <<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_7
+            +++++++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov12/17 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov14/15 [->+<]
+                        <<<<<+
+                        >>>>>>>-
+                    <<]
+                    mov15/14 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<[-]
+                        >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <[-]
+            >>[-]
+            %12 = trunc i32 %11 to i8
+            
+            <<<<<<<[-]
+            dup9/11/12 >>[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            mov12/7 >[-<<<<<+>>>>>]
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            dup7/11/12 <<<<<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %14 = zext i8 %3 to i32
+            
+            <<<<<[-]
+            dup6/11/12 <[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            mov12/7 >[-<<<<<+>>>>>]
+            %16 = zext i8 %5 to i32
+            
+            <<<<<<[-]
+            dup8/11/12 >>[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov12/6 >[-<<<<<<+>>>>>>]
+            %17 = add i32 %14_ i32 %16
+            
+            <<<<[-]
+            dup7/11/12 <[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            dup6/13/14 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov12/8 <[-<<<<+>>>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/20/22 <<<<<<<<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov22/8 >>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<<<<<<<+
+                    >>>>>>>>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %18 = add i32 %17_ i32 35
+            
+            <<<<<<<<<<<<<[-]
+            dup8/11/12 >>[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_35
+            >+++++
+            [
+                -
+                <+++++++
+            >]
+            mov12/6 <<[-<<<<<<+>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup6/16/18 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov18/6 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %19 = trunc i32 %18 to i8
+            
+            <<<<<<<<[-]
+            dup6/11/12 <[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            mov12/7 >[-<<<<<+>>>>>]
+            call @putchar(i8 %19)
+            
+            putchar intrinsic
+            dup7/11/12 <<<<<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
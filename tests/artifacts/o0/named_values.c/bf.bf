@@ -0,0 +1,816 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %for_end after call 0 (call continuation)
+call graph:
+  bump (depth 1)
+  main calls bump (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  bump: 3666 ops ~61941 steps
+  main: 4882 ops ~67756 steps
+frame widths:
+  bump: 32 cells
+  main: 35 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#bump
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/bump
+            >>>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_bump
+        #F:bump
+        #F:main
+        #B:%entry
+        #B:%ret_lading_pad
+        #B:%for_cond
+        #B:%for_body
+        #B:%for_end
+        #alloc_%by_addr
+        #alloc_%total
+        #alloc_%i
+        #%0_=_load_i8*_%i__align_1(mult)
+        #%conv_=_zext_i8_%0_to_i32(mult)
+        #%conv1_=_zext_i8_%1_to_i32(mult)
+        #%by(mult)
+        <#B:%entry
+        [
+            -
+            copy up args
+            #arg_%by
+            l2 
+            mov0/16 <<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            r2 >>
+            %by_addr = alloca i8_ align 1
+            %total = alloca i8_ align 1
+            %i = alloca i8_ align 1
+            store i8 %by_ i8* %by_addr_ align 1
+            
+            dup14/15/16 >>>>>>>>>>>>>>[->+>+<<]
+            mov15/14 >[-<+>]
+            <<<<<<<[-]
+            mov16/8 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            store i8 0_ i8* %total_ align 1
+            
+            <<<<<<<[-]
+            
+            store i8 0_ i8* %i_ align 1
+            
+            >[-]
+            
+            br label %for_cond
+            
+            <<<<<+
+        <<]
+        >>#B:%for_cond
+        [
+            -
+            %0 = load i8* %i_ align 1
+            
+            >>>>>>[-]
+            dup10/11/15 <[->+>>>>+<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            %conv = zext i8 %0 to i32
+            
+            <<<[-]
+            dup11/15/17 <[->>>>+>>+<<<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            mov17/12 >>[-<<<<<+>>>>>]
+            %1 = load i8* %by_addr_ align 1
+            
+            <<<<<<[-]
+            dup8/11/15 <<<[->>>+>>>>+<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %conv1 = zext i8 %1 to i32
+            
+            <<[-]
+            dup11/15/16 <<[->>>>+>+<<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            mov16/13 >[-<<<+>>>]
+            %cmp = icmp slt i32 %conv_ i32 %conv1
+            
+            <<<<<[-]
+            dup12/15/16 >[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            dup13/18/17 <<[->>>>>+<+<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/21 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/26 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/29 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            br i1 %cmp_ label %for_body_ label %for_end
+            
+            dup11/16/15 <<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<+
+            >>>>>>>>>>>[
+                -
+                <<<<<<<<<<<-
+                >>+
+                #B:bump/%for_body_true
+            >>>>>>>>>]
+            <<<<<<<<<<<[
+                -
+                >>>+
+                #B:bump/%for_end_false
+            <<<]
+        >]
+        >#B:%for_body
+        [
+            -
+            %2 = load i8* %total_ align 1
+            
+            >>>>>[-]
+            dup9/11/17 <<[->>+>>>>>>+<<<<<<<<]
+            mov17/9 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %conv3 = zext i8 %2 to i32
+            
+            <<<<<[-]
+            dup11/15/16 <[->>>>+>+<<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            mov16/12 >[-<<<<+>>>>]
+            %add = add i32 %conv3_ i32 2
+            
+            <<<<<[-]
+            dup12/15/16 >[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            mov16/11 <[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup11/19/21 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov21/11 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %conv4 = trunc i32 %add to i8
+            
+            <<<<<<[-]
+            dup11/15/16 <[->>>>+>+<<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            mov16/12 >[-<<<<+>>>>]
+            store i8 %conv4_ i8* %total_ align 1
+            
+            dup12/15/16 <<<<[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            <<<<<<[-]
+            mov16/9 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %3 = load i8* %i_ align 1
+            
+            <<<<<[-]
+            dup10/11/15 <[->+>>>>+<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            %inc = add i8 %3_ i8 1
+            
+            <<<[-]
+            dup11/15/16 <[->>>>+>+<<<<<]
+            mov15/11 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov16/12 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/19/21 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov21/12 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %inc_ i8* %i_ align 1
+            
+            dup12/15/16 <<<<<<[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            br label %for_cond
+            
+            <<<<<<<<<<<+
+        >]
+        >#B:%for_end
+        [
+            -
+            %4 = load i8* %total_ align 1
+            
+            >>>>[-]
+            dup9/11/15 <<[->>+>>>>+<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            ret i8 %4
+            
+            mov11/15 <<<<[->>>>+<<<<]
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            mov15/8 >[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:bump
+        #F:main
+        #B:%entry
+        #B:%ret_lading_pad
+        #B:%for_cond
+        #B:%for_body
+        #B:%if_then
+        #B:%if_else
+        #B:%if_end
+        #B:%for_end
+        #B:%call_term_for_0
+        #alloc_%last
+        #alloc_%c
+        #%0_=_load_i8*_%c__align_1(mult)
+        #%conv_=_zext_i8_%0_to_i32(mult)
+        #%conv1_=_zext_i8_%1_to_i32(mult)
+        #%call_=_call_@bump(i8_3)(mult)
+        <#B:%entry
+        [
+            -
+            copy up args
+            %last = alloca i8_ align 1
+            %c = alloca i8_ align 1
+            store volatile i8 99_ i8* %last_ align 1
+            
+            >>>>>>>>>[-]
+            >>>>>>+++++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            store i8 97_ i8* %c_ align 1
+            
+            <<<<<[-]
+            >>>>>>++++++++
+            [
+                -
+                <<<<<<++++++++++++
+            >>>>>>]
+            <<<<<<+
+            br label %for_cond
+            
+            <<<<<<<<+
+        <<]
+        >>#B:%for_cond
+        [
+            -
+            %0 = load i8* %c_ align 1
+            
+            >>>>>>>>>[-]
+            dup13/14/18 <[->+>>>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            %conv = zext i8 %0 to i32
+            
+            <<<[-]
+            dup14/18/20 <[->>>>+>>+<<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov20/15 >>[-<<<<<+>>>>>]
+            %1 = load volatile i8* %last_ align 1
+            
+            <<<<<<[-]
+            dup12/14/18 <<[->>+>>>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            %conv1 = zext i8 %1 to i32
+            
+            <<[-]
+            dup14/18/19 <<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov19/16 >[-<<<+>>>]
+            %cmp = icmp sle i32 %conv_ i32 %conv1
+            
+            <<<<<[-]
+            dup15/18/19 >[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            dup16/21/20 <<[->>>>>+<+<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/24 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/29 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/32 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>]
+            br i1 %cmp_ label %for_body_ label %for_end
+            
+            dup14/19/18 <<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>+
+                #B:main/%for_body_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%for_end_false
+            <<<<<<]
+        >]
+        >#B:%for_body
+        [
+            -
+            %2 = load i8* %c_ align 1
+            
+            >>>>>>>>[-]
+            dup13/14/20 <[->+>>>>>>+<<<<<<<]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %conv3 = zext i8 %2 to i32
+            
+            <<<<<[-]
+            dup14/18/19 <[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov19/15 >[-<<<<+>>>>]
+            %cmp4 = icmp eq i32 %conv3_ i32 122
+            
+            <<<<<[-]
+            dup15/18/19 >[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_122
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/23 [->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<+
+            >>>>>>>>>[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            br i1 %cmp4_ label %if_then_ label %if_else
+            
+            dup14/19/18 <<<<<<<<[->>>>>+<+<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%if_then_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%if_else_false
+            <<<<]
+        >>]
+        >#B:%if_then
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %if_end
+            
+            <<<<<<<<<<<+
+        <<]
+        >#B:%if_else
+        [
+            -
+            %3 = load i8* %c_ align 1
+            
+            >>>>>>[-]
+            dup13/14/18 <[->+>>>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup14/18/19 <<<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            br label %if_end
+            
+            <<<<<<<<<<+
+        <]
+        >#B:%if_end
+        [
+            -
+            %4 = load i8* %c_ align 1
+            
+            >>>>>[-]
+            dup13/14/18 <[->+>>>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            %inc = add i8 %4_ i8 1
+            
+            <<<[-]
+            dup14/18/19 <[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov19/15 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/22/24 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov24/15 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %inc_ i8* %c_ align 1
+            
+            dup15/18/19 <<<<<<[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            br label %for_cond
+            
+            <<<<<<<<<<<<<<+
+        >>>>]
+        >#B:%for_end
+        [
+            -
+            %call = call @bump(i8 3)
+            
+            >>>>>>>[-]
+            enable next block when we return
+            <<<<<<#caller/%call_term_for_0
+            +
+            stack_width 35
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_3
+            +++
+            mov18/40 [->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/42/43 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov43/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r35 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_bump
+            +
+            >#bump
+            +
+            >>#bump/b0
+            +
+        >>>>>>>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %call
+            mov50/17 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %conv6 = zext i8 %call to i32
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup17/18/19 >>>[->+>+<<]
+            mov18/17 >[-<+>]
+            mov19/14 >[-<<<<<+>>>>>]
+            %add = add i32 64_ i32 %conv6
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_64
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            dup14/20/21 <<<<<[->>>>>>+>+<<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            mov18/15 <<[-<<<+>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/23/25 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov25/15 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov22/23 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov23/22 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %conv7 = trunc i32 %add to i8
+            
+            <<<<<<<<[-]
+            dup15/18/19 >[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            mov19/14 >[-<<<<<+>>>>>]
+            call @putchar(i8 %conv7)
+            
+            putchar intrinsic
+            dup14/18/19 <<<<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>>>]
+    <<<<<<<<<]
+<<]
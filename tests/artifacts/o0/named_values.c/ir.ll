@@ -0,0 +1,195 @@
+; ModuleID = './tests/artifacts/o0/named_values.c/ir.bc'
+source_filename = "./tests/cases/named_values.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %s) #0 {
+entry:
+  %s.addr = alloca i8*, align 8
+  store i8* %s, i8** %s.addr, align 8
+  br label %while.cond
+
+while.cond:                                       ; preds = %while.body, %entry
+  %0 = load i8*, i8** %s.addr, align 8
+  %1 = load i8, i8* %0, align 1
+  %tobool = icmp ne i8 %1, 0
+  br i1 %tobool, label %while.body, label %while.end
+
+while.body:                                       ; preds = %while.cond
+  %2 = load i8*, i8** %s.addr, align 8
+  %3 = load i8, i8* %2, align 1
+  call void @putchar(i8 noundef zeroext %3)
+  %4 = load i8*, i8** %s.addr, align 8
+  %incdec.ptr = getelementptr inbounds i8, i8* %4, i32 1
+  store i8* %incdec.ptr, i8** %s.addr, align 8
+  br label %while.cond, !llvm.loop !6
+
+while.end:                                        ; preds = %while.cond
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %n) #0 {
+entry:
+  %n.addr = alloca i32, align 4
+  %digs = alloca i32, align 4
+  store i32 %n, i32* %n.addr, align 4
+  %0 = load i32, i32* %n.addr, align 4
+  %tobool = icmp ne i32 %0, 0
+  br i1 %tobool, label %if.end, label %if.then
+
+if.then:                                          ; preds = %entry
+  call void @putchar(i8 noundef zeroext 48)
+  br label %while.end6
+
+if.end:                                           ; preds = %entry
+  store i32 1, i32* %digs, align 4
+  br label %while.cond
+
+while.cond:                                       ; preds = %while.body, %if.end
+  %1 = load i32, i32* %n.addr, align 4
+  %2 = load i32, i32* %digs, align 4
+  %div = udiv i32 %1, %2
+  %cmp = icmp uge i32 %div, 10
+  br i1 %cmp, label %while.body, label %while.end
+
+while.body:                                       ; preds = %while.cond
+  %3 = load i32, i32* %digs, align 4
+  %mul = mul i32 %3, 10
+  store i32 %mul, i32* %digs, align 4
+  br label %while.cond, !llvm.loop !8
+
+while.end:                                        ; preds = %while.cond
+  br label %while.cond1
+
+while.cond1:                                      ; preds = %while.body3, %while.end
+  %4 = load i32, i32* %digs, align 4
+  %tobool2 = icmp ne i32 %4, 0
+  br i1 %tobool2, label %while.body3, label %while.end6
+
+while.body3:                                      ; preds = %while.cond1
+  %5 = load i32, i32* %n.addr, align 4
+  %6 = load i32, i32* %digs, align 4
+  %div4 = udiv i32 %5, %6
+  %rem = urem i32 %div4, 10
+  %add = add i32 48, %rem
+  %conv = trunc i32 %add to i8
+  call void @putchar(i8 noundef zeroext %conv)
+  %7 = load i32, i32* %digs, align 4
+  %div5 = udiv i32 %7, 10
+  store i32 %div5, i32* %digs, align 4
+  br label %while.cond1, !llvm.loop !9
+
+while.end6:                                       ; preds = %while.cond1, %if.then
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local zeroext i8 @bump(i8 noundef zeroext %by) #0 {
+entry:
+  %by.addr = alloca i8, align 1
+  %total = alloca i8, align 1
+  %i = alloca i8, align 1
+  store i8 %by, i8* %by.addr, align 1
+  store i8 0, i8* %total, align 1
+  store i8 0, i8* %i, align 1
+  br label %for.cond
+
+for.cond:                                         ; preds = %for.inc, %entry
+  %0 = load i8, i8* %i, align 1
+  %conv = zext i8 %0 to i32
+  %1 = load i8, i8* %by.addr, align 1
+  %conv1 = zext i8 %1 to i32
+  %cmp = icmp slt i32 %conv, %conv1
+  br i1 %cmp, label %for.body, label %for.end
+
+for.body:                                         ; preds = %for.cond
+  %2 = load i8, i8* %total, align 1
+  %conv3 = zext i8 %2 to i32
+  %add = add nsw i32 %conv3, 2
+  %conv4 = trunc i32 %add to i8
+  store i8 %conv4, i8* %total, align 1
+  br label %for.inc
+
+for.inc:                                          ; preds = %for.body
+  %3 = load i8, i8* %i, align 1
+  %inc = add i8 %3, 1
+  store i8 %inc, i8* %i, align 1
+  br label %for.cond, !llvm.loop !10
+
+for.end:                                          ; preds = %for.cond
+  %4 = load i8, i8* %total, align 1
+  ret i8 %4
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @main() #0 {
+entry:
+  %last = alloca i8, align 1
+  %c = alloca i8, align 1
+  store volatile i8 99, i8* %last, align 1
+  store i8 97, i8* %c, align 1
+  br label %for.cond
+
+for.cond:                                         ; preds = %for.inc, %entry
+  %0 = load i8, i8* %c, align 1
+  %conv = zext i8 %0 to i32
+  %1 = load volatile i8, i8* %last, align 1
+  %conv1 = zext i8 %1 to i32
+  %cmp = icmp sle i32 %conv, %conv1
+  br i1 %cmp, label %for.body, label %for.end
+
+for.body:                                         ; preds = %for.cond
+  %2 = load i8, i8* %c, align 1
+  %conv3 = zext i8 %2 to i32
+  %cmp4 = icmp eq i32 %conv3, 122
+  br i1 %cmp4, label %if.then, label %if.else
+
+if.then:                                          ; preds = %for.body
+  call void @putchar(i8 noundef zeroext 33)
+  br label %if.end
+
+if.else:                                          ; preds = %for.body
+  %3 = load i8, i8* %c, align 1
+  call void @putchar(i8 noundef zeroext %3)
+  br label %if.end
+
+if.end:                                           ; preds = %if.else, %if.then
+  br label %for.inc
+
+for.inc:                                          ; preds = %if.end
+  %4 = load i8, i8* %c, align 1
+  %inc = add i8 %4, 1
+  store i8 %inc, i8* %c, align 1
+  br label %for.cond, !llvm.loop !11
+
+for.end:                                          ; preds = %for.cond
+  %call = call zeroext i8 @bump(i8 noundef zeroext 3)
+  %conv6 = zext i8 %call to i32
+  %add = add nsw i32 64, %conv6
+  %conv7 = trunc i32 %add to i8
+  call void @putchar(i8 noundef zeroext %conv7)
+  ret void
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
+!10 = distinct !{!10, !7}
+!11 = distinct !{!11, !7}
@@ -0,0 +1,1005 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 8206 ops ~186769 steps
+frame widths:
+  main: 37 cells
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+r4 >>>>
+++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l37 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%14
+        #B:%18
+        #B:%24
+        #B:%26
+        #B:%38
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%3{1}
+        #alloc_%3{2}
+        #alloc_%4
+        #%5_=_bitcast_{3_x_i8}*_%3_to_i8*(mult)
+        #%copy_0_0_byte_=_load_getelementptr_inbounds_({3_x_i8}*_@__const_main_word__i64_0__i64_0)__align_1(mult)
+        #%copy_0_1_dst_=_getelementptr_inbounds_i8*_%5__i64_1(mult)
+        #%23_=_icmp_ne_i32_%20__i32_%22(mult)
+        #%25_=_phi_i1_{_i1_false__%14_}__{_i1_%23__%18_}(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca {3 x i8}_ align 1
+            %4 = alloca i8_ align 1
+            store volatile i8 98_ i8* %1_ align 1
+            
+            >>>>>>>[-]
+            >>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>]
+            store i8 255_ i8* %2_ align 1
+            
+            <<<<<<<<<<[-]
+            >>>>>>>>>>>+++++++++++++++
+            [
+                -
+                <<<<<<<<<<<+++++++++++++++++
+            >>>>>>>>>>>]
+            %5 = bitcast {3 x i8}* %3 to i8*
+            
+            <<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/21/23 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <<++++++++++++
+            mov20/15 [-<<<<<+>>>>>]
+            %copy_0_0_byte = load getelementptr inbounds ({3 x i8}* @__const_main_word_ i64 0_ i64 0)_ align 1
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_1
+            +
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/27 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/25 [-<<+>>]
+                <<+++++++++++++++++++++++
+            >>]
+            <[
+                if neg
+                <+++++++++++++++++++++++
+                >[
+                    -
+                    <-
+                >]
+            ]
+            drive left! choo choo
+            <[
+                mov24/23 <[-<+>]
+                mov25/24 >[-<+>]
+                
+                mov22/25 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup22/23/25 <<<[->+>>+<<<]
+            mov23/22 >[-<+>]
+            >[
+                mov26/23 >>[-<<<+>>>]
+                mov25/26 <[->+<]
+                mov24/25 <[->+<]
+                r1 >
+                -
+            ]
+            mov25/16 >[-<<<<<<<<<+>>>>>>>>>]
+            store i8 %copy_0_0_byte_ i8* %5_ align 1
+            
+            dup16/20/21 <<<<<<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            dup15/22/23 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov22/15 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/29/31 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/31 [->+<]
+            >>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov31/26 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++++++++++
+            >>>>>]
+            <<[
+                if neg
+                <<<++++++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            mov21/27 <<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov25/24 <[-<+>]
+                mov26/25 >[-<+>]
+                mov27/26 >[-<+>]
+                mov23/27 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov27/23 >>>>[-<<<<+>>>>]
+            <<[
+                mov28/24 >>>[-<<<<+>>>>]
+                
+                mov25/26 <<<[->+<]
+                r1 >
+                -
+            ]
+            %copy_0_1_byte = load getelementptr inbounds ({3 x i8}* @__const_main_word_ i64 0_ i64 1)_ align 1
+            
+            <<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_2
+            ++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/23 [-<<<<+>>>>]
+                <<<<+++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov22/21 <[-<+>]
+                mov23/22 >[-<+>]
+                
+                mov20/23 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup20/21/23 <<<[->+>>+<<<]
+            mov21/20 >[-<+>]
+            >[
+                mov24/21 >>[-<<<+>>>]
+                mov23/24 <[->+<]
+                mov22/23 <[->+<]
+                r1 >
+                -
+            ]
+            mov23/16 >[-<<<<<<<+>>>>>>>]
+            %copy_0_1_dst = getelementptr inbounds i8* %5_ i64 1
+            
+            <<<<<<[-]
+            dup15/20/21 <<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/17 <[-<<<<+>>>>]
+            >[
+                -
+                <<<<<+
+            >>>>>]
+            store i8 %copy_0_1_byte_ i8* %copy_0_1_dst_ align 1
+            
+            dup16/20/21 <<<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            dup17/22/23 <<<[->>>>>+>+<<<<<<]
+            mov22/17 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/29/30 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/34 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov34/26 [-<<<<<<<<+>>>>>>>>]
+                <<<<<<<<++++++++++++++++++++++++
+            >>>>>>>>]
+            <<<<[
+                if neg
+                <<<<++++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov21/27 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov25/24 <[-<+>]
+                mov26/25 >[-<+>]
+                mov27/26 >[-<+>]
+                mov23/27 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov27/23 >>>>[-<<<<+>>>>]
+            <<[
+                mov28/24 >>>[-<<<<+>>>>]
+                
+                mov25/26 <<<[->+<]
+                r1 >
+                -
+            ]
+            %copy_0_2_byte = load getelementptr inbounds ({3 x i8}* @__const_main_word_ i64 0_ i64 2)_ align 1
+            
+            <<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_3
+            +++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/23 [-<<<<+>>>>]
+                <<<<+++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov22/21 <[-<+>]
+                mov23/22 >[-<+>]
+                
+                mov20/23 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup20/21/23 <<<[->+>>+<<<]
+            mov21/20 >[-<+>]
+            >[
+                mov24/21 >>[-<<<+>>>]
+                mov23/24 <[->+<]
+                mov22/23 <[->+<]
+                r1 >
+                -
+            ]
+            mov23/16 >[-<<<<<<<+>>>>>>>]
+            %copy_0_2_dst = getelementptr inbounds i8* %5_ i64 2
+            
+            <<<<<<[-]
+            dup15/20/21 <<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            mov21/17 <[-<<<<+>>>>]
+            >[
+                -
+                <<<<<+
+            >>>>>]
+            store i8 %copy_0_2_byte_ i8* %copy_0_2_dst_ align 1
+            
+            dup16/20/21 <<<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            dup17/22/23 <<<[->>>>>+>+<<<<<<]
+            mov22/17 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/29/30 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/31 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov31/26 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov21/27 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov25/24 <[-<+>]
+                mov26/25 >[-<+>]
+                mov27/26 >[-<+>]
+                mov23/27 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov27/23 >>>>[-<<<<+>>>>]
+            <<[
+                mov28/24 >>>[-<<<<+>>>>]
+                
+                mov25/26 <<<[->+<]
+                r1 >
+                -
+            ]
+            %6 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<<[-]
+            dup9/15/20 <<<<<<[->>>>>>+>>>>>+<<<<<<<<<<<]
+            mov20/9 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %7 = zext i8 %6 to i32
+            
+            <<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %8 = sub i32 %7_ i32 1
+            
+            <<<<<<[-]
+            dup16/20/21 >[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/15 <[-<<<<<<+>>>>>>]
+            >[
+                -
+                >>+
+                <<<<<<<<<[
+                    -
+                    mov15/23 [->>>>>>>>+<<<<<<<<]
+                    >>>>>>>>>-
+                <<<<<<<<<]
+                mov23/15 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>]
+            <<]
+            %9 = trunc i32 %8 to i8
+            
+            <<<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            dup16/20/21 <<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %10 = load volatile i8* %1_ align 1
+            
+            <<<<<<[-]
+            dup9/15/20 <<<<<<[->>>>>>+>>>>>+<<<<<<<<<<<]
+            mov20/9 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %12 = sub i32 %11_ i32 30
+            
+            <<<<<<[-]
+            dup16/20/21 >[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_30
+            >+++++
+            [
+                -
+                <++++++
+            >]
+            mov21/15 <<[-<<<<<<+>>>>>>]
+            >[
+                -
+                >>>+
+                <<<<<<<<<<[
+                    -
+                    mov15/24 [->>>>>>>>>+<<<<<<<<<]
+                    >>>>>>>>>>-
+                <<<<<<<<<<]
+                mov24/15 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>>]
+            <<<]
+            %13 = trunc i32 %12 to i8
+            
+            <<<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            call @putchar(i8 %13)
+            
+            putchar intrinsic
+            dup16/20/21 <<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            store i8 3_ i8* %4_ align 1
+            
+            <<<<<<<[-]
+            +++
+            br label %14
+            
+            <<<<<<<<<<+
+        <<]
+        >>#B:%14
+        [
+            -
+            %15 = load i8* %4_ align 1
+            
+            >>>>>>>>>>>[-]
+            dup14/15/20 <[->+>>>>>+<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            %16 = zext i8 %15 to i32
+            
+            <<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %17 = icmp ne i32 %16_ i32 0
+            
+            <<<<<<[-]
+            dup16/20/21 >[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            >[
+                [-]
+                <<<<<<+
+            >>>>>>]
+            br i1 %17_ label %18_ label %24
+            
+            dup15/21/20 <<<<<<[->>>>>>+<+<<<<<]
+            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
+            doing phi stuff
+            %25 = phi i1 { i1 false_ %14 }_ { i1 %23_ %18 }
+            op_to_reg storing const value in temp address
+            >#constop_0
+            
+            mov22/23 [->+<]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%18_true
+                >>>>>>>>>>>>>>>>>>[-]
+            <<<]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%24_false
+                >>>>>>>>>>>>>[-]
+                mov23/19 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<<]
+        >]
+        >#B:%18
+        [
+            -
+            %19 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>[-]
+            dup9/15/24 <<<<<<[->>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<]
+            mov24/9 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %20 = zext i8 %19 to i32
+            
+            <<<<<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %21 = load i8* %2_ align 1
+            
+            <<<<<<[-]
+            dup10/15/20 <<<<<[->>>>>+>>>>>+<<<<<<<<<<]
+            mov20/10 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %22 = zext i8 %21 to i32
+            
+            <<<[-]
+            dup15/20/21 <<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/17 >[-<<<<+>>>>]
+            %23 = icmp ne i32 %20_ i32 %22
+            
+            <<<[-]
+            dup16/20/21 <<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            dup17/22/23 <<<[->>>>>+>+<<<<<<]
+            mov22/17 >>>>>[-<<<<<+>>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/25 <[->>>>+<<<<]
+            >>>>>>+
+            <<<<[
+                -
+                >+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<+
+            >>>>>>>]
+            <[
+                [-]
+                <<<<<<+
+            >>>>>>]
+            br label %24
+            
+            doing phi stuff
+            %25 = phi i1 { i1 false_ %14 }_ { i1 %23_ %18 }
+            <<<<<[-]
+            mov18/19 <[->+<]
+            <<<<<<<<<<<<+
+        <]
+        >#B:%24
+        [
+            -
+            %25 = phi i1 { i1 false_ %14 }_ { i1 %23_ %18 }
+            br i1 %25_ label %26_ label %38
+            
+            dup19/21/20 >>>>>>>>>>>>>[->>+<+<]
+            mov21/19 >>[-<<+>>]
+            <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%26_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:main/%38_false
+            <<<<<]
+        >>>]
+        >#B:%26
+        [
+            -
+            %27 = load i8* %4_ align 1
+            
+            >>>>>>>>[-]
+            dup14/15/22 <[->+>>>>>>>+<<<<<<<<]
+            mov22/14 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %28 = zext i8 %27 to i32
+            
+            <<<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %29 = sub i32 %28_ i32 1
+            
+            <<<<[-]
+            dup16/20/21 <[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/17 <[-<<<<+>>>>]
+            >[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov17/23 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov23/17 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            %30 = sext i32 %29 to i64
+            
+            <<<<<<[-]
+            dup17/20/21 >[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %31 = getelementptr inbounds {3 x i8}* %3_ i64 0_ i64 %30
+            
+            <<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/21/22 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++++++
+            dup16/22/23 <<<<[->>>>>>+>+<<<<<<<]
+            mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            mov20/17 <<[-<<<+>>>]
+            >>>[
+                -
+                <<<<<<+
+            >>>>>>]
+            %32 = load i8* %31_ align 1
+            
+            <<<<<<<[-]
+            dup17/20/21 >[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/26/27 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov26/0 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/28 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov28/24 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                
+                mov21/24 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup21/22/24 <<<[->+>>+<<<]
+            mov22/21 >[-<+>]
+            >[
+                mov25/22 >>[-<<<+>>>]
+                mov24/25 <[->+<]
+                mov23/24 <[->+<]
+                r1 >
+                -
+            ]
+            mov24/16 >[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %32)
+            
+            putchar intrinsic
+            dup16/20/21 <<<<<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %35 = zext i8 %27 to i32
+            
+            <<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            %36 = sub i32 %35_ i32 1
+            
+            <<<<<<[-]
+            dup16/20/21 >[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/15 <[-<<<<<<+>>>>>>]
+            >[
+                -
+                >>+
+                <<<<<<<<<[
+                    -
+                    mov15/23 [->>>>>>>>+<<<<<<<<]
+                    >>>>>>>>>-
+                <<<<<<<<<]
+                mov23/15 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>]
+            <<]
+            %37 = trunc i32 %36 to i8
+            
+            <<<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov21/16 >[-<<<<<+>>>>>]
+            store i8 %37_ i8* %4_ align 1
+            
+            dup16/20/21 <<<<<[->>>>+>+<<<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<<<<<[-]
+            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
+            br label %14
+            
+            <<<<<<<<<<<<<<<<<+
+        >>>]
+        >#B:%38
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>]
+    <<<<<<<]
+<]
+clear globals:
+l4 <<<
+[-]
+>[-]
+>[-]
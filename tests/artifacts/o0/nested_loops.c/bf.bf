@@ -0,0 +1,1112 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 8103 ops ~153563 steps
+frame widths:
+  main: 44 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%7
+        #B:%13
+        #B:%14
+        #B:%20
+        #B:%26
+        #B:%31
+        #B:%36
+        #B:%37
+        #B:%38
+        #B:%39
+        #B:%43
+        #B:%47
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_volatile_i8*_%2__align_1(mult)
+        #%9_=_zext_i8_%8_to_i32(mult)
+        #%11_=_zext_i8_%10_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>>>>>>>[-]
+            
+            store volatile i8 4_ i8* %2_ align 1
+            
+            >[-]
+            ++++
+            %6 = load volatile i8* %2_ align 1
+            
+            >>>>[-]
+            known to be 4
+            ++++
+            store i8 %6_ i8* %3_ align 1
+            
+            <<<[-]
+            ++++
+            store i8 0_ i8* %4_ align 1
+            
+            >[-]
+            
+            br label %7
+            
+            <<<<<<<<<<<<<<<+
+        <<]
+        >>#B:%7
+        [
+            -
+            %8 = load i8* %4_ align 1
+            
+            >>>>>>>>>>>>>>>>>[-]
+            dup19/21/24 <<[->>+>>>+<<<<<]
+            mov24/19 >>>>>[-<<<<<+>>>>>]
+            scratch must be clean
+            
+            
+            %9 = zext i8 %8 to i32
+            
+            <<[-]
+            dup21/25/24 <[->>>>+<+<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
+            mov24/22 <[-<<+>>]
+            scratch must be clean
+            
+            
+            >[
+            ]
+            %10 = load i8* %3_ align 1
+            
+            <<<<[-]
+            dup18/21/24 <<<[->>>+>>>+<<<<<<]
+            mov24/18 >>>>>>[-<<<<<<+>>>>>>]
+            scratch must be clean
+            
+            
+            %11 = zext i8 %10 to i32
+            
+            <[-]
+            dup21/24/26 <<[->>>+>>+<<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov26/23 >>[-<<<+>>>]
+            scratch must be clean
+            <<[
+            ]
+            >>[
+            ]
+            %12 = icmp slt i32 %9_ i32 %11
+            
+            <<<<<[-]
+            dup22/24/25 >[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            dup23/27/26 <[->>>>+<+<<<]
+            mov27/23 >>>>[-<<<<+>>>>]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/30 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/35 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/38 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <<<<<<<<<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            br i1 %12_ label %13_ label %47
+            
+            dup21/25/24 <<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%13_true
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>+
+                #B:main/%47_false
+            <<<<<<<<<<<<]
+        >]
+        >#B:%13
+        [
+            -
+            store i8 0_ i8* %5_ align 1
+            
+            >>>>>>>>>>>>>>>[-]
+            
+            scratch must be clean
+            >>>>[
+            ]
+            >[
+            ]
+            br label %14
+            
+            <<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%14
+        [
+            -
+            %15 = load i8* %5_ align 1
+            
+            >>>>>>>>>>>>>>>[-]
+            dup20/21/24 <[->+>>>+<<<<]
+            mov24/20 >>>>[-<<<<+>>>>]
+            scratch must be clean
+            
+            
+            %16 = zext i8 %15 to i32
+            
+            <<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/22 >[-<<<+>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %17 = load i8* %4_ align 1
+            
+            <<<<[-]
+            dup19/21/24 <<[->>+>>>+<<<<<]
+            mov24/19 >>>>>[-<<<<<+>>>>>]
+            scratch must be clean
+            
+            
+            %18 = zext i8 %17 to i32
+            
+            <[-]
+            dup21/24/25 <<[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/23 >[-<<+>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %19 = icmp sle i32 %16_ i32 %18
+            
+            <<<<[-]
+            dup22/24/25 >[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            dup23/26/27 <[->>>+>+<<<<]
+            mov26/23 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/30 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/35 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/41 <<<[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>>>>>>+
+            <<<<<<<<[
+                -
+                
+                >>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<]
+            >>>>>>>>-
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <<<<<<<<<<<<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >>>>[
+            ]
+            >[
+            ]
+            >[
+            ]
+            br i1 %19_ label %20_ label %43
+            
+            dup21/25/24 <<<<<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%20_true
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>+
+                #B:main/%43_false
+            <<<<<<<<<<<]
+        >>>]
+        >#B:%20
+        [
+            -
+            %21 = load i8* %5_ align 1
+            
+            >>>>>>>>>>>>>>[-]
+            dup20/21/26 <[->+>>>>>+<<<<<<]
+            mov26/20 >>>>>>[-<<<<<<+>>>>>>]
+            scratch must be clean
+            <<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %22 = zext i8 %21 to i32
+            
+            <<<<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/22 >[-<<<+>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %23 = load i8* %4_ align 1
+            
+            <<<<[-]
+            dup19/21/24 <<[->>+>>>+<<<<<]
+            mov24/19 >>>>>[-<<<<<+>>>>>]
+            scratch must be clean
+            
+            
+            %24 = zext i8 %23 to i32
+            
+            <[-]
+            dup21/24/25 <<[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/23 >[-<<+>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %25 = icmp eq i32 %22_ i32 %24
+            
+            <<<<[-]
+            dup22/24/25 >[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            dup23/26/27 <[->>>+>+<<<<]
+            mov26/23 >>>[-<<<+>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/29 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            br i1 %25_ label %26_ label %31
+            
+            dup21/25/24 <<<<<<<<<<[->>>>+<+<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%26_true
+            >>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%31_false
+            <<<<<<]
+        >>>>]
+        >#B:%26
+        [
+            -
+            %27 = load i8* %5_ align 1
+            
+            >>>>>>>>>>>>>[-]
+            dup20/21/26 <[->+>>>>>+<<<<<<]
+            mov26/20 >>>>>>[-<<<<<<+>>>>>>]
+            scratch must be clean
+            <<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %28 = zext i8 %27 to i32
+            
+            <<<<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/22 >[-<<<+>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %29 = add i32 48_ i32 %28
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup22/26/27 <<<[->>>>+>+<<<<<]
+            mov26/22 >>>>[-<<<<+>>>>]
+            mov24/21 <<[-<<<+>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup21/29/31 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov31/21 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %30 = trunc i32 %29 to i8
+            
+            <<<<<<<<<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/22 >[-<<<+>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            call @putchar(i8 %30)
+            
+            putchar intrinsic
+            dup22/24/25 <<<[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            >.
+            [-]
+            br label %39
+            
+            <<<<<<<<<<<<+
+        <<<<<]
+        >#B:%31
+        [
+            -
+            %32 = load i8* %5_ align 1
+            
+            >>>>>>>>>>>>[-]
+            dup20/21/24 <[->+>>>+<<<<]
+            mov24/20 >>>>[-<<<<+>>>>]
+            scratch must be clean
+            
+            
+            %33 = zext i8 %32 to i32
+            
+            <<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            mov25/22 >[-<<<+>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %34 = and i32 %33_ i32 1
+            
+            <<<<[-]
+            dup22/24/25 >[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov25/32 <[->>>>>>>+<<<<<<<]
+            mov26/37 >[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>+
+            >>>>>+
+            <<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup36/33/32 >>>>[-<<<+<+>>>>]
+                mov33/36 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup41/38/37 >>>>[-<<<+<+>>>>]
+                mov38/41 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov40/27 >>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup31/28/29 <<<<<<<<<[-<<<+>+>>]
+                mov28/31 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov28/27 >[-<+>]
+                >]
+                mov27/21 <<[-<<<<<<+>>>>>>]
+                continue?
+                dup32/27/28 >>>>>[-<<<<<+>+>>>>]
+                mov27/32 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup37/27/28 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov27/37 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            scratch must be clean
+            <<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %35 = icmp ne i32 %34_ i32 0
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<<+
+            >>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %35_ label %36_ label %37
+            
+            dup22/25/24 <<<[->>>+<+<<]
+            mov25/22 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%36_true
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%37_false
+            <<<<<<<<]
+        >>>>>>]
+        >#B:%36
+        [
+            -
+            call @putchar(i8 111)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>#constop_111
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %38
+            
+            <<<<<<<<<<<<<<+
+        <<]
+        >#B:%37
+        [
+            -
+            call @putchar(i8 101)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_101
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %38
+            
+            <<<<<<<<<<<<+
+        <]
+        >#B:%38
+        [
+            -
+            br label %39
+            
+            >+
+        <]
+        >#B:%39
+        [
+            -
+            %41 = load i8* %5_ align 1
+            
+            >>>>>>>>[-]
+            dup20/21/24 <[->+>>>+<<<<]
+            mov24/20 >>>>[-<<<<+>>>>]
+            scratch must be clean
+            
+            
+            %42 = add i8 %41_ i8 1
+            
+            <<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov25/22 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup22/28/30 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov30/22 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov27/28 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov28/27 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            store i8 %42_ i8* %5_ align 1
+            
+            dup22/24/25 <<<<<<<<[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            <<<<[-]
+            mov25/20 >>>>>[-<<<<<+>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br label %14
+            
+            <<<<<<<<<<<<<<<<<<<+
+        >>>>>>>]
+        >#B:%43
+        [
+            -
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_32
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %45 = load i8* %4_ align 1
+            
+            <<<[-]
+            dup19/21/24 <<[->>+>>>+<<<<<]
+            mov24/19 >>>>>[-<<<<<+>>>>>]
+            scratch must be clean
+            
+            
+            %46 = add i8 %45_ i8 1
+            
+            <<[-]
+            dup21/24/25 <[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov25/22 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup22/28/30 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov30/22 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov27/28 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov28/27 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            store i8 %46_ i8* %4_ align 1
+            
+            dup22/24/25 <<<<<<<<[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            <<<<<[-]
+            mov25/19 >>>>>>[-<<<<<<+>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br label %7
+            
+            <<<<<<<<<<<<<<<<<<<<<+
+        >>>>>>>>>>]
+        >#B:%47
+        [
+            -
+            %48 = load i32* %1_ align 4
+            
+            >>>>>>[-]
+            dup16/21/24 <<<<<[->>>>>+>>>+<<<<<<<<]
+            mov24/16 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            scratch must be clean
+            
+            
+            ret i32 %48
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>]
+    <<<<<<<<<<<<<<]
+<]
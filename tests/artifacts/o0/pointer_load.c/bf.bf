@@ -1,830 +1,97 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 5057 ops ~67077 steps
+frame widths:
+  main: 25 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/19 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup10/11/18 <[->+>>>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup11/19/18 <[->>>>>>>>+<+<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/22 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup10/13/18 <<<[->>>+>>>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup13/18/19 <[->>>>>+>+<<<<<<]
-            mov18/13 >>>>>[-<<<<<+>>>>>]
-            mov19/14 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup14/18/19 >>>>>>[->>>>+>+<<<<<]
-            mov18/14 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup10/15/18 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup15/18/19 <[->>>+>+<<<<]
-            mov18/15 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov19/16 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup16/18/19 <<<<[->>+>+<<<]
-            mov18/16 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>[-]
-            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup16/18/37 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup18/38/37 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov37/41 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
-                -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup16/20/37 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup17/21/37 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup20/37/38 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov37/20 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup21/39/40 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup40/42/44 >[->>+>>+<<<<]
-            mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/45 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov45/38 >>>>[-<<<<<<<+>>>>>>>]
-                dup40/42/44 <<<<<[->>+>>+<<<<]
-                mov44/40 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup22/37/38 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/40 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/24/37 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup24/37/38 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup38/25/40 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov40/38 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup25/37/38 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup17/26/37 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup26/37/38 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov37/26 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/41 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup16/28/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov37/16 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup17/29/37 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup28/37/38 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup29/39/40 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup40/42/43 >[->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/44 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov44/38 >>>[-<<<<<<+>>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup30/37/38 <[->>>>>>>+>+<<<<<<<<]
-            mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov43/38 <<[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov41/31 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov40/31 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup31/38/39 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov37/32 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup32/37/38 <[->>>>>+>+<<<<<<]
-            mov37/32 >>>>>[-<<<<<+>>>>>]
-            mov38/33 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup33/37/38 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov37/33 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup17/34/37 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup34/37/38 <[->>>+>+<<<<]
-            mov37/34 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup39/41/42 [->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov38/43 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov43/38 >>>[-<<<<<+>>>>>]
-                dup39/41/42 <<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup35/37/38 <<<<<<[->>+>+<<<]
-            mov37/35 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<]
+<<[
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<-
             #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            >-
+            #dead_fn_pad/main
+            >>-
+            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#alloc_%1
-        >#alloc_%2
-        >#alloc_%3
-        >#alloc_%4
-        >#alloc_%5
-        >#%6_=_load_i32**_%2__align_8(mult)
-        >#%7_=_load_i32*_%6__align_4(mult)
-        >#%8_=_trunc_i32_%7_to_i8(mult)
-        >#%9_=_load_i32***_%3__align_8(mult)
-        >#%10_=_load_i32**_%9__align_8(mult)
-        >#%11_=_load_i32*_%10__align_4(mult)
-        >#%12_=_trunc_i32_%11_to_i8(mult)
-        >#%13_=_load_i32****_%4__align_8(mult)
-        >#%14_=_load_i32***_%13__align_8(mult)
-        >#%15_=_load_i32**_%14__align_8(mult)
-        >#%16_=_load_i32*_%15__align_4(mult)
-        >#%17_=_trunc_i32_%16_to_i8(mult)
-        >#%18_=_load_i32*****_%5__align_8(mult)
-        >#%19_=_load_i32****_%18__align_8(mult)
-        >#%20_=_load_i32***_%19__align_8(mult)
-        >#%21_=_load_i32**_%20__align_8(mult)
-        >#%22_=_load_i32*_%21__align_4(mult)
-        >#%23_=_trunc_i32_%22_to_i8(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_main
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_i32**_%2__align_8(mult)
+        #%7_=_load_i32*_%6__align_4(mult)
+        <#B:%0
         [
             -
             copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
             %1 = alloca i32_ align 4
             %2 = alloca i32*_ align 8
             %3 = alloca i32**_ align 8
             %4 = alloca i32***_ align 8
             %5 = alloca i32****_ align 8
             store i32 112_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_112
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            <<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov34/11 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            
+            >>[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++++++++
+            >>>>>>>]
             store i32* %1_ i32** %2_ align 8
+            
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/36/35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov35/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup0/13/12 <<<<<<<<<<<[->>>>>>>>>>>>>+<+<<<<<<<<<<<<]
+            mov12/0 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
             r1 >
-            ++++++++++++
-            <<<<<<<<<<<<<<<<<<<<<<<[-]
-            mov35/12 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            +++++
+            <<<<<<<[-]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
             %6 = load i32** %2_ align 8
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup12/16/34 <<<<[->>>>+>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            mov34/12 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<<[-]
+            dup5/9/11 <<<<[->>>>+>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
             %7 = load i32* %6_ align 4
-            <<<<<<<<<<<<<<<<<[-]
-            dup16/34/36 <[->>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            >>>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >#stackptr
-            <<<<<#stackptr_tmp
-            l1 <
-            dup0/36/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            dup9/11/13 <[->>+>>+<<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/13/18 <<<<<<<<<<<<[->>>>>>>>>>>>>+>>>>>+<<<<<<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
             r1 >
-            >>>>>#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            >>>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<[
                 -
@@ -844,12 +111,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/39 [-<<<+>>>]
-                <<<+++++++++++++++++++++++++++++++++++++
+                mov19/16 [-<<<+>>>]
+                <<<++++++++++++++
             >>>]
             <[
                 if neg
-                <<+++++++++++++++++++++++++++++++++++++
+                <<++++++++++++++
                 >>[
                     -
                     <<-
@@ -857,75 +124,73 @@ runtime init:
             ]
             drive left! choo choo
             <<[
-                mov38/37 <[-<+>]
-                mov39/38 >[-<+>]
+                mov15/14 <[-<+>]
+                mov16/15 >[-<+>]
                 
-                mov36/39 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov13/16 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup36/37/39 <<<[->+>>+<<<]
-            mov37/36 >[-<+>]
+            dup13/14/16 <<<[->+>>+<<<]
+            mov14/13 >[-<+>]
             >[
-                mov40/37 >>[-<<<+>>>]
-                mov39/40 <[->+<]
-                mov38/39 <[->+<]
+                mov17/14 >>[-<<<+>>>]
+                mov16/17 <[->+<]
+                mov15/16 <[->+<]
                 r1 >
                 -
             ]
-            mov39/17 >[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            mov16/10 >[-<<<<<<+>>>>>>]
             %8 = trunc i32 %7 to i8
-            <<<<<<<<<<<<<<<<<<<<<[-]
-            dup17/34/35 <[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            mov35/18 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            
+            <<<<<<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            mov12/9 >[-<<<+>>>]
             call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_0
-            +
+            
             putchar intrinsic
-            dup18/34/35 >>>>>>>>>>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
-            mov34/18 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            dup9/11/12 <<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
             store i32** %2_ i32*** %3_ align 8
+            
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/35/36 <<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<+++++++++++++
-            <<<<<<<<<<<<<<<<<<<<<[-]
-            mov34/13 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            dup0/12/13 <<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <++++++
+            <<<<<[-]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
             %9 = load i32*** %3_ align 8
-            <<<<<<<<<<<<<<<[-]
-            dup13/19/34 <<<<<<[->>>>>>+>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov34/13 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup6/9/11 <<<[->>>+>>+<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
             %10 = load i32** %9_ align 8
-            <<<<<<<<<<<<<<[-]
-            dup19/34/35 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/45 <<<<<<<[->>>>>+<<<<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/22 [->>>>>+<<<<<]
             >>>>>>>+
             <<<<<<<<<<<<[
                 -
@@ -945,12 +210,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov45/38 [-<<<<<<<+>>>>>>>]
-                <<<<<<<++++++++++++++++++++++++++++++++++++
+                mov22/15 [-<<<<<<<+>>>>>>>]
+                <<<<<<<+++++++++++++
             >>>>>>>]
             <<<<[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -958,44 +223,45 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/20 >[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            mov15/10 >[-<<<<<+>>>>>]
             %11 = load i32* %10_ align 4
-            <<<<<<<<<<<<<<<<<[-]
-            dup20/34/35 <[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov34/20 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1015,12 +281,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1028,75 +294,73 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/21 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov15/9 >[-<<<<<<+>>>>>>]
             %12 = trunc i32 %11 to i8
-            <<<<<<<<<<<<<<<<[-]
-            dup21/34/35 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov34/21 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            mov35/22 >[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            
+            <<<<<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            mov12/10 >[-<<+>>]
             call @putchar(i8 %12)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_1
-            +
+            
             putchar intrinsic
-            dup22/34/35 >>>>>>>>>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov34/22 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            dup10/11/12 <<[->+>+<<]
+            mov11/10 >[-<+>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
             store i32*** %3_ i32**** %4_ align 8
+            
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/35/36 <<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<++++++++++++++
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov34/14 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            dup0/12/13 <<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            <<<<[-]
+            mov11/7 >>>>[-<<<<+>>>>]
             %13 = load i32**** %4_ align 8
-            <<<<<<<<<<<[-]
-            dup14/23/34 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov34/14 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup7/9/11 <<[->>+>>+<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
             %14 = load i32*** %13_ align 8
-            <<<<<<<<<<[-]
-            dup23/34/35 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov34/23 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1116,12 +380,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1129,44 +393,45 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/24 >[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            mov15/10 >[-<<<<<+>>>>>]
             %15 = load i32** %14_ align 8
-            <<<<<<<<<<<<<[-]
-            dup24/34/35 <[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov34/24 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1186,12 +451,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1199,44 +464,45 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/25 >[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov15/9 >[-<<<<<<+>>>>>>]
             %16 = load i32* %15_ align 4
-            <<<<<<<<<<<<[-]
-            dup25/34/35 <[->>>>>>>>>+>+<<<<<<<<<<]
-            mov34/25 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1256,12 +522,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1269,75 +535,73 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/26 >[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            mov15/10 >[-<<<<<+>>>>>]
             %17 = trunc i32 %16 to i8
-            <<<<<<<<<<<[-]
-            dup26/34/35 <[->>>>>>>>+>+<<<<<<<<<]
-            mov34/26 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov35/27 >[-<<<<<<<<+>>>>>>>>]
+            
+            <<<<<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            mov12/9 >[-<<<+>>>]
             call @putchar(i8 %17)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
-            dup27/34/35 >>>>>>>>>>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
-            mov34/27 >>>>>>>[-<<<<<<<+>>>>>>>]
+            dup9/11/12 <<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
             store i32**** %4_ i32***** %5_ align 8
+            
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/35/36 <<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<+++++++++++++++
-            <<<<<<<<<<<<<<<<<<<[-]
-            mov34/15 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            dup0/12/13 <<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <++++++++
+            <<<[-]
+            mov11/8 >>>[-<<<+>>>]
             %18 = load i32***** %5_ align 8
-            <<<<<<[-]
-            dup15/28/34 <<<<<<<<<<<<<[->>>>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            mov34/15 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            
+            <<[-]
+            dup8/9/11 <[->+>>+<<<]
+            mov11/8 >>>[-<<<+>>>]
             %19 = load i32**** %18_ align 8
-            <<<<<[-]
-            dup28/34/35 <[->>>>>>+>+<<<<<<<]
-            mov34/28 >>>>>>[-<<<<<<+>>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1357,12 +621,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1370,44 +634,45 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/29 >[-<<<<<<<<<+>>>>>>>>>]
+            mov15/10 >[-<<<<<+>>>>>]
             %20 = load i32*** %19_ align 8
-            <<<<<<<<[-]
-            dup29/34/35 <[->>>>>+>+<<<<<<]
-            mov34/29 >>>>>[-<<<<<+>>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<[-]
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1427,12 +692,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1440,44 +705,45 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/30 >[-<<<<<<<<+>>>>>>>>]
+            mov15/9 >[-<<<<<<+>>>>>>]
             %21 = load i32** %20_ align 8
-            <<<<<<<[-]
-            dup30/34/35 <[->>>>+>+<<<<<]
-            mov34/30 >>>>[-<<<<+>>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1497,12 +763,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1510,44 +776,45 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/31 >[-<<<<<<<+>>>>>>>]
+            mov15/10 >[-<<<<<+>>>>>]
             %22 = load i32* %21_ align 4
+            
             <<<<<<[-]
-            dup31/34/35 <[->>>+>+<<<<]
-            mov34/31 >>>[-<<<+>>>]
-            >>#train_tmp
-            >#train_ret
-            >#train_ptr
+            dup10/11/12 >[->+>+<<]
+            mov11/10 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
             
-            >>#stackptr
-            <#stackptr_tmp
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov40/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/17/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             r1 >
-            >#ptr_underflow
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov40/42 <<<<[->>+<<]
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/19 [->>+<<]
             >>>>+
             <<<<<<<<<[
                 -
@@ -1567,12 +834,12 @@ runtime init:
             <<#subnu_to
             [
                 if pos
-                mov42/38 [-<<<<+>>>>]
-                <<<<++++++++++++++++++++++++++++++++++++
+                mov19/15 [-<<<<+>>>>]
+                <<<<+++++++++++++
             >>>>]
             <[
                 if neg
-                <<<++++++++++++++++++++++++++++++++++++
+                <<<+++++++++++++
                 >>>[
                     -
                     <<<-
@@ -1580,75 +847,55 @@ runtime init:
             ]
             drive left! choo choo
             <<<[
-                mov37/36 <[-<+>]
-                mov38/37 >[-<+>]
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
                 
-                mov35/38 <<<[->>>+<<<]
-                l1 <
-                >>>-
+                mov12/15 <<<[->>>+<<<]
+                l1 
+                >>-
                 <+
             >]
             get our bag
-            dup35/36/38 <<<[->+>>+<<<]
-            mov36/35 >[-<+>]
+            dup12/13/15 <<<[->+>>+<<<]
+            mov13/12 >[-<+>]
             >[
-                mov39/36 >>[-<<<+>>>]
-                mov38/39 <[->+<]
-                mov37/38 <[->+<]
+                mov16/13 >>[-<<<+>>>]
+                mov15/16 <[->+<]
+                mov14/15 <[->+<]
                 r1 >
                 -
             ]
-            mov38/32 >[-<<<<<<+>>>>>>]
+            mov15/9 >[-<<<<<<+>>>>>>]
             %23 = trunc i32 %22 to i8
+            
             <<<<<[-]
-            dup32/34/35 <[->>+>+<<<]
-            mov34/32 >>[-<<+>>]
-            mov35/33 >[-<<+>>]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            mov12/10 >[-<<+>>]
             call @putchar(i8 %23)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_3
-            +
+            
             putchar intrinsic
-            dup33/34/35 >>>>>>>>>>>>>>>>>>>>>>>[->+>+<<]
-            mov34/33 >[-<+>]
+            dup10/11/12 <<[->+>+<<]
+            mov11/10 >[-<+>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
             ret i32 0
+            
             zero all function allocs
+            <<<<<<<<[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>]
-    <<<<<<<]
-<<<]
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
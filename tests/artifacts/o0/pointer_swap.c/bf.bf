@@ -0,0 +1,954 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  swap (depth 1)
+  main calls swap (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  swap: 2700 ops ~31988 steps
+  main: 4240 ops ~34564 steps
+frame widths:
+  swap: 30 cells
+  main: 33 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#swap
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/swap
+            >>>-
+            l30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_swap
+        #F:swap
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_i8**_%3__align_8(mult)
+        #%7_=_load_i8*_%6__align_1(mult)
+        #%9_=_load_i8*_%8__align_1(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/13 <<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/15 <[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            r3 >>>
+            %3 = alloca i8*_ align 8
+            %4 = alloca i8*_ align 8
+            %5 = alloca i8_ align 1
+            store i8* %0_ i8** %3_ align 8
+            
+            dup11/13/14 >>>>>>>>>>>[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            <<<<<<<<[-]
+            mov14/5 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            store i8* %1_ i8** %4_ align 8
+            
+            dup12/13/15 <<[->+>>+<<<]
+            mov13/12 >[-<+>]
+            <<<<<<<[-]
+            mov15/6 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %6 = load i8** %3_ align 8
+            
+            <<<<<<<[-]
+            dup5/8/13 <<<[->>>+>>>>>+<<<<<<<<]
+            mov13/5 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %7 = load i8* %6_ align 1
+            
+            <<<<[-]
+            dup8/13/14 <[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/16/20 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<]
+            mov16/0 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            r1 >
+            >>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/21 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov21/18 [-<<<+>>>]
+                <<<++++++++++++++++
+            >>>]
+            <[
+                if neg
+                <<++++++++++++++++
+                >>[
+                    -
+                    <<-
+                >>]
+            ]
+            drive left! choo choo
+            <<[
+                mov17/16 <[-<+>]
+                mov18/17 >[-<+>]
+                
+                mov15/18 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup15/16/18 <<<[->+>>+<<<]
+            mov16/15 >[-<+>]
+            >[
+                mov19/16 >>[-<<<+>>>]
+                mov18/19 <[->+<]
+                mov17/18 <[->+<]
+                r1 >
+                -
+            ]
+            mov18/9 >[-<<<<<<<<<+>>>>>>>>>]
+            store i8 %7_ i8* %5_ align 1
+            
+            dup9/13/14 <<<<<<<<<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<<[-]
+            mov14/7 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %8 = load i8** %4_ align 8
+            
+            <<<<<[-]
+            dup6/9/13 <<<[->>>+>>>>+<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %9 = load i8* %8_ align 1
+            
+            <<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/19/20 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov19/0 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/24 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<[
+                -
+                >>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov24/17 [-<<<<<<<+>>>>>>>]
+                <<<<<<<+++++++++++++++
+            >>>>>>>]
+            <<<<[
+                if neg
+                <<<+++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov16/15 <[-<+>]
+                mov17/16 >[-<+>]
+                
+                mov14/17 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup14/15/17 <<<[->+>>+<<<]
+            mov15/14 >[-<+>]
+            >[
+                mov18/15 >>[-<<<+>>>]
+                mov17/18 <[->+<]
+                mov16/17 <[->+<]
+                r1 >
+                -
+            ]
+            mov17/10 >[-<<<<<<<+>>>>>>>]
+            store i8 %9_ i8* %6_ align 1
+            
+            dup10/13/14 <<<<<<<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            dup8/15/16 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/22/23 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/27 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/19 [-<<<<<<<<+>>>>>>>>]
+                <<<<<<<<+++++++++++++++++
+            >>>>>>>>]
+            <<<<[
+                if neg
+                <<<<+++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov14/20 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov18/17 <[-<+>]
+                mov19/18 >[-<+>]
+                mov20/19 >[-<+>]
+                mov16/20 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<[
+                mov21/17 >>>[-<<<<+>>>>]
+                
+                mov18/19 <<<[->+<]
+                r1 >
+                -
+            ]
+            %11 = load i8* %5_ align 1
+            
+            <<<<<<<<<<[-]
+            dup7/8/13 <[->+>>>>>+<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            %12 = load i8** %4_ align 8
+            
+            <<<<[-]
+            dup6/9/13 <<<[->>>+>>>>+<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            store i8 %11_ i8* %12_ align 1
+            
+            dup8/13/14 <<<<<[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            dup9/15/16 <<<<[->>>>>>+>+<<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/22/23 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/24 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov24/19 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov14/20 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov18/17 <[-<+>]
+                mov19/18 >[-<+>]
+                mov20/19 >[-<+>]
+                mov16/20 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<[
+                mov21/17 >>>[-<<<<+>>>>]
+                
+                mov18/19 <<<[->+<]
+                r1 >
+                -
+            ]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l8 <<<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:swap
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%18
+        #B:%21
+        #B:%22
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_i8**_%4__align_8(mult)
+        #%6_=_load_i8*_%5__align_1(mult)
+        #%11_=_load_i8*_%10__align_1(mult)
+        #%20_=_zext_i8_%19_to_i32(mult)
+        #%23_=_phi_i32_{_i32_%20__%18_}__{_i32_33__%21_}(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8*_ align 8
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            store i8 120_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<++++++++++++
+            >>>>>>>>]
+            store i8 121_ i8* %3_ align 1
+            
+            <<<<<<<[-]
+            >>>>>>>>+++++++++++
+            [
+                -
+                <<<<<<<<+++++++++++
+            >>>>>>>>]
+            store i8* %2_ i8** %4_ align 8
+            
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/20/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <<++++++++++++
+            <<<<<<[-]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %5 = load i8** %4_ align 8
+            
+            <<<<<[-]
+            dup13/14/19 <[->+>>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %6 = load i8* %5_ align 1
+            
+            <<<<[-]
+            dup14/19/20 <[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/22/26 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            >>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/24 [-<<<+>>>]
+                <<<++++++++++++++++++++++
+            >>>]
+            <[
+                if neg
+                <<++++++++++++++++++++++
+                >>[
+                    -
+                    <<-
+                >>]
+            ]
+            drive left! choo choo
+            <<[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                
+                mov21/24 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup21/22/24 <<<[->+>>+<<<]
+            mov22/21 >[-<+>]
+            >[
+                mov25/22 >>[-<<<+>>>]
+                mov24/25 <[->+<]
+                mov23/24 <[->+<]
+                r1 >
+                -
+            ]
+            mov24/15 >[-<<<<<<<<<+>>>>>>>>>]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup15/19/20 <<<<<<<<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %7 = load i8* %3_ align 1
+            
+            <<<<<<[-]
+            dup12/14/19 <<[->>+>>>>>+<<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup14/19/20 <<<<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            call @swap(i8* %2_ i8* %3)
+            
+            enable next block when we return
+            <<<<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 33
+            ret_pad_width 4
+            copy up arg 0
+            #arg_0
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/20/21 <<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++++++
+            mov19/39 [->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
+            copy up arg 1
+            #arg_1
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++++++
+            mov21/38 [->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/41/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r33 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_swap
+            +
+            >#swap
+            +
+            >>#swap/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %8 = load i8* %2_ align 1
+            
+            >>>>>>>>[-]
+            dup11/14/19 <<<[->>>+>>>>>+<<<<<<<<]
+            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup14/19/20 <<<<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            %9 = load i8* %3_ align 1
+            
+            <<<<<[-]
+            dup12/15/19 <<<[->>>+>>>>+<<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            dup15/19/20 <<<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %10 = load i8** %4_ align 8
+            
+            <<<<<[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %11 = load i8* %10_ align 1
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/30 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<[
+                -
+                >>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov30/23 [-<<<<<<<+>>>>>>>]
+                <<<<<<<+++++++++++++++++++++
+            >>>>>>>]
+            <<<<[
+                if neg
+                <<<+++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov22/21 <[-<+>]
+                mov23/22 >[-<+>]
+                
+                mov20/23 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup20/21/23 <<<[->+>>+<<<]
+            mov21/20 >[-<+>]
+            >[
+                mov24/21 >>[-<<<+>>>]
+                mov23/24 <[->+<]
+                mov22/23 <[->+<]
+                r1 >
+                -
+            ]
+            mov23/16 >[-<<<<<<<+>>>>>>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            dup16/19/20 <<<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            >.
+            [-]
+            %13 = load i8* %10_ align 1
+            
+            <<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/23 [-<<<<+>>>>]
+                <<<<+++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov22/21 <[-<+>]
+                mov23/22 >[-<+>]
+                
+                mov20/23 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup20/21/23 <<<[->+>>+<<<]
+            mov21/20 >[-<+>]
+            >[
+                mov24/21 >>[-<<<+>>>]
+                mov23/24 <[->+<]
+                mov22/23 <[->+<]
+                r1 >
+                -
+            ]
+            mov23/16 >[-<<<<<<<+>>>>>>>]
+            %14 = zext i8 %13 to i32
+            
+            <<<<<<<<[-]
+            dup16/19/20 >[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            mov20/15 >[-<<<<<+>>>>>]
+            %16 = zext i8 %8 to i32
+            
+            <<<<[-]
+            dup14/19/20 <<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %17 = icmp eq i32 %14_ i32 %16
+            
+            <<<<<<[-]
+            dup15/19/20 >[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/24 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<<+
+            >>>>>>>>>>[
+                [-]
+                <<<<<<<<<<[-]
+            >>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            br i1 %17_ label %18_ label %21
+            
+            dup14/20/19 <<<<<<<<<[->>>>>>+<+<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%18_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%21_false
+            <<<<]
+        >>]
+        >#B:%18
+        [
+            -
+            %19 = load i8* %3_ align 1
+            
+            >>>>>>>[-]
+            dup12/14/21 <<[->>+>>>>>>>+<<<<<<<<<]
+            mov21/12 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %20 = zext i8 %19 to i32
+            
+            <<<<[-]
+            dup14/19/20 <<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            mov20/17 >[-<<<+>>>]
+            br label %22
+            
+            doing phi stuff
+            %23 = phi i32 { i32 %20_ %18 }_ { i32 33_ %21 }
+            <<[-]
+            mov17/18 <[->+<]
+            <<<<<<<<+
+        <<]
+        >#B:%21
+        [
+            -
+            br label %22
+            
+            doing phi stuff
+            %23 = phi i32 { i32 %20_ %18 }_ { i32 33_ %21 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            <[-]
+            mov19/18 >[-<+>]
+            <<<<<<<<<<+
+        <]
+        >#B:%22
+        [
+            -
+            %23 = phi i32 { i32 %20_ %18 }_ { i32 33_ %21 }
+            %24 = trunc i32 %23 to i8
+            
+            >>>>>[-]
+            dup18/21/22 >>>>[->>>+>+<<<<]
+            mov21/18 >>>[-<<<+>>>]
+            mov22/14 >[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %24)
+            
+            putchar intrinsic
+            dup14/19/20 <<<<<<<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            %25 = load i32* %1_ align 4
+            
+            <<<<<<[-]
+            dup10/14/19 <<<<[->>>>+>>>>>+<<<<<<<<<]
+            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            ret i32 %25
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>]
+    <<<<<<<]
+<<]
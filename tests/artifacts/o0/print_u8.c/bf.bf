@@ -0,0 +1,2117 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 26455 ops ~593539 steps
+frame widths:
+  main: 60 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l60 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%0_printf_0_0_hundreds
+        #B:%0_printf_0_0_tens?
+        #B:%0_printf_0_0_tens
+        #B:%0_printf_0_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+        #alloc_%2
+        #%printf_0_0_hundreds_=_udiv_i8_0__i8_100(mult)
+        #%printf_0_0_over9_=_udiv_i8_0__i8_10(mult)
+        #%printf_0_0_tens_=_urem_i8_%printf_0_0_over9__i8_10(mult)
+        #%printf_0_0_ones_=_urem_i8_0__i8_10(mult)
+        #%printf_0_0_any_hundreds_=_icmp_ne_i8_%printf_0_0_hundreds__i8_0(mult)
+        #%printf_1_0_hundreds_=_udiv_i8_7__i8_100(mult)
+        #%printf_1_0_over9_=_udiv_i8_7__i8_10(mult)
+        #%printf_1_0_tens_=_urem_i8_%printf_1_0_over9__i8_10(mult)
+        #%printf_1_0_ones_=_urem_i8_7__i8_10(mult)
+        #%printf_2_0_hundreds_=_udiv_i8_%3__i8_100(mult)
+        #%printf_2_0_over9_=_udiv_i8_%3__i8_10(mult)
+        #%printf_2_0_tens_=_urem_i8_%printf_2_0_over9__i8_10(mult)
+        #%printf_2_0_ones_=_urem_i8_%3__i8_10(mult)
+        #%printf_3_0_hundreds_=_udiv_i8_255__i8_100(mult)
+        #%printf_3_0_over9_=_udiv_i8_255__i8_10(mult)
+        #%printf_3_0_tens_=_urem_i8_%printf_3_0_over9__i8_10(mult)
+        #%printf_3_0_ones_=_urem_i8_255__i8_10(mult)
+        #%printf_4_0_hundreds_=_udiv_i8_%4__i8_100(mult)
+        #%printf_4_0_over9_=_udiv_i8_%4__i8_10(mult)
+        #%printf_4_0_tens_=_urem_i8_%printf_4_0_over9__i8_10(mult)
+        #%printf_4_0_ones_=_urem_i8_%4__i8_10(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = alloca i8_ align 1
+            store i8 42_ i8* %2_ align 1
+            
+            >>>>>>>>>>>>>>>>>>>>>>[-]
+            ++++++++++++++++++++++++++++++++++++++++++
+            scratch must be clean
+            >>>>>>>>>>>>>>>>>>>>>>[
+            ]
+            %printf_0_0_hundreds = udiv i8 0_ i8 100
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            %printf_0_0_over9 = udiv i8 0_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_0_tens = urem i8 %printf_0_0_over9_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_0_ones = urem i8 0_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_0_any_hundreds = icmp ne i8 %printf_0_0_hundreds_ i8 0
+            
+            >[-]
+            known to be 0
+            
+            br i1 %printf_0_0_any_hundreds_ label %0_printf_0_0_hundreds_ label %0_printf_0_0_tens?
+            
+            dup29/46/47 [->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >+
+                #B:main/%0_printf_0_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%0_printf_0_0_tens?_false
+            <<]
+        <]
+        >>#B:%0_printf_0_0_hundreds
+        [
+            -
+            %printf_0_0_hundreds_digit = add i8 %printf_0_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup25/48/49 <<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov48/25 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_0_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_tens?
+        [
+            -
+            %printf_0_0_any_tens = icmp ne i8 %printf_0_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup26/46/47 <<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov46/26 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_0_0_any_tens_ label %0_printf_0_0_tens_ label %0_printf_0_0_ones
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%0_printf_0_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%0_printf_0_0_ones_false
+            <<<<]
+        >>]
+        >#B:%0_printf_0_0_tens
+        [
+            -
+            %printf_0_0_tens_digit = add i8 %printf_0_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup27/48/49 <<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov48/27 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/57/59 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov59/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>>>>>>>+
+                <<[
+                    -
+                    mov56/57 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov57/56 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<<]
+            >>>>>>[-]
+            scratch must be clean
+            <<<<<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >>>>>[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_0_0_tens_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones
+        [
+            -
+            %printf_0_0_ones_digit = add i8 %printf_0_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>>[-]
+            dup28/46/47 <[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov47/29 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/51/53 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov53/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov50/51 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov51/50 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_0_0_ones_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %printf_1_0_hundreds = udiv i8 7_ i8 100
+            
+            <<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            %printf_1_0_over9 = udiv i8 7_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_1_0_tens = urem i8 %printf_1_0_over9_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_1_0_ones = urem i8 7_ i8 10
+            
+            >[-]
+            known to be 7
+            +++++++
+            %printf_1_0_any_hundreds = icmp ne i8 %printf_1_0_hundreds_ i8 0
+            
+            <<<<[-]
+            known to be 0
+            
+            br i1 %printf_1_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_tens?
+            
+            dup29/47/46 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_tens?_false
+            <<<<<<]
+        >>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_hundreds
+        [
+            -
+            %printf_1_0_hundreds_digit = add i8 %printf_1_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>[-]
+            dup30/48/49 >[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov48/30 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_1_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_tens?
+        [
+            -
+            %printf_1_0_any_tens = icmp ne i8 %printf_1_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>>>>>>[-]
+            dup31/46/47 >>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov46/31 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_1_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_false
+            <<<<<<<<]
+        >>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_tens
+        [
+            -
+            %printf_1_0_tens_digit = add i8 %printf_1_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>[-]
+            dup32/48/49 >>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov48/32 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_1_0_tens_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones
+        [
+            -
+            %printf_1_0_ones_digit = add i8 %printf_1_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>[-]
+            dup33/46/47 >>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov46/33 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov47/29 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/51/53 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov53/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov50/51 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov51/50 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_1_0_ones_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %3 = load i8* %2_ align 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup24/29/46 <<<<<[->>>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov46/24 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            
+            
+            %printf_2_0_hundreds = udiv i8 %3_ i8 100
+            
+            <<<<<<<<<<<<[-]
+            dup29/46/47 <<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup48/51/52 <[->>>+>+<<<<]
+            mov52/48 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/53 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>]
+                mov53/47 >>>[-<<<<<<+>>>>>>]
+                dup48/51/52 <<<<<[->>>+>+<<<<]
+                mov52/48 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            scratch must be clean
+            <<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_2_0_over9 = udiv i8 %3_ i8 10
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup29/46/47 <<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup48/50/51 [->>+>+<<<]
+            mov51/48 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/52 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>]
+                mov52/47 >>>[-<<<<<+>>>>>]
+                dup48/50/51 <<<<[->>+>+<<<]
+                mov51/48 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_2_0_tens = urem i8 %printf_2_0_over9_ i8 10
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            dup35/46/47 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov46/35 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup48/50/51 [->>+>+<<<]
+            mov51/48 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/52 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov52/47 <<[-<<<<<+>>>>>]
+                dup48/50/51 <<<<[->>+>+<<<]
+                mov51/48 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov50/36 >[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<<-
+                >>>>>>>>>>>>>]
+            ]
+            mov49/36 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_2_0_ones = urem i8 %3_ i8 10
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup29/46/47 <<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup48/50/51 [->>+>+<<<]
+            mov51/48 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/52 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov52/47 <<[-<<<<<+>>>>>]
+                dup48/50/51 <<<<[->>+>+<<<]
+                mov51/48 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov50/37 >[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<-
+                >>>>>>>>>>>>]
+            ]
+            mov49/37 [-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_2_0_any_hundreds = icmp ne i8 %printf_2_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup34/46/47 >>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov46/34 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_2_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?_false
+            <<<<<<<<<<]
+        >>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds
+        [
+            -
+            %printf_2_0_hundreds_digit = add i8 %printf_2_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>>[-]
+            dup34/48/49 >>>>>[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov48/34 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_2_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?
+        [
+            -
+            %printf_2_0_any_tens = icmp ne i8 %printf_2_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>>[-]
+            dup35/46/47 >>>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov46/35 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_2_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_false
+            <<<<<<<<<<<<]
+        >>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens
+        [
+            -
+            %printf_2_0_tens_digit = add i8 %printf_2_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>>[-]
+            dup36/48/49 >>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov48/36 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_2_0_tens_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+        [
+            -
+            %printf_2_0_ones_digit = add i8 %printf_2_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>>[-]
+            dup37/46/47 >>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov46/37 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov47/29 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/51/53 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov53/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov50/51 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov51/50 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_2_0_ones_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %printf_3_0_hundreds = udiv i8 255_ i8 100
+            
+            <<<<<<<<[-]
+            known to be 2
+            ++
+            %printf_3_0_over9 = udiv i8 255_ i8 10
+            
+            >[-]
+            known to be 25
+            +++++++++++++++++++++++++
+            scratch must be clean
+            >>>>>>>[
+            ]
+            %printf_3_0_tens = urem i8 %printf_3_0_over9_ i8 10
+            
+            <<<<<<[-]
+            known to be 5
+            +++++
+            %printf_3_0_ones = urem i8 255_ i8 10
+            
+            >[-]
+            known to be 5
+            +++++
+            %printf_3_0_any_hundreds = icmp ne i8 %printf_3_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<[-]
+            known to be 1
+            +
+            br i1 %printf_3_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?
+            
+            dup29/47/46 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?_false
+            <<<<<<<<<<<<<<]
+        >>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds
+        [
+            -
+            %printf_3_0_hundreds_digit = add i8 %printf_3_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>[-]
+            dup38/48/49 >>>>>>>>>[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov48/38 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_3_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?
+        [
+            -
+            %printf_3_0_any_tens = icmp ne i8 %printf_3_0_over9_ i8 0
+            
+            >>>>>>>>>>>>[-]
+            dup39/46/47 >>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov46/39 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_3_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_false
+            <<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens
+        [
+            -
+            %printf_3_0_tens_digit = add i8 %printf_3_0_tens_ i8 48
+            
+            >>>>>>>>>>>[-]
+            dup40/48/49 >>>>>>>>>>>[->>>>>>>>+>+<<<<<<<<<]
+            mov48/40 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_3_0_tens_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+        [
+            -
+            %printf_3_0_ones_digit = add i8 %printf_3_0_ones_ i8 48
+            
+            >>>>>>>>>>[-]
+            dup41/46/47 >>>>>>>>>>>>[->>>>>+>+<<<<<<]
+            mov46/41 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov47/29 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/51/53 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov53/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov50/51 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov51/50 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_3_0_ones_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %4 = load i8* %2_ align 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup24/29/46 <<<<<[->>>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov46/24 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            
+            
+            %printf_4_0_hundreds = udiv i8 %4_ i8 100
+            
+            <<<<[-]
+            dup29/46/47 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup48/51/52 <[->>>+>+<<<<]
+            mov52/48 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/53 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<+
+                >>>>>>>>[
+                    [-]
+                    <<<<<<<<-
+                >>>>>>>>]
+                mov53/47 >>>[-<<<<<<+>>>>>>]
+                dup48/51/52 <<<<<[->>>+>+<<<<]
+                mov52/48 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            scratch must be clean
+            <<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_4_0_over9 = udiv i8 %4_ i8 10
+            
+            <<<<<<<<<<<<[-]
+            dup29/46/47 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup48/50/51 [->>+>+<<<]
+            mov51/48 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/52 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<+
+                >>>>>>[
+                    [-]
+                    <<<<<<-
+                >>>>>>]
+                mov52/47 >>>[-<<<<<+>>>>>]
+                dup48/50/51 <<<<[->>+>+<<<]
+                mov51/48 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_4_0_tens = urem i8 %printf_4_0_over9_ i8 10
+            
+            <<<<<<<<<<[-]
+            dup43/46/47 <[->>>+>+<<<<]
+            mov46/43 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup48/50/51 [->>+>+<<<]
+            mov51/48 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/52 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov52/47 <<[-<<<<<+>>>>>]
+                dup48/50/51 <<<<[->>+>+<<<]
+                mov51/48 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov50/44 >[-<<<<<<+>>>>>>]
+                <[
+                    -
+                    <<<<<-
+                >>>>>]
+            ]
+            mov49/44 [-<<<<<+>>>>>]
+            <[-]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_4_0_ones = urem i8 %4_ i8 10
+            
+            <<<<<<<<<[-]
+            dup29/46/47 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup48/50/51 [->>+>+<<<]
+            mov51/48 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov47/52 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov52/47 <<[-<<<<<+>>>>>]
+                dup48/50/51 <<<<[->>+>+<<<]
+                mov51/48 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov50/45 >[-<<<<<+>>>>>]
+                <[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov49/45 [-<<<<+>>>>]
+            <[-]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %printf_4_0_any_hundreds = icmp ne i8 %printf_4_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup42/46/47 >>>>>>>>>>>>>[->>>>+>+<<<<<]
+            mov46/42 >>>>[-<<<<+>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_4_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?_false
+            <<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds
+        [
+            -
+            %printf_4_0_hundreds_digit = add i8 %printf_4_0_hundreds_ i8 48
+            
+            >>>>>>>>>[-]
+            dup42/48/49 >>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov48/42 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_4_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?
+        [
+            -
+            %printf_4_0_any_tens = icmp ne i8 %printf_4_0_over9_ i8 0
+            
+            >>>>>>>>[-]
+            dup43/46/47 >>>>>>>>>>>>>>[->>>+>+<<<<]
+            mov46/43 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_4_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+            
+            dup29/47/46 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones_false
+            <<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens
+        [
+            -
+            %printf_4_0_tens_digit = add i8 %printf_4_0_tens_ i8 48
+            
+            >>>>>>>[-]
+            dup44/48/49 >>>>>>>>>>>>>>>[->>>>+>+<<<<<]
+            mov48/44 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov49/29 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/53/55 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov55/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov52/53 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov53/52 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_4_0_tens_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+        [
+            -
+            %printf_4_0_ones_digit = add i8 %printf_4_0_ones_ i8 48
+            
+            >>>>>>[-]
+            dup45/46/47 >>>>>>>>>>>>>>>>[->+>+<<]
+            mov46/45 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov47/29 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup29/51/53 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov53/29 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov50/51 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov51/50 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_4_0_ones_digit)
+            
+            putchar intrinsic
+            dup29/46/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov46/29 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<<<<<]
+<]
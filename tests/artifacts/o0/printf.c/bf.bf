@@ -0,0 +1,1471 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 22588 ops ~756475 steps
+frame widths:
+  main: 52 cells
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++
+r1 >
+
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+
+r1 >
+r4 >>>>
++++++++++++++++++++++++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l52 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%0_printf_0_0_hundreds
+        #B:%0_printf_0_0_tens?
+        #B:%0_printf_0_0_tens
+        #B:%0_printf_0_0_ones
+        #B:%0_printf_0_4_hundreds
+        #B:%0_printf_0_4_tens?
+        #B:%0_printf_0_4_tens
+        #B:%0_printf_0_4_ones
+        #B:%0_printf_0_6_hundreds
+        #B:%0_printf_0_6_tens?
+        #B:%0_printf_0_6_tens
+        #B:%0_printf_0_6_ones
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #%5_=_call_@getchar()(mult)
+        #%6_=_zext_i8_%5_to_i32(mult)
+        #%7_=_sub_i32_%6__i32_48(mult)
+        #%9_=_call_@getchar()(mult)
+        #%12_=_load_i8*_%3__align_1(mult)
+        #%13_=_zext_i8_%12_to_i32(mult)
+        #%14_=_sub_i32_%13__i32_48(mult)
+        #%20_=_zext_i8_%12_to_i32(mult)
+        #%22_=_zext_i8_%21_to_i32(mult)
+        #%printf_0_0_hundreds_=_udiv_i8_%printf_0_0_byte__i8_100(mult)
+        #%printf_0_0_over9_=_udiv_i8_%printf_0_0_byte__i8_10(mult)
+        #%printf_0_0_tens_=_urem_i8_%printf_0_0_over9__i8_10(mult)
+        #%printf_0_0_ones_=_urem_i8_%printf_0_0_byte__i8_10(mult)
+        #%printf_0_4_hundreds_=_udiv_i8_%printf_0_4_byte__i8_100(mult)
+        #%printf_0_4_over9_=_udiv_i8_%printf_0_4_byte__i8_10(mult)
+        #%printf_0_4_tens_=_urem_i8_%printf_0_4_over9__i8_10(mult)
+        #%printf_0_4_ones_=_urem_i8_%printf_0_4_byte__i8_10(mult)
+        #%printf_0_6_hundreds_=_udiv_i8_%printf_0_6_byte__i8_100(mult)
+        #%printf_0_6_over9_=_udiv_i8_%printf_0_6_byte__i8_10(mult)
+        #%printf_0_6_tens_=_urem_i8_%printf_0_6_over9__i8_10(mult)
+        #%printf_0_6_ones_=_urem_i8_%printf_0_6_byte__i8_10(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = call @getchar()
+            
+            >>>>>>>>>>>>>>>>>[-]
+            getchar intrinsic
+            ,
+            %6 = zext i8 %5 to i32
+            
+            >[-]
+            dup19/40/41 <[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/19 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            mov41/20 >[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            %7 = sub i32 %6_ i32 48
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup20/40/42 <[->>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >#constop_48
+            >>++++++
+            [
+                -
+                <<++++++++
+            >>]
+            mov42/21 <[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <[
+                -
+                >>>>+
+                <<<<<<<<<<<<<<<<<<<<<<<<[
+                    -
+                    mov21/44 [->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
+                    >>>>>>>>>>>>>>>>>>>>>>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<]
+                mov44/21 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<]
+            %8 = trunc i32 %7 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup21/40/41 >[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov40/21 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            mov41/20 >[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            store i8 %8_ i8* %2_ align 1
+            
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            mov41/16 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %9 = call @getchar()
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            getchar intrinsic
+            ,
+            store i8 %9_ i8* %3_ align 1
+            
+            dup22/40/41 [->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov40/22 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            mov41/17 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            %10 = load i8* %2_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup16/20/40 <<<<[->>>>+>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/16 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup20/40/41 <[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            mov41/21 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            %12 = load i8* %3_ align 1
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            dup17/23/40 <<<<<<[->>>>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/17 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %13 = zext i8 %12 to i32
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup23/40/41 <[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov40/23 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov41/24 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %14 = sub i32 %13_ i32 48
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup24/40/41 <[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov40/24 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov41/25 <<[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >[
+                -
+                >>>>>+
+                <<<<<<<<<<<<<<<<<<<<<<[
+                    -
+                    mov25/46 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+                    >>>>>>>>>>>>>>>>>>>>>>-
+                <<<<<<<<<<<<<<<<<<<<<<]
+                mov46/25 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<]
+            %15 = mul i32 %11_ i32 %14
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            dup21/40/41 <<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov40/21 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            dup25/42/43 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov42/25 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<[
+                -
+                mov41/51 <<[->>>>>>>>>>+<<<<<<<<<<]
+                >>>>>>>>>>[
+                    -
+                    <<<<<<<<<<+
+                    >>>>>>>>>+
+                    <<[
+                        -
+                        mov48/49 [->+<]
+                        <<<<<<<<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov49/48 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<<<<<]
+            <<[-]
+            >>>>>>>[-]
+            %16 = trunc i32 %15 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup24/40/41 >>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov40/24 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov41/21 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            store i8 %16_ i8* %4_ align 1
+            
+            dup21/40/41 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov40/21 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            mov41/18 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %18 = zext i8 %10 to i32
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup20/40/41 <[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            mov41/21 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            %20 = zext i8 %12 to i32
+            
+            <<<<<<<<<<<<<<<[-]
+            dup23/40/41 <<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov40/23 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov41/26 >[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %21 = load i8* %4_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup18/20/40 <<[->>+>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/18 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            %22 = zext i8 %21 to i32
+            
+            <<<<<<<<<<<<<[-]
+            dup20/40/41 <<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            mov41/27 >[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            %printf_0_0_byte = trunc i32 %18 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup21/40/41 >[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov40/21 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            mov41/20 >[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            %printf_0_0_hundreds = udiv i8 %printf_0_0_byte_ i8 100
+            
+            <<<<<<<<<<<<<[-]
+            dup20/40/41 <<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup42/45/46 <[->>>+>+<<<<]
+            mov46/42 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/47 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>]
+                mov47/41 >>>[-<<<<<<+>>>>>>]
+                dup42/45/46 <<<<<[->>>+>+<<<<]
+                mov46/42 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            %printf_0_0_over9 = udiv i8 %printf_0_0_byte_ i8 10
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup20/40/41 <<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup42/44/45 [->>+>+<<<]
+            mov45/42 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/46 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>]
+                mov46/41 >>>[-<<<<<+>>>>>]
+                dup42/44/45 <<<<[->>+>+<<<]
+                mov45/42 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %printf_0_0_tens = urem i8 %printf_0_0_over9_ i8 10
+            
+            <<<<<<<<<<<<<<[-]
+            dup29/40/41 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov40/29 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup42/44/45 [->>+>+<<<]
+            mov45/42 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/46 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov46/41 <<[-<<<<<+>>>>>]
+                dup42/44/45 <<<<[->>+>+<<<]
+                mov45/42 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov44/30 >[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<<-
+                >>>>>>>>>>>>>]
+            ]
+            mov43/30 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_0_ones = urem i8 %printf_0_0_byte_ i8 10
+            
+            <<<<<<<<<<<<<[-]
+            dup20/40/41 <<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup42/44/45 [->>+>+<<<]
+            mov45/42 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/46 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov46/41 <<[-<<<<<+>>>>>]
+                dup42/44/45 <<<<[->>+>+<<<]
+                mov45/42 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov44/31 >[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<-
+                >>>>>>>>>>>>]
+            ]
+            mov43/31 [-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_0_any_hundreds = icmp ne i8 %printf_0_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/40/41 >>>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov40/28 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_0_any_hundreds_ label %0_printf_0_0_hundreds_ label %0_printf_0_0_tens?
+            
+            dup20/41/40 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<]
+            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >+
+                #B:main/%0_printf_0_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%0_printf_0_0_tens?_false
+            <<]
+        <]
+        >>#B:%0_printf_0_0_hundreds
+        [
+            -
+            %printf_0_0_hundreds_digit = add i8 %printf_0_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>[-]
+            dup28/42/43 >>>>>>>>[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov42/28 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov43/20 <<[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/47/49 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov49/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov46/47 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov47/46 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_tens?
+        [
+            -
+            %printf_0_0_any_tens = icmp ne i8 %printf_0_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>[-]
+            dup29/40/41 >>>>>>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov40/29 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_0_any_tens_ label %0_printf_0_0_tens_ label %0_printf_0_0_ones
+            
+            dup20/41/40 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<]
+            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%0_printf_0_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%0_printf_0_0_ones_false
+            <<<<]
+        >>]
+        >#B:%0_printf_0_0_tens
+        [
+            -
+            %printf_0_0_tens_digit = add i8 %printf_0_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>[-]
+            dup30/42/43 >>>>>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov42/30 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov43/20 <<[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/47/49 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov49/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov46/47 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov47/46 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_0_tens_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones
+        [
+            -
+            %printf_0_0_ones_digit = add i8 %printf_0_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>[-]
+            dup31/40/41 >>>>>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov40/31 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov41/20 <<[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/45/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov47/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov44/45 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov45/44 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_0_ones_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32_ i8 42_ i8 32)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            ++++++++++
+            .
+            ----------
+            .
+            [-]
+            %printf_0_2_byte = trunc i32 %20 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup26/40/41 >>>>>>[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov40/26 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            mov41/20 >[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %printf_0_2_byte)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32_ i8 61_ i8 32)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            >>++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<+
+            .
+            -----------------------------
+            .
+            [-]
+            %printf_0_4_byte = trunc i32 %22 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup27/40/41 >>>>>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov40/27 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov41/20 >[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            %printf_0_4_hundreds = udiv i8 %printf_0_4_byte_ i8 100
+            
+            <<<<<<<<<[-]
+            dup20/40/41 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup42/45/46 <[->>>+>+<<<<]
+            mov46/42 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/47 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<-
+                >>>>>>>>>>>>]
+                mov47/41 >>>[-<<<<<<+>>>>>>]
+                dup42/45/46 <<<<<[->>>+>+<<<<]
+                mov46/42 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            %printf_0_4_over9 = udiv i8 %printf_0_4_byte_ i8 10
+            
+            <<<<<<<<<<<<[-]
+            dup20/40/41 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup42/44/45 [->>+>+<<<]
+            mov45/42 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/46 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<+
+                >>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<-
+                >>>>>>>>>>]
+                mov46/41 >>>[-<<<<<+>>>>>]
+                dup42/44/45 <<<<[->>+>+<<<]
+                mov45/42 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %printf_0_4_tens = urem i8 %printf_0_4_over9_ i8 10
+            
+            <<<<<<<<<<[-]
+            dup33/40/41 <[->>>>>>>+>+<<<<<<<<]
+            mov40/33 >>>>>>>[-<<<<<<<+>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup42/44/45 [->>+>+<<<]
+            mov45/42 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/46 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov46/41 <<[-<<<<<+>>>>>]
+                dup42/44/45 <<<<[->>+>+<<<]
+                mov45/42 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov44/34 >[-<<<<<<<<<<+>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<-
+                >>>>>>>>>]
+            ]
+            mov43/34 [-<<<<<<<<<+>>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_4_ones = urem i8 %printf_0_4_byte_ i8 10
+            
+            <<<<<<<<<[-]
+            dup20/40/41 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup42/44/45 [->>+>+<<<]
+            mov45/42 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov41/46 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov46/41 <<[-<<<<<+>>>>>]
+                dup42/44/45 <<<<[->>+>+<<<]
+                mov45/42 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov44/35 >[-<<<<<<<<<+>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<-
+                >>>>>>>>]
+            ]
+            mov43/35 [-<<<<<<<<+>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_4_any_hundreds = icmp ne i8 %printf_0_4_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup32/40/41 >>>>>>>>>>>>[->>>>>>>>+>+<<<<<<<<<]
+            mov40/32 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_4_any_hundreds_ label %0_printf_0_4_hundreds_ label %0_printf_0_4_tens?
+            
+            dup20/41/40 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<]
+            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%0_printf_0_4_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%0_printf_0_4_tens?_false
+            <<<<<<]
+        >>>>]
+        >#B:%0_printf_0_4_hundreds
+        [
+            -
+            %printf_0_4_hundreds_digit = add i8 %printf_0_4_hundreds_ i8 48
+            
+            >>>>>>>>>>>>[-]
+            dup32/42/43 >>>>>>>>>>>>[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov42/32 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov43/20 <<[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/47/49 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov49/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov46/47 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov47/46 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_4_hundreds_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_4_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_4_tens?
+        [
+            -
+            %printf_0_4_any_tens = icmp ne i8 %printf_0_4_over9_ i8 0
+            
+            >>>>>>>>>>>[-]
+            dup33/40/41 >>>>>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov40/33 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_4_any_tens_ label %0_printf_0_4_tens_ label %0_printf_0_4_ones
+            
+            dup20/41/40 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<]
+            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%0_printf_0_4_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%0_printf_0_4_ones_false
+            <<<<<<<<]
+        >>>>>>]
+        >#B:%0_printf_0_4_tens
+        [
+            -
+            %printf_0_4_tens_digit = add i8 %printf_0_4_tens_ i8 48
+            
+            >>>>>>>>>>[-]
+            dup34/42/43 >>>>>>>>>>>>>>[->>>>>>>>+>+<<<<<<<<<]
+            mov42/34 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov43/20 <<[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/47/49 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov49/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov46/47 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov47/46 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_4_tens_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_4_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_4_ones
+        [
+            -
+            %printf_0_4_ones_digit = add i8 %printf_0_4_ones_ i8 48
+            
+            >>>>>>>>>[-]
+            dup35/40/41 >>>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
+            mov40/35 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov41/20 <<[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/45/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov47/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov44/45 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov45/44 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_4_ones_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 44_ i8 32)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            ------------
+            .
+            [-]
+            %printf_0_6_byte = trunc i32 0 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            %printf_0_6_hundreds = udiv i8 %printf_0_6_byte_ i8 100
+            
+            >>>>>>>>>>>>>>>>[-]
+            known to be 0
+            
+            %printf_0_6_over9 = udiv i8 %printf_0_6_byte_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_6_tens = urem i8 %printf_0_6_over9_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_6_ones = urem i8 %printf_0_6_byte_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_6_any_hundreds = icmp ne i8 %printf_0_6_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            br i1 %printf_0_6_any_hundreds_ label %0_printf_0_6_hundreds_ label %0_printf_0_6_tens?
+            
+            dup20/41/40 [->>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<]
+            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%0_printf_0_6_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>+
+                #B:main/%0_printf_0_6_tens?_false
+            <<<<<<<<<<]
+        >>>>>>>>]
+        >#B:%0_printf_0_6_hundreds
+        [
+            -
+            %printf_0_6_hundreds_digit = add i8 %printf_0_6_hundreds_ i8 48
+            
+            >>>>>>>>[-]
+            dup36/42/43 >>>>>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov42/36 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov43/20 <<[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/47/49 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov49/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov46/47 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov47/46 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_6_hundreds_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_6_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_6_tens?
+        [
+            -
+            %printf_0_6_any_tens = icmp ne i8 %printf_0_6_over9_ i8 0
+            
+            >>>>>>>[-]
+            dup37/40/41 >>>>>>>>>>>>>>>>>[->>>+>+<<<<]
+            mov40/37 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_6_any_tens_ label %0_printf_0_6_tens_ label %0_printf_0_6_ones
+            
+            dup20/41/40 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<]
+            mov41/20 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%0_printf_0_6_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>+
+                #B:main/%0_printf_0_6_ones_false
+            <<<<<<<<<<<<]
+        >>>>>>>>>>]
+        >#B:%0_printf_0_6_tens
+        [
+            -
+            %printf_0_6_tens_digit = add i8 %printf_0_6_tens_ i8 48
+            
+            >>>>>>[-]
+            dup38/42/43 >>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
+            mov42/38 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov43/20 <<[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/47/49 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov49/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov46/47 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov47/46 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_6_tens_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_6_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_6_ones
+        [
+            -
+            %printf_0_6_ones_digit = add i8 %printf_0_6_ones_ i8 48
+            
+            >>>>>[-]
+            dup39/40/41 >>>>>>>>>>>>>>>>>>>[->+>+<<]
+            mov40/39 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov41/20 <<[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/45/47 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov47/20 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov44/45 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov45/44 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_6_ones_digit)
+            
+            putchar intrinsic
+            dup20/40/41 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov40/20 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32_ i8 122_ i8 101_ i8 114_ i8 111_ i8 10)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            >>+++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<.
+            ---------------------
+            .
+            +++++++++++++
+            .
+            ---
+            .
+            -----------------------------------------------------------------------------------------------------
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>]
+    <<<<<<<<<<<<<<]
+<]
+clear globals:
+l27 <<<<<<<<<<<<<<<<<<<<<<<<<<
+[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
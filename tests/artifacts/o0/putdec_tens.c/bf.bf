@@ -0,0 +1,962 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%call_term_for_3 from %0 after call 3 (call continuation)
+  main/%call_term_for_4 from %0 after call 4 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  putdec (depth 1)
+  main calls putdec (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  putdec: 4216 ops ~275319 steps
+  main: 2748 ops ~11008 steps
+frame widths:
+  putdec: 32 cells
+  main: 21 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#putdec
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/putdec
+            >>>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_putdec
+        #F:putdec
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%7
+        #B:%8
+        #B:%13
+        #B:%16
+        #B:%17
+        #B:%20
+        #B:%29
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i32*_%2__align_4(mult)
+        #%5_=_icmp_ne_i32_%4__i32_0(mult)
+        #%11_=_udiv_i32_%9__i32_%10(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/20 <<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i32_ align 4
+            %3 = alloca i32_ align 4
+            store i32 %0_ i32* %2_ align 4
+            
+            dup18/19/20 >>>>>>>>>>>>>>>>>>[->+>+<<]
+            mov19/18 >[-<+>]
+            <<<<<<[-]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %4 = load i32* %2_ align 4
+            
+            <<<<<[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %5 = icmp ne i32 %4_ i32 0
+            
+            <<<[-]
+            dup15/19/21 <[->>>>+>>+<<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >>[
+                [-]
+                <<<<<+
+            >>>>>]
+            br i1 %5_ label %7_ label %6
+            
+            dup16/20/19 <<<<<[->>>>+<+<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>+
+                #B:putdec/%7_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:putdec/%6_false
+            <]
+        <]
+        >>#B:%6
+        [
+            -
+            call @putchar(i8 48)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>#constop_48
+            ++++++
+            [
+                -
+                >++++++++
+            <]
+            >.
+            [-]
+            br label %29
+            
+            <<<<<<<<<<+
+        <<<<<<<]
+        >#B:%7
+        [
+            -
+            store i32 1_ i32* %3_ align 4
+            
+            >>>>>>>>[-]
+            +
+            br label %8
+            
+            <<<<<<<+
+        <]
+        >#B:%8
+        [
+            -
+            %9 = load i32* %2_ align 4
+            
+            >>>>>>>>[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %10 = load i32* %3_ align 4
+            
+            <<<[-]
+            dup14/16/19 <<[->>+>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %11 = udiv i32 %9_ i32 %10
+            
+            <<[-]
+            dup15/19/20 <<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            dup16/21/23 <<<[->>>>>+>>+<<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            dup23/24/25 >>[->+>+<<]
+            mov25/23 >>[-<<+>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/26 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <<+
+                    >>>>[
+                        -
+                        <<<<-
+                        r1 >
+                    >>>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<+
+                >>>>>[
+                    [-]
+                    <<<<<-
+                >>>>>]
+                mov26/20 >>>>[-<<<<<<+>>>>>>]
+                dup23/24/25 <<<[->+>+<<]
+                mov25/23 >>[-<<+>>]
+            <<<<<]
+            >>>[-]
+            >[-]
+            %12 = icmp uge i32 %11_ i32 10
+            
+            <<<<<<<<<[-]
+            dup17/19/20 >>[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/22 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            br i1 %12_ label %13_ label %16
+            
+            dup15/20/19 <<<<<<<[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>>>+
+                #B:putdec/%13_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:putdec/%16_false
+            <<<<<]
+        >>>]
+        >#B:%13
+        [
+            -
+            %14 = load i32* %3_ align 4
+            
+            >>>>>>>[-]
+            dup14/15/21 <[->+>>>>>>+<<<<<<<]
+            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %15 = mul i32 %14_ i32 10
+            
+            <<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov20/25 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov22/23 [->+<]
+                        <<<<<<+
+                        >>>>>>>>-
+                    <<]
+                    mov23/22 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<[-]
+                        >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <[-]
+            >>[-]
+            store i32 %15_ i32* %3_ align 4
+            
+            dup16/19/20 <<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            br label %8
+            
+            <<<<<<<<<<<<<+
+        >]
+        >#B:%16
+        [
+            -
+            br label %17
+            
+            >+
+        <]
+        >#B:%17
+        [
+            -
+            %18 = load i32* %3_ align 4
+            
+            >>>>>[-]
+            dup14/15/19 <[->+>>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %19 = icmp ne i32 %18_ i32 0
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >[
+                [-]
+                <<<<+
+            >>>>]
+            br i1 %19_ label %20_ label %29
+            
+            dup16/20/19 <<<<[->>>>+<+<<<]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:putdec/%20_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:putdec/%29_false
+            <<<<<<<<]
+        >>>>>>]
+        >#B:%20
+        [
+            -
+            %21 = load i32* %2_ align 4
+            
+            >>>>[-]
+            dup13/15/21 <<[->>+>>>>>>+<<<<<<<<]
+            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %22 = load i32* %3_ align 4
+            
+            <<<<<[-]
+            dup14/16/19 <<[->>+>>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %23 = udiv i32 %21_ i32 %22
+            
+            <<[-]
+            dup15/19/20 <<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            dup22/24/25 >[->>+>+<<<]
+            mov25/22 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/29 [->>>>>>>>>+<<<<<<<<<]
+                >>>>>>>>>>>+
+                <<<<<<<[
+                    -
+                    <+
+                    >>>>>>[
+                        -
+                        <<<<<<-
+                        r1 >
+                    >>>>>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<<<<]
+                >>>>>>>-
+                <<<<<<<<<<<<<<+
+                >>>>>>[
+                    [-]
+                    <<<<<<-
+                >>>>>>]
+                mov29/20 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                dup22/24/25 <<<<<<<[->>+>+<<<]
+                mov25/22 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %24 = urem i32 %23_ i32 10
+            
+            <<<<<<<<<[-]
+            dup17/19/20 >>[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup21/23/24 [->>+>+<<<]
+            mov24/21 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/25 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov25/20 <<[-<<<<<+>>>>>]
+                dup21/23/24 <<<<[->>+>+<<<]
+                mov24/21 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov23/15 >[-<<<<<<<<+>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<-
+                >>>>>>>]
+            ]
+            mov22/15 [-<<<<<<<+>>>>>>>]
+            <[-]
+            >>[-]
+            %25 = add i32 48_ i32 %24
+            
+            <<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup15/21/22 <<<<<[->>>>>>+>+<<<<<<<]
+            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
+            mov19/17 <<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/24/26 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov26/17 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %26 = trunc i32 %25 to i8
+            
+            <<<<<<<<[-]
+            dup17/19/20 >>[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/15 >[-<<<<<+>>>>>]
+            call @putchar(i8 %26)
+            
+            putchar intrinsic
+            dup15/19/20 <<<<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %28 = udiv i32 %22_ i32 10
+            
+            <<<<<[-]
+            dup16/19/20 >[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup21/23/24 [->>+>+<<<]
+            mov24/21 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov20/25 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<+
+                >>>>>>>[
+                    [-]
+                    <<<<<<<-
+                >>>>>>>]
+                mov25/20 >>>[-<<<<<+>>>>>]
+                dup21/23/24 <<<<[->>+>+<<<]
+                mov24/21 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            store i32 %28_ i32* %3_ align 4
+            
+            dup15/19/20 <<<<<<<<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            <<<<<[-]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            br label %17
+            
+            <<<<<<<<<<+
+        >]
+        >#B:%29
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >>>>>>]
+    <<<<<<<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l21 <<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:putdec
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #B:%call_term_for_3
+        #B:%call_term_for_4
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_volatile_i8*_%1__align_1(mult)
+        #%7_=_zext_i8_%6_to_i32(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            store volatile i8 100_ i8* %1_ align 1
+            
+            >>>>>>[-]
+            >>>>>>>++++++++++
+            [
+                -
+                <<<<<<<++++++++++
+            >>>>>>>]
+            store volatile i8 10_ i8* %2_ align 1
+            
+            <<<<<<[-]
+            ++++++++++
+            store volatile i8 9_ i8* %3_ align 1
+            
+            >[-]
+            +++++++++
+            store volatile i8 0_ i8* %4_ align 1
+            
+            >[-]
+            
+            store volatile i8 255_ i8* %5_ align 1
+            
+            >[-]
+            >>>>+++++++++++++++
+            [
+                -
+                <<<<+++++++++++++++++
+            >>>>]
+            %6 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            %7 = zext i8 %6 to i32
+            
+            <[-]
+            known to be 100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            call @putdec(i32 %7)
+            
+            enable next block when we return
+            <<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            mov18/26 <<[->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_putdec
+            +
+            >#putdec
+            +
+            >>#putdec/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %8 = load volatile i8* %2_ align 1
+            
+            <<[-]
+            dup12/16/18 <<<<[->>>>+>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            %9 = zext i8 %8 to i32
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            mov19/17 >[-<<+>>]
+            call @putdec(i32 %9)
+            
+            enable next block when we return
+            <<<<<<<<<<<<#caller/%call_term_for_1
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            dup17/18/19 [->+>+<<]
+            mov18/17 >[-<+>]
+            mov19/26 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_putdec
+            +
+            >#putdec
+            +
+            >>#putdec/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %10 = load volatile i8* %3_ align 1
+            
+            <<[-]
+            dup13/16/18 <<<[->>>+>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            mov19/17 >[-<<+>>]
+            call @putdec(i32 %11)
+            
+            enable next block when we return
+            <<<<<<<<<<<#caller/%call_term_for_2
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>#arg_0
+            dup17/18/19 [->+>+<<]
+            mov18/17 >[-<+>]
+            mov19/26 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_putdec
+            +
+            >#putdec
+            +
+            >>#putdec/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %12 = load volatile i8* %4_ align 1
+            
+            <<[-]
+            dup14/16/18 <<[->>+>>+<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            %13 = zext i8 %12 to i32
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            mov19/17 >[-<<+>>]
+            call @putdec(i32 %13)
+            
+            enable next block when we return
+            <<<<<<<<<<#caller/%call_term_for_3
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>#arg_0
+            dup17/18/19 [->+>+<<]
+            mov18/17 >[-<+>]
+            mov19/26 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_putdec
+            +
+            >#putdec
+            +
+            >>#putdec/b0
+            +
+        >>>>>]
+        >#B:%call_term_for_3
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %14 = load volatile i8* %5_ align 1
+            
+            <<[-]
+            dup15/16/18 <[->+>>+<<<]
+            mov18/15 >>>[-<<<+>>>]
+            %15 = zext i8 %14 to i32
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            mov19/17 >[-<<+>>]
+            call @putdec(i32 %15)
+            
+            enable next block when we return
+            <<<<<<<<<#caller/%call_term_for_4
+            +
+            stack_width 21
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            dup17/18/19 [->+>+<<]
+            mov18/17 >[-<+>]
+            mov19/26 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++
+            l1 <
+            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r21 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_putdec
+            +
+            >#putdec
+            +
+            >>#putdec/b0
+            +
+        >>>>>>]
+        >#B:%call_term_for_4
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>>]
+    <<<<<<<<]
+<<]
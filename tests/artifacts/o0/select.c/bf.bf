@@ -0,0 +1,529 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4570 ops ~115086 steps
+frame widths:
+  main: 28 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l28 <<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%27
+        #B:%28
+        #B:%33
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #alloc_%6
+        #%7_=_load_volatile_i8*_%2__align_1(mult)
+        #%8_=_zext_i8_%7_to_i32(mult)
+        #%32_=_add_i32_%31__i32_78(mult)
+        #%34_=_phi_i32_{_i32_89__%27_}__{_i32_%32__%28_}(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            %6 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            store volatile i8 121_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>>>>>+++++++++++
+            [
+                -
+                <<<<<<<<<+++++++++++
+            >>>>>>>>>]
+            store volatile i8 110_ i8* %3_ align 1
+            
+            <<<<<<<<[-]
+            >>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<+++++++++++
+            >>>>>>>>>]
+            %7 = load volatile i8* %2_ align 1
+            
+            <<<<<[-]
+            known to be 121
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %8 = zext i8 %7 to i32
+            
+            <<<[-]
+            known to be 121
+            >>>+++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %9 = icmp eq i32 %8_ i32 121
+            
+            <<<<[-]
+            known to be 1
+            +
+            %10 = zext i1 %9 to i32
+            
+            >[-]
+            known to be 1
+            +
+            %11 = trunc i32 %10 to i8
+            
+            <[-]
+            known to be 1
+            +
+            store i8 %11_ i8* %4_ align 1
+            
+            <<<[-]
+            +
+            %12 = load volatile i8* %3_ align 1
+            
+            >>>[-]
+            known to be 110
+            >>>>++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %13 = zext i8 %12 to i32
+            
+            <<<[-]
+            known to be 110
+            >>>++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %14 = icmp eq i32 %13_ i32 121
+            
+            <<<<[-]
+            known to be 0
+            
+            %15 = zext i1 %14 to i32
+            
+            >[-]
+            known to be 0
+            
+            %16 = trunc i32 %15 to i8
+            
+            <[-]
+            known to be 0
+            
+            store i8 %16_ i8* %5_ align 1
+            
+            <<[-]
+            
+            %17 = load i8* %4_ align 1
+            
+            >>[-]
+            known to be 1
+            +
+            %18 = zext i8 %17 to i32
+            
+            >[-]
+            known to be 1
+            +
+            %19 = icmp ne i32 %18_ i32 0
+            
+            <[-]
+            known to be 1
+            +
+            %21 = select i1 %19_ i32 89_ i32 78
+            
+            >[-]
+            known to be 89
+            >>>++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %22 = trunc i32 %21 to i8
+            
+            <[-]
+            known to be 89
+            >>>>++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            store i8 %22_ i8* %6_ align 1
+            
+            <[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            <<<<<+
+            %23 = load i8* %6_ align 1
+            
+            >[-]
+            known to be 89
+            >>>>++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            call @putchar(i8 %23)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>#constop_89
+            >>++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            %24 = load i8* %5_ align 1
+            
+            <<<<[-]
+            dup11/13/17 <<[->>+>>>>+<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            %25 = zext i8 %24 to i32
+            
+            <<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            %26 = icmp ne i32 %25_ i32 0
+            
+            <<<<<[-]
+            dup14/17/18 >[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<<<<+
+            >>>>>]
+            br i1 %26_ label %27_ label %28
+            
+            dup13/18/17 <<<<<[->>>>>+<+<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >+
+                #B:main/%27_true
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%28_false
+            <<]
+        <]
+        >>#B:%27
+        [
+            -
+            br label %33
+            
+            doing phi stuff
+            %34 = phi i32 { i32 89_ %27 }_ { i32 %32_ %28 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>#constop_89
+            ++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            >+
+            <<<<[-]
+            mov20/16 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+        <<]
+        >#B:%28
+        [
+            -
+            %29 = load i8* %6_ align 1
+            
+            >>>>>>>>[-]
+            dup12/13/21 <[->+>>>>>>>>+<<<<<<<<<]
+            mov21/12 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %30 = zext i8 %29 to i32
+            
+            <<<<<<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            %31 = sub i32 %30_ i32 89
+            
+            <<<<<[-]
+            dup14/17/18 >[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_89
+            >++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            mov18/13 <[-<<<<<+>>>>>]
+            >[
+                -
+                >>>>+
+                <<<<<<<<<<[
+                    -
+                    mov13/22 [->>>>>>>>>+<<<<<<<<<]
+                    >>>>>>>>>>-
+                <<<<<<<<<<]
+                mov22/13 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>>]
+            <<<<]
+            %32 = add i32 %31_ i32 78
+            
+            <<<<[-]
+            dup13/17/18 <<[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_78
+            >++++++
+            [
+                -
+                <+++++++++++++
+            >]
+            mov18/15 <<[-<<<+>>>]
+            >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/25/27 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov27/15 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov24/25 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov25/24 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            br label %33
+            
+            doing phi stuff
+            %34 = phi i32 { i32 89_ %27 }_ { i32 %32_ %28 }
+            <<<<<<<<[-]
+            mov15/16 <[->+<]
+            <<<<<<<<<+
+        <]
+        >#B:%33
+        [
+            -
+            %34 = phi i32 { i32 89_ %27 }_ { i32 %32_ %28 }
+            %35 = trunc i32 %34 to i8
+            
+            >>>>>>>[-]
+            dup16/17/18 >>>[->+>+<<]
+            mov17/16 >[-<+>]
+            mov18/13 >[-<<<<<+>>>>>]
+            call @putchar(i8 %35)
+            
+            putchar intrinsic
+            dup13/17/18 <<<<<[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %36 = load i8* %4_ align 1
+            
+            <<<<<[-]
+            dup10/13/17 <<<[->>>+>>>>+<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %37 = zext i8 %36 to i32
+            
+            <<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            %38 = add i32 48_ i32 %37
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup14/19/20 <<<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            mov17/13 <<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/22/24 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov24/13 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %39 = trunc i32 %38 to i8
+            
+            <<<<<<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            call @putchar(i8 %39)
+            
+            putchar intrinsic
+            dup14/17/18 <<<<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            >.
+            [-]
+            %40 = load i8* %5_ align 1
+            
+            <<<<<[-]
+            dup11/13/17 <<[->>+>>>>+<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            %41 = zext i8 %40 to i32
+            
+            <<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            %42 = add i32 48_ i32 %41
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup14/19/20 <<<<[->>>>>+>+<<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            mov17/13 <<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/22/24 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov24/13 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %43 = trunc i32 %42 to i8
+            
+            <<<<<<<[-]
+            dup13/17/18 <[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov18/14 >[-<<<<+>>>>]
+            call @putchar(i8 %43)
+            
+            putchar intrinsic
+            dup14/17/18 <<<<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            >.
+            [-]
+            %44 = load i32* %1_ align 4
+            
+            <<<<<[-]
+            dup7/13/17 <<<<<<[->>>>>>+>>>>+<<<<<<<<<<]
+            mov17/7 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            ret i32 %44
+            
+            zero all function allocs
+            <<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
@@ -1,838 +1,199 @@
-r7 >>>>>>>
-+++++++
+eof: 0
+blocks made up by bfcc:
+  lol/%call_term_for_0 from %8 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  lol calls lol (depth unbounded_ recursive)
+  main calls lol (depth unbounded)
+  max depth from main: unbounded
+cost (loops go round 8 times):
+  lol: 3773 ops ~63350 steps
+  main: 301 ops ~917 steps
+frame widths:
+  lol: 30 cells
+  main: 8 cells
+r5 >>>>>
++++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>#main
+>>#main
 +
 >#main/b0
 +
-<<<<<[
-    >#putstr
+<<<[
+    >#lol
     [
-        >>>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>>-
-            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:lol
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%6
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%4_=_load_i8**_%2__align_8(mult)
-        >#%5_=_icmp_ne_i8*_%4__i8*_null(mult)
-        >#%7_=_load_i8**_%2__align_8(mult)
-        >#%8_=_ptrtoint_i8*_%7_to_i8(mult)
-        >#%9_=_load_i8**_%2__align_8(mult)
-        >#%10_=_getelementptr_i8*_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/20 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = alloca i8*_ align 8
-            store i8* %0_ i8** %2_ align 8
-            >>>>>>>>>>>[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            br label %3
-            <<<<<<<<<<<+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = load i8** %2_ align 8
-            >>>>>[-]
-            dup11/12/19 <[->+>>>>>>>+<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %5 = icmp ne i8* %4_ i8* null
-            <<<<<<[-]
-            dup12/20/19 <[->>>>>>>>+<+<<<<<<<]
-            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/23 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
-                -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<+
-            >>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<+
-            >>>>>>>>>]
-            br i1 %5_ label %6_ label %11
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>[
-                -
-                <<<<<<<-
-                >>+
-                #B:putstr/6_true
-            >>>>>]
-            <<<<<<<[
-                -
-                >>>>+
-                #B:putstr/11_false
-            <<<<]
-        >]
-        >#B:%6
-        [
-            -
-            %7 = load i8** %2_ align 8
-            >>>>>>[-]
-            dup11/14/19 <<<[->>>+>>>>>+<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %8 = ptrtoint i8* %7 to i8
-            <<<<[-]
-            dup14/19/20 <[->>>>>+>+<<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            mov20/15 >[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup15/19/20 >>>>>>[->>>>+>+<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = load i8** %2_ align 8
-            >>>>>>>[-]
-            dup11/16/19 <<<<<[->>>>>+>>>+<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %10 = getelementptr i8* %9_ i32 1
-            <<[-]
-            dup16/19/20 <[->>>+>+<<<<]
-            mov19/16 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov20/17 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            store i8* %10_ i8** %2_ align 8
-            dup17/19/20 <<<<[->>+>+<<<]
-            mov19/17 >>[-<<+>>]
-            <<<<<<<<[-]
-            mov20/11 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            br label %3
-            <<<<<<<<<<<<<+
-        >>]
-        >#B:%11
+        >>>#B:%ret_lading_pad
         [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<-
+            <<<<-
             #dead_frame
             >-
-            l1 <
-            <[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>]
-    <<<<<<<<<]
-    >#putdec
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>-
-            l49 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/lol
+            >>>-
+            l30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:lol
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%6
-        >#B:%call_term_for_1
-        >#B:%7
-        >#B:%8
-        >#B:%13
-        >#B:%16
-        >#B:%17
-        >#B:%20
-        >#B:%call_term_for_8
-        >#B:%29
-        >#alloc_%2
-        >#alloc_%3
-        >#%4_=_load_i32*_%2__align_4(mult)
-        >#%5_=_icmp_ne_i32_%4__i32_0(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_load_i32*_%3__align_4(mult)
-        >#%11_=_sdiv_i32_%9__i32_%10(mult)
-        >#%12_=_icmp_sgt_i32_%11__i32_10(mult)
-        >#%14_=_load_i32*_%3__align_4(mult)
-        >#%15_=_mul_i32_%14__i32_10(mult)
-        >#%18_=_load_i32*_%3__align_4(mult)
-        >#%19_=_icmp_ne_i32_%18__i32_0(mult)
-        >#%21_=_load_i32*_%2__align_4(mult)
-        >#%22_=_load_i32*_%3__align_4(mult)
-        >#%23_=_sdiv_i32_%21__i32_%22(mult)
-        >#%24_=_srem_i32_%23__i32_10(mult)
-        >#%25_=_add_i32_48__i32_%24(mult)
-        >#%26_=_trunc_i32_%25_to_i8(mult)
-        >#%27_=_load_i32*_%3__align_4(mult)
-        >#%28_=_sdiv_i32_%27__i32_10(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        #mainloop_lol
+        #F:lol
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%8
+        #B:%call_term_for_0
+        #B:%11
+        #alloc_%2
+        #%3_=_load_i32*_%2__align_4(mult)
+        #%4_=_add_i32_%3__i32_97(mult)
+        #%5_=_trunc_i32_%4_to_i8(mult)
+        #%0(mult)
+        <#B:%1
         [
             -
             copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            #arg_%0
+            l2 
+            mov0/14 <<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
             r2 >>
             %2 = alloca i32_ align 4
-            %3 = alloca i32_ align 4
             store i32 %0_ i32* %2_ align 4
-            >>>>>>>>>>>>>>>>>[-]
-            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %4 = load i32* %2_ align 4
-            <<<<<<<<<<<<<<<<<<[-]
-            dup17/19/38 <<[->>+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %5 = icmp ne i32 %4_ i32 0
-            <<<<<<<<<<<<<<<<<<[-]
-            dup19/39/38 <[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
-            mov39/19 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_0
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov38/42 <<<<<<[->>>>+<<<<]
-            >>>>>>+
-            <<<<[
+            dup12/13/14 >>>>>>>>>>>>[->+>+<<]
+            mov13/12 >[-<+>]
+            <<<<<[-]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            %3 = load i32* %2_ align 4
+            
+            <<<<<[-]
+            dup8/9/13 <[->+>>>>+<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            %4 = add i32 %3_ i32 97
+            
+            <<<[-]
+            dup9/13/15 <[->>>>+>>+<<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >#constop_97
+            >>++++++++
+            [
                 -
-                >+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
-            br i1 %5_ label %7_ label %6
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
+                <<++++++++++++
+            >>]
+            <<+
+            mov15/10 >[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/18/20 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
                 -
-                <<<<<<<<<<<<<<-
-                >>>+
-                #B:putdec/7_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<[
+                <-
+            >]
+            mov20/10 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<<[
                 -
-                >+
-                #B:putdec/6_false
-            <]
-        <]
-        >>#B:%6
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
+                >>>>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<]
+            >>>[-]
+            %5 = trunc i32 %4 to i8
+            
+            <<<<<<[-]
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/11 >[-<<<+>>>]
+            call @putchar(i8 %5)
+            
             putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            dup11/13/14 <<<[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            br label %29
-            >>>>>>>>+
-        <<<<<<<<]
-        >#B:%7
-        [
-            -
-            store i32 1_ i32* %3_ align 4
+            %7 = icmp slt i32 %3_ i32 5
+            
+            <<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_1
-            +
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        <]
-        >#B:%8
-        [
-            -
-            %9 = load i32* %2_ align 4
-            >>>>>>>>>>>[-]
-            dup17/21/38 <<<<[->>>>+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %10 = load i32* %3_ align 4
-            <<<<<<<<<<<<<<<<[-]
-            dup18/22/38 <<<<[->>>>+>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %11 = sdiv i32 %9_ i32 %10
-            <<<<<<<<<<<<<<<[-]
-            dup21/38/39 <<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
-            mov38/21 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-            dup22/40/41 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov40/22 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            dup41/43/45 >[->>+>>+<<<<]
-            mov45/41 >>>>[-<<<<+>>>>]
+            >>#constop_5
+            +++++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/21 <<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
             <<<<<<[
-                >>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/46 <<<<<<<<<[->>>>>>>+<<<<<<<]
-                >>>>>>>>>+
-                <<<<<[
-                    -
-                    <+
-                    >>>>[
-                        -
-                        <<<<-
-                        r1 >
-                    >>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<]
-                >>>>>-
-                <<<<<<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>]
-                mov46/39 >>>>[-<<<<<<<+>>>>>>>]
-                dup41/43/45 <<<<<[->>+>>+<<<<]
-                mov45/41 >>>>[-<<<<+>>>>]
-            <<<<<<]
-            >>[-]
-            >>[-]
-            %12 = icmp sgt i32 %11_ i32 10
-            <<<<<<<<<<<<<<<<<<<[-]
-            dup23/38/39 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov38/23 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov39/41 <<<<[->>+<<]
-            >>>>+
-            <<<[
                 -
-                
-                >[
+                <+
+                >>>>>[
                     -
-                    
+                    <<<<<-
                     r1 >
-                ]
+                >>>>>]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %12_ label %13_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/13_true
-            >>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/16_false
-            <<<<<<]
-        >>>>]
-        >#B:%13
-        [
-            -
-            %14 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup18/25/38 <<<<<<<[->>>>>>>+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %15 = mul i32 %14_ i32 10
-            <<<<<<<<<<<<[-]
-            dup25/38/39 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov38/25 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup39/26/41 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>+<<]
-                mov41/39 >>[-<<+>>]
-            <]
-            <[-]
-            store i32 %15_ i32* %3_ align 4
-            dup26/38/39 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov39/18 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %8
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >]
-        >#B:%16
-        [
-            -
-            br label %17
-            >+
-        <]
-        >#B:%17
-        [
-            -
-            %18 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup18/27/38 <<<<<<<<<[->>>>>>>>>+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %19 = icmp ne i32 %18_ i32 0
-            <<<<<<<<<<[-]
-            dup27/38/39 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov39/42 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<[
+            <<<<]
+            >>>>>>-
+            <<<<<<<[
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<[
+                    -
+                    >>>>>-
+                <<<<<]
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/24 <<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<<<<<[
                 -
-                >+
-                >[
+                <+
+                >>>>>>[
                     -
-                    <-
+                    <<<<<<-
                     r1 >
-                >]
+                >>>>>>]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %19_ label %20_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/20_true
-            >>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>+
-                #B:putdec/29_false
-            <<<<<<<<<<]
-        >>>>>>>]
-        >#B:%20
-        [
-            -
-            %21 = load i32* %2_ align 4
-            >>>>>>>>>>>>>>>[-]
-            dup17/29/38 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            %22 = load i32* %3_ align 4
-            <<<<<<<<[-]
-            dup18/30/38 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %23 = sdiv i32 %21_ i32 %22
-            <<<<<<<[-]
-            dup29/38/39 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov38/29 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            dup30/40/41 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov40/30 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            dup41/43/44 >[->>+>+<<<]
-            mov44/41 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/45 <<<<<<<<[->>>>>>+<<<<<<]
-                >>>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<-
-                >>>>>>>>>>>]
-                mov45/39 >>>[-<<<<<<+>>>>>>]
-                dup41/43/44 <<<<[->>+>+<<<]
-                mov44/41 >>>[-<<<+>>>]
             <<<<<]
-            >>[-]
-            >>[-]
-            %24 = srem i32 %23_ i32 10
-            <<<<<<<<<<<[-]
-            dup31/38/39 <[->>>>>>>+>+<<<<<<<<]
-            mov38/31 >>>>>>>[-<<<<<<<+>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup40/42/43 [->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/44 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
+            >>>>>>>-
+            <<<<<<<<[
+                >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<<[
                     -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov44/39 <<[-<<<<<+>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov42/32 >[-<<<<<<<<<<+>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<-
-                >>>>>>>>>]
-            ]
-            mov41/32 [-<<<<<<<<<+>>>>>>>>>]
-            <[-]
-            >>[-]
-            %25 = add i32 48_ i32 %24
-            <<<<<<<<<[-]
-            op_to_reg storing const value in temp address
-            >>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            dup32/39/40 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov39/32 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov38/33 <[-<<<<<+>>>>>]
-            >>[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            %26 = trunc i32 %25 to i8
-            <<<<<<[-]
-            dup33/38/39 <[->>>>>+>+<<<<<<]
-            mov38/33 >>>>>[-<<<<<+>>>>>]
-            mov39/34 >[-<<<<<+>>>>>]
-            call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            dup34/38/39 >>>>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
-            mov38/34 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            %27 = load i32* %3_ align 4
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup18/35/38 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
-            mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            %28 = sdiv i32 %27_ i32 10
-            <<[-]
-            dup35/38/39 <[->>>+>+<<<<]
-            mov38/35 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup40/42/43 [->>+>+<<<]
-            mov43/40 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov39/44 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<+
-                >>>>>[
-                    [-]
-                    <<<<<-
-                >>>>>]
-                mov44/39 >>>[-<<<<<+>>>>>]
-                dup40/42/43 <<<<[->>+>+<<<]
-                mov43/40 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            store i32 %28_ i32* %3_ align 4
-            dup36/38/39 <<<<<<[->>+>+<<<]
-            mov38/36 >>[-<<+>>]
-            <<<<<<<<<<<<<<<<<<<<[-]
-            mov39/18 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
-            br label %17
-            <<<<<<<<<<<<<<<<<<<<<<<<<<+
-        >>]
-        >#B:%29
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<]
-    >#lol
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/lol
-            >>>>>>-
-            l28 <<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_lol
-        >#F:putstr
-        >#F:putdec
-        >#F:lol
-        >#F:main
-        >#B:%no_block0_call_for_lol
-        >#B:%ret_lading_pad
-        >#B:%1
-        >#B:%call_term_for_0
-        >#B:%8
-        >#B:%call_term_for_2
-        >#B:%11
-        >#alloc_%2
-        >#%3_=_load_i32*_%2__align_4(mult)
-        >#%4_=_add_i32_%3__i32_97(mult)
-        >#%5_=_trunc_i32_%4_to_i8(mult)
-        >#%6_=_load_i32*_%2__align_4(mult)
-        >#%7_=_icmp_slt_i32_%6__i32_5(mult)
-        >#%9_=_load_i32*_%2__align_4(mult)
-        >#%10_=_add_i32_%9__i32_1(mult)
-        >#%0
-        <<<<<<<<<<<<<<<#B:%no_block0_call_for_lol
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            br label %1
-            >>>>>>>+
-        <<]
-        >>#B:%1
-        [
-            -
-            %2 = alloca i32_ align 4
-            store i32 %0_ i32* %2_ align 4
-            >>>>>[-]
-            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            %3 = load i32* %2_ align 4
-            <<<<<<<[-]
-            dup12/13/21 <[->+>>>>>>>>+<<<<<<<<<]
-            mov21/12 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            %4 = add i32 %3_ i32 97
-            <<<<<<<[-]
-            dup13/22/21 <[->>>>>>>>>+<+<<<<<<<<]
-            mov22/13 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov21/14 <<[-<<<<<<<+>>>>>>>]
-            >>[
-                -
-                <<<<<<<<<+
-            >>>>>>>>>]
-            %5 = trunc i32 %4 to i8
-            <<<<<<<<[-]
-            dup14/21/22 <[->>>>>>>+>+<<<<<<<<]
-            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov22/15 >[-<<<<<<<+>>>>>>>]
-            call @putchar(i8 %5)
-            enable next block when we return
-            <<<<<<<<<<<<<<#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            dup15/21/22 >>>>>>>[->>>>>>+>+<<<<<<<]
-            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
-            %6 = load i32* %2_ align 4
-            >>>>>>>>[-]
-            dup12/16/21 <<<<[->>>>+>>>>>+<<<<<<<<<]
-            mov21/12 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            %7 = icmp slt i32 %6_ i32 5
-            <<<<[-]
-            dup16/21/22 <[->>>>>+>+<<<<<<]
-            mov21/16 >>>>>[-<<<<<+>>>>>]
-            op_to_reg storing const value in temp address
-            >>>#constop_5
-            +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov24/25 <<<[->+<]
-            >>>+
-            <<<<<[
+                    >>>>>>-
+                <<<<<<]
+                >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<<]
+            >>>>>>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/27 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<[
                 -
                 
-                >>>[
+                >>>>>>[
                     -
                     
                     r1 >
@@ -842,166 +203,191 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<]
-            >>>>>-
+            <<<<<<]
+            >>>>>>>>-
             <<[
                 [-]
-                <<<<<<<<+
-            >>>>>>>>]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
             br i1 %7_ label %8_ label %11
-            <<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>[
+            
+            dup10/14/13 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<+
+            >>>>>>>>>[
                 -
-                <<<<<<<<<<<-
-                >>>+
-                #B:lol/8_true
+                <<<<<<<<<-
+                >+
+                #B:lol/%8_true
             >>>>>>>>]
-            <<<<<<<<<<<[
+            <<<<<<<<<[
                 -
-                >>>>>+
-                #B:lol/11_false
-            <<<<<]
-        >>]
-        >#B:%8
+                >>>+
+                #B:lol/%11_false
+            <<<]
+        <]
+        >>#B:%8
         [
             -
             %9 = load i32* %2_ align 4
-            >>>>>>>>>[-]
-            dup12/18/21 <<<<<<[->>>>>>+>>>+<<<<<<<<<]
-            mov21/12 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            
+            >>>>[-]
+            dup8/9/15 <[->+>>>>>>+<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
             %10 = add i32 %9_ i32 1
-            <<[-]
-            dup18/21/22 <[->>>+>+<<<<]
-            mov21/18 >>>[-<<<+>>>]
+            
+            <<<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov22/19 <[-<<<+>>>]
-            >[
+            mov14/10 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/17/19 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
                 -
-                <<<<+
-            >>>>]
+                <-
+            >]
+            mov19/10 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             call @lol(i32 %10)
+            
             enable next block when we return
-            <<<<<<<<<<<<<#caller/%call_term_for_2
+            <<<<<<<<<<#caller/%call_term_for_0
             +
-            stack_width 28
-            ret_pad_width 6
+            stack_width 30
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup19/21/22 <<<<<<<<<<<<<<<<[->>+>+<<<]
-            mov21/19 >>[-<<+>>]
-            mov22/35 >[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            >>>>#arg_0
+            dup10/13/14 [->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/35 >[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>>>>>>#stack_ptr
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
             +++++++++++++++++++++++++++++++++++++
             l1 <
             dup0/37/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
             mov38/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r28 >>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            r1 
+            r30 
+            <<<<<<<#JUMP_PAD
             +
-            >>>#caller
+            >#caller
             +
             >>>#caller/jump_pad_blk
             +
-            r9 >>>>>>>>>
-            <<<<<<#===FRAME_lol
+            r7 >>>
+            #===FRAME_lol
             +
-            >>>#lol
+            >#lol
             +
             >>#lol/b0
             +
-        >>>>]
-        >#B:%call_term_for_2
+        >>]
+        >#B:%call_term_for_0
         [
             -
             br label %11
+            
             >+
         <]
         >#B:%11
         [
             -
             ret void
+            
             zero all function allocs
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>>]
-    <<<<<<<<]
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
     >#main
     [
         >>#B:%ret_lading_pad
         [
-            <<<<<<-
+            <<<<-
             #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/main
-            >>>>>>-
-            l10 <<<<<<<<<<
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l8 <<<<<<<<
         ]
-        <<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:lol
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        <<<#B:%no_block0_call_for_main
+        #mainloop_main
+        #F:lol
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        <#B:%no_block0_call_for_main
         [
             -
             copy up args
             br label %0
+            
             >>+
         <<]
         >>#B:%0
         [
             -
             call @lol(i32 0)
+            
             enable next block when we return
             >#caller/%call_term_for_0
             +
-            stack_width 10
-            ret_pad_width 6
+            stack_width 8
+            ret_pad_width 4
             copy up arg 0
-            >>>>>>>>>#arg_0
+            >#arg_0
             op_to_reg storing const value in temp address
-            <<<<<<<<#constop_0
+            #constop_0
             
-            mov9/17 [->>>>>>>>+<<<<<<<<]
+            mov7/13 [->>>>>>+<<<<<<]
             give callee a stack pointer
-            >>>>>>>>>#stack_ptr
-            +++++++++++++++++++
+            >>>>>>>#stack_ptr
+            +++++++++++++++
             l1 <
-            dup0/19/20 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
-            mov20/0 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r10 >>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<#JUMP_PAD
+            dup0/15/16 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov16/0 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            r1 
+            r8 
+            <<<<<<<#JUMP_PAD
             +
-            >>>>#caller
+            >>#caller
             +
             >>#caller/jump_pad_blk
             +
-            r9 >>>>>>>>>
-            <<<<<<#===FRAME_lol
+            r7 >>>
+            #===FRAME_lol
             +
-            >>>#lol
+            >#lol
             +
             >>#lol/b0
             +
@@ -1010,14 +396,15 @@ runtime init:
         [
             -
             ret void
+            
             zero all function allocs
-            <<<<<<<<-
+            <<<<<<-
             #dead_frame
-            >>>>-
+            >>-
             l1 <
-            <<<<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>]
+            <<[-]
+            r1 
+            l6 
+        >]
     <<<<]
-<<<<]
+<<]
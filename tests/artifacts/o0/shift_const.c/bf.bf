@@ -0,0 +1,311 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2546 ops ~416288 steps
+frame widths:
+  main: 19 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l19 <<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i8*_%1__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%6_=_shl_i32_%5__i32_2(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store i8 25_ i8* %1_ align 1
+            
+            >>[-]
+            +++++++++++++++++++++++++
+            store i8 2_ i8* %2_ align 1
+            
+            >[-]
+            ++
+            %4 = load i8* %1_ align 1
+            
+            >>[-]
+            known to be 25
+            +++++++++++++++++++++++++
+            %5 = zext i8 %4 to i32
+            
+            >[-]
+            known to be 25
+            >>+++++
+            [
+                -
+                <<+++++
+            >>]
+            %6 = shl i32 %5_ i32 2
+            
+            <[-]
+            known to be 100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            %7 = trunc i32 %6 to i8
+            
+            <<[-]
+            known to be 100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            store i8 %7_ i8* %3_ align 1
+            
+            <<<<[-]
+            >>>>++++++++++
+            [
+                -
+                <<<<++++++++++
+            >>>>]
+            %8 = load i8* %3_ align 1
+            
+            <<[-]
+            known to be 100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_100
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<.
+            [-]
+            %10 = zext i8 %8 to i32
+            
+            <[-]
+            known to be 100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            %11 = ashr i32 %10_ i32 3
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            op_to_reg storing const value in temp address
+            >>#constop_3
+            +++
+            mov10/8 <<<[-<<+>>]
+            >>>>>>+
+            <<<[
+                -
+                mov8/14 <<<<<[->>>>>>+<<<<<<]
+                >>>>>>[
+                    -
+                    [
+                        -
+                        <<<<<<+
+                    >>>>>>>]
+                    >[
+                    <]
+                <]
+            <]
+            >>>[-]
+            %12 = add i32 65_ i32 %11
+            
+            <<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup8/12/13 <<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov10/9 <<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/15/17 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov17/9 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov14/15 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov15/14 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %13 = trunc i32 %12 to i8
+            
+            <<<<<<[-]
+            dup9/10/11 >[->+>+<<]
+            mov10/9 >[-<+>]
+            mov11/8 >[-<<<+>>>]
+            call @putchar(i8 %13)
+            
+            putchar intrinsic
+            dup8/10/11 <<<[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            >.
+            [-]
+            %15 = zext i8 %4 to i32
+            
+            <<<[-]
+            known to be 25
+            >>+++++
+            [
+                -
+                <<+++++
+            >>]
+            %16 = load i8* %2_ align 1
+            
+            <<<[-]
+            dup5/7/10 <<[->>+>>>+<<<<<]
+            mov10/5 >>>>>[-<<<<<+>>>>>]
+            %17 = zext i8 %16 to i32
+            
+            <[-]
+            dup7/10/11 <<[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/9 >[-<<+>>]
+            %18 = shl i32 %15_ i32 %17
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_25
+            >+++++
+            [
+                -
+                <+++++
+            >]
+            dup9/12/13 <<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            >>+
+            <[
+                -
+                mov7/10 <<<<<<[->>>+<<<]
+                >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<[
+                    -
+                    >>>>>>>+
+                    <<[
+                        -
+                        mov15/16 [->+<]
+                        <<<<<<<<+
+                        >>>>>>>>>>-
+                    <<]
+                    mov16/15 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<[-]
+                        >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                    +
+                    <<[
+                        -
+                        mov15/16 [->+<]
+                        <<<<<<<<+
+                        >>>>>>>>>>-
+                    <<]
+                    mov16/15 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<[-]
+                        >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                <<<<<<<]
+                >>>>>[-]
+                <[-]
+            <]
+            >[
+                -
+                mov10/7 <<<<[-<<<+>>>]
+            >>>>]
+            %19 = trunc i32 %18 to i8
+            
+            <<<<<<[-]
+            dup7/10/11 <[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            mov11/8 >[-<<<+>>>]
+            call @putchar(i8 %19)
+            
+            putchar intrinsic
+            dup8/10/11 <<<[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
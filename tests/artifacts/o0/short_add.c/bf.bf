@@ -0,0 +1,457 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 10344 ops ~185358 steps
+frame widths:
+  main: 47 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%1_hi
+        #alloc_%2
+        #alloc_%2_hi
+        #alloc_%3
+        #alloc_%3_hi
+        #alloc_%4
+        #alloc_%4_hi
+        #alloc_%5
+        #alloc_%5_hi
+        #alloc_%6
+        #alloc_%6_hi
+        #%7_=_load_i16*_%1__align_2(mult)
+        #%7_hi(mult)
+        #%9_=_load_i16*_%2__align_2(mult)
+        #%9_hi(mult)
+        #%12_=_add_i16_%7__i16_%9(mult)
+        #%12_hi(mult)
+        #%13_=_load_i16*_%3__align_2(mult)
+        #%13_hi(mult)
+        #%14_=_trunc_i16_%13_to_i8(mult)
+        #%20_=_sub_i16_%13__i16_%7(mult)
+        #%20_hi(mult)
+        #%21_=_load_i16*_%4__align_2(mult)
+        #%21_hi(mult)
+        #%28_=_add_i16_%13__i16_%13(mult)
+        #%28_hi(mult)
+        #%29_=_load_i16*_%5__align_2(mult)
+        #%29_hi(mult)
+        #%34_=_sub_i16_%29__i16_500(mult)
+        #%34_hi(mult)
+        #%35_=_load_i16*_%6__align_2(mult)
+        #%35_hi(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i16_ align 2
+            %2 = alloca i16_ align 2
+            %3 = alloca i16_ align 2
+            %4 = alloca i16_ align 2
+            %5 = alloca i16_ align 2
+            %6 = alloca i16_ align 2
+            store i16 200_ i16* %1_ align 2
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<++++++++++++++++++++
+            >>]
+            <
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            mov37/4 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov38/5 >[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            store i16 100_ i16* %2_ align 2
+            
+            >>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            <<
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            mov37/6 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov38/7 >[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %7 = load i16* %1_ align 2
+            
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            dup4/39/37 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/4 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup5/39/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/5 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/16 <<[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            mov38/17 >[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            %9 = load i16* %2_ align 2
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            dup6/39/37 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/6 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup7/39/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/7 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/18 <<[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            mov38/19 >[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            %12 = add i16 %7_ i16 %9
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            dup16/39/37 <<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<]
+            mov39/16 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            dup17/39/38 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<]
+            mov39/17 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            dup18/40/41 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/18 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            dup19/40/42 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov40/19 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            mov38/21 <<[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov42/21 >>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<<[
+                -
+                >>>>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            >>>>[
+                -
+                >>>>+
+                <<[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>[-]
+                    mov43/44 <<[->+<]
+                ]
+                mov44/43 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    <<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<]
+            >>[-]
+            store i16 %12_ i16* %3_ align 2
+            
+            dup20/39/37 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<]
+            mov39/20 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            dup21/39/38 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov39/21 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            mov37/8 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov38/9 >[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %13 = load i16* %3_ align 2
+            
+            <<<<<<<<<<<<<<<<[-]
+            >[-]
+            dup8/39/37 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/8 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup9/39/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/9 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/22 <<[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            mov38/23 >[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %14 = trunc i16 %13 to i8
+            
+            <<<<<<<<<<<<<<[-]
+            dup22/39/37 <<[->>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<]
+            mov39/22 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            dup23/39/38 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<]
+            mov39/23 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov37/24 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >[-]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            dup24/37/38 <<<<<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >.
+            [-]
+            %20 = sub i16 %13_ i16 %7
+            
+            <<<<<<<<<<<<<[-]
+            >[-]
+            dup22/39/37 <<<<[->>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<]
+            mov39/22 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            dup23/39/38 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<]
+            mov39/23 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            dup16/42/40 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/16 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup17/42/41 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/17 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/25 <<<<<[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            mov38/26 >[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >>>[
+                -
+                <<<<<<<<<<<<<<<-
+            >>>>>>>>>>>>>>>]
+            <[
+                -
+                >>>>+
+                <<<<<<<<<<<<<<<<<<<[
+                    -
+                    >>>>>>>>>>>>>>>>>>>[-]
+                    mov25/43 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
+                ]
+                mov43/25 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >-
+                >>>>>>>>>>>>>>>>>>]
+            <<<<]
+            store i16 %20_ i16* %4_ align 2
+            
+            dup25/39/37 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>+<<+<<<<<<<<<<<<]
+            mov39/25 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            dup26/39/38 <<<<<<<<<<<<<[->>>>>>>>>>>>>+<+<<<<<<<<<<<<]
+            mov39/26 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            mov37/10 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov38/11 >[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %21 = load i16* %4_ align 2
+            
+            <<<<<<<<<<<[-]
+            >[-]
+            dup10/39/37 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/10 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup11/39/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/11 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/27 <<[-<<<<<<<<<<+>>>>>>>>>>]
+            mov38/28 >[-<<<<<<<<<<+>>>>>>>>>>]
+            %22 = trunc i16 %21 to i8
+            
+            <<<<<<<<<<<<<<[-]
+            dup27/39/37 >>>[->>>>>>>>>>>>+<<+<<<<<<<<<<]
+            mov39/27 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            dup28/39/38 <<<<<<<<<<<[->>>>>>>>>>>+<+<<<<<<<<<<]
+            mov39/28 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            mov37/24 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >[-]
+            call @putchar(i8 %22)
+            
+            putchar intrinsic
+            dup24/37/38 <<<<<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >.
+            [-]
+            %28 = add i16 %13_ i16 %13
+            
+            <<<<<<<<<[-]
+            >[-]
+            dup22/39/37 <<<<<<<<[->>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<]
+            mov39/22 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            dup23/39/38 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<]
+            mov39/23 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            dup22/42/40 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<]
+            mov42/22 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            dup23/42/41 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<]
+            mov42/23 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            mov38/30 <<<<[-<<<<<<<<+>>>>>>>>]
+            mov41/30 >>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<<[
+                -
+                >>>>>>-
+                <<<<<<<<<<<<<<+
+            >>>>>>>>]
+            >>>[
+                -
+                >>>>>>+
+                <<<[
+                    -
+                    <<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>[-]
+                    mov43/44 <<<[->+<]
+                ]
+                mov44/43 >[-<+>]
+                >>[
+                    -
+                    <<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    <<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>]
+            <<<<<<]
+            >>>[-]
+            store i16 %28_ i16* %5_ align 2
+            
+            dup29/39/37 <<<<<<<<<<<<<<[->>>>>>>>>>+<<+<<<<<<<<]
+            mov39/29 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            dup30/39/38 <<<<<<<<<[->>>>>>>>>+<+<<<<<<<<]
+            mov39/30 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            mov37/12 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov38/13 >[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %29 = load i16* %5_ align 2
+            
+            <<<<<<<[-]
+            >[-]
+            dup12/39/37 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/12 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup13/39/38 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/13 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/31 <<[-<<<<<<+>>>>>>]
+            mov38/32 >[-<<<<<<+>>>>>>]
+            %30 = trunc i16 %29 to i8
+            
+            <<<<<<<<<<<<<<[-]
+            dup31/39/37 >>>>>>>[->>>>>>>>+<<+<<<<<<]
+            mov39/31 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            dup32/39/38 <<<<<<<[->>>>>>>+<+<<<<<<]
+            mov39/32 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov37/24 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >[-]
+            call @putchar(i8 %30)
+            
+            putchar intrinsic
+            dup24/37/38 <<<<<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >.
+            [-]
+            %34 = sub i16 %29_ i16 500
+            
+            <<<<<[-]
+            >[-]
+            dup31/39/37 <<<[->>>>>>>>+<<+<<<<<<]
+            mov39/31 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            dup32/39/38 <<<<<<<[->>>>>>>+<+<<<<<<]
+            mov39/32 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >>>+++++++++++
+            [
+                -
+                <<++++++++++++++++++++++
+            >>]
+            <<++
+            >+
+            mov37/33 <<<<[-<<<<+>>>>]
+            mov38/34 >[-<<<<+>>>>]
+            >>>[
+                -
+                <<<<<<<-
+            >>>>>>>]
+            <[
+                -
+                >>>>+
+                <<<<<<<<<<<[
+                    -
+                    >>>>>>>>>>>[-]
+                    mov33/43 <<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                ]
+                mov43/33 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >-
+                >>>>>>>>>>]
+            <<<<]
+            store i16 %34_ i16* %6_ align 2
+            
+            dup33/39/37 <<<<<<<[->>>>>>+<<+<<<<]
+            mov39/33 >>>>>>[-<<<<<<+>>>>>>]
+            dup34/39/38 <<<<<[->>>>>+<+<<<<]
+            mov39/34 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            mov37/14 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            mov38/15 >[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %35 = load i16* %6_ align 2
+            
+            <<<[-]
+            >[-]
+            dup14/39/37 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+<<+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/14 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup15/39/38 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/15 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov37/35 <<[-<<+>>]
+            mov38/36 >[-<<+>>]
+            %36 = trunc i16 %35 to i8
+            
+            <<<<<<<<<<<<<<[-]
+            dup35/39/37 >>>>>>>>>>>[->>>>+<<+<<]
+            mov39/35 >>>>[-<<<<+>>>>]
+            dup36/39/38 <<<[->>>+<+<<]
+            mov39/36 >>>[-<<<+>>>]
+            mov37/24 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >[-]
+            call @putchar(i8 %36)
+            
+            putchar intrinsic
+            dup24/37/38 <<<<<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,154 @@
+; ModuleID = './tests/artifacts/o0/short_add.c/ir.bc'
+source_filename = "./tests/cases/short_add.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @main() #0 {
+  %1 = alloca i16, align 2
+  %2 = alloca i16, align 2
+  %3 = alloca i16, align 2
+  %4 = alloca i16, align 2
+  %5 = alloca i16, align 2
+  %6 = alloca i16, align 2
+  store i16 200, i16* %1, align 2
+  store i16 100, i16* %2, align 2
+  %7 = load i16, i16* %1, align 2
+  %8 = zext i16 %7 to i32
+  %9 = load i16, i16* %2, align 2
+  %10 = zext i16 %9 to i32
+  %11 = add nsw i32 %8, %10
+  %12 = trunc i32 %11 to i16
+  store i16 %12, i16* %3, align 2
+  %13 = load i16, i16* %3, align 2
+  %14 = trunc i16 %13 to i8
+  call void @putchar(i8 noundef zeroext %14)
+  %15 = load i16, i16* %3, align 2
+  %16 = zext i16 %15 to i32
+  %17 = load i16, i16* %1, align 2
+  %18 = zext i16 %17 to i32
+  %19 = sub nsw i32 %16, %18
+  %20 = trunc i32 %19 to i16
+  store i16 %20, i16* %4, align 2
+  %21 = load i16, i16* %4, align 2
+  %22 = trunc i16 %21 to i8
+  call void @putchar(i8 noundef zeroext %22)
+  %23 = load i16, i16* %3, align 2
+  %24 = zext i16 %23 to i32
+  %25 = load i16, i16* %3, align 2
+  %26 = zext i16 %25 to i32
+  %27 = add nsw i32 %24, %26
+  %28 = trunc i32 %27 to i16
+  store i16 %28, i16* %5, align 2
+  %29 = load i16, i16* %5, align 2
+  %30 = trunc i16 %29 to i8
+  call void @putchar(i8 noundef zeroext %30)
+  %31 = load i16, i16* %5, align 2
+  %32 = zext i16 %31 to i32
+  %33 = sub nsw i32 %32, 500
+  %34 = trunc i32 %33 to i16
+  store i16 %34, i16* %6, align 2
+  %35 = load i16, i16* %6, align 2
+  %36 = trunc i16 %35 to i8
+  call void @putchar(i8 noundef zeroext %36)
+  ret i32 0
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
@@ -0,0 +1,611 @@
+eof: 0
+blocks made up by bfcc:
+  down/%call_term_for_0 from %10 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  down calls down (depth unbounded_ recursive)
+  main calls down (depth unbounded)
+  max depth from main: unbounded
+cost (loops go round 8 times):
+  down: 4704 ops ~87689 steps
+  main: 899 ops ~15018 steps
+frame widths:
+  down: 30 cells
+  main: 9 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+stack guard wall:
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
+>++
+>+++
+>++++
+>+++++
+>++++++
+>+++++++
+>++++++++
+>+++++++++
+>++++++++++
+>+++++++++++
+>++++++++++++
+>+++++++++++++
+>++++++++++++++
+>+++++++++++++++
+>++++++++++++++++
+>+++++++++++++++++
+>++++++++++++++++++
+>+++++++++++++++++++
+>++++++++++++++++++++
+>+++++++++++++++++++++
+>++++++++++++++++++++++
+>+++++++++++++++++++++++
+>++++++++++++++++++++++++
+>+++++++++++++++++++++++++
+>++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++
+>++++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++++
+>++++++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++++++
+>++++++++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++++++++
+>++++++++++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++++++++++
+>++++++++++++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++++++++++++
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+    >#down
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/down
+            >>>-
+            l30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_down
+        #F:down
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%10
+        #B:%call_term_for_0
+        #B:%15
+        #alloc_%2
+        #%3_=_load_i8*_%2__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%5_=_add_i32_97__i32_%4(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/14 <<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            store i8 %0_ i8* %2_ align 1
+            
+            dup12/13/14 >>>>>>>>>>>>[->+>+<<]
+            mov13/12 >[-<+>]
+            <<<<<[-]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            %3 = load i8* %2_ align 1
+            
+            <<<<<[-]
+            dup8/9/13 <[->+>>>>+<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            %4 = zext i8 %3 to i32
+            
+            <<<[-]
+            dup9/13/15 <[->>>>+>>+<<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov15/10 >>[-<<<<<+>>>>>]
+            %5 = add i32 97_ i32 %4
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            dup10/16/15 <<<[->>>>>>+<+<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            mov13/11 <<<[-<<+>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup11/18/20 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov20/11 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %6 = trunc i32 %5 to i8
+            
+            <<<<<<<[-]
+            dup11/13/14 >[->>+>+<<<]
+            mov13/11 >>[-<<+>>]
+            mov14/10 >[-<<<<+>>>>]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup10/13/14 <<<<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            >.
+            [-]
+            %8 = zext i8 %3 to i32
+            
+            <<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov14/10 >[-<<<<+>>>>]
+            %9 = icmp slt i32 %8_ i32 20
+            
+            <<<<<[-]
+            dup10/13/14 >[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_20
+            >++++
+            [
+                -
+                <+++++
+            >]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/21 <<<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<<<[
+                -
+                <+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<[
+                >>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<[
+                    -
+                    >>>>-
+                <<<<]
+                >>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/24 <<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<<<<[
+                -
+                <+
+                >>>>>[
+                    -
+                    <<<<<-
+                    r1 >
+                >>>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<[
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<[
+                    -
+                    >>>>>-
+                <<<<<]
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<]
+            >>>>>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/27 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<[
+                -
+                
+                >>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<]
+            >>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            br i1 %9_ label %10_ label %15
+            
+            dup9/14/13 <<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<+
+            >>>>>>>>>[
+                -
+                <<<<<<<<<-
+                >+
+                #B:down/%10_true
+            >>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>>+
+                #B:down/%15_false
+            <<<]
+        <]
+        >>#B:%10
+        [
+            -
+            %11 = load i8* %2_ align 1
+            
+            >>>>[-]
+            dup8/9/15 <[->+>>>>>>+<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %12 = zext i8 %11 to i32
+            
+            <<<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov14/10 >[-<<<<+>>>>]
+            %13 = add i32 %12_ i32 1
+            
+            <<<<<[-]
+            dup10/13/14 >[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov14/9 <[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/17/19 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov19/9 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %14 = trunc i32 %13 to i8
+            
+            <<<<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            mov14/10 >[-<<<<+>>>>]
+            call @down(i8 %14)
+            
+            enable next block when we return
+            <<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 30
+            ret_pad_width 4
+            stack guard
+            >>>>>>>+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                [
+                    <[-]
+                    mov67/66 >[-<+>]
+                    l1 <
+                    -
+                ]
+                r67 
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                .
+                +
+                .
+                -------------------
+                .
+                ++
+                .
+                ++++++++
+                .
+                ++++
+                .
+                +++++++
+                .
+                -----------------
+                .
+                +++++++++++++
+                .
+                ------------
+                .
+                ++++++
+                .
+                +++
+                .
+                ++++++++
+                .
+                [-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                copy up arg 0
+                #arg_0
+                dup10/14/15 <<<[->>>>+>+<<<<<]
+                mov14/10 >>>>[-<<<<+>>>>]
+                mov15/35 >[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
+                give callee a stack pointer
+                >>>>>>>>>>>>>>>>>>>>>#stack_ptr
+                +++++++++++++++++++++++++++++++++++++
+                l1 <
+                dup0/37/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+                mov38/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                r1 
+                r30 
+                <<<<<<<#JUMP_PAD
+                +
+                >#caller
+                +
+                >>>#caller/jump_pad_blk
+                +
+                r7 >>>
+                #===FRAME_down
+                +
+                >#down
+                +
+                >>#down/b0
+                +
+            >>>>>>>>>>]
+        <<<<<<<<]
+        >#B:%call_term_for_0
+        [
+            -
+            call @putchar(i8 46)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>#constop_46
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %15
+            
+            <<<<<<+
+        <]
+        >#B:%15
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l9 <<<<<<<<<
+        ]
+        #mainloop_main
+        #F:down
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            call @down(i8 0)
+            
+            enable next block when we return
+            >#caller/%call_term_for_0
+            +
+            stack_width 9
+            ret_pad_width 4
+            stack guard
+            >+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                [
+                    <[-]
+                    mov46/45 >[-<+>]
+                    l1 <
+                    -
+                ]
+                r46 
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                .
+                +
+                .
+                -------------------
+                .
+                ++
+                .
+                ++++++++
+                .
+                ++++
+                .
+                +++++++
+                .
+                -----------------
+                .
+                +++++++++++++
+                .
+                ------------
+                .
+                ++++++
+                .
+                +++
+                .
+                ++++++++
+                .
+                [-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                copy up arg 0
+                >#arg_0
+                op_to_reg storing const value in temp address
+                #constop_0
+                
+                mov8/14 [->>>>>>+<<<<<<]
+                give callee a stack pointer
+                >>>>>>>#stack_ptr
+                ++++++++++++++++
+                l1 <
+                dup0/16/17 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+                mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                r1 
+                r9 
+                <<<<<<<#JUMP_PAD
+                +
+                >>#caller
+                +
+                >>#caller/jump_pad_blk
+                +
+                r7 >>>
+                #===FRAME_down
+                +
+                >#down
+                +
+                >>#down/b0
+                +
+            >>>>]
+        <<]
+        >#B:%call_term_for_0
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            <<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >]
+    <<<<]
+<<]
+clear stack guard wall:
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
@@ -0,0 +1,752 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  twice (depth 1)
+  main calls twice (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  twice: 3353 ops ~55482 steps
+  main: 2952 ops ~38218 steps
+frame widths:
+  twice: 29 cells
+  main: 26 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#twice
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/twice
+            >>>-
+            l29 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_twice
+        #F:twice
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #alloc_%3
+        #alloc_%4
+        #%5_=_load_i8*_%4__align_1(mult)
+        #%6_=_load_i8**_%3__align_8(mult)
+        #%11_=_zext_i8_%10_to_i32(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/12 <<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/14 <[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            r3 >>>
+            %3 = alloca i8*_ align 8
+            %4 = alloca i8_ align 1
+            store i8* %0_ i8** %3_ align 8
+            
+            dup10/12/13 >>>>>>>>>>[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            <<<<<<<[-]
+            mov13/5 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            store i8 %1_ i8* %4_ align 1
+            
+            dup11/12/14 <<[->+>>+<<<]
+            mov12/11 >[-<+>]
+            <<<<<<[-]
+            mov14/6 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %5 = load i8* %4_ align 1
+            
+            <<<<<<<[-]
+            dup6/7/12 <[->+>>>>>+<<<<<<]
+            mov12/6 >>>>>>[-<<<<<<+>>>>>>]
+            %6 = load i8** %3_ align 8
+            
+            <<<<[-]
+            dup5/8/12 <<<[->>>+>>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            store i8 %5_ i8* %6_ align 1
+            
+            dup7/12/13 <<<<<[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            dup8/15/14 <<<<[->>>>>>>+<+<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/21/22 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/23 [->>+<<]
+            >>>>+
+            <<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<]
+            >>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov23/18 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov13/19 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov17/16 <[-<+>]
+                mov18/17 >[-<+>]
+                mov19/18 >[-<+>]
+                mov15/19 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov19/15 >>>>[-<<<<+>>>>]
+            <<[
+                mov20/16 >>>[-<<<<+>>>>]
+                
+                mov17/18 <<<[->+<]
+                r1 >
+                -
+            ]
+            %7 = load i8** %3_ align 8
+            
+            <<<<<<<<<<[-]
+            dup5/7/12 <<[->>+>>>>>+<<<<<<<]
+            mov12/5 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %8 = load i8* %7_ align 1
+            
+            <<<<[-]
+            dup7/12/13 <[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/20 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov20/16 [-<<<<+>>>>]
+                <<<<++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov15/14 <[-<+>]
+                mov16/15 >[-<+>]
+                
+                mov13/16 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup13/14/16 <<<[->+>>+<<<]
+            mov14/13 >[-<+>]
+            >[
+                mov17/14 >>[-<<<+>>>]
+                mov16/17 <[->+<]
+                mov15/16 <[->+<]
+                r1 >
+                -
+            ]
+            mov16/8 >[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup8/12/13 <<<<<<<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            %10 = load i8* %7_ align 1
+            
+            <<<<<[-]
+            dup7/12/13 <[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/20 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov20/16 [-<<<<+>>>>]
+                <<<<++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov15/14 <[-<+>]
+                mov16/15 >[-<+>]
+                
+                mov13/16 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup13/14/16 <<<[->+>>+<<<]
+            mov14/13 >[-<+>]
+            >[
+                mov17/14 >>[-<<<+>>>]
+                mov16/17 <[->+<]
+                mov15/16 <[->+<]
+                r1 >
+                -
+            ]
+            mov16/8 >[-<<<<<<<<+>>>>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<<<<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            %12 = add i32 %11_ i32 1
+            
+            <<<<<[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov13/8 <[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/16/18 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov18/8 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %13 = trunc i32 %12 to i8
+            
+            <<<<<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            mov13/9 >[-<<<<+>>>>]
+            store i8 %13_ i8* %7_ align 1
+            
+            dup9/12/13 <<<<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            dup7/14/15 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov14/7 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/21/22 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/26 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov26/18 [-<<<<<<<<+>>>>>>>>]
+                <<<<<<<<++++++++++++++++
+            >>>>>>>>]
+            <<<<[
+                if neg
+                <<<<++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov13/19 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov17/16 <[-<+>]
+                mov18/17 >[-<+>]
+                mov19/18 >[-<+>]
+                mov15/19 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov19/15 >>>>[-<<<<+>>>>]
+            <<[
+                mov20/16 >>>[-<<<<+>>>>]
+                
+                mov17/18 <<<[->+<]
+                r1 >
+                -
+            ]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l8 <<<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:twice
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_i8**_%2__align_8(mult)
+        #%5_=_load_i8**_%2__align_8(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8*_ align 8
+            store i8* %1_ i8** %2_ align 8
+            
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/13/14 <<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov14/0 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            <<<[-]
+            mov12/9 >>>[-<<<+>>>]
+            %3 = load i8** %2_ align 8
+            
+            <<[-]
+            dup9/10/12 <[->+>>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            store i8 65_ i8* %3_ align 1
+            
+            op_to_reg storing const value in temp address
+            #constop_65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            dup10/13/15 <<[->>>+>>+<<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/21/22 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/23 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov23/18 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov12/19 <<<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov17/16 <[-<+>]
+                mov18/17 >[-<+>]
+                mov19/18 >[-<+>]
+                mov15/19 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov19/15 >>>>[-<<<<+>>>>]
+            <<[
+                mov20/16 >>>[-<<<<+>>>>]
+                
+                mov17/18 <<<[->+<]
+                r1 >
+                -
+            ]
+            %4 = load i8* %1_ align 1
+            
+            <<<<<<<[-]
+            dup8/10/12 <<[->>+>>+<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup10/12/13 <<[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            >.
+            [-]
+            %5 = load i8** %2_ align 8
+            
+            <<[-]
+            dup9/11/12 <<[->>+>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            call @twice(i8* %5_ i8 %4)
+            
+            enable next block when we return
+            <<<<<<#caller/%call_term_for_0
+            +
+            stack_width 26
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            dup11/12/13 [->+>+<<]
+            mov12/11 >[-<+>]
+            mov13/32 >[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
+            copy up arg 1
+            #arg_1
+            dup10/14/15 <<<[->>>>+>+<<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
+            mov15/31 >[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/34/35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov35/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r26 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_twice
+            +
+            >#twice
+            +
+            >>#twice/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %7 = load i8* %1_ align 1
+            
+            >>>>[-]
+            dup8/10/12 <<[->>+>>+<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup10/12/13 <<[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            >.
+            [-]
+            %9 = zext i8 %7 to i32
+            
+            <<[-]
+            dup10/12/13 <[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            mov13/11 >[-<<+>>]
+            %10 = add i32 %9_ i32 1
+            
+            <<<[-]
+            dup11/12/13 >[->+>+<<]
+            mov12/11 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov13/10 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/16/18 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov18/10 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %11 = trunc i32 %10 to i8
+            
+            <<<<[-]
+            dup10/12/13 <[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            mov13/11 >[-<<+>>]
+            call @twice(i8* %1_ i8 %11)
+            
+            enable next block when we return
+            <<<<<<#caller/%call_term_for_1
+            +
+            stack_width 26
+            ret_pad_width 4
+            copy up arg 0
+            #arg_0
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/13/14 <<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov14/0 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            mov12/32 [->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
+            copy up arg 1
+            #arg_1
+            dup11/14/15 <[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov15/31 >[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/34/35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov35/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r26 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_twice
+            +
+            >#twice
+            +
+            >>#twice/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
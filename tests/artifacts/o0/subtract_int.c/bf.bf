@@ -0,0 +1,163 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 488 ops ~2224 steps
+frame widths:
+  main: 12 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l12 <<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_i32*_%1__align_4(mult)
+        #%4_=_sub_i32_%3__i32_5(mult)
+        #%6_=_trunc_i32_%5_to_i8(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i32_ align 4
+            store i32 110_ i32* %1_ align 4
+            
+            >>[-]
+            >>>>>++++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %3 = load i32* %1_ align 4
+            
+            <<<[-]
+            known to be 110
+            >>>>++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %4 = sub i32 %3_ i32 5
+            
+            <<<[-]
+            known to be 105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            store i32 %4_ i32* %2_ align 4
+            
+            <<<<[-]
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++++++++++
+            >>>>]
+            %5 = load i32* %2_ align 4
+            
+            <<[-]
+            known to be 105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            %6 = trunc i32 %5 to i8
+            
+            <[-]
+            known to be 105
+            >+++++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            <<.
+            [-]
+            %9 = sub i32 %3_ i32 %5
+            
+            <[-]
+            known to be 5
+            +++++
+            %10 = add i32 %9_ i32 100
+            
+            <<[-]
+            known to be 105
+            >>>+++++++
+            [
+                -
+                <<<+++++++++++++++
+            >>>]
+            %11 = trunc i32 %10 to i8
+            
+            <<[-]
+            known to be 105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_105
+            >+++++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,635 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 7252 ops ~162645 steps
+frame widths:
+  main: 30 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%9
+        #B:%15
+        #B:%36
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #alloc_%6
+        #alloc_%7
+        #alloc_%8
+        #%10_=_load_i8*_%4__align_1(mult)
+        #%11_=_zext_i8_%10_to_i32(mult)
+        #%13_=_zext_i8_%12_to_i32(mult)
+        #%19_=_zext_i8_%18_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            %6 = alloca i8_ align 1
+            %7 = alloca i8_ align 1
+            %8 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>[-]
+            
+            store volatile i8 4_ i8* %2_ align 1
+            
+            >[-]
+            ++++
+            store volatile i8 1_ i8* %3_ align 1
+            
+            >[-]
+            +
+            store i8 0_ i8* %4_ align 1
+            
+            >[-]
+            
+            store i8 0_ i8* %5_ align 1
+            
+            >[-]
+            
+            store i8 0_ i8* %6_ align 1
+            
+            >[-]
+            
+            store i8 1_ i8* %7_ align 1
+            
+            >[-]
+            +
+            br label %9
+            
+            <<<<<<<<<+
+        <<]
+        >>#B:%9
+        [
+            -
+            %10 = load i8* %4_ align 1
+            
+            >>>>>>>>>>>[-]
+            dup10/15/19 <<<<<[->>>>>+>>>>+<<<<<<<<<]
+            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %11 = zext i8 %10 to i32
+            
+            <<<[-]
+            dup15/20/19 <[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov19/16 <[-<<<+>>>]
+            %12 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup8/15/19 <<<<<<<[->>>>>>>+>>>>+<<<<<<<<<<<]
+            mov19/8 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %13 = zext i8 %12 to i32
+            
+            <<[-]
+            dup15/19/21 <<[->>>>+>>+<<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov21/17 >>[-<<<<+>>>>]
+            %14 = icmp ne i32 %11_ i32 %13
+            
+            <<<<<<[-]
+            dup16/19/20 >[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            dup17/22/21 <<[->>>>>+<+<<<<]
+            mov22/17 >>>>>[-<<<<<+>>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/24 <<[->>>>+<<<<]
+            >>>>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<+
+            >>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<+
+            >>>>>>>>]
+            br i1 %14_ label %15_ label %36
+            
+            dup15/20/19 <<<<<<<<[->>>>>+<+<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%15_true
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%36_false
+            <<<]
+        >]
+        >#B:%15
+        [
+            -
+            %16 = load i8* %4_ align 1
+            
+            >>>>>>>>>>[-]
+            dup10/15/21 <<<<<[->>>>>+>>>>>>+<<<<<<<<<<<]
+            mov21/10 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %17 = zext i8 %16 to i32
+            
+            <<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %18 = load volatile i8* %3_ align 1
+            
+            <<<[-]
+            dup9/17/19 <<<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            mov19/9 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %19 = zext i8 %18 to i32
+            
+            <[-]
+            dup17/19/20 <[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/18 >[-<<+>>]
+            %20 = icmp ne i32 %17_ i32 %19
+            
+            <<<[-]
+            dup16/19/20 <[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            dup18/21/22 <[->>>+>+<<<<]
+            mov21/18 >>>[-<<<+>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/27 <[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<<<<<[
+                -
+                >+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<]
+            >>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<+
+            >>>>>>>>>>]
+            <<<<[
+                [-]
+                <<<<<<+
+            >>>>>>]
+            %21 = zext i1 %20 to i32
+            
+            <<<<<<<[-]
+            dup17/19/20 >[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/16 >[-<<<<+>>>>]
+            %22 = load i8* %5_ align 1
+            
+            <<<[-]
+            dup11/17/19 <<<<<<[->>>>>>+>>+<<<<<<<<]
+            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %23 = zext i8 %22 to i32
+            
+            <[-]
+            dup17/19/20 <[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/18 >[-<<+>>]
+            %24 = add i32 %23_ i32 %21
+            
+            <<<[-]
+            dup18/19/20 >[->+>+<<]
+            mov19/18 >[-<+>]
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            mov20/17 <[-<<<+>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/24/26 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov26/17 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %25 = trunc i32 %24 to i8
+            
+            <<<<<<<[-]
+            dup17/19/20 >[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/16 >[-<<<<+>>>>]
+            store i8 %25_ i8* %5_ align 1
+            
+            dup16/19/20 <<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<<<[-]
+            mov20/11 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %26 = load i8* %6_ align 1
+            
+            <<<<[-]
+            dup12/16/19 <<<<[->>>>+>>>+<<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            store i8 %26_ i8* %8_ align 1
+            
+            dup16/19/20 <<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<[-]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            %27 = load i8* %7_ align 1
+            
+            <<<<[-]
+            dup13/16/19 <<<[->>>+>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            store i8 %27_ i8* %6_ align 1
+            
+            dup16/19/20 <<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<<[-]
+            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %28 = load i8* %8_ align 1
+            
+            <<<[-]
+            dup14/17/19 <<<[->>>+>>+<<<<<]
+            mov19/14 >>>>>[-<<<<<+>>>>>]
+            %29 = zext i8 %28 to i32
+            
+            <[-]
+            dup17/19/20 <[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            mov20/18 >[-<<+>>]
+            %31 = zext i8 %27 to i32
+            
+            <<<[-]
+            dup16/19/20 <[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            mov20/17 >[-<<<+>>>]
+            %32 = add i32 %29_ i32 %31
+            
+            <<<<[-]
+            dup18/19/20 >>[->+>+<<]
+            mov19/18 >[-<+>]
+            dup17/21/22 <<[->>>>+>+<<<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            mov20/16 <[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/24/26 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov26/16 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %33 = trunc i32 %32 to i8
+            
+            <<<<<<[-]
+            dup16/19/20 <[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            mov20/17 >[-<<<+>>>]
+            store i8 %33_ i8* %7_ align 1
+            
+            dup17/19/20 <<<[->>+>+<<<]
+            mov19/17 >>[-<<+>>]
+            <<<<<<[-]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %35 = add i8 %16_ i8 1
+            
+            <<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov20/16 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/23/25 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov25/16 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov22/23 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov23/22 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %35_ i8* %4_ align 1
+            
+            dup16/19/20 <<<<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<<<<[-]
+            mov20/10 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            br label %9
+            
+            <<<<<<<<<<<<<<<<+
+        >]
+        >#B:%36
+        [
+            -
+            %37 = load i8* %4_ align 1
+            
+            >>>>>>>>>[-]
+            dup10/15/19 <<<<<[->>>>>+>>>>+<<<<<<<<<]
+            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %38 = zext i8 %37 to i32
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %39 = add i32 48_ i32 %38
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup16/21/22 <<<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            mov19/15 <<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/24/26 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov26/15 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %40 = trunc i32 %39 to i8
+            
+            <<<<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            call @putchar(i8 %40)
+            
+            putchar intrinsic
+            dup16/19/20 <<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            >.
+            [-]
+            %41 = load i8* %5_ align 1
+            
+            <<<<<[-]
+            dup11/15/19 <<<<[->>>>+>>>>+<<<<<<<<]
+            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %42 = zext i8 %41 to i32
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %43 = add i32 48_ i32 %42
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup16/21/22 <<<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            mov19/15 <<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/24/26 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov26/15 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %44 = trunc i32 %43 to i8
+            
+            <<<<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            call @putchar(i8 %44)
+            
+            putchar intrinsic
+            dup16/19/20 <<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            >.
+            [-]
+            %45 = load i8* %6_ align 1
+            
+            <<<<<[-]
+            dup12/15/19 <<<[->>>+>>>>+<<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %46 = zext i8 %45 to i32
+            
+            <<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            %47 = add i32 65_ i32 %46
+            
+            <<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup16/21/22 <<<[->>>>>+>+<<<<<<]
+            mov21/16 >>>>>[-<<<<<+>>>>>]
+            mov19/15 <<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/24/26 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov26/15 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %48 = trunc i32 %47 to i8
+            
+            <<<<<<<[-]
+            dup15/19/20 <[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov20/16 >[-<<<<+>>>>]
+            call @putchar(i8 %48)
+            
+            putchar intrinsic
+            dup16/19/20 <<<<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            >.
+            [-]
+            %49 = load i32* %1_ align 4
+            
+            <<<<<[-]
+            dup7/15/19 <<<<<<<<[->>>>>>>>+>>>>+<<<<<<<<<<<<]
+            mov19/7 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            ret i32 %49
+            
+            zero all function allocs
+            <<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
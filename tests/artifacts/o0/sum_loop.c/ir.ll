@@ -0,0 +1,179 @@
+; ModuleID = './tests/artifacts/o0/sum_loop.c/ir.bc'
+source_filename = "./tests/cases/sum_loop.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @main() #0 {
+  %1 = alloca i32, align 4
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  %8 = alloca i8, align 1
+  store i32 0, i32* %1, align 4
+  store volatile i8 4, i8* %2, align 1
+  store volatile i8 1, i8* %3, align 1
+  store i8 0, i8* %4, align 1
+  store i8 0, i8* %5, align 1
+  store i8 0, i8* %6, align 1
+  store i8 1, i8* %7, align 1
+  br label %9
+
+9:                                                ; preds = %15, %0
+  %10 = load i8, i8* %4, align 1
+  %11 = zext i8 %10 to i32
+  %12 = load volatile i8, i8* %2, align 1
+  %13 = zext i8 %12 to i32
+  %14 = icmp ne i32 %11, %13
+  br i1 %14, label %15, label %36
+
+15:                                               ; preds = %9
+  %16 = load i8, i8* %4, align 1
+  %17 = zext i8 %16 to i32
+  %18 = load volatile i8, i8* %3, align 1
+  %19 = zext i8 %18 to i32
+  %20 = icmp ne i32 %17, %19
+  %21 = zext i1 %20 to i32
+  %22 = load i8, i8* %5, align 1
+  %23 = zext i8 %22 to i32
+  %24 = add nsw i32 %23, %21
+  %25 = trunc i32 %24 to i8
+  store i8 %25, i8* %5, align 1
+  %26 = load i8, i8* %6, align 1
+  store i8 %26, i8* %8, align 1
+  %27 = load i8, i8* %7, align 1
+  store i8 %27, i8* %6, align 1
+  %28 = load i8, i8* %8, align 1
+  %29 = zext i8 %28 to i32
+  %30 = load i8, i8* %7, align 1
+  %31 = zext i8 %30 to i32
+  %32 = add nsw i32 %29, %31
+  %33 = trunc i32 %32 to i8
+  store i8 %33, i8* %7, align 1
+  %34 = load i8, i8* %4, align 1
+  %35 = add i8 %34, 1
+  store i8 %35, i8* %4, align 1
+  br label %9, !llvm.loop !10
+
+36:                                               ; preds = %9
+  %37 = load i8, i8* %4, align 1
+  %38 = zext i8 %37 to i32
+  %39 = add nsw i32 48, %38
+  %40 = trunc i32 %39 to i8
+  call void @putchar(i8 noundef zeroext %40)
+  %41 = load i8, i8* %5, align 1
+  %42 = zext i8 %41 to i32
+  %43 = add nsw i32 48, %42
+  %44 = trunc i32 %43 to i8
+  call void @putchar(i8 noundef zeroext %44)
+  %45 = load i8, i8* %6, align 1
+  %46 = zext i8 %45 to i32
+  %47 = add nsw i32 65, %46
+  %48 = trunc i32 %47 to i8
+  call void @putchar(i8 noundef zeroext %48)
+  %49 = load i32, i32* %1, align 4
+  ret i32 %49
+}
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
+!10 = distinct !{!10, !7}
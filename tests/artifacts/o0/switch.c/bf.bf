@@ -0,0 +1,665 @@
+eof: 0
+blocks made up by bfcc:
+  say/%1_sw_1 from %1 (switch case)
+  say/%1_sw_2 from %1 (switch case)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%call_term_for_3 from %0 after call 3 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  say (depth 1)
+  main calls say (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  say: 1574 ops ~9750 steps
+  main: 1696 ops ~6582 steps
+frame widths:
+  say: 27 cells
+  main: 15 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#say
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/say
+            >>>-
+            l27 <<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_say
+        #F:say
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%1_sw_1
+        #B:%1_sw_2
+        #B:%5
+        #B:%6
+        #B:%7
+        #B:%8
+        #B:%9
+        #alloc_%2
+        #%3_=_load_i8*_%2__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/17 <<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            store i8 %0_ i8* %2_ align 1
+            
+            dup15/16/17 >>>>>>>>>>>>>>>[->+>+<<]
+            mov16/15 >[-<+>]
+            <<<<[-]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            %3 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %4 = zext i8 %3 to i32
+            
+            <<[-]
+            dup13/16/18 <[->>>+>>+<<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov18/14 >>[-<<<<+>>>>]
+            %sw_1_0 = icmp eq i32 %4_ i32 97
+            
+            <<<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            ++++++++
+            [
+                -
+                >++++++++++++
+            <]
+            >+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/21 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            br i1 %sw_1_0_ label %5_ label %1_sw_1
+            
+            dup13/17/16 <<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>>+
+                #B:say/%5_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >+
+                #B:say/%1_sw_1_false
+            <]
+        <]
+        >>#B:%1_sw_1
+        [
+            -
+            %sw_1_1 = icmp eq i32 %4_ i32 98
+            
+            >>>>>>>>[-]
+            dup14/18/19 >[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_98
+            >+++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/24 <[->>>>+<<<<]
+            >>>>>>+
+            <<<<<<<[
+                -
+                >>>+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<]
+            >>>>>>>-
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<[-]
+            >>>>>>>>>>>]
+            <<[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            br i1 %sw_1_1_ label %6_ label %1_sw_2
+            
+            dup13/17/16 <<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>>>+
+                #B:say/%6_true
+            >>>>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >>+
+                #B:say/%1_sw_2_false
+            <<]
+        >]
+        >#B:%1_sw_2
+        [
+            -
+            %sw_1_2 = icmp eq i32 %4_ i32 99
+            
+            >>>>>>>[-]
+            dup14/18/19 >[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_99
+            >+++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/23 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<<+
+            >>>>>>>>>>[
+                [-]
+                <<<<<<<<<<[-]
+            >>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            br i1 %sw_1_2_ label %7_ label %8
+            
+            dup13/17/16 <<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>>>>+
+                #B:say/%7_true
+            >>>>>>>]
+            <<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:say/%8_false
+            <<<<<<]
+        >>]
+        >#B:%5
+        [
+            -
+            call @putchar(i8 65)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %9
+            
+            <<<<<<<+
+        <<<<]
+        >#B:%6
+        [
+            -
+            call @putchar(i8 66)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            br label %9
+            
+            <<<<<+
+        <<<]
+        >#B:%7
+        [
+            -
+            call @putchar(i8 67)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>#constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %9
+            
+            <<<<<+
+        <<]
+        >#B:%8
+        [
+            -
+            call @putchar(i8 63)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_63
+            >+++++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            br label %9
+            
+            <<<<<+
+        <]
+        >#B:%9
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >>>>>]
+    <<<<<<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:say
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #B:%call_term_for_3
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            store volatile i8 97_ i8* %1_ align 1
+            
+            >>>>>[-]
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            call @say(i8 %2)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_0
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_say
+            +
+            >#say
+            +
+            >>#say/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            store volatile i8 98_ i8* %1_ align 1
+            
+            >>>>[-]
+            >>+++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            %3 = load volatile i8* %1_ align 1
+            
+            <[-]
+            known to be 98
+            >+++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            call @say(i8 %3)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_1
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_98
+            >+++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            mov12/20 <[->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_say
+            +
+            >#say
+            +
+            >>#say/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            store volatile i8 99_ i8* %1_ align 1
+            
+            >>>[-]
+            >>+++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %4 = load volatile i8* %1_ align 1
+            
+            <[-]
+            known to be 99
+            >+++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            call @say(i8 %4)
+            
+            enable next block when we return
+            <<<<#caller/%call_term_for_2
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_99
+            >+++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            mov12/20 <[->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_say
+            +
+            >#say
+            +
+            >>#say/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            store volatile i8 122_ i8* %1_ align 1
+            
+            >>[-]
+            >>+++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            %5 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 122
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            call @say(i8 %5)
+            
+            enable next block when we return
+            <<#caller/%call_term_for_3
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_122
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_say
+            +
+            >#say
+            +
+            >>#say/b0
+            +
+        >>>>>]
+        >#B:%call_term_for_3
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>]
+    <<<<<<<]
+<<]
@@ -0,0 +1,192 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 496 ops ~1735 steps
+frame widths:
+  main: 12 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l12 <<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #%4_=_load_i8*_%1__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            store i8 5_ i8* %1_ align 1
+            
+            >>[-]
+            +++++
+            %4 = load i8* %1_ align 1
+            
+            >>>[-]
+            known to be 5
+            +++++
+            %5 = zext i8 %4 to i32
+            
+            >[-]
+            known to be 5
+            +++++
+            %6 = add i32 %5_ i32 1
+            
+            <[-]
+            known to be 6
+            ++++++
+            %7 = trunc i32 %6 to i8
+            
+            >[-]
+            known to be 6
+            ++++++
+            store i8 %7_ i8* %2_ align 1
+            
+            <<<[-]
+            ++++++
+            %8 = load i8* %2_ align 1
+            
+            >>[-]
+            known to be 6
+            ++++++
+            %9 = zext i8 %8 to i32
+            
+            >[-]
+            known to be 6
+            ++++++
+            %10 = mul i32 %9_ i32 2
+            
+            <[-]
+            known to be 12
+            ++++++++++++
+            %11 = trunc i32 %10 to i8
+            
+            >[-]
+            known to be 12
+            ++++++++++++
+            store i8 %11_ i8* %2_ align 1
+            
+            <<<[-]
+            ++++++++++++
+            store i8 111_ i8* %3_ align 1
+            
+            >[-]
+            >>>++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %12 = load i8* %3_ align 1
+            
+            >[-]
+            known to be 111
+            >>>++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>#constop_111
+            >>++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            %14 = zext i8 %12 to i32
+            
+            <[-]
+            known to be 111
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            %15 = sub i32 %14_ i32 4
+            
+            <[-]
+            known to be 107
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            <<++
+            %16 = trunc i32 %15 to i8
+            
+            >[-]
+            known to be 107
+            >+++++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <++
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >#constop_107
+            >+++++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <++
+            .
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,192 @@
+; ModuleID = './tests/artifacts/o0/vla.c/ir.bc'
+source_filename = "./tests/cases/vla.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putstr(i8* noundef %0) #0 {
+  %2 = alloca i8*, align 8
+  store i8* %0, i8** %2, align 8
+  br label %3
+
+3:                                                ; preds = %7, %1
+  %4 = load i8*, i8** %2, align 8
+  %5 = load i8, i8* %4, align 1
+  %6 = icmp ne i8 %5, 0
+  br i1 %6, label %7, label %12
+
+7:                                                ; preds = %3
+  %8 = load i8*, i8** %2, align 8
+  %9 = load i8, i8* %8, align 1
+  call void @putchar(i8 noundef zeroext %9)
+  %10 = load i8*, i8** %2, align 8
+  %11 = getelementptr inbounds i8, i8* %10, i32 1
+  store i8* %11, i8** %2, align 8
+  br label %3, !llvm.loop !6
+
+12:                                               ; preds = %3
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) #1
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local void @putdec(i32 noundef %0) #0 {
+  %2 = alloca i32, align 4
+  %3 = alloca i32, align 4
+  store i32 %0, i32* %2, align 4
+  %4 = load i32, i32* %2, align 4
+  %5 = icmp ne i32 %4, 0
+  br i1 %5, label %7, label %6
+
+6:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48)
+  br label %29
+
+7:                                                ; preds = %1
+  store i32 1, i32* %3, align 4
+  br label %8
+
+8:                                                ; preds = %13, %7
+  %9 = load i32, i32* %2, align 4
+  %10 = load i32, i32* %3, align 4
+  %11 = udiv i32 %9, %10
+  %12 = icmp uge i32 %11, 10
+  br i1 %12, label %13, label %16
+
+13:                                               ; preds = %8
+  %14 = load i32, i32* %3, align 4
+  %15 = mul i32 %14, 10
+  store i32 %15, i32* %3, align 4
+  br label %8, !llvm.loop !8
+
+16:                                               ; preds = %8
+  br label %17
+
+17:                                               ; preds = %20, %16
+  %18 = load i32, i32* %3, align 4
+  %19 = icmp ne i32 %18, 0
+  br i1 %19, label %20, label %29
+
+20:                                               ; preds = %17
+  %21 = load i32, i32* %2, align 4
+  %22 = load i32, i32* %3, align 4
+  %23 = udiv i32 %21, %22
+  %24 = urem i32 %23, 10
+  %25 = add i32 48, %24
+  %26 = trunc i32 %25 to i8
+  call void @putchar(i8 noundef zeroext %26)
+  %27 = load i32, i32* %3, align 4
+  %28 = udiv i32 %27, 10
+  store i32 %28, i32* %3, align 4
+  br label %17, !llvm.loop !9
+
+29:                                               ; preds = %17, %6
+  ret void
+}
+
+; Function Attrs: noinline nounwind optnone uwtable
+define dso_local i32 @main() #0 {
+  %1 = alloca i32, align 4
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8*, align 8
+  %5 = alloca i64, align 8
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  store i32 0, i32* %1, align 4
+  store volatile i8 3, i8* %2, align 1
+  %8 = load volatile i8, i8* %2, align 1
+  store i8 %8, i8* %3, align 1
+  %9 = load i8, i8* %3, align 1
+  %10 = zext i8 %9 to i64
+  %11 = call i8* @llvm.stacksave()
+  store i8* %11, i8** %4, align 8
+  %12 = alloca i8, i64 %10, align 16
+  store i64 %10, i64* %5, align 8
+  store i8 0, i8* %6, align 1
+  br label %13
+
+13:                                               ; preds = %27, %0
+  %14 = load i8, i8* %6, align 1
+  %15 = zext i8 %14 to i32
+  %16 = load i8, i8* %3, align 1
+  %17 = zext i8 %16 to i32
+  %18 = icmp slt i32 %15, %17
+  br i1 %18, label %19, label %30
+
+19:                                               ; preds = %13
+  %20 = load i8, i8* %6, align 1
+  %21 = zext i8 %20 to i32
+  %22 = add nsw i32 97, %21
+  %23 = trunc i32 %22 to i8
+  %24 = load i8, i8* %6, align 1
+  %25 = zext i8 %24 to i64
+  %26 = getelementptr inbounds i8, i8* %12, i64 %25
+  store i8 %23, i8* %26, align 1
+  br label %27
+
+27:                                               ; preds = %19
+  %28 = load i8, i8* %6, align 1
+  %29 = add i8 %28, 1
+  store i8 %29, i8* %6, align 1
+  br label %13, !llvm.loop !10
+
+30:                                               ; preds = %13
+  store i8 0, i8* %7, align 1
+  br label %31
+
+31:                                               ; preds = %42, %30
+  %32 = load i8, i8* %7, align 1
+  %33 = zext i8 %32 to i32
+  %34 = load i8, i8* %3, align 1
+  %35 = zext i8 %34 to i32
+  %36 = icmp slt i32 %33, %35
+  br i1 %36, label %37, label %45
+
+37:                                               ; preds = %31
+  %38 = load i8, i8* %7, align 1
+  %39 = zext i8 %38 to i64
+  %40 = getelementptr inbounds i8, i8* %12, i64 %39
+  %41 = load i8, i8* %40, align 1
+  call void @putchar(i8 noundef zeroext %41)
+  br label %42
+
+42:                                               ; preds = %37
+  %43 = load i8, i8* %7, align 1
+  %44 = add i8 %43, 1
+  store i8 %44, i8* %7, align 1
+  br label %31, !llvm.loop !11
+
+45:                                               ; preds = %31
+  %46 = load i8*, i8** %4, align 8
+  call void @llvm.stackrestore(i8* %46)
+  %47 = load i32, i32* %1, align 4
+  ret i32 %47
+}
+
+; Function Attrs: nofree nosync nounwind willreturn
+declare i8* @llvm.stacksave() #2
+
+; Function Attrs: nofree nosync nounwind willreturn
+declare void @llvm.stackrestore(i8*) #2
+
+attributes #0 = { noinline nounwind optnone uwtable "frame-pointer"="all" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="all" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { nofree nosync nounwind willreturn }
+
+!llvm.module.flags = !{!0, !1, !2, !3, !4}
+!llvm.ident = !{!5}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{i32 7, !"frame-pointer", i32 2}
+!5 = !{!"Debian clang version 14.0.6"}
+!6 = distinct !{!6, !7}
+!7 = !{!"llvm.loop.mustprogress"}
+!8 = distinct !{!8, !7}
+!9 = distinct !{!9, !7}
+!10 = distinct !{!10, !7}
+!11 = distinct !{!11, !7}
@@ -0,0 +1,716 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 7488 ops ~88541 steps
+frame widths:
+  main: 35 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%7
+        #B:%16
+        #B:%20
+        #B:%24
+        #B:%28
+        #B:%29
+        #alloc_%1
+        #alloc_%2
+        #%4_=_load_i8*_%2__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        #%10_=_add_i32_48__i32_%9(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            %2 = alloca i8_ align 1
+            store i32 0_ i32* %1_ align 4
+            
+            >>>>>>>>>[-]
+            
+            store i8 0_ i8* %2_ align 1
+            
+            >[-]
+            
+            br label %3
+            
+            <<<<<<<<+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = load i8* %2_ align 1
+            
+            >>>>>>>>>[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %5 = zext i8 %4 to i32
+            
+            <<[-]
+            dup13/17/16 <[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            mov16/14 <[-<<+>>]
+            %6 = icmp slt i32 %5_ i32 10
+            
+            <<<[-]
+            dup14/16/18 >[->>+>>+<<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >#constop_10
+            ++++++++++
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/21 <<[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/26 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/29 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
+            br i1 %6_ label %7_ label %16
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >>+
+                #B:main/%7_true
+            >>>>>>>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%16_false
+            <<<]
+        >]
+        >#B:%7
+        [
+            -
+            %8 = load i8* %2_ align 1
+            
+            >>>>>>>>[-]
+            dup12/13/18 <[->+>>>>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            %9 = zext i8 %8 to i32
+            
+            <<<<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %10 = add i32 48_ i32 %9
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            dup14/18/19 <<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            mov16/15 <<[-<+>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/21/23 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov23/15 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %11 = trunc i32 %10 to i8
+            
+            <<<<<<[-]
+            dup15/16/17 >[->+>+<<]
+            mov16/15 >[-<+>]
+            mov17/14 >[-<<<+>>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            dup14/16/17 <<<[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            >.
+            [-]
+            %13 = zext i8 %8 to i32
+            
+            <<<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %14 = add i32 %13_ i32 1
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov17/13 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/20/22 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov22/13 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %15 = trunc i32 %14 to i8
+            
+            <<<<<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            store i8 %15_ i8* %2_ align 1
+            
+            dup14/16/17 <<<[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            <<<<[-]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            br label %3
+            
+            <<<<<<<<<<<<<+
+        >]
+        >#B:%16
+        [
+            -
+            %17 = load i8* %2_ align 1
+            
+            >>>>>>>[-]
+            dup12/13/16 <[->+>>>+<<<<]
+            mov16/12 >>>>[-<<<<+>>>>]
+            %18 = zext i8 %17 to i32
+            
+            <<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %19 = icmp sle i32 10_ i32 %18
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>#constop_10
+            ++++++++++
+            dup14/17/18 <<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/21 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/26 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/32 <<<[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>>>>>>+
+            <<<<<<<<[
+                -
+                
+                >>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<]
+            >>>>>>>>-
+            <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %19_ label %20_ label %29
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%20_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%29_false
+            <<<<<<<]
+        >>>]
+        >#B:%20
+        [
+            -
+            %21 = load i8* %2_ align 1
+            
+            >>>>>>[-]
+            dup12/13/18 <[->+>>>>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            %22 = zext i8 %21 to i32
+            
+            <<<<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %23 = icmp sge i32 %22_ i32 10
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/21 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/26 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/29 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                
+                >>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>]
+            br i1 %23_ label %24_ label %29
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%24_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%29_false
+            <<<<<<<]
+        >>>>]
+        >#B:%24
+        [
+            -
+            %25 = load i8* %2_ align 1
+            
+            >>>>>[-]
+            dup12/13/18 <[->+>>>>>+<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            %26 = zext i8 %25 to i32
+            
+            <<<<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/14 >[-<<<+>>>]
+            %27 = icmp sgt i32 %26_ i32 10
+            
+            <<<<[-]
+            dup14/16/17 >[->>+>+<<<]
+            mov16/14 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/21 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/26 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/29 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
+            br i1 %27_ label %29_ label %28
+            
+            dup13/17/16 <<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%29_true
+            >>>>>>]
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%28_false
+            <<<<<<]
+        >>>>>]
+        >#B:%28
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %29
+            
+            <<<<<<<<+
+        <]
+        >#B:%29
+        [
+            -
+            %30 = load i32* %1_ align 4
+            
+            >>>[-]
+            dup11/13/16 <<[->>+>>>+<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            ret i32 %30
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>]
+    <<<<<<<<<]
+<]
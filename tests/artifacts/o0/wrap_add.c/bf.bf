@@ -0,0 +1,226 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 558 ops ~2308 steps
+frame widths:
+  main: 15 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_volatile_i8*_%1__align_1(mult)
+        #%9_=_zext_i8_%8_to_i32(mult)
+        #%12_=_zext_i8_%11_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            store volatile i8 2_ i8* %1_ align 1
+            
+            >>[-]
+            ++
+            store volatile i8 2_ i8* %2_ align 1
+            
+            >[-]
+            ++
+            %6 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 2
+            ++
+            store i8 %6_ i8* %3_ align 1
+            
+            <<<[-]
+            ++
+            %7 = load volatile i8* %2_ align 1
+            
+            >>>[-]
+            known to be 2
+            ++
+            store i8 %7_ i8* %4_ align 1
+            
+            <<[-]
+            ++
+            %8 = load i8* %3_ align 1
+            
+            >>[-]
+            known to be 2
+            ++
+            %9 = zext i8 %8 to i32
+            
+            >[-]
+            known to be 2
+            ++
+            %10 = mul i32 %9_ i32 100
+            
+            <[-]
+            known to be 200
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++++++++++++
+            >>>]
+            %11 = load i8* %4_ align 1
+            
+            <<[-]
+            known to be 2
+            ++
+            %12 = zext i8 %11 to i32
+            
+            >[-]
+            known to be 2
+            ++
+            %13 = mul i32 %12_ i32 50
+            
+            <[-]
+            known to be 100
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            %14 = add i32 %10_ i32 %13
+            
+            <<[-]
+            known to be 44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            %15 = trunc i32 %14 to i8
+            
+            <<<[-]
+            known to be 44
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            store i8 %15_ i8* %5_ align 1
+            
+            <<<<[-]
+            >>>>++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %16 = load i8* %5_ align 1
+            
+            <<<[-]
+            known to be 44
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            %18 = zext i8 %16 to i32
+            
+            <<[-]
+            known to be 44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %19 = add i32 %18_ i32 250
+            
+            <<<[-]
+            known to be 38
+            >>>++++++
+            [
+                -
+                <<<++++++
+            >>>]
+            <<<++
+            %20 = trunc i32 %19 to i8
+            
+            >[-]
+            known to be 38
+            >>++++++
+            [
+                -
+                <<++++++
+            >>]
+            <<++
+            call @putchar(i8 %20)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>#constop_38
+            >++++++
+            [
+                -
+                <++++++
+            >]
+            <++
+            .
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
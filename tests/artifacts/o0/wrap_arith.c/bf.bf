@@ -0,0 +1,315 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 957 ops ~4044 steps
+frame widths:
+  main: 16 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l16 <<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #%6_=_load_volatile_i8*_%1__align_1(mult)
+        #%9_=_zext_i8_%8_to_i32(mult)
+        #%11_=_zext_i8_%10_to_i32(mult)
+        #%12_=_sub_i32_%9__i32_%11(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            store volatile i8 3_ i8* %1_ align 1
+            
+            >>[-]
+            +++
+            store volatile i8 5_ i8* %2_ align 1
+            
+            >[-]
+            +++++
+            %6 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 3
+            +++
+            store i8 %6_ i8* %3_ align 1
+            
+            <<<[-]
+            +++
+            %7 = load volatile i8* %2_ align 1
+            
+            >>>[-]
+            known to be 5
+            +++++
+            store i8 %7_ i8* %4_ align 1
+            
+            <<[-]
+            +++++
+            %8 = load i8* %3_ align 1
+            
+            >>[-]
+            known to be 3
+            +++
+            %9 = zext i8 %8 to i32
+            
+            >[-]
+            known to be 3
+            +++
+            %10 = load i8* %4_ align 1
+            
+            <[-]
+            known to be 5
+            +++++
+            %11 = zext i8 %10 to i32
+            
+            >>[-]
+            known to be 5
+            +++++
+            %12 = sub i32 %9_ i32 %11
+            
+            >[-]
+            known to be 254
+            >++++++++++++++
+            [
+                -
+                <++++++++++++++++++
+            >]
+            <++
+            %13 = trunc i32 %12 to i8
+            
+            <<[-]
+            known to be 254
+            >>>>++++++++++++++
+            [
+                -
+                <<<<++++++++++++++++++
+            >>>>]
+            <<<<++
+            store i8 %13_ i8* %5_ align 1
+            
+            <<[-]
+            >>>>>++++++++++++++
+            [
+                -
+                <<<<<++++++++++++++++++
+            >>>>>]
+            <<<<<++
+            %14 = load i8* %5_ align 1
+            
+            >>[-]
+            known to be 254
+            >>>++++++++++++++
+            [
+                -
+                <<<++++++++++++++++++
+            >>>]
+            <<<++
+            %15 = zext i8 %14 to i32
+            
+            >[-]
+            known to be 254
+            >>++++++++++++++
+            [
+                -
+                <<++++++++++++++++++
+            >>]
+            <<++
+            %16 = add i32 %15_ i32 10
+            
+            >[-]
+            known to be 8
+            ++++++++
+            %17 = add i32 %16_ i32 97
+            
+            <[-]
+            known to be 105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            %18 = trunc i32 %17 to i8
+            
+            <[-]
+            known to be 105
+            >+++++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            call @putchar(i8 %18)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_105
+            >>+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            <<.
+            [-]
+            %20 = zext i8 %10 to i32
+            
+            <<[-]
+            known to be 5
+            +++++
+            %21 = mul i32 %20_ i32 60
+            
+            >[-]
+            known to be 44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            %22 = trunc i32 %21 to i8
+            
+            <<[-]
+            known to be 44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            call @putchar(i8 %22)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %24 = zext i8 %10 to i32
+            
+            <<[-]
+            known to be 5
+            +++++
+            %25 = shl i32 %24_ i32 6
+            
+            <<[-]
+            known to be 64
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            %26 = trunc i32 %25 to i8
+            
+            <<[-]
+            known to be 64
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            call @putchar(i8 %26)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_64
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %28 = zext i8 %14 to i32
+            
+            <<<<[-]
+            known to be 254
+            >>>>++++++++++++++
+            [
+                -
+                <<<<++++++++++++++++++
+            >>>>]
+            <<<<++
+            %29 = sub i32 %28_ i32 200
+            
+            >[-]
+            known to be 54
+            >>>++++++
+            [
+                -
+                <<<+++++++++
+            >>>]
+            %30 = trunc i32 %29 to i8
+            
+            <<<<[-]
+            known to be 54
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++
+            >>>>]
+            call @putchar(i8 %30)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_54
+            >++++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,213 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1376 ops ~28165 steps
+frame widths:
+  main: 22 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l22 <<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%7
+        #B:%10
+        #alloc_%2
+        #%4_=_load_i8*_%2__align_1(mult)
+        #%5_=_zext_i8_%4_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = alloca i8_ align 1
+            store i8 3_ i8* %2_ align 1
+            
+            >>>>>[-]
+            +++
+            br label %3
+            
+            <<<+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = load i8* %2_ align 1
+            
+            >>>>[-]
+            dup7/8/10 <[->+>>+<<<]
+            mov10/7 >>>[-<<<+>>>]
+            %5 = zext i8 %4 to i32
+            
+            <[-]
+            dup8/11/10 <[->>>+<+<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov10/9 <[-<+>]
+            %6 = icmp ne i32 %5_ i32 253
+            
+            <<[-]
+            dup9/10/12 >[->+>>+<<<]
+            mov10/9 >[-<+>]
+            op_to_reg storing const value in temp address
+            >#constop_253
+            >>++++++++++++++
+            [
+                -
+                <<++++++++++++++++++
+            >>]
+            <<+
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/15 [->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<[
+                [-]
+                <<<<<<<+
+            >>>>>>>]
+            <[
+                [-]
+                <<<<<<+
+            >>>>>>]
+            br i1 %6_ label %7_ label %10
+            
+            dup8/11/10 <<<<<<[->>>+<+<<]
+            mov11/8 >>>[-<<<+>>>]
+            <<<<<<<<+
+            >>>>>>>[
+                -
+                <<<<<<<-
+                >>+
+                #B:main/%7_true
+            >>>>>]
+            <<<<<<<[
+                -
+                >>>+
+                #B:main/%10_false
+            <<<]
+        >]
+        >#B:%7
+        [
+            -
+            call @putchar(i8 97)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            .
+            [-]
+            %8 = load i8* %2_ align 1
+            
+            <<<<[-]
+            dup7/8/10 <[->+>>+<<<]
+            mov10/7 >>>[-<<<+>>>]
+            %9 = add i8 %8_ i8 255
+            
+            <[-]
+            dup8/10/11 <[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov11/9 <<[-<<+>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/19/21 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov21/9 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>>>>>>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<<]
+            >>>>>>[-]
+            store i8 %9_ i8* %2_ align 1
+            
+            dup9/10/11 <<<<<<<<<[->+>+<<]
+            mov10/9 >[-<+>]
+            <<<[-]
+            mov11/7 >>>>[-<<<<+>>>>]
+            br label %3
+            
+            <<<<<<<+
+        >]
+        >#B:%10
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
@@ -0,0 +1,155 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 483 ops ~2191 steps
+frame widths:
+  main: 12 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l12 <<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_zext_i8_%3_to_i32(mult)
+        #%6_=_zext_i8_%5_to_i32(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 200_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>++++++++++
+            [
+                -
+                <<<<<++++++++++++++++++++
+            >>>>>]
+            store volatile i8 100_ i8* %2_ align 1
+            
+            <<<<[-]
+            >>>>>++++++++++
+            [
+                -
+                <<<<<++++++++++
+            >>>>>]
+            %3 = load volatile i8* %1_ align 1
+            
+            <<<<[-]
+            known to be 200
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++++++++++++
+            >>>]
+            %4 = zext i8 %3 to i32
+            
+            <<[-]
+            known to be 200
+            >>++++++++++
+            [
+                -
+                <<++++++++++++++++++++
+            >>]
+            %5 = load volatile i8* %2_ align 1
+            
+            <<<[-]
+            known to be 100
+            >>>++++++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            %6 = zext i8 %5 to i32
+            
+            <[-]
+            known to be 100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            %7 = add i32 %4_ i32 %6
+            
+            <<<[-]
+            known to be 44
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %8 = and i32 %7_ i32 255
+            
+            <<[-]
+            known to be 44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %9 = trunc i32 %8 to i8
+            
+            <<<[-]
+            known to be 44
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,137 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 320 ops ~1272 steps
+frame widths:
+  main: 11 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l11 <<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_add_i8_%2__i8_1(mult)
+        #%4_=_add_i8_%2__i8_2(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 65_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            <<<<+
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 65
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            <<<<+
+            %3 = add i8 %2_ i8 1
+            
+            >[-]
+            known to be 66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %4 = add i8 %2_ i8 2
+            
+            <[-]
+            known to be 67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >#constop_66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            call @putchar(i8 %2)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
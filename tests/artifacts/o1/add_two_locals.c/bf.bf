@@ -0,0 +1,164 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 381 ops ~1508 steps
+frame widths:
+  main: 13 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l13 <<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_load_volatile_i8*_%2__align_1(mult)
+        #%5_=_add_i8_%4__i8_%3(mult)
+        #%6_=_add_i8_%3__i8_48(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 2_ i8* %1_ align 1
+            
+            >>[-]
+            ++
+            store volatile i8 65_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++
+            >>>>>]
+            <<<<<+
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 2
+            ++
+            %4 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 65
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            <<<<+
+            %5 = add i8 %4_ i8 %3
+            
+            >[-]
+            known to be 67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>#constop_67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            %6 = add i8 %3_ i8 48
+            
+            <[-]
+            known to be 50
+            >+++++
+            [
+                -
+                <++++++++++
+            >]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_50
+            >+++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            [-]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,114 @@
+; ModuleID = './tests/artifacts/o1/add_two_locals.c/ir.bc'
+source_filename = "./tests/cases/add_two_locals.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 {
+  %2 = load i8, i8* %0, align 1, !tbaa !5
+  %3 = icmp eq i8 %2, 0
+  br i1 %3, label %10, label %4
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @putchar(i8 noundef zeroext %5) #3
+  %7 = getelementptr inbounds i8, i8* %6, i64 1
+  %8 = load i8, i8* %7, align 1, !tbaa !5
+  %9 = icmp eq i8 %8, 0
+  br i1 %9, label %10, label %4, !llvm.loop !8
+
+10:                                               ; preds = %4, %1
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 {
+  %2 = icmp eq i32 %0, 0
+  br i1 %2, label %5, label %3
+
+3:                                                ; preds = %1
+  %4 = icmp ugt i32 %0, 9
+  br i1 %4, label %9, label %6
+
+5:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %22
+
+6:                                                ; preds = %9, %3
+  %7 = phi i32 [ 1, %3 ], [ %11, %9 ]
+  %8 = icmp eq i32 %7, 0
+  br i1 %8, label %22, label %14
+
+9:                                                ; preds = %9, %3
+  %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
+  %11 = mul i32 %10, 10
+  %12 = udiv i32 %0, %11
+  %13 = icmp ugt i32 %12, 9
+  br i1 %13, label %9, label %6, !llvm.loop !11
+
+14:                                               ; preds = %14, %6
+  %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
+  %16 = udiv i32 %0, %15
+  %17 = urem i32 %16, 10
+  %18 = trunc i32 %17 to i8
+  %19 = or i8 %18, 48
+  call void @putchar(i8 noundef zeroext %19) #3
+  %20 = udiv i32 %15, 10
+  %21 = icmp ult i32 %15, 10
+  br i1 %21, label %22, label %14, !llvm.loop !12
+
+22:                                               ; preds = %14, %6, %5
+  ret void
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: nounwind uwtable
+define dso_local i32 @main() local_unnamed_addr #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %1)
+  store volatile i8 2, i8* %1, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %2)
+  store volatile i8 65, i8* %2, align 1, !tbaa !5
+  %3 = load volatile i8, i8* %1, align 1, !tbaa !5
+  %4 = load volatile i8, i8* %2, align 1, !tbaa !5
+  %5 = add i8 %4, %3
+  call void @putchar(i8 noundef zeroext %5) #3
+  %6 = add i8 %3, 48
+  call void @putchar(i8 noundef zeroext %6) #3
+  call void @putchar(i8 noundef zeroext %4) #3
+  call void @putchar(i8 noundef zeroext %5) #3
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %2)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %1)
+  ret i32 0
+}
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { argmemonly nofree nosync nounwind willreturn }
+attributes #3 = { nounwind }
+
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
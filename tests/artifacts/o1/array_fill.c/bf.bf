@@ -0,0 +1,524 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 5151 ops ~102080 steps
+frame widths:
+  main: 32 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%10
+        #B:%11
+        #alloc_%1
+        #alloc_%1{1}
+        #alloc_%1{2}
+        #alloc_%1{3}
+        #%4_=_phi_i64_{_i64_0__%0_}__{_i64_%8__%3_}(mult)
+        #%5_=_getelementptr_inbounds_{4_x_i8}*_%1__i64_0__i64_%4(mult)
+        #%6_=_trunc_i64_%4_to_i8(mult)
+        #%7_=_add_i8_%6__i8_97(mult)
+        #%8_=_add_i64_%4__i64_1(mult)
+        #%12_=_phi_i64_{_i64_%15__%11_}__{_i64_0__%3_}(mult)
+        #%15_=_add_i64_%12__i64_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca {4 x i8}_ align 1
+            br label %3
+            
+            doing phi stuff
+            %4 = phi i64 { i64 0_ %0 }_ { i64 %8_ %3 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_0
+            
+            [-]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = phi i64 { i64 0_ %0 }_ { i64 %8_ %3 }
+            %5 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 %4
+            
+            >>>>>>>>[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/20/21 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++
+            dup11/21/22 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov21/11 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            mov19/12 <<[-<<<<<<<+>>>>>>>]
+            >>>[
+                -
+                <<<<<<<<<<+
+            >>>>>>>>>>]
+            %6 = trunc i64 %4 to i8
+            
+            <<<<<<<<<[-]
+            dup11/18/19 <<[->>>>>>>+>+<<<<<<<<]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov19/13 >[-<<<<<<+>>>>>>]
+            %7 = add i8 %6_ i8 97
+            
+            <<<<<[-]
+            dup13/18/19 <[->>>>>+>+<<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov19/14 <[-<<<<<+>>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/24/26 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov26/14 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<[
+                -
+                >>>>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<]
+            >>>[-]
+            store i8 %7_ i8* %5_ align 1
+            
+            dup14/18/19 <<<<<<<<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            dup12/20/21 <<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/28/27 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/29 <[->>>+<<<]
+            >>>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov29/24 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov19/25 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                mov25/24 >[-<+>]
+                mov21/25 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<[
+                mov26/22 >>>[-<<<<+>>>>]
+                
+                mov23/24 <<<[->+<]
+                r1 >
+                -
+            ]
+            %8 = add i64 %4_ i64 1
+            
+            <<<<<<<<[-]
+            dup11/18/19 <<<<[->>>>>>>+>+<<<<<<<<]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov19/15 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/22/24 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov24/15 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %9 = icmp eq i64 %8_ i64 4
+            
+            <<<<<<<<<[-]
+            dup15/18/19 >>>[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_4
+            ++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/22 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<+
+            >>>>>>>>>>[
+                [-]
+                <<<<<<<<<<[-]
+            >>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            br i1 %9_ label %11_ label %3
+            
+            dup12/19/18 <<<<<<<<<[->>>>>>>+<+<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            doing phi stuff
+            %12 = phi i64 { i64 %15_ %11 }_ { i64 0_ %3 }
+            op_to_reg storing const value in temp address
+            >#constop_0
+            
+            mov20/21 [->+<]
+            doing phi stuff
+            %4 = phi i64 { i64 0_ %0 }_ { i64 %8_ %3 }
+            dup15/22/23 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov22/15 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov23/24 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%11_true
+                >>>>>>>>>>[-]
+                mov21/16 >>>>>[-<<<<<+>>>>>]
+                >>>[-]
+            <<<<<<]
+            <<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%3_false
+                >>>>>>>[-]
+                mov24/11 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<]
+        >]
+        >#B:%10
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+        >#B:%11
+        [
+            -
+            %12 = phi i64 { i64 %15_ %11 }_ { i64 0_ %3 }
+            %13 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 %12
+            
+            >>>>>>[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/26/27 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov27/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++
+            dup16/27/28 <<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            mov25/12 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >>>[
+                -
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
+            %14 = load i8* %13_ align 1
+            
+            <<<<<<<<<<<<<<<[-]
+            dup12/18/19 <[->>>>>>+>+<<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/24/25 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov24/0 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/26 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov26/22 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov21/20 <[-<+>]
+                mov22/21 >[-<+>]
+                
+                mov19/22 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup19/20/22 <<<[->+>>+<<<]
+            mov20/19 >[-<+>]
+            >[
+                mov23/20 >>[-<<<+>>>]
+                mov22/23 <[->+<]
+                mov21/22 <[->+<]
+                r1 >
+                -
+            ]
+            mov22/13 >[-<<<<<<<<<+>>>>>>>>>]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            dup13/18/19 <<<<<<<<<[->>>>>+>+<<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            %15 = add i64 %12_ i64 1
+            
+            <<[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov19/17 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/22/24 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov24/17 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %16 = icmp eq i64 %15_ i64 4
+            
+            <<<<<<<<<[-]
+            dup17/18/19 >>>>>[->+>+<<]
+            mov18/17 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_4
+            ++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/22 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<+
+            >>>>>>>>>>[
+                [-]
+                <<<<<<<<<<[-]
+            >>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            br i1 %16_ label %10_ label %11
+            
+            dup12/19/18 <<<<<<<<<[->>>>>>>+<+<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            doing phi stuff
+            %12 = phi i64 { i64 %15_ %11 }_ { i64 0_ %3 }
+            dup17/20/21 <<[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/22 >[->+<]
+            <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%10_true
+                >>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%11_false
+                >>>>>>>>>>[-]
+                mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<]
+        >>>]
+    <<<<<]
+<]
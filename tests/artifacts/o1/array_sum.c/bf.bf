@@ -0,0 +1,696 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 5847 ops ~96784 steps
+frame widths:
+  main: 32 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%6
+        #B:%7
+        #alloc_%1
+        #alloc_%1{1}
+        #alloc_%1{2}
+        #alloc_%1{3}
+        #%2_=_getelementptr_inbounds_{4_x_i8}*_%1__i64_0__i64_0(mult)
+        #%8_=_phi_i64_{_i64_0__%0_}__{_i64_%13__%7_}(mult)
+        #%9_=_phi_i8_{_i8_0__%0_}__{_i8_%12__%7_}(mult)
+        #%11_=_load_i8*_%10__align_1(mult)
+        #%12_=_add_i8_%11__i8_%9(mult)
+        #%13_=_add_i64_%8__i64_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca {4 x i8}_ align 1
+            %2 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 0
+            
+            >>>>>>>>[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/17/18 <<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov16/10 [-<<<<<<+>>>>>>]
+            >>[
+                -
+                <<<<<<<<+
+            >>>>>>>>]
+            store i8 10_ i8* %2_ align 1
+            
+            op_to_reg storing const value in temp address
+            <<#constop_10
+            ++++++++++
+            dup10/17/19 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/19/25 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov19/0 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            >>>>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/26 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov26/22 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            mov16/23 <<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov21/20 <[-<+>]
+                mov22/21 >[-<+>]
+                mov23/22 >[-<+>]
+                mov19/23 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov23/19 >>>>[-<<<<+>>>>]
+            <<[
+                mov24/20 >>>[-<<<<+>>>>]
+                
+                mov21/22 <<<[->+<]
+                r1 >
+                -
+            ]
+            %3 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 1
+            
+            <<<<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/17/18 <<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov16/10 <<[-<<<<<<+>>>>>>]
+            >>[
+                -
+                <<<<<<<<+
+            >>>>>>>>]
+            store i8 20_ i8* %3_ align 1
+            
+            op_to_reg storing const value in temp address
+            <#constop_20
+            ++++
+            [
+                -
+                <+++++
+            >]
+            dup10/18/19 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/29 [->>>>+<<<<]
+            >>>>>>+
+            <<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov29/22 [-<<<<<<<+>>>>>>>]
+                <<<<<<<++++++++++++++++++++
+            >>>>>>>]
+            <<<[
+                if neg
+                <<<<++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov16/23 <<<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov21/20 <[-<+>]
+                mov22/21 >[-<+>]
+                mov23/22 >[-<+>]
+                mov19/23 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov23/19 >>>>[-<<<<+>>>>]
+            <<[
+                mov24/20 >>>[-<<<<+>>>>]
+                
+                mov21/22 <<<[->+<]
+                r1 >
+                -
+            ]
+            %4 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 2
+            
+            <<<<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/17/18 <<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            mov16/10 <<[-<<<<<<+>>>>>>]
+            >>[
+                -
+                <<<<<<<<+
+            >>>>>>>>]
+            store i8 30_ i8* %4_ align 1
+            
+            op_to_reg storing const value in temp address
+            <#constop_30
+            +++++
+            [
+                -
+                <++++++
+            >]
+            dup10/18/19 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/25/26 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov25/0 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov27/22 [-<<<<<+>>>>>]
+                <<<<<++++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<++++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov16/23 <<<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov21/20 <[-<+>]
+                mov22/21 >[-<+>]
+                mov23/22 >[-<+>]
+                mov19/23 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov23/19 >>>>[-<<<<+>>>>]
+            <<[
+                mov24/20 >>>[-<<<<+>>>>]
+                
+                mov21/22 <<<[->+<]
+                r1 >
+                -
+            ]
+            %5 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 3
+            
+            <<<<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/17/18 <<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            op_to_reg storing const value in temp address
+            >>#constop_3
+            +++
+            mov16/10 <<[-<<<<<<+>>>>>>]
+            >>[
+                -
+                <<<<<<<<+
+            >>>>>>>>]
+            store i8 5_ i8* %5_ align 1
+            
+            op_to_reg storing const value in temp address
+            <<#constop_5
+            +++++
+            dup10/17/18 <<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/24/25 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov24/0 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov24/26 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov26/21 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov16/22 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov20/19 <[-<+>]
+                mov21/20 >[-<+>]
+                mov22/21 >[-<+>]
+                mov18/22 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<[
+                mov23/19 >>>[-<<<<+>>>>]
+                
+                mov20/21 <<<[->+<]
+                r1 >
+                -
+            ]
+            br label %7
+            
+            doing phi stuff
+            %8 = phi i64 { i64 0_ %0 }_ { i64 %13_ %7 }
+            op_to_reg storing const value in temp address
+            <<<<#constop_0
+            
+            mov16/17 [->+<]
+            doing phi stuff
+            %9 = phi i8 { i8 0_ %0 }_ { i8 %12_ %7 }
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            mov18/19 [->+<]
+            <<<<<<<[-]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<[-]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<+
+        <<<]
+        >>#B:%6
+        [
+            -
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            dup14/20/21 >>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        ]
+        >#B:%7
+        [
+            -
+            %8 = phi i64 { i64 0_ %0 }_ { i64 %13_ %7 }
+            %9 = phi i8 { i8 0_ %0 }_ { i8 %12_ %7 }
+            %10 = getelementptr inbounds {4 x i8}* %1_ i64 0_ i64 %8
+            
+            >>>>>[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/17/18 <<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 
+            <+++++++
+            dup11/18/19 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov16/10 <<[-<<<<<<+>>>>>>]
+            >>>[
+                -
+                <<<<<<<<<+
+            >>>>>>>>>]
+            %11 = load i8* %10_ align 1
+            
+            <<<<<<[-]
+            dup10/16/17 <<<[->>>>>>+>+<<<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/22/23 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/24 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov24/20 [-<<<<+>>>>]
+                <<<<++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov19/18 <[-<+>]
+                mov20/19 >[-<+>]
+                
+                mov17/20 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup17/18/20 <<<[->+>>+<<<]
+            mov18/17 >[-<+>]
+            >[
+                mov21/18 >>[-<<<+>>>]
+                mov20/21 <[->+<]
+                mov19/20 <[->+<]
+                r1 >
+                -
+            ]
+            mov20/13 >[-<<<<<<<+>>>>>>>]
+            %12 = add i8 %11_ i8 %9
+            
+            <<<<<<[-]
+            dup13/16/17 <[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            dup12/18/19 <<<<[->>>>>>+>+<<<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            mov17/14 <[-<<<+>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/21/23 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov23/14 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %13 = add i64 %8_ i64 1
+            
+            <<<<<[-]
+            dup11/16/17 <<<<[->>>>>+>+<<<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov17/15 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/20/22 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov22/15 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %14 = icmp eq i64 %13_ i64 4
+            
+            <<<<<<<<<[-]
+            dup15/16/17 >>>>>[->+>+<<]
+            mov16/15 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_4
+            ++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/20 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<+
+            >>>>>>>>>>[
+                [-]
+                <<<<<<<<<<[-]
+            >>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            br i1 %14_ label %6_ label %7
+            
+            dup10/17/16 <<<<<<<<<[->>>>>>>+<+<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            doing phi stuff
+            %8 = phi i64 { i64 0_ %0 }_ { i64 %13_ %7 }
+            dup15/18/19 <<[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            mov19/20 >[->+<]
+            doing phi stuff
+            %9 = phi i8 { i8 0_ %0 }_ { i8 %12_ %7 }
+            dup14/21/22 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov22/23 >[->+<]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<-
+                >+
+                #B:main/%6_true
+                >>>>>>>>>>>>>>>>[-]
+                >>>[-]
+            <<<<<<<]
+            <<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%7_false
+                >>>>>>[-]
+                mov20/11 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                <<<<<<<<[-]
+                mov23/12 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<]
+        >>]
+    <<<<]
+<]
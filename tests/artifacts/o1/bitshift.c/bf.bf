@@ -1,328 +1,147 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 17735 ops ~1265261 steps
+frame widths:
+  main: 56 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>#B:%ret_lading_pad
+        >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l24 <<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/main
+            >>-
+            l56 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%5
+        #B:%8
+        #B:%13
+        #B:%21
+        #B:%24
+        #B:%25
+        #B:%29
+        #B:%32
+        #B:%37
+        #B:%45
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%22__%21_}(mult)
+        #%3_=_shl_i32_1__i32_%2(mult)
+        #%4_=_icmp_ugt_i32_%2__i32_3(mult)
+        #%6_=_phi_i32_{_i32_1__%1_}__{_i32_%10__%8_}(mult)
+        #%9_=_phi_i32_{_i32_%10__%8_}__{_i32_1__%1_}(mult)
+        #%10_=_mul_i32_%9__i32_10(mult)
+        #%12_=_icmp_ugt_i32_%11__i32_9(mult)
+        #%14_=_phi_i32_{_i32_%19__%13_}__{_i32_%6__%5_}(mult)
+        #%19_=_udiv_i32_%14__i32_10(mult)
+        #%22_=_add_i32_%2__i32_1(mult)
+        #%26_=_phi_i32_{_i32_%46__%45_}__{_i32_0__%21_}(mult)
+        #%27_=_lshr_i32_128__i32_%26(mult)
+        #%30_=_phi_i32_{_i32_1__%25_}__{_i32_%34__%32_}(mult)
+        #%33_=_phi_i32_{_i32_%34__%32_}__{_i32_1__%25_}(mult)
+        #%34_=_mul_i32_%33__i32_10(mult)
+        #%38_=_phi_i32_{_i32_%43__%37_}__{_i32_%30__%29_}(mult)
+        #%43_=_udiv_i32_%38__i32_10(mult)
+        #%46_=_add_i32_%26__i32_1(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/17 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
+            br label %1
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov16/18 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
             doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup15/16/17 <<[->+>+<<]
-            mov16/15 >[-<+>]
-            <<<<<<[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup10/18/19 <[->>>>>>>>+>+<<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov19/11 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup11/16/17 <[->>>>>+>+<<<<<<]
-            mov16/11 >>>>>[-<<<<<+>>>>>]
-            mov17/12 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup12/16/17 >>>>>[->>>>+>+<<<<<]
-            mov16/12 >>>>[-<<<<+>>>>]
-            >.
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %22_ %21 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_0
+            
             [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
+            mov33/15 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >>#B:%1
         [
             -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup10/16/17 <<<[->>>>>>+>+<<<<<<<]
-            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %22_ %21 }
+            %3 = shl i32 1_ i32 %2
+            
+            >>>>>>>>>>>>[-]
             op_to_reg storing const value in temp address
-            >>#constop_1
+            >>>>>>>>>>>>>>>>>>#constop_1
             +
-            mov17/13 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup13/16/17 <[->>>+>+<<<<]
-            mov16/13 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/21 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
+            dup15/35/36 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov35/15 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>+
+            <[
                 -
-                >>+
-                >>[
+                mov16/34 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
+                >>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<[
                     -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup13/16/17 <<<<<<[->>>+>+<<<<]
-            mov16/13 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
+                    >>>>>>+
+                    <<[
+                        -
+                        mov38/39 [->+<]
+                        <<<<<<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov39/38 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                    +
+                    <<[
+                        -
+                        mov38/39 [->+<]
+                        <<<<<<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov39/38 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                <<<<<<]
+                >>>>[-]
+                <[-]
             <]
-        >>]
-        >#B:%9
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>]
-    <<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l45 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov31/33 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
+            >[
                 -
-                >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
+                mov34/16 <<<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>]
+            %4 = icmp ugt i32 %2_ i32 3
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup15/33/34 <<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov33/15 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov30/32 <<<<[->>+<<]
+            >>#constop_3
+            +++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov34/36 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -341,182 +160,171 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %4_ label %8_ label %5
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
+            %9 = phi i32 { i32 %10_ %8 }_ { i32 1_ %1 }
             op_to_reg storing const value in temp address
-            <#constop_1
+            >#constop_1
             +
-            <<<<<<<<<<<<<[-]
-            mov31/18 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov35/36 [->+<]
             doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
+            %6 = phi i32 { i32 1_ %1 }_ { i32 %10_ %8 }
             op_to_reg storing const value in temp address
-            >#constop_1
+            >>#constop_1
             +
-            <<<<<<<<<<<<<<<<[-]
-            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
+            mov37/38 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%8_true
+                >>>>>>>>>>>>>[-]
+                mov36/19 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                >>[-]
+            <<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
+                >>+
+                #B:main/%5_false
+                >>>>>>>>>>>>>[-]
+                mov38/18 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+                <<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
         >]
         >#B:%5
         [
             -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup16/31/32 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            %6 = phi i32 { i32 1_ %1 }_ { i32 %10_ %8 }
+            %7 = icmp eq i32 %6_ i32 0
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/36 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
+            >>>>>>>>>>>>[-]
+            dup18/39/41 >[->>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov39/18 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
+                <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %7_ label %21_ label %13
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup16/31/32 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov32/22 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
+            %14 = phi i32 { i32 %19_ %13 }_ { i32 %6_ %5 }
+            dup18/35/36 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov35/18 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov36/37 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%21_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
+                >>>>+
+                #B:main/%13_false
+                >>>>>>>>>>>>>>>[-]
+                mov37/22 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>]
+        >#B:%8
         [
             -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup18/33/34 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov33/18 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %9 = phi i32 { i32 %10_ %8 }_ { i32 1_ %1 }
+            %10 = mul i32 %9_ i32 10
+            
+            >>>>>>>>>>>>>>[-]
+            dup19/38/39 <[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov38/19 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            [
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<[
                 -
-                dup34/19/36 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov36/34 >>[-<<+>>]
-            <]
+                mov39/45 <[->>>>>>+<<<<<<]
+                >>>>>>[
+                    -
+                    <<<<<<+
+                    >>>>>+
+                    <<[
+                        -
+                        mov42/43 [->+<]
+                        <<<<<<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov43/42 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<<]
             <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup19/31/32 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup32/34/35 >[->>+>+<<<]
-            mov35/32 >>>[-<<<+>>>]
+            >>>[-]
+            %11 = udiv i32 %3_ i32 %10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup16/33/34 <[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov33/16 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            dup20/35/36 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov35/20 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            dup36/38/39 >[->>+>+<<<]
+            mov39/36 >>>[-<<<+>>>]
             <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov30/39 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov34/40 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
                     -
                     <+
-                    >>>>>>[
+                    >>>[
                         -
-                        <<<<<<-
+                        <<<-
                         r1 >
-                    >>>>>>]
+                    >>>]
                     r1 >
                     [
                         l1 <
                     ]
                     l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>[
                     [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov39/30 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup32/34/35 <<<<<<<[->>+>+<<<]
-                mov35/32 >>>[-<<<+>>>]
+                    <<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>]
+                mov40/34 >>>[-<<<<<<+>>>>>>]
+                dup36/38/39 <<<<[->>+>+<<<]
+                mov39/36 >>>[-<<<+>>>]
             <<<<<]
             >>[-]
             >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup20/31/32 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov31/20 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %12 = icmp ugt i32 %11_ i32 9
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup17/33/34 <<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/34 <<<<[->>+<<]
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov34/36 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -535,93 +343,104 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
+                <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>]
+            br i1 %12_ label %8_ label %5
+            
+            dup21/34/33 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>+<+<<<<<<<<<<<<]
+            mov34/21 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
             doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup19/31/32 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            %9 = phi i32 { i32 %10_ %8 }_ { i32 1_ %1 }
+            dup20/35/36 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov35/20 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            mov36/37 >[->+<]
             doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup19/33/34 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
+            %6 = phi i32 { i32 1_ %1 }_ { i32 %10_ %8 }
+            dup20/38/39 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov38/20 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            mov39/40 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%8_true
+                >>>>>>>>>>>>>[-]
+                mov37/19 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                >>>[-]
+            <<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
+                >>+
+                #B:main/%5_false
+                >>>>>>>>>>>>>[-]
+                mov40/18 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>]
+        >#B:%13
         [
             -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup22/35/36 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov35/22 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup36/38/39 >[->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov30/42 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
+            %14 = phi i32 { i32 %19_ %13 }_ { i32 %6_ %5 }
+            %15 = udiv i32 %3_ i32 %14
+            
+            >>>>>>>>>>[-]
+            dup16/41/42 <[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov41/16 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup22/43/44 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov43/22 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            dup44/45/47 >[->+>>+<<<]
+            mov47/44 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov42/48 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<<[
                     -
-                    <+
-                    >>>>>[
+                    >+
+                    >>[
                         -
-                        <<<<<-
+                        <<-
                         r1 >
-                    >>>>>]
+                    >>]
                     r1 >
                     [
                         l1 <
                     ]
                     l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
+                <<<]
+                >>>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                     [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov42/30 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup36/38/39 <<<<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov48/42 >>[-<<<<<<+>>>>>>]
+                dup44/45/47 <<<<[->+>>+<<<]
+                mov47/44 >>>[-<<<+>>>]
+            <<<<<]
             >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup23/31/32 <[->>>>>>>>+>+<<<<<<<<<]
-            mov31/23 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            >[-]
+            %16 = urem i32 %15_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup17/33/34 <<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            dup33/35/36 [->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
+            dup35/37/38 [->>+>+<<<]
+            mov38/35 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
             <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov32/37 <<<<<<<[->>>>>+<<<<<]
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov34/39 [->>>>>+<<<<<]
                 >>>>>>>+
                 <<<<[
                     -
@@ -638,65 +457,151 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                mov37/32 <<[-<<<<<+>>>>>]
-                dup33/35/36 <<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
+                mov39/34 <<[-<<<<<+>>>>>]
+                dup35/37/38 <<<<[->>+>+<<<]
+                mov38/35 >>>[-<<<+>>>]
             <<<<]
             >>#neg
             [
-                mov35/24 >[-<<<<<<<<<<<+>>>>>>>>>>>]
+                mov37/21 >[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
                 <[
                     -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
+                    <<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>]
             ]
-            mov34/24 [-<<<<<<<<<<+>>>>>>>>>>]
+            mov36/21 [-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
             <[-]
             >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup24/31/32 <[->>>>>>>+>+<<<<<<<<]
-            mov31/24 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov32/25 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup25/31/32 <[->>>>>>+>+<<<<<<<]
-            mov31/25 >>>>>>[-<<<<<<+>>>>>>]
+            %17 = trunc i32 %16 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup21/33/34 >>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov33/21 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            mov34/17 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %18 = or i8 %17_ i8 48
+            
+            <<<<<<<<<<<<<[-]
+            dup17/33/34 <<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov32/26 <[-<<<<<<+>>>>>>]
-            >[
+            >++++++
+            [
                 -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
+                <++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov34/42 <<[->>>>>>>>+<<<<<<<<]
+            mov35/51 >[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>>+
+            >>>>>>>>>+
+            <<<<<<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup46/43/42 >>>>[-<<<+<+>>>>]
+                mov43/46 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup55/52/51 >>>>[-<<<+<+>>>>]
+                mov52/55 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<<<<<[
+                    mov45/37 [-<<<<<<<<+>>>>>>>>]
+                    >>>>>>>>>[-]
+                <<<<<<<<<]
+                >>>>>>>>>[
+                    mov54/37 [-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                ]
+                dup41/38/39 <<<<<<<<<<<<<[-<<<+>+>>]
+                mov38/41 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov38/37 >[-<+>]
+                >]
+                mov37/21 <<[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+                continue?
+                dup42/37/38 >>>>>[-<<<<<+>+>>>>]
+                mov37/42 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup51/37/38 >>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>]
+                mov37/51 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>>>>-
+            <<<<<<<<<<<<[-]
+            call @putchar(i8 %18)
+            
             putchar intrinsic
-            dup26/31/32 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov31/26 >>>>>[-<<<<<+>>>>>]
+            dup21/33/34 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov33/21 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup22/31/32 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %19 = udiv i32 %14_ i32 10
+            
+            <<<<<<<<<<<[-]
+            dup22/33/34 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov33/22 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            dup33/35/36 [->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
+            dup35/37/38 [->>+>+<<<]
+            mov38/35 >>>[-<<<+>>>]
             <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov32/37 <<<<<<<[->>>>>+<<<<<]
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov34/39 [->>>>>+<<<<<]
                 >>>>>>>+
                 <<<<[
                     -
@@ -713,40 +618,29 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
+                <<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>[
                     [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov37/32 >>>[-<<<<<+>>>>>]
-                dup33/35/36 <<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
+                    <<<<<<<<<<<<<-
+                >>>>>>>>>>>>>]
+                mov39/34 >>>[-<<<<<+>>>>>]
+                dup35/37/38 <<<<[->>+>+<<<]
+                mov38/35 >>>[-<<<+>>>]
             <<<<]
             >[-]
             >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup22/31/32 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov32/28 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup28/31/32 <[->>>+>+<<<<]
-            mov31/28 >>>[-<<<+>>>]
+            %20 = icmp ult i32 %14_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup22/33/34 >>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov33/22 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/34 <<<[->+<]
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/36 [->+<]
             >>>+
             <<<<[
                 -
@@ -765,258 +659,160 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov27/22 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>]
-    <<<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l34 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%1
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%7
-        >#B:%call_term_for_5
-        >#B:%call_term_for_6
-        >#%2_=_phi_i32_{_i32_0__%0_}__{_i32_%4__%call_term_for_2_}(mult)
-        >#%3_=_shl_i32_1__i32_%2(mult)
-        >#%4_=_add_i32_%2__i32_1(mult)
-        >#%5_=_icmp_eq_i32_%4__i32_8(mult)
-        >#%8_=_phi_i32_{_i32_%10__%call_term_for_6_}__{_i32_0__%call_term_for_2_}(mult)
-        >#%9_=_lshr_i32_128__i32_%8(mult)
-        >#%10_=_add_i32_%8__i32_1(mult)
-        >#%11_=_icmp_eq_i32_%10__i32_8(mult)
-        <<<<<<<<<<<<<<<<#B:%0
-        [
-            -
-            copy up args
-            br label %1
-            doing phi stuff
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %20_ label %21_ label %13
             
-            <<<<<<<<[-]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            <<<<<<<<<<<<<<<+
-        <<]
-        >>#B:%1
-        [
-            -
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            %3 = shl i32 1_ i32 %2
-            >>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>#constop_1
-            +
-            dup13/23/24 <<<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov23/13 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            >>+
-            <[
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %14 = phi i32 { i32 %19_ %13 }_ { i32 %6_ %5 }
+            mov23/35 <<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                mov14/22 <<<<<<<<<<[->>>>>>>>+<<<<<<<<]
-                >>>>>>>>[
-                    -
-                    <<<<<<<<++
-                >>>>>>>>]
-                >>>[-]
-            <]
-            >[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%21_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                mov22/14 <<<[-<<<<<<<<+>>>>>>>>]
-            >>>]
-            call @putdec(i32 %3)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<#caller/%call_term_for_1
-            +
-            stack_width 34
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup14/21/22 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov22/40 >[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/42/43 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov43/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r34 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
-            +
-            >>#putdec
-            +
-            >>#putdec/b0
-            +
-        >>]
-        >#B:%call_term_for_1
+                >>>>+
+                #B:main/%13_false
+                >>>>>>>>>>>>>>>[-]
+                mov35/22 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>]
+        >#B:%21
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %4 = add i32 %2_ i32 1
-            >>>>>>>[-]
-            dup13/21/22 <<[->>>>>>>>+>+<<<<<<<<<]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %22 = add i32 %2_ i32 1
+            
+            <<<<<<<<<<<<[-]
+            dup15/33/34 <<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov33/15 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov22/15 <[-<<<<<<<+>>>>>>>]
-            >[
+            mov34/24 <[-<<<<<<<<<<+>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup24/37/39 <<<<<<<<<<<<[->>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov39/24 >>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            <<<<[
                 -
-                <<<<<<<<+
-            >>>>>>>>]
-            %5 = icmp eq i32 %4_ i32 8
-            <<<<<<<[-]
-            dup15/21/22 <[->>>>>>+>+<<<<<<<]
-            mov21/15 >>>>>>[-<<<<<<+>>>>>>]
+                >>>+
+                <<[
+                    -
+                    mov36/37 [->+<]
+                    <<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>-
+                <<]
+                mov37/36 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %23 = icmp eq i32 %22_ i32 8
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup24/33/34 >>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov33/24 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_8
             ++++++++
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov23/26 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/37 [->>+<<]
+            >>>>+
+            <<<<<[
                 -
                 >>+
-                >>[
+                >[
                     -
-                    <<-
+                    <-
                     r1 >
-                >>]
+                >]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<+
-            >>>>>>>>>>[
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
                 [-]
-                <<<<<<<<<<[-]
-            >>>>>>>>>>]
-            <<[
+                <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>]
+            <[
                 [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %5_ label %7_ label %1
+                <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %23_ label %25_ label %1
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %8 = phi i32 _ i32 %10_ %call_term_for_6 __ _ i32 0_ %call_term_for_2 _
+            %26 = phi i32 { i32 %46_ %45 }_ { i32 0_ %21 }
             op_to_reg storing const value in temp address
-            <<<#constop_0
+            >#constop_0
             
-            <<<<[-]
-            mov21/17 >>>>[-<<<<+>>>>]
+            mov35/36 [->+<]
             doing phi stuff
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            dup15/22/23 <<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov22/15 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<[-]
-            mov23/13 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>[
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %22_ %21 }
+            dup24/37/38 <<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov37/24 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov38/39 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<-
-                >>>>>+
-                #B:main/7_true
-            >>>>>>]
-            <<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%25_true
+                >>>>>>>>>>>>>>>[-]
+                mov36/25 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+                >>>[-]
+            <<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
                 >+
-                #B:main/1_false
-            <]
-        >>>]
-        >#B:%6
+                #B:main/%1_false
+                >>>>>>>>>>>[-]
+                mov39/15 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>]
+        >#B:%24
         [
             -
             ret void
+            
             zero all function allocs
-            >>>>[-]
+            >>>>>>[-]
+            >[-]
+            >[-]
+            >[-]
             >[-]
             >[-]
             >[-]
@@ -1024,158 +820,716 @@ runtime init:
             >[-]
             >[-]
             >[-]
-            <<<<<<<<<<<<<<<<<<<<-
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>]
-        >#B:%7
+            <[-]
+            r1 
+            l5 
+        >>>>>]
+        >#B:%25
         [
             -
-            %8 = phi i32 _ i32 %10_ %call_term_for_6 __ _ i32 0_ %call_term_for_2 _
-            %9 = lshr i32 128_ i32 %8
-            >>>>>>>>[-]
+            %26 = phi i32 { i32 %46_ %45 }_ { i32 0_ %21 }
+            %27 = lshr i32 128_ i32 %26
+            
+            >>>>>>>>>>>>>>>>[-]
             op_to_reg storing const value in temp address
-            >>>>>>#constop_128
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            dup17/25/26 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
-            mov25/17 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov24/18 <[-<<<<<<+>>>>>>]
-            >>>>>>>+
-            <<<<<[
+            >>>>>>>>>>>>>>#constop_128
+            >++++++++
+            [
                 -
-                mov18/29 <<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
-                >>>>>>>>>>>[
+                <++++++++++++++++
+            >]
+            dup25/42/43 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov42/25 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov40/26 <<[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            >>>>>>+
+            <<<[
+                -
+                mov26/44 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
+                >>>>>>>>>>>>>>>>>>[
                     -
                     [
                         -
-                        <<<<<<<<<<<+
-                    >>>>>>>>>>>>]
+                        <<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>]
                     >[
                     <]
                 <]
-            <<<]
-            >>>>>[-]
-            call @putdec(i32 %9)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_5
-            +
-            stack_width 34
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup18/21/22 <<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]
-            mov21/18 >>>[-<<<+>>>]
-            mov22/40 >[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/42/43 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov43/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r34 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
-            +
-            >>#putdec
-            +
-            >>#putdec/b0
-            +
-        >>>>>>]
-        >#B:%call_term_for_5
-        [
-            -
-            call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %10 = add i32 %8_ i32 1
-            >>>>>>>[-]
-            dup17/21/22 <<[->>>>+>+<<<<<]
-            mov21/17 >>>>[-<<<<+>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov22/19 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            %11 = icmp eq i32 %10_ i32 8
-            <<<[-]
-            dup19/21/22 <[->>+>+<<<]
-            mov21/19 >>[-<<+>>]
+            <]
+            >>>[-]
+            %28 = icmp ugt i32 %27_ i32 9
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup26/33/34 >>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov33/26 >>>>>>>[-<<<<<<<+>>>>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_8
-            ++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov23/25 <<<<[->>+<<]
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov34/36 <[->>+<<]
             >>>>+
-            <<<<<[
+            <<<[
                 -
-                >>+
+                
                 >[
                     -
-                    <-
+                    
                     r1 >
-                >]
+                ]
                 r1 >
                 [
                     l1 <
                 ]
                 l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<+
-            >>>>>[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            <[
+            <]
+            >>>-
+            <<[
                 [-]
-                <<<<[-]
-            >>>>]
-            br i1 %11_ label %6_ label %7
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %28_ label %32_ label %29
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %8 = phi i32 _ i32 %10_ %call_term_for_6 __ _ i32 0_ %call_term_for_2 _
-            dup19/21/22 <<<<<[->>+>+<<<]
-            mov21/19 >>[-<<+>>]
-            <<<<[-]
-            mov22/17 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<-
-                >>>>+
-                #B:main/6_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<[
-                -
-                >>>>>+
-                #B:main/7_false
+            %33 = phi i32 { i32 %34_ %32 }_ { i32 1_ %25 }
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            mov35/36 [->+<]
+            doing phi stuff
+            %30 = phi i32 { i32 1_ %25 }_ { i32 %34_ %32 }
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov37/38 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%32_true
+                >>>>>>>>>>>>>>>>[-]
+                mov36/28 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >>[-]
             <<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%29_false
+                >>>>>>>>>>>>>>>>[-]
+                mov38/27 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+                <<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
         >>>>>>>]
-    <<<<<<<<<]
-<<<]
+        >#B:%29
+        [
+            -
+            %30 = phi i32 { i32 1_ %25 }_ { i32 %34_ %32 }
+            %31 = icmp eq i32 %30_ i32 0
+            
+            >>>>>>[-]
+            dup27/39/40 >>>>>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov39/27 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %31_ label %45_ label %37
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %38 = phi i32 { i32 %43_ %37 }_ { i32 %30_ %29 }
+            dup27/35/36 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov35/27 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            mov36/37 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%45_true
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>+
+                #B:main/%37_false
+                >>>>>>>>>>>>>>>>>[-]
+                mov37/30 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>]
+        >#B:%32
+        [
+            -
+            %33 = phi i32 { i32 %34_ %32 }_ { i32 1_ %25 }
+            %34 = mul i32 %33_ i32 10
+            
+            >>>>>>>>>>>>>>>>>[-]
+            dup28/38/39 <[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov38/28 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov39/44 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov41/42 [->+<]
+                        <<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>-
+                    <<]
+                    mov42/41 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <[-]
+            >>[-]
+            %35 = udiv i32 %27_ i32 %34
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup26/33/34 >>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov33/26 >>>>>>>[-<<<<<<<+>>>>>>>]
+            dup29/35/36 <<<<[->>>>>>+>+<<<<<<<]
+            mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+            dup36/38/39 >[->>+>+<<<]
+            mov39/36 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov34/40 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>]
+                mov40/34 >>>[-<<<<<<+>>>>>>]
+                dup36/38/39 <<<<[->>+>+<<<]
+                mov39/36 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %36 = icmp ugt i32 %35_ i32 9
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup17/33/34 <<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov34/36 <[->>+<<]
+            >>>>+
+            <<<[
+                -
+                
+                >[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>]
+            br i1 %36_ label %32_ label %29
+            
+            dup21/34/33 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>+<+<<<<<<<<<<<<]
+            mov34/21 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            doing phi stuff
+            %33 = phi i32 { i32 %34_ %32 }_ { i32 1_ %25 }
+            dup29/35/36 <<<<<[->>>>>>+>+<<<<<<<]
+            mov35/29 >>>>>>[-<<<<<<+>>>>>>]
+            mov36/37 >[->+<]
+            doing phi stuff
+            %30 = phi i32 { i32 1_ %25 }_ { i32 %34_ %32 }
+            dup29/38/39 <<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov38/29 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov39/40 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%32_true
+                >>>>>>>>>>>>>>>>[-]
+                mov37/28 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                >>>[-]
+            <<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%29_false
+                >>>>>>>>>>>>>>>>[-]
+                mov40/27 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>]
+        >#B:%37
+        [
+            -
+            %38 = phi i32 { i32 %43_ %37 }_ { i32 %30_ %29 }
+            %39 = udiv i32 %27_ i32 %38
+            
+            >>>>[-]
+            dup26/41/42 >>>>>>>>>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov41/26 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            dup30/43/44 <<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov43/30 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            dup44/46/47 >[->>+>+<<<]
+            mov47/44 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov42/48 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov48/42 >>>[-<<<<<<+>>>>>>]
+                dup44/46/47 <<<<[->>+>+<<<]
+                mov47/44 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %40 = urem i32 %39_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup17/33/34 <<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup35/37/38 [->>+>+<<<]
+            mov38/35 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov34/39 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov39/34 <<[-<<<<<+>>>>>]
+                dup35/37/38 <<<<[->>+>+<<<]
+                mov38/35 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov37/21 >[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>]
+            ]
+            mov36/21 [-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %41 = trunc i32 %40 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup21/33/34 >>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov33/21 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            mov34/17 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %42 = or i8 %41_ i8 48
+            
+            <<<<<<<<<<<<<[-]
+            dup17/33/34 <<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov34/42 <<[->>>>>>>>+<<<<<<<<]
+            mov35/47 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            >>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup46/43/42 >>>>[-<<<+<+>>>>]
+                mov43/46 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup51/48/47 >>>>[-<<<+<+>>>>]
+                mov48/51 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    mov45/37 [-<<<<<<<<+>>>>>>>>]
+                    >>>>>[-]
+                <<<<<]
+                >>>>>[
+                    mov50/37 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                ]
+                dup41/38/39 <<<<<<<<<[-<<<+>+>>]
+                mov38/41 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov38/37 >[-<+>]
+                >]
+                mov37/21 <<[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+                continue?
+                dup42/37/38 >>>>>[-<<<<<+>+>>>>]
+                mov37/42 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup47/37/38 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov37/47 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            call @putchar(i8 %42)
+            
+            putchar intrinsic
+            dup21/33/34 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov33/21 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >.
+            [-]
+            %43 = udiv i32 %38_ i32 10
+            
+            <<<[-]
+            dup30/33/34 <[->>>+>+<<<<]
+            mov33/30 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup35/37/38 [->>+>+<<<]
+            mov38/35 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov34/39 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<+
+                >>>>>[
+                    [-]
+                    <<<<<-
+                >>>>>]
+                mov39/34 >>>[-<<<<<+>>>>>]
+                dup35/37/38 <<<<[->>+>+<<<]
+                mov38/35 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %44 = icmp ult i32 %38_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup30/33/34 >>>>>>>>>>>>>[->>>+>+<<<<]
+            mov33/30 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/36 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %44_ label %45_ label %37
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %38 = phi i32 { i32 %43_ %37 }_ { i32 %30_ %29 }
+            mov31/35 <<<[->>>>+<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%45_true
+                >>>>>>>>>>>>>>>>>>>>>[-]
+            <<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>+
+                #B:main/%37_false
+                >>>>>>>>>>>>>>>>>[-]
+                mov35/30 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>]
+        >#B:%45
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %46 = add i32 %26_ i32 1
+            
+            <<<<[-]
+            dup25/33/34 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov33/25 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov34/32 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup32/37/39 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov39/32 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov36/37 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov37/36 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %47 = icmp eq i32 %46_ i32 8
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup32/33/34 >>>>>>>>>>>>>>>[->+>+<<]
+            mov33/32 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_8
+            ++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov35/37 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %47_ label %24_ label %25
+            
+            dup17/34/33 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov34/17 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %26 = phi i32 { i32 %46_ %45 }_ { i32 0_ %21 }
+            dup32/35/36 <<[->>>+>+<<<<]
+            mov35/32 >>>[-<<<+>>>]
+            mov36/37 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%24_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%25_false
+                >>>>>>>>>>>>>>>[-]
+                mov37/25 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>]
+    <<<<<<<<<<<<<]
+<]
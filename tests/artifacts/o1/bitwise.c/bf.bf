@@ -1,281 +1,200 @@
-+++++++++++++++++++++++++++++++++++++++++++++++
-r1 >
-
-r1 >
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 31670 ops ~1398203 steps
+frame widths:
+  main: 74 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>#B:%ret_lading_pad
+        >>#B:%ret_lading_pad
         [
-            <<<<<-
+            <<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l24 <<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/main
+            >>-
+            l74 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%3
+        #B:%4
+        #B:%7
+        #B:%11
+        #B:%13
+        #B:%14
+        #B:%17
+        #B:%22
+        #B:%30
+        #B:%33
+        #B:%35
+        #B:%36
+        #B:%39
+        #B:%44
+        #B:%52
+        #B:%55
+        #B:%57
+        #B:%58
+        #B:%61
+        #B:%66
+        #B:%74
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%5__%4_}(mult)
+        #%5_=_add_i32_%2__i32_1(mult)
+        #%6_=_icmp_eq_i32_%5__i32_5(mult)
+        #%8_=_phi_i32_{_i32_0__%1_}__{_i32_%75__%74_}(mult)
+        #%9_=_or_i32_%8__i32_%2(mult)
+        #%15_=_phi_i32_{_i32_1__%11_}__{_i32_%19__%17_}(mult)
+        #%18_=_phi_i32_{_i32_%19__%17_}__{_i32_1__%11_}(mult)
+        #%19_=_mul_i32_%18__i32_10(mult)
+        #%21_=_icmp_ugt_i32_%20__i32_9(mult)
+        #%23_=_phi_i32_{_i32_%28__%22_}__{_i32_%15__%14_}(mult)
+        #%28_=_udiv_i32_%23__i32_10(mult)
+        #%31_=_and_i32_%8__i32_%2(mult)
+        #%37_=_phi_i32_{_i32_1__%33_}__{_i32_%41__%39_}(mult)
+        #%40_=_phi_i32_{_i32_%41__%39_}__{_i32_1__%33_}(mult)
+        #%41_=_mul_i32_%40__i32_10(mult)
+        #%45_=_phi_i32_{_i32_%50__%44_}__{_i32_%37__%36_}(mult)
+        #%50_=_udiv_i32_%45__i32_10(mult)
+        #%53_=_xor_i32_%8__i32_%2(mult)
+        #%59_=_phi_i32_{_i32_1__%55_}__{_i32_%63__%61_}(mult)
+        #%62_=_phi_i32_{_i32_%63__%61_}__{_i32_1__%55_}(mult)
+        #%63_=_mul_i32_%62__i32_10(mult)
+        #%67_=_phi_i32_{_i32_%72__%66_}__{_i32_%59__%58_}(mult)
+        #%72_=_udiv_i32_%67__i32_10(mult)
+        #%75_=_add_i32_%8__i32_1(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/17 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
+            br label %1
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov16/18 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
             doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup15/16/17 <<[->+>+<<]
-            mov16/15 >[-<+>]
-            <<<<<<[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup10/18/19 <[->>>>>>>>+>+<<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov19/11 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup11/16/17 <[->>>>>+>+<<<<<<]
-            mov16/11 >>>>>[-<<<<<+>>>>>]
-            mov17/12 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup12/16/17 >>>>>[->>>>+>+<<<<<]
-            mov16/12 >>>>[-<<<<+>>>>]
-            >.
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>>>#constop_0
+            
             [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
+            mov50/26 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >>#B:%1
         [
             -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup10/16/17 <<<[->>>>>>+>+<<<<<<<]
-            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov17/13 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup13/16/17 <[->>>+>+<<<<]
-            mov16/13 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            br label %7
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/21 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
             doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup13/16/17 <<<<<<[->>>+>+<<<<]
-            mov16/13 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %75_ %74 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
+            
+            [-]
+            mov51/29 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<<]
+        >#B:%3
         [
             -
             ret void
+            
             zero all function allocs
+            >>>>>>>>>>>>>>>>>>>>>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            <<<<<<<<<<<<<<-
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
             #dead_frame
             >-
             l1 <
             <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>]
-    <<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l45 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+            r1 
+            l5 
+        >]
+        >#B:%4
         [
             -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>[-]
+            %5 = add i32 %2_ i32 1
+            
+            >>>>>>>>>>>>>>>>>>>>>[-]
+            dup26/52/53 <[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov52/26 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+            >>#constop_1
+            +
+            mov53/27 <[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup27/56/58 <<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov58/27 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov55/56 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov56/55 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %6 = icmp eq i32 %5_ i32 5
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov31/33 <<<<[->>+<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup27/50/51 <[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/27 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_5
+            +++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov52/54 [->>+<<]
             >>>>+
             <<<<<[
                 -
@@ -292,41 +211,183 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
+                <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %6_ label %3_ label %1
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            dup27/52/53 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov52/27 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            mov53/54 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
                 >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
+                #B:main/%3_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
                 >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
+                #B:main/%1_false
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/26 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>]
+        >#B:%7
+        [
+            -
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %75_ %74 }
+            %9 = or i32 %8_ i32 %2
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup29/55/56 <[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov55/29 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup26/57/59 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov57/26 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov56/64 <[->>>>>>>>+<<<<<<<<]
+            mov59/69 >>>[->>>>>>>>>>+<<<<<<<<<<]
+            >>>>>>>+
+            >>>>>+
+            <<<<<<<<<<<<<+
+            [
+                [-]
+                >>>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup68/65/64 >>>>[-<<<+<+>>>>]
+                mov65/68 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup73/70/69 >>>>[-<<<+<+>>>>]
+                mov70/73 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    mov67/60 [-<<<<<<<+>>>>>>>]
+                    >>>>>[-]
+                <<<<<]
+                >>>>>[
+                    mov72/60 [-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                ]
+                dup63/61/62 <<<<<<<<<[-<<+>+>]
+                mov61/63 <<[->>+<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov61/60 >[-<+>]
+                >]
+                mov60/30 <<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                continue?
+                dup64/60/61 >>>>[-<<<<+>+>>>]
+                mov60/64 <<<<[->>>>+<<<<]
+                >[
+                    [-]
+                    <<<+
+                >>>]
+                dup69/60/61 >>>>>>>>[-<<<<<<<<<+>+>>>>>>>>]
+                mov60/69 <<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+                >[
+                    [-]
+                    <<<+
+                >>>]
+                >>+
+            <<<<<]
+            >>>>>>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            %10 = icmp eq i32 %9_ i32 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup30/50/51 >>[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov50/30 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %10_ label %13_ label %11
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%13_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:main/%11_false
+            <<<<<]
+        >>>>]
+        >#B:%11
         [
             -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
+            %12 = icmp ugt i32 %9_ i32 9
+            
+            >>>>>>>>>>>>>>>>>>>>[-]
+            dup30/52/53 >>[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov52/30 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov30/32 <<<<[->>+<<]
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov53/55 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -345,182 +406,190 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %12_ label %17_ label %14
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
+            %18 = phi i32 { i32 %19_ %17 }_ { i32 1_ %11 }
             op_to_reg storing const value in temp address
-            <#constop_1
+            >#constop_1
             +
-            <<<<<<<<<<<<<[-]
-            mov31/18 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov52/53 [->+<]
             doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
+            %15 = phi i32 { i32 1_ %11 }_ { i32 %19_ %17 }
             op_to_reg storing const value in temp address
-            >#constop_1
+            >>#constop_1
             +
-            <<<<<<<<<<<<<<<<[-]
-            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
+            mov54/55 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>+
+                #B:main/%17_true
+                >>>>>>>>>>>>>>>>>>>>>[-]
+                mov53/32 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+                >>[-]
+            <<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
+                >>>>>>>+
+                #B:main/%14_false
+                >>>>>>>>>>>>>>>>>>>>>[-]
+                mov55/31 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>]
+        >#B:%13
         [
             -
             call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
+            br label %30
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<<<]
+        >#B:%14
         [
             -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
+            %15 = phi i32 { i32 1_ %11 }_ { i32 %19_ %17 }
+            %16 = icmp eq i32 %15_ i32 0
+            
+            >>>>>>>>>>>>>>>>>>[-]
+            dup31/50/51 >>>[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov50/31 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %16_ label %30_ label %22
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %23 = phi i32 { i32 %28_ %22 }_ { i32 %15_ %14 }
+            dup31/52/53 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov52/31 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            mov53/54 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>+
+                #B:main/%30_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>+
+                #B:main/%22_false
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/35 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>]
+        >#B:%17
         [
             -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup16/31/32 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            %18 = phi i32 { i32 %19_ %17 }_ { i32 1_ %11 }
+            %19 = mul i32 %18_ i32 10
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/36 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
+            >>>>>>>>>>>>>>>>>>>>>>[-]
+            dup32/55/56 <[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov55/32 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
                 -
-                >>+
-                >>[
+                mov56/61 <[->>>>>+<<<<<]
+                >>>>>[
                     -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov58/59 [->+<]
+                        <<<<<<<<<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov59/58 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
             <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup16/31/32 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov32/22 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup18/33/34 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov33/18 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup34/19/36 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov36/34 >>[-<<+>>]
-            <]
             <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup19/31/32 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup32/34/35 >[->>+>+<<<]
-            mov35/32 >>>[-<<<+>>>]
+            >>[-]
+            %20 = udiv i32 %9_ i32 %19
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup30/50/51 >>[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov50/30 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            dup33/52/53 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov52/33 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            dup53/55/56 >[->>+>+<<<]
+            mov56/53 >>>[-<<<+>>>]
             <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov30/39 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/57 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
                     -
                     <+
-                    >>>>>>[
+                    >>>[
                         -
-                        <<<<<<-
+                        <<<-
                         r1 >
-                    >>>>>>]
+                    >>>]
                     r1 >
                     [
                         l1 <
                     ]
                     l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>[
                     [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov39/30 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup32/34/35 <<<<<<<[->>+>+<<<]
-                mov35/32 >>>[-<<<+>>>]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov57/51 >>>[-<<<<<<+>>>>>>]
+                dup53/55/56 <<<<[->>+>+<<<]
+                mov56/53 >>>[-<<<+>>>]
             <<<<<]
             >>[-]
             >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup20/31/32 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov31/20 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %21 = icmp ugt i32 %20_ i32 9
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/34 <<<<[->>+<<]
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov51/53 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -539,93 +608,104 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %21_ label %17_ label %14
+            
+            dup34/51/50 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov51/34 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup19/31/32 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            %18 = phi i32 { i32 %19_ %17 }_ { i32 1_ %11 }
+            dup33/52/53 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov52/33 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            mov53/54 >[->+<]
             doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup19/33/34 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
+            %15 = phi i32 { i32 1_ %11 }_ { i32 %19_ %17 }
+            dup33/55/56 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov55/33 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            mov56/57 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>+
+                #B:main/%17_true
+                >>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/32 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+                >>>[-]
+            <<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
+                >>>>>>>+
+                #B:main/%14_false
+                >>>>>>>>>>>>>>>>>>>>>[-]
+                mov57/31 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>]
+        >#B:%22
         [
             -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup22/35/36 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov35/22 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup36/38/39 >[->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov30/42 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
+            %23 = phi i32 { i32 %28_ %22 }_ { i32 %15_ %14 }
+            %24 = udiv i32 %9_ i32 %23
+            
+            >>>>>>>>>>>>>>>>[-]
+            dup30/58/59 >>[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov58/30 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup35/60/61 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov60/35 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            dup61/63/64 >[->>+>+<<<]
+            mov64/61 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov59/65 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
                     -
                     <+
-                    >>>>>[
+                    >>>[
                         -
-                        <<<<<-
+                        <<<-
                         r1 >
-                    >>>>>]
+                    >>>]
                     r1 >
                     [
                         l1 <
                     ]
                     l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                     [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov42/30 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup36/38/39 <<<<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov65/59 >>>[-<<<<<<+>>>>>>]
+                dup61/63/64 <<<<[->>+>+<<<]
+                mov64/61 >>>[-<<<+>>>]
+            <<<<<]
             >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup23/31/32 <[->>>>>>>>+>+<<<<<<<<<]
-            mov31/23 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            >>[-]
+            %25 = urem i32 %24_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            dup33/35/36 [->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
+            dup52/54/55 [->>+>+<<<]
+            mov55/52 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
             <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov32/37 <<<<<<<[->>>>>+<<<<<]
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/56 [->>>>>+<<<<<]
                 >>>>>>>+
                 <<<<[
                     -
@@ -642,65 +722,151 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                mov37/32 <<[-<<<<<+>>>>>]
-                dup33/35/36 <<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
+                mov56/51 <<[-<<<<<+>>>>>]
+                dup52/54/55 <<<<[->>+>+<<<]
+                mov55/52 >>>[-<<<+>>>]
             <<<<]
             >>#neg
             [
-                mov35/24 >[-<<<<<<<<<<<+>>>>>>>>>>>]
+                mov54/34 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
                 <[
                     -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
+                    <<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>]
             ]
-            mov34/24 [-<<<<<<<<<<+>>>>>>>>>>]
+            mov53/34 [-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
             <[-]
             >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup24/31/32 <[->>>>>>>+>+<<<<<<<<]
-            mov31/24 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov32/25 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup25/31/32 <[->>>>>>+>+<<<<<<<]
-            mov31/25 >>>>>>[-<<<<<<+>>>>>>]
+            %26 = trunc i32 %25 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup34/50/51 >>>>>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov50/34 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov51/28 >[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %27 = or i8 %26_ i8 48
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov32/26 <[-<<<<<<+>>>>>>]
-            >[
+            >++++++
+            [
                 -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
+                <++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov51/59 <<[->>>>>>>>+<<<<<<<<]
+            mov52/64 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            >>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup63/60/59 >>>>[-<<<+<+>>>>]
+                mov60/63 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup68/65/64 >>>>[-<<<+<+>>>>]
+                mov65/68 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    mov62/54 [-<<<<<<<<+>>>>>>>>]
+                    >>>>>[-]
+                <<<<<]
+                >>>>>[
+                    mov67/54 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                ]
+                dup58/55/56 <<<<<<<<<[-<<<+>+>>]
+                mov55/58 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov55/54 >[-<+>]
+                >]
+                mov54/34 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+                continue?
+                dup59/54/55 >>>>>[-<<<<<+>+>>>>]
+                mov54/59 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup64/54/55 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov54/64 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            call @putchar(i8 %27)
+            
             putchar intrinsic
-            dup26/31/32 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov31/26 >>>>>[-<<<<<+>>>>>]
+            dup34/50/51 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov50/34 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup22/31/32 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %28 = udiv i32 %23_ i32 10
+            
+            <<<<<<<<<<<<<<<[-]
+            dup35/50/51 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov50/35 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            dup33/35/36 [->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
+            dup52/54/55 [->>+>+<<<]
+            mov55/52 >>>[-<<<+>>>]
             <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov32/37 <<<<<<<[->>>>>+<<<<<]
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/56 [->>>>>+<<<<<]
                 >>>>>>>+
                 <<<<[
                     -
@@ -717,40 +883,29 @@ runtime init:
                     l1 <
                 <<]
                 >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
+                <<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>[
                     [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov37/32 >>>[-<<<<<+>>>>>]
-                dup33/35/36 <<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
+                    <<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>]
+                mov56/51 >>>[-<<<<<+>>>>>]
+                dup52/54/55 <<<<[->>+>+<<<]
+                mov55/52 >>>[-<<<+>>>]
             <<<<]
             >[-]
             >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup22/31/32 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov32/28 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup28/31/32 <[->>>+>+<<<<]
-            mov31/28 >>>[-<<<+>>>]
+            %29 = icmp ult i32 %23_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup35/50/51 >>>>>>>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov50/35 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/34 <<<[->+<]
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov52/53 [->+<]
             >>>+
             <<<<[
                 -
@@ -769,246 +924,574 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
+                <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %29_ label %30_ label %22
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov27/22 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
+            %23 = phi i32 { i32 %28_ %22 }_ { i32 %15_ %14 }
+            mov36/52 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>+
+                #B:main/%30_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>]
-    <<<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l46 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%1
-        >#B:%3
-        >#B:%4
-        >#B:%7
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%call_term_for_6
-        >#B:%call_term_for_7
-        >#B:%call_term_for_8
-        >#B:%call_term_for_9
-        >#%2_=_phi_i32_{_i32_0__%0_}__{_i32_%5__%4_}(mult)
-        >#%5_=_add_i32_%2__i32_1(mult)
-        >#%6_=_icmp_eq_i32_%5__i32_5(mult)
-        >#%8_=_phi_i32_{_i32_0__%1_}__{_i32_%12__%call_term_for_9_}(mult)
-        >#%9_=_or_i32_%8__i32_%2(mult)
-        >#%10_=_and_i32_%8__i32_%2(mult)
-        >#%11_=_xor_i32_%8__i32_%2(mult)
-        >#%12_=_add_i32_%8__i32_1(mult)
-        >#%13_=_icmp_eq_i32_%12__i32_5(mult)
-        <<<<<<<<<<<<<<<<<<<<#B:%0
-        [
-            -
-            copy up args
-            br label %1
-            doing phi stuff
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %5_ %4 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>#constop_0
-            
-            <<<<<<<<<[-]
-            mov25/16 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<+
-        <<]
-        >>#B:%1
+                >>>>>>>>>+
+                #B:main/%22_false
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                mov52/35 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>]
+        >#B:%30
         [
             -
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %5_ %4 _
-            br label %7
-            doing phi stuff
-            %8 = phi i32 _ i32 0_ %1 __ _ i32 %12_ %call_term_for_9 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>#constop_0
+            call @putchar(i8 47)
             
-            <<<<<<<[-]
-            mov26/19 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<<<<<<+
-        <<<]
-        >#B:%3
-        [
-            -
-            ret void
-            zero all function allocs
-            >>>>>>>>>[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>-
-            l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>]
-        >#B:%4
-        [
-            -
-            %5 = add i32 %2_ i32 1
-            >>>>>>>>>[-]
-            dup16/27/28 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            putchar intrinsic
             op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov28/17 <[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >[
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_47
+            >+++++
+            [
                 -
-                <<<<<<<<<<<<+
-            >>>>>>>>>>>>]
-            %6 = icmp eq i32 %5_ i32 5
-            <<<<<<<<<<<[-]
-            dup17/25/26 <[->>>>>>>>+>+<<<<<<<<<]
-            mov25/17 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_5
-            +++++
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov27/30 <<<<<[->>>+<<<]
+                <+++++++++
+            >]
+            <++
+            .
+            [-]
+            %31 = and i32 %8_ i32 %2
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup29/50/51 <<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/29 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            dup26/52/53 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov52/26 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov51/59 <[->>>>>>>>+<<<<<<<<]
+            mov53/64 >>[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>+
             >>>>>+
-            <<<<<<[
-                -
-                >>+
+            <<<<<<<<<+
+            [
+                [-]
                 >>[
                     -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<[-]
-            >>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<[-]
-            >>>>>>>>>>]
-            br i1 %6_ label %3_ label %1
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup63/60/59 >>>>[-<<<+<+>>>>]
+                mov60/63 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup68/65/64 >>>>[-<<<+<+>>>>]
+                mov65/68 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov67/54 >>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup58/55/56 <<<<<<<<<[-<<<+>+>>]
+                mov55/58 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov55/54 >[-<+>]
+                >]
+                mov54/37 <<[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                continue?
+                dup59/54/55 >>>>>[-<<<<<+>+>>>>]
+                mov54/59 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup64/54/55 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov54/64 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            %32 = icmp eq i32 %31_ i32 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup37/50/51 >>>>>>>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov50/37 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %32_ label %35_ label %33
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>+
+                #B:main/%35_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>+
+                #B:main/%33_false
+            <<<<<<<<<<<]
+        >>>>>>>>>>]
+        >#B:%33
+        [
+            -
+            %34 = icmp ugt i32 %31_ i32 9
+            
+            >>>>>>>>>>>>>>[-]
+            dup37/52/53 >>>>>>>>>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov52/37 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov53/55 <[->>+<<]
+            >>>>+
+            <<<[
+                -
+                
+                >[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %34_ label %39_ label %36
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %5_ %4 _
-            dup17/25/26 <<<<<<<<<<<[->>>>>>>>+>+<<<<<<<<<]
-            mov25/17 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            <<<<<<<<<[-]
-            mov26/16 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>[
+            %40 = phi i32 { i32 %41_ %39 }_ { i32 1_ %33 }
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            mov52/53 [->+<]
+            doing phi stuff
+            %37 = phi i32 { i32 1_ %33 }_ { i32 %41_ %39 }
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov54/55 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<-
-                >>+
-                #B:main/3_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>+
+                #B:main/%39_true
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov53/39 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                >>[-]
+            <<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >+
-                #B:main/1_false
+                >>>>>>>>>>>>>+
+                #B:main/%36_false
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov55/38 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                <<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>]
+        >#B:%35
+        [
+            -
+            call @putchar(i8 48)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            br label %52
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<<<]
+        >#B:%36
+        [
+            -
+            %37 = phi i32 { i32 1_ %33 }_ { i32 %41_ %39 }
+            %38 = icmp eq i32 %37_ i32 0
+            
+            >>>>>>>>>>>>[-]
+            dup38/50/51 >>>>>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov50/38 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %38_ label %52_ label %44
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %45 = phi i32 { i32 %50_ %44 }_ { i32 %37_ %36 }
+            dup38/52/53 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov52/38 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            mov53/54 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>+
+                #B:main/%52_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>+
+                #B:main/%44_false
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/41 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>]
+        >#B:%39
+        [
+            -
+            %40 = phi i32 { i32 %41_ %39 }_ { i32 1_ %33 }
+            %41 = mul i32 %40_ i32 10
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup39/55/56 <[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov55/39 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov56/61 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
+                    <<[
+                        -
+                        mov58/59 [->+<]
+                        <<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov59/58 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <[-]
+            >>[-]
+            %42 = udiv i32 %31_ i32 %41
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup37/50/51 >>>>>>>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov50/37 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            dup40/52/53 <<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov52/40 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            dup53/55/56 >[->>+>+<<<]
+            mov56/53 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/57 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov57/51 >>>[-<<<<<<+>>>>>>]
+                dup53/55/56 <<<<[->>+>+<<<]
+                mov56/53 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %43 = icmp ugt i32 %42_ i32 9
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov51/53 <[->>+<<]
+            >>>>+
+            <<<[
+                -
+                
+                >[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
             <]
-        >>>]
-        >#B:%7
+            >>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %43_ label %39_ label %36
+            
+            dup34/51/50 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov51/34 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %40 = phi i32 { i32 %41_ %39 }_ { i32 1_ %33 }
+            dup40/52/53 <<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov52/40 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            mov53/54 >[->+<]
+            doing phi stuff
+            %37 = phi i32 { i32 1_ %33 }_ { i32 %41_ %39 }
+            dup40/55/56 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov55/40 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            mov56/57 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>+
+                #B:main/%39_true
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/39 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+                >>>[-]
+            <<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>+
+                #B:main/%36_false
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov57/38 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>]
+        >#B:%44
         [
             -
-            %8 = phi i32 _ i32 0_ %1 __ _ i32 %12_ %call_term_for_9 _
-            %9 = or i32 %8_ i32 %2
-            >>>>>>>>>>>[-]
-            dup19/27/28 <[->>>>>>>>+>+<<<<<<<<<]
-            mov27/19 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            dup16/29/30 <<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov29/16 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            >>>>#dub_scratch
-            <<#loop_ctrl
-            >#nth
-            >>>>#op0div
-            >>>>>#op1div
-            <<<<<#op0_v
-            >#op0_0
-            >#op0_1
-            >#op0_h
-            >#op0_l
-            >#op1_v
-            >#op1_0
-            >#op1_1
-            >#op1_h
-            >#op1_l
-            mov28/36 <<<<<<<<<<<<<<<<<[->>>>>>>>+<<<<<<<<]
-            mov30/41 >>[->>>>>>>>>>>+<<<<<<<<<<<]
-            >>>>>>>>+
+            %45 = phi i32 { i32 %50_ %44 }_ { i32 %37_ %36 }
+            %46 = udiv i32 %31_ i32 %45
+            
+            >>>>>>>>>>[-]
+            dup37/58/59 >>>>>>>>>[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov58/37 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            dup41/60/61 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov60/41 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            dup61/63/64 >[->>+>+<<<]
+            mov64/61 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov59/65 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov65/59 >>>[-<<<<<<+>>>>>>]
+                dup61/63/64 <<<<[->>+>+<<<]
+                mov64/61 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %47 = urem i32 %46_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup52/54/55 [->>+>+<<<]
+            mov55/52 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/56 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov56/51 <<[-<<<<<+>>>>>]
+                dup52/54/55 <<<<[->>+>+<<<]
+                mov55/52 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov54/34 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>]
+            ]
+            mov53/34 [-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %48 = trunc i32 %47 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup34/50/51 >>>>>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov50/34 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov51/28 >[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %49 = or i8 %48_ i8 48
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov51/59 <<[->>>>>>>>+<<<<<<<<]
+            mov52/64 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            >>>>>>>>>+
             >>>>>+
-            <<<<<<<<<<<<+
+            <<<<<<<<<+
             [
                 [-]
-                >>>>>[
+                >>[
                     -
                     >>>+
                     <<<[
@@ -1018,8 +1501,8 @@ runtime init:
                     >[
                     <]
                 <]
-                dup40/37/36 >>>>[-<<<+<+>>>>]
-                mov37/40 <<<[->>>+<<<]
+                dup63/60/59 >>>>[-<<<+<+>>>>]
+                mov60/63 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
@@ -1034,144 +1517,192 @@ runtime init:
                     >[
                     <]
                 <]
-                dup45/42/41 >>>>[-<<<+<+>>>>]
-                mov42/45 <<<[->>>+<<<]
+                dup68/65/64 >>>>[-<<<+<+>>>>]
+                mov65/68 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
                 <<<<<<[
-                    mov39/33 [-<<<<<<+>>>>>>]
+                    mov62/54 [-<<<<<<<<+>>>>>>>>]
                     >>>>>[-]
                 <<<<<]
                 >>>>>[
-                    mov44/33 [-<<<<<<<<<<<+>>>>>>>>>>>]
+                    mov67/54 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
                 ]
-                dup32/34/35 <<<<<<<<<<<<[->>+>+<<<]
-                mov34/32 >>[-<<+>>]
+                dup58/55/56 <<<<<<<<<[-<<<+>+>>]
+                mov55/58 <<<[->>>+<<<]
                 >[
                     -
                     <<[
                         -
                         >++
                     <]
-                    mov34/33 >[-<+>]
+                    mov55/54 >[-<+>]
                 >]
-                mov33/20 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                mov54/34 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
                 continue?
-                dup36/33/34 >>>[-<<<+>+>>]
-                mov33/36 <<<[->>>+<<<]
+                dup59/54/55 >>>>>[-<<<<<+>+>>>>]
+                mov54/59 <<<<<[->>>>>+<<<<<]
                 >[
                     [-]
-                    <<<+
-                >>>]
-                dup41/33/34 >>>>>>>[-<<<<<<<<+>+>>>>>>>]
-                mov33/41 <<<<<<<<[->>>>>>>>+<<<<<<<<]
+                    >>+
+                <<]
+                dup64/54/55 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov54/64 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
                 >[
                     [-]
-                    <<<+
-                >>>]
-                <<+
+                    >>+
+                <<]
+                >>>+
             <]
-            >>>>>>>-
+            >>>>-
             >>>>>-
-            <<<<<<<<<<<[-]
-            call @putdec(i32 %9)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_4
-            +
-            stack_width 46
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup20/25/26 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+>+<<<<<<]
-            mov25/20 >>>>>[-<<<<<+>>>>>]
-            mov26/52 >[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/54/55 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov55/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r46 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
-            +
-            >>#putdec
-            +
-            >>#putdec/b0
-            +
-        >>>>>]
-        >#B:%call_term_for_4
-        [
-            -
-            call @putstr(getelementptr inbounds (_2 x i8_* @_str_ i64 0_ i64 0))
-            enable next block when we return
-            >#caller/%call_term_for_5
-            +
-            stack_width 46
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            <<<<<<<<[-]
+            call @putchar(i8 %49)
+            
+            putchar intrinsic
+            dup34/50/51 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov50/34 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            %50 = udiv i32 %45_ i32 10
+            
+            <<<<<<<<<[-]
+            dup41/50/51 <[->>>>>>>>>+>+<<<<<<<<<<]
+            mov50/41 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<#constop_0
+            >>#constop_10
+            ++++++++++
+            dup52/54/55 [->>+>+<<<]
+            mov55/52 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/56 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<-
+                >>>>>>>>>>>]
+                mov56/51 >>>[-<<<<<+>>>>>]
+                dup52/54/55 <<<<[->>+>+<<<]
+                mov55/52 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %51 = icmp ult i32 %45_ i32 10
             
-            mov25/52 [->>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/54/55 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov55/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r46 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putstr
-            +
-            >#putstr
-            +
-            >>>#putstr/b0
-            +
-        >>>>>>]
-        >#B:%call_term_for_5
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup41/50/51 >>>>>>>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov50/41 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov52/53 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %51_ label %52_ label %44
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %45 = phi i32 { i32 %50_ %44 }_ { i32 %37_ %36 }
+            mov42/52 <<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>+
+                #B:main/%52_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>+
+                #B:main/%44_false
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                mov52/41 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>]
+        >#B:%52
         [
             -
-            %10 = and i32 %8_ i32 %2
-            >>>>>>>>>>[-]
-            dup19/25/26 <<[->>>>>>+>+<<<<<<<]
-            mov25/19 >>>>>>[-<<<<<<+>>>>>>]
-            dup16/27/28 <<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >>#dub_scratch
-            >>>#loop_ctrl
-            >#nth
-            >#op0div
-            >>>>>#op1div
-            <<<<<#op0_v
-            >#op0_0
-            >#op0_1
-            >#op0_h
-            >#op0_l
-            >#op1_v
-            >#op1_0
-            >#op1_1
-            >#op1_h
-            >#op1_l
-            mov26/34 <<<<<<<<<<<<<<<<<[->>>>>>>>+<<<<<<<<]
-            mov28/39 >>[->>>>>>>>>>>+<<<<<<<<<<<]
+            call @putchar(i8 47)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_47
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <++
+            .
+            [-]
+            %53 = xor i32 %8_ i32 %2
+            
+            <<<<<<<<<<[-]
+            dup29/50/51 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/29 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            dup26/52/53 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov52/26 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov51/59 <[->>>>>>>>+<<<<<<<<]
+            mov53/64 >>[->>>>>>>>>>>+<<<<<<<<<<<]
             >>>>>>>>+
             >>>>>+
             <<<<<<<<<+
@@ -1187,8 +1718,8 @@ runtime init:
                     >[
                     <]
                 <]
-                dup38/35/34 >>>>[-<<<+<+>>>>]
-                mov35/38 <<<[->>>+<<<]
+                dup63/60/59 >>>>[-<<<+<+>>>>]
+                mov60/63 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
@@ -1203,143 +1734,484 @@ runtime init:
                     >[
                     <]
                 <]
-                dup43/40/39 >>>>[-<<<+<+>>>>]
-                mov40/43 <<<[->>>+<<<]
+                dup68/65/64 >>>>[-<<<+<+>>>>]
+                mov65/68 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
                 <<<<<<[
                     -
-                    mov42/29 >>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                    <<<<<<<<+
+                    >>>>>>>>>>>>>[
+                        -
+                        <<<<<<<<<<<<<-
+                    >>>>>>>>>>>>>]
                 <<<<<]
-                >>>>>[-]
-                dup33/30/31 <<<<<<<<<[-<<<+>+>>]
-                mov30/33 <<<[->>>+<<<]
+                >>>>>[
+                    -
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>[
+                        -
+                        <<<<<<<<-
+                    >>>>>>>>]
+                >>>>>]
+                dup58/55/56 <<<<<<<<<[-<<<+>+>>]
+                mov55/58 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov55/54 >[-<+>]
+                >]
+                mov54/43 <<[-<<<<<<<<<<<+>>>>>>>>>>>]
+                continue?
+                dup59/54/55 >>>>>[-<<<<<+>+>>>>]
+                mov54/59 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup64/54/55 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov54/64 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>-
+            <<<<<<<<[-]
+            %54 = icmp eq i32 %53_ i32 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup43/50/51 >>>>>>>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov50/43 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %54_ label %57_ label %55
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>+
+                #B:main/%57_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>+
+                #B:main/%55_false
+            <<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>]
+        >#B:%55
+        [
+            -
+            %56 = icmp ugt i32 %53_ i32 9
+            
+            >>>>>>>>[-]
+            dup43/52/53 >>>>>>>>>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov52/43 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov53/55 <[->>+<<]
+            >>>>+
+            <<<[
+                -
+                
                 >[
                     -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %56_ label %61_ label %58
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %62 = phi i32 { i32 %63_ %61 }_ { i32 1_ %55 }
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            mov52/53 [->+<]
+            doing phi stuff
+            %59 = phi i32 { i32 1_ %55 }_ { i32 %63_ %61 }
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov54/55 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>+
+                #B:main/%61_true
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov53/45 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >>[-]
+            <<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>+
+                #B:main/%58_false
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov55/44 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+                <<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>]
+        >#B:%57
+        [
+            -
+            call @putchar(i8 48)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            br label %74
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<<<]
+        >#B:%58
+        [
+            -
+            %59 = phi i32 { i32 1_ %55 }_ { i32 %63_ %61 }
+            %60 = icmp eq i32 %59_ i32 0
+            
+            >>>>>>[-]
+            dup44/50/51 >>>>>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov50/44 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %60_ label %74_ label %66
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %67 = phi i32 { i32 %72_ %66 }_ { i32 %59_ %58 }
+            dup44/52/53 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov52/44 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            mov53/54 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%74_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%66_false
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/47 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>]
+        >#B:%61
+        [
+            -
+            %62 = phi i32 { i32 %63_ %61 }_ { i32 1_ %55 }
+            %63 = mul i32 %62_ i32 10
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup45/55/56 <[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov55/45 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            >+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov56/61 <[->>>>>+<<<<<]
+                >>>>>[
+                    -
+                    <<<<<+
+                    >>>>+
                     <<[
                         -
-                        >++
-                    <]
-                    mov30/29 >[-<+>]
+                        mov58/59 [->+<]
+                        <<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>-
+                    <<]
+                    mov59/58 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
                 >]
-                mov29/21 <<[-<<<<<<<<+>>>>>>>>]
-                continue?
-                dup34/29/30 >>>>>[-<<<<<+>+>>>>]
-                mov29/34 <<<<<[->>>>>+<<<<<]
-                >[
-                    [-]
-                    >>+
+            <<<<]
+            <[-]
+            >>[-]
+            %64 = udiv i32 %53_ i32 %63
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup43/50/51 >>>>>>>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov50/43 >>>>>>>[-<<<<<<<+>>>>>>>]
+            dup46/52/53 <<<<[->>>>>>+>+<<<<<<<]
+            mov52/46 >>>>>>[-<<<<<<+>>>>>>]
+            dup53/55/56 >[->>+>+<<<]
+            mov56/53 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/57 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
                 <<]
-                dup39/29/30 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
-                mov29/39 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
-                >[
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>[
                     [-]
-                    >>+
-                <<]
-                >>>+
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov57/51 >>>[-<<<<<<+>>>>>>]
+                dup53/55/56 <<<<[->>+>+<<<]
+                mov56/53 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %65 = icmp ugt i32 %64_ i32 9
+            
+            <<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_9
+            +++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov51/53 <[->>+<<]
+            >>>>+
+            <<<[
+                -
+                
+                >[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
             <]
-            >>>>-
-            >>>>>-
-            <<<<<<<<[-]
-            call @putdec(i32 %10)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            stack_width 46
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup21/25/26 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>+<<<<<]
-            mov25/21 >>>>[-<<<<+>>>>]
-            mov26/52 >[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/54/55 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov55/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r46 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
-            +
-            >>#putdec
-            +
-            >>#putdec/b0
-            +
-        >>>>>>>]
-        >#B:%call_term_for_6
+            >>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            br i1 %65_ label %61_ label %58
+            
+            dup34/51/50 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov51/34 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %62 = phi i32 { i32 %63_ %61 }_ { i32 1_ %55 }
+            dup46/52/53 <<<<<[->>>>>>+>+<<<<<<<]
+            mov52/46 >>>>>>[-<<<<<<+>>>>>>]
+            mov53/54 >[->+<]
+            doing phi stuff
+            %59 = phi i32 { i32 1_ %55 }_ { i32 %63_ %61 }
+            dup46/55/56 <<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov56/57 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>+
+                #B:main/%61_true
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/45 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+                >>>[-]
+            <<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>+
+                #B:main/%58_false
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov57/44 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>]
+        >#B:%66
         [
             -
-            call @putstr(getelementptr inbounds (_2 x i8_* @_str_ i64 0_ i64 0))
-            enable next block when we return
-            >#caller/%call_term_for_7
-            +
-            stack_width 46
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
+            %67 = phi i32 { i32 %72_ %66 }_ { i32 %59_ %58 }
+            %68 = udiv i32 %53_ i32 %67
+            
+            >>>>[-]
+            dup43/58/59 >>>>>>>>>>>>>>>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov58/43 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            dup47/60/61 <<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov60/47 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            dup61/63/64 >[->>+>+<<<]
+            mov64/61 >>>[-<<<+>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov59/65 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                mov65/59 >>>[-<<<<<<+>>>>>>]
+                dup61/63/64 <<<<[->>+>+<<<]
+                mov64/61 >>>[-<<<+>>>]
+            <<<<<]
+            >>[-]
+            >>[-]
+            %69 = urem i32 %68_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<#constop_0
+            >>#constop_10
+            ++++++++++
+            dup52/54/55 [->>+>+<<<]
+            mov55/52 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/56 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov56/51 <<[-<<<<<+>>>>>]
+                dup52/54/55 <<<<[->>+>+<<<]
+                mov55/52 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov54/34 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>]
+            ]
+            mov53/34 [-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %70 = trunc i32 %69 to i8
             
-            mov25/52 [->>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/54/55 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov55/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r46 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putstr
-            +
-            >#putstr
-            +
-            >>>#putstr/b0
-            +
-        >>>>>>>>]
-        >#B:%call_term_for_7
-        [
-            -
-            %11 = xor i32 %8_ i32 %2
-            >>>>>>>>>[-]
-            dup19/25/26 <<<[->>>>>>+>+<<<<<<<]
-            mov25/19 >>>>>>[-<<<<<<+>>>>>>]
-            dup16/27/28 <<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            >>#dub_scratch
-            >>>#loop_ctrl
-            >#nth
-            >#op0div
-            >>>>>#op1div
-            <<<<<#op0_v
-            >#op0_0
-            >#op0_1
-            >#op0_h
-            >#op0_l
-            >#op1_v
-            >#op1_0
-            >#op1_1
-            >#op1_h
-            >#op1_l
-            mov26/34 <<<<<<<<<<<<<<<<<[->>>>>>>>+<<<<<<<<]
-            mov28/39 >>[->>>>>>>>>>>+<<<<<<<<<<<]
-            >>>>>>>>+
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup34/50/51 >>>>>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov50/34 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov51/28 >[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            %71 = or i8 %70_ i8 48
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup28/50/51 <<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/28 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov51/59 <<[->>>>>>>>+<<<<<<<<]
+            mov52/64 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            >>>>>>>>>+
             >>>>>+
             <<<<<<<<<+
             [
@@ -1354,8 +2226,8 @@ runtime init:
                     >[
                     <]
                 <]
-                dup38/35/34 >>>>[-<<<+<+>>>>]
-                mov35/38 <<<[->>>+<<<]
+                dup63/60/59 >>>>[-<<<+<+>>>>]
+                mov60/63 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
@@ -1370,48 +2242,39 @@ runtime init:
                     >[
                     <]
                 <]
-                dup43/40/39 >>>>[-<<<+<+>>>>]
-                mov40/43 <<<[->>>+<<<]
+                dup68/65/64 >>>>[-<<<+<+>>>>]
+                mov65/68 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
                 <<<<<<[
-                    -
-                    <<<<<<<<+
-                    >>>>>>>>>>>>>[
-                        -
-                        <<<<<<<<<<<<<-
-                    >>>>>>>>>>>>>]
+                    mov62/54 [-<<<<<<<<+>>>>>>>>]
+                    >>>>>[-]
                 <<<<<]
                 >>>>>[
-                    -
-                    <<<<<<<<<<<<<+
-                    >>>>>>>>[
-                        -
-                        <<<<<<<<-
-                    >>>>>>>>]
-                >>>>>]
-                dup33/30/31 <<<<<<<<<[-<<<+>+>>]
-                mov30/33 <<<[->>>+<<<]
+                    mov67/54 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                ]
+                dup58/55/56 <<<<<<<<<[-<<<+>+>>]
+                mov55/58 <<<[->>>+<<<]
                 >[
                     -
                     <<[
                         -
                         >++
                     <]
-                    mov30/29 >[-<+>]
+                    mov55/54 >[-<+>]
                 >]
-                mov29/22 <<[-<<<<<<<+>>>>>>>]
+                mov54/34 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
                 continue?
-                dup34/29/30 >>>>>[-<<<<<+>+>>>>]
-                mov29/34 <<<<<[->>>>>+<<<<<]
+                dup59/54/55 >>>>>[-<<<<<+>+>>>>]
+                mov54/59 <<<<<[->>>>>+<<<<<]
                 >[
                     [-]
                     >>+
                 <<]
-                dup39/29/30 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
-                mov29/39 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
+                dup64/54/55 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov54/64 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
                 >[
                     [-]
                     >>+
@@ -1421,79 +2284,170 @@ runtime init:
             >>>>-
             >>>>>-
             <<<<<<<<[-]
-            call @putdec(i32 %11)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<#caller/%call_term_for_8
-            +
-            stack_width 46
-            ret_pad_width 5
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup22/25/26 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]
-            mov25/22 >>>[-<<<+>>>]
-            mov26/52 >[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/54/55 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov55/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r46 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<#===FRAME_putdec
-            +
-            >>#putdec
-            +
-            >>#putdec/b0
-            +
-        >>>>>>>>>]
-        >#B:%call_term_for_8
+            call @putchar(i8 %71)
+            
+            putchar intrinsic
+            dup34/50/51 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov50/34 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            %72 = udiv i32 %67_ i32 10
+            
+            <<<[-]
+            dup47/50/51 <[->>>+>+<<<<]
+            mov50/47 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup52/54/55 [->>+>+<<<]
+            mov55/52 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov51/56 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<+
+                >>>>>[
+                    [-]
+                    <<<<<-
+                >>>>>]
+                mov56/51 >>>[-<<<<<+>>>>>]
+                dup52/54/55 <<<<[->>+>+<<<]
+                mov55/52 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %73 = icmp ult i32 %67_ i32 10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup47/50/51 >>>>>>>>>>>>>>>>>>>[->>>+>+<<<<]
+            mov50/47 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov52/53 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %73_ label %74_ label %66
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %67 = phi i32 { i32 %72_ %66 }_ { i32 %59_ %58 }
+            mov48/52 <<<[->>>>+<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%74_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>>+
+                #B:main/%66_false
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                mov52/47 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>>]
+        >#B:%74
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_9
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_9
-        [
-            -
-            %12 = add i32 %8_ i32 1
-            >>>>>>>>[-]
-            dup19/25/26 <<<<[->>>>>>+>+<<<<<<<]
-            mov25/19 >>>>>>[-<<<<<<+>>>>>>]
+            %75 = add i32 %8_ i32 1
+            
+            <<<<[-]
+            dup29/50/51 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov50/29 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov26/23 <[-<<<+>>>]
-            >[
+            mov51/49 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup49/54/56 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
                 -
-                <<<<+
-            >>>>]
-            %13 = icmp eq i32 %12_ i32 5
-            <<<[-]
-            dup23/25/26 <[->>+>+<<<]
-            mov25/23 >>[-<<+>>]
+                <-
+            >]
+            mov56/49 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov53/54 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov54/53 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %76 = icmp eq i32 %75_ i32 5
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup49/50/51 >>>>>>>>>>>>>>>>>>>>>[->+>+<<]
+            mov50/49 >[-<+>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov27/29 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov52/54 [->>+<<]
             >>>>+
             <<<<<[
                 -
@@ -1510,34 +2464,39 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<+
-            >>>>>[
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 [-]
-                <<<<<[-]
-            >>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>]
             <[
                 [-]
-                <<<<[-]
-            >>>>]
-            br i1 %13_ label %4_ label %7
+                <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            br i1 %76_ label %4_ label %7
+            
+            dup28/51/50 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<<<<]
+            mov51/28 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
             doing phi stuff
-            %8 = phi i32 _ i32 0_ %1 __ _ i32 %12_ %call_term_for_9 _
-            dup23/25/26 <<<<<[->>+>+<<<]
-            mov25/23 >>[-<<+>>]
-            <<<<<<[-]
-            mov26/19 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %75_ %74 }
+            dup49/52/53 <<[->>>+>+<<<<]
+            mov52/49 >>>[-<<<+>>>]
+            mov53/54 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
                 >>>+
-                #B:main/4_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<[
+                #B:main/%4_true
+                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
                 >>>>+
-                #B:main/7_false
-            <<<<]
-        >>>>>>>>>>]
-    <<<<<<<<<<<<]
-<<<]
+                #B:main/%7_false
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+                mov54/29 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<<<<<<<]
+<]
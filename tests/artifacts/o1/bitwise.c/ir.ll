@@ -1,117 +1,222 @@
 ; ModuleID = './tests/artifacts/o1/bitwise.c/ir.bc'
 source_filename = "./tests/cases/bitwise.c"
 target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
-target triple = "x86_64-unknown-linux-gnu"
-
-@.str = private unnamed_addr constant [2 x i8] c"/\00", align 1
-
-; Function Attrs: nounwind sspstrong uwtable
-define void @putstr(i8* %0) local_unnamed_addr #0 {
-  %2 = icmp eq i8* %0, null
-  br i1 %2, label %9, label %3
-
-3:                                                ; preds = %1, %3
-  %4 = phi i8* [ %7, %3 ], [ %0, %1 ]
-  %5 = ptrtoint i8* %4 to i64
-  %6 = trunc i64 %5 to i8
-  call void @putchar(i8 zeroext %6) #2
-  %7 = getelementptr i8, i8* %4, i64 1
-  %8 = icmp eq i8* %7, null
-  br i1 %8, label %9, label %3, !llvm.loop !3
-
-9:                                                ; preds = %3, %1
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 {
+  %2 = load i8, i8* %0, align 1, !tbaa !5
+  %3 = icmp eq i8 %2, 0
+  br i1 %3, label %10, label %4
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @putchar(i8 noundef zeroext %5) #2
+  %7 = getelementptr inbounds i8, i8* %6, i64 1
+  %8 = load i8, i8* %7, align 1, !tbaa !5
+  %9 = icmp eq i8 %8, 0
+  br i1 %9, label %10, label %4, !llvm.loop !8
+
+10:                                               ; preds = %4, %1
   ret void
 }
 
-declare void @putchar(i8 zeroext) local_unnamed_addr #1
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
 
-; Function Attrs: nounwind sspstrong uwtable
-define void @putdec(i32 %0) local_unnamed_addr #0 {
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 {
   %2 = icmp eq i32 %0, 0
   br i1 %2, label %5, label %3
 
 3:                                                ; preds = %1
-  %4 = icmp sgt i32 %0, 10
+  %4 = icmp ugt i32 %0, 9
   br i1 %4, label %9, label %6
 
 5:                                                ; preds = %1
-  call void @putchar(i8 zeroext 48) #2
-  br label %23
+  call void @putchar(i8 noundef zeroext 48) #2
+  br label %22
 
 6:                                                ; preds = %9, %3
   %7 = phi i32 [ 1, %3 ], [ %11, %9 ]
   %8 = icmp eq i32 %7, 0
-  br i1 %8, label %23, label %14
+  br i1 %8, label %22, label %14
 
-9:                                                ; preds = %3, %9
+9:                                                ; preds = %9, %3
   %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
   %11 = mul i32 %10, 10
-  %12 = sdiv i32 %0, %11
-  %13 = icmp sgt i32 %12, 10
-  br i1 %13, label %9, label %6, !llvm.loop !6
+  %12 = udiv i32 %0, %11
+  %13 = icmp ugt i32 %12, 9
+  br i1 %13, label %9, label %6, !llvm.loop !11
 
-14:                                               ; preds = %6, %14
+14:                                               ; preds = %14, %6
   %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
-  %16 = sdiv i32 %0, %15
-  %17 = srem i32 %16, 10
+  %16 = udiv i32 %0, %15
+  %17 = urem i32 %16, 10
   %18 = trunc i32 %17 to i8
-  %19 = add i8 %18, 48
-  call void @putchar(i8 zeroext %19) #2
-  %20 = sdiv i32 %15, 10
-  %21 = add i32 %15, 9
-  %22 = icmp ult i32 %21, 19
-  br i1 %22, label %23, label %14, !llvm.loop !7
-
-23:                                               ; preds = %14, %6, %5
+  %19 = or i8 %18, 48
+  call void @putchar(i8 noundef zeroext %19) #2
+  %20 = udiv i32 %15, 10
+  %21 = icmp ult i32 %15, 10
+  br i1 %21, label %22, label %14, !llvm.loop !12
+
+22:                                               ; preds = %14, %6, %5
   ret void
 }
 
-; Function Attrs: nounwind sspstrong uwtable
-define void @main() local_unnamed_addr #0 {
+; Function Attrs: nounwind uwtable
+define dso_local void @main() local_unnamed_addr #0 {
   br label %1
 
-1:                                                ; preds = %0, %4
+1:                                                ; preds = %4, %0
   %2 = phi i32 [ 0, %0 ], [ %5, %4 ]
   br label %7
 
 3:                                                ; preds = %4
   ret void
 
-4:                                                ; preds = %7
+4:                                                ; preds = %74
   %5 = add nuw nsw i32 %2, 1
   %6 = icmp eq i32 %5, 5
-  br i1 %6, label %3, label %1, !llvm.loop !8
+  br i1 %6, label %3, label %1, !llvm.loop !13
 
-7:                                                ; preds = %1, %7
-  %8 = phi i32 [ 0, %1 ], [ %12, %7 ]
+7:                                                ; preds = %74, %1
+  %8 = phi i32 [ 0, %1 ], [ %75, %74 ]
   %9 = or i32 %8, %2
-  call void @putdec(i32 %9)
-  call void @putstr(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @.str, i64 0, i64 0))
-  %10 = and i32 %8, %2
-  call void @putdec(i32 %10)
-  call void @putstr(i8* getelementptr inbounds ([2 x i8], [2 x i8]* @.str, i64 0, i64 0))
-  %11 = xor i32 %8, %2
-  call void @putdec(i32 %11)
-  call void @putchar(i8 zeroext 44) #2
-  %12 = add nuw nsw i32 %8, 1
-  %13 = icmp eq i32 %12, 5
-  br i1 %13, label %4, label %7, !llvm.loop !9
+  %10 = icmp eq i32 %9, 0
+  br i1 %10, label %13, label %11
+
+11:                                               ; preds = %7
+  %12 = icmp ugt i32 %9, 9
+  br i1 %12, label %17, label %14
+
+13:                                               ; preds = %7
+  call void @putchar(i8 noundef zeroext 48) #2
+  br label %30
+
+14:                                               ; preds = %17, %11
+  %15 = phi i32 [ 1, %11 ], [ %19, %17 ]
+  %16 = icmp eq i32 %15, 0
+  br i1 %16, label %30, label %22
+
+17:                                               ; preds = %17, %11
+  %18 = phi i32 [ %19, %17 ], [ 1, %11 ]
+  %19 = mul i32 %18, 10
+  %20 = udiv i32 %9, %19
+  %21 = icmp ugt i32 %20, 9
+  br i1 %21, label %17, label %14, !llvm.loop !11
+
+22:                                               ; preds = %22, %14
+  %23 = phi i32 [ %28, %22 ], [ %15, %14 ]
+  %24 = udiv i32 %9, %23
+  %25 = urem i32 %24, 10
+  %26 = trunc i32 %25 to i8
+  %27 = or i8 %26, 48
+  call void @putchar(i8 noundef zeroext %27) #2
+  %28 = udiv i32 %23, 10
+  %29 = icmp ult i32 %23, 10
+  br i1 %29, label %30, label %22, !llvm.loop !12
+
+30:                                               ; preds = %22, %14, %13
+  call void @putchar(i8 noundef zeroext 47) #2
+  %31 = and i32 %8, %2
+  %32 = icmp eq i32 %31, 0
+  br i1 %32, label %35, label %33
+
+33:                                               ; preds = %30
+  %34 = icmp ugt i32 %31, 9
+  br i1 %34, label %39, label %36
+
+35:                                               ; preds = %30
+  call void @putchar(i8 noundef zeroext 48) #2
+  br label %52
+
+36:                                               ; preds = %39, %33
+  %37 = phi i32 [ 1, %33 ], [ %41, %39 ]
+  %38 = icmp eq i32 %37, 0
+  br i1 %38, label %52, label %44
+
+39:                                               ; preds = %39, %33
+  %40 = phi i32 [ %41, %39 ], [ 1, %33 ]
+  %41 = mul i32 %40, 10
+  %42 = udiv i32 %31, %41
+  %43 = icmp ugt i32 %42, 9
+  br i1 %43, label %39, label %36, !llvm.loop !11
+
+44:                                               ; preds = %44, %36
+  %45 = phi i32 [ %50, %44 ], [ %37, %36 ]
+  %46 = udiv i32 %31, %45
+  %47 = urem i32 %46, 10
+  %48 = trunc i32 %47 to i8
+  %49 = or i8 %48, 48
+  call void @putchar(i8 noundef zeroext %49) #2
+  %50 = udiv i32 %45, 10
+  %51 = icmp ult i32 %45, 10
+  br i1 %51, label %52, label %44, !llvm.loop !12
+
+52:                                               ; preds = %44, %36, %35
+  call void @putchar(i8 noundef zeroext 47) #2
+  %53 = xor i32 %8, %2
+  %54 = icmp eq i32 %53, 0
+  br i1 %54, label %57, label %55
+
+55:                                               ; preds = %52
+  %56 = icmp ugt i32 %53, 9
+  br i1 %56, label %61, label %58
+
+57:                                               ; preds = %52
+  call void @putchar(i8 noundef zeroext 48) #2
+  br label %74
+
+58:                                               ; preds = %61, %55
+  %59 = phi i32 [ 1, %55 ], [ %63, %61 ]
+  %60 = icmp eq i32 %59, 0
+  br i1 %60, label %74, label %66
+
+61:                                               ; preds = %61, %55
+  %62 = phi i32 [ %63, %61 ], [ 1, %55 ]
+  %63 = mul i32 %62, 10
+  %64 = udiv i32 %53, %63
+  %65 = icmp ugt i32 %64, 9
+  br i1 %65, label %61, label %58, !llvm.loop !11
+
+66:                                               ; preds = %66, %58
+  %67 = phi i32 [ %72, %66 ], [ %59, %58 ]
+  %68 = udiv i32 %53, %67
+  %69 = urem i32 %68, 10
+  %70 = trunc i32 %69 to i8
+  %71 = or i8 %70, 48
+  call void @putchar(i8 noundef zeroext %71) #2
+  %72 = udiv i32 %67, 10
+  %73 = icmp ult i32 %67, 10
+  br i1 %73, label %74, label %66, !llvm.loop !12
+
+74:                                               ; preds = %66, %58, %57
+  call void @putchar(i8 noundef zeroext 44) #2
+  %75 = add nuw nsw i32 %8, 1
+  %76 = icmp eq i32 %75, 5
+  br i1 %76, label %4, label %7, !llvm.loop !14
 }
 
-attributes #0 = { nounwind sspstrong uwtable "disable-tail-calls"="false" "frame-pointer"="none" "less-precise-fpmad"="false" "min-legal-vector-width"="0" "no-infs-fp-math"="false" "no-jump-tables"="false" "no-nans-fp-math"="false" "no-signed-zeros-fp-math"="false" "no-trapping-math"="true" "stack-protector-buffer-size"="4" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" "unsafe-fp-math"="false" "use-soft-float"="false" }
-attributes #1 = { "disable-tail-calls"="false" "frame-pointer"="none" "less-precise-fpmad"="false" "no-infs-fp-math"="false" "no-nans-fp-math"="false" "no-signed-zeros-fp-math"="false" "no-trapping-math"="true" "stack-protector-buffer-size"="4" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" "unsafe-fp-math"="false" "use-soft-float"="false" }
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
 attributes #2 = { nounwind }
 
-!llvm.module.flags = !{!0, !1}
-!llvm.ident = !{!2}
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
 
 !0 = !{i32 1, !"wchar_size", i32 4}
 !1 = !{i32 7, !"PIC Level", i32 2}
-!2 = !{!"clang version 12.0.1"}
-!3 = distinct !{!3, !4, !5}
-!4 = !{!"llvm.loop.mustprogress"}
-!5 = !{!"llvm.loop.unroll.disable"}
-!6 = distinct !{!6, !4, !5}
-!7 = distinct !{!7, !4, !5}
-!8 = distinct !{!8, !4, !5}
-!9 = distinct !{!9, !4, !5}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
+!13 = distinct !{!13, !9, !10}
+!14 = distinct !{!14, !9, !10}
@@ -1,1022 +1,66 @@
-r7 >>>>>>>
-+++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 86 ops ~191 steps
+frame widths:
+  main: 6 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>#main
+>#main
 +
 >#main/b0
 +
-<<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:call_me
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/18 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov17/19 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup16/17/18 <<[->+>+<<]
-            mov17/16 >[-<+>]
-            <<<<<<[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup11/19/20 <[->>>>>>>>+>+<<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov20/12 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup12/17/18 <[->>>>>+>+<<<<<<]
-            mov17/12 >>>>>[-<<<<<+>>>>>]
-            mov18/13 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup13/17/18 >>>>>[->>>>+>+<<<<<]
-            mov17/13 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup11/17/18 <<<[->>>>>>+>+<<<<<<<]
-            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov18/14 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup14/17/18 <[->>>+>+<<<<]
-            mov17/14 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/22 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup14/17/18 <<<<<<[->>>+>+<<<<]
-            mov17/14 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
+        >>#B:%ret_lading_pad
         [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<-
+            <<<-
             #dead_frame
             >-
-            l1 <
-            <[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
+            #dead_fn_pad/main
             >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>-
-            l46 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            l6 <<<<<<
         ]
-        <<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:call_me
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+            call @putchar(i8 49_ i8 50_ i8 51)
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/34 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov31/33 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            op_to_reg storing const value in temp address
-            <#constop_1
-            +
-            <<<<<<<<<<<<<[-]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            <<<<<<<<<<<<<<<<[-]
-            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
-                -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup17/32/33 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/37 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup17/32/33 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov33/23 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup19/34/35 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
+            putchar run intrinsic
+            >>>+++++++
             [
                 -
-                dup35/20/37 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov37/35 >>[-<<+>>]
-            <]
-            <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup20/32/33 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup33/35/36 >[->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov31/40 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
-                    -
-                    <+
-                    >>>>>>[
-                        -
-                        <<<<<<-
-                        r1 >
-                    >>>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov40/31 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup33/35/36 <<<<<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup21/32/33 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov32/21 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup20/32/33 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup20/34/35 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov34/20 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov35/17 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
-        [
-            -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup23/36/37 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov36/23 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup37/39/40 >[->>+>+<<<]
-            mov40/37 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov31/43 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
-                    -
-                    <+
-                    >>>>>[
-                        -
-                        <<<<<-
-                        r1 >
-                    >>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov43/31 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup37/39/40 <<<<<<[->>+>+<<<]
-                mov40/37 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
-            >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup24/32/33 <[->>>>>>>>+>+<<<<<<<<<]
-            mov32/24 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup34/36/37 [->>+>+<<<]
-            mov37/34 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/38 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov38/33 <<[-<<<<<+>>>>>]
-                dup34/36/37 <<<<[->>+>+<<<]
-                mov37/34 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov36/25 >[-<<<<<<<<<<<+>>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
-            ]
-            mov35/25 [-<<<<<<<<<<+>>>>>>>>>>]
-            <[-]
-            >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup25/32/33 <[->>>>>>>+>+<<<<<<<<]
-            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov33/26 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup26/32/33 <[->>>>>>+>+<<<<<<<]
-            mov32/26 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov33/27 <[-<<<<<<+>>>>>>]
-            >[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            dup27/32/33 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov32/27 >>>>>[-<<<<<+>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup23/32/33 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup34/36/37 [->>+>+<<<]
-            mov37/34 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/38 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
-                    [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov38/33 >>>[-<<<<<+>>>>>]
-                dup34/36/37 <<<<[->>+>+<<<]
-                mov37/34 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup23/32/33 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov33/29 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup29/32/33 <[->>>+>+<<<<]
-            mov32/29 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/35 <<<[->+<]
-            >>>+
-            <<<<[
-                -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov28/23 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<<<<<]
-    >#call_me
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/call_me
-            >>>>>>-
-            l20 <<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_call_me
-        >#F:putstr
-        >#F:putdec
-        >#F:call_me
-        >#F:main
-        >#B:%no_block0_call_for_call_me
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#%4_=_trunc_i32_%0_to_i8(mult)
-        >#%5_=_trunc_i32_%1_to_i8(mult)
-        >#%6_=_trunc_i32_%2_to_i8(mult)
-        >#%0
-        >#%1
-        >#%2
-        <<<<<<<<<<<#B:%no_block0_call_for_call_me
-        [
-            -
-            copy up args
-            >>>>>>>>>#arg_%0
-            l2 <<
-            mov0/16 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            r2 >>
-            >>>>>>>>>>>>>>>#arg_%1
-            l3 <<<
-            mov0/18 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            r3 >>>
-            >>>>>>>>>>>>>>>>#arg_%2
-            l4 <<<<
-            mov0/20 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            r4 >>>>
-            br label %3
-            >>>>>>>+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = trunc i32 %0 to i8
-            >>>>[-]
-            mov14/11 >>>[-<<<+>>>]
-            call @putchar(i8 %4)
-            enable next block when we return
-            <<<<<<#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            dup11/17/18 >>>[->>>>>>+>+<<<<<<<]
-            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
-            %5 = trunc i32 %1 to i8
-            >>>>[-]
-            mov15/12 >>>[-<<<+>>>]
-            call @putchar(i8 %5)
-            enable next block when we return
-            <<<<<<#caller/%call_term_for_1
+                <+++++++
+            >]
+            <.
             +
-            putchar intrinsic
-            dup12/17/19 >>>[->>>>>+>>+<<<<<<<]
-            mov17/12 >>>>>[-<<<<<+>>>>>]
-            >>.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %6 = trunc i32 %2 to i8
-            >>>>[-]
-            mov16/13 >>>[-<<<+>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<#caller/%call_term_for_2
+            .
             +
-            putchar intrinsic
-            dup13/17/18 >>>[->>>>+>+<<<<<]
-            mov17/13 >>>>[-<<<<+>>>>]
-            >.
+            .
             [-]
-        <<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
             ret void
+            
             zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<-
+            <<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>]
-    <<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/main
-            >>>>>>-
-            l12 <<<<<<<<<<<<
+            <[-]
+            r1 
+            l5 <<
         ]
-        <<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:call_me
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        <<<#B:%no_block0_call_for_main
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            call @call_me(i32 49_ i32 50_ i32 51)
-            enable next block when we return
-            >#caller/%call_term_for_0
-            +
-            stack_width 12
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<#constop_49
-            +++++++++++++++++++++++++++++++++++++++++++++++++
-            mov9/21 [->>>>>>>>>>>>+<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<#constop_50
-            ++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov10/20 [->>>>>>>>>>+<<<<<<<<<<]
-            copy up arg 2
-            >>>>>>>>>#arg_2
-            op_to_reg storing const value in temp address
-            <<<<<<<<#constop_51
-            +++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov11/19 [->>>>>>>>+<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++
-            l1 <
-            dup0/23/24 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov24/0 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r12 >>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r11 >>>>>>>>>>>
-            <<<<<<#===FRAME_call_me
-            +
-            >>>#call_me
-            +
-            >>#call_me/b0
-            +
-        >>]
-        >#B:%call_term_for_0
-        [
-            -
-            ret void
-            zero all function allocs
-            <<<<<<<<-
-            #dead_frame
-            >>>>-
-            l1 <
-            <<<<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>]
-    <<<<]
-<<<<]
+    <]
+<]
@@ -1,1654 +1,116 @@
-r9 >>>>>>>>>
-+++++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 684 ops ~1265 steps
+frame widths:
+  main: 12 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>>>#main
+>#main
 +
 >#main/b0
 +
-<<<<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>>>>-
-            l27 <<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:three_args
-        >#F:five_args
-        >#F:twelve_args
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/20 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/21 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup18/19/20 <<[->+>+<<]
-            mov19/18 >[-<+>]
-            <<<<<<[-]
-            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup13/21/22 <[->>>>>>>>+>+<<<<<<<<<]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov22/14 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup14/19/20 <[->>>>>+>+<<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            mov20/15 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup15/19/20 >>>>>[->>>>+>+<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup13/19/20 <<<[->>>>>>+>+<<<<<<<]
-            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov20/16 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup16/19/20 <[->>>+>+<<<<]
-            mov19/16 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov21/24 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup16/19/20 <<<<<<[->>>+>+<<<<]
-            mov19/16 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
+        >>#B:%ret_lading_pad
         [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<-
+            <<<-
             #dead_frame
             >-
-            l1 <
-            <[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>>]
-    <<<<<<<<<<]
-    >#putdec
-    [
-        >>>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
+            #dead_fn_pad/main
             >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            l12 <<<<<<<<<<<<
         ]
-        <<<<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:three_args
-        >#F:five_args
-        >#F:twelve_args
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+            call @putchar(i8 79_ i8 119_ i8 79_ i8 10_ i8 122_ i8 97_ i8 100_ i8 100_ i8 121_ i8 10_ i8 108_ i8 109_ i8 97_ i8 111_ i8 109_ i8 121_ i8 100_ i8 117_ i8 100_ i8 101_ i8 33_ i8 63)
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/36 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            op_to_reg storing const value in temp address
-            <#constop_1
-            +
-            <<<<<<<<<<<<<[-]
-            mov34/21 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            <<<<<<<<<<<<<<<<[-]
-            mov35/19 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
-                -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
+            putchar run intrinsic
+            >>>++++++
+            [
                 -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
+                <+++++++++++++
+            >]
+            <+
             .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup19/34/35 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov36/39 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup19/34/35 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov35/25 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup21/36/37 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov36/21 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
+            >>+++++
             [
                 -
-                dup37/22/39 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov39/37 >>[-<<+>>]
-            <]
-            <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup22/34/35 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov34/22 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup35/37/38 >[->>+>+<<<]
-            mov38/35 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/42 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
-                    -
-                    <+
-                    >>>>>>[
-                        -
-                        <<<<<<-
-                        r1 >
-                    >>>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov42/33 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup35/37/38 <<<<<<<[->>+>+<<<]
-                mov38/35 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup23/34/35 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov34/23 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov35/37 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup22/34/35 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov34/22 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov35/21 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup22/36/37 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov36/22 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov37/19 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
-        [
-            -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup25/38/39 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov38/25 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup39/41/42 >[->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/45 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
-                    -
-                    <+
-                    >>>>>[
-                        -
-                        <<<<<-
-                        r1 >
-                    >>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov45/33 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup39/41/42 <<<<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
-            >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup26/34/35 <[->>>>>>>>+>+<<<<<<<<<]
-            mov34/26 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup36/38/39 [->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov35/40 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov40/35 <<[-<<<<<+>>>>>]
-                dup36/38/39 <<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
+                <<++++++++
+            >>]
+            <<.
+            ----------------------------------------
+            .
+            ---------------------------------------------------------------------
+            .
+            >>>++++++++
             [
-                mov38/27 >[-<<<<<<<<<<<+>>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
-            ]
-            mov37/27 [-<<<<<<<<<<+>>>>>>>>>>]
-            <[-]
-            >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup27/34/35 <[->>>>>>>+>+<<<<<<<<]
-            mov34/27 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov35/28 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup28/34/35 <[->>>>>>+>+<<<<<<<]
-            mov34/28 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov35/29 <[-<<<<<<+>>>>>>]
-            >[
                 -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            dup29/34/35 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov34/29 >>>>>[-<<<<<+>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup25/34/35 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov34/25 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup36/38/39 [->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov35/40 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
-                    [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov40/35 >>>[-<<<<<+>>>>>]
-                dup36/38/39 <<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup25/34/35 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov34/25 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov35/31 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup31/34/35 <[->>>+>+<<<<]
-            mov34/31 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov36/37 <<<[->+<]
-            >>>+
-            <<<<[
+                <<<++++++++++++++
+            >>>]
+            <<<.
+            -------------------------
+            .
+            +++
+            .
+            .
+            +++++++++++++++++++++
+            .
+            ---------------------------------------------------------------------------------------------------------------
+            .
+            >>>>>+++++++
+            [
                 -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<+
+                <<<<<++++++++++++++
             >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov30/25 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<]
-    >#three_args
-    [
-        >>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/three_args
-            >>>>>>>>-
-            l22 <<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_three_args
-        >#F:putstr
-        >#F:putdec
-        >#F:three_args
-        >#F:five_args
-        >#F:twelve_args
-        >#F:main
-        >#B:%no_block0_call_for_three_args
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#%4_=_trunc_i32_%0_to_i8(mult)
-        >#%5_=_trunc_i32_%1_to_i8(mult)
-        >#%6_=_trunc_i32_%2_to_i8(mult)
-        >#%0
-        >#%1
-        >#%2
-        <<<<<<<<<<<#B:%no_block0_call_for_three_args
-        [
-            -
-            copy up args
-            >>>>>>>>>#arg_%0
-            l2 <<
-            mov0/18 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            >>>>>>>>>>>>>>>>>#arg_%1
-            l3 <<<
-            mov0/20 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            r3 >>>
-            >>>>>>>>>>>>>>>>>>#arg_%2
-            l4 <<<<
-            mov0/22 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            r4 >>>>
-            br label %3
-            >>>>>>>>>+
-        <<]
-        >>#B:%3
-        [
-            -
-            %4 = trunc i32 %0 to i8
-            >>>>[-]
-            mov16/13 >>>[-<<<+>>>]
-            call @putchar(i8 %4)
-            enable next block when we return
-            <<<<<<#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            dup13/19/20 >>>[->>>>>>+>+<<<<<<<]
-            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
-            %5 = trunc i32 %1 to i8
-            >>>>[-]
-            mov17/14 >>>[-<<<+>>>]
-            call @putchar(i8 %5)
-            enable next block when we return
-            <<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup14/19/21 >>>[->>>>>+>>+<<<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            >>.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %6 = trunc i32 %2 to i8
-            >>>>[-]
-            mov18/15 >>>[-<<<+>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup15/19/20 >>>[->>>>+>+<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>-
-            l1 <
-            <<<[-]
-            r1 >
-            l13 <<<<<<<<<<<<<
-        >>>>>>>>>>>>]
-    <<<<<<<<<]
-    >#five_args
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/five_args
-            >>>>>>>>-
-            l28 <<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_five_args
-        >#F:putstr
-        >#F:putdec
-        >#F:three_args
-        >#F:five_args
-        >#F:twelve_args
-        >#F:main
-        >#B:%no_block0_call_for_five_args
-        >#B:%ret_lading_pad
-        >#B:%5
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#%6_=_trunc_i32_%0_to_i8(mult)
-        >#%7_=_trunc_i32_%1_to_i8(mult)
-        >#%8_=_trunc_i32_%2_to_i8(mult)
-        >#%9_=_trunc_i32_%3_to_i8(mult)
-        >#%10_=_trunc_i32_%4_to_i8(mult)
-        >#%0
-        >#%1
-        >#%2
-        >#%3
-        >#%4
-        <<<<<<<<<<<<<<<<<#B:%no_block0_call_for_five_args
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            >>>>>>>>>>>>>>>>>>>>>#arg_%1
-            l3 <<<
-            mov0/24 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
-            r3 >>>
-            >>>>>>>>>>>>>>>>>>>>>>#arg_%2
-            l4 <<<<
-            mov0/26 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r4 >>>>
-            >>>>>>>>>>>>>>>>>>>>>>>#arg_%3
-            l5 <<<<<
-            mov0/28 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r5 >>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>#arg_%4
-            l6 <<<<<<
-            mov0/30 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r6 >>>>>>
-            br label %5
-            >>>>>>>>>+
-        <<]
-        >>#B:%5
-        [
-            -
-            %6 = trunc i32 %0 to i8
-            >>>>>>[-]
-            mov20/15 >>>>>[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            dup15/25/26 >>>>>[->>>>>>>>>>+>+<<<<<<<<<<<]
-            mov25/15 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
-            %7 = trunc i32 %1 to i8
-            >>>>>>[-]
-            mov21/16 >>>>>[-<<<<<+>>>>>]
-            call @putchar(i8 %7)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup16/25/27 >>>>>[->>>>>>>>>+>>+<<<<<<<<<<<]
-            mov25/16 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            >>.
-            [-]
-        <<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %8 = trunc i32 %2 to i8
-            >>>>>>[-]
-            mov22/17 >>>>>[-<<<<<+>>>>>]
-            call @putchar(i8 %8)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup17/25/26 >>>>>[->>>>>>>>+>+<<<<<<<<<]
-            mov25/17 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %9 = trunc i32 %3 to i8
-            >>>>>>[-]
-            mov23/18 >>>>>[-<<<<<+>>>>>]
-            call @putchar(i8 %9)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_3
-            +
-            putchar intrinsic
-            dup18/25/26 >>>>>[->>>>>>>+>+<<<<<<<<]
-            mov25/18 >>>>>>>[-<<<<<<<+>>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
-            %10 = trunc i32 %4 to i8
-            >>>>>>[-]
-            mov24/19 >>>>>[-<<<<<+>>>>>]
-            call @putchar(i8 %10)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_4
-            +
-            putchar intrinsic
-            dup19/25/26 >>>>>[->>>>>>+>+<<<<<<<]
-            mov25/19 >>>>>>[-<<<<<<+>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>>-
-            l1 <
-            <<<<[-]
-            r1 >
-            l15 <<<<<<<<<<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<<<]
-    >#twelve_args
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>>-
-            <<<<<#dead_fn_pad/twelve_args
-            >>>>>>>>-
-            l35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_twelve_args
-        >#F:putstr
-        >#F:putdec
-        >#F:three_args
-        >#F:five_args
-        >#F:twelve_args
-        >#F:main
-        >#B:%no_block0_call_for_twelve_args
-        >#B:%ret_lading_pad
-        >#B:%12
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#%13_=_trunc_i32_%8_to_i8(mult)
-        >#%14_=_trunc_i32_%9_to_i8(mult)
-        >#%15_=_trunc_i32_%10_to_i8(mult)
-        >#%16_=_trunc_i32_%11_to_i8(mult)
-        >#%0
-        >#%1
-        >#%2
-        >#%3
-        >#%4
-        >#%5
-        >#%6
-        >#%7
-        >#%8
-        >#%9
-        >#%10
-        >#%11
-        <<<<<<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_twelve_args
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            >>>>>>>>>>>>>>>>>>>>>#arg_%1
-            l3 <<<
-            mov0/24 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
-            r3 >>>
-            >>>>>>>>>>>>>>>>>>>>>>#arg_%2
-            l4 <<<<
-            mov0/26 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r4 >>>>
-            >>>>>>>>>>>>>>>>>>>>>>>#arg_%3
-            l5 <<<<<
-            mov0/28 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r5 >>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>#arg_%4
-            l6 <<<<<<
-            mov0/30 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r6 >>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>#arg_%5
-            l7 <<<<<<<
-            mov0/32 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r7 >>>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%6
-            l8 <<<<<<<<
-            mov0/34 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r8 >>>>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%7
-            l9 <<<<<<<<<
-            mov0/36 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r9 >>>>>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%8
-            l10 <<<<<<<<<<
-            mov0/38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r10 >>>>>>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%9
-            l11 <<<<<<<<<<<
-            mov0/40 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r11 >>>>>>>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%10
-            l12 <<<<<<<<<<<<
-            mov0/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r12 >>>>>>>>>>>>
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%11
-            l13 <<<<<<<<<<<<<
-            mov0/44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r13 >>>>>>>>>>>>>
-            br label %12
-            >>>>>>>>>+
-        <<]
-        >>#B:%12
-        [
-            -
-            call @three_args(i32 %0_ i32 %1_ i32 %2)
-            enable next block when we return
-            >#caller/%call_term_for_0
-            +
-            stack_width 35
-            ret_pad_width 8
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            mov20/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>>>>>>>>>>>#arg_1
-            mov21/45 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 2
-            >>>>>>>>>>>>>>>>>>>>>>>#arg_2
-            mov22/44 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/48/49 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov49/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r35 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>#caller
-            +
-            >>>#caller/jump_pad_blk
-            +
-            r13 >>>>>>>>>>>>>
-            <<<<<<<<#===FRAME_three_args
-            +
-            >>>#three_args
-            +
-            >>>>#three_args/b0
-            +
-        >>]
-        >#B:%call_term_for_0
-        [
-            -
-            call @five_args(i32 %3_ i32 %4_ i32 %5_ i32 %6_ i32 %7)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            stack_width 35
-            ret_pad_width 8
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            mov23/48 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>>>>>>>>>>#arg_1
-            mov24/47 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 2
-            >>>>>>>>>>>>>>>>>>>>>>#arg_2
-            mov25/46 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 3
-            >>>>>>>>>>>>>>>>>>>>#arg_3
-            mov26/45 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            copy up arg 4
-            >>>>>>>>>>>>>>>>>>#arg_4
-            mov27/44 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/50/51 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov51/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r35 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>#caller
-            +
-            >>>#caller/jump_pad_blk
-            +
-            r15 >>>>>>>>>>>>>>>
-            <<<<<<<<#===FRAME_five_args
-            +
-            >>>>#five_args
-            +
-            >>>#five_args/b0
-            +
-        >>>]
-        >#B:%call_term_for_1
-        [
-            -
-            %13 = trunc i32 %8 to i8
-            >>>>>[-]
-            mov28/16 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            call @putchar(i8 %13)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            dup16/32/33 >>>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
-            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %14 = trunc i32 %9 to i8
-            >>>>>[-]
-            mov29/17 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            call @putchar(i8 %14)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<#caller/%call_term_for_3
-            +
-            putchar intrinsic
-            dup17/32/34 >>>>[->>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            >>.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
-            %15 = trunc i32 %10 to i8
-            >>>>>[-]
-            mov30/18 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            call @putchar(i8 %15)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<#caller/%call_term_for_4
-            +
-            putchar intrinsic
-            dup18/32/33 >>>>[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
-            %16 = trunc i32 %11 to i8
-            >>>>>[-]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            call @putchar(i8 %16)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<#caller/%call_term_for_5
-            +
-            putchar intrinsic
-            dup19/32/33 >>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_5
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>>>-
-            l1 <
-            <<<<<[-]
-            r1 >
-            l22 <<<<<<<<<<<<<<<<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>>>-
-            <<<<<<#dead_fn_pad/main
-            >>>>>>>>-
-            l27 <<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:three_args
-        >#F:five_args
-        >#F:twelve_args
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        <<<<<<<#B:%no_block0_call_for_main
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            call @three_args(i32 79_ i32 119_ i32 79)
-            enable next block when we return
-            >#caller/%call_term_for_0
+            <<<<<.
             +
-            stack_width 27
-            ret_pad_width 8
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<#constop_79
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov15/38 [->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<#constop_119
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov16/37 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 2
-            >>>>>>>>>>>>>>>>>>>>#arg_2
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<#constop_79
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov17/36 [->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/40/41 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov41/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r27 >>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r13 >>>>>>>>>>>>>
-            <<<<<<<<#===FRAME_three_args
-            +
-            >>>#three_args
-            +
-            >>>>#three_args/b0
-            +
-        >>]
-        >#B:%call_term_for_0
-        [
-            -
-            call @putchar(i8 10)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>#constop_10
-            ++++++++++
             .
-            [-]
-        <<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            call @five_args(i32 122_ i32 97_ i32 100_ i32 100_ i32 121)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            stack_width 27
-            ret_pad_width 8
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<#constop_122
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov15/40 [->>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov16/39 [->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 2
-            >>>>>>>>>>>>>>>>>>>>>>#arg_2
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<#constop_100
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov18/38 [->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 3
-            >>>>>>>>>>>>>>>>>>>#arg_3
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<#constop_100
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov17/37 [->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 4
-            >>>>>>>>>>>>>>>>>>>#arg_4
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<#constop_121
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov19/36 [->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/42/43 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov43/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r27 >>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r15 >>>>>>>>>>>>>>>
-            <<<<<<<<#===FRAME_five_args
-            +
-            >>>>#five_args
-            +
-            >>>#five_args/b0
-            +
-        >>>>]
-        >#B:%call_term_for_2
-        [
-            -
-            call @putchar(i8 10)
-            enable next block when we return
-            >#caller/%call_term_for_3
+            ------------
+            .
+            ++++++++++++++
+            .
+            --
+            .
+            ++++++++++++
+            .
+            ---------------------
+            .
+            +++++++++++++++++
+            .
+            -----------------
+            .
             +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
+            .
+            --------------------------------------------------------------------
+            .
+            ++++++++++++++++++++++++++++++
             .
             [-]
-        <<<]
-        >#B:%call_term_for_3
-        [
-            -
-            call @twelve_args(i32 108_ i32 109_ i32 97_ i32 111_ i32 109_ i32 121_ i32 100_ i32 117_ i32 100_ i32 101_ i32 33_ i32 63)
-            enable next block when we return
-            >#caller/%call_term_for_4
-            +
-            stack_width 27
-            ret_pad_width 8
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov15/47 [->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#constop_109
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov16/46 [->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 2
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_2
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov17/45 [->>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 3
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_3
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<<<<#constop_111
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov18/44 [->>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>#arg_4
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<#constop_109
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov20/43 [->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 5
-            >>>>>>>>>>>>>>>>>>>>>>#arg_5
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<<<<#constop_121
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov19/42 [->>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 6
-            >>>>>>>>>>>>>>>>>>>>>>#arg_6
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<<<#constop_100
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov21/41 [->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            copy up arg 7
-            >>>>>>>>>>>>>>>>>>>#arg_7
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<<<#constop_117
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov22/40 [->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            copy up arg 8
-            >>>>>>>>>>>>>>>>>#arg_8
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_100
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov23/39 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 9
-            >>>>>>>>>>>>>>>#arg_9
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov24/38 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            copy up arg 10
-            >>>>>>>>>>>>>#arg_10
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<#constop_33
-            +++++++++++++++++++++++++++++++++
-            mov25/37 [->>>>>>>>>>>>+<<<<<<<<<<<<]
-            copy up arg 11
-            >>>>>>>>>>>#arg_11
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<#constop_63
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            mov26/36 [->>>>>>>>>>+<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/49/50 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov50/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r27 >>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r22 >>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<#===FRAME_twelve_args
-            +
-            >>>>>#twelve_args
-            +
-            >>#twelve_args/b0
-            +
-        >>>>>>]
-        >#B:%call_term_for_4
-        [
-            -
             ret void
+            
             zero all function allocs
-            <<<<<<<<<<<<<<-
+            <<<<-
             #dead_frame
-            >>>>>>-
+            >-
             l1 <
-            <<<<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<]
-<<<<<<]
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
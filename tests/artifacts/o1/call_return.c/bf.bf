@@ -0,0 +1,517 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  shout (depth 1)
+  main calls shout (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  shout: 1134 ops ~26236 steps
+  main: 3807 ops ~58519 steps
+frame widths:
+  shout: 17 cells
+  main: 25 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#shout
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/shout
+            >>>-
+            l17 <<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_shout
+        #F:shout
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%4
+        #%2_=_icmp_eq_i8_%0__i8_63(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/10 <<<<<[->>>>>>>>>>+<<<<<<<<<<]
+            r2 >>
+            %2 = icmp eq i8 %0_ i8 63
+            
+            >>>>>>>[-]
+            dup8/9/10 >[->+>+<<]
+            mov9/8 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_63
+            >+++++++
+            [
+                -
+                <+++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov11/14 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            <[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %2_ label %3_ label %4
+            
+            dup7/10/9 <<<<<<[->>>+<+<<]
+            mov10/7 >>>[-<<<+>>>]
+            <<<<<<+
+            >>>>>[
+                -
+                <<<<<-
+                >+
+                #B:shout/%3_true
+            >>>>]
+            <<<<<[
+                -
+                >>+
+                #B:shout/%4_false
+            <<]
+        <]
+        >>#B:%3
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %4
+            
+            <<<<<+
+        <]
+        >#B:%4
+        [
+            -
+            %5 = add i8 %0_ i8 1
+            
+            >[-]
+            dup8/9/10 >[->+>+<<]
+            mov9/8 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov10/7 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/13/15 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov15/7 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov12/13 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov13/12 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            ret i8 %5
+            
+            mov7/9 <<<<<[->>+<<]
+            zero all function allocs
+            [-]
+            >[-]
+            mov9/7 >[-<<+>>]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        ]
+    <<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:shout
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #alloc_%1
+        #alloc_%2
+        #%add_inl0_3_=_add_i8_35__i8_30(mult)
+        #%4_=_load_volatile_i8*_%1__align_1(mult)
+        #%add_inl1_3_=_add_i8_%add_inl0_3__i8_%4(mult)
+        #%8_=_call_@shout(i8_%7)(mult)
+        #%9_=_call_@shout(i8_97)(mult)
+        #%10_=_call_@shout(i8_%9)(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 1_ i8* %1_ align 1
+            
+            >>>>[-]
+            +
+            store volatile i8 63_ i8* %2_ align 1
+            
+            >[-]
+            >>>>>>>+++++++
+            [
+                -
+                <<<<<<<+++++++++
+            >>>>>>>]
+            %add_inl0_3 = add i8 35_ i8 30
+            
+            <<<<<<[-]
+            known to be 65
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++
+            >>>>>>>]
+            <<<<<<<+
+            call @putchar(i8 %add_inl0_3)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            .
+            [-]
+            %4 = load volatile i8* %1_ align 1
+            
+            <<<<<[-]
+            dup9/12/17 <<<[->>>+>>>>>+<<<<<<<<]
+            mov17/9 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %add_inl1_3 = add i8 %add_inl0_3_ i8 %4
+            
+            <<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup12/20/19 <<<<<[->>>>>>>>+<+<<<<<<<]
+            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            mov17/13 <<<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/22/24 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov24/13 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %add_inl2_3 = add i8 1_ i8 %add_inl1_3
+            
+            <<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>>#constop_1
+            +
+            dup13/18/19 <<<<[->>>>>+>+<<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            mov17/12 <[-<<<<<+>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/21/23 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov23/12 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            call @putchar(i8 %add_inl2_3)
+            
+            putchar intrinsic
+            dup12/17/18 <<<<<<<<[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            %7 = load volatile i8* %2_ align 1
+            
+            <<<<<<[-]
+            dup10/12/17 <<[->>+>>>>>+<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %8 = call @shout(i8 %7)
+            
+            <<<[-]
+            enable next block when we return
+            <<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 25
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>#arg_0
+            dup12/17/18 [->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            mov18/30 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r25 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_shout
+            +
+            >#shout
+            +
+            >>#shout/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %8
+            mov39/14 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup14/17/18 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            >.
+            [-]
+            %9 = call @shout(i8 97)
+            
+            <<<[-]
+            enable next block when we return
+            <<<<<<<<#caller/%call_term_for_1
+            +
+            stack_width 25
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov17/30 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r25 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_shout
+            +
+            >#shout
+            +
+            >>#shout/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            returned %9
+            mov39/15 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            %10 = call @shout(i8 %9)
+            
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            enable next block when we return
+            <<<<<<<<#caller/%call_term_for_2
+            +
+            stack_width 25
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            dup15/17/18 [->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            mov18/30 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r25 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_shout
+            +
+            >#shout
+            +
+            >>#shout/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            returned %10
+            mov39/16 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup16/17/18 <<<<<<<<<<<<<<<<<<<<<<<[->+>+<<]
+            mov17/16 >[-<+>]
+            >.
+            [-]
+            call @putchar(i8 %add_inl0_3)
+            
+            putchar intrinsic
+            dup11/17/18 <<<<<<<[->>>>>>+>+<<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>]
+    <<<<<<]
+<<]
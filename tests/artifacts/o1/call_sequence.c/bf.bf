@@ -0,0 +1,455 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %8 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  g (depth 1)
+  main calls g (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  g: 103 ops ~229 steps
+  main: 2657 ops ~34395 steps
+frame widths:
+  g: 7 cells
+  main: 26 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#g
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/g
+            >>>-
+            l7 <<<<<<<
+        ]
+        #mainloop_g
+        #F:g
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        <#B:%0
+        [
+            -
+            copy up args
+            call @putchar(i8 103_ i8 102)
+            
+            putchar run intrinsic
+            >>>++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
+            .
+            -
+            .
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l6 <<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:g
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%2
+        #B:%3
+        #B:%7
+        #B:%8
+        #B:%call_term_for_1
+        #B:%9
+        #alloc_%1
+        #%4_=_phi_i8_{_i8_0__%call_term_for_0_}__{_i8_%10__%9_}(mult)
+        #%5_=_load_volatile_i8*_%1__align_1(mult)
+        #%6_=_icmp_eq_i8_%5__i8_121(mult)
+        #%10_=_add_i8_%4__i8_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>#constop_102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            call @g()
+            
+            enable next block when we return
+            <<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 26
+            ret_pad_width 4
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r26 
+            <<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r6 >>
+            #===FRAME_g
+            +
+            >#g
+            +
+            >>#g/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>#constop_102
+            >>++++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<++
+            .
+            [-]
+            store volatile i8 121_ i8* %1_ align 1
+            
+            <<<<<[-]
+            >>>>>+++++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            br label %3
+            
+            doing phi stuff
+            %4 = phi i8 { i8 0_ %call_term_for_0 }_ { i8 %10_ %9 }
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            <<<<[-]
+            mov18/14 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<+
+        <<]
+        >#B:%2
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >>>>>>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+        >#B:%3
+        [
+            -
+            %4 = phi i8 { i8 0_ %call_term_for_0 }_ { i8 %10_ %9 }
+            %5 = load volatile i8* %1_ align 1
+            
+            >>>>>>>[-]
+            dup13/15/19 <<[->>+>>>>+<<<<<<]
+            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
+            %6 = icmp eq i8 %5_ i8 121
+            
+            <<<[-]
+            dup15/18/19 <[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov21/23 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            <[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %6_ label %7_ label %8
+            
+            dup16/19/18 <<<<<<[->>>+<+<<]
+            mov19/16 >>>[-<<<+>>>]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%7_true
+            >>>>>>>>>]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%8_false
+            <<<<<<]
+        >>>>]
+        >#B:%7
+        [
+            -
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            br label %9
+            
+            <<<<<<<<+
+        <<<]
+        >#B:%8
+        [
+            -
+            call @g()
+            
+            enable next block when we return
+            >#caller/%call_term_for_1
+            +
+            stack_width 26
+            ret_pad_width 4
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r26 
+            <<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r6 >>
+            #===FRAME_g
+            +
+            >#g
+            +
+            >>#g/b0
+            +
+        >>>>>>>]
+        >#B:%call_term_for_1
+        [
+            -
+            br label %9
+            
+            >+
+        <]
+        >#B:%9
+        [
+            -
+            call @putchar(i8 102)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_102
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            store volatile i8 110_ i8* %1_ align 1
+            
+            <<<<<[-]
+            >>>>>++++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %10 = add i8 %4_ i8 1
+            
+            <[-]
+            dup14/18/19 <<<[->>>>+>+<<<<<]
+            mov18/14 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov19/17 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/22/24 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov24/17 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %11 = icmp eq i8 %10_ i8 2
+            
+            <<<<<<[-]
+            dup17/18/19 >>[->+>+<<]
+            mov18/17 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/22 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<+
+            >>>>>>>[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            <[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %11_ label %2_ label %3
+            
+            dup15/19/18 <<<<<<[->>>>+<+<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            doing phi stuff
+            %4 = phi i8 { i8 0_ %call_term_for_0 }_ { i8 %10_ %9 }
+            dup17/20/21 <<[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/22 >[->+<]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%2_true
+                >>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%3_false
+                >>>>>>[-]
+                mov22/14 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<]
+        >>>>>>>>]
+    <<<<<<<<<<]
+<<]
@@ -0,0 +1,122 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 248 ops ~990 steps
+frame widths:
+  main: 8 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l8 <<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #%show_inl0_3_=_add_i8_1__i8_64(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %show_inl0_3 = add i8 1_ i8 64
+            
+            >>[-]
+            known to be 65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            call @putchar(i8 %show_inl0_3)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >#constop_65
+            ++++++++
+            [
+                -
+                >++++++++
+            <]
+            >+
+            .
+            [-]
+            call @putchar(i8 64)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_64
+            >++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<.
+            [-]
+            %show_inl1_3 = add i8 2_ i8 96
+            
+            <[-]
+            known to be 98
+            >+++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            call @putchar(i8 %show_inl1_3)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_98
+            >+++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            <.
+            [-]
+            call @putchar(i8 96)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_96
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <[-]
+            <<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -1,1275 +1,247 @@
-r9 >>>>>>>>>
-+++++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2225 ops ~55110 steps
+frame widths:
+  main: 25 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>>>#main
+>#main
 +
 >#main/b0
 +
-<<<<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>>>>#B:%ret_lading_pad
+        >>#B:%ret_lading_pad
         [
-            <<<<<<<<-
+            <<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>>>>-
-            l27 <<<<<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/main
+            >>-
+            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%3
+        #B:%7
+        #B:%10
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%8__%7_}(mult)
+        #%4_=_phi_i32_{_i32_%5__%3_}__{_i32_0__%1_}(mult)
+        #%5_=_add_i32_%4__i32_1(mult)
+        #%6_=_icmp_eq_i32_%4__i32_0(mult)
+        #%8_=_add_i32_%2__i32_1(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/20 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
+            br label %1
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/21 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
             doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup18/19/20 <<[->+>+<<]
-            mov19/18 >[-<+>]
-            <<<<<<[-]
-            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup13/21/22 <[->>>>>>>>+>+<<<<<<<<<]
-            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov22/14 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup14/19/20 <[->>>>>+>+<<<<<<]
-            mov19/14 >>>>>[-<<<<<+>>>>>]
-            mov20/15 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup15/19/20 >>>>>[->>>>+>+<<<<<]
-            mov19/15 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup13/19/20 <<<[->>>>>>+>+<<<<<<<]
-            mov19/13 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov20/16 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup16/19/20 <[->>>+>+<<<<]
-            mov19/16 >>>[-<<<+>>>]
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %8_ %7 }
             op_to_reg storing const value in temp address
-            >>#constop_0
+            >>>>>>#constop_0
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov21/24 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
+            [-]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
             <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup16/19/20 <<<<<<[->>>+>+<<<<]
-            mov19/16 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
-            l1 <
-            <[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>>]
-    <<<<<<<<<<]
-    >#putdec
-    [
-        >>>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>>>-
-            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        <<]
+        >>#B:%1
         [
             -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %8_ %7 }
+            br label %3
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/36 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
             doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
+            %4 = phi i32 { i32 %5_ %3 }_ { i32 0_ %1 }
             op_to_reg storing const value in temp address
-            <#constop_1
-            +
-            <<<<<<<<<<<<<[-]
-            mov34/21 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            <<<<<<<<<<<<<<<<[-]
-            mov35/19 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
-                -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
+            >>>>>#constop_0
+            
+            [-]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<+
+        <]
+        >#B:%3
         [
             -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+            %4 = phi i32 { i32 %5_ %3 }_ { i32 0_ %1 }
+            call @putchar(i8 46)
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>#constop_46
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup19/34/35 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            %5 = add i32 %4_ i32 1
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov36/39 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup19/34/35 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov35/25 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup21/36/37 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov36/21 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            <<<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
+            >>#constop_1
+            +
+            mov14/10 <[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/18/20 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
                 -
-                dup37/22/39 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov39/37 >>[-<<+>>]
-            <]
-            <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup22/34/35 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov34/22 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup35/37/38 >[->>+>+<<<]
-            mov38/35 >>>[-<<<+>>>]
+                <-
+            >]
+            mov20/10 >>[-<<<<<<<<<<+>>>>>>>>>>]
             <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/42 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
-                    -
-                    <+
-                    >>>>>>[
-                        -
-                        <<<<<<-
-                        r1 >
-                    >>>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov42/33 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup35/37/38 <<<<<<<[->>+>+<<<]
-                mov38/35 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup23/34/35 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov34/23 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov35/37 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup22/34/35 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov34/22 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov35/21 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup22/36/37 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov36/22 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov37/19 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
                 -
                 >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
-        [
-            -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup25/38/39 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov38/25 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup39/41/42 >[->>+>+<<<]
-            mov42/39 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/45 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
-                    -
-                    <+
-                    >>>>>[
-                        -
-                        <<<<<-
-                        r1 >
-                    >>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov45/33 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup39/41/42 <<<<<<[->>+>+<<<]
-                mov42/39 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
-            >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup26/34/35 <[->>>>>>>>+>+<<<<<<<<<]
-            mov34/26 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup36/38/39 [->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov35/40 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
+                <<[
                     -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
+                    mov17/18 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
                 <<]
-                >>>>-
-                mov40/35 <<[-<<<<<+>>>>>]
-                dup36/38/39 <<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov38/27 >[-<<<<<<<<<<<+>>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
-            ]
-            mov37/27 [-<<<<<<<<<<+>>>>>>>>>>]
-            <[-]
-            >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup27/34/35 <[->>>>>>>+>+<<<<<<<<]
-            mov34/27 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov35/28 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup28/34/35 <[->>>>>>+>+<<<<<<<]
-            mov34/28 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov35/29 <[-<<<<<<+>>>>>>]
-            >[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            dup29/34/35 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov34/29 >>>>>[-<<<<<+>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup25/34/35 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov34/25 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup36/38/39 [->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov35/40 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
+                mov18/17 >[-<+>]
+                >[
                     -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
-                    [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov40/35 >>>[-<<<<<+>>>>>]
-                dup36/38/39 <<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
             <<<<]
-            >[-]
             >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup25/34/35 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov34/25 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov35/31 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup31/34/35 <[->>>+>+<<<<]
-            mov34/31 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov36/37 <<<[->+<]
-            >>>+
-            <<<<[
-                -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov30/25 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l11 <<<<<<<<<<<
-        >>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<]
-    >#c
-    [
-        >>>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/c
-            >>>>>>>>-
-            l12 <<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_c
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%no_block0_call_for_c
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        <<<#B:%no_block0_call_for_c
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            call @putchar(i8 46)
-            enable next block when we return
-            >#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >#constop_46
-            ++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<]
-        >#B:%call_term_for_0
-        [
-            -
-            ret void
-            zero all function allocs
-            <<<<<<<<<<-
-            #dead_frame
-            >>>-
-            l1 <
-            <<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>]
-    <<<<<<<]
-    >#b
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/b
-            >>>>>>>>-
-            l22 <<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_b
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%1
-        >#B:%2
-        >#B:%call_term_for_2
-        >#%3_=_phi_i32_{_i32_0__%0_}__{_i32_%4__%call_term_for_2_}(mult)
-        >#%4_=_add_i32_%3__i32_1(mult)
-        >#%5_=_icmp_eq_i32_%4__i32_2(mult)
-        <<<<<<<#B:%0
-        [
-            -
-            copy up args
-            br label %2
-            doing phi stuff
-            %3 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>#constop_0
+            %6 = icmp eq i32 %4_ i32 0
             
-            <<<[-]
-            mov15/12 >>>[-<<<+>>>]
-            <<<<<+
-        <<<]
-        >>#B:%1
-        [
-            -
-            ret void
-            zero all function allocs
-            >>>[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<-
-            #dead_frame
-            >>>>-
-            l1 <
-            <<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>]
-        >#B:%2
-        [
-            -
-            %3 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            call @c()
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            stack_width 22
-            ret_pad_width 8
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r22 >>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>>>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<<<#===FRAME_c
-            +
-            >>>#c
-            +
-            >>>>#c/b0
-            +
-        >>>]
-        >#B:%call_term_for_2
-        [
-            -
-            %4 = add i32 %3_ i32 1
-            >>[-]
-            dup12/16/15 <[->>>>+<+<<<]
-            mov16/12 >>>>[-<<<<+>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            mov15/13 <<[-<<+>>]
-            >>[
-                -
-                <<<<+
-            >>>>]
-            %5 = icmp eq i32 %4_ i32 2
-            <<<[-]
-            dup13/15/16 <[->>+>+<<<]
-            mov15/13 >>[-<<+>>]
-            op_to_reg storing const value in temp address
-            >>>#constop_2
-            ++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/19 <<<[->+<]
-            >>>+
-            <<<<<[
-                -
-                >+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<+
-            >>>>>[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            <<[
+            <<<<<<[-]
+            dup9/13/14 <<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<+
+            >>>[
                 [-]
                 <<<[-]
             >>>]
-            br i1 %5_ label %1_ label %2
+            br i1 %6_ label %3_ label %7
+            
+            dup11/14/13 <<<[->>>+<+<<]
+            mov14/11 >>>[-<<<+>>>]
             doing phi stuff
-            %3 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            dup13/15/16 <<<<[->>+>+<<<]
-            mov15/13 >>[-<<+>>]
-            <<<[-]
-            mov16/12 >>>>[-<<<<+>>>>]
-            <<<<<<<<+
-            >>>>>>[
-                -
-                <<<<<<-
-                >+
-                #B:b/1_true
-            >>>>>]
-            <<<<<<[
+            %4 = phi i32 { i32 %5_ %3 }_ { i32 0_ %1 }
+            mov10/15 <<<<[->>>>>+<<<<<]
+            <<<<<<<+
+            >>>>>>>>>>[
                 -
+                <<<<<<<<<<-
                 >>+
-                #B:b/2_false
+                #B:main/%3_true
+                >>>>[-]
+                mov15/9 >>>>>>[-<<<<<<+>>>>>>]
             <<]
-        >>>]
-    <<<<<<<]
-    >#a
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>>-
-            <<<<<#dead_fn_pad/a
-            >>>>>>>>-
-            l22 <<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_a
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%1
-        >#B:%2
-        >#B:%call_term_for_2
-        >#%3_=_phi_i32_{_i32_0__%0_}__{_i32_%4__%call_term_for_2_}(mult)
-        >#%4_=_add_i32_%3__i32_1(mult)
-        >#%5_=_icmp_eq_i32_%4__i32_2(mult)
-        <<<<<<<#B:%0
+            <<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%7_false
+                >>>>>>>>>[-]
+            <<<<<<<<<<<<]
+        >>]
+        >#B:%7
         [
             -
-            copy up args
-            br label %2
-            doing phi stuff
-            %3 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>#constop_0
+            %8 = add i32 %2_ i32 1
             
-            <<<[-]
-            mov15/12 >>>[-<<<+>>>]
-            <<<<<+
-        <<<]
-        >>#B:%1
-        [
-            -
-            ret void
-            zero all function allocs
-            >>>[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<-
-            #dead_frame
-            >>>>>-
-            l1 <
-            <<<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>]
-        >#B:%2
-        [
-            -
-            %3 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            call @b()
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            stack_width 22
-            ret_pad_width 8
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r22 >>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>#caller
-            +
-            >>>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<<<#===FRAME_b
-            +
-            >>>>#b
-            +
-            >>>#b/b0
-            +
-        >>>]
-        >#B:%call_term_for_2
-        [
-            -
-            %4 = add i32 %3_ i32 1
-            >>[-]
-            dup12/16/15 <[->>>>+<+<<<]
-            mov16/12 >>>>[-<<<<+>>>>]
+            >>>>>>[-]
+            dup8/16/17 <<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov16/8 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
             op_to_reg storing const value in temp address
-            >#constop_1
+            >>#constop_1
             +
-            mov15/13 <<[-<<+>>]
-            >>[
+            mov17/12 <[-<<<<<+>>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/22/24 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
                 -
-                <<<<+
-            >>>>]
-            %5 = icmp eq i32 %4_ i32 2
-            <<<[-]
-            dup13/15/16 <[->>+>+<<<]
-            mov15/13 >>[-<<+>>]
-            op_to_reg storing const value in temp address
-            >>>#constop_2
-            ++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/19 <<<[->+<]
-            >>>+
-            <<<<<[
+                <-
+            >]
+            mov24/12 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<[
                 -
-                >+
-                >>[
+                >>>>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
                     -
-                    <<-
-                    r1 >
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
                 >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<+
-            >>>>>[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            <<[
+            <<<<<]
+            >>>[-]
+            %9 = icmp eq i32 %2_ i32 0
+            
+            <<<<<<<<<<[-]
+            dup8/13/14 <<<[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            <<+
+            >>>[
                 [-]
                 <<<[-]
             >>>]
-            br i1 %5_ label %1_ label %2
+            br i1 %9_ label %1_ label %10
+            
+            dup11/14/13 <<<[->>>+<+<<]
+            mov14/11 >>>[-<<<+>>>]
             doing phi stuff
-            %3 = phi i32 _ i32 0_ %0 __ _ i32 %4_ %call_term_for_2 _
-            dup13/15/16 <<<<[->>+>+<<<]
-            mov15/13 >>[-<<+>>]
-            <<<[-]
-            mov16/12 >>>>[-<<<<+>>>>]
-            <<<<<<<<+
-            >>>>>>[
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %8_ %7 }
+            mov12/15 <<[->>>+<<<]
+            <<<<<<<<<+
+            >>>>>>>>>>[
                 -
-                <<<<<<-
+                <<<<<<<<<<-
                 >+
-                #B:a/1_true
-            >>>>>]
-            <<<<<<[
-                -
-                >>+
-                #B:a/2_false
+                #B:main/%1_true
+                >>>>[-]
+                mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
             <<]
+            <<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%10_false
+                >>>>>>>>[-]
+            <<<<<<<<<<<<]
         >>>]
-    <<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<<<<-
-            #dead_frame
-            >>>>>>-
-            <<<<<<#dead_fn_pad/main
-            >>>>>>>>-
-            l11 <<<<<<<<<<<
-        ]
-        <<<<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:c
-        >#F:b
-        >#F:a
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        <<<#B:%no_block0_call_for_main
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            call @a()
-            enable next block when we return
-            >#caller/%call_term_for_0
-            +
-            stack_width 11
-            ret_pad_width 8
-            give callee a stack pointer
-            >>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++
-            l1 <
-            dup0/21/22 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
-            mov22/0 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r11 >>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<<<#===FRAME_a
-            +
-            >>>>>#a
-            +
-            >>#a/b0
-            +
-        >>]
-        >#B:%call_term_for_0
+        >#B:%10
         [
             -
             ret void
+            
             zero all function allocs
-            <<<<<<<<<<-
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<-
             #dead_frame
-            >>>>>>-
+            >-
             l1 <
-            <<<<<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>>>]
-    <<<<]
-<<<<<<]
+            <[-]
+            r1 
+            l5 
+        >>>]
+    <<<<<<]
+<]
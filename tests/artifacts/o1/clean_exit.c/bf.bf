@@ -0,0 +1,566 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  pick (depth 1)
+  main calls pick (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  pick: 3390 ops ~115481 steps
+  main: 961 ops ~3565 steps
+frame widths:
+  pick: 33 cells
+  main: 15 cells
+r1 >
++
+r1 >
+++
+r1 >
++++
+r1 >
+r5 >>>>>
++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#pick
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/pick
+            >>>-
+            l33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_pick
+        #F:pick
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%10
+        #%2_=_icmp_eq_i8_%0__i8_0(mult)
+        #%5_=_add_i64_%4__i64_4294967295(mult)
+        #%9_=_add_i8_%8__i8_97(mult)
+        #%11_=_phi_i8_{_i8_%9__%3_}__{_i8_122__%1_}(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/13 <<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            r2 >>
+            %2 = icmp eq i8 %0_ i8 0
+            
+            >>>>>>>[-]
+            dup11/12/13 >>>>[->+>+<<]
+            mov12/11 >[-<+>]
+            <<<<<+
+            >>>>>>[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %2_ label %10_ label %3
+            
+            dup7/14/12 <<<<<<[->>>>>>>+<<+<<<<<]
+            mov14/7 >>>>>>>[-<<<<<<<+>>>>>>>]
+            doing phi stuff
+            %11 = phi i8 { i8 %9_ %3 }_ { i8 122_ %1 }
+            op_to_reg storing const value in temp address
+            #constop_122
+            >+++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            mov13/16 [->>>+<<<]
+            <<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >>+
+                #B:pick/%10_true
+                >>>>[-]
+                mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<]
+            <<<<<<<<[
+                -
+                >+
+                #B:pick/%3_false
+                >>>>>>>>>>>[-]
+            <<<<<<<<<<<<]
+        <]
+        >>#B:%3
+        [
+            -
+            %4 = zext i8 %0 to i64
+            
+            >>[-]
+            dup11/17/18 >>>>[->>>>>>+>+<<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            mov18/7 >[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %5 = add i64 %4_ i64 4294967295
+            
+            <<<<<<<<<<[-]
+            dup7/12/13 <[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov13/8 <<[-<<<<<+>>>>>]
+            >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/20/22 <<<<<<<<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov22/8 >>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<<<<<<<+
+                    >>>>>>>>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %6 = and i64 %5_ i64 4294967295
+            
+            <<<<<<<<<<<<[-]
+            dup8/12/13 >[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov13/23 <<[->>>>>>>>>>+<<<<<<<<<<]
+            mov14/28 >[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            >>>>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<<<+
+            [
+                [-]
+                >>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup27/24/23 >>>>[-<<<+<+>>>>]
+                mov24/27 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup32/29/28 >>>>[-<<<+<+>>>>]
+                mov29/32 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov31/16 >>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup20/17/18 <<<<<<<<<<<[-<<<+>+>>]
+                mov17/20 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov17/16 >[-<+>]
+                >]
+                mov16/7 <<[-<<<<<<<<<+>>>>>>>>>]
+                continue?
+                dup23/16/17 >>>>>>>[-<<<<<<<+>+>>>>>>]
+                mov16/23 <<<<<<<[->>>>>>>+<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup28/16/17 >>>>>>>>>>>[-<<<<<<<<<<<<+>+>>>>>>>>>>>]
+                mov16/28 <<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>>>-
+            >>>>>-
+            <<<<<<<<<<[-]
+            %7 = getelementptr inbounds {3 x i8}* @counts_ i64 0_ i64 %6
+            
+            <<<<<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>#constop_1
+            +
+            dup7/13/14 <<<<<[->>>>>>+>+<<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            mov12/8 <[-<<<<+>>>>]
+            >>[
+                -
+                <<<<<<+
+            >>>>>>]
+            %8 = load i8* %7_ align 1
+            
+            <<<<<<<[-]
+            dup8/12/13 >[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/20 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov20/16 [-<<<<+>>>>]
+                <<<<++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov15/14 <[-<+>]
+                mov16/15 >[-<+>]
+                
+                mov13/16 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup13/14/16 <<<[->+>>+<<<]
+            mov14/13 >[-<+>]
+            >[
+                mov17/14 >>[-<<<+>>>]
+                mov16/17 <[->+<]
+                mov15/16 <[->+<]
+                r1 >
+                -
+            ]
+            mov16/7 >[-<<<<<<<<<+>>>>>>>>>]
+            %9 = add i8 %8_ i8 97
+            
+            <<<<<<<[-]
+            dup7/12/13 <<[->>>>>+>+<<<<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov13/9 <[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/17/19 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov19/9 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            br label %10
+            
+            doing phi stuff
+            %11 = phi i8 { i8 %9_ %3 }_ { i8 122_ %1 }
+            <<<<<<[-]
+            mov9/10 <[->+<]
+            <<<+
+        <]
+        >#B:%10
+        [
+            -
+            %11 = phi i8 { i8 %9_ %3 }_ { i8 122_ %1 }
+            ret i8 %11
+            
+            mov10/12 >>>>[->>+<<]
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            mov12/7 >[-<<<<<+>>>>>]
+            <<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        ]
+    <<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:pick
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_call_@pick(i8_%2)(mult)
+        #%4_=_call_@pick(i8_0)(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            store volatile i8 2_ i8* %1_ align 1
+            
+            >>>[-]
+            ++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 2
+            ++
+            %3 = call @pick(i8 %2)
+            
+            >[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_0
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_2
+            ++
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_pick
+            +
+            >#pick
+            +
+            >>#pick/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %3
+            mov29/10 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup10/13/12 <<<<<<<<<<<<<<<<<<<[->>>+<+<<]
+            mov13/10 >>>[-<<<+>>>]
+            <.
+            [-]
+            %4 = call @pick(i8 0)
+            
+            <[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_1
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_pick
+            +
+            >#pick
+            +
+            >>#pick/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            returned %4
+            mov29/11 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup11/12/14 <<<<<<<<<<<<<<<<<<[->+>>+<<<]
+            mov12/11 >[-<+>]
+            >>.
+            [-]
+            ret i32 7
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
+clear globals:
+l4 <<<
+[-]
+>[-]
+>[-]
@@ -0,0 +1,459 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2956 ops ~89217 steps
+frame widths:
+  main: 35 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l35 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%2
+        #B:%3
+        #B:%7
+        #B:%8
+        #alloc_%1
+        #%4_=_phi_i32_{_i32_0__%0_}__{_i32_%12__%8_}(mult)
+        #%5_=_load_volatile_i8*_%1__align_1(mult)
+        #%6_=_icmp_ult_i8_%5__i8_97(mult)
+        #%9_=_phi_i8_{_i8_85__%7_}__{_i8_45__%3_}(mult)
+        #%10_=_phi_i8_{_i8_86__%7_}__{_i8_87__%3_}(mult)
+        #%12_=_and_i32_%11__i32_255(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 65_ i8* %1_ align 1
+            
+            >>>>>>[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++
+            >>>>>>>]
+            <<<<<<<+
+            br label %3
+            
+            doing phi stuff
+            %4 = phi i32 { i32 0_ %0 }_ { i32 %12_ %8 }
+            op_to_reg storing const value in temp address
+            >#constop_0
+            
+            [-]
+            mov16/9 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<+
+        <<<]
+        >>#B:%2
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >>>>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        ]
+        >#B:%3
+        [
+            -
+            %4 = phi i32 { i32 0_ %0 }_ { i32 %12_ %8 }
+            %5 = load volatile i8* %1_ align 1
+            
+            >>>>>[-]
+            dup8/10/15 <<[->>+>>>>>+<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %6 = icmp ult i8 %5_ i8 97
+            
+            <<<<[-]
+            dup10/15/17 <[->>>>>+>>+<<<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >#constop_97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/19 [->>>+<<<]
+            >>>>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<+
+            >>>>>>>>]
+            br i1 %6_ label %7_ label %8
+            
+            dup11/16/15 <<<<<<<<[->>>>>+<+<<<<]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
+            doing phi stuff
+            %9 = phi i8 { i8 85_ %7 }_ { i8 45_ %3 }
+            op_to_reg storing const value in temp address
+            >#constop_45
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            mov17/19 <[->>+<<]
+            doing phi stuff
+            %10 = phi i8 { i8 86_ %7 }_ { i8 87_ %3 }
+            op_to_reg storing const value in temp address
+            >>>#constop_87
+            >>+++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+++
+            mov20/21 [->+<]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>>+
+                #B:main/%7_true
+                >>>>>>>>>>>>>[-]
+                >>[-]
+            <<<<<<]
+            <<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%8_false
+                >>>>>[-]
+                mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+                <<<<<<[-]
+                mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<]
+        >>]
+        >#B:%7
+        [
+            -
+            call @putchar(i8 84_ i8 45)
+            
+            putchar run intrinsic
+            >>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
+            ---------------------------------------
+            .
+            [-]
+            br label %8
+            
+            doing phi stuff
+            %9 = phi i8 { i8 85_ %7 }_ { i8 45_ %3 }
+            op_to_reg storing const value in temp address
+            <<<<<<<#constop_85
+            +++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov15/17 [->>+<<]
+            doing phi stuff
+            %10 = phi i8 { i8 86_ %7 }_ { i8 87_ %3 }
+            op_to_reg storing const value in temp address
+            >>>#constop_86
+            >+++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <++
+            mov18/20 [->>+<<]
+            <<<<<<[-]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            <<<<[-]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<+
+        <]
+        >#B:%8
+        [
+            -
+            %9 = phi i8 { i8 85_ %7 }_ { i8 45_ %3 }
+            %10 = phi i8 { i8 86_ %7 }_ { i8 87_ %3 }
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            dup12/21/22 >>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov21/12 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup13/15/16 <<<<<<<<<[->>+>+<<<]
+            mov15/13 >>[-<<+>>]
+            >.
+            [-]
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            ++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            store volatile i8 97_ i8* %1_ align 1
+            
+            <<<<<<<[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++++++
+            >>>>>>>]
+            <<<<<<<+
+            %11 = add i32 %4_ i32 1
+            
+            >>[-]
+            dup9/15/16 <[->>>>>>+>+<<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov16/10 <[-<<<<<<+>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/19/21 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov21/10 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %12 = and i32 %11_ i32 255
+            
+            <<<<[-]
+            dup10/15/16 <<<<[->>>>>+>+<<<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov16/25 <<[->>>>>>>>>+<<<<<<<<<]
+            mov17/30 >[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<<+
+            [
+                [-]
+                >>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup29/26/25 >>>>[-<<<+<+>>>>]
+                mov26/29 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup34/31/30 >>>>[-<<<+<+>>>>]
+                mov31/34 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov33/19 >>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup23/20/21 <<<<<<<<<<[-<<<+>+>>]
+                mov20/23 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov20/19 >[-<+>]
+                >]
+                mov19/14 <<[-<<<<<+>>>>>]
+                continue?
+                dup25/19/20 >>>>>>[-<<<<<<+>+>>>>>]
+                mov19/25 <<<<<<[->>>>>>+<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup30/19/20 >>>>>>>>>>[-<<<<<<<<<<<+>+>>>>>>>>>>]
+                mov19/30 <<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>>-
+            >>>>>-
+            <<<<<<<<<[-]
+            %13 = icmp ult i32 %12_ i32 2
+            
+            <<<<<<<<<<<<<[-]
+            dup14/15/16 >>>>[->+>+<<]
+            mov15/14 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov17/18 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<+
+            >>>>>>>>]
+            br i1 %13_ label %3_ label %2
+            
+            dup10/16/15 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
+            doing phi stuff
+            %4 = phi i32 { i32 0_ %0 }_ { i32 %12_ %8 }
+            dup14/17/18 <<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            mov18/19 >[->+<]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<-
+                >>+
+                #B:main/%3_true
+                >>>>[-]
+                mov19/9 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<]
+            <<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%2_false
+                >>>>>>>>>>>>>>>[-]
+            <<<<<<<<<<<<<<<<]
+        >>>>]
+    <<<<<<]
+<]
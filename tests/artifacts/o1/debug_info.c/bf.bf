@@ -0,0 +1,597 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 5715 ops ~160065 steps
+frame widths:
+  main: 40 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l40 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%9
+        #B:%10
+        #alloc_%1
+        #alloc_%1{1}
+        #alloc_%1{2}
+        #%4_=_phi_i64_{_i64_0__%0_}__{_i64_%7__%3_}(mult)
+        #%5_=_call_@getchar()_(with_debugloc)(mult)
+        #%6_=_getelementptr_inbounds_{3_x_i8}*_%1__i64_0__i64_%4_(with_debugloc)(mult)
+        #%7_=_add_i64_%4__i64_1_(with_debugloc)(mult)
+        #%11_=_phi_i64_{_i64_%16__%10_}__{_i64_3__%3_}(mult)
+        #%13_=_and_i64_%12__i64_4294967295(mult)
+        #%16_=_add_i64_%11__i64__1_(with_debugloc)(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca {3 x i8}_ align 1
+            br label %3 (with debugloc)
+            
+            doing phi stuff
+            %4 = phi i64 { i64 0_ %0 }_ { i64 %7_ %3 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>#constop_0
+            
+            [-]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<+
+        <<]
+        >>#B:%3
+        [
+            -
+            %4 = phi i64 { i64 0_ %0 }_ { i64 %7_ %3 }
+            %5 = call @getchar() (with debugloc)
+            
+            >>>>>>>[-]
+            getchar intrinsic
+            ,
+            %6 = getelementptr inbounds {3 x i8}* %1_ i64 0_ i64 %4 (with debugloc)
+            
+            >[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/19/18 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            ++++++++
+            dup10/19/20 <<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov19/10 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov18/12 <[-<<<<<<+>>>>>>]
+            >>[
+                -
+                <<<<<<<<+
+            >>>>>>>>]
+            store i8 %5_ i8* %6_ align 1 (with debugloc)
+            
+            dup11/17/18 <<<<<<<<<[->>>>>>+>+<<<<<<<]
+            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
+            dup12/19/21 <<<<<[->>>>>>>+>>+<<<<<<<<<]
+            mov19/12 >>>>>>>[-<<<<<<<+>>>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/21/27 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov21/0 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            >>>>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov26/28 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov28/24 [-<<<<+>>>>]
+                <<<<++++++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<++++++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            mov18/25 <<<<<<<<<[->>>>>>>+<<<<<<<]
+            drive left! choo choo
+            >>>>>>[
+                mov23/22 <[-<+>]
+                mov24/23 >[-<+>]
+                mov25/24 >[-<+>]
+                mov21/25 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov25/21 >>>>[-<<<<+>>>>]
+            <<[
+                mov26/22 >>>[-<<<<+>>>>]
+                
+                mov23/24 <<<[->+<]
+                r1 >
+                -
+            ]
+            %7 = add i64 %4_ i64 1 (with debugloc)
+            
+            <<<<<<<<<<[-]
+            dup10/17/18 <<<[->>>>>>>+>+<<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov18/13 <[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/21/23 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov23/13 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov20/21 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov21/20 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %8 = icmp eq i64 %7_ i64 3 (with debugloc)
+            
+            <<<<<<<<[-]
+            dup13/17/18 >[->>>>+>+<<<<<]
+            mov17/13 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_3
+            +++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/21 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<+
+            >>>>>>>>>[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            br i1 %8_ label %10_ label %3 (with debugloc)
+            
+            dup12/18/17 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            doing phi stuff
+            %11 = phi i64 { i64 %16_ %10 }_ { i64 3_ %3 }
+            op_to_reg storing const value in temp address
+            >#constop_3
+            +++
+            mov19/20 [->+<]
+            doing phi stuff
+            %4 = phi i64 { i64 0_ %0 }_ { i64 %7_ %3 }
+            dup13/21/22 <<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov21/13 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            mov22/23 >[->+<]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%10_true
+                >>>>>>>>[-]
+                mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+                >>>[-]
+            <<<<<<]
+            <<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%3_false
+                >>>>>>[-]
+                mov23/10 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<]
+        >]
+        >#B:%9
+        [
+            -
+            ret i32 0 (with debugloc)
+            
+            zero all function allocs
+            >>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+        >#B:%10
+        [
+            -
+            %11 = phi i64 { i64 %16_ %10 }_ { i64 3_ %3 }
+            %12 = add i64 %11_ i64 4294967295 (with debugloc)
+            
+            >>>>>>[-]
+            dup14/24/25 >>[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov25/12 <<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/32/34 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov34/12 >>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov31/32 [->+<]
+                    <<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov32/31 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %13 = and i64 %12_ i64 4294967295
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup12/17/18 <<<[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov18/26 <<[->>>>>>>>+<<<<<<<<]
+            mov19/35 >[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>>+
+            >>>>>>>>>+
+            <<<<<<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup30/27/26 >>>>[-<<<+<+>>>>]
+                mov27/30 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup39/36/35 >>>>[-<<<+<+>>>>]
+                mov36/39 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<<<<<[
+                    -
+                    mov38/21 >>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<]
+                >>>>>>>>>[-]
+                dup25/22/23 <<<<<<<<<<<<<[-<<<+>+>>]
+                mov22/25 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov22/21 >[-<+>]
+                >]
+                mov21/15 <<[-<<<<<<+>>>>>>]
+                continue?
+                dup26/21/22 >>>>>[-<<<<<+>+>>>>]
+                mov21/26 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup35/21/22 >>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>]
+                mov21/35 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>>>>-
+            <<<<<<<<<<<<[-]
+            %14 = getelementptr inbounds {3 x i8}* %1_ i64 0_ i64 %13 (with debugloc)
+            
+            <<<<<<<<<<<<<[-]
+            resolve alloca addr storing pointer value in temp address
+            l1 <
+            dup0/18/19 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov19/0 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            r1 
+            <++++++++
+            dup15/19/20 <<[->>>>+>+<<<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            mov17/12 <<[-<<<<<+>>>>>]
+            >>>[
+                -
+                <<<<<<<<+
+            >>>>>>>>]
+            %15 = load i8* %14_ align 1 (with debugloc)
+            
+            <<<<<[-]
+            dup12/17/18 <<<[->>>>>+>+<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/23/24 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/25 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov25/21 [-<<<<+>>>>]
+                <<<<+++++++++++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov20/19 <[-<+>]
+                mov21/20 >[-<+>]
+                
+                mov18/21 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup18/19/21 <<<[->+>>+<<<]
+            mov19/18 >[-<+>]
+            >[
+                mov22/19 >>[-<<<+>>>]
+                mov21/22 <[->+<]
+                mov20/21 <[->+<]
+                r1 >
+                -
+            ]
+            mov21/15 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %15) (with debugloc)
+            
+            putchar intrinsic
+            dup15/17/18 <<<<<<[->>+>+<<<]
+            mov17/15 >>[-<<+>>]
+            >.
+            [-]
+            %16 = add i64 %11_ i64 _1 (with debugloc)
+            
+            <<[-]
+            dup14/17/18 <<[->>>+>+<<<<]
+            mov17/14 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov18/16 <<[-<<+>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/22/24 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov24/16 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %17 = icmp eq i64 %16_ i64 0 (with debugloc)
+            
+            <<<<<<<<<[-]
+            dup16/17/18 >>>>[->+>+<<]
+            mov17/16 >[-<+>]
+            <<<<<+
+            >>>>>>[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %17_ label %9_ label %10 (with debugloc)
+            
+            dup12/18/17 <<<<<<[->>>>>>+<+<<<<<]
+            mov18/12 >>>>>>[-<<<<<<+>>>>>>]
+            doing phi stuff
+            %11 = phi i64 { i64 %16_ %10 }_ { i64 3_ %3 }
+            dup16/19/20 <<[->>>+>+<<<<]
+            mov19/16 >>>[-<<<+>>>]
+            mov20/21 >[->+<]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >>+
+                #B:main/%9_true
+                >>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%10_false
+                >>>>>>>>[-]
+                mov21/14 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<<<<]
+        >>>]
+    <<<<<]
+<]
@@ -0,0 +1,249 @@
+; ModuleID = './tests/artifacts/o1/debug_info.c/ir.bc'
+source_filename = "./tests/cases/debug_info.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 !dbg !9 {
+  call void @llvm.dbg.value(metadata i8* %0, metadata !16, metadata !DIExpression()), !dbg !17
+  %2 = load i8, i8* %0, align 1, !dbg !18, !tbaa !19
+  %3 = icmp eq i8 %2, 0, !dbg !22
+  br i1 %3, label %10, label %4, !dbg !22
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @llvm.dbg.value(metadata i8* %6, metadata !16, metadata !DIExpression()), !dbg !17
+  call void @putchar(i8 noundef zeroext %5) #5, !dbg !23
+  %7 = getelementptr inbounds i8, i8* %6, i64 1, !dbg !25
+  call void @llvm.dbg.value(metadata i8* %7, metadata !16, metadata !DIExpression()), !dbg !17
+  %8 = load i8, i8* %7, align 1, !dbg !18, !tbaa !19
+  %9 = icmp eq i8 %8, 0, !dbg !22
+  br i1 %9, label %10, label %4, !dbg !22, !llvm.loop !26
+
+10:                                               ; preds = %4, %1
+  ret void, !dbg !30
+}
+
+; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
+declare void @llvm.dbg.declare(metadata, metadata, metadata) #1
+
+declare !dbg !31 void @putchar(i8 noundef zeroext) local_unnamed_addr #2
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 !dbg !36 {
+  call void @llvm.dbg.value(metadata i32 %0, metadata !41, metadata !DIExpression()), !dbg !43
+  %2 = icmp eq i32 %0, 0, !dbg !44
+  br i1 %2, label %5, label %3, !dbg !46
+
+3:                                                ; preds = %1
+  call void @llvm.dbg.value(metadata i32 1, metadata !42, metadata !DIExpression()), !dbg !43
+  %4 = icmp ugt i32 %0, 9, !dbg !47
+  br i1 %4, label %9, label %6, !dbg !48
+
+5:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48) #5, !dbg !49
+  br label %22, !dbg !51
+
+6:                                                ; preds = %9, %3
+  %7 = phi i32 [ 1, %3 ], [ %11, %9 ], !dbg !43
+  call void @llvm.dbg.value(metadata i32 %7, metadata !42, metadata !DIExpression()), !dbg !43
+  %8 = icmp eq i32 %7, 0, !dbg !52
+  br i1 %8, label %22, label %14, !dbg !52
+
+9:                                                ; preds = %9, %3
+  %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
+  call void @llvm.dbg.value(metadata i32 %10, metadata !42, metadata !DIExpression()), !dbg !43
+  %11 = mul i32 %10, 10, !dbg !53
+  call void @llvm.dbg.value(metadata i32 %11, metadata !42, metadata !DIExpression()), !dbg !43
+  %12 = udiv i32 %0, %11, !dbg !54
+  %13 = icmp ugt i32 %12, 9, !dbg !47
+  br i1 %13, label %9, label %6, !dbg !48, !llvm.loop !55
+
+14:                                               ; preds = %14, %6
+  %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
+  call void @llvm.dbg.value(metadata i32 %15, metadata !42, metadata !DIExpression()), !dbg !43
+  %16 = udiv i32 %0, %15, !dbg !57
+  %17 = urem i32 %16, 10, !dbg !59
+  %18 = trunc i32 %17 to i8, !dbg !60
+  %19 = or i8 %18, 48, !dbg !60
+  call void @putchar(i8 noundef zeroext %19) #5, !dbg !61
+  %20 = udiv i32 %15, 10, !dbg !62
+  call void @llvm.dbg.value(metadata i32 %20, metadata !42, metadata !DIExpression()), !dbg !43
+  %21 = icmp ult i32 %15, 10, !dbg !52
+  br i1 %21, label %22, label %14, !dbg !52, !llvm.loop !63
+
+22:                                               ; preds = %14, %6, %5
+  ret void, !dbg !65
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #3
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #3
+
+; Function Attrs: nounwind uwtable
+define dso_local i32 @main() local_unnamed_addr #0 !dbg !66 {
+  %1 = alloca [3 x i8], align 1
+  %2 = getelementptr inbounds [3 x i8], [3 x i8]* %1, i64 0, i64 0, !dbg !80
+  call void @llvm.lifetime.start.p0i8(i64 3, i8* nonnull %2) #5, !dbg !80
+  call void @llvm.dbg.declare(metadata [3 x i8]* %1, metadata !72, metadata !DIExpression()), !dbg !81
+  call void @llvm.dbg.value(metadata i8 0, metadata !76, metadata !DIExpression()), !dbg !82
+  br label %3, !dbg !83
+
+3:                                                ; preds = %3, %0
+  %4 = phi i64 [ 0, %0 ], [ %7, %3 ]
+  call void @llvm.dbg.value(metadata i64 %4, metadata !76, metadata !DIExpression()), !dbg !82
+  %5 = call zeroext i8 @getchar(), !dbg !84
+  %6 = getelementptr inbounds [3 x i8], [3 x i8]* %1, i64 0, i64 %4, !dbg !87
+  store i8 %5, i8* %6, align 1, !dbg !88, !tbaa !19
+  %7 = add nuw nsw i64 %4, 1, !dbg !89
+  call void @llvm.dbg.value(metadata i64 %7, metadata !76, metadata !DIExpression()), !dbg !82
+  %8 = icmp eq i64 %7, 3, !dbg !90
+  br i1 %8, label %10, label %3, !dbg !83, !llvm.loop !91
+
+9:                                                ; preds = %10
+  call void @llvm.lifetime.end.p0i8(i64 3, i8* nonnull %2) #5, !dbg !93
+  ret i32 0, !dbg !94
+
+10:                                               ; preds = %10, %3
+  %11 = phi i64 [ %16, %10 ], [ 3, %3 ]
+  call void @llvm.dbg.value(metadata i64 %11, metadata !78, metadata !DIExpression()), !dbg !95
+  %12 = add nuw nsw i64 %11, 4294967295, !dbg !96
+  %13 = and i64 %12, 4294967295
+  %14 = getelementptr inbounds [3 x i8], [3 x i8]* %1, i64 0, i64 %13, !dbg !99
+  %15 = load i8, i8* %14, align 1, !dbg !99, !tbaa !19
+  call void @putchar(i8 noundef zeroext %15) #5, !dbg !100
+  %16 = add nsw i64 %11, -1, !dbg !101
+  call void @llvm.dbg.value(metadata i64 %16, metadata !78, metadata !DIExpression()), !dbg !95
+  %17 = icmp eq i64 %16, 0, !dbg !102
+  br i1 %17, label %9, label %10, !dbg !103, !llvm.loop !104
+}
+
+; Function Attrs: nofree nounwind
+declare !dbg !106 noundef zeroext i8 @getchar() local_unnamed_addr #4
+
+; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
+declare void @llvm.dbg.value(metadata, metadata, metadata) #1
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { nofree nosync nounwind readnone speculatable willreturn }
+attributes #2 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #3 = { argmemonly nofree nosync nounwind willreturn }
+attributes #4 = { nofree nounwind "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #5 = { nounwind }
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!2, !3, !4, !5, !6, !7}
+!llvm.ident = !{!8}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "Debian clang version 14.0.6", isOptimized: true, runtimeVersion: 0, emissionKind: FullDebug, splitDebugInlining: false, nameTableKind: None)
+!1 = !DIFile(filename: "tests/cases/debug_info.c", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "e5079375f93b388a0692332ff725c732")
+!2 = !{i32 7, !"Dwarf Version", i32 5}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = !{i32 1, !"wchar_size", i32 4}
+!5 = !{i32 7, !"PIC Level", i32 2}
+!6 = !{i32 7, !"PIE Level", i32 2}
+!7 = !{i32 7, !"uwtable", i32 1}
+!8 = !{!"Debian clang version 14.0.6"}
+!9 = distinct !DISubprogram(name: "putstr", scope: !10, file: !10, line: 31, type: !11, scopeLine: 31, flags: DIFlagPrototyped | DIFlagAllCallsDescribed, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !15)
+!10 = !DIFile(filename: "./stdfuck.h", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "b67b4bb6984323629ac25b8a7f940293")
+!11 = !DISubroutineType(types: !12)
+!12 = !{null, !13}
+!13 = !DIDerivedType(tag: DW_TAG_pointer_type, baseType: !14, size: 64)
+!14 = !DIBasicType(name: "char", size: 8, encoding: DW_ATE_signed_char)
+!15 = !{!16}
+!16 = !DILocalVariable(name: "s", arg: 1, scope: !9, file: !10, line: 31, type: !13)
+!17 = !DILocation(line: 0, scope: !9)
+!18 = !DILocation(line: 32, column: 8, scope: !9)
+!19 = !{!20, !20, i64 0}
+!20 = !{!"omnipotent char", !21, i64 0}
+!21 = !{!"Simple C/C++ TBAA"}
+!22 = !DILocation(line: 32, column: 2, scope: !9)
+!23 = !DILocation(line: 33, column: 3, scope: !24)
+!24 = distinct !DILexicalBlock(scope: !9, file: !10, line: 32, column: 12)
+!25 = !DILocation(line: 34, column: 4, scope: !24)
+!26 = distinct !{!26, !22, !27, !28, !29}
+!27 = !DILocation(line: 35, column: 2, scope: !9)
+!28 = !{!"llvm.loop.mustprogress"}
+!29 = !{!"llvm.loop.unroll.disable"}
+!30 = !DILocation(line: 36, column: 1, scope: !9)
+!31 = !DISubprogram(name: "putchar", scope: !10, file: !10, line: 5, type: !32, flags: DIFlagPrototyped, spFlags: DISPFlagOptimized, retainedNodes: !35)
+!32 = !DISubroutineType(types: !33)
+!33 = !{null, !34}
+!34 = !DIBasicType(name: "unsigned char", size: 8, encoding: DW_ATE_unsigned_char)
+!35 = !{}
+!36 = distinct !DISubprogram(name: "putdec", scope: !10, file: !10, line: 39, type: !37, scopeLine: 39, flags: DIFlagPrototyped | DIFlagAllCallsDescribed, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !40)
+!37 = !DISubroutineType(types: !38)
+!38 = !{null, !39}
+!39 = !DIBasicType(name: "unsigned int", size: 32, encoding: DW_ATE_unsigned)
+!40 = !{!41, !42}
+!41 = !DILocalVariable(name: "n", arg: 1, scope: !36, file: !10, line: 39, type: !39)
+!42 = !DILocalVariable(name: "digs", scope: !36, file: !10, line: 45, type: !39)
+!43 = !DILocation(line: 0, scope: !36)
+!44 = !DILocation(line: 40, column: 8, scope: !45)
+!45 = distinct !DILexicalBlock(scope: !36, file: !10, line: 40, column: 7)
+!46 = !DILocation(line: 40, column: 7, scope: !36)
+!47 = !DILocation(line: 46, column: 18, scope: !36)
+!48 = !DILocation(line: 46, column: 3, scope: !36)
+!49 = !DILocation(line: 41, column: 5, scope: !50)
+!50 = distinct !DILexicalBlock(scope: !45, file: !10, line: 40, column: 11)
+!51 = !DILocation(line: 42, column: 5, scope: !50)
+!52 = !DILocation(line: 48, column: 3, scope: !36)
+!53 = !DILocation(line: 46, column: 30, scope: !36)
+!54 = !DILocation(line: 46, column: 11, scope: !36)
+!55 = distinct !{!55, !48, !56, !28, !29}
+!56 = !DILocation(line: 46, column: 33, scope: !36)
+!57 = !DILocation(line: 49, column: 23, scope: !58)
+!58 = distinct !DILexicalBlock(scope: !36, file: !10, line: 48, column: 15)
+!59 = !DILocation(line: 49, column: 30, scope: !58)
+!60 = !DILocation(line: 49, column: 13, scope: !58)
+!61 = !DILocation(line: 49, column: 5, scope: !58)
+!62 = !DILocation(line: 50, column: 10, scope: !58)
+!63 = distinct !{!63, !52, !64, !28, !29}
+!64 = !DILocation(line: 51, column: 3, scope: !36)
+!65 = !DILocation(line: 52, column: 1, scope: !36)
+!66 = distinct !DISubprogram(name: "main", scope: !67, file: !67, line: 6, type: !68, scopeLine: 6, flags: DIFlagAllCallsDescribed, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !71)
+!67 = !DIFile(filename: "./tests/cases/debug_info.c", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "e5079375f93b388a0692332ff725c732")
+!68 = !DISubroutineType(types: !69)
+!69 = !{!70}
+!70 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!71 = !{!72, !76, !78}
+!72 = !DILocalVariable(name: "buf", scope: !66, file: !67, line: 7, type: !73)
+!73 = !DICompositeType(tag: DW_TAG_array_type, baseType: !34, size: 24, elements: !74)
+!74 = !{!75}
+!75 = !DISubrange(count: 3)
+!76 = !DILocalVariable(name: "i", scope: !77, file: !67, line: 8, type: !34)
+!77 = distinct !DILexicalBlock(scope: !66, file: !67, line: 8, column: 3)
+!78 = !DILocalVariable(name: "i", scope: !79, file: !67, line: 11, type: !34)
+!79 = distinct !DILexicalBlock(scope: !66, file: !67, line: 11, column: 3)
+!80 = !DILocation(line: 7, column: 3, scope: !66)
+!81 = !DILocation(line: 7, column: 11, scope: !66)
+!82 = !DILocation(line: 0, scope: !77)
+!83 = !DILocation(line: 8, column: 3, scope: !77)
+!84 = !DILocation(line: 9, column: 14, scope: !85)
+!85 = distinct !DILexicalBlock(scope: !86, file: !67, line: 8, column: 35)
+!86 = distinct !DILexicalBlock(scope: !77, file: !67, line: 8, column: 3)
+!87 = !DILocation(line: 9, column: 5, scope: !85)
+!88 = !DILocation(line: 9, column: 12, scope: !85)
+!89 = !DILocation(line: 8, column: 31, scope: !86)
+!90 = !DILocation(line: 8, column: 25, scope: !86)
+!91 = distinct !{!91, !83, !92, !28, !29}
+!92 = !DILocation(line: 10, column: 3, scope: !77)
+!93 = !DILocation(line: 15, column: 1, scope: !66)
+!94 = !DILocation(line: 14, column: 3, scope: !66)
+!95 = !DILocation(line: 0, scope: !79)
+!96 = !DILocation(line: 12, column: 19, scope: !97)
+!97 = distinct !DILexicalBlock(scope: !98, file: !67, line: 11, column: 35)
+!98 = distinct !DILexicalBlock(scope: !79, file: !67, line: 11, column: 3)
+!99 = !DILocation(line: 12, column: 13, scope: !97)
+!100 = !DILocation(line: 12, column: 5, scope: !97)
+!101 = !DILocation(line: 11, column: 31, scope: !98)
+!102 = !DILocation(line: 11, column: 25, scope: !98)
+!103 = !DILocation(line: 11, column: 3, scope: !79)
+!104 = distinct !{!104, !103, !105, !28, !29}
+!105 = !DILocation(line: 13, column: 3, scope: !79)
+!106 = !DISubprogram(name: "getchar", scope: !10, file: !10, line: 6, type: !107, flags: DIFlagPrototyped, spFlags: DISPFlagOptimized, retainedNodes: !35)
+!107 = !DISubroutineType(types: !108)
+!108 = !{!34}
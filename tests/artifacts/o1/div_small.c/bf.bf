@@ -0,0 +1,66 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 123 ops ~249 steps
+frame widths:
+  main: 6 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l6 <<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        <#B:%0
+        [
+            -
+            copy up args
+            call @putchar(i8 80_ i8 55_ i8 65)
+            
+            putchar run intrinsic
+            >>>++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            -------------------------
+            .
+            ++++++++++
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
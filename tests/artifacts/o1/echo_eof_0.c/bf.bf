@@ -0,0 +1,156 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 655 ops ~5912 steps
+frame widths:
+  main: 17 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l17 <<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%4
+        #B:%9
+        #%1_=_call_@getchar()(mult)
+        #%3_=_icmp_eq_i8_%1__i8_0(mult)
+        #%5_=_phi_i8_{_i8_%6__%4_}__{_i8_%1__%0_}(mult)
+        #%6_=_call_@getchar()(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = call @getchar()
+            
+            >>>>[-]
+            getchar intrinsic
+            ,
+            %3 = icmp eq i8 %1_ i8 0
+            
+            >[-]
+            dup6/10/11 <[->>>>+>+<<<<<]
+            mov10/6 >>>>[-<<<<+>>>>]
+            <<<+
+            >>>>[
+                [-]
+                <<<<[-]
+            >>>>]
+            br i1 %3_ label %9_ label %4
+            
+            dup7/12/10 <<<<[->>>>>+<<+<<<]
+            mov12/7 >>>>>[-<<<<<+>>>>>]
+            doing phi stuff
+            %5 = phi i8 { i8 %6_ %4 }_ { i8 %1_ %0 }
+            dup6/11/13 <<<<<<[->>>>>+>>+<<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            mov13/14 >>[->+<]
+            <<<<<<<<<<+
+            >>>>>>>[
+                -
+                <<<<<<<-
+                >>+
+                #B:main/%9_true
+                >>>>>>>>>[-]
+            <<<<]
+            <<<<<<<[
+                -
+                >+
+                #B:main/%4_false
+                >>>>[-]
+                mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<]
+        <]
+        >>#B:%4
+        [
+            -
+            %5 = phi i8 { i8 %6_ %4 }_ { i8 %1_ %0 }
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup8/15/16 >>>>[->>>>>>>+>+<<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >.
+            [-]
+            %6 = call @getchar()
+            
+            <<<<<<<[-]
+            getchar intrinsic
+            ,
+            %8 = icmp eq i8 %6_ i8 0
+            
+            <<[-]
+            dup9/10/11 >>[->+>+<<]
+            mov10/9 >[-<+>]
+            <<<+
+            >>>>[
+                [-]
+                <<<<[-]
+            >>>>]
+            br i1 %8_ label %9_ label %4
+            
+            dup7/11/10 <<<<[->>>>+<+<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            doing phi stuff
+            %5 = phi i8 { i8 %6_ %4 }_ { i8 %1_ %0 }
+            dup9/12/13 <<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            mov13/14 >[->+<]
+            <<<<<<<<<<+
+            >>>>>>>[
+                -
+                <<<<<<<-
+                >>+
+                #B:main/%9_true
+                >>>>>>>>>[-]
+            <<<<]
+            <<<<<<<[
+                -
+                >+
+                #B:main/%4_false
+                >>>>[-]
+                mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<]
+        >]
+        >#B:%9
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+    <<<<]
+<]
@@ -0,0 +1,82 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 128 ops ~380 steps
+frame widths:
+  main: 8 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l8 <<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #%2_=_icmp_eq_i8_0__i8_0(mult)
+        #%3_=_select_i1_%2__i8_48__i8_63(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = icmp eq i8 0_ i8 0
+            
+            >>[-]
+            known to be 1
+            +
+            %3 = select i1 %2_ i8 48_ i8 63
+            
+            >[-]
+            known to be 48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_48
+            ++++++
+            [
+                -
+                >++++++++
+            <]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<[-]
+            >[-]
+            <<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,135 @@
+eof: 255
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 368 ops ~2531 steps
+frame widths:
+  main: 15 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%4
+        #%2_=_icmp_eq_i8_255__i8_255(mult)
+        #%5_=_phi_i8_{_i8_53__%3_}__{_i8_63__%0_}(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %2 = icmp eq i8 255_ i8 255
+            
+            >>>>[-]
+            known to be 1
+            +
+            br i1 %2_ label %3_ label %4
+            
+            dup6/9/8 [->>>+<+<<]
+            mov9/6 >>>[-<<<+>>>]
+            doing phi stuff
+            %5 = phi i8 { i8 53_ %3 }_ { i8 63_ %0 }
+            op_to_reg storing const value in temp address
+            >#constop_63
+            >+++++++
+            [
+                -
+                <+++++++++
+            >]
+            mov10/12 <[->>+<<]
+            <<<<<<<+
+            >>>>>[
+                -
+                <<<<<-
+                >+
+                #B:main/%3_true
+                >>>>>>>>[-]
+            <<<<]
+            <<<<<[
+                -
+                >>+
+                #B:main/%4_false
+                >>[-]
+                mov12/7 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<]
+        <]
+        >>#B:%3
+        [
+            -
+            call @putchar(i8 50_ i8 53)
+            
+            putchar run intrinsic
+            >>>>>>>>>>+++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            +++
+            .
+            [-]
+            br label %4
+            
+            doing phi stuff
+            %5 = phi i8 { i8 53_ %3 }_ { i8 63_ %0 }
+            op_to_reg storing const value in temp address
+            <<<<#constop_53
+            ++++
+            [
+                -
+                <+++++++++++++
+            >]
+            <+
+            <[-]
+            mov8/7 >[-<+>]
+            <<<+
+        <]
+        >#B:%4
+        [
+            -
+            %5 = phi i8 { i8 53_ %3 }_ { i8 63_ %0 }
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup7/10/11 >>[->>>+>+<<<<]
+            mov10/7 >>>[-<<<+>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            <<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+    <<<<]
+<]
@@ -0,0 +1,212 @@
+eof: 0
+blocks made up by bfcc:
+  main/%1_sw_1 from %1 (switch case)
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 683 ops ~4365 steps
+frame widths:
+  main: 21 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l21 <<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%1_sw_1
+        #B:%3
+        #B:%4
+        #B:%5
+        #%2_=_call_@getchar()(mult)
+        #%sw_1_0_=_icmp_eq_i8_%2__i8_0(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            br label %1
+            
+            >>+
+        <<]
+        >>#B:%1
+        [
+            -
+            %2 = call @getchar()
+            
+            >>>>>[-]
+            getchar intrinsic
+            ,
+            %sw_1_0 = icmp eq i8 %2_ i8 0
+            
+            >[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            <+
+            >>[
+                [-]
+                <<[-]
+            >>]
+            br i1 %sw_1_0_ label %5_ label %1_sw_1
+            
+            dup10/13/11 <<[->>>+<<+<]
+            mov13/10 >>>[-<<<+>>>]
+            <<<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >>>>>+
+                #B:main/%5_true
+            >>>]
+            <<<<<<<<[
+                -
+                >>+
+                #B:main/%1_sw_1_false
+            <<]
+        >]
+        >#B:%1_sw_1
+        [
+            -
+            %sw_1_1 = icmp eq i8 %2_ i8 120
+            
+            >>>>>[-]
+            dup9/12/14 <[->>>+>>+<<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>>#constop_120
+            >++++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/18 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            br i1 %sw_1_1_ label %3_ label %4
+            
+            dup10/12/11 <<<<<<<[->>+<+<]
+            mov12/10 >>[-<<+>>]
+            <<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >>>+
+                #B:main/%3_true
+            >>>>>]
+            <<<<<<<<[
+                -
+                >>>>+
+                #B:main/%4_false
+            <<<<]
+        >>]
+        >#B:%3
+        [
+            -
+            unreachable
+            
+            unreachable_ ending it all
+            zero all function allocs
+            >>>[-]
+            >[-]
+            <<<<<<<<<[-]
+            <[-]
+            l2 <
+            -
+            [
+                <[-]
+                mov1/0 >[-<+>]
+                l1 <
+                -
+            ]
+        >>>>>]
+        >#B:%4
+        [
+            -
+            call @putchar(i8 %2)
+            
+            putchar intrinsic
+            dup9/13/14 >>[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            br label %1
+            
+            <<<<<<<<<<+
+        >>>]
+        >#B:%5
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>]
+    <<<<<<<]
+<]
@@ -0,0 +1,170 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 497 ops ~4725 steps
+frame widths:
+  main: 14 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l14 <<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_icmp_eq_i8_%2__i8_3(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 3_ i8* %1_ align 1
+            
+            >>[-]
+            +++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 3
+            +++
+            %3 = icmp eq i8 %2_ i8 3
+            
+            >[-]
+            known to be 1
+            +
+            %4 = select i1 %3_ i8 121_ i8 120
+            
+            <[-]
+            known to be 121
+            >>+++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            >.
+            [-]
+            %5 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            dup4/5/7 <[->+>>+<<<]
+            mov7/4 >>>[-<<<+>>>]
+            %6 = icmp ugt i8 %5_ i8 5
+            
+            <[-]
+            dup5/7/9 <[->>+>>+<<<<]
+            mov7/5 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >#constop_5
+            +++++
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov9/10 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<+
+            >>>>]
+            %7 = select i1 %6_ i8 120_ i8 110
+            
+            <<<<<[-]
+            dup6/7/8 >[->+>+<<]
+            mov7/6 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_120
+            >++++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            op_to_reg storing const value in temp address
+            >#constop_110
+            >>++++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <+
+            <<<<[
+                mov9/5 >[-<<<<+>>>>]
+                >>[-]
+                <<<[-]
+                >>>>[-]
+            <<<<]
+            >>>>[
+                -
+                mov11/5 <[-<<<<<<+>>>>>>]
+                <<[-]
+                <[-]
+            >>>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup5/7/8 <<<<<<<[->>+>+<<<]
+            mov7/5 >>[-<<+>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            <<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
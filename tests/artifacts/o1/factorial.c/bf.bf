@@ -0,0 +1,463 @@
+eof: 0
+blocks made up by bfcc:
+  fact/%call_term_for_0 from %3 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  fact calls fact (depth unbounded_ recursive)
+  main calls fact (depth unbounded)
+  max depth from main: unbounded
+cost (loops go round 8 times):
+  fact: 2674 ops ~219940 steps
+  main: 1942 ops ~28759 steps
+frame widths:
+  fact: 25 cells
+  main: 20 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#fact
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/fact
+            >>>-
+            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_fact
+        #F:fact
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%3
+        #B:%call_term_for_0
+        #B:%7
+        #%2_=_icmp_ult_i8_%0__i8_2(mult)
+        #%5_=_call_@fact(i8_%4)(mult)
+        #%6_=_mul_i8_%5__i8_%0(mult)
+        #%8_=_phi_i8_{_i8_%6__%call_term_for_0_}__{_i8_1__%1_}(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/14 <<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = icmp ult i8 %0_ i8 2
+            
+            >>>>>>>>[-]
+            dup12/13/14 >>>>[->+>+<<]
+            mov13/12 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_2
+            ++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/16 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<+
+            >>>>>>>>]
+            br i1 %2_ label %7_ label %3
+            
+            dup8/14/13 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            doing phi stuff
+            %8 = phi i8 { i8 %6_ %call_term_for_0 }_ { i8 1_ %1 }
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            mov15/16 [->+<]
+            <<<<<<<<<<<+
+            >>>>>>>>>[
+                -
+                <<<<<<<<<-
+                >>>+
+                #B:fact/%7_true
+                >>>>[-]
+                mov16/11 >>>>>[-<<<<<+>>>>>]
+            <<<]
+            <<<<<<<<<[
+                -
+                >+
+                #B:fact/%3_false
+                >>>>>>>>>>>[-]
+            <<<<<<<<<<<<]
+        <]
+        >>#B:%3
+        [
+            -
+            %4 = add i8 %0_ i8 255
+            
+            >>>[-]
+            dup12/17/19 >>>>[->>>>>+>>+<<<<<<<]
+            mov17/12 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >#constop_255
+            >>+++++++++++++++
+            [
+                -
+                <<+++++++++++++++++
+            >>]
+            mov19/8 <[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/22/24 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov24/8 >>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            <<<<<<[
+                -
+                >>>>>+
+                <<[
+                    -
+                    mov21/22 [->+<]
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>-
+                <<]
+                mov22/21 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<]
+            >>>[-]
+            %5 = call @fact(i8 %4)
+            
+            <<<<<<<<<<<<[-]
+            enable next block when we return
+            <<<#caller/%call_term_for_0
+            +
+            stack_width 25
+            ret_pad_width 4
+            copy up arg 0
+            >>#arg_0
+            dup8/13/14 [->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            mov14/30 >[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/32/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov33/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r25 
+            <<<<<<<#JUMP_PAD
+            +
+            >#caller
+            +
+            >>>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_fact
+            +
+            >#fact
+            +
+            >>#fact/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %5
+            mov40/9 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %6 = mul i8 %5_ i8 %0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup9/13/14 <[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            dup12/15/16 <[->>>+>+<<<<]
+            mov15/12 >>>[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov14/20 <<[->>>>>>+<<<<<<]
+                >>>>>>[
+                    -
+                    <<<<<<+
+                    >>>>>+
+                    <<[
+                        -
+                        mov17/18 [->+<]
+                        <<<<<<<+
+                        >>>>>>>>>-
+                    <<]
+                    mov18/17 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<[-]
+                        >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <<[-]
+            >>>[-]
+            br label %7
+            
+            doing phi stuff
+            %8 = phi i8 { i8 %6_ %call_term_for_0 }_ { i8 1_ %1 }
+            <<<<<<[-]
+            mov10/11 <[->+<]
+            <<<+
+        <]
+        >#B:%7
+        [
+            -
+            %8 = phi i8 { i8 %6_ %call_term_for_0 }_ { i8 1_ %1 }
+            ret i8 %8
+            
+            mov11/13 >>>>[->>+<<]
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            mov13/8 >[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:fact
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_call_@fact(i8_%2)(mult)
+        #%5_=_call_@fact(i8_%4)(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            store volatile i8 5_ i8* %1_ align 1
+            
+            >>>[-]
+            +++++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 5
+            +++++
+            %3 = call @fact(i8 %2)
+            
+            >[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_0
+            +
+            stack_width 20
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_5
+            +++++
+            mov12/25 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++
+            l1 <
+            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r20 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_fact
+            +
+            >#fact
+            +
+            >>#fact/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            returned %3
+            mov35/10 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup10/13/12 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>+<+<<]
+            mov13/10 >>>[-<<<+>>>]
+            <.
+            [-]
+            store volatile i8 4_ i8* %1_ align 1
+            
+            <<<<[-]
+            ++++
+            %4 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 4
+            ++++
+            %5 = call @fact(i8 %4)
+            
+            >>[-]
+            enable next block when we return
+            <<<<#caller/%call_term_for_1
+            +
+            stack_width 20
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_4
+            ++++
+            mov12/25 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++
+            l1 <
+            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r20 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_fact
+            +
+            >#fact
+            +
+            >>#fact/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            returned %5
+            mov35/11 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            %6 = add i8 %5_ i8 65
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup11/12/14 >>[->+>>+<<<]
+            mov12/11 >[-<+>]
+            op_to_reg storing const value in temp address
+            >#constop_65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            mov14/9 >[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/17/19 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov19/9 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<<[
+                -
+                >>>>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<]
+            >>>[-]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup9/12/13 <<<<<<<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
@@ -0,0 +1,1300 @@
+; ModuleID = './tests/artifacts/o1/frame_overflow.c/ir.bc'
+source_filename = "./tests/cases/frame_overflow.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 {
+  %2 = load i8, i8* %0, align 1, !tbaa !5
+  %3 = icmp eq i8 %2, 0
+  br i1 %3, label %10, label %4
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @putchar(i8 noundef zeroext %5) #3
+  %7 = getelementptr inbounds i8, i8* %6, i64 1
+  %8 = load i8, i8* %7, align 1, !tbaa !5
+  %9 = icmp eq i8 %8, 0
+  br i1 %9, label %10, label %4, !llvm.loop !8
+
+10:                                               ; preds = %4, %1
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 {
+  %2 = icmp eq i32 %0, 0
+  br i1 %2, label %5, label %3
+
+3:                                                ; preds = %1
+  %4 = icmp ugt i32 %0, 9
+  br i1 %4, label %9, label %6
+
+5:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %22
+
+6:                                                ; preds = %9, %3
+  %7 = phi i32 [ 1, %3 ], [ %11, %9 ]
+  %8 = icmp eq i32 %7, 0
+  br i1 %8, label %22, label %14
+
+9:                                                ; preds = %9, %3
+  %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
+  %11 = mul i32 %10, 10
+  %12 = udiv i32 %0, %11
+  %13 = icmp ugt i32 %12, 9
+  br i1 %13, label %9, label %6, !llvm.loop !11
+
+14:                                               ; preds = %14, %6
+  %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
+  %16 = udiv i32 %0, %15
+  %17 = urem i32 %16, 10
+  %18 = trunc i32 %17 to i8
+  %19 = or i8 %18, 48
+  call void @putchar(i8 noundef zeroext %19) #3
+  %20 = udiv i32 %15, 10
+  %21 = icmp ult i32 %15, 10
+  br i1 %21, label %22, label %14, !llvm.loop !12
+
+22:                                               ; preds = %14, %6, %5
+  ret void
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: nounwind uwtable
+define dso_local void @main() local_unnamed_addr #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  %8 = alloca i8, align 1
+  %9 = alloca i8, align 1
+  %10 = alloca i8, align 1
+  %11 = alloca i8, align 1
+  %12 = alloca i8, align 1
+  %13 = alloca i8, align 1
+  %14 = alloca i8, align 1
+  %15 = alloca i8, align 1
+  %16 = alloca i8, align 1
+  %17 = alloca i8, align 1
+  %18 = alloca i8, align 1
+  %19 = alloca i8, align 1
+  %20 = alloca i8, align 1
+  %21 = alloca i8, align 1
+  %22 = alloca i8, align 1
+  %23 = alloca i8, align 1
+  %24 = alloca i8, align 1
+  %25 = alloca i8, align 1
+  %26 = alloca i8, align 1
+  %27 = alloca i8, align 1
+  %28 = alloca i8, align 1
+  %29 = alloca i8, align 1
+  %30 = alloca i8, align 1
+  %31 = alloca i8, align 1
+  %32 = alloca i8, align 1
+  %33 = alloca i8, align 1
+  %34 = alloca i8, align 1
+  %35 = alloca i8, align 1
+  %36 = alloca i8, align 1
+  %37 = alloca i8, align 1
+  %38 = alloca i8, align 1
+  %39 = alloca i8, align 1
+  %40 = alloca i8, align 1
+  %41 = alloca i8, align 1
+  %42 = alloca i8, align 1
+  %43 = alloca i8, align 1
+  %44 = alloca i8, align 1
+  %45 = alloca i8, align 1
+  %46 = alloca i8, align 1
+  %47 = alloca i8, align 1
+  %48 = alloca i8, align 1
+  %49 = alloca i8, align 1
+  %50 = alloca i8, align 1
+  %51 = alloca i8, align 1
+  %52 = alloca i8, align 1
+  %53 = alloca i8, align 1
+  %54 = alloca i8, align 1
+  %55 = alloca i8, align 1
+  %56 = alloca i8, align 1
+  %57 = alloca i8, align 1
+  %58 = alloca i8, align 1
+  %59 = alloca i8, align 1
+  %60 = alloca i8, align 1
+  %61 = alloca i8, align 1
+  %62 = alloca i8, align 1
+  %63 = alloca i8, align 1
+  %64 = alloca i8, align 1
+  %65 = alloca i8, align 1
+  %66 = alloca i8, align 1
+  %67 = alloca i8, align 1
+  %68 = alloca i8, align 1
+  %69 = alloca i8, align 1
+  %70 = alloca i8, align 1
+  %71 = alloca i8, align 1
+  %72 = alloca i8, align 1
+  %73 = alloca i8, align 1
+  %74 = alloca i8, align 1
+  %75 = alloca i8, align 1
+  %76 = alloca i8, align 1
+  %77 = alloca i8, align 1
+  %78 = alloca i8, align 1
+  %79 = alloca i8, align 1
+  %80 = alloca i8, align 1
+  %81 = alloca i8, align 1
+  %82 = alloca i8, align 1
+  %83 = alloca i8, align 1
+  %84 = alloca i8, align 1
+  %85 = alloca i8, align 1
+  %86 = alloca i8, align 1
+  %87 = alloca i8, align 1
+  %88 = alloca i8, align 1
+  %89 = alloca i8, align 1
+  %90 = alloca i8, align 1
+  %91 = alloca i8, align 1
+  %92 = alloca i8, align 1
+  %93 = alloca i8, align 1
+  %94 = alloca i8, align 1
+  %95 = alloca i8, align 1
+  %96 = alloca i8, align 1
+  %97 = alloca i8, align 1
+  %98 = alloca i8, align 1
+  %99 = alloca i8, align 1
+  %100 = alloca i8, align 1
+  %101 = alloca i8, align 1
+  %102 = alloca i8, align 1
+  %103 = alloca i8, align 1
+  %104 = alloca i8, align 1
+  %105 = alloca i8, align 1
+  %106 = alloca i8, align 1
+  %107 = alloca i8, align 1
+  %108 = alloca i8, align 1
+  %109 = alloca i8, align 1
+  %110 = alloca i8, align 1
+  %111 = alloca i8, align 1
+  %112 = alloca i8, align 1
+  %113 = alloca i8, align 1
+  %114 = alloca i8, align 1
+  %115 = alloca i8, align 1
+  %116 = alloca i8, align 1
+  %117 = alloca i8, align 1
+  %118 = alloca i8, align 1
+  %119 = alloca i8, align 1
+  %120 = alloca i8, align 1
+  %121 = alloca i8, align 1
+  %122 = alloca i8, align 1
+  %123 = alloca i8, align 1
+  %124 = alloca i8, align 1
+  %125 = alloca i8, align 1
+  %126 = alloca i8, align 1
+  %127 = alloca i8, align 1
+  %128 = alloca i8, align 1
+  %129 = alloca i8, align 1
+  %130 = alloca i8, align 1
+  %131 = alloca i8, align 1
+  %132 = alloca i8, align 1
+  %133 = alloca i8, align 1
+  %134 = alloca i8, align 1
+  %135 = alloca i8, align 1
+  %136 = alloca i8, align 1
+  %137 = alloca i8, align 1
+  %138 = alloca i8, align 1
+  %139 = alloca i8, align 1
+  %140 = alloca i8, align 1
+  %141 = alloca i8, align 1
+  %142 = alloca i8, align 1
+  %143 = alloca i8, align 1
+  %144 = alloca i8, align 1
+  %145 = alloca i8, align 1
+  %146 = alloca i8, align 1
+  %147 = alloca i8, align 1
+  %148 = alloca i8, align 1
+  %149 = alloca i8, align 1
+  %150 = alloca i8, align 1
+  %151 = alloca i8, align 1
+  %152 = alloca i8, align 1
+  %153 = alloca i8, align 1
+  %154 = alloca i8, align 1
+  %155 = alloca i8, align 1
+  %156 = alloca i8, align 1
+  %157 = alloca i8, align 1
+  %158 = alloca i8, align 1
+  %159 = alloca i8, align 1
+  %160 = alloca i8, align 1
+  %161 = alloca i8, align 1
+  %162 = alloca i8, align 1
+  %163 = alloca i8, align 1
+  %164 = alloca i8, align 1
+  %165 = alloca i8, align 1
+  %166 = alloca i8, align 1
+  %167 = alloca i8, align 1
+  %168 = alloca i8, align 1
+  %169 = alloca i8, align 1
+  %170 = alloca i8, align 1
+  %171 = alloca i8, align 1
+  %172 = alloca i8, align 1
+  %173 = alloca i8, align 1
+  %174 = alloca i8, align 1
+  %175 = alloca i8, align 1
+  %176 = alloca i8, align 1
+  %177 = alloca i8, align 1
+  %178 = alloca i8, align 1
+  %179 = alloca i8, align 1
+  %180 = alloca i8, align 1
+  %181 = alloca i8, align 1
+  %182 = alloca i8, align 1
+  %183 = alloca i8, align 1
+  %184 = alloca i8, align 1
+  %185 = alloca i8, align 1
+  %186 = alloca i8, align 1
+  %187 = alloca i8, align 1
+  %188 = alloca i8, align 1
+  %189 = alloca i8, align 1
+  %190 = alloca i8, align 1
+  %191 = alloca i8, align 1
+  %192 = alloca i8, align 1
+  %193 = alloca i8, align 1
+  %194 = alloca i8, align 1
+  %195 = alloca i8, align 1
+  %196 = alloca i8, align 1
+  %197 = alloca i8, align 1
+  %198 = alloca i8, align 1
+  %199 = alloca i8, align 1
+  %200 = alloca i8, align 1
+  %201 = alloca i8, align 1
+  %202 = alloca i8, align 1
+  %203 = alloca i8, align 1
+  %204 = alloca i8, align 1
+  %205 = alloca i8, align 1
+  %206 = alloca i8, align 1
+  %207 = alloca i8, align 1
+  %208 = alloca i8, align 1
+  %209 = alloca i8, align 1
+  %210 = alloca i8, align 1
+  %211 = alloca i8, align 1
+  %212 = alloca i8, align 1
+  %213 = alloca i8, align 1
+  %214 = alloca i8, align 1
+  %215 = alloca i8, align 1
+  %216 = alloca i8, align 1
+  %217 = alloca i8, align 1
+  %218 = alloca i8, align 1
+  %219 = alloca i8, align 1
+  %220 = alloca i8, align 1
+  %221 = alloca i8, align 1
+  %222 = alloca i8, align 1
+  %223 = alloca i8, align 1
+  %224 = alloca i8, align 1
+  %225 = alloca i8, align 1
+  %226 = alloca i8, align 1
+  %227 = alloca i8, align 1
+  %228 = alloca i8, align 1
+  %229 = alloca i8, align 1
+  %230 = alloca i8, align 1
+  %231 = alloca i8, align 1
+  %232 = alloca i8, align 1
+  %233 = alloca i8, align 1
+  %234 = alloca i8, align 1
+  %235 = alloca i8, align 1
+  %236 = alloca i8, align 1
+  %237 = alloca i8, align 1
+  %238 = alloca i8, align 1
+  %239 = alloca i8, align 1
+  %240 = alloca i8, align 1
+  %241 = alloca i8, align 1
+  %242 = alloca i8, align 1
+  %243 = alloca i8, align 1
+  %244 = alloca i8, align 1
+  %245 = alloca i8, align 1
+  %246 = alloca i8, align 1
+  %247 = alloca i8, align 1
+  %248 = alloca i8, align 1
+  %249 = alloca i8, align 1
+  %250 = alloca i8, align 1
+  %251 = alloca i8, align 1
+  %252 = alloca i8, align 1
+  %253 = alloca i8, align 1
+  %254 = alloca i8, align 1
+  %255 = alloca i8, align 1
+  %256 = alloca i8, align 1
+  %257 = alloca i8, align 1
+  %258 = alloca i8, align 1
+  %259 = alloca i8, align 1
+  %260 = alloca i8, align 1
+  %261 = alloca i8, align 1
+  %262 = alloca i8, align 1
+  %263 = alloca i8, align 1
+  %264 = alloca i8, align 1
+  %265 = alloca i8, align 1
+  %266 = alloca i8, align 1
+  %267 = alloca i8, align 1
+  %268 = alloca i8, align 1
+  %269 = alloca i8, align 1
+  %270 = alloca i8, align 1
+  %271 = alloca i8, align 1
+  %272 = alloca i8, align 1
+  %273 = alloca i8, align 1
+  %274 = alloca i8, align 1
+  %275 = alloca i8, align 1
+  %276 = alloca i8, align 1
+  %277 = alloca i8, align 1
+  %278 = alloca i8, align 1
+  %279 = alloca i8, align 1
+  %280 = alloca i8, align 1
+  %281 = alloca i8, align 1
+  %282 = alloca i8, align 1
+  %283 = alloca i8, align 1
+  %284 = alloca i8, align 1
+  %285 = alloca i8, align 1
+  %286 = alloca i8, align 1
+  %287 = alloca i8, align 1
+  %288 = alloca i8, align 1
+  %289 = alloca i8, align 1
+  %290 = alloca i8, align 1
+  %291 = alloca i8, align 1
+  %292 = alloca i8, align 1
+  %293 = alloca i8, align 1
+  %294 = alloca i8, align 1
+  %295 = alloca i8, align 1
+  %296 = alloca i8, align 1
+  %297 = alloca i8, align 1
+  %298 = alloca i8, align 1
+  %299 = alloca i8, align 1
+  %300 = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %1)
+  store volatile i8 1, i8* %1, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %2)
+  store volatile i8 1, i8* %2, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %3)
+  store volatile i8 1, i8* %3, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %4)
+  store volatile i8 1, i8* %4, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %5)
+  store volatile i8 1, i8* %5, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %6)
+  store volatile i8 1, i8* %6, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %7)
+  store volatile i8 1, i8* %7, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %8)
+  store volatile i8 1, i8* %8, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %9)
+  store volatile i8 1, i8* %9, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %10)
+  store volatile i8 1, i8* %10, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %11)
+  store volatile i8 1, i8* %11, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %12)
+  store volatile i8 1, i8* %12, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %13)
+  store volatile i8 1, i8* %13, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %14)
+  store volatile i8 1, i8* %14, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %15)
+  store volatile i8 1, i8* %15, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %16)
+  store volatile i8 1, i8* %16, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %17)
+  store volatile i8 1, i8* %17, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %18)
+  store volatile i8 1, i8* %18, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %19)
+  store volatile i8 1, i8* %19, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %20)
+  store volatile i8 1, i8* %20, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %21)
+  store volatile i8 1, i8* %21, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %22)
+  store volatile i8 1, i8* %22, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %23)
+  store volatile i8 1, i8* %23, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %24)
+  store volatile i8 1, i8* %24, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %25)
+  store volatile i8 1, i8* %25, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %26)
+  store volatile i8 1, i8* %26, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %27)
+  store volatile i8 1, i8* %27, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %28)
+  store volatile i8 1, i8* %28, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %29)
+  store volatile i8 1, i8* %29, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %30)
+  store volatile i8 1, i8* %30, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %31)
+  store volatile i8 1, i8* %31, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %32)
+  store volatile i8 1, i8* %32, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %33)
+  store volatile i8 1, i8* %33, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %34)
+  store volatile i8 1, i8* %34, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %35)
+  store volatile i8 1, i8* %35, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %36)
+  store volatile i8 1, i8* %36, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %37)
+  store volatile i8 1, i8* %37, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %38)
+  store volatile i8 1, i8* %38, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %39)
+  store volatile i8 1, i8* %39, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %40)
+  store volatile i8 1, i8* %40, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %41)
+  store volatile i8 1, i8* %41, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %42)
+  store volatile i8 1, i8* %42, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %43)
+  store volatile i8 1, i8* %43, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %44)
+  store volatile i8 1, i8* %44, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %45)
+  store volatile i8 1, i8* %45, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %46)
+  store volatile i8 1, i8* %46, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %47)
+  store volatile i8 1, i8* %47, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %48)
+  store volatile i8 1, i8* %48, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %49)
+  store volatile i8 1, i8* %49, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %50)
+  store volatile i8 1, i8* %50, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %51)
+  store volatile i8 1, i8* %51, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %52)
+  store volatile i8 1, i8* %52, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %53)
+  store volatile i8 1, i8* %53, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %54)
+  store volatile i8 1, i8* %54, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %55)
+  store volatile i8 1, i8* %55, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %56)
+  store volatile i8 1, i8* %56, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %57)
+  store volatile i8 1, i8* %57, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %58)
+  store volatile i8 1, i8* %58, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %59)
+  store volatile i8 1, i8* %59, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %60)
+  store volatile i8 1, i8* %60, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %61)
+  store volatile i8 1, i8* %61, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %62)
+  store volatile i8 1, i8* %62, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %63)
+  store volatile i8 1, i8* %63, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %64)
+  store volatile i8 1, i8* %64, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %65)
+  store volatile i8 1, i8* %65, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %66)
+  store volatile i8 1, i8* %66, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %67)
+  store volatile i8 1, i8* %67, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %68)
+  store volatile i8 1, i8* %68, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %69)
+  store volatile i8 1, i8* %69, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %70)
+  store volatile i8 1, i8* %70, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %71)
+  store volatile i8 1, i8* %71, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %72)
+  store volatile i8 1, i8* %72, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %73)
+  store volatile i8 1, i8* %73, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %74)
+  store volatile i8 1, i8* %74, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %75)
+  store volatile i8 1, i8* %75, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %76)
+  store volatile i8 1, i8* %76, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %77)
+  store volatile i8 1, i8* %77, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %78)
+  store volatile i8 1, i8* %78, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %79)
+  store volatile i8 1, i8* %79, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %80)
+  store volatile i8 1, i8* %80, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %81)
+  store volatile i8 1, i8* %81, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %82)
+  store volatile i8 1, i8* %82, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %83)
+  store volatile i8 1, i8* %83, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %84)
+  store volatile i8 1, i8* %84, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %85)
+  store volatile i8 1, i8* %85, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %86)
+  store volatile i8 1, i8* %86, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %87)
+  store volatile i8 1, i8* %87, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %88)
+  store volatile i8 1, i8* %88, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %89)
+  store volatile i8 1, i8* %89, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %90)
+  store volatile i8 1, i8* %90, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %91)
+  store volatile i8 1, i8* %91, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %92)
+  store volatile i8 1, i8* %92, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %93)
+  store volatile i8 1, i8* %93, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %94)
+  store volatile i8 1, i8* %94, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %95)
+  store volatile i8 1, i8* %95, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %96)
+  store volatile i8 1, i8* %96, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %97)
+  store volatile i8 1, i8* %97, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %98)
+  store volatile i8 1, i8* %98, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %99)
+  store volatile i8 1, i8* %99, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %100)
+  store volatile i8 1, i8* %100, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %101)
+  store volatile i8 1, i8* %101, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %102)
+  store volatile i8 1, i8* %102, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %103)
+  store volatile i8 1, i8* %103, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %104)
+  store volatile i8 1, i8* %104, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %105)
+  store volatile i8 1, i8* %105, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %106)
+  store volatile i8 1, i8* %106, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %107)
+  store volatile i8 1, i8* %107, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %108)
+  store volatile i8 1, i8* %108, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %109)
+  store volatile i8 1, i8* %109, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %110)
+  store volatile i8 1, i8* %110, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %111)
+  store volatile i8 1, i8* %111, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %112)
+  store volatile i8 1, i8* %112, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %113)
+  store volatile i8 1, i8* %113, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %114)
+  store volatile i8 1, i8* %114, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %115)
+  store volatile i8 1, i8* %115, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %116)
+  store volatile i8 1, i8* %116, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %117)
+  store volatile i8 1, i8* %117, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %118)
+  store volatile i8 1, i8* %118, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %119)
+  store volatile i8 1, i8* %119, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %120)
+  store volatile i8 1, i8* %120, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %121)
+  store volatile i8 1, i8* %121, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %122)
+  store volatile i8 1, i8* %122, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %123)
+  store volatile i8 1, i8* %123, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %124)
+  store volatile i8 1, i8* %124, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %125)
+  store volatile i8 1, i8* %125, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %126)
+  store volatile i8 1, i8* %126, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %127)
+  store volatile i8 1, i8* %127, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %128)
+  store volatile i8 1, i8* %128, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %129)
+  store volatile i8 1, i8* %129, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %130)
+  store volatile i8 1, i8* %130, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %131)
+  store volatile i8 1, i8* %131, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %132)
+  store volatile i8 1, i8* %132, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %133)
+  store volatile i8 1, i8* %133, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %134)
+  store volatile i8 1, i8* %134, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %135)
+  store volatile i8 1, i8* %135, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %136)
+  store volatile i8 1, i8* %136, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %137)
+  store volatile i8 1, i8* %137, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %138)
+  store volatile i8 1, i8* %138, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %139)
+  store volatile i8 1, i8* %139, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %140)
+  store volatile i8 1, i8* %140, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %141)
+  store volatile i8 1, i8* %141, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %142)
+  store volatile i8 1, i8* %142, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %143)
+  store volatile i8 1, i8* %143, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %144)
+  store volatile i8 1, i8* %144, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %145)
+  store volatile i8 1, i8* %145, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %146)
+  store volatile i8 1, i8* %146, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %147)
+  store volatile i8 1, i8* %147, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %148)
+  store volatile i8 1, i8* %148, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %149)
+  store volatile i8 1, i8* %149, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %150)
+  store volatile i8 1, i8* %150, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %151)
+  store volatile i8 1, i8* %151, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %152)
+  store volatile i8 1, i8* %152, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %153)
+  store volatile i8 1, i8* %153, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %154)
+  store volatile i8 1, i8* %154, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %155)
+  store volatile i8 1, i8* %155, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %156)
+  store volatile i8 1, i8* %156, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %157)
+  store volatile i8 1, i8* %157, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %158)
+  store volatile i8 1, i8* %158, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %159)
+  store volatile i8 1, i8* %159, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %160)
+  store volatile i8 1, i8* %160, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %161)
+  store volatile i8 1, i8* %161, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %162)
+  store volatile i8 1, i8* %162, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %163)
+  store volatile i8 1, i8* %163, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %164)
+  store volatile i8 1, i8* %164, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %165)
+  store volatile i8 1, i8* %165, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %166)
+  store volatile i8 1, i8* %166, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %167)
+  store volatile i8 1, i8* %167, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %168)
+  store volatile i8 1, i8* %168, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %169)
+  store volatile i8 1, i8* %169, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %170)
+  store volatile i8 1, i8* %170, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %171)
+  store volatile i8 1, i8* %171, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %172)
+  store volatile i8 1, i8* %172, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %173)
+  store volatile i8 1, i8* %173, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %174)
+  store volatile i8 1, i8* %174, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %175)
+  store volatile i8 1, i8* %175, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %176)
+  store volatile i8 1, i8* %176, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %177)
+  store volatile i8 1, i8* %177, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %178)
+  store volatile i8 1, i8* %178, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %179)
+  store volatile i8 1, i8* %179, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %180)
+  store volatile i8 1, i8* %180, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %181)
+  store volatile i8 1, i8* %181, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %182)
+  store volatile i8 1, i8* %182, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %183)
+  store volatile i8 1, i8* %183, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %184)
+  store volatile i8 1, i8* %184, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %185)
+  store volatile i8 1, i8* %185, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %186)
+  store volatile i8 1, i8* %186, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %187)
+  store volatile i8 1, i8* %187, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %188)
+  store volatile i8 1, i8* %188, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %189)
+  store volatile i8 1, i8* %189, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %190)
+  store volatile i8 1, i8* %190, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %191)
+  store volatile i8 1, i8* %191, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %192)
+  store volatile i8 1, i8* %192, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %193)
+  store volatile i8 1, i8* %193, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %194)
+  store volatile i8 1, i8* %194, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %195)
+  store volatile i8 1, i8* %195, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %196)
+  store volatile i8 1, i8* %196, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %197)
+  store volatile i8 1, i8* %197, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %198)
+  store volatile i8 1, i8* %198, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %199)
+  store volatile i8 1, i8* %199, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %200)
+  store volatile i8 1, i8* %200, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %201)
+  store volatile i8 1, i8* %201, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %202)
+  store volatile i8 1, i8* %202, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %203)
+  store volatile i8 1, i8* %203, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %204)
+  store volatile i8 1, i8* %204, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %205)
+  store volatile i8 1, i8* %205, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %206)
+  store volatile i8 1, i8* %206, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %207)
+  store volatile i8 1, i8* %207, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %208)
+  store volatile i8 1, i8* %208, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %209)
+  store volatile i8 1, i8* %209, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %210)
+  store volatile i8 1, i8* %210, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %211)
+  store volatile i8 1, i8* %211, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %212)
+  store volatile i8 1, i8* %212, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %213)
+  store volatile i8 1, i8* %213, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %214)
+  store volatile i8 1, i8* %214, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %215)
+  store volatile i8 1, i8* %215, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %216)
+  store volatile i8 1, i8* %216, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %217)
+  store volatile i8 1, i8* %217, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %218)
+  store volatile i8 1, i8* %218, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %219)
+  store volatile i8 1, i8* %219, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %220)
+  store volatile i8 1, i8* %220, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %221)
+  store volatile i8 1, i8* %221, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %222)
+  store volatile i8 1, i8* %222, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %223)
+  store volatile i8 1, i8* %223, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %224)
+  store volatile i8 1, i8* %224, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %225)
+  store volatile i8 1, i8* %225, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %226)
+  store volatile i8 1, i8* %226, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %227)
+  store volatile i8 1, i8* %227, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %228)
+  store volatile i8 1, i8* %228, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %229)
+  store volatile i8 1, i8* %229, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %230)
+  store volatile i8 1, i8* %230, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %231)
+  store volatile i8 1, i8* %231, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %232)
+  store volatile i8 1, i8* %232, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %233)
+  store volatile i8 1, i8* %233, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %234)
+  store volatile i8 1, i8* %234, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %235)
+  store volatile i8 1, i8* %235, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %236)
+  store volatile i8 1, i8* %236, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %237)
+  store volatile i8 1, i8* %237, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %238)
+  store volatile i8 1, i8* %238, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %239)
+  store volatile i8 1, i8* %239, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %240)
+  store volatile i8 1, i8* %240, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %241)
+  store volatile i8 1, i8* %241, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %242)
+  store volatile i8 1, i8* %242, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %243)
+  store volatile i8 1, i8* %243, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %244)
+  store volatile i8 1, i8* %244, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %245)
+  store volatile i8 1, i8* %245, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %246)
+  store volatile i8 1, i8* %246, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %247)
+  store volatile i8 1, i8* %247, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %248)
+  store volatile i8 1, i8* %248, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %249)
+  store volatile i8 1, i8* %249, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %250)
+  store volatile i8 1, i8* %250, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %251)
+  store volatile i8 1, i8* %251, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %252)
+  store volatile i8 1, i8* %252, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %253)
+  store volatile i8 1, i8* %253, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %254)
+  store volatile i8 1, i8* %254, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %255)
+  store volatile i8 1, i8* %255, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %256)
+  store volatile i8 1, i8* %256, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %257)
+  store volatile i8 1, i8* %257, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %258)
+  store volatile i8 1, i8* %258, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %259)
+  store volatile i8 1, i8* %259, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %260)
+  store volatile i8 1, i8* %260, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %261)
+  store volatile i8 1, i8* %261, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %262)
+  store volatile i8 1, i8* %262, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %263)
+  store volatile i8 1, i8* %263, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %264)
+  store volatile i8 1, i8* %264, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %265)
+  store volatile i8 1, i8* %265, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %266)
+  store volatile i8 1, i8* %266, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %267)
+  store volatile i8 1, i8* %267, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %268)
+  store volatile i8 1, i8* %268, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %269)
+  store volatile i8 1, i8* %269, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %270)
+  store volatile i8 1, i8* %270, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %271)
+  store volatile i8 1, i8* %271, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %272)
+  store volatile i8 1, i8* %272, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %273)
+  store volatile i8 1, i8* %273, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %274)
+  store volatile i8 1, i8* %274, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %275)
+  store volatile i8 1, i8* %275, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %276)
+  store volatile i8 1, i8* %276, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %277)
+  store volatile i8 1, i8* %277, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %278)
+  store volatile i8 1, i8* %278, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %279)
+  store volatile i8 1, i8* %279, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %280)
+  store volatile i8 1, i8* %280, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %281)
+  store volatile i8 1, i8* %281, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %282)
+  store volatile i8 1, i8* %282, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %283)
+  store volatile i8 1, i8* %283, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %284)
+  store volatile i8 1, i8* %284, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %285)
+  store volatile i8 1, i8* %285, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %286)
+  store volatile i8 1, i8* %286, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %287)
+  store volatile i8 1, i8* %287, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %288)
+  store volatile i8 1, i8* %288, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %289)
+  store volatile i8 1, i8* %289, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %290)
+  store volatile i8 1, i8* %290, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %291)
+  store volatile i8 1, i8* %291, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %292)
+  store volatile i8 1, i8* %292, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %293)
+  store volatile i8 1, i8* %293, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %294)
+  store volatile i8 1, i8* %294, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %295)
+  store volatile i8 1, i8* %295, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %296)
+  store volatile i8 1, i8* %296, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %297)
+  store volatile i8 1, i8* %297, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %298)
+  store volatile i8 1, i8* %298, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %299)
+  store volatile i8 1, i8* %299, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %300)
+  store volatile i8 1, i8* %300, align 1, !tbaa !5
+  %301 = load volatile i8, i8* %1, align 1, !tbaa !5
+  call void @putchar(i8 noundef zeroext %301) #3
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %300)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %299)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %298)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %297)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %296)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %295)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %294)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %293)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %292)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %291)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %290)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %289)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %288)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %287)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %286)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %285)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %284)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %283)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %282)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %281)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %280)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %279)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %278)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %277)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %276)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %275)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %274)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %273)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %272)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %271)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %270)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %269)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %268)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %267)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %266)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %265)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %264)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %263)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %262)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %261)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %260)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %259)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %258)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %257)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %256)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %255)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %254)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %253)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %252)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %251)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %250)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %249)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %248)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %247)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %246)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %245)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %244)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %243)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %242)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %241)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %240)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %239)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %238)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %237)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %236)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %235)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %234)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %233)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %232)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %231)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %230)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %229)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %228)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %227)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %226)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %225)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %224)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %223)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %222)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %221)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %220)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %219)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %218)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %217)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %216)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %215)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %214)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %213)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %212)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %211)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %210)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %209)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %208)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %207)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %206)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %205)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %204)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %203)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %202)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %201)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %200)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %199)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %198)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %197)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %196)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %195)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %194)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %193)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %192)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %191)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %190)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %189)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %188)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %187)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %186)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %185)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %184)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %183)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %182)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %181)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %180)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %179)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %178)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %177)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %176)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %175)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %174)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %173)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %172)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %171)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %170)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %169)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %168)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %167)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %166)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %165)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %164)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %163)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %162)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %161)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %160)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %159)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %158)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %157)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %156)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %155)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %154)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %153)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %152)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %151)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %150)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %149)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %148)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %147)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %146)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %145)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %144)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %143)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %142)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %141)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %140)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %139)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %138)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %137)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %136)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %135)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %134)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %133)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %132)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %131)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %130)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %129)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %128)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %127)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %126)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %125)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %124)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %123)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %122)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %121)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %120)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %119)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %118)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %117)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %116)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %115)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %114)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %113)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %112)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %111)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %110)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %109)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %108)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %107)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %106)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %105)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %104)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %103)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %102)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %101)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %100)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %99)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %98)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %97)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %96)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %95)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %94)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %93)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %92)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %91)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %90)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %89)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %88)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %87)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %86)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %85)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %84)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %83)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %82)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %81)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %80)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %79)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %78)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %77)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %76)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %75)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %74)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %73)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %72)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %71)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %70)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %69)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %68)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %67)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %66)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %65)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %64)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %63)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %62)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %61)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %60)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %59)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %58)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %57)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %56)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %55)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %54)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %53)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %52)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %51)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %50)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %49)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %48)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %47)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %46)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %45)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %44)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %43)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %42)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %41)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %40)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %39)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %38)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %37)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %36)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %35)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %34)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %33)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %32)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %31)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %30)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %29)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %28)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %27)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %26)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %25)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %24)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %23)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %22)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %21)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %20)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %19)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %18)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %17)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %16)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %15)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %14)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %13)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %12)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %11)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %10)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %9)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %8)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %7)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %6)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %5)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %4)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %3)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %2)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %1)
+  ret void
+}
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { argmemonly nofree nosync nounwind willreturn }
+attributes #3 = { nounwind }
+
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
@@ -0,0 +1,364 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1336 ops ~25787 steps
+frame widths:
+  main: 20 cells
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+
+r1 >
+r4 >>>>
+++++++++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_udiv_i8_%2__i8_10(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 47_ i8* %1_ align 1
+            
+            >>[-]
+            >>>+++++
+            [
+                -
+                <<<+++++++++
+            >>>]
+            <<<++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 47
+            >>>+++++
+            [
+                -
+                <<<+++++++++
+            >>>]
+            <<<++
+            %3 = udiv i8 %2_ i8 10
+            
+            >[-]
+            known to be 4
+            ++++
+            %4 = urem i8 %3_ i8 10
+            
+            <[-]
+            known to be 4
+            ++++
+            %5 = zext i8 %4 to i64
+            
+            >[-]
+            known to be 4
+            ++++
+            %6 = getelementptr inbounds {11 x i8}* @digits_ i64 0_ i64 %5
+            
+            <[-]
+            known to be 5
+            +++++
+            %7 = load i8* %6_ align 1
+            
+            >[-]
+            op_to_reg storing const value in temp address
+            >#constop_5
+            +++++
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/9/13 <<<<<<<<[->>>>>>>>>+>>>>+<<<<<<<<<<<<<]
+            mov9/0 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            r1 >
+            >>>#ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/14 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov14/11 [-<<<+>>>]
+                <<<+++++++++
+            >>>]
+            <[
+                if neg
+                <<+++++++++
+                >>[
+                    -
+                    <<-
+                >>]
+            ]
+            drive left! choo choo
+            <<[
+                mov10/9 <[-<+>]
+                mov11/10 >[-<+>]
+                
+                mov8/11 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup8/9/11 <<<[->+>>+<<<]
+            mov9/8 >[-<+>]
+            >[
+                mov12/9 >>[-<<<+>>>]
+                mov11/12 <[->+<]
+                mov10/11 <[->+<]
+                r1 >
+                -
+            ]
+            mov11/6 >[-<<<<<+>>>>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup6/7/8 <<<<<[->+>+<<]
+            mov7/6 >[-<+>]
+            >.
+            [-]
+            %8 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            dup4/5/7 <[->+>>+<<<]
+            mov7/4 >>>[-<<<+>>>]
+            %9 = urem i8 %8_ i8 10
+            
+            <[-]
+            dup5/7/8 <[->>+>+<<<]
+            mov7/5 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup9/11/12 [->>+>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov8/13 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov13/8 <<[-<<<<<+>>>>>]
+                dup9/11/12 <<<<[->>+>+<<<]
+                mov12/9 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov11/6 >[-<<<<<+>>>>>]
+                <[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov10/6 [-<<<<+>>>>]
+            <[-]
+            >>[-]
+            %10 = zext i8 %9 to i64
+            
+            <<<<<<[-]
+            dup6/7/8 >[->+>+<<]
+            mov7/6 >[-<+>]
+            mov8/5 >[-<<<+>>>]
+            %11 = getelementptr inbounds {11 x i8}* @digits_ i64 0_ i64 %10
+            
+            <<[-]
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            dup5/8/9 <<[->>>+>+<<<<]
+            mov8/5 >>>[-<<<+>>>]
+            mov7/6 <[-<+>]
+            >>[
+                -
+                <<<+
+            >>>]
+            %12 = load i8* %11_ align 1
+            
+            <<<<[-]
+            dup6/7/8 >[->+>+<<]
+            mov7/6 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/13/14 <<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/17 [->>>>+<<<<]
+            >>>>>>+
+            <<<<<<<<<<<[
+                -
+                >>>>>>+
+                >>>[
+                    -
+                    <<<-
+                    r1 >
+                >>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<]
+            >>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov17/11 [-<<<<<<+>>>>>>]
+                <<<<<<+++++++++
+            >>>>>>]
+            <<<[
+                if neg
+                <<<+++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov10/9 <[-<+>]
+                mov11/10 >[-<+>]
+                
+                mov8/11 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup8/9/11 <<<[->+>>+<<<]
+            mov9/8 >[-<+>]
+            >[
+                mov12/9 >>[-<<<+>>>]
+                mov11/12 <[->+<]
+                mov10/11 <[->+<]
+                r1 >
+                -
+            ]
+            mov11/5 >[-<<<<<<+>>>>>>]
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            dup5/7/8 <<<<<<[->>+>+<<<]
+            mov7/5 >>[-<<+>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            <<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
+clear globals:
+l12 <<<<<<<<<<<
+[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
@@ -0,0 +1,247 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 644 ops ~2702 steps
+frame widths:
+  main: 14 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l14 <<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_load_volatile_i8*_%2__align_1(mult)
+        #%5_=_icmp_ult_i8_%3__i8_%4(mult)
+        #%6_=_icmp_ugt_i8_%3__i8_%4(mult)
+        #%7_=_icmp_eq_i8_%3__i8_%4(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 97_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++++++
+            >>>>>>>]
+            <<<<<<<+
+            scratch must be clean
+            >>>>>>>[
+            ]
+            store volatile i8 98_ i8* %2_ align 1
+            
+            <<<<<<[-]
+            >>>>>>>+++++++
+            [
+                -
+                <<<<<<<++++++++++++++
+            >>>>>>>]
+            scratch must be clean
+            
+            
+            %3 = load volatile i8* %1_ align 1
+            
+            <<<<<<[-]
+            known to be 97
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            scratch must be clean
+            >>>>>[
+            ]
+            %4 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            known to be 98
+            >>>>+++++++
+            [
+                -
+                <<<<++++++++++++++
+            >>>>]
+            scratch must be clean
+            
+            
+            %5 = icmp ult i8 %3_ i8 %4
+            
+            <<<[-]
+            known to be 1
+            +
+            %6 = icmp ugt i8 %3_ i8 %4
+            
+            >[-]
+            known to be 0
+            
+            %7 = icmp eq i8 %3_ i8 %4
+            
+            >[-]
+            known to be 0
+            
+            %8 = zext i1 %5 to i8
+            
+            <<<<[-]
+            known to be 1
+            +
+            %9 = select i1 %5_ i8 49_ i8 48
+            
+            >[-]
+            known to be 49
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++
+            >>>>]
+            scratch must be clean
+            
+            
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_49
+            >>+++++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<.
+            [-]
+            %10 = select i1 %6_ i8 49_ i8 48
+            
+            <<<<[-]
+            known to be 48
+            >>>>++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            scratch must be clean
+            
+            
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %11 = zext i1 %7 to i8
+            
+            <<<<[-]
+            known to be 0
+            
+            %12 = add i8 %8_ i8 %11
+            
+            >>[-]
+            known to be 1
+            +
+            %13 = shl i8 %12_ i8 1
+            
+            <<<[-]
+            known to be 2
+            ++
+            %14 = or i8 %13_ i8 48
+            
+            >[-]
+            known to be 50
+            >>>>+++++
+            [
+                -
+                <<<<++++++++++
+            >>>>]
+            scratch must be clean
+            
+            
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_50
+            >+++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            [-]
+            %15 = select i1 %5_ i8 48_ i8 49
+            
+            <<<<<[-]
+            known to be 48
+            >>>>>++++++
+            [
+                -
+                <<<<<++++++++
+            >>>>>]
+            scratch must be clean
+            
+            
+            call @putchar(i8 %15)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
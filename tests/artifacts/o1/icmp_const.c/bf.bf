@@ -1,915 +1,94 @@
-r6 >>>>>>
-++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 7163 ops ~89203 steps
+frame widths:
+  main: 43 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>#main
+>#main
 +
 >#main/b0
 +
-<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >-
-            <#dead_fn_pad/putstr
-            >>>>>-
-            l24 <<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/17 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov16/18 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup15/16/17 <<[->+>+<<]
-            mov16/15 >[-<+>]
-            <<<<<<[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup10/18/19 <[->>>>>>>>+>+<<<<<<<<<]
-            mov18/10 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov19/11 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup11/16/17 <[->>>>>+>+<<<<<<]
-            mov16/11 >>>>>[-<<<<<+>>>>>]
-            mov17/12 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup12/16/17 >>>>>[->>>>+>+<<<<<]
-            mov16/12 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup10/16/17 <<<[->>>>>>+>+<<<<<<<]
-            mov16/10 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov17/13 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup13/16/17 <[->>>+>+<<<<]
-            mov16/13 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
-            
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov18/21 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup13/16/17 <<<<<<[->>>+>+<<<<]
-            mov16/13 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
+        >>#B:%ret_lading_pad
         [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<-
+            <<<-
             #dead_frame
             >-
-            l1 <
-            <[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>]
-    <<<<<<<]
-    >#putdec
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
+            #dead_fn_pad/main
             >>-
-            <<#dead_fn_pad/putdec
-            >>>>>-
-            l45 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+            l43 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%4
+        #B:%5
+        #B:%9
+        #B:%10
+        #B:%12
+        #B:%13
+        #B:%15
+        #B:%16
+        #B:%19
+        #B:%20
+        #B:%23
+        #B:%24
+        #B:%28
+        #B:%29
+        #B:%31
+        #B:%32
+        #B:%34
+        #B:%35
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%17__%16_}(mult)
+        #%3_=_icmp_ult_i32_%2__i32_5(mult)
+        #%7_=_select_i1_%6__i8_101__i8_110(mult)
+        #%17_=_add_i32_%2__i32_1(mult)
+        #%21_=_phi_i32_{_i32_%36__%35_}__{_i32_0__%16_}(mult)
+        #%36_=_add_i32_%21__i32_1(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+            br label %1
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov31/33 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov30/32 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            op_to_reg storing const value in temp address
-            <#constop_1
-            +
-            <<<<<<<<<<<<<[-]
-            mov31/18 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
             doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            <<<<<<<<<<<<<<<<[-]
-            mov32/16 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
-                -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            putchar intrinsic
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %17_ %16 }
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup16/31/32 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            >>>>>>>>>>>>>>>>>>>>>#constop_0
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/36 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup16/31/32 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov31/16 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov32/22 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup18/33/34 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov33/18 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup34/19/36 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov36/34 >>[-<<+>>]
-            <]
-            <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup19/31/32 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup32/34/35 >[->>+>+<<<]
-            mov35/32 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov30/39 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
-                    -
-                    <+
-                    >>>>>>[
-                        -
-                        <<<<<<-
-                        r1 >
-                    >>>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov39/30 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup32/34/35 <<<<<<<[->>+>+<<<]
-                mov35/32 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup20/31/32 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov31/20 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/34 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup19/31/32 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov31/19 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov32/18 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup19/33/34 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov34/16 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
-        [
-            -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup22/35/36 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov35/22 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup36/38/39 >[->>+>+<<<]
-            mov39/36 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov30/42 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
-                    -
-                    <+
-                    >>>>>[
-                        -
-                        <<<<<-
-                        r1 >
-                    >>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov42/30 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup36/38/39 <<<<<<[->>+>+<<<]
-                mov39/36 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
-            >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup23/31/32 <[->>>>>>>>+>+<<<<<<<<<]
-            mov31/23 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup33/35/36 [->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov32/37 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov37/32 <<[-<<<<<+>>>>>]
-                dup33/35/36 <<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov35/24 >[-<<<<<<<<<<<+>>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
-            ]
-            mov34/24 [-<<<<<<<<<<+>>>>>>>>>>]
-            <[-]
-            >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup24/31/32 <[->>>>>>>+>+<<<<<<<<]
-            mov31/24 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov32/25 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup25/31/32 <[->>>>>>+>+<<<<<<<]
-            mov31/25 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov32/26 <[-<<<<<<+>>>>>>]
-            >[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            dup26/31/32 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov31/26 >>>>>[-<<<<<+>>>>>]
-            >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup22/31/32 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup33/35/36 [->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov32/37 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
-                    [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov37/32 >>>[-<<<<<+>>>>>]
-                dup33/35/36 <<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup22/31/32 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov32/28 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup28/31/32 <[->>>+>+<<<<]
-            mov31/28 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/34 <<<[->+<]
-            >>>+
-            <<<<[
-                -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov27/22 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>]
-    <<<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/main
-            >>>>>-
-            l65 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:main
-        >#B:%0
-        >#B:%ret_lading_pad
-        >#B:%1
-        >#B:%4
-        >#B:%call_term_for_2
-        >#B:%5
-        >#B:%call_term_for_4
-        >#B:%9
-        >#B:%call_term_for_6
-        >#B:%10
-        >#B:%12
-        >#B:%call_term_for_9
-        >#B:%13
-        >#B:%15
-        >#B:%call_term_for_12
-        >#B:%16
-        >#B:%call_term_for_14
-        >#B:%19
-        >#B:%20
-        >#B:%23
-        >#B:%call_term_for_18
-        >#B:%24
-        >#B:%call_term_for_20
-        >#B:%28
-        >#B:%call_term_for_22
-        >#B:%29
-        >#B:%31
-        >#B:%call_term_for_25
-        >#B:%32
-        >#B:%34
-        >#B:%call_term_for_28
-        >#B:%35
-        >#B:%call_term_for_30
-        >#%2_=_phi_i32_{_i32_0__%0_}__{_i32_%17__%call_term_for_14_}(mult)
-        >#%3_=_icmp_ult_i32_%2__i32_5(mult)
-        >#%6_=_icmp_eq_i32_%2__i32_5(mult)
-        >#%7_=_select_i1_%6__i8_101__i8_110(mult)
-        >#%8_=_icmp_ugt_i32_%2__i32_5(mult)
-        >#%11_=_icmp_ult_i32_%2__i32_6(mult)
-        >#%14_=_icmp_ugt_i32_%2__i32_4(mult)
-        >#%17_=_add_i32_%2__i32_1(mult)
-        >#%18_=_icmp_eq_i32_%17__i32_10(mult)
-        >#%21_=_phi_i32_{_i32_%36__%call_term_for_30_}__{_i32_0__%call_term_for_14_}(mult)
-        >#%22_=_icmp_ugt_i32_%21__i32_5(mult)
-        >#%25_=_icmp_eq_i32_%21__i32_5(mult)
-        >#%26_=_select_i1_%25__i8_101__i8_110(mult)
-        >#%27_=_icmp_ult_i32_%21__i32_5(mult)
-        >#%30_=_icmp_ugt_i32_%21__i32_4(mult)
-        >#%33_=_icmp_ult_i32_%21__i32_6(mult)
-        >#%36_=_add_i32_%21__i32_1(mult)
-        >#%37_=_icmp_eq_i32_%36__i32_10(mult)
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#B:%0
-        [
-            -
-            copy up args
-            br label %1
-            doing phi stuff
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %17_ %call_term_for_14 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_0
-            
-            <<<<<<<<<<<<<<<<<<[-]
-            mov55/37 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
         <<]
         >>#B:%1
         [
             -
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %17_ %call_term_for_14 _
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %17_ %16 }
             %3 = icmp ult i32 %2_ i32 5
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup37/56/57 <[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
-            mov56/37 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>>>[-]
+            dup23/30/31 <[->>>>>>>+>+<<<<<<<<]
+            mov30/23 >>>>>>>[-<<<<<<<+>>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov58/59 <<<[->+<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov32/33 [->+<]
             >>>+
             <<<<[
                 -
@@ -928,56 +107,59 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<+
+            >>>>>>>>>]
             br i1 %3_ label %4_ label %5
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
                 >>+
-                #B:main/4_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                #B:main/%4_true
+            >>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>+
-                #B:main/5_false
-            <<<<]
+                >>>+
+                #B:main/%5_false
+            <<<]
         >]
         >#B:%4
         [
             -
             call @putchar(i8 108)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
             br label %5
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%5
         [
             -
             %6 = icmp eq i32 %2_ i32 5
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup37/55/56 <<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov55/37 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>>>[-]
+            dup23/29/30 <[->>>>>>+>+<<<<<<<]
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >>>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/62 <<<<<<<[->>>>>+<<<<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/36 [->>>>>+<<<<<]
             >>>>>>>+
             <<<<<<<<[
                 -
@@ -994,62 +176,67 @@ runtime init:
                 l1 <
             <<<<<<]
             >>>>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>[
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>]
             <<<<[
                 [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<[-]
+            >>>>>>>>]
             %7 = select i1 %6_ i8 101_ i8 110
-            <<<<<<<<<<<<<<<<<<[-]
-            dup39/55/56 <[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
-            mov55/39 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            
+            <<<<<<<[-]
+            dup24/29/30 <[->>>>>+>+<<<<<<]
+            mov29/24 >>>>>[-<<<<<+>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             op_to_reg storing const value in temp address
-            >#constop_110
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
             >+
-            <<<[
-                mov57/40 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
-                >[-]
-                <<[-]
-                >>>[-]
-            <<<]
-            >>>[
+            <<<<<[
+                mov31/25 >[-<<<<<<+>>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
                 -
-                mov58/40 <[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-                <[-]
+                mov33/25 <<[-<<<<<<<<+>>>>>>>>]
+                <<[-]
                 <[-]
-            >>>]
+            >>>>>]
             call @putchar(i8 %7)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_4
-            +
+            
             putchar intrinsic
-            dup40/55/56 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov55/40 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            dup25/29/30 <<<<<<<<<<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
             %8 = icmp ugt i32 %2_ i32 5
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup37/55/56 <<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov55/37 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            <<<<<<[-]
+            dup23/29/30 <[->>>>>>+>+<<<<<<<]
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov56/58 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/32 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -1068,56 +255,60 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
+                <<<<<<<<+
+            >>>>>>>>]
             br i1 %8_ label %9_ label %10
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>+
-                #B:main/9_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%9_true
+            >>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>+
-                #B:main/10_false
-            <<<<<<<<]
-        >>>>>]
+                >>>>>+
+                #B:main/%10_false
+            <<<<<]
+        >>>]
         >#B:%9
         [
             -
             call @putchar(i8 103)
-            enable next block when we return
-            >#caller/%call_term_for_6
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_103
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>>#constop_103
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
             br label %10
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%10
         [
             -
             %11 = icmp ult i32 %2_ i32 6
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup37/55/56 <<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov55/37 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>>>[-]
+            dup23/29/30 <[->>>>>>+>+<<<<<<<]
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_6
             ++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/58 <<<[->+<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/32 [->+<]
             >>>+
             <<<<[
                 -
@@ -1136,56 +327,60 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>]
+                <<<<<<<<+
+            >>>>>>>>]
             br i1 %11_ label %12_ label %13
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>+
-                #B:main/12_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%12_true
+            >>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>+
-                #B:main/13_false
-            <<<<<<<<<<<]
-        >>>>>>>>]
+                >>>>>>>+
+                #B:main/%13_false
+            <<<<<<<]
+        >>>>>]
         >#B:%12
         [
             -
             call @putchar(i8 76)
-            enable next block when we return
-            >#caller/%call_term_for_9
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_76
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>#constop_76
+            >+++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_9
-        [
-            -
             br label %13
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%13
         [
             -
             %14 = icmp ugt i32 %2_ i32 4
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup37/55/56 <<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov55/37 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            >>>>>>>>>>>>>>[-]
+            dup23/29/30 <[->>>>>>+>+<<<<<<<]
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_4
             ++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov56/58 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/32 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -1204,82 +399,105 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>]
+                <<<<<<<<+
+            >>>>>>>>]
             br i1 %14_ label %15_ label %16
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>+
-                #B:main/15_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>+
+                #B:main/%15_true
+            >>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>+
-                #B:main/16_false
-            <<<<<<<<<<<<<<]
-        >>>>>>>>>>>]
+                >>>>>>>>>+
+                #B:main/%16_false
+            <<<<<<<<<]
+        >>>>>>>]
         >#B:%15
         [
             -
             call @putchar(i8 71)
-            enable next block when we return
-            >#caller/%call_term_for_12
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_71
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>#constop_71
+            >+++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_12
-        [
-            -
             br label %16
-            >+
+            
+            <<<<<<<<<<<<<<<<<<<+
         <]
         >#B:%16
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_14
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_14
-        [
-            -
             %17 = add i32 %2_ i32 1
-            >>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup37/55/56 <<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
-            mov55/37 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            
+            <<<[-]
+            dup23/29/30 <<<[->>>>>>+>+<<<<<<<]
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov56/44 <[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            >[
+            mov30/26 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup26/33/35 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov35/26 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
                 -
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
+                >>>+
+                <<[
+                    -
+                    mov32/33 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov33/32 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             %18 = icmp eq i32 %17_ i32 10
-            <<<<<<<<<<<<[-]
-            dup44/55/56 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov55/44 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            
+            <<<<<<<<[-]
+            dup26/29/30 >>[->>>+>+<<<<]
+            mov29/26 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/59 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/33 [->>+<<]
             >>>>+
             <<<<<[
                 -
@@ -1296,89 +514,86 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>[
+            <<<<<<<<<<<+
+            >>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>]
+                <<<<<<<<[-]
+            >>>>>>>>]
             br i1 %18_ label %20_ label %1
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
             doing phi stuff
-            %21 = phi i32 _ i32 %36_ %call_term_for_30 __ _ i32 0_ %call_term_for_14 _
+            %21 = phi i32 { i32 %36_ %35 }_ { i32 0_ %16 }
             op_to_reg storing const value in temp address
-            <<<#constop_0
+            >#constop_0
             
-            <<<<<<<<<[-]
-            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov31/32 [->+<]
             doing phi stuff
-            %2 = phi i32 _ i32 0_ %0 __ _ i32 %17_ %call_term_for_14 _
-            dup44/56/57 <<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov56/44 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<[-]
-            mov57/37 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %17_ %16 }
+            dup26/33/34 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov33/26 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov34/35 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>+
-                #B:main/20_true
-            >>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%20_true
+                >>>>>>>>>>>>>[-]
+                mov32/27 >>>>>[-<<<<<+>>>>>]
+                >>>[-]
+            <<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
                 >+
-                #B:main/1_false
-            <]
-        >>>>>>>>>>>>>>>]
+                #B:main/%1_false
+                >>>>>>>>>>>>>>>>>>>[-]
+                mov35/23 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>]
         >#B:%19
         [
             -
             ret void
+            
             zero all function allocs
-            >>>>>>>>>>>>>>>>[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
+            >>>>>>>>>>[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<-
             #dead_frame
-            >>>-
+            >-
             l1 <
-            <<<[-]
-            r1 >
-            l7 <<<<<<<
-        >>>>>>>>>>>>>>>>>>>>>]
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>]
         >#B:%20
         [
             -
-            %21 = phi i32 _ i32 %36_ %call_term_for_30 __ _ i32 0_ %call_term_for_14 _
+            %21 = phi i32 { i32 %36_ %35 }_ { i32 0_ %16 }
             %22 = icmp ugt i32 %21_ i32 5
-            >>>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup46/58/59 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov58/46 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            
+            >>>>>>>>>>[-]
+            dup27/36/37 >>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov36/27 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
             op_to_reg storing const value in temp address
-            >>#constop_5
+            >>>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov59/61 <<<<[->>+<<]
-            >>>>+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov37/40 <<[->>>+<<<]
+            >>>>>+
             <<<[
                 -
                 
@@ -1396,56 +611,59 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>]
             br i1 %22_ label %23_ label %24
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<<<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>+
-                #B:main/23_true
-            >>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>+
+                #B:main/%23_true
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>+
-                #B:main/24_false
-            <<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>+
+                #B:main/%24_false
+            <<<<<<<<<<<<<]
+        >>>>>>>>>>>]
         >#B:%23
         [
             -
             call @putchar(i8 108)
-            enable next block when we return
-            >#caller/%call_term_for_18
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>>>>>>>>>>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_18
-        [
-            -
             br label %24
-            >+
+            
+            <<<<<<<<<<<<<<<+
         <]
         >#B:%24
         [
             -
             %25 = icmp eq i32 %21_ i32 5
-            >>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup46/55/56 <<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            
+            >>>>>>>>[-]
+            dup27/29/30 >>>[->>+>+<<<]
+            mov29/27 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/59 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/33 [->>+<<]
             >>>>+
             <<<<<[
                 -
@@ -1462,62 +680,67 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<<<<<<<+
-            >>>>>>>>>>>[
+            <<<<<<<<<<<+
+            >>>>>>>>>[
                 [-]
-                <<<<<<<<<<<[-]
-            >>>>>>>>>>>]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
             <[
                 [-]
-                <<<<<<<<<<[-]
-            >>>>>>>>>>]
+                <<<<<<<<[-]
+            >>>>>>>>]
             %26 = select i1 %25_ i8 101_ i8 110
-            <<<<<<<<<[-]
-            dup48/55/56 <[->>>>>>>+>+<<<<<<<<]
-            mov55/48 >>>>>>>[-<<<<<<<+>>>>>>>]
+            
+            <<<<<<<[-]
+            dup24/29/30 <[->>>>>+>+<<<<<<]
+            mov29/24 >>>>>[-<<<<<+>>>>>]
             op_to_reg storing const value in temp address
             >>#constop_101
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             op_to_reg storing const value in temp address
-            >#constop_110
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
             >+
-            <<<[
-                mov57/49 >[-<<<<<<<<+>>>>>>>>]
-                >[-]
-                <<[-]
-                >>>[-]
-            <<<]
-            >>>[
+            <<<<<[
+                mov31/25 >[-<<<<<<+>>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
                 -
-                mov58/49 <[-<<<<<<<<<+>>>>>>>>>]
-                <[-]
+                mov33/25 <<[-<<<<<<<<+>>>>>>>>]
+                <<[-]
                 <[-]
-            >>>]
+            >>>>>]
             call @putchar(i8 %26)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_20
-            +
+            
             putchar intrinsic
-            dup49/55/56 >>>>>>>>>>>>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
-            mov55/49 >>>>>>[-<<<<<<+>>>>>>]
+            dup25/29/30 <<<<<<<<<<[->>>>+>+<<<<<]
+            mov29/25 >>>>[-<<<<+>>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_20
-        [
-            -
             %27 = icmp ult i32 %21_ i32 5
-            >>>>>>>>>>>>>>>>>>>>>>>>[-]
-            dup46/55/56 <<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            
+            <<<<<<[-]
+            dup27/29/30 >>>[->>+>+<<<]
+            mov29/27 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_5
             +++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/58 <<<[->+<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/32 [->+<]
             >>>+
             <<<<[
                 -
@@ -1539,53 +762,57 @@ runtime init:
                 <<<<<<<<+
             >>>>>>>>]
             br i1 %27_ label %28_ label %29
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/28_true
-            >>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>+
+                #B:main/%28_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/29_false
-            <<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>>+
+                #B:main/%29_false
+            <<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>]
         >#B:%28
         [
             -
             call @putchar(i8 103)
-            enable next block when we return
-            >#caller/%call_term_for_22
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>#constop_103
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>>>#constop_103
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+++
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_22
-        [
-            -
             br label %29
-            >+
+            
+            <<<<<<<<<<<<<+
         <]
         >#B:%29
         [
             -
             %30 = icmp ugt i32 %21_ i32 4
-            >>>>>>>>>>>>>>>>>>>>>>[-]
-            dup46/55/56 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            
+            >>>>>>[-]
+            dup27/29/30 >>>[->>+>+<<<]
+            mov29/27 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_4
             ++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov56/58 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov30/32 <[->>+<<]
             >>>>+
             <<<[
                 -
@@ -1604,56 +831,60 @@ runtime init:
             >>>-
             <<[
                 [-]
-                <<<<<<<+
-            >>>>>>>]
+                <<<<<<<<+
+            >>>>>>>>]
             br i1 %30_ label %31_ label %32
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/31_true
-            >>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>+
+                #B:main/%31_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/32_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>>>>+
+                #B:main/%32_false
+            <<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>]
         >#B:%31
         [
             -
             call @putchar(i8 76)
-            enable next block when we return
-            >#caller/%call_term_for_25
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>#constop_76
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>>>#constop_76
+            >+++++
+            [
+                -
+                <+++++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_25
-        [
-            -
             br label %32
-            >+
+            
+            <<<<<<<<<<<+
         <]
         >#B:%32
         [
             -
             %33 = icmp ult i32 %21_ i32 6
-            >>>>>>>>>>>>>>>>>>>>[-]
-            dup46/55/56 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            
+            >>>>[-]
+            dup27/29/30 >>>[->>+>+<<<]
+            mov29/27 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_6
             ++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/58 <<<[->+<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/32 [->+<]
             >>>+
             <<<<[
                 -
@@ -1672,82 +903,105 @@ runtime init:
             >>>>-
             <<[
                 [-]
-                <<<<<<+
-            >>>>>>]
+                <<<<<<<<+
+            >>>>>>>>]
             br i1 %33_ label %34_ label %35
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/34_true
-            >>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>+
+                #B:main/%34_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
-                #B:main/35_false
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                >>>>>>>>>>>>>>>>>>>+
+                #B:main/%35_false
+            <<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>]
         >#B:%34
         [
             -
             call @putchar(i8 71)
-            enable next block when we return
-            >#caller/%call_term_for_28
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>#constop_71
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>>>>>>#constop_71
+            >+++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_28
-        [
-            -
             br label %35
-            >+
+            
+            <<<<<<<<<+
         <]
         >#B:%35
         [
             -
             call @putchar(i8 44)
-            enable next block when we return
-            >#caller/%call_term_for_30
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>#constop_44
-            ++++++++++++++++++++++++++++++++++++++++++++
-            .
+            >>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_30
-        [
-            -
             %36 = add i32 %21_ i32 1
-            >>>>>>>>>>>>>>>>>[-]
-            dup46/55/56 <<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov55/46 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            
+            <[-]
+            dup27/29/30 <[->>+>+<<<]
+            mov29/27 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_1
             +
-            mov56/53 <[-<<<+>>>]
-            >[
+            mov30/28 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/33/35 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov35/28 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
                 -
-                <<<<+
-            >>>>]
+                >>>+
+                <<[
+                    -
+                    mov32/33 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov33/32 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
             %37 = icmp eq i32 %36_ i32 10
-            <<<[-]
-            dup53/55/56 <[->>+>+<<<]
-            mov55/53 >>[-<<+>>]
+            
+            <<<<<<<<[-]
+            dup28/29/30 >>>>[->+>+<<]
+            mov29/28 >[-<+>]
             op_to_reg storing const value in temp address
             >>#constop_10
             ++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov57/59 <<<<[->>+<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov31/33 [->>+<<]
             >>>>+
             <<<<<[
                 -
@@ -1764,34 +1018,39 @@ runtime init:
                 l1 <
             <<<]
             >>>>>-
-            <<<<<<<+
-            >>>>>[
+            <<<<<<<<<<<+
+            >>>>>>>>>[
                 [-]
-                <<<<<[-]
-            >>>>>]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
             <[
                 [-]
-                <<<<[-]
-            >>>>]
+                <<<<<<<<[-]
+            >>>>>>>>]
             br i1 %37_ label %19_ label %20
+            
+            dup24/30/29 <<<<<<<<[->>>>>>+<+<<<<<]
+            mov30/24 >>>>>>[-<<<<<<+>>>>>>]
             doing phi stuff
-            %21 = phi i32 _ i32 %36_ %call_term_for_30 __ _ i32 0_ %call_term_for_14 _
-            dup53/55/56 <<<<<[->>+>+<<<]
-            mov55/53 >>[-<<+>>]
-            <<<<<<<<<[-]
-            mov56/46 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+            %21 = phi i32 { i32 %36_ %35 }_ { i32 0_ %16 }
+            dup28/31/32 <<[->>>+>+<<<<]
+            mov31/28 >>>[-<<<+>>>]
+            mov32/33 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
                 -
-                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>>>+
-                #B:main/19_true
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                <<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>+
+                #B:main/%19_true
+                >>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<[
                 -
-                >>>>>>>>>>>>>>>>>+
-                #B:main/20_false
-            <<<<<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-<<<]
+                >>>>>>>>>>>+
+                #B:main/%20_false
+                >>>>>>>>>>>>>[-]
+                mov33/27 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<<<<]
+<]
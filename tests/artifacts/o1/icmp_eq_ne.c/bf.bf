@@ -0,0 +1,523 @@
+eof: 0
+blocks made up by bfcc:
+  check/%1_sw_1 from %1 (switch case)
+  main/%0_sw_1 from %0 (switch case)
+  main/%5_sw_1 from %5 (switch case)
+  main/%10_sw_1 from %10 (switch case)
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4112 ops ~81294 steps
+frame widths:
+  main: 30 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%0_sw_1
+        #B:%3
+        #B:%4
+        #B:%5
+        #B:%5_sw_1
+        #B:%8
+        #B:%9
+        #B:%10
+        #B:%10_sw_1
+        #B:%13
+        #B:%14
+        #B:%15
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%sw_0_0_=_icmp_eq_i8_%2__i8_3(mult)
+        #%7_=_load_volatile_i8*_%1__align_1(mult)
+        #%12_=_load_volatile_i8*_%1__align_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 3_ i8* %1_ align 1
+            
+            >>>>>>>>>>>>>>[-]
+            +++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 3
+            +++
+            %sw_0_0 = icmp eq i8 %2_ i8 3
+            
+            >[-]
+            known to be 1
+            +
+            br i1 %sw_0_0_ label %3_ label %0_sw_1
+            
+            dup18/22/21 [->>>>+<+<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%3_true
+            >>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%0_sw_1_false
+            <]
+        <]
+        >>#B:%0_sw_1
+        [
+            -
+            %sw_0_1 = icmp eq i8 %2_ i8 0
+            
+            >>>>>>>>>>>>>>[-]
+            dup17/23/24 <[->>>>>>+>+<<<<<<<]
+            mov23/17 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<+
+            >>>>>>[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %sw_0_1_ label %5_ label %4
+            
+            dup18/22/21 <<<<<<[->>>>+<+<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%5_true
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%4_false
+            <<<]
+        >]
+        >#B:%3
+        [
+            -
+            call @putchar(i8 61)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>#constop_61
+            >>++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            br label %4
+            
+            <<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%4
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %5
+            
+            <<<<<<<<<<<<<<+
+        <]
+        >#B:%5
+        [
+            -
+            %6 = add i8 %2_ i8 48
+            
+            >>>>>>>>>>>[-]
+            dup17/21/22 <[->>>>+>+<<<<<]
+            mov21/17 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov22/18 <<[-<<<<+>>>>]
+            >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup18/27/29 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov29/18 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<<[
+                -
+                >>>>>+
+                <<[
+                    -
+                    mov26/27 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov27/26 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<]
+            >>>[-]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup18/21/22 <<<<<<<<[->>>+>+<<<<]
+            mov21/18 >>>[-<<<+>>>]
+            >.
+            [-]
+            store volatile i8 5_ i8* %1_ align 1
+            
+            <<<<<<[-]
+            +++++
+            %7 = load volatile i8* %1_ align 1
+            
+            >>>[-]
+            known to be 5
+            +++++
+            %sw_5_0 = icmp eq i8 %7_ i8 3
+            
+            <[-]
+            known to be 0
+            
+            br i1 %sw_5_0_ label %8_ label %5_sw_1
+            
+            dup18/22/21 [->>>>+<+<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%8_true
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>+
+                #B:main/%5_sw_1_false
+            <<<<<]
+        >>>>]
+        >#B:%5_sw_1
+        [
+            -
+            %sw_5_1 = icmp eq i8 %7_ i8 0
+            
+            >>>>>>>>>>[-]
+            dup19/23/24 >[->>>>+>+<<<<<]
+            mov23/19 >>>>[-<<<<+>>>>]
+            <<<<<+
+            >>>>>>[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %sw_5_1_ label %10_ label %9
+            
+            dup18/22/21 <<<<<<[->>>>+<+<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<-
+                >>>>>>>>+
+                #B:main/%10_true
+            >>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%9_false
+            <<<<<<<]
+        >>>>>]
+        >#B:%8
+        [
+            -
+            call @putchar(i8 61)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>#constop_61
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %9
+            
+            <<<<<<<<<<<<<+
+        <]
+        >#B:%9
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %10
+            
+            <<<<<<<<<<+
+        <]
+        >#B:%10
+        [
+            -
+            %11 = add i8 %7_ i8 48
+            
+            >>>>>>>[-]
+            dup19/21/22 >[->>+>+<<<]
+            mov21/19 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov22/18 <<[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup18/26/28 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov28/18 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov25/26 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov26/25 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            dup18/21/22 <<<<<<<[->>>+>+<<<<]
+            mov21/18 >>>[-<<<+>>>]
+            >.
+            [-]
+            store volatile i8 0_ i8* %1_ align 1
+            
+            <<<<<<[-]
+            
+            %12 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            known to be 0
+            
+            %sw_10_0 = icmp eq i8 %12_ i8 3
+            
+            <<[-]
+            known to be 0
+            
+            br i1 %sw_10_0_ label %13_ label %10_sw_1
+            
+            dup18/22/21 [->>>>+<+<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>+
+                #B:main/%13_true
+            >>>>>>>>]
+            <<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>+
+                #B:main/%10_sw_1_false
+            <<<<<<<<<]
+        >>>>>>>>]
+        >#B:%10_sw_1
+        [
+            -
+            %sw_10_1 = icmp eq i8 %12_ i8 0
+            
+            >>>>>>[-]
+            dup20/23/24 >>[->>>+>+<<<<]
+            mov23/20 >>>[-<<<+>>>]
+            <<<<<+
+            >>>>>>[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            br i1 %sw_10_1_ label %15_ label %14
+            
+            dup18/22/21 <<<<<<[->>>>+<+<<<]
+            mov22/18 >>>>[-<<<<+>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>+
+                #B:main/%15_true
+            >>>>>>]
+            <<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>+
+                #B:main/%14_false
+            <<<<<<<<<<<]
+        >>>>>>>>>]
+        >#B:%13
+        [
+            -
+            call @putchar(i8 61)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_61
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %14
+            
+            <<<<<<<<<+
+        <]
+        >#B:%14
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %15
+            
+            <<<<<<+
+        <]
+        >#B:%15
+        [
+            -
+            %16 = add i8 %12_ i8 48
+            
+            >>>[-]
+            dup20/21/22 >>[->+>+<<]
+            mov21/20 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov22/18 <<[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup18/26/28 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov28/18 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov25/26 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov26/25 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            dup18/21/22 <<<<<<<[->>>+>+<<<<]
+            mov21/18 >>>[-<<<+>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>]
+    <<<<<<<<<<<<<<]
+<]
@@ -0,0 +1,940 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %11 after call 0 (call continuation)
+  main/%call_term_for_1 from %11 after call 1 (call continuation)
+  main/%call_term_for_2 from %11 after call 2 (call continuation)
+  main/%call_term_for_3 from %11 after call 3 (call continuation)
+  main/%call_term_for_4 from %11 after call 4 (call continuation)
+call graph:
+  sign (depth 1)
+  main calls sign (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  sign: 3239 ops ~24162 steps
+  main: 5939 ops ~78172 steps
+frame widths:
+  sign: 32 cells
+  main: 34 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#sign
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/sign
+            >>>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_sign
+        #F:sign
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%6
+        #B:%8
+        #B:%9
+        #%2_=_icmp_sgt_i8_%0__i8_255(mult)
+        #%3_=_select_i1_%2__i8_43__i8_45(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/13 <<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            r2 >>
+            %2 = icmp sgt i8 %0_ i8 255
+            
+            >>>>>>>>>[-]
+            dup11/12/13 >>[->+>+<<]
+            mov12/11 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/18 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/23 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/26 <<<[->>>>>>>>+<<<<<<<<]
+            >>>>>>>>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            %3 = select i1 %2_ i8 43_ i8 45
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup9/12/13 <[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_43
+            >++++++
+            [
+                -
+                <+++++++
+            >]
+            <+
+            op_to_reg storing const value in temp address
+            >>#constop_45
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            >+
+            <<<<<[
+                mov14/10 >[-<<<<+>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov16/10 <<[-<<<<<<+>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup10/12/13 <<<<<<<<[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            >.
+            [-]
+            %4 = icmp slt i8 %0_ i8 156
+            
+            <<<<[-]
+            dup11/12/13 >>[->+>+<<]
+            mov12/11 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_156
+            >++++++++++++
+            [
+                -
+                <+++++++++++++
+            >]
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/18 <<<<[->>>>>+<<<<<]
+            >>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/23 <<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/29 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                
+                >>>>>>>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %4_ label %5_ label %6
+            
+            dup9/13/12 <<<<<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >+
+                #B:sign/%5_true
+            >>>>>>>]
+            <<<<<<<<[
+                -
+                >>+
+                #B:sign/%6_false
+            <<]
+        <]
+        >>#B:%5
+        [
+            -
+            call @putchar(i8 60)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_60
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            [-]
+            br label %6
+            
+            <<<<<<<<+
+        <]
+        >#B:%6
+        [
+            -
+            %7 = icmp eq i8 %0_ i8 127
+            
+            >>>[-]
+            dup11/12/13 >>[->+>+<<]
+            mov12/11 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_127
+            >+++++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            <+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/17 [->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            br i1 %7_ label %8_ label %9
+            
+            dup9/13/12 <<<<<<<[->>>>+<+<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            <<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >>>+
+                #B:sign/%8_true
+            >>>>>]
+            <<<<<<<<[
+                -
+                >>>>+
+                #B:sign/%9_false
+            <<<<]
+        >>]
+        >#B:%8
+        [
+            -
+            call @putchar(i8 62)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>#constop_62
+            >++++++
+            [
+                -
+                <++++++++++
+            >]
+            <++
+            .
+            [-]
+            br label %9
+            
+            <<<<<<+
+        <]
+        >#B:%9
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >>]
+    <<<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l34 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:sign
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%11
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #B:%call_term_for_3
+        #B:%call_term_for_4
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_icmp_sgt_i8_%3__i8_0(mult)
+        #%6_=_phi_i8_{_i8_%9__%5_}__{_i8_%3__%0_}(mult)
+        #%9_=_sub_i8_%6__i8_%8(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 3_ i8* %1_ align 1
+            
+            >>>>>>>>>[-]
+            +++
+            store volatile i8 1_ i8* %2_ align 1
+            
+            >[-]
+            +
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 3
+            +++
+            %4 = icmp sgt i8 %3_ i8 0
+            
+            >[-]
+            known to be 1
+            +
+            br i1 %4_ label %5_ label %11
+            
+            dup15/19/18 [->>>>+<+<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            doing phi stuff
+            %6 = phi i8 { i8 %9_ %5 }_ { i8 %3_ %0 }
+            dup14/20/21 <<<<<[->>>>>>+>+<<<<<<<]
+            mov20/14 >>>>>>[-<<<<<<+>>>>>>]
+            mov21/22 >[->+<]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >+
+                #B:main/%5_true
+                >>>>>>>>>>>[-]
+                mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<]
+            <<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%11_false
+                >>>>>>>>>>>>>>>>[-]
+            <<<<<<<<<<<<<<<<<<]
+        <]
+        >>#B:%5
+        [
+            -
+            %6 = phi i8 { i8 %9_ %5 }_ { i8 %3_ %0 }
+            %7 = add i8 %6_ i8 48
+            
+            >>>>>>>>>>[-]
+            dup16/23/24 >[->>>>>>>+>+<<<<<<<<]
+            mov23/16 >>>>>>>[-<<<<<<<+>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov24/15 <<[-<<<<<<<<<+>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/28/30 <<<<<<<<<<<<[->>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov30/15 >>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov27/28 [->+<]
+                    <<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>-
+                <<]
+                mov28/27 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            dup15/18/19 <<<<<<<<<<<<[->>>+>+<<<<]
+            mov18/15 >>>[-<<<+>>>]
+            >.
+            [-]
+            %8 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup13/15/18 <<[->>+>>>+<<<<<]
+            mov18/13 >>>>>[-<<<<<+>>>>>]
+            %9 = sub i8 %6_ i8 %8
+            
+            <[-]
+            dup16/18/19 <[->>+>+<<<]
+            mov18/16 >>[-<<+>>]
+            dup15/20/21 <<<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            mov19/17 <[-<<+>>]
+            >>[
+                -
+                >>+
+                <<<<<<[
+                    -
+                    mov17/22 [->>>>>+<<<<<]
+                    >>>>>>-
+                <<<<<<]
+                mov22/17 >>>>>[-<<<<<+>>>>>]
+                >[
+                    -
+                    <<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>]
+            <<]
+            %10 = icmp sgt i8 %9_ i8 0
+            
+            <<<<<<[-]
+            dup17/18/19 >>[->+>+<<]
+            mov18/17 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov19/23 <<<[->>>>+<<<<]
+            >>>>>>+
+            <<<[
+                -
+                <+
+                >>[
+                    -
+                    <<-
+                    r1 >
+                >>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<<<[
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<[
+                    -
+                    >>-
+                <<]
+                >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<]
+            >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov20/31 <<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
+            >>>>>>>>>>>>>+
+            <<<<<<[
+                -
+                <+
+                >>>>>[
+                    -
+                    <<<<<-
+                    r1 >
+                >>>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<[
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<[
+                    -
+                    >>>>>-
+                <<<<<]
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/28 <<<[->>>>>+<<<<<]
+            >>>>>>>+
+            >[
+                -
+                
+                <<<[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 
+            >>]
+            <-
+            <<[
+                [-]
+                <<<<<<<<<<<<<+
+            >>>>>>>>>>>>>]
+            br i1 %10_ label %5_ label %11
+            
+            dup15/19/18 <<<<<<<<<<<<<[->>>>+<+<<<]
+            mov19/15 >>>>[-<<<<+>>>>]
+            doing phi stuff
+            %6 = phi i8 { i8 %9_ %5 }_ { i8 %3_ %0 }
+            dup17/20/21 <<[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            mov21/22 >[->+<]
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<-
+                >+
+                #B:main/%5_true
+                >>>>>>>>>>>[-]
+                mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<]
+            <<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%11_false
+                >>>>>>>>>>>>>>>>[-]
+            <<<<<<<<<<<<<<<<<<]
+        >]
+        >#B:%11
+        [
+            -
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>#constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            call @sign(i8 255)
+            
+            enable next block when we return
+            <<<<<<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 34
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            mov18/39 <[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/41/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>]
+        >#B:%call_term_for_0
+        [
+            -
+            call @sign(i8 5)
+            
+            enable next block when we return
+            >#caller/%call_term_for_1
+            +
+            stack_width 34
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_5
+            +++++
+            mov18/39 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/41/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>]
+        >#B:%call_term_for_1
+        [
+            -
+            call @sign(i8 128)
+            
+            enable next block when we return
+            >#caller/%call_term_for_2
+            +
+            stack_width 34
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_128
+            >++++++++
+            [
+                -
+                <++++++++++++++++
+            >]
+            mov18/39 <[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/41/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            call @sign(i8 0)
+            
+            enable next block when we return
+            >#caller/%call_term_for_3
+            +
+            stack_width 34
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov18/39 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/41/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>>]
+        >#B:%call_term_for_3
+        [
+            -
+            call @sign(i8 127)
+            
+            enable next block when we return
+            >#caller/%call_term_for_4
+            +
+            stack_width 34
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_127
+            >+++++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            <+
+            mov18/39 [->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/41/42 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r34 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_sign
+            +
+            >#sign
+            +
+            >>#sign/b0
+            +
+        >>>>>>>]
+        >#B:%call_term_for_4
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>>>>]
+    <<<<<<<<<]
+<<]
@@ -0,0 +1,447 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2927 ops ~110153 steps
+frame widths:
+  main: 31 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l31 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_icmp_eq_i8_%3__i8_0(mult)
+        #%19_=_icmp_ne_i8_%18__i8_0(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 0_ i8* %1_ align 1
+            
+            >>[-]
+            
+            store volatile i8 7_ i8* %2_ align 1
+            
+            >[-]
+            +++++++
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 0
+            
+            %4 = icmp eq i8 %3_ i8 0
+            
+            >[-]
+            known to be 1
+            +
+            %5 = select i1 %4_ i8 121_ i8 110
+            
+            <[-]
+            known to be 121
+            >>>+++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            >.
+            [-]
+            %6 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/6/9 <[->+>>>+<<<<]
+            mov9/5 >>>>[-<<<<+>>>>]
+            %7 = icmp eq i8 %6_ i8 0
+            
+            <<[-]
+            dup6/9/11 <[->>>+>>+<<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            <<+
+            >>>>[
+                [-]
+                <<<<[-]
+            >>>>]
+            %8 = select i1 %7_ i8 121_ i8 110
+            
+            <<<<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            op_to_reg storing const value in temp address
+            >>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            >+
+            <<<<<[
+                mov12/6 >>[-<<<<<<+>>>>>>]
+                >[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov13/6 <<[-<<<<<<<+>>>>>>>]
+                <[-]
+                <<[-]
+            >>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup6/9/10 <<<<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            >.
+            [-]
+            %9 = load volatile i8* %1_ align 1
+            
+            <<<<[-]
+            dup4/6/9 <<[->>+>>>+<<<<<]
+            mov9/4 >>>>>[-<<<<<+>>>>>]
+            %10 = icmp eq i8 %9_ i8 0
+            
+            <<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            <<+
+            >>>[
+                [-]
+                <<<[-]
+            >>>]
+            %11 = select i1 %10_ i8 110_ i8 121
+            
+            <<<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            op_to_reg storing const value in temp address
+            >#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            >>+
+            <<<<<<[
+                mov11/6 >[-<<<<<+>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>>[-]
+            <<<<<<]
+            >>>>>>[
+                -
+                mov13/6 <<<[-<<<<<<<+>>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            dup6/9/10 <<<<<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            >.
+            [-]
+            %12 = load volatile i8* %2_ align 1
+            
+            <<<<[-]
+            dup5/6/9 <[->+>>>+<<<<]
+            mov9/5 >>>>[-<<<<+>>>>]
+            %13 = icmp eq i8 %12_ i8 0
+            
+            <<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            <<+
+            >>>[
+                [-]
+                <<<[-]
+            >>>]
+            %14 = select i1 %13_ i8 110_ i8 121
+            
+            <<<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_110
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            op_to_reg storing const value in temp address
+            >#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            >+
+            <<<<<[
+                mov11/6 >[-<<<<<+>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov13/6 <<[-<<<<<<<+>>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            dup6/9/10 <<<<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            >.
+            [-]
+            %15 = load volatile i8* %1_ align 1
+            
+            <<<<[-]
+            dup4/6/9 <<[->>+>>>+<<<<<]
+            mov9/4 >>>>>[-<<<<<+>>>>>]
+            %16 = icmp eq i8 %15_ i8 0
+            
+            <<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            <<+
+            >>>[
+                [-]
+                <<<[-]
+            >>>]
+            %17 = zext i1 %16 to i8
+            
+            <<<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            mov10/6 >[-<<<<+>>>>]
+            %18 = load volatile i8* %2_ align 1
+            
+            <<<[-]
+            dup5/7/9 <<[->>+>>+<<<<]
+            mov9/5 >>>>[-<<<<+>>>>]
+            %19 = icmp ne i8 %18_ i8 0
+            
+            <[-]
+            dup7/9/10 <[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            >[
+                [-]
+                <<+
+            >>]
+            %20 = zext i1 %19 to i8
+            
+            <<<[-]
+            dup8/9/10 >[->+>+<<]
+            mov9/8 >[-<+>]
+            mov10/7 >[-<<<+>>>]
+            %21 = add i8 %20_ i8 %17
+            
+            <<[-]
+            dup7/9/10 <[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            dup6/11/12 <<<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            mov10/8 <[-<<+>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/18/20 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov20/8 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %22 = or i8 %21_ i8 48
+            
+            <<<<<<<<<<<[-]
+            dup8/9/10 >>[->+>+<<]
+            mov9/8 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov10/21 <<[->>>>>>>>>>>+<<<<<<<<<<<]
+            mov11/26 >[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<<<<+
+            [
+                [-]
+                >>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup25/22/21 >>>>[-<<<+<+>>>>]
+                mov22/25 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup30/27/26 >>>>[-<<<+<+>>>>]
+                mov27/30 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    mov24/13 [-<<<<<<<<<<<+>>>>>>>>>>>]
+                    >>>>>[-]
+                <<<<<]
+                >>>>>[
+                    mov29/13 [-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+                ]
+                dup17/14/15 <<<<<<<<<<<<[-<<<+>+>>]
+                mov14/17 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov14/13 >[-<+>]
+                >]
+                mov13/6 <<[-<<<<<<<+>>>>>>>]
+                continue?
+                dup21/13/14 >>>>>>>>[-<<<<<<<<+>+>>>>>>>]
+                mov13/21 <<<<<<<<[->>>>>>>>+<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup26/13/14 >>>>>>>>>>>>[-<<<<<<<<<<<<<+>+>>>>>>>>>>>>]
+                mov13/26 <<<<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>>>>-
+            >>>>>-
+            <<<<<<<<<<<[-]
+            call @putchar(i8 %22)
+            
+            putchar intrinsic
+            dup6/9/10 <<<<<<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,567 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 4211 ops ~125059 steps
+frame widths:
+  main: 33 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_icmp_eq_i8_%3__i8_121(mult)
+        #%7_=_icmp_eq_i8_%6__i8_121(mult)
+        #%11_=_load_volatile_i8*_%2__align_1(mult)
+        #%12_=_icmp_ugt_i8_%10__i8_%11(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 121_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>>+++++++++++
+            [
+                -
+                <<<<<<<+++++++++++
+            >>>>>>>]
+            store volatile i8 110_ i8* %2_ align 1
+            
+            <<<<<<[-]
+            >>>>>>>++++++++++
+            [
+                -
+                <<<<<<<+++++++++++
+            >>>>>>>]
+            %3 = load volatile i8* %1_ align 1
+            
+            <<<<<<[-]
+            known to be 121
+            >>>>>+++++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %4 = icmp eq i8 %3_ i8 121
+            
+            <<<<[-]
+            known to be 1
+            +
+            %5 = select i1 %4_ i8 49_ i8 48
+            
+            <[-]
+            known to be 49
+            >>>>>+++++++
+            [
+                -
+                <<<<<+++++++
+            >>>>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_49
+            >>+++++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<.
+            [-]
+            %6 = load volatile i8* %2_ align 1
+            
+            <<<<<[-]
+            dup5/6/11 <[->+>>>>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %7 = icmp eq i8 %6_ i8 121
+            
+            <<<[-]
+            dup6/11/12 <<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            +++++++++++
+            [
+                -
+                >+++++++++++
+            <]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/16 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            %8 = select i1 %7_ i8 68_ i8 65
+            
+            <<<<<<<<<[-]
+            dup8/11/12 >>[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_68
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <++
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            >>+
+            <<<<<[
+                mov13/6 >[-<<<<<<<+>>>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov15/6 <<[-<<<<<<<<<+>>>>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup6/11/12 <<<<<<<<<<<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            %9 = select i1 %4_ i8 66_ i8 65
+            
+            <<<<<<[-]
+            known to be 66
+            >>>>>++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %10 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            dup4/8/11 <<<<[->>>>+>>>+<<<<<<<]
+            mov11/4 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %11 = load volatile i8* %2_ align 1
+            
+            <<[-]
+            dup5/9/11 <<<<[->>>>+>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %12 = icmp ugt i8 %10_ i8 %11
+            
+            <[-]
+            dup8/11/12 <<[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            dup9/13/14 <<[->>>>+>+<<<<<]
+            mov13/9 >>>>[-<<<<+>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/15 <[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                
+                >[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<[
+                [-]
+                <<<<<+
+            >>>>>]
+            %13 = zext i1 %12 to i8
+            
+            <<<<<<<[-]
+            dup10/11/12 >>[->+>+<<]
+            mov11/10 >[-<+>]
+            mov12/8 >[-<<<<+>>>>]
+            %14 = add i8 %9_ i8 %13
+            
+            <<<[-]
+            op_to_reg storing const value in temp address
+            >>#constop_66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            dup8/13/14 <<<<[->>>>>+>+<<<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            mov11/9 <<[-<<+>>]
+            >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/20/22 <<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov22/9 >>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<[
+                -
+                >>>>>>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<<<<<<+
+                    >>>>>>>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<[-]
+                    >>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<]
+            >>>>>[-]
+            %15 = load volatile i8* %2_ align 1
+            
+            <<<<<<<<<<<<<[-]
+            dup5/6/11 <[->+>>>>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %16 = icmp ult i8 %15_ i8 97
+            
+            <<<[-]
+            dup6/11/12 <<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/15 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<<+
+            >>>>>>>]
+            %17 = zext i1 %16 to i8
+            
+            <<<<<<<<<[-]
+            dup8/11/12 >>[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov12/6 >[-<<<<<<+>>>>>>]
+            %18 = add i8 %14_ i8 %17
+            
+            <<<<[-]
+            dup9/11/12 >[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            dup6/13/14 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov12/8 <[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/16/18 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov18/8 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            call @putchar(i8 %18)
+            
+            putchar intrinsic
+            dup8/11/12 <<<<<<<[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            >.
+            [-]
+            %19 = load volatile i8* %2_ align 1
+            
+            <<<<<<[-]
+            dup5/6/11 <[->+>>>>>+<<<<<<]
+            mov11/5 >>>>>>[-<<<<<<+>>>>>>]
+            %20 = icmp eq i8 %19_ i8 121
+            
+            <<<[-]
+            dup6/11/12 <<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_121
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/16 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<+
+            >>>>>>>>[
+                [-]
+                <<<<<<<<[-]
+            >>>>>>>>]
+            <[
+                [-]
+                <<<<<<<[-]
+            >>>>>>>]
+            %21 = and i1 %4_ i1 %20
+            
+            <<<<<<<<<[-]
+            op_to_reg storing const value in temp address
+            >>>>>#constop_1
+            +
+            dup8/12/13 <<<[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov11/23 <[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            mov13/28 >>[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<<<<<+
+            [
+                [-]
+                >>>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup27/24/23 >>>>[-<<<+<+>>>>]
+                mov24/27 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup32/29/28 >>>>[-<<<+<+>>>>]
+                mov29/32 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov31/14 >>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup18/15/16 <<<<<<<<<<<<<[-<<<+>+>>]
+                mov15/18 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov15/14 >[-<+>]
+                >]
+                mov14/6 <<[-<<<<<<<<+>>>>>>>>]
+                continue?
+                dup23/14/15 >>>>>>>>>[-<<<<<<<<<+>+>>>>>>>>]
+                mov14/23 <<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup28/14/15 >>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>]
+                mov14/28 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>>>>>-
+            >>>>>-
+            <<<<<<<<<<<<[-]
+            %22 = select i1 %21_ i8 49_ i8 48
+            
+            <<<<<<<<<<<[-]
+            dup6/11/12 <[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_49
+            >+++++++
+            [
+                -
+                <+++++++
+            >]
+            op_to_reg storing const value in temp address
+            >#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            >+
+            <<<<<[
+                mov13/7 >[-<<<<<<+>>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov15/7 <<[-<<<<<<<<+>>>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %22)
+            
+            putchar intrinsic
+            dup7/11/12 <<<<<<<<<<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
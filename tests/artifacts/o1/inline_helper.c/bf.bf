@@ -0,0 +1,217 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1904 ops ~51779 steps
+frame widths:
+  main: 18 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l18 <<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%2
+        #%3_=_phi_i8_{_i8_0__%0_}__{_i8_%5__%2_}(mult)
+        #%4_=_add_i8_%3__i8_48(mult)
+        #%5_=_add_i8_%3__i8_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            br label %2
+            
+            doing phi stuff
+            %3 = phi i8 { i8 0_ %0 }_ { i8 %5_ %2 }
+            op_to_reg storing const value in temp address
+            >>>>#constop_0
+            
+            [-]
+            mov9/6 >>>[-<<<+>>>]
+            <<<<+
+        <<<]
+        >>#B:%1
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >>[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        ]
+        >#B:%2
+        [
+            -
+            %3 = phi i8 { i8 0_ %0 }_ { i8 %5_ %2 }
+            %4 = add i8 %3_ i8 48
+            
+            >>[-]
+            dup6/10/11 <[->>>>+>+<<<<<]
+            mov10/6 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov11/7 <<[-<<<<+>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/15/17 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov17/7 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov14/15 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov15/14 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup7/9/10 <<<<<<<[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            >.
+            [-]
+            %5 = add i8 %3_ i8 1
+            
+            <<[-]
+            dup6/9/10 <<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov10/8 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/13/15 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov15/8 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov12/13 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov13/12 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %6 = icmp eq i8 %5_ i8 10
+            
+            <<<<<[-]
+            dup8/9/10 >[->+>+<<]
+            mov9/8 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov11/13 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<+
+            >>>>>>[
+                [-]
+                <<<<<<[-]
+            >>>>>>]
+            <[
+                [-]
+                <<<<<[-]
+            >>>>>]
+            br i1 %6_ label %1_ label %2
+            
+            dup7/10/9 <<<<<[->>>+<+<<]
+            mov10/7 >>>[-<<<+>>>]
+            doing phi stuff
+            %3 = phi i8 { i8 0_ %0 }_ { i8 %5_ %2 }
+            dup8/11/12 <<[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov12/13 >[->+<]
+            <<<<<<<<<+
+            >>>>>>[
+                -
+                <<<<<<-
+                >+
+                #B:main/%1_true
+                >>>>>>>>>[-]
+            <<<<]
+            <<<<<<[
+                -
+                >>+
+                #B:main/%2_false
+                >[-]
+                mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<]
+        >>]
+    <<<<]
+<]
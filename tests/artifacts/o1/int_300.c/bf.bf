@@ -0,0 +1,97 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 201 ops ~803 steps
+frame widths:
+  main: 10 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l10 <<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #%3_=_load_volatile_i32*_%1__align_4(mult)
+        #%4_=_trunc_i32_%3_to_i8(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i32_ align 4
+            store volatile i32 300_ i32* %1_ align 4
+            
+            >>[-]
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %3 = load volatile i32* %1_ align 4
+            
+            <<[-]
+            known to be 44
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %4 = trunc i32 %3 to i8
+            
+            <<[-]
+            known to be 44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<[-]
+            >[-]
+            >[-]
+            <<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
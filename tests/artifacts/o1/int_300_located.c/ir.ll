@@ -0,0 +1,194 @@
+; ModuleID = './tests/artifacts/o1/int_300_located.c/ir.bc'
+source_filename = "./tests/cases/int_300_located.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 !dbg !9 {
+  call void @llvm.dbg.value(metadata i8* %0, metadata !16, metadata !DIExpression()), !dbg !17
+  %2 = load i8, i8* %0, align 1, !dbg !18, !tbaa !19
+  %3 = icmp eq i8 %2, 0, !dbg !22
+  br i1 %3, label %10, label %4, !dbg !22
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @llvm.dbg.value(metadata i8* %6, metadata !16, metadata !DIExpression()), !dbg !17
+  call void @putchar(i8 noundef zeroext %5) #4, !dbg !23
+  %7 = getelementptr inbounds i8, i8* %6, i64 1, !dbg !25
+  call void @llvm.dbg.value(metadata i8* %7, metadata !16, metadata !DIExpression()), !dbg !17
+  %8 = load i8, i8* %7, align 1, !dbg !18, !tbaa !19
+  %9 = icmp eq i8 %8, 0, !dbg !22
+  br i1 %9, label %10, label %4, !dbg !22, !llvm.loop !26
+
+10:                                               ; preds = %4, %1
+  ret void, !dbg !30
+}
+
+; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
+declare void @llvm.dbg.declare(metadata, metadata, metadata) #1
+
+declare !dbg !31 void @putchar(i8 noundef zeroext) local_unnamed_addr #2
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 !dbg !36 {
+  call void @llvm.dbg.value(metadata i32 %0, metadata !41, metadata !DIExpression()), !dbg !43
+  %2 = icmp eq i32 %0, 0, !dbg !44
+  br i1 %2, label %5, label %3, !dbg !46
+
+3:                                                ; preds = %1
+  call void @llvm.dbg.value(metadata i32 1, metadata !42, metadata !DIExpression()), !dbg !43
+  %4 = icmp ugt i32 %0, 9, !dbg !47
+  br i1 %4, label %9, label %6, !dbg !48
+
+5:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48) #4, !dbg !49
+  br label %22, !dbg !51
+
+6:                                                ; preds = %9, %3
+  %7 = phi i32 [ 1, %3 ], [ %11, %9 ], !dbg !43
+  call void @llvm.dbg.value(metadata i32 %7, metadata !42, metadata !DIExpression()), !dbg !43
+  %8 = icmp eq i32 %7, 0, !dbg !52
+  br i1 %8, label %22, label %14, !dbg !52
+
+9:                                                ; preds = %9, %3
+  %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
+  call void @llvm.dbg.value(metadata i32 %10, metadata !42, metadata !DIExpression()), !dbg !43
+  %11 = mul i32 %10, 10, !dbg !53
+  call void @llvm.dbg.value(metadata i32 %11, metadata !42, metadata !DIExpression()), !dbg !43
+  %12 = udiv i32 %0, %11, !dbg !54
+  %13 = icmp ugt i32 %12, 9, !dbg !47
+  br i1 %13, label %9, label %6, !dbg !48, !llvm.loop !55
+
+14:                                               ; preds = %14, %6
+  %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
+  call void @llvm.dbg.value(metadata i32 %15, metadata !42, metadata !DIExpression()), !dbg !43
+  %16 = udiv i32 %0, %15, !dbg !57
+  %17 = urem i32 %16, 10, !dbg !59
+  %18 = trunc i32 %17 to i8, !dbg !60
+  %19 = or i8 %18, 48, !dbg !60
+  call void @putchar(i8 noundef zeroext %19) #4, !dbg !61
+  %20 = udiv i32 %15, 10, !dbg !62
+  call void @llvm.dbg.value(metadata i32 %20, metadata !42, metadata !DIExpression()), !dbg !43
+  %21 = icmp ult i32 %15, 10, !dbg !52
+  br i1 %21, label %22, label %14, !dbg !52, !llvm.loop !63
+
+22:                                               ; preds = %14, %6, %5
+  ret void, !dbg !65
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #3
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #3
+
+; Function Attrs: nounwind uwtable
+define dso_local i32 @main() local_unnamed_addr #0 !dbg !66 {
+  %1 = alloca i32, align 4
+  %2 = bitcast i32* %1 to i8*, !dbg !74
+  call void @llvm.lifetime.start.p0i8(i64 4, i8* nonnull %2), !dbg !74
+  call void @llvm.dbg.declare(metadata i32* %1, metadata !72, metadata !DIExpression()), !dbg !75
+  store volatile i32 300, i32* %1, align 4, !dbg !75, !tbaa !76
+  %3 = load volatile i32, i32* %1, align 4, !dbg !78, !tbaa !76
+  %4 = trunc i32 %3 to i8, !dbg !78
+  call void @putchar(i8 noundef zeroext %4) #4, !dbg !79
+  %5 = bitcast i32* %1 to i8*, !dbg !80
+  call void @llvm.lifetime.end.p0i8(i64 4, i8* nonnull %5), !dbg !80
+  ret i32 0, !dbg !80
+}
+
+; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
+declare void @llvm.dbg.value(metadata, metadata, metadata) #1
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { nofree nosync nounwind readnone speculatable willreturn }
+attributes #2 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #3 = { argmemonly nofree nosync nounwind willreturn }
+attributes #4 = { nounwind }
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!2, !3, !4, !5, !6, !7}
+!llvm.ident = !{!8}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "Debian clang version 14.0.6", isOptimized: true, runtimeVersion: 0, emissionKind: FullDebug, splitDebugInlining: false, nameTableKind: None)
+!1 = !DIFile(filename: "tests/cases/int_300_located.c", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "0e92319475bde9cae10fddd7f43e291f")
+!2 = !{i32 7, !"Dwarf Version", i32 5}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = !{i32 1, !"wchar_size", i32 4}
+!5 = !{i32 7, !"PIC Level", i32 2}
+!6 = !{i32 7, !"PIE Level", i32 2}
+!7 = !{i32 7, !"uwtable", i32 1}
+!8 = !{!"Debian clang version 14.0.6"}
+!9 = distinct !DISubprogram(name: "putstr", scope: !10, file: !10, line: 31, type: !11, scopeLine: 31, flags: DIFlagPrototyped | DIFlagAllCallsDescribed, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !15)
+!10 = !DIFile(filename: "./stdfuck.h", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "b67b4bb6984323629ac25b8a7f940293")
+!11 = !DISubroutineType(types: !12)
+!12 = !{null, !13}
+!13 = !DIDerivedType(tag: DW_TAG_pointer_type, baseType: !14, size: 64)
+!14 = !DIBasicType(name: "char", size: 8, encoding: DW_ATE_signed_char)
+!15 = !{!16}
+!16 = !DILocalVariable(name: "s", arg: 1, scope: !9, file: !10, line: 31, type: !13)
+!17 = !DILocation(line: 0, scope: !9)
+!18 = !DILocation(line: 32, column: 8, scope: !9)
+!19 = !{!20, !20, i64 0}
+!20 = !{!"omnipotent char", !21, i64 0}
+!21 = !{!"Simple C/C++ TBAA"}
+!22 = !DILocation(line: 32, column: 2, scope: !9)
+!23 = !DILocation(line: 33, column: 3, scope: !24)
+!24 = distinct !DILexicalBlock(scope: !9, file: !10, line: 32, column: 12)
+!25 = !DILocation(line: 34, column: 4, scope: !24)
+!26 = distinct !{!26, !22, !27, !28, !29}
+!27 = !DILocation(line: 35, column: 2, scope: !9)
+!28 = !{!"llvm.loop.mustprogress"}
+!29 = !{!"llvm.loop.unroll.disable"}
+!30 = !DILocation(line: 36, column: 1, scope: !9)
+!31 = !DISubprogram(name: "putchar", scope: !10, file: !10, line: 5, type: !32, flags: DIFlagPrototyped, spFlags: DISPFlagOptimized, retainedNodes: !35)
+!32 = !DISubroutineType(types: !33)
+!33 = !{null, !34}
+!34 = !DIBasicType(name: "unsigned char", size: 8, encoding: DW_ATE_unsigned_char)
+!35 = !{}
+!36 = distinct !DISubprogram(name: "putdec", scope: !10, file: !10, line: 39, type: !37, scopeLine: 39, flags: DIFlagPrototyped | DIFlagAllCallsDescribed, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !40)
+!37 = !DISubroutineType(types: !38)
+!38 = !{null, !39}
+!39 = !DIBasicType(name: "unsigned int", size: 32, encoding: DW_ATE_unsigned)
+!40 = !{!41, !42}
+!41 = !DILocalVariable(name: "n", arg: 1, scope: !36, file: !10, line: 39, type: !39)
+!42 = !DILocalVariable(name: "digs", scope: !36, file: !10, line: 45, type: !39)
+!43 = !DILocation(line: 0, scope: !36)
+!44 = !DILocation(line: 40, column: 8, scope: !45)
+!45 = distinct !DILexicalBlock(scope: !36, file: !10, line: 40, column: 7)
+!46 = !DILocation(line: 40, column: 7, scope: !36)
+!47 = !DILocation(line: 46, column: 18, scope: !36)
+!48 = !DILocation(line: 46, column: 3, scope: !36)
+!49 = !DILocation(line: 41, column: 5, scope: !50)
+!50 = distinct !DILexicalBlock(scope: !45, file: !10, line: 40, column: 11)
+!51 = !DILocation(line: 42, column: 5, scope: !50)
+!52 = !DILocation(line: 48, column: 3, scope: !36)
+!53 = !DILocation(line: 46, column: 30, scope: !36)
+!54 = !DILocation(line: 46, column: 11, scope: !36)
+!55 = distinct !{!55, !48, !56, !28, !29}
+!56 = !DILocation(line: 46, column: 33, scope: !36)
+!57 = !DILocation(line: 49, column: 23, scope: !58)
+!58 = distinct !DILexicalBlock(scope: !36, file: !10, line: 48, column: 15)
+!59 = !DILocation(line: 49, column: 30, scope: !58)
+!60 = !DILocation(line: 49, column: 13, scope: !58)
+!61 = !DILocation(line: 49, column: 5, scope: !58)
+!62 = !DILocation(line: 50, column: 10, scope: !58)
+!63 = distinct !{!63, !52, !64, !28, !29}
+!64 = !DILocation(line: 51, column: 3, scope: !36)
+!65 = !DILocation(line: 52, column: 1, scope: !36)
+!66 = distinct !DISubprogram(name: "main", scope: !67, file: !67, line: 4, type: !68, scopeLine: 4, flags: DIFlagAllCallsDescribed, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !71)
+!67 = !DIFile(filename: "./tests/cases/int_300_located.c", directory: "/root/crate", checksumkind: CSK_MD5, checksum: "0e92319475bde9cae10fddd7f43e291f")
+!68 = !DISubroutineType(types: !69)
+!69 = !{!70}
+!70 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!71 = !{!72}
+!72 = !DILocalVariable(name: "x", scope: !66, file: !67, line: 7, type: !73)
+!73 = !DIDerivedType(tag: DW_TAG_volatile_type, baseType: !70)
+!74 = !DILocation(line: 7, column: 3, scope: !66)
+!75 = !DILocation(line: 7, column: 16, scope: !66)
+!76 = !{!77, !77, i64 0}
+!77 = !{!"int", !20, i64 0}
+!78 = !DILocation(line: 8, column: 11, scope: !66)
+!79 = !DILocation(line: 8, column: 3, scope: !66)
+!80 = !DILocation(line: 9, column: 1, scope: !66)
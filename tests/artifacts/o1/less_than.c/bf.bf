@@ -1,283 +1,177 @@
-r7 >>>>>>>
-+++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3178 ops ~63539 steps
+frame widths:
+  main: 27 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>#main
+>#main
 +
 >#main/b0
 +
-<<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>>#B:%ret_lading_pad
+        >>#B:%ret_lading_pad
         [
-            <<<<<<-
+            <<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/main
+            >>-
+            l27 <<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%3
+        #B:%4
+        #B:%7
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%5__%4_}(mult)
+        #%5_=_add_i32_%2__i32_1(mult)
+        #%6_=_icmp_eq_i32_%5__i32_30(mult)
+        #%8_=_phi_i32_{_i32_0__%1_}__{_i32_%11__%7_}(mult)
+        #%10_=_select_i1_%9__i8_108__i8_114(mult)
+        #%11_=_add_i32_%8__i32_1(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/18 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
+            call @putchar(i8 108_ i8 114_ i8 108_ i8 114_ i8 108_ i8 108_ i8 108_ i8 108_ i8 10)
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov17/19 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup16/17/18 <<[->+>+<<]
-            mov17/16 >[-<+>]
-            <<<<<<[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
+            putchar run intrinsic
+            >>>>>>>>>>>>>+++++++++
+            [
                 -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
+                <++++++++++++
             >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup11/19/20 <[->>>>>>>>+>+<<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov20/12 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup12/17/18 <[->>>>>+>+<<<<<<]
-            mov17/12 >>>>>[-<<<<<+>>>>>]
-            mov18/13 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup13/17/18 >>>>>[->>>>+>+<<<<<]
-            mov17/13 >>>>[-<<<<+>>>>]
-            >.
+            <.
+            ++++++
+            .
+            ------
+            .
+            ++++++
+            .
+            ------
+            .
+            .
+            .
+            .
+            --------------------------------------------------------------------------------------------------
+            .
             [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
+            br label %1
+            
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            <<<<<<[-]
+            mov14/8 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<+
+        <<]
+        >>#B:%1
         [
             -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup11/17/18 <<<[->>>>>>+>+<<<<<<<]
-            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov18/14 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup14/17/18 <[->>>+>+<<<<]
-            mov17/14 >>>[-<<<+>>>]
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            br label %7
+            
+            doing phi stuff
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %11_ %7 }
             op_to_reg storing const value in temp address
-            >>#constop_0
+            >>>>>>>#constop_0
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/22 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
+            [-]
+            mov16/11 >>>>>[-<<<<<+>>>>>]
             <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup14/17/18 <<<<<<[->>>+>+<<<<]
-            mov17/14 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
+        <<<]
+        >#B:%3
         [
             -
             ret void
+            
             zero all function allocs
+            >>>[-]
             >[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            <<<<<<<<<<<<<<<-
+            <<<<<<<<<<<<<-
             #dead_frame
             >-
             l1 <
             <[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>-
-            l46 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
+            r1 
+            l5 
+        >]
+        >#B:%4
         [
             -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>>[-]
+            %5 = add i32 %2_ i32 1
+            
+            >>>[-]
+            dup8/15/17 <[->>>>>>>+>>+<<<<<<<<<]
+            mov15/8 >>>>>>>[-<<<<<<<+>>>>>>>]
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
+            >>>#constop_1
+            +
+            mov17/9 <[-<<<<<<<<+>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/20/22 <<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov22/9 >>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov19/20 [->+<]
+                    <<<<<<<<<<+
+                    >>>>>>>>>>>>-
+                <<]
+                mov20/19 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<[-]
+                    >>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %6 = icmp eq i32 %5_ i32 30
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/34 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
+            <<<<<<<<<[-]
+            dup9/14/15 <[->>>>>+>+<<<<<<]
+            mov14/9 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_30
+            >+++++
+            [
                 -
-                >>+
+                <++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/19 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
                 >[
                     -
                     <-
@@ -288,48 +182,62 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<+
+            >>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
             <[
                 [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
+                <<<<<<<<[-]
+            >>>>>>>>]
+            br i1 %6_ label %3_ label %1
+            
+            dup10/15/14 <<<<<<<<[->>>>>+<+<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            dup9/16/17 <<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov16/9 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov17/18 >[->+<]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>[
+                -
+                <<<<<<<<<<<-
                 >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
+                #B:main/%3_true
+                >>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<[
                 -
                 >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
+                #B:main/%1_false
+                >>>>[-]
+                mov18/8 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<<<<<<<<<<<<]
+        >>>]
+        >#B:%7
         [
             -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov31/33 <<<<[->>+<<]
-            >>>>+
-            <<<[
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %11_ %7 }
+            %9 = icmp ult i32 %2_ i32 %8
+            
+            >>>[-]
+            dup8/19/20 <<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov19/8 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            dup11/21/23 <<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            mov21/11 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/24 [->+<]
+            >>>+
+            <<<<<<[
                 -
                 
-                >[
+                >>>>[
                     -
                     
                     r1 >
@@ -339,84 +247,110 @@ runtime init:
                     l1 <
                 ]
                 l1 <
-            <]
-            >>>-
+            <<<<]
+            >>>>>>-
             <<[
                 [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
+                <<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>]
+            %10 = select i1 %9_ i8 108_ i8 114
+            
+            <<<<<<<<<<<<[-]
+            dup10/14/15 <<[->>>>+>+<<<<<]
+            mov14/10 >>>>[-<<<<+>>>>]
             op_to_reg storing const value in temp address
-            <#constop_1
-            +
-            <<<<<<<<<<<<<[-]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
+            >>#constop_108
+            >+++++++++
+            [
+                -
+                <++++++++++++
+            >]
             op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            <<<<<<<<<<<<<<<<[-]
-            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
+            >#constop_114
+            >++++++++
+            [
                 -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
+                <++++++++++++++
+            >]
+            <++
+            >>+
+            <<<<<[
+                mov16/12 >[-<<<<+>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
                 -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+                mov18/12 <<[-<<<<<<+>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %10)
+            
             putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            dup12/14/15 <<<<<<<<[->>+>+<<<]
+            mov14/12 >>[-<<+>>]
+            >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup17/32/33 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %11 = add i32 %8_ i32 1
+            
+            <<[-]
+            dup11/14/15 <<[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
-            >>#constop_0
+            >>#constop_1
+            +
+            mov15/13 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/18/20 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov20/13 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov17/18 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov18/17 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %12 = icmp eq i32 %11_ i32 30
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/37 <<<<<[->>>+<<<]
+            <<<<<<<[-]
+            dup13/14/15 >>>[->+>+<<]
+            mov14/13 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_30
+            >+++++
+            [
+                -
+                <++++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov16/19 <[->>>+<<<]
             >>>>>+
             <<<<<<[
                 -
-                >>+
-                >>[
+                >>>+
+                >[
                     -
-                    <<-
+                    <-
                     r1 >
-                >>]
+                >]
                 r1 >
                 [
                     l1 <
@@ -424,1149 +358,39 @@ runtime init:
                 l1 <
             <<<<]
             >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
+            <<<<<<<<<<<+
+            >>>>>>>>>[
                 [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            <[
                 [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
+                <<<<<<<<[-]
+            >>>>>>>>]
+            br i1 %12_ label %4_ label %7
+            
+            dup10/15/14 <<<<<<<<[->>>>>+<+<<<<]
+            mov15/10 >>>>>[-<<<<<+>>>>>]
             doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup17/32/33 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov33/23 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %11_ %7 }
+            dup13/16/17 <<[->>>+>+<<<<]
+            mov16/13 >>>[-<<<+>>>]
+            mov17/18 >[->+<]
+            <<<<<<<<<<<<<<+
+            >>>>>>>>>>>[
+                -
+                <<<<<<<<<<<-
+                >>>+
+                #B:main/%4_true
+                >>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<[
                 -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
+                >>>>+
+                #B:main/%7_false
+                >>>>[-]
+                mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<<<<<<]
         >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup19/34/35 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup35/20/37 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov37/35 >>[-<<+>>]
-            <]
-            <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup20/32/33 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup33/35/36 >[->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov31/40 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
-                    -
-                    <+
-                    >>>>>>[
-                        -
-                        <<<<<<-
-                        r1 >
-                    >>>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov40/31 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup33/35/36 <<<<<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup21/32/33 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov32/21 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup20/32/33 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup20/34/35 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov34/20 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov35/17 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
-        [
-            -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup23/36/37 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov36/23 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup37/39/40 >[->>+>+<<<]
-            mov40/37 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov31/43 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
-                    -
-                    <+
-                    >>>>>[
-                        -
-                        <<<<<-
-                        r1 >
-                    >>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov43/31 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup37/39/40 <<<<<<[->>+>+<<<]
-                mov40/37 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
-            >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup24/32/33 <[->>>>>>>>+>+<<<<<<<<<]
-            mov32/24 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup34/36/37 [->>+>+<<<]
-            mov37/34 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/38 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov38/33 <<[-<<<<<+>>>>>]
-                dup34/36/37 <<<<[->>+>+<<<]
-                mov37/34 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
-            [
-                mov36/25 >[-<<<<<<<<<<<+>>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
-            ]
-            mov35/25 [-<<<<<<<<<<+>>>>>>>>>>]
-            <[-]
-            >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup25/32/33 <[->>>>>>>+>+<<<<<<<<]
-            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov33/26 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup26/32/33 <[->>>>>>+>+<<<<<<<]
-            mov32/26 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov33/27 <[-<<<<<<+>>>>>>]
-            >[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            dup27/32/33 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov32/27 >>>>>[-<<<<<+>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup23/32/33 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup34/36/37 [->>+>+<<<]
-            mov37/34 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/38 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
-                    [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov38/33 >>>[-<<<<<+>>>>>]
-                dup34/36/37 <<<<[->>+>+<<<]
-                mov37/34 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup23/32/33 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov33/29 <[-<<<<+>>>>]
-            >[
-                -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup29/32/33 <[->>>+>+<<<<]
-            mov32/29 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/35 <<<[->+<]
-            >>>+
-            <<<<[
-                -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov28/23 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<<<<<]
-    >#lt
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/lt
-            >>>>>>-
-            l18 <<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_lt
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%no_block0_call_for_lt
-        >#B:%ret_lading_pad
-        >#B:%2
-        >#B:%call_term_for_0
-        >#%3_=_icmp_slt_i32_%0__i32_%1(mult)
-        >#%4_=_select_i1_%3__i8_108__i8_114(mult)
-        >#%0
-        >#%1
-        <<<<<<<#B:%no_block0_call_for_lt
-        [
-            -
-            copy up args
-            >>>>>>#arg_%0
-            l2 <<
-            mov0/13 <<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
-            r2 >>
-            >>>>>>>>>>>>#arg_%1
-            l3 <<<
-            mov0/15 <<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
-            r3 >>>
-            br label %2
-            >>>>>>>+
-        <<]
-        >>#B:%2
-        [
-            -
-            %3 = icmp slt i32 %0_ i32 %1
-            >>[-]
-            >>>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov12/13 <<<[->+<]
-            >>>+
-            <<<<[
-                -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<+
-            >>>>]
-            %4 = select i1 %3_ i8 108_ i8 114
-            <<<[-]
-            dup9/13/14 <[->>>>+>+<<<<<]
-            mov13/9 >>>>[-<<<<+>>>>]
-            op_to_reg storing const value in temp address
-            >>>#constop_108
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            op_to_reg storing const value in temp address
-            <#constop_114
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >>+
-            <<<[
-                mov16/10 >>[-<<<<<<+>>>>>>]
-                <[-]
-                <[-]
-                >>>[-]
-            <<<]
-            >>>[
-                -
-                mov15/10 <<[-<<<<<+>>>>>]
-                >[-]
-                <<[-]
-            >>>]
-            call @putchar(i8 %4)
-            enable next block when we return
-            <<<<<<<<<#caller/%call_term_for_0
-            +
-            putchar intrinsic
-            dup10/13/14 >>[->>>+>+<<<<]
-            mov13/10 >>>[-<<<+>>>]
-            >.
-            [-]
-        <<<<<<<]
-        >#B:%call_term_for_0
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            <<<<<<<<<<-
-            #dead_frame
-            >>>-
-            l1 <
-            <<<[-]
-            r1 >
-            l10 <<<<<<<<<<
-        >>>>>>>>]
-    <<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/main
-            >>>>>>-
-            l36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:lt
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%call_term_for_6
-        >#B:%call_term_for_7
-        >#B:%call_term_for_8
-        >#B:%1
-        >#B:%3
-        >#B:%4
-        >#B:%7
-        >#B:%call_term_for_13
-        >#%2_=_phi_i32_{_i32_0__%call_term_for_8_}__{_i32_%5__%4_}(mult)
-        >#%5_=_add_i32_%2__i32_1(mult)
-        >#%6_=_icmp_eq_i32_%5__i32_30(mult)
-        >#%8_=_phi_i32_{_i32_0__%1_}__{_i32_%9__%call_term_for_13_}(mult)
-        >#%9_=_add_i32_%8__i32_1(mult)
-        >#%10_=_icmp_eq_i32_%9__i32_30(mult)
-        <<<<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_main
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            call @lt(i32 1_ i32 2)
-            enable next block when we return
-            >#caller/%call_term_for_0
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_1
-            +
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_2
-            ++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>]
-        >#B:%call_term_for_0
-        [
-            -
-            call @lt(i32 2_ i32 1)
-            enable next block when we return
-            >#caller/%call_term_for_1
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_2
-            ++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<#constop_1
-            +
-            mov30/43 [->>>>>>>>>>>>>+<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>]
-        >#B:%call_term_for_1
-        [
-            -
-            call @lt(i32 3_ i32 4)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_3
-            +++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_4
-            ++++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>]
-        >#B:%call_term_for_2
-        [
-            -
-            call @lt(i32 3_ i32 3)
-            enable next block when we return
-            >#caller/%call_term_for_3
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_3
-            +++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_3
-            +++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>>]
-        >#B:%call_term_for_3
-        [
-            -
-            call @lt(i32 4_ i32 5)
-            enable next block when we return
-            >#caller/%call_term_for_4
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_4
-            ++++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_5
-            +++++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>>>]
-        >#B:%call_term_for_4
-        [
-            -
-            call @lt(i32 5_ i32 6)
-            enable next block when we return
-            >#caller/%call_term_for_5
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_5
-            +++++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_6
-            ++++++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>>>>]
-        >#B:%call_term_for_5
-        [
-            -
-            call @lt(i32 6_ i32 7)
-            enable next block when we return
-            >#caller/%call_term_for_6
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_6
-            ++++++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_7
-            +++++++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>>>>>]
-        >#B:%call_term_for_6
-        [
-            -
-            call @lt(i32 7_ i32 8)
-            enable next block when we return
-            >#caller/%call_term_for_7
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<<<#constop_7
-            +++++++
-            mov28/44 [->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>>>>#arg_1
-            op_to_reg storing const value in temp address
-            <<<<<<<<<<<<<<#constop_8
-            ++++++++
-            mov29/43 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>>>>>>]
-        >#B:%call_term_for_7
-        [
-            -
-            call @putchar(i8 10)
-            enable next block when we return
-            >#caller/%call_term_for_8
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>#constop_10
-            ++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<]
-        >#B:%call_term_for_8
-        [
-            -
-            br label %1
-            doing phi stuff
-            %2 = phi i32 _ i32 0_ %call_term_for_8 __ _ i32 %5_ %4 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>#constop_0
-            
-            <<<<<<[-]
-            mov28/22 >>>>>>[-<<<<<<+>>>>>>]
-            <<<<<<<<<<<+
-        <]
-        >#B:%1
-        [
-            -
-            %2 = phi i32 _ i32 0_ %call_term_for_8 __ _ i32 %5_ %4 _
-            br label %7
-            doing phi stuff
-            %8 = phi i32 _ i32 0_ %1 __ _ i32 %9_ %call_term_for_13 _
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>#constop_0
-            
-            <<<<[-]
-            mov29/25 >>>>[-<<<<+>>>>]
-            <<<<<<<<<+
-        <<<]
-        >#B:%3
-        [
-            -
-            ret void
-            zero all function allocs
-            >>>>[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>>-
-            l1 <
-            <<<<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>>>>]
-        >#B:%4
-        [
-            -
-            %5 = add i32 %2_ i32 1
-            >>>>[-]
-            dup22/31/30 <[->>>>>>>>>+<+<<<<<<<<]
-            mov31/22 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            mov30/23 <<[-<<<<<<<+>>>>>>>]
-            >>[
-                -
-                <<<<<<<<<+
-            >>>>>>>>>]
-            %6 = icmp eq i32 %5_ i32 30
-            <<<<<<<<[-]
-            dup23/28/29 <[->>>>>+>+<<<<<<]
-            mov28/23 >>>>>[-<<<<<+>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_30
-            ++++++++++++++++++++++++++++++
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov30/33 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            br i1 %6_ label %3_ label %1
-            doing phi stuff
-            %2 = phi i32 _ i32 0_ %call_term_for_8 __ _ i32 %5_ %4 _
-            dup23/28/29 <<<<<<<<[->>>>>+>+<<<<<<]
-            mov28/23 >>>>>[-<<<<<+>>>>>]
-            <<<<<<[-]
-            mov29/22 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>+
-                #B:main/3_true
-            >>>>>>]
-            <<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>+
-                #B:main/1_false
-            <<<<<<<<<<<]
-        >>>>>>>>>>>>>]
-        >#B:%7
-        [
-            -
-            %8 = phi i32 _ i32 0_ %1 __ _ i32 %9_ %call_term_for_13 _
-            call @lt(i32 %2_ i32 %8)
-            enable next block when we return
-            >#caller/%call_term_for_13
-            +
-            stack_width 36
-            ret_pad_width 6
-            copy up arg 0
-            >>>>>>>>>>>>>>>>>>>>>>>#arg_0
-            dup22/30/31 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>+>+<<<<<<<<<]
-            mov30/22 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov31/44 >[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
-            copy up arg 1
-            >>>>>>>>>>>>#arg_1
-            dup25/32/33 <<<<<<<<<<<<<<<<<<[->>>>>>>+>+<<<<<<<<]
-            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov33/43 >[->>>>>>>>>>+<<<<<<<<<<]
-            give callee a stack pointer
-            >>>>>>>>>>>>#stack_ptr
-            +++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/46/47 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov47/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r36 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r10 >>>>>>>>>>
-            <<<<<<#===FRAME_lt
-            +
-            >>>#lt
-            +
-            >>#lt/b0
-            +
-        >>>>>>>>>>>>>>>]
-        >#B:%call_term_for_13
-        [
-            -
-            %9 = add i32 %8_ i32 1
-            >>>>>[-]
-            dup25/28/29 <[->>>+>+<<<<]
-            mov28/25 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov29/26 <[-<<<+>>>]
-            >[
-                -
-                <<<<+
-            >>>>]
-            %10 = icmp eq i32 %9_ i32 30
-            <<<[-]
-            dup26/28/29 <[->>+>+<<<]
-            mov28/26 >>[-<<+>>]
-            op_to_reg storing const value in temp address
-            >>#constop_30
-            ++++++++++++++++++++++++++++++
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov30/32 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<+
-            >>>>>[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            <[
-                [-]
-                <<<<[-]
-            >>>>]
-            br i1 %10_ label %4_ label %7
-            doing phi stuff
-            %8 = phi i32 _ i32 0_ %1 __ _ i32 %9_ %call_term_for_13 _
-            dup26/28/29 <<<<<[->>+>+<<<]
-            mov28/26 >>[-<<+>>]
-            <<<[-]
-            mov29/25 >>>>[-<<<<+>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>+
-                #B:main/4_true
-            >>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>>>>>>>>>+
-                #B:main/7_false
-            <<<<<<<<<<<<<<]
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<<<<<<<]
-<<<<]
+    <<<<<<]
+<]
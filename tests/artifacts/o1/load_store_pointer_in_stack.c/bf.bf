@@ -1,933 +1,102 @@
-r7 >>>>>>>
-+++++++
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 3717 ops ~132181 steps
+frame widths:
+  main: 33 cells
+r4 >>>>
+++++
 r1 >
 runtime init:
 #===TOP_FRAME
 +
->>>>#main
+>#main
 +
 >#main/b0
 +
-<<<<<[
-    >#putstr
+<<[
+    >#main
     [
-        >>>>>#B:%ret_lading_pad
+        >>#B:%ret_lading_pad
         [
-            <<<<<<-
+            <<<-
             #dead_frame
             >-
-            <#dead_fn_pad/putstr
-            >>>>>>-
-            l25 <<<<<<<<<<<<<<<<<<<<<<<<<
+            #dead_fn_pad/main
+            >>-
+            l33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
         ]
-        <<<<<<#mainloop_putstr
-        >#F:putstr
-        >#F:putdec
-        >#F:another_stack
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%call_term_for_1
-        >#B:%9
-        >#%2_=_icmp_eq_i8*_%0__i8*_null(mult)
-        >#%4_=_phi_i8*_{_i8*_%7__%call_term_for_1_}__{_i8*_%0__%1_}(mult)
-        >#%5_=_ptrtoint_i8*_%4_to_i64(mult)
-        >#%6_=_trunc_i64_%5_to_i8(mult)
-        >#%7_=_getelementptr_i8*_%4__i64_1(mult)
-        >#%8_=_icmp_eq_i8*_%7__i8*_null(mult)
-        >#%0
-        <<<<<<<<<<<#B:%1
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%4_=_ptrtoint_i32*_%2_to_i64(mult)
+        #%5_=_and_i64_%4__i64_252(mult)
+        #%7_=_select_i1_%6__i8_65__i8_66(mult)
+        <#B:%0
         [
             -
             copy up args
-            >>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/18 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i8* %0_ i8* null
-            >>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>#constop_0
+            %1 = alloca i32_ align 4
+            %2 = alloca i32_ align 4
+            store i32 112_ i32* %2_ align 4
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov17/19 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
-                [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            br i1 %2_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup16/17/18 <<[->+>+<<]
-            mov17/16 >[-<+>]
-            <<<<<<[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>[
-                -
-                <<<<-
-                >>>+
-                #B:putstr/9_true
-            >]
-            <<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            %5 = ptrtoint i8* %4 to i64
-            >>>>>[-]
-            dup11/19/20 <[->>>>>>>>+>+<<<<<<<<<]
-            mov19/11 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov20/12 >[-<<<<<<<<+>>>>>>>>]
-            %6 = trunc i64 %5 to i8
-            <<<<<<<[-]
-            dup12/17/18 <[->>>>>+>+<<<<<<]
-            mov17/12 >>>>>[-<<<<<+>>>>>]
-            mov18/13 >[-<<<<<+>>>>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<#caller/%call_term_for_1
-            +
-            putchar intrinsic
-            dup13/17/18 >>>>>[->>>>+>+<<<<<]
-            mov17/13 >>>>[-<<<<+>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
-            %7 = getelementptr i8* %4_ i64 1
-            >>>>>>[-]
-            dup11/17/18 <<<[->>>>>>+>+<<<<<<<]
-            mov17/11 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_1
-            +
-            mov18/14 <[-<<<<+>>>>]
-            >[
+            >>>[-]
+            >>>>++++++++
+            [
                 -
-                <<<<<+
-            >>>>>]
-            %8 = icmp eq i8* %7_ i8* null
-            <<<<[-]
-            dup14/17/18 <[->>>+>+<<<<]
-            mov17/14 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+                <<<<++++++++++++++
+            >>>>]
+            %4 = ptrtoint i32* %2 to i64
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov19/22 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<+
-            >>>>>>>[
-                [-]
-                <<<<<<<[-]
-            >>>>>>>]
-            <<[
-                [-]
-                <<<<<[-]
-            >>>>>]
-            br i1 %8_ label %9_ label %3
-            doing phi stuff
-            %4 = phi i8* _ i8* %7_ %call_term_for_1 __ _ i8* %0_ %1 _
-            dup14/17/18 <<<<<<[->>>+>+<<<<]
-            mov17/14 >>>[-<<<+>>>]
-            <<<<<<[-]
-            mov18/11 >>>>>>>[-<<<<<<<+>>>>>>>]
-            <<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>>+
-                #B:putstr/9_true
-            >>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putstr/3_false
-            <]
-        >>]
-        >#B:%9
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<-
-            #dead_frame
-            >-
+            <<<[-]
+            resolve alloca addr storing pointer value in temp address
             l1 <
-            <[-]
+            dup0/11/10 <<<<<<[->>>>>>>>>>>+<+<<<<<<<<<<]
+            mov10/0 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
             r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>]
-    <<<<<<<<]
-    >#putdec
-    [
-        >>>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>-
-            <<#dead_fn_pad/putdec
-            >>>>>>-
-            l46 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_putdec
-        >#F:putstr
-        >#F:putdec
-        >#F:another_stack
-        >#F:main
-        >#B:%1
-        >#B:%ret_lading_pad
-        >#B:%3
-        >#B:%5
-        >#B:%call_term_for_2
-        >#B:%6
-        >#B:%9
-        >#B:%14
-        >#B:%call_term_for_6
-        >#B:%23
-        >#%2_=_icmp_eq_i32_%0__i32_0(mult)
-        >#%4_=_icmp_sgt_i32_%0__i32_10(mult)
-        >#%7_=_phi_i32_{_i32_1__%3_}__{_i32_%11__%9_}(mult)
-        >#%8_=_icmp_eq_i32_%7__i32_0(mult)
-        >#%10_=_phi_i32_{_i32_%11__%9_}__{_i32_1__%3_}(mult)
-        >#%11_=_mul_i32_%10__i32_10(mult)
-        >#%12_=_sdiv_i32_%0__i32_%11(mult)
-        >#%13_=_icmp_sgt_i32_%12__i32_10(mult)
-        >#%15_=_phi_i32_{_i32_%20__%call_term_for_6_}__{_i32_%7__%6_}(mult)
-        >#%16_=_sdiv_i32_%0__i32_%15(mult)
-        >#%17_=_srem_i32_%16__i32_10(mult)
-        >#%18_=_trunc_i32_%17_to_i8(mult)
-        >#%19_=_add_i8_%18__i8_48(mult)
-        >#%20_=_sdiv_i32_%15__i32_10(mult)
-        >#%21_=_add_i32_%15__i32_9(mult)
-        >#%22_=_icmp_ult_i32_%21__i32_19(mult)
-        >#%0
-        <<<<<<<<<<<<<<<<<<<<<<<<<<#B:%1
-        [
-            -
-            copy up args
-            >>>>>>>>>>>>>>>>>>>>>>>>>>#arg_%0
-            l2 <<
-            mov0/33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            r2 >>
-            %2 = icmp eq i32 %0_ i32 0
-            >>>>>>>>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_0
-            
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov32/34 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <[
-                [-]
-                <<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>]
-            br i1 %2_ label %5_ label %3
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>[
-                -
-                <<<<<<<<<-
-                >>+
-                #B:putdec/5_true
-            >>>>>>>]
-            <<<<<<<<<[
-                -
-                >+
-                #B:putdec/3_false
-            <]
-        <]
-        >>#B:%3
-        [
-            -
-            %4 = icmp sgt i32 %0_ i32 10
-            >>>>>>>>>[-]
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov31/33 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>]
-            br i1 %4_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            op_to_reg storing const value in temp address
-            <#constop_1
-            +
-            <<<<<<<<<<<<<[-]
-            mov32/19 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            op_to_reg storing const value in temp address
-            >#constop_1
-            +
-            <<<<<<<<<<<<<<<<[-]
-            mov33/17 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>[
-                -
-                <<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>]
-            <<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >]
-        >#B:%5
-        [
-            -
-            call @putchar(i8 48)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
-            putchar intrinsic
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>>>>>>>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            .
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            br label %23
-            >>>>>+
-        <<<<<]
-        >#B:%6
-        [
-            -
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            %8 = icmp eq i32 %7_ i32 0
-            >>>>>>>>[-]
-            dup17/32/33 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            ++++++
+            mov10/6 [-<<<<+>>>>]
+            %5 = and i64 %4_ i64 252
             
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/37 <<<<<[->>>+<<<]
-            >>>>>+
-            <<<<<<[
-                -
-                >>+
-                >>[
-                    -
-                    <<-
-                    r1 >
-                >>]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<<]
-            >>>>>>-
-            <<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>>>]
-            <<[
-                [-]
-                <<<<<<<<<<<<<<<<<[-]
-            >>>>>>>>>>>>>>>>>]
-            br i1 %8_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            dup17/32/33 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov32/17 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            <<<<<<<<<[-]
-            mov33/23 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>]
-            <<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>]
-        >#B:%9
-        [
-            -
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            %11 = mul i32 %10_ i32 10
-            >>>>>>>>>[-]
-            dup19/34/35 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
-            mov34/19 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            [
-                -
-                dup35/20/37 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>+<<]
-                mov37/35 >>[-<<+>>]
-            <]
-            <[-]
-            %12 = sdiv i32 %0_ i32 %11
-            <<<<<<<<<<<<<<[-]
-            dup20/32/33 <[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            dup33/35/36 >[->>+>+<<<]
-            mov36/33 >>>[-<<<+>>>]
-            <<<<<[
-                >>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov31/40 <<<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
-                >>>>>>>>>>>+
-                <<<<<<<[
-                    -
-                    <+
-                    >>>>>>[
-                        -
-                        <<<<<<-
-                        r1 >
-                    >>>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<<]
-                >>>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<-
-                >>>>>>>>>>>>>]
-                mov40/31 >>>>>>[-<<<<<<<<<+>>>>>>>>>]
-                dup33/35/36 <<<<<<<[->>+>+<<<]
-                mov36/33 >>>[-<<<+>>>]
-            <<<<<]
-            >>[-]
-            >>[-]
-            %13 = icmp sgt i32 %12_ i32 10
-            <<<<<<<<<<<<<[-]
-            dup21/32/33 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
-            mov32/21 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov33/35 <<<<[->>+<<]
-            >>>>+
-            <<<[
-                -
-                
-                >[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <]
-            >>>-
-            <<[
-                [-]
-                <<<<<<<<<<<<<+
-            >>>>>>>>>>>>>]
-            br i1 %13_ label %9_ label %6
-            doing phi stuff
-            %10 = phi i32 _ i32 %11_ %9 __ _ i32 1_ %3 _
-            dup20/32/33 <<<<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
-            mov32/20 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-            <<<<<<<<<<<<<[-]
-            mov33/19 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            doing phi stuff
-            %7 = phi i32 _ i32 1_ %3 __ _ i32 %11_ %9 _
-            dup20/34/35 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
-            mov34/20 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<[-]
-            mov35/17 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<-
-                >>>>>+
-                #B:putdec/9_true
-            >>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<[
-                -
-                >>>>+
-                #B:putdec/6_false
-            <<<<]
-        >>>>>]
-        >#B:%14
-        [
-            -
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            %16 = sdiv i32 %0_ i32 %15
-            >>>>>>>>>>>>[-]
-            dup23/36/37 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
-            mov36/23 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
-            dup37/39/40 >[->>+>+<<<]
-            mov40/37 >>>[-<<<+>>>]
-            <<<<<<<<<[
-                >>>>>>>>>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov31/43 <<<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
-                >>>>>>>>>>>>>>+
-                <<<<<<[
-                    -
-                    <+
-                    >>>>>[
-                        -
-                        <<<<<-
-                        r1 >
-                    >>>>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<<<]
-                >>>>>>-
-                <<<<<<<<<<<<<<<<<<<<<+
-                >>>>>>>>>>>>>>[
-                    [-]
-                    <<<<<<<<<<<<<<-
-                >>>>>>>>>>>>>>]
-                mov43/31 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                dup37/39/40 <<<<<<[->>+>+<<<]
-                mov40/37 >>>[-<<<+>>>]
-            <<<<<<<<<]
-            >>>>>>[-]
-            >>[-]
-            %17 = srem i32 %16_ i32 10
-            <<<<<<<<<<<<<<[-]
-            dup24/32/33 <[->>>>>>>>+>+<<<<<<<<<]
-            mov32/24 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            <<<[-]
+            dup6/9/11 <[->>>+>>+<<<<<]
+            mov9/6 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup34/36/37 [->>+>+<<<]
-            mov37/34 >>>[-<<<+>>>]
-            <<<<#op0
-            >#op1
-            >#neg
-            <<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/38 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                mov38/33 <<[-<<<<<+>>>>>]
-                dup34/36/37 <<<<[->>+>+<<<]
-                mov37/34 >>>[-<<<+>>>]
-            <<<<]
-            >>#neg
+            >#constop_252
+            >>++++++++++++++
             [
-                mov36/25 >[-<<<<<<<<<<<+>>>>>>>>>>>]
-                <[
-                    -
-                    <<<<<<<<<<-
-                >>>>>>>>>>]
-            ]
-            mov35/25 [-<<<<<<<<<<+>>>>>>>>>>]
-            <[-]
-            >>[-]
-            %18 = trunc i32 %17 to i8
-            <<<<<<<<<<[-]
-            dup25/32/33 <[->>>>>>>+>+<<<<<<<<]
-            mov32/25 >>>>>>>[-<<<<<<<+>>>>>>>]
-            mov33/26 >[-<<<<<<<+>>>>>>>]
-            %19 = add i8 %18_ i8 48
-            <<<<<<[-]
-            dup26/32/33 <[->>>>>>+>+<<<<<<<]
-            mov32/26 >>>>>>[-<<<<<<+>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_48
-            ++++++++++++++++++++++++++++++++++++++++++++++++
-            mov33/27 <[-<<<<<<+>>>>>>]
-            >[
-                -
-                <<<<<<<+
-            >>>>>>>]
-            call @putchar(i8 %19)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_6
-            +
-            putchar intrinsic
-            dup27/32/33 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
-            mov32/27 >>>>>[-<<<<<+>>>>>]
-            >.
-            [-]
-        <<<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_6
-        [
-            -
-            %20 = sdiv i32 %15_ i32 10
-            >>>>>>>>>>>>>>>[-]
-            dup23/32/33 <<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_10
-            ++++++++++
-            dup34/36/37 [->>+>+<<<]
-            mov37/34 >>>[-<<<+>>>]
-            <<<<[
-                >>>>>#subnu_tmpb
-                >#subnu_tmp0
-                >#subnu_tmp1
-                mov33/38 <<<<<<<[->>>>>+<<<<<]
-                >>>>>>>+
-                <<<<[
-                    -
-                    <+
-                    >>>[
-                        -
-                        <<<-
-                        r1 >
-                    >>>]
-                    r1 >
-                    [
-                        l1 <
-                    ]
-                    l1 <
-                <<]
-                >>>>-
-                <<<<<<<<<<<<+
-                >>>>>>>[
-                    [-]
-                    <<<<<<<-
-                >>>>>>>]
-                mov38/33 >>>[-<<<<<+>>>>>]
-                dup34/36/37 <<<<[->>+>+<<<]
-                mov37/34 >>>[-<<<+>>>]
-            <<<<]
-            >[-]
-            >>[-]
-            %21 = add i32 %15_ i32 9
-            <<<<<<<[-]
-            dup23/32/33 <<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
-            mov32/23 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_9
-            +++++++++
-            mov33/29 <[-<<<<+>>>>]
-            >[
                 -
-                <<<<<+
-            >>>>>]
-            %22 = icmp ult i32 %21_ i32 19
-            <<<<[-]
-            dup29/32/33 <[->>>+>+<<<<]
-            mov32/29 >>>[-<<<+>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_19
-            +++++++++++++++++++
-            >#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov34/35 <<<[->+<]
-            >>>+
-            <<<<[
-                -
-                
-                >>[
-                    -
-                    
-                    r1 >
-                ]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<]
-            >>>>-
-            <<[
-                [-]
-                <<<<<+
-            >>>>>]
-            br i1 %22_ label %23_ label %14
-            doing phi stuff
-            %15 = phi i32 _ i32 %20_ %call_term_for_6 __ _ i32 %7_ %6 _
-            <<<<<<<<<<<<[-]
-            mov28/23 >>>>>[-<<<<<+>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<+
-            >>>>>>>>>>>>>>>>>>>>>>>>[
-                -
-                <<<<<<<<<<<<<<<<<<<<<<<<-
-                >>>>>>>>+
-                #B:putdec/23_true
-            >>>>>>>>>>>>>>>>]
-            <<<<<<<<<<<<<<<<<<<<<<<<[
-                -
-                >>>>>>+
-                #B:putdec/14_false
-            <<<<<<]
-        >>>>>>>]
-        >#B:%23
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>-
-            l1 <
-            <<[-]
-            r1 >
-            l9 <<<<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<<<<<]
-    >#another_stack
-    [
-        >>>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>-
-            <<<#dead_fn_pad/another_stack
-            >>>>>>-
-            l44 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_another_stack
-        >#F:putstr
-        >#F:putdec
-        >#F:another_stack
-        >#F:main
-        >#B:%no_block0_call_for_another_stack
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%call_term_for_6
-        >#alloc_%1
-        >#%2_=_bitcast_i32*_%1_to_i8*(mult)
-        >#%3_=_ptrtoint_i32*_%1_to_i64(mult)
-        >#%4_=_and_i64_%3__i64_252(mult)
-        >#%5_=_icmp_eq_i64_%4__i64_0(mult)
-        >#%6_=_select_i1_%5__i8_65__i8_66(mult)
-        >#%7_=_inttoptr_i64_%4_to_i32*(mult)
-        >#%8_=_icmp_eq_i32*_%1__i32*_%7(mult)
-        >#%9_=_select_i1_%8__i8_66__i8_65(mult)
-        >#%10_=_load_i32*_%1__align_4(mult)
-        >#%11_=_trunc_i32_%10_to_i8(mult)
-        <<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_another_stack
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            %1 = alloca i32_ align 4
-            %2 = bitcast i32* %1 to i8*
-            >>>>>>>>>[-]
-            resolve alloca addr storing pointer value in temp address
-            l1 <
-            dup0/27/28 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<++++++++++++++++
-            mov26/16 [-<<<<<<<<<<+>>>>>>>>>>]
-            call @llvm_lifetime_start_p0i8(i64 4_ i8* %2)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<#caller/%call_term_for_0
-            +
-            ignoring llvm intrinsic
-        <]
-        >#B:%call_term_for_0
-        [
-            -
-            store i32 112_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>#constop_112
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            <<<<<<<<<<<[-]
-            mov26/15 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            %3 = ptrtoint i32* %1 to i64
-            <<<<<<<<<[-]
-            resolve alloca addr storing pointer value in temp address
-            l1 <
-            dup0/27/29 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<<++++++++++++++++
-            mov26/17 [-<<<<<<<<<+>>>>>>>>>]
-            %4 = and i64 %3_ i64 252
-            <<<<<<<<[-]
-            dup17/26/27 <[->>>>>>>>>+>+<<<<<<<<<<]
-            mov26/17 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>>#constop_252
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >#dub_scratch
-            <<#loop_ctrl
-            >>>>>#nth
-            >#op0div
-            >>>>>#op1div
-            <<<<<#op0_v
-            >#op0_0
-            >#op0_1
-            >#op0_h
-            >#op0_l
-            >#op1_v
-            >#op1_0
-            >#op1_1
-            >#op1_h
-            >#op1_l
-            mov27/34 <<<<<<<<<<<<<<<<[->>>>>>>+<<<<<<<]
-            mov29/39 >>[->>>>>>>>>>+<<<<<<<<<<]
-            >>>>>>>+
+                <<++++++++++++++++++
+            >>]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov11/18 <[->>>>>>>+<<<<<<<]
+            mov10/23 <[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>+
             >>>>>+
-            <<<<<<<<<<<<<+
+            <<<<<<<<<+
             [
                 [-]
-                >>>>>>[
+                >>[
                     -
                     >>>+
                     <<<[
@@ -937,8 +106,8 @@ runtime init:
                     >[
                     <]
                 <]
-                dup38/35/34 >>>>[-<<<+<+>>>>]
-                mov35/38 <<<[->>>+<<<]
+                dup22/19/18 >>>>[-<<<+<+>>>>]
+                mov19/22 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
@@ -953,151 +122,129 @@ runtime init:
                     >[
                     <]
                 <]
-                dup43/40/39 >>>>[-<<<+<+>>>>]
-                mov40/43 <<<[->>>+<<<]
+                dup27/24/23 >>>>[-<<<+<+>>>>]
+                mov24/27 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
                 <<<<<<[
                     -
-                    mov42/30 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                    mov26/13 >>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
                 <<<<<]
                 >>>>>[-]
-                dup33/31/32 <<<<<<<<<[-<<+>+>]
-                mov31/33 <<[->>+<<]
+                dup17/14/15 <<<<<<<<<[-<<<+>+>>]
+                mov14/17 <<<[->>>+<<<]
                 >[
                     -
                     <<[
                         -
                         >++
                     <]
-                    mov31/30 >[-<+>]
+                    mov14/13 >[-<+>]
                 >]
-                mov30/18 <<[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                mov13/7 <<[-<<<<<<+>>>>>>]
                 continue?
-                dup34/30/31 >>>>[-<<<<+>+>>>]
-                mov30/34 <<<<[->>>>+<<<<]
+                dup18/13/14 >>>>>[-<<<<<+>+>>>>]
+                mov13/18 <<<<<[->>>>>+<<<<<]
                 >[
                     [-]
-                    <<<+
-                >>>]
-                dup39/30/31 >>>>>>>>[-<<<<<<<<<+>+>>>>>>>>]
-                mov30/39 <<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+                    >>+
+                <<]
+                dup23/13/14 >>>>>>>>>[-<<<<<<<<<<+>+>>>>>>>>>]
+                mov13/23 <<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]
                 >[
                     [-]
-                    <<<+
-                >>>]
-                >>+
-            <<<<<]
-            >>>>>>>>-
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
             >>>>>-
             <<<<<<<<[-]
-            %5 = icmp eq i64 %4_ i64 0
-            <<<<<<<<<<<<<<[-]
-            dup18/26/27 <[->>>>>>>>+>+<<<<<<<<<]
-            mov26/18 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+            %6 = icmp eq i64 %5_ i64 0
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov28/30 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<+
-            >>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<[-]
-            >>>>>>>>>>>]
-            <[
+            <<<<<<<<<<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            <<<+
+            >>>>[
                 [-]
-                <<<<<<<<<<[-]
-            >>>>>>>>>>]
-            %6 = select i1 %5_ i8 65_ i8 66
-            <<<<<<<<<[-]
-            dup19/26/27 <[->>>>>>>+>+<<<<<<<<]
-            mov26/19 >>>>>>>[-<<<<<<<+>>>>>>>]
+                <<<<[-]
+            >>>>]
+            %7 = select i1 %6_ i8 65_ i8 66
+            
+            <<[-]
+            dup6/9/10 <<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
             op_to_reg storing const value in temp address
-            >#constop_66
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>#constop_66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
             >+
-            <<<[
-                mov28/20 >[-<<<<<<<<+>>>>>>>>]
-                >[-]
-                <<[-]
-                >>>[-]
-            <<<]
-            >>>[
+            <<<<<[
+                mov11/8 >[-<<<+>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
                 -
-                mov29/20 <[-<<<<<<<<<+>>>>>>>>>]
-                <[-]
+                mov13/8 <<[-<<<<<+>>>>>]
+                <<[-]
                 <[-]
-            >>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_1
-            +
+            >>>>>]
+            call @putchar(i8 %7)
+            
             putchar intrinsic
-            dup20/26/27 >>>>>>>>>>>[->>>>>>+>+<<<<<<<]
-            mov26/20 >>>>>>[-<<<<<<+>>>>>>]
+            dup8/9/10 <<<<<<<[->+>+<<]
+            mov9/8 >[-<+>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
             call @putchar(i8 66)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>#constop_66
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            #constop_66
+            ++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %7 = inttoptr i64 %4 to i32*
-            >>>>>>>>>>>[-]
-            dup18/26/27 <<<[->>>>>>>>+>+<<<<<<<<<]
-            mov26/18 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov27/21 >[-<<<<<<+>>>>>>]
-            %8 = icmp eq i32* %1_ i32* %7
-            <<<<<[-]
+            %8 = inttoptr i64 %5 to i32*
+            
+            <<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            mov10/6 >[-<<<<+>>>>]
+            %9 = icmp eq i32* %2_ i32* %8
+            
+            <<<[-]
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<++++++++++++++++
-            dup21/28/29 <<<<<[->>>>>>>+>+<<<<<<<<]
-            mov28/21 >>>>>>>[-<<<<<<<+>>>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov29/31 <<<<[->>+<<]
+            dup0/10/11 <<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov11/0 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            r1 
+            <++++++
+            dup6/11/12 <<<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/14 [->>+<<]
             >>>>+
             <<<<<<<[
                 -
@@ -1114,236 +261,148 @@ runtime init:
                 l1 <
             <<<<<]
             >>>>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
+            <<<<<<<<<+
+            >>>>>>>[
                 [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
+                <<<<<<<[-]
+            >>>>>>>]
             <[
                 [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            %9 = select i1 %8_ i8 66_ i8 65
+                <<<<<<[-]
+            >>>>>>]
+            %10 = select i1 %9_ i8 66_ i8 65
+            
             <<<<<<<[-]
-            dup22/26/27 <[->>>>+>+<<<<<]
-            mov26/22 >>>>[-<<<<+>>>>]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_66
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
             op_to_reg storing const value in temp address
             >#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >+
-            <<<[
-                mov28/23 >[-<<<<<+>>>>>]
-                >[-]
-                <<[-]
-                >>>[-]
-            <<<]
-            >>>[
+            >++++++++
+            [
                 -
-                mov29/23 <[-<<<<<<+>>>>>>]
-                <[-]
+                <++++++++
+            >]
+            <+
+            >>+
+            <<<<<[
+                mov11/6 >[-<<<<<+>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov13/6 <<[-<<<<<<<+>>>>>>>]
+                <<[-]
                 <[-]
-            >>>]
-            call @putchar(i8 %9)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<#caller/%call_term_for_3
-            +
+            >>>>>]
+            call @putchar(i8 %10)
+            
             putchar intrinsic
-            dup23/26/27 >>>>>>>>>>>>[->>>+>+<<<<]
-            mov26/23 >>>[-<<<+>>>]
+            dup6/9/10 <<<<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
-            %10 = load i32* %1_ align 4
-            >>>>>>>>>>>>>[-]
-            dup15/24/26 <<<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
-            mov26/15 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            %11 = trunc i32 %10 to i8
-            <[-]
-            dup24/26/27 <[->>+>+<<<]
-            mov26/24 >>[-<<+>>]
-            mov27/25 >[-<<+>>]
-            call @putchar(i8 %11)
-            enable next block when we return
-            <<<<<<<<<<<<<<<#caller/%call_term_for_4
-            +
+            %11 = load i32* %2_ align 4
+            
+            <<<<[-]
+            dup5/6/9 <[->+>>>+<<<<]
+            mov9/5 >>>>[-<<<<+>>>>]
+            %12 = trunc i32 %11 to i8
+            
+            <<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            mov10/7 >[-<<<+>>>]
+            call @putchar(i8 %12)
+            
             putchar intrinsic
-            dup25/26/27 >>>>>>>>>>>>>[->+>+<<]
-            mov26/25 >[-<+>]
+            dup7/9/10 <<<[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
-            store i32 97_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            <<<<<<<<<<<[-]
-            mov26/15 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            store i32 97_ i32* %2_ align 4
+            
+            <<<<<[-]
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
             call @putchar(i8 97)
-            enable next block when we return
-            <<<<<<<<<<<<<#caller/%call_term_for_5
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<]
-        >#B:%call_term_for_5
-        [
-            -
-            call @llvm_lifetime_end_p0i8(i64 4_ i8* %2)
-            enable next block when we return
-            >#caller/%call_term_for_6
-            +
-            ignoring llvm intrinsic
-        <]
-        >#B:%call_term_for_6
-        [
-            -
-            ret void
-            zero all function allocs
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<-
-            #dead_frame
-            >>>-
-            l1 <
-            <<<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>]
-    <<<<<<<<<<<]
-    >#main
-    [
-        >>#B:%ret_lading_pad
-        [
-            <<<<<<-
-            #dead_frame
-            >>>>-
-            <<<<#dead_fn_pad/main
-            >>>>>>-
-            l45 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
-        ]
-        <<<<<<#mainloop_main
-        >#F:putstr
-        >#F:putdec
-        >#F:another_stack
-        >#F:main
-        >#B:%no_block0_call_for_main
-        >#B:%ret_lading_pad
-        >#B:%0
-        >#B:%call_term_for_0
-        >#B:%call_term_for_1
-        >#B:%call_term_for_2
-        >#B:%call_term_for_3
-        >#B:%call_term_for_4
-        >#B:%call_term_for_5
-        >#B:%call_term_for_6
-        >#B:%call_term_for_7
-        >#alloc_%1
-        >#%2_=_bitcast_i32*_%1_to_i8*(mult)
-        >#%3_=_ptrtoint_i32*_%1_to_i64(mult)
-        >#%4_=_and_i64_%3__i64_252(mult)
-        >#%5_=_icmp_eq_i64_%4__i64_0(mult)
-        >#%6_=_select_i1_%5__i8_65__i8_66(mult)
-        >#%7_=_inttoptr_i64_%4_to_i32*(mult)
-        >#%8_=_icmp_eq_i32*_%1__i32*_%7(mult)
-        >#%9_=_select_i1_%8__i8_66__i8_65(mult)
-        >#%10_=_load_i32*_%1__align_4(mult)
-        >#%11_=_trunc_i32_%10_to_i8(mult)
-        <<<<<<<<<<<<<<<<<<<<<#B:%no_block0_call_for_main
-        [
-            -
-            copy up args
-            br label %0
-            >>+
-        <<]
-        >>#B:%0
-        [
-            -
-            %1 = alloca i32_ align 4
-            %2 = bitcast i32* %1 to i8*
-            >>>>>>>>>>[-]
-            resolve alloca addr storing pointer value in temp address
-            l1 <
-            dup0/28/29 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<+++++++++++++++++
-            mov27/17 [-<<<<<<<<<<+>>>>>>>>>>]
-            call @llvm_lifetime_start_p0i8(i64 4_ i8* %2)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<#caller/%call_term_for_0
-            +
-            ignoring llvm intrinsic
-        <]
-        >#B:%call_term_for_0
-        [
-            -
             store i32 112_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>>>#constop_112
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            <<<<<<<<<<<[-]
-            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            %3 = ptrtoint i32* %1 to i64
-            <<<<<<<<<[-]
+            
+            <<<<<[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++++
+            >>>>>]
+            %14 = ptrtoint i32* %1 to i64
+            
+            <<<[-]
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/28/30 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov30/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<<+++++++++++++++++
-            mov27/18 [-<<<<<<<<<+>>>>>>>>>]
-            %4 = and i64 %3_ i64 252
-            <<<<<<<<[-]
-            dup18/27/28 <[->>>>>>>>>+>+<<<<<<<<<<]
-            mov27/18 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            dup0/10/11 <<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov11/0 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            r1 
+            <+++++
+            mov9/6 [-<<<+>>>]
+            %15 = and i64 %14_ i64 252
+            
+            <<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
-            >>>#constop_252
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >#dub_scratch
-            <<#loop_ctrl
-            >>>>>#nth
-            >#op0div
-            >>>>>#op1div
-            <<<<<#op0_v
-            >#op0_0
-            >#op0_1
-            >#op0_h
-            >#op0_l
-            >#op1_v
-            >#op1_0
-            >#op1_1
-            >#op1_h
-            >#op1_l
-            mov28/35 <<<<<<<<<<<<<<<<[->>>>>>>+<<<<<<<]
-            mov30/40 >>[->>>>>>>>>>+<<<<<<<<<<]
-            >>>>>>>+
-            >>>>>+
-            <<<<<<<<<<<<<+
+            >>#constop_252
+            >++++++++++++++
+            [
+                -
+                <++++++++++++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov10/18 <<[->>>>>>>>+<<<<<<<<]
+            mov11/28 >[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>+
+            >>>>>>>>>>+
+            <<<<<<<<<<<<<<+
             [
                 [-]
-                >>>>>>[
+                >>[
                     -
                     >>>+
                     <<<[
@@ -1353,13 +412,13 @@ runtime init:
                     >[
                     <]
                 <]
-                dup39/36/35 >>>>[-<<<+<+>>>>]
-                mov36/39 <<<[->>>+<<<]
+                dup22/19/18 >>>>[-<<<+<+>>>>]
+                mov19/22 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
-                >[
+                >>>>>>[
                     -
                     >>>+
                     <<<[
@@ -1369,151 +428,129 @@ runtime init:
                     >[
                     <]
                 <]
-                dup44/41/40 >>>>[-<<<+<+>>>>]
-                mov41/44 <<<[->>>+<<<]
+                dup32/29/28 >>>>[-<<<+<+>>>>]
+                mov29/32 <<<[->>>+<<<]
                 >>>[
                     -
                     <-
                 >]
-                <<<<<<[
+                <<<<<<<<<<<[
                     -
-                    mov43/31 >>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
-                <<<<<]
-                >>>>>[-]
-                dup34/32/33 <<<<<<<<<[-<<+>+>]
-                mov32/34 <<[->>+<<]
+                    mov31/13 >>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<]
+                >>>>>>>>>>[-]
+                dup17/14/15 <<<<<<<<<<<<<<[-<<<+>+>>]
+                mov14/17 <<<[->>>+<<<]
                 >[
                     -
                     <<[
                         -
                         >++
                     <]
-                    mov32/31 >[-<+>]
+                    mov14/13 >[-<+>]
                 >]
-                mov31/19 <<[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                mov13/7 <<[-<<<<<<+>>>>>>]
                 continue?
-                dup35/31/32 >>>>[-<<<<+>+>>>]
-                mov31/35 <<<<[->>>>+<<<<]
+                dup18/13/14 >>>>>[-<<<<<+>+>>>>]
+                mov13/18 <<<<<[->>>>>+<<<<<]
                 >[
                     [-]
-                    <<<+
-                >>>]
-                dup40/31/32 >>>>>>>>[-<<<<<<<<<+>+>>>>>>>>]
-                mov31/40 <<<<<<<<<[->>>>>>>>>+<<<<<<<<<]
+                    >>+
+                <<]
+                dup28/13/14 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>>]
+                mov13/28 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
                 >[
                     [-]
-                    <<<+
-                >>>]
-                >>+
-            <<<<<]
-            >>>>>>>>-
-            >>>>>-
-            <<<<<<<<[-]
-            %5 = icmp eq i64 %4_ i64 0
-            <<<<<<<<<<<<<<[-]
-            dup19/27/28 <[->>>>>>>>+>+<<<<<<<<<]
-            mov27/19 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            op_to_reg storing const value in temp address
-            >>#constop_0
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<[-]
+            %16 = icmp eq i64 %15_ i64 0
             
-            >>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov29/31 <<<<[->>+<<]
-            >>>>+
-            <<<<<[
-                -
-                >>+
-                >[
-                    -
-                    <-
-                    r1 >
-                >]
-                r1 >
-                [
-                    l1 <
-                ]
-                l1 <
-            <<<]
-            >>>>>-
-            <<<<<<<<<<<<<+
-            >>>>>>>>>>>[
-                [-]
-                <<<<<<<<<<<[-]
-            >>>>>>>>>>>]
-            <[
+            <<<<<<<<<<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            <<<+
+            >>>>[
                 [-]
-                <<<<<<<<<<[-]
-            >>>>>>>>>>]
-            %6 = select i1 %5_ i8 65_ i8 66
-            <<<<<<<<<[-]
-            dup20/27/28 <[->>>>>>>+>+<<<<<<<<]
-            mov27/20 >>>>>>>[-<<<<<<<+>>>>>>>]
+                <<<<[-]
+            >>>>]
+            %17 = select i1 %16_ i8 65_ i8 66
+            
+            <<[-]
+            dup6/9/10 <<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
             op_to_reg storing const value in temp address
             >>#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
             op_to_reg storing const value in temp address
-            >#constop_66
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>#constop_66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
             >+
-            <<<[
-                mov29/21 >[-<<<<<<<<+>>>>>>>>]
-                >[-]
-                <<[-]
-                >>>[-]
-            <<<]
-            >>>[
+            <<<<<[
+                mov11/8 >[-<<<+>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
                 -
-                mov30/21 <[-<<<<<<<<<+>>>>>>>>>]
-                <[-]
+                mov13/8 <<[-<<<<<+>>>>>]
+                <<[-]
                 <[-]
-            >>>]
-            call @putchar(i8 %6)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_1
-            +
+            >>>>>]
+            call @putchar(i8 %17)
+            
             putchar intrinsic
-            dup21/27/28 >>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
-            mov27/21 >>>>>>[-<<<<<<+>>>>>>]
+            dup8/9/10 <<<<<<<[->+>+<<]
+            mov9/8 >[-<+>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_1
-        [
-            -
             call @putchar(i8 66)
-            enable next block when we return
-            >#caller/%call_term_for_2
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>>>#constop_66
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            .
+            #constop_66
+            ++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
             [-]
-        <<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_2
-        [
-            -
-            %7 = inttoptr i64 %4 to i32*
-            >>>>>>>>>>>>[-]
-            dup19/27/28 <<<[->>>>>>>>+>+<<<<<<<<<]
-            mov27/19 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
-            mov28/22 >[-<<<<<<+>>>>>>]
-            %8 = icmp eq i32* %1_ i32* %7
-            <<<<<[-]
+            %18 = inttoptr i64 %15 to i32*
+            
+            <<<[-]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            mov10/6 >[-<<<<+>>>>]
+            %19 = icmp eq i32* %1_ i32* %18
+            
+            <<<[-]
             resolve alloca addr storing pointer value in temp address
             l1 <
-            dup0/28/29 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov29/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            <<+++++++++++++++++
-            dup22/29/30 <<<<<[->>>>>>>+>+<<<<<<<<]
-            mov29/22 >>>>>>>[-<<<<<<<+>>>>>>>]
-            >>>#subnu_tmpb
-            >#subnu_tmp0
-            >#subnu_tmp1
-            mov30/32 <<<<[->>+<<]
+            dup0/10/11 <<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov11/0 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            r1 
+            <+++++
+            dup6/11/12 <<<[->>>>>+>+<<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/14 [->>+<<]
             >>>>+
             <<<<<<<[
                 -
@@ -1530,153 +567,110 @@ runtime init:
                 l1 <
             <<<<<]
             >>>>>>>-
-            <<<<<<<<<<<+
-            >>>>>>>>>[
+            <<<<<<<<<+
+            >>>>>>>[
                 [-]
-                <<<<<<<<<[-]
-            >>>>>>>>>]
+                <<<<<<<[-]
+            >>>>>>>]
             <[
                 [-]
-                <<<<<<<<[-]
-            >>>>>>>>]
-            %9 = select i1 %8_ i8 66_ i8 65
+                <<<<<<[-]
+            >>>>>>]
+            %20 = select i1 %19_ i8 66_ i8 65
+            
             <<<<<<<[-]
-            dup23/27/28 <[->>>>+>+<<<<<]
-            mov27/23 >>>>[-<<<<+>>>>]
+            dup7/9/10 >[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
             op_to_reg storing const value in temp address
             >>#constop_66
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
             op_to_reg storing const value in temp address
             >#constop_65
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            >+
-            <<<[
-                mov29/24 >[-<<<<<+>>>>>]
-                >[-]
-                <<[-]
-                >>>[-]
-            <<<]
-            >>>[
+            >++++++++
+            [
                 -
-                mov30/24 <[-<<<<<<+>>>>>>]
-                <[-]
+                <++++++++
+            >]
+            <+
+            >>+
+            <<<<<[
+                mov11/6 >[-<<<<<+>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov13/6 <<[-<<<<<<<+>>>>>>>]
+                <<[-]
                 <[-]
-            >>>]
-            call @putchar(i8 %9)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<<<<<#caller/%call_term_for_3
-            +
+            >>>>>]
+            call @putchar(i8 %20)
+            
             putchar intrinsic
-            dup24/27/28 >>>>>>>>>>>>>[->>>+>+<<<<]
-            mov27/24 >>>[-<<<+>>>]
+            dup6/9/10 <<<<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_3
-        [
-            -
-            %10 = load i32* %1_ align 4
-            >>>>>>>>>>>>>>[-]
-            dup16/25/27 <<<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
-            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
-            %11 = trunc i32 %10 to i8
-            <[-]
-            dup25/27/28 <[->>+>+<<<]
-            mov27/25 >>[-<<+>>]
-            mov28/26 >[-<<+>>]
-            call @putchar(i8 %11)
-            enable next block when we return
-            <<<<<<<<<<<<<<<<#caller/%call_term_for_4
-            +
+            %21 = load i32* %1_ align 4
+            
+            <<<<[-]
+            dup4/6/9 <<[->>+>>>+<<<<<]
+            mov9/4 >>>>>[-<<<<<+>>>>>]
+            %22 = trunc i32 %21 to i8
+            
+            <<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            mov10/7 >[-<<<+>>>]
+            call @putchar(i8 %22)
+            
             putchar intrinsic
-            dup26/27/28 >>>>>>>>>>>>>>[->+>+<<]
-            mov27/26 >[-<+>]
+            dup7/9/10 <<<[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
             >.
             [-]
-        <<<<<<<<<<<<<<<<<]
-        >#B:%call_term_for_4
-        [
-            -
             store i32 97_ i32* %1_ align 4
-            op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>>#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            <<<<<<<<<<<[-]
-            mov27/16 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            
+            <<<<<<[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
             call @putchar(i8 97)
-            enable next block when we return
-            <<<<<<<<<<<<<<#caller/%call_term_for_5
-            +
+            
             putchar intrinsic
             op_to_reg storing const value in temp address
-            >>>>>>>>>>>>>>#constop_97
-            +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            >>>>>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
             .
             [-]
-        <<<<<<<<<<<<<<<]
-        >#B:%call_term_for_5
-        [
-            -
-            call @another_stack()
-            enable next block when we return
-            >#caller/%call_term_for_6
-            +
-            stack_width 45
-            ret_pad_width 6
-            give callee a stack pointer
-            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>#stack_ptr
-            ++++++++++++++++++++++++++++++++++++++++++++++++++++++
-            l1 <
-            dup0/53/54 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
-            mov54/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
-            r1 >
-            r45 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
-            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<#JUMP_PAD
-            +
-            >>>>#caller
-            +
-            >>#caller/jump_pad_blk
-            +
-            r8 >>>>>>>>
-            <<<<<<#===FRAME_another_stack
-            +
-            >>>#another_stack
-            +
-            >>#another_stack/b0
-            +
-        >>>>>>>>]
-        >#B:%call_term_for_6
-        [
-            -
-            call @llvm_lifetime_end_p0i8(i64 4_ i8* %2)
-            enable next block when we return
-            >#caller/%call_term_for_7
-            +
-            ignoring llvm intrinsic
-        <]
-        >#B:%call_term_for_7
-        [
-            -
             ret void
+            
             zero all function allocs
+            <<<<<[-]
             >[-]
             >[-]
             >[-]
             >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            >[-]
-            <<<<<<<<<<<<<<<<<<<<<<<<<<-
+            <<<<<<<<-
             #dead_frame
-            >>>>-
+            >-
             l1 <
-            <<<<[-]
-            r1 >
-            l8 <<<<<<<<
-        >>>>>>>>>>>>>>>]
-    <<<<<<<<<<<]
-<<<<]
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,1198 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%call_term_for_2 from %0 after call 2 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  row (depth 1)
+  main calls row (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  row: 12786 ops ~580514 steps
+  main: 1203 ops ~4563 steps
+frame widths:
+  row: 36 cells
+  main: 15 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#row
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/row
+            >>>-
+            l36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_row
+        #F:row
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #alloc_%2
+        #alloc_%3
+        #alloc_%4
+        #alloc_%5
+        #alloc_%6
+        #alloc_%7
+        #alloc_%8
+        #alloc_%9
+        #alloc_%10
+        #alloc_%11
+        #alloc_%12
+        #alloc_%13
+        #alloc_%14
+        #alloc_%15
+        #alloc_%16
+        #alloc_%17
+        #alloc_%18
+        #alloc_%19
+        #alloc_%20
+        #alloc_%21
+        #%22_=_load_volatile_i8*_%2__align_1(mult)
+        #%23_=_sub_i8_%22__i8_%0(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/29 <<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            r2 >>
+            %2 = alloca i8_ align 1
+            %3 = alloca i8_ align 1
+            %4 = alloca i8_ align 1
+            %5 = alloca i8_ align 1
+            %6 = alloca i8_ align 1
+            %7 = alloca i8_ align 1
+            %8 = alloca i8_ align 1
+            %9 = alloca i8_ align 1
+            %10 = alloca i8_ align 1
+            %11 = alloca i8_ align 1
+            %12 = alloca i8_ align 1
+            %13 = alloca i8_ align 1
+            %14 = alloca i8_ align 1
+            %15 = alloca i8_ align 1
+            %16 = alloca i8_ align 1
+            %17 = alloca i8_ align 1
+            %18 = alloca i8_ align 1
+            %19 = alloca i8_ align 1
+            %20 = alloca i8_ align 1
+            %21 = alloca i8_ align 1
+            store volatile i8 97_ i8* %2_ align 1
+            
+            >>>>>[-]
+            >>>>>>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<+
+            store volatile i8 98_ i8* %3_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 99_ i8* %4_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 100_ i8* %5_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>>]
+            store volatile i8 101_ i8* %6_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            store volatile i8 102_ i8* %7_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<++
+            store volatile i8 103_ i8* %8_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+++
+            store volatile i8 104_ i8* %9_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<+++++++++++++
+            >>>>>>>>>>>>>>>>]
+            store volatile i8 105_ i8* %10_ align 1
+            
+            <<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>]
+            store volatile i8 106_ i8* %11_ align 1
+            
+            <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<+
+            store volatile i8 107_ i8* %12_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<++
+            store volatile i8 108_ i8* %13_ align 1
+            
+            >[-]
+            >>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>]
+            store volatile i8 109_ i8* %14_ align 1
+            
+            <<<<<<<<<<<[-]
+            >>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>]
+            <<<<<<<<<<<+
+            store volatile i8 110_ i8* %15_ align 1
+            
+            >[-]
+            >>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<+++++++++++
+            >>>>>>>>>>]
+            store volatile i8 111_ i8* %16_ align 1
+            
+            <<<<<<<<<[-]
+            >>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<+++++++++++
+            >>>>>>>>>]
+            <<<<<<<<<+
+            store volatile i8 112_ i8* %17_ align 1
+            
+            >[-]
+            >>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<++++++++++++++
+            >>>>>>>>]
+            store volatile i8 113_ i8* %18_ align 1
+            
+            <<<<<<<[-]
+            >>>>>>>++++++++
+            [
+                -
+                <<<<<<<++++++++++++++
+            >>>>>>>]
+            <<<<<<<+
+            store volatile i8 114_ i8* %19_ align 1
+            
+            >[-]
+            >>>>>>++++++++
+            [
+                -
+                <<<<<<++++++++++++++
+            >>>>>>]
+            <<<<<<++
+            store volatile i8 115_ i8* %20_ align 1
+            
+            >[-]
+            >>>>>++++++++
+            [
+                -
+                <<<<<++++++++++++++
+            >>>>>]
+            <<<<<+++
+            store volatile i8 116_ i8* %21_ align 1
+            
+            >[-]
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++++
+            >>>>]
+            <<<<++++
+            %22 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 97
+            >>>++++++++
+            [
+                -
+                <<<++++++++++++
+            >>>]
+            <<<+
+            %23 = sub i8 %22_ i8 %0
+            
+            >[-]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            dup27/29/31 <[->>+>>+<<<<]
+            mov29/27 >>[-<<+>>]
+            mov28/26 <[-<<+>>]
+            >>>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %23)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %24 = load volatile i8* %3_ align 1
+            
+            <<<<[-]
+            dup6/25/28 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/6 >>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            %25 = sub i8 %24_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>>>+
+                <<<<<<<<<[
+                    -
+                    mov26/34 [->>>>>>>>+<<<<<<<<]
+                    >>>>>>>>>-
+                <<<<<<<<<]
+                mov34/26 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>]
+            <<<<]
+            call @putchar(i8 %25)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %26 = load volatile i8* %4_ align 1
+            
+            <<<<[-]
+            dup7/25/28 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<<]
+            mov28/7 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            %27 = sub i8 %26_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %27)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %28 = load volatile i8* %5_ align 1
+            
+            <<<<[-]
+            dup8/25/28 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<<]
+            mov28/8 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            %29 = sub i8 %28_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %29)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %30 = load volatile i8* %6_ align 1
+            
+            <<<<[-]
+            dup9/25/28 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<<]
+            mov28/9 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            %31 = sub i8 %30_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %31)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %32 = load volatile i8* %7_ align 1
+            
+            <<<<[-]
+            dup10/25/28 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<<]
+            mov28/10 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            %33 = sub i8 %32_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %33)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %34 = load volatile i8* %8_ align 1
+            
+            <<<<[-]
+            dup11/25/28 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<<]
+            mov28/11 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %35 = sub i8 %34_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %35)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %36 = load volatile i8* %9_ align 1
+            
+            <<<<[-]
+            dup12/25/28 <<<<<<<<<<<<<[->>>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<<]
+            mov28/12 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            %37 = sub i8 %36_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %37)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %38 = load volatile i8* %10_ align 1
+            
+            <<<<[-]
+            dup13/25/28 <<<<<<<<<<<<[->>>>>>>>>>>>+>>>+<<<<<<<<<<<<<<<]
+            mov28/13 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            %39 = sub i8 %38_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %39)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %40 = load volatile i8* %11_ align 1
+            
+            <<<<[-]
+            dup14/25/28 <<<<<<<<<<<[->>>>>>>>>>>+>>>+<<<<<<<<<<<<<<]
+            mov28/14 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            %41 = sub i8 %40_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %41)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %42 = load volatile i8* %12_ align 1
+            
+            <<<<[-]
+            dup15/25/28 <<<<<<<<<<[->>>>>>>>>>+>>>+<<<<<<<<<<<<<]
+            mov28/15 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            %43 = sub i8 %42_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %43)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %44 = load volatile i8* %13_ align 1
+            
+            <<<<[-]
+            dup16/25/28 <<<<<<<<<[->>>>>>>>>+>>>+<<<<<<<<<<<<]
+            mov28/16 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            %45 = sub i8 %44_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %45)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %46 = load volatile i8* %14_ align 1
+            
+            <<<<[-]
+            dup17/25/28 <<<<<<<<[->>>>>>>>+>>>+<<<<<<<<<<<]
+            mov28/17 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            %47 = sub i8 %46_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %47)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %48 = load volatile i8* %15_ align 1
+            
+            <<<<[-]
+            dup18/25/28 <<<<<<<[->>>>>>>+>>>+<<<<<<<<<<]
+            mov28/18 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            %49 = sub i8 %48_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %49)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %50 = load volatile i8* %16_ align 1
+            
+            <<<<[-]
+            dup19/25/28 <<<<<<[->>>>>>+>>>+<<<<<<<<<]
+            mov28/19 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            %51 = sub i8 %50_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %51)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %52 = load volatile i8* %17_ align 1
+            
+            <<<<[-]
+            dup20/25/28 <<<<<[->>>>>+>>>+<<<<<<<<]
+            mov28/20 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            %53 = sub i8 %52_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %53)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %54 = load volatile i8* %18_ align 1
+            
+            <<<<[-]
+            dup21/25/28 <<<<[->>>>+>>>+<<<<<<<]
+            mov28/21 >>>>>>>[-<<<<<<<+>>>>>>>]
+            %55 = sub i8 %54_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %55)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %56 = load volatile i8* %19_ align 1
+            
+            <<<<[-]
+            dup22/25/28 <<<[->>>+>>>+<<<<<<]
+            mov28/22 >>>>>>[-<<<<<<+>>>>>>]
+            %57 = sub i8 %56_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %57)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %58 = load volatile i8* %20_ align 1
+            
+            <<<<[-]
+            dup23/25/28 <<[->>+>>>+<<<<<]
+            mov28/23 >>>>>[-<<<<<+>>>>>]
+            %59 = sub i8 %58_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %59)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            %60 = load volatile i8* %21_ align 1
+            
+            <<<<[-]
+            dup24/25/28 <[->+>>>+<<<<]
+            mov28/24 >>>>[-<<<<+>>>>]
+            %61 = sub i8 %60_ i8 %0
+            
+            <<[-]
+            dup25/28/29 <[->>>+>+<<<<]
+            mov28/25 >>>[-<<<+>>>]
+            dup27/30/31 <[->>>+>+<<<<]
+            mov30/27 >>>[-<<<+>>>]
+            mov29/26 <[-<<<+>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<[
+                    -
+                    mov26/32 [->>>>>>+<<<<<<]
+                    >>>>>>>-
+                <<<<<<<]
+                mov32/26 >>>>>>[-<<<<<<+>>>>>>]
+                >[
+                    -
+                    <<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>]
+            <<]
+            call @putchar(i8 %61)
+            
+            putchar intrinsic
+            dup26/28/29 <<<<<[->>+>+<<<]
+            mov28/26 >>[-<<+>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 <<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:row
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #B:%call_term_for_2
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 10_ i8* %1_ align 1
+            
+            >>>>[-]
+            ++++++++++
+            store volatile i8 32_ i8* %2_ align 1
+            
+            >[-]
+            >>++++
+            [
+                -
+                <<++++++++
+            >>]
+            call @row(i8 0)
+            
+            enable next block when we return
+            <<<<<<#caller/%call_term_for_0
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov13/20 [->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_row
+            +
+            >#row
+            +
+            >>#row/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %3 = load volatile i8* %1_ align 1
+            
+            >>>>>[-]
+            dup9/11/12 <<[->>+>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup11/12/14 <[->+>>+<<<]
+            mov12/11 >[-<+>]
+            >>.
+            [-]
+            %4 = load volatile i8* %2_ align 1
+            
+            <<<[-]
+            dup10/11/12 <[->+>+<<]
+            mov12/10 >>[-<<+>>]
+            call @row(i8 %4)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_1
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>#arg_0
+            dup11/12/13 [->+>+<<]
+            mov12/11 >[-<+>]
+            mov13/20 >[->>>>>>>+<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_row
+            +
+            >#row
+            +
+            >>#row/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            %5 = load volatile i8* %1_ align 1
+            
+            >>>>[-]
+            dup9/11/12 <<[->>+>+<<<]
+            mov12/9 >>>[-<<<+>>>]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup11/12/13 <[->+>+<<]
+            mov12/11 >[-<+>]
+            >.
+            [-]
+            call @row(i8 0)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_2
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            >>>>#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov12/20 [->>>>>>>>+<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>#stack_ptr
+            ++++++++++++++++++++++
+            l1 <
+            dup0/22/23 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<]
+            mov23/0 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_row
+            +
+            >#row
+            +
+            >>#row/b0
+            +
+        >>>>]
+        >#B:%call_term_for_2
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>>]
+    <<<<<<]
+<<]
@@ -0,0 +1,260 @@
+; ModuleID = './tests/artifacts/o1/many_locals.c/ir.bc'
+source_filename = "./tests/cases/many_locals.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 {
+  %2 = load i8, i8* %0, align 1, !tbaa !5
+  %3 = icmp eq i8 %2, 0
+  br i1 %3, label %10, label %4
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @putchar(i8 noundef zeroext %5) #4
+  %7 = getelementptr inbounds i8, i8* %6, i64 1
+  %8 = load i8, i8* %7, align 1, !tbaa !5
+  %9 = icmp eq i8 %8, 0
+  br i1 %9, label %10, label %4, !llvm.loop !8
+
+10:                                               ; preds = %4, %1
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 {
+  %2 = icmp eq i32 %0, 0
+  br i1 %2, label %5, label %3
+
+3:                                                ; preds = %1
+  %4 = icmp ugt i32 %0, 9
+  br i1 %4, label %9, label %6
+
+5:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48) #4
+  br label %22
+
+6:                                                ; preds = %9, %3
+  %7 = phi i32 [ 1, %3 ], [ %11, %9 ]
+  %8 = icmp eq i32 %7, 0
+  br i1 %8, label %22, label %14
+
+9:                                                ; preds = %9, %3
+  %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
+  %11 = mul i32 %10, 10
+  %12 = udiv i32 %0, %11
+  %13 = icmp ugt i32 %12, 9
+  br i1 %13, label %9, label %6, !llvm.loop !11
+
+14:                                               ; preds = %14, %6
+  %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
+  %16 = udiv i32 %0, %15
+  %17 = urem i32 %16, 10
+  %18 = trunc i32 %17 to i8
+  %19 = or i8 %18, 48
+  call void @putchar(i8 noundef zeroext %19) #4
+  %20 = udiv i32 %15, 10
+  %21 = icmp ult i32 %15, 10
+  br i1 %21, label %22, label %14, !llvm.loop !12
+
+22:                                               ; preds = %14, %6, %5
+  ret void
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: noinline nounwind uwtable
+define dso_local void @row(i8 noundef zeroext %0) local_unnamed_addr #3 {
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  %6 = alloca i8, align 1
+  %7 = alloca i8, align 1
+  %8 = alloca i8, align 1
+  %9 = alloca i8, align 1
+  %10 = alloca i8, align 1
+  %11 = alloca i8, align 1
+  %12 = alloca i8, align 1
+  %13 = alloca i8, align 1
+  %14 = alloca i8, align 1
+  %15 = alloca i8, align 1
+  %16 = alloca i8, align 1
+  %17 = alloca i8, align 1
+  %18 = alloca i8, align 1
+  %19 = alloca i8, align 1
+  %20 = alloca i8, align 1
+  %21 = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %2)
+  store volatile i8 97, i8* %2, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %3)
+  store volatile i8 98, i8* %3, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %4)
+  store volatile i8 99, i8* %4, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %5)
+  store volatile i8 100, i8* %5, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %6)
+  store volatile i8 101, i8* %6, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %7)
+  store volatile i8 102, i8* %7, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %8)
+  store volatile i8 103, i8* %8, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %9)
+  store volatile i8 104, i8* %9, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %10)
+  store volatile i8 105, i8* %10, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %11)
+  store volatile i8 106, i8* %11, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %12)
+  store volatile i8 107, i8* %12, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %13)
+  store volatile i8 108, i8* %13, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %14)
+  store volatile i8 109, i8* %14, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %15)
+  store volatile i8 110, i8* %15, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %16)
+  store volatile i8 111, i8* %16, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %17)
+  store volatile i8 112, i8* %17, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %18)
+  store volatile i8 113, i8* %18, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %19)
+  store volatile i8 114, i8* %19, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %20)
+  store volatile i8 115, i8* %20, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %21)
+  store volatile i8 116, i8* %21, align 1, !tbaa !5
+  %22 = load volatile i8, i8* %2, align 1, !tbaa !5
+  %23 = sub i8 %22, %0
+  call void @putchar(i8 noundef zeroext %23) #4
+  %24 = load volatile i8, i8* %3, align 1, !tbaa !5
+  %25 = sub i8 %24, %0
+  call void @putchar(i8 noundef zeroext %25) #4
+  %26 = load volatile i8, i8* %4, align 1, !tbaa !5
+  %27 = sub i8 %26, %0
+  call void @putchar(i8 noundef zeroext %27) #4
+  %28 = load volatile i8, i8* %5, align 1, !tbaa !5
+  %29 = sub i8 %28, %0
+  call void @putchar(i8 noundef zeroext %29) #4
+  %30 = load volatile i8, i8* %6, align 1, !tbaa !5
+  %31 = sub i8 %30, %0
+  call void @putchar(i8 noundef zeroext %31) #4
+  %32 = load volatile i8, i8* %7, align 1, !tbaa !5
+  %33 = sub i8 %32, %0
+  call void @putchar(i8 noundef zeroext %33) #4
+  %34 = load volatile i8, i8* %8, align 1, !tbaa !5
+  %35 = sub i8 %34, %0
+  call void @putchar(i8 noundef zeroext %35) #4
+  %36 = load volatile i8, i8* %9, align 1, !tbaa !5
+  %37 = sub i8 %36, %0
+  call void @putchar(i8 noundef zeroext %37) #4
+  %38 = load volatile i8, i8* %10, align 1, !tbaa !5
+  %39 = sub i8 %38, %0
+  call void @putchar(i8 noundef zeroext %39) #4
+  %40 = load volatile i8, i8* %11, align 1, !tbaa !5
+  %41 = sub i8 %40, %0
+  call void @putchar(i8 noundef zeroext %41) #4
+  %42 = load volatile i8, i8* %12, align 1, !tbaa !5
+  %43 = sub i8 %42, %0
+  call void @putchar(i8 noundef zeroext %43) #4
+  %44 = load volatile i8, i8* %13, align 1, !tbaa !5
+  %45 = sub i8 %44, %0
+  call void @putchar(i8 noundef zeroext %45) #4
+  %46 = load volatile i8, i8* %14, align 1, !tbaa !5
+  %47 = sub i8 %46, %0
+  call void @putchar(i8 noundef zeroext %47) #4
+  %48 = load volatile i8, i8* %15, align 1, !tbaa !5
+  %49 = sub i8 %48, %0
+  call void @putchar(i8 noundef zeroext %49) #4
+  %50 = load volatile i8, i8* %16, align 1, !tbaa !5
+  %51 = sub i8 %50, %0
+  call void @putchar(i8 noundef zeroext %51) #4
+  %52 = load volatile i8, i8* %17, align 1, !tbaa !5
+  %53 = sub i8 %52, %0
+  call void @putchar(i8 noundef zeroext %53) #4
+  %54 = load volatile i8, i8* %18, align 1, !tbaa !5
+  %55 = sub i8 %54, %0
+  call void @putchar(i8 noundef zeroext %55) #4
+  %56 = load volatile i8, i8* %19, align 1, !tbaa !5
+  %57 = sub i8 %56, %0
+  call void @putchar(i8 noundef zeroext %57) #4
+  %58 = load volatile i8, i8* %20, align 1, !tbaa !5
+  %59 = sub i8 %58, %0
+  call void @putchar(i8 noundef zeroext %59) #4
+  %60 = load volatile i8, i8* %21, align 1, !tbaa !5
+  %61 = sub i8 %60, %0
+  call void @putchar(i8 noundef zeroext %61) #4
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %21)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %20)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %19)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %18)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %17)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %16)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %15)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %14)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %13)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %12)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %11)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %10)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %9)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %8)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %7)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %6)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %5)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %4)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %3)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %2)
+  ret void
+}
+
+; Function Attrs: nounwind uwtable
+define dso_local void @main() local_unnamed_addr #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %1)
+  store volatile i8 10, i8* %1, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %2)
+  store volatile i8 32, i8* %2, align 1, !tbaa !5
+  call void @row(i8 noundef zeroext 0)
+  %3 = load volatile i8, i8* %1, align 1, !tbaa !5
+  call void @putchar(i8 noundef zeroext %3) #4
+  %4 = load volatile i8, i8* %2, align 1, !tbaa !5
+  call void @row(i8 noundef zeroext %4)
+  %5 = load volatile i8, i8* %1, align 1, !tbaa !5
+  call void @putchar(i8 noundef zeroext %5) #4
+  call void @row(i8 noundef zeroext 0)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %2)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %1)
+  ret void
+}
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { argmemonly nofree nosync nounwind willreturn }
+attributes #3 = { noinline nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #4 = { nounwind }
+
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
@@ -0,0 +1,785 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 7004 ops ~35746 steps
+frame widths:
+  main: 33 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l33 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%37
+        #B:%38
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_add_i8_%2__i8_1(mult)
+        #%4_=_load_volatile_i8*_%1__align_1(mult)
+        #%6_=_load_volatile_i8*_%1__align_1(mult)
+        #%8_=_load_volatile_i8*_%1__align_1(mult)
+        #%10_=_load_volatile_i8*_%1__align_1(mult)
+        #%12_=_load_volatile_i8*_%1__align_1(mult)
+        #%14_=_load_volatile_i8*_%1__align_1(mult)
+        #%16_=_load_volatile_i8*_%1__align_1(mult)
+        #%18_=_load_volatile_i8*_%1__align_1(mult)
+        #%20_=_load_volatile_i8*_%1__align_1(mult)
+        #%22_=_load_volatile_i8*_%1__align_1(mult)
+        #%24_=_load_volatile_i8*_%1__align_1(mult)
+        #%26_=_load_volatile_i8*_%1__align_1(mult)
+        #%28_=_load_volatile_i8*_%1__align_1(mult)
+        #%30_=_load_volatile_i8*_%1__align_1(mult)
+        #%32_=_load_volatile_i8*_%1__align_1(mult)
+        #%35_=_select_i1_%34__i8_%32__i8_%2(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 97_ i8* %1_ align 1
+            
+            >>>>[-]
+            >>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 97
+            >>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %3 = add i8 %2_ i8 1
+            
+            >[-]
+            known to be 98
+            >>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %3_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %4 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<[-]
+            known to be 98
+            >>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>]
+            %5 = add i8 %4_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 99
+            >>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %5_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %6 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<[-]
+            known to be 99
+            >>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>]
+            %7 = add i8 %6_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 100
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %7_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %8 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<[-]
+            known to be 100
+            >>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>]
+            %9 = add i8 %8_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 101
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            store volatile i8 %9_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %10 = load volatile i8* %1_ align 1
+            
+            >>>>>>[-]
+            known to be 101
+            >>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>]
+            <<<<<<<<<<<<<+
+            %11 = add i8 %10_ i8 1
+            
+            <<<<[-]
+            known to be 102
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<++
+            store volatile i8 %11_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<++
+            %12 = load volatile i8* %1_ align 1
+            
+            >>>>>>>[-]
+            known to be 102
+            >>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>]
+            <<<<<<<<<<<<++
+            %13 = add i8 %12_ i8 1
+            
+            <<<<<[-]
+            known to be 103
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+++
+            store volatile i8 %13_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+++
+            %14 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>[-]
+            known to be 103
+            >>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<++++++++++
+            >>>>>>>>>>>]
+            <<<<<<<<<<<+++
+            %15 = add i8 %14_ i8 1
+            
+            <<<<<<[-]
+            known to be 104
+            >>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %15_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %16 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<<[-]
+            known to be 104
+            >>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<+++++++++++++
+            >>>>>>>>>>]
+            %17 = add i8 %16_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 105
+            >>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %17_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %18 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<[-]
+            known to be 105
+            >>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<+++++++++++++++
+            >>>>>>>>>]
+            %19 = add i8 %18_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 106
+            >>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            store volatile i8 %19_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %20 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>[-]
+            known to be 106
+            >>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<+++++++++++++++
+            >>>>>>>>]
+            <<<<<<<<+
+            %21 = add i8 %20_ i8 1
+            
+            <<<<<<<<<[-]
+            known to be 107
+            >>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<++
+            store volatile i8 %21_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<++
+            %22 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>>[-]
+            known to be 107
+            >>>>>>>+++++++
+            [
+                -
+                <<<<<<<+++++++++++++++
+            >>>>>>>]
+            <<<<<<<++
+            %23 = add i8 %22_ i8 1
+            
+            <<<<<<<<<<[-]
+            known to be 108
+            >>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %23_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %24 = load volatile i8* %1_ align 1
+            
+            <<<<<<[-]
+            known to be 108
+            >>>>>>+++++++++
+            [
+                -
+                <<<<<<++++++++++++
+            >>>>>>]
+            %25 = add i8 %24_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 109
+            >>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            store volatile i8 %25_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>+++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %26 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>>>>[-]
+            known to be 109
+            >>>>>+++++++++
+            [
+                -
+                <<<<<++++++++++++
+            >>>>>]
+            <<<<<+
+            %27 = add i8 %26_ i8 1
+            
+            <<<<<<<<<<<<[-]
+            known to be 110
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %27_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %28 = load volatile i8* %1_ align 1
+            
+            <<<<[-]
+            known to be 110
+            >>>>++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %29 = add i8 %28_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 111
+            >>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            store volatile i8 %29_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<+++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %30 = load volatile i8* %1_ align 1
+            
+            >>>>>>>>>>>>>>>>[-]
+            known to be 111
+            >>>++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %31 = add i8 %30_ i8 1
+            
+            <<<<<<<<<<<<<<[-]
+            known to be 112
+            >>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            store volatile i8 %31_ i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            %32 = load volatile i8* %1_ align 1
+            
+            <<[-]
+            known to be 112
+            >>++++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            %33 = add i8 %32_ i8 1
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 113
+            >>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+
+            store volatile i8 %33_ i8* %1_ align 1
+            
+            <<[-]
+            >>>>>>>>>>>>>>>>>>>++++++++
+            [
+                -
+                <<<<<<<<<<<<<<<<<<<++++++++++++++
+            >>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+            %34 = icmp ugt i8 %8_ i8 %6
+            
+            >>[-]
+            known to be 1
+            +
+            %35 = select i1 %34_ i8 %32_ i8 %2
+            
+            >>>>>>>>>>>>>>>>[-]
+            known to be 112
+            >++++++++
+            [
+                -
+                <++++++++++++++
+            >]
+            call @putchar(i8 %35)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_112
+            >>++++++++
+            [
+                -
+                <<++++++++++++++
+            >>]
+            <<.
+            [-]
+            %36 = icmp eq i8 %16_ i8 104
+            
+            <<<<<<<<<<<<<<<<<[-]
+            known to be 1
+            +
+            br i1 %36_ label %37_ label %38
+            
+            dup8/26/25 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov26/8 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<-
+                >+
+                #B:main/%37_true
+            >>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%38_false
+            <<]
+        <]
+        >>#B:%37
+        [
+            -
+            call @putchar(i8 %18)
+            
+            putchar intrinsic
+            dup16/28/27 >>>>>>>>>>>>[->>>>>>>>>>>>+<+<<<<<<<<<<<]
+            mov28/16 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <.
+            [-]
+            br label %38
+            
+            <<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%38
+        [
+            -
+            %39 = icmp ult i8 %22_ i8 %4
+            
+            >>>[-]
+            dup18/25/26 >>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov25/18 >>>>>>>[-<<<<<<<+>>>>>>>]
+            dup9/27/29 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<]
+            mov27/9 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov29/30 [->+<]
+            >>>+
+            <<<<<<[
+                -
+                
+                >>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>]
+            %40 = select i1 %39_ i8 33_ i8 %26
+            
+            <<<<<<[-]
+            dup8/25/26 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov25/8 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup20/29/30 <<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov29/20 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            >>+
+            <<<<<[
+                mov27/24 >[-<<<+>>>]
+                >>>[-]
+                <<<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov30/24 <[-<<<<<<+>>>>>>]
+                <<<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %40)
+            
+            putchar intrinsic
+            dup24/25/26 <<<<<<<[->+>+<<]
+            mov25/24 >[-<+>]
+            >.
+            [-]
+            call @putchar(i8 %32)
+            
+            putchar intrinsic
+            dup23/25/26 <<<[->>+>+<<<]
+            mov25/23 >>[-<<+>>]
+            >.
+            [-]
+            call @putchar(i8 %30)
+            
+            putchar intrinsic
+            dup22/25/26 <<<<[->>>+>+<<<<]
+            mov25/22 >>>[-<<<+>>>]
+            >.
+            [-]
+            call @putchar(i8 %28)
+            
+            putchar intrinsic
+            dup21/25/26 <<<<<[->>>>+>+<<<<<]
+            mov25/21 >>>>[-<<<<+>>>>]
+            >.
+            [-]
+            call @putchar(i8 %26)
+            
+            putchar intrinsic
+            dup20/25/26 <<<<<<[->>>>>+>+<<<<<<]
+            mov25/20 >>>>>[-<<<<<+>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %24)
+            
+            putchar intrinsic
+            dup19/25/26 <<<<<<<[->>>>>>+>+<<<<<<<]
+            mov25/19 >>>>>>[-<<<<<<+>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %22)
+            
+            putchar intrinsic
+            dup18/25/26 <<<<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov25/18 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %20)
+            
+            putchar intrinsic
+            dup17/25/26 <<<<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov25/17 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %18)
+            
+            putchar intrinsic
+            dup16/25/26 <<<<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov25/16 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            dup15/25/26 <<<<<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov25/15 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %14)
+            
+            putchar intrinsic
+            dup14/25/26 <<<<<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov25/14 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %12)
+            
+            putchar intrinsic
+            dup13/25/26 <<<<<<<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov25/13 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup12/25/26 <<<<<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov25/12 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            dup11/25/26 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov25/11 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            dup10/25/26 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov25/10 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup9/25/26 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov25/9 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %2)
+            
+            putchar intrinsic
+            dup7/25/26 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov25/7 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+    <<<<]
+<]
@@ -0,0 +1,322 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1971 ops ~35718 steps
+frame widths:
+  main: 20 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%entry
+        #B:%ret_lading_pad
+        #B:%for_cond_cleanup
+        #B:%for_body
+        #alloc_%last
+        #%last_0_last_0_last_0_11_=_load_volatile_i8*_%last__align_1(mult)
+        #%cmp_not12_=_icmp_ult_i8_%last_0_last_0_last_0_11__i8_97(mult)
+        #%c_013_=_phi_i8_{_i8_%inc__%for_body_}__{_i8_97__%entry_}(mult)
+        #%inc_=_add_i8_%c_013__i8_1(mult)
+        <#B:%entry
+        [
+            -
+            copy up args
+            %last = alloca i8_ align 1
+            store volatile i8 99_ i8* %last_ align 1
+            
+            >>>>[-]
+            >>>>>+++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %last_0_last_0_last_0_11 = load volatile i8* %last_ align 1
+            
+            <<<<[-]
+            known to be 99
+            >>>>>+++++++++
+            [
+                -
+                <<<<<+++++++++++
+            >>>>>]
+            %cmp_not12 = icmp ult i8 %last_0_last_0_last_0_11_ i8 97
+            
+            <<<<[-]
+            known to be 0
+            
+            br i1 %cmp_not12_ label %for_cond_cleanup_ label %for_body
+            
+            dup8/13/11 [->>>>>+<<+<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            doing phi stuff
+            %c_013 = phi i8 { i8 %inc_ %for_body }_ { i8 97_ %entry }
+            op_to_reg storing const value in temp address
+            #constop_97
+            >++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            mov12/15 [->>>+<<<]
+            <<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >+
+                #B:main/%for_cond_cleanup_true
+                >>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<[
+                -
+                >>+
+                #B:main/%for_body_false
+                >>>>[-]
+                mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<]
+        <]
+        >>#B:%for_cond_cleanup
+        [
+            -
+            %bump_inl0_0 = shl i8 3_ i8 1
+            
+            >>>[-]
+            known to be 6
+            ++++++
+            %add = add i8 %bump_inl0_0_ i8 64
+            
+            >[-]
+            known to be 70
+            >>>+++++++
+            [
+                -
+                <<<++++++++++
+            >>>]
+            call @putchar(i8 %add)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_70
+            >+++++++
+            [
+                -
+                <++++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        ]
+        >#B:%for_body
+        [
+            -
+            %c_013 = phi i8 { i8 %inc_ %for_body }_ { i8 97_ %entry }
+            %cmp4 = icmp eq i8 %c_013_ i8 122
+            
+            >>[-]
+            dup9/11/12 >>[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_122
+            >+++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/17 [->>>>+<<<<]
+            >>>>>>+
+            <<<<<<<[
+                -
+                >>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<]
+            >>>>>>>-
+            <<<<<<<<<<<<+
+            >>>>>>>>>>[
+                [-]
+                <<<<<<<<<<[-]
+            >>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            %_c_013 = select i1 %cmp4_ i8 33_ i8 %c_013
+            
+            <<<<<<<<[-]
+            dup7/11/12 <[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            dup9/15/16 <<<<[->>>>>>+>+<<<<<<<]
+            mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            >>+
+            <<<<<[
+                mov13/8 >[-<<<<<+>>>>>]
+                >>>[-]
+                <<<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov16/8 <[-<<<<<<<<+>>>>>>>>]
+                <<<[-]
+                <[-]
+            >>>>>]
+            call @putchar(i8 %_c_013)
+            
+            putchar intrinsic
+            dup8/11/12 <<<<<<<<<[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            >.
+            [-]
+            %inc = add i8 %c_013_ i8 1
+            
+            <<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov12/10 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/15/17 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov17/10 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov14/15 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov15/14 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %last_0_last_0_last_0_ = load volatile i8* %last_ align 1
+            
+            <<<<<<<[-]
+            dup6/7/11 <[->+>>>>+<<<<<]
+            mov11/6 >>>>>[-<<<<<+>>>>>]
+            %cmp_not = icmp ugt i8 %inc_ i8 %last_0_last_0_last_0_
+            
+            <<<[-]
+            dup10/11/12 >>[->+>+<<]
+            mov11/10 >[-<+>]
+            dup7/13/14 <<<<[->>>>>>+>+<<<<<<<]
+            mov13/7 >>>>>>[-<<<<<<+>>>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/15 <[->>>+<<<]
+            >>>>>+
+            <<<[
+                -
+                
+                >[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <]
+            >>>-
+            <<[
+                [-]
+                <<<<<<<+
+            >>>>>>>]
+            br i1 %cmp_not_ label %for_cond_cleanup_ label %for_body
+            
+            dup8/12/11 <<<<<<<[->>>>+<+<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            doing phi stuff
+            %c_013 = phi i8 { i8 %inc_ %for_body }_ { i8 97_ %entry }
+            dup10/13/14 <<[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/15 >[->+<]
+            <<<<<<<<<<<+
+            >>>>>>>>[
+                -
+                <<<<<<<<-
+                >+
+                #B:main/%for_cond_cleanup_true
+                >>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<[
+                -
+                >>+
+                #B:main/%for_body_false
+                >>>>[-]
+                mov15/9 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<]
+        >>]
+    <<<<]
+<]
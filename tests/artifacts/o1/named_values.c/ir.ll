@@ -0,0 +1,132 @@
+; ModuleID = './tests/artifacts/o1/named_values.c/ir.bc'
+source_filename = "./tests/cases/named_values.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %s) local_unnamed_addr #0 {
+entry:
+  %0 = load i8, i8* %s, align 1, !tbaa !5
+  %tobool.not3 = icmp eq i8 %0, 0
+  br i1 %tobool.not3, label %while.end, label %while.body
+
+while.body:                                       ; preds = %while.body, %entry
+  %1 = phi i8 [ %2, %while.body ], [ %0, %entry ]
+  %s.addr.04 = phi i8* [ %incdec.ptr, %while.body ], [ %s, %entry ]
+  call void @putchar(i8 noundef zeroext %1) #4
+  %incdec.ptr = getelementptr inbounds i8, i8* %s.addr.04, i64 1
+  %2 = load i8, i8* %incdec.ptr, align 1, !tbaa !5
+  %tobool.not = icmp eq i8 %2, 0
+  br i1 %tobool.not, label %while.end, label %while.body, !llvm.loop !8
+
+while.end:                                        ; preds = %while.body, %entry
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %n) local_unnamed_addr #0 {
+entry:
+  %tobool.not = icmp eq i32 %n, 0
+  br i1 %tobool.not, label %if.then, label %while.cond.preheader
+
+while.cond.preheader:                             ; preds = %entry
+  %cmp14 = icmp ugt i32 %n, 9
+  br i1 %cmp14, label %while.body, label %while.cond1.preheader
+
+if.then:                                          ; preds = %entry
+  call void @putchar(i8 noundef zeroext 48) #4
+  br label %return
+
+while.cond1.preheader:                            ; preds = %while.body, %while.cond.preheader
+  %digs.0.lcssa = phi i32 [ 1, %while.cond.preheader ], [ %mul, %while.body ]
+  %tobool2.not16 = icmp eq i32 %digs.0.lcssa, 0
+  br i1 %tobool2.not16, label %return, label %while.body3
+
+while.body:                                       ; preds = %while.body, %while.cond.preheader
+  %digs.015 = phi i32 [ %mul, %while.body ], [ 1, %while.cond.preheader ]
+  %mul = mul i32 %digs.015, 10
+  %div = udiv i32 %n, %mul
+  %cmp = icmp ugt i32 %div, 9
+  br i1 %cmp, label %while.body, label %while.cond1.preheader, !llvm.loop !11
+
+while.body3:                                      ; preds = %while.body3, %while.cond1.preheader
+  %digs.117 = phi i32 [ %div5, %while.body3 ], [ %digs.0.lcssa, %while.cond1.preheader ]
+  %div4 = udiv i32 %n, %digs.117
+  %rem = urem i32 %div4, 10
+  %0 = trunc i32 %rem to i8
+  %conv = or i8 %0, 48
+  call void @putchar(i8 noundef zeroext %conv) #4
+  %div5 = udiv i32 %digs.117, 10
+  %1 = icmp ult i32 %digs.117, 10
+  br i1 %1, label %return, label %while.body3, !llvm.loop !12
+
+return:                                           ; preds = %while.body3, %while.cond1.preheader, %if.then
+  ret void
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: nofree noinline norecurse nosync nounwind readnone uwtable
+define dso_local zeroext i8 @bump(i8 noundef zeroext %by) local_unnamed_addr #3 {
+entry:
+  %0 = shl i8 %by, 1
+  ret i8 %0
+}
+
+; Function Attrs: nounwind uwtable
+define dso_local void @main() local_unnamed_addr #0 {
+entry:
+  %last = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %last)
+  store volatile i8 99, i8* %last, align 1, !tbaa !5
+  %last.0.last.0.last.0.11 = load volatile i8, i8* %last, align 1, !tbaa !5
+  %cmp.not12 = icmp ult i8 %last.0.last.0.last.0.11, 97
+  br i1 %cmp.not12, label %for.cond.cleanup, label %for.body
+
+for.cond.cleanup:                                 ; preds = %for.body, %entry
+  %call = call zeroext i8 @bump(i8 noundef zeroext 3)
+  %add = add i8 %call, 64
+  call void @putchar(i8 noundef zeroext %add) #4
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %last)
+  ret void
+
+for.body:                                         ; preds = %for.body, %entry
+  %c.013 = phi i8 [ %inc, %for.body ], [ 97, %entry ]
+  %cmp4 = icmp eq i8 %c.013, 122
+  %.c.013 = select i1 %cmp4, i8 33, i8 %c.013
+  call void @putchar(i8 noundef zeroext %.c.013) #4
+  %inc = add i8 %c.013, 1
+  %last.0.last.0.last.0. = load volatile i8, i8* %last, align 1, !tbaa !5
+  %cmp.not = icmp ugt i8 %inc, %last.0.last.0.last.0.
+  br i1 %cmp.not, label %for.cond.cleanup, label %for.body, !llvm.loop !13
+}
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { argmemonly nofree nosync nounwind willreturn }
+attributes #3 = { nofree noinline norecurse nosync nounwind readnone uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #4 = { nounwind }
+
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
+!13 = distinct !{!13, !9, !10}
@@ -0,0 +1,834 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 6939 ops ~183185 steps
+frame widths:
+  main: 39 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l39 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%4
+        #B:%7
+        #B:%8
+        #B:%12
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_icmp_eq_i8_%2__i8_0(mult)
+        #%5_=_phi_i8_{_i8_%10__%8_}__{_i8_1__%0_}(mult)
+        #%6_=_phi_i8_{_i8_%9__%8_}__{_i8_0__%0_}(mult)
+        #%9_=_add_i8_%6__i8_1(mult)
+        #%10_=_add_i8_%5__i8_1(mult)
+        #%13_=_phi_i8_{_i8_0__%4_}__{_i8_%20__%12_}(mult)
+        #%15_=_add_i8_%13__i8_48(mult)
+        #%16_=_and_i8_%13__i8_1(mult)
+        #%17_=_icmp_eq_i8_%16__i8_0(mult)
+        #%20_=_add_i8_%13__i8_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 4_ i8* %1_ align 1
+            
+            >>>>>>[-]
+            ++++
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 4
+            ++++
+            %3 = icmp eq i8 %2_ i8 0
+            
+            >[-]
+            known to be 0
+            
+            br i1 %3_ label %7_ label %4
+            
+            dup10/21/20 [->>>>>>>>>>>+<+<<<<<<<<<<]
+            mov21/10 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            doing phi stuff
+            %5 = phi i8 { i8 %10_ %8 }_ { i8 1_ %0 }
+            op_to_reg storing const value in temp address
+            >#constop_1
+            +
+            mov22/23 [->+<]
+            doing phi stuff
+            %6 = phi i8 { i8 %9_ %8 }_ { i8 0_ %0 }
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            mov24/25 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%7_true
+                >>>>>>>>>>>>>>>>>>[-]
+                >>[-]
+            <<<<<]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%4_false
+                >>>>>>>[-]
+                mov23/11 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+                <<<<<<<<<<<[-]
+                mov25/12 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<]
+        <]
+        >>#B:%4
+        [
+            -
+            %5 = phi i8 { i8 %10_ %8 }_ { i8 1_ %0 }
+            %6 = phi i8 { i8 %9_ %8 }_ { i8 0_ %0 }
+            br label %12
+            
+            doing phi stuff
+            %13 = phi i8 { i8 0_ %4 }_ { i8 %20_ %12 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>#constop_0
+            
+            [-]
+            mov26/15 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+        <<<]
+        >#B:%7
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >>>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+        >#B:%8
+        [
+            -
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>>>>>>>#constop_32
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %9 = add i8 %6_ i8 1
+            
+            <<<<<<<<<<<<<<[-]
+            dup12/20/21 <[->>>>>>>>+>+<<<<<<<<<]
+            mov20/12 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/13 <[-<<<<<<<<+>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/24/26 <<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov26/13 >>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<<<<+
+                    >>>>>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<[-]
+                    >>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %10 = add i8 %5_ i8 1
+            
+            <<<<<<<<<<<<[-]
+            dup11/20/21 <<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov20/11 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/14 <[-<<<<<<<+>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/24/26 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov26/14 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %11 = icmp eq i8 %9_ i8 %2
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup13/20/21 >>>[->>>>>>>+>+<<<<<<<<]
+            mov20/13 >>>>>>>[-<<<<<<<+>>>>>>>]
+            dup9/22/23 <<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov22/9 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/25 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            br i1 %11_ label %7_ label %4
+            
+            dup10/21/20 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>+<+<<<<<<<<<<]
+            mov21/10 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            doing phi stuff
+            %5 = phi i8 { i8 %10_ %8 }_ { i8 1_ %0 }
+            mov14/22 <<<<<<<[->>>>>>>>+<<<<<<<<]
+            doing phi stuff
+            %6 = phi i8 { i8 %9_ %8 }_ { i8 0_ %0 }
+            dup13/23/24 <[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov23/13 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            mov24/25 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%7_true
+                >>>>>>>>>>>>>>>>>[-]
+                >>>[-]
+            <<<<<]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%4_false
+                >>>>>>>[-]
+                mov22/11 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+                <<<<<<<<<<[-]
+                mov25/12 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<]
+        >>>]
+        >#B:%12
+        [
+            -
+            %13 = phi i8 { i8 0_ %4 }_ { i8 %20_ %12 }
+            %14 = icmp eq i8 %13_ i8 %6
+            
+            >>>[-]
+            dup15/26/27 >>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov26/15 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            dup12/29/28 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<]
+            mov29/12 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov28/31 <[->>>+<<<]
+            >>>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <<<<<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %15 = add i8 %13_ i8 48
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup15/20/21 <[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov21/16 <<[-<<<<<+>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/25/27 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov27/16 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov24/25 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov25/24 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %16 = and i8 %13_ i8 1
+            
+            <<<<<<<<<<[-]
+            dup15/20/21 <<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov21/28 <[->>>>>>>+<<<<<<<]
+            mov22/34 >[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            >>>>>>>>+
+            >>>>>>+
+            <<<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup32/29/28 >>>>[-<<<+<+>>>>]
+                mov29/32 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup38/35/34 >>>>[-<<<+<+>>>>]
+                mov35/38 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<<[
+                    -
+                    mov37/23 >>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                <<<<<<]
+                >>>>>>[-]
+                dup27/24/25 <<<<<<<<<<[-<<<+>+>>]
+                mov24/27 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov24/23 >[-<+>]
+                >]
+                mov23/17 <<[-<<<<<<+>>>>>>]
+                continue?
+                dup28/23/24 >>>>>[-<<<<<+>+>>>>]
+                mov23/28 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup34/23/24 >>>>>>>>>>[-<<<<<<<<<<<+>+>>>>>>>>>>]
+                mov23/34 <<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>-
+            <<<<<<<<<[-]
+            scratch must be clean
+            <<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >>[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %17 = icmp eq i8 %16_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup17/20/21 <[->>>+>+<<<<]
+            mov20/17 >>>[-<<<+>>>]
+            <<+
+            >>>[
+                [-]
+                <<<[-]
+            >>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            %18 = select i1 %17_ i8 101_ i8 111
+            
+            <<<<[-]
+            dup18/20/21 >[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_101
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            <+
+            op_to_reg storing const value in temp address
+            >>#constop_111
+            >++++++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            >>+
+            <<<<<[
+                mov22/17 >[-<<<<<+>>>>>]
+                >>[-]
+                <<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov24/17 <<[-<<<<<<<+>>>>>>>]
+                <<[-]
+                <[-]
+            >>>>>]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %19 = select i1 %14_ i8 %15_ i8 %18
+            
+            <<<<<<<<[-]
+            dup10/20/21 <<<<<<<<[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov20/10 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            dup16/22/23 <<<<[->>>>>>+>+<<<<<<<]
+            mov22/16 >>>>>>[-<<<<<<+>>>>>>]
+            dup17/24/25 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov24/17 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >>+
+            <<<<<[
+                mov23/18 >>[-<<<<<+>>>>>]
+                >>[-]
+                <<<<[-]
+                >>>>>[-]
+            <<<<<]
+            >>>>>[
+                -
+                mov25/18 <[-<<<<<<<+>>>>>>>]
+                <<[-]
+                <<[-]
+            >>>>>]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %19)
+            
+            putchar intrinsic
+            dup18/20/21 <<<<<<<<[->>+>+<<<]
+            mov20/18 >>[-<<+>>]
+            >.
+            [-]
+            %20 = add i8 %13_ i8 1
+            
+            <<[-]
+            dup15/20/21 <<<<[->>>>>+>+<<<<<<]
+            mov20/15 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov21/19 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup19/24/26 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov26/19 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov23/24 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov24/23 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            scratch must be clean
+            <<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            %21 = icmp eq i8 %20_ i8 %5
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup19/20/21 >>>>>>>>>[->+>+<<]
+            mov20/19 >[-<+>]
+            dup11/22/23 <<<<<<<<<[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov22/11 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov23/25 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            br i1 %21_ label %8_ label %12
+            
+            dup10/21/20 <<<<<<<<<<<<<<<<<[->>>>>>>>>>>+<+<<<<<<<<<<]
+            mov21/10 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            doing phi stuff
+            %13 = phi i8 { i8 0_ %4 }_ { i8 %20_ %12 }
+            dup19/22/23 <<[->>>+>+<<<<]
+            mov22/19 >>>[-<<<+>>>]
+            mov23/24 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%8_true
+                >>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%12_false
+                >>>>>>>>[-]
+                mov24/15 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<]
+        >>>>]
+    <<<<<<]
+<]
@@ -0,0 +1,525 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  swap (depth 1)
+  main calls swap (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  swap: 2022 ops ~28510 steps
+  main: 964 ops ~4156 steps
+frame widths:
+  swap: 26 cells
+  main: 15 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#swap
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/swap
+            >>>-
+            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_swap
+        #F:swap
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #%3_=_load_i8*_%0__align_1(mult)
+        #%4_=_load_i8*_%1__align_1(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/9 <<<<<[->>>>>>>>>+<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/11 <[->>>>>>>>>>>+<<<<<<<<<<<]
+            r3 >>>
+            %3 = load i8* %0_ align 1
+            
+            >>>>>[-]
+            dup7/9/10 >>[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/15/16 <<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov15/0 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/17 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov17/13 [-<<<<+>>>>]
+                <<<<+++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov12/11 <[-<+>]
+                mov13/12 >[-<+>]
+                
+                mov10/13 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup10/11/13 <<<[->+>>+<<<]
+            mov11/10 >[-<+>]
+            >[
+                mov14/11 >>[-<<<+>>>]
+                mov13/14 <[->+<]
+                mov12/13 <[->+<]
+                r1 >
+                -
+            ]
+            mov13/5 >[-<<<<<<<<+>>>>>>>>]
+            %4 = load i8* %1_ align 1
+            
+            <<<<<<<[-]
+            dup8/9/10 >>[->+>+<<]
+            mov9/8 >[-<+>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/15/16 <<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov15/0 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/20 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<[
+                -
+                >>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<]
+            >>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov20/13 [-<<<<<<<+>>>>>>>]
+                <<<<<<<+++++++++++
+            >>>>>>>]
+            <<<<[
+                if neg
+                <<<+++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov12/11 <[-<+>]
+                mov13/12 >[-<+>]
+                
+                mov10/13 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup10/11/13 <<<[->+>>+<<<]
+            mov11/10 >[-<+>]
+            >[
+                mov14/11 >>[-<<<+>>>]
+                mov13/14 <[->+<]
+                mov12/13 <[->+<]
+                r1 >
+                -
+            ]
+            mov13/6 >[-<<<<<<<+>>>>>>>]
+            store i8 %4_ i8* %0_ align 1
+            
+            dup6/9/10 <<<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            dup7/11/12 <<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/23 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov23/15 [-<<<<<<<<+>>>>>>>>]
+                <<<<<<<<+++++++++++++
+            >>>>>>>>]
+            <<<<[
+                if neg
+                <<<<+++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov10/16 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                mov16/15 >[-<+>]
+                mov12/16 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov16/12 >>>>[-<<<<+>>>>]
+            <<[
+                mov17/13 >>>[-<<<<+>>>>]
+                
+                mov14/15 <<<[->+<]
+                r1 >
+                -
+            ]
+            store i8 %3_ i8* %1_ align 1
+            
+            dup5/9/10 <<<<<<<<<[->>>>+>+<<<<<]
+            mov9/5 >>>>[-<<<<+>>>>]
+            dup8/11/12 <[->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/20 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov20/15 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov10/16 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                mov16/15 >[-<+>]
+                mov12/16 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov16/12 >>>>[-<<<<+>>>>]
+            <<[
+                mov17/13 >>>[-<<<<+>>>>]
+                
+                mov14/15 <<<[->+<]
+                r1 >
+                -
+            ]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l8 <<<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l15 <<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:swap
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_i8*_%1__align_1(mult)
+        #%4_=_load_i8*_%2__align_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store i8 120_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>++++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            store i8 121_ i8* %2_ align 1
+            
+            <<<[-]
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            call @putchar(i8 120_ i8 121)
+            
+            putchar run intrinsic
+            >++++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<.
+            +
+            .
+            [-]
+            call @swap(i8* %1_ i8* %2)
+            
+            enable next block when we return
+            <<<<<#caller/%call_term_for_0
+            +
+            stack_width 15
+            ret_pad_width 4
+            copy up arg 0
+            #arg_0
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/12/13 <<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <++++++++
+            mov11/21 [->>>>>>>>>>+<<<<<<<<<<]
+            copy up arg 1
+            #arg_1
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/15/14 <<<<<<<<<<<<[->>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<]
+            mov14/0 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            r1 >
+            +++++++++
+            mov14/20 [->>>>>>+<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>#stack_ptr
+            +++++++++++++++++++++++
+            l1 <
+            dup0/23/24 <<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov24/0 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r15 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_swap
+            +
+            >#swap
+            +
+            >>#swap/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %3 = load i8* %1_ align 1
+            
+            >>>[-]
+            dup7/9/11 <<[->>+>>+<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup9/11/12 <<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            %4 = load i8* %2_ align 1
+            
+            <<[-]
+            dup8/10/11 <<[->>+>+<<<]
+            mov11/8 >>>[-<<<+>>>]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup10/11/12 <[->+>+<<]
+            mov11/10 >[-<+>]
+            >.
+            [-]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup9/11/12 <<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            call @putchar(i8 %4)
+            
+            putchar intrinsic
+            dup10/11/12 <<[->+>+<<]
+            mov11/10 >[-<+>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >]
+    <<<<]
+<<]
@@ -1,141 +1,179 @@
 ; ModuleID = './tests/artifacts/o1/print_fib.c/ir.bc'
 source_filename = "./tests/cases/print_fib.c"
 target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
-target triple = "x86_64-unknown-linux-gnu"
-
-; Function Attrs: nounwind sspstrong uwtable
-define void @putstr(i8* %0) local_unnamed_addr #0 {
-  %2 = icmp eq i8* %0, null
-  br i1 %2, label %9, label %3
-
-3:                                                ; preds = %1, %3
-  %4 = phi i8* [ %7, %3 ], [ %0, %1 ]
-  %5 = ptrtoint i8* %4 to i64
-  %6 = trunc i64 %5 to i8
-  call void @putchar(i8 zeroext %6) #2
-  %7 = getelementptr i8, i8* %4, i64 1
-  %8 = icmp eq i8* %7, null
-  br i1 %8, label %9, label %3, !llvm.loop !3
-
-9:                                                ; preds = %3, %1
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 {
+  %2 = load i8, i8* %0, align 1, !tbaa !5
+  %3 = icmp eq i8 %2, 0
+  br i1 %3, label %10, label %4
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @putchar(i8 noundef zeroext %5) #3
+  %7 = getelementptr inbounds i8, i8* %6, i64 1
+  %8 = load i8, i8* %7, align 1, !tbaa !5
+  %9 = icmp eq i8 %8, 0
+  br i1 %9, label %10, label %4, !llvm.loop !8
+
+10:                                               ; preds = %4, %1
   ret void
 }
 
-declare void @putchar(i8 zeroext) local_unnamed_addr #1
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
 
-; Function Attrs: nounwind sspstrong uwtable
-define void @putdec(i32 %0) local_unnamed_addr #0 {
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 {
   %2 = icmp eq i32 %0, 0
   br i1 %2, label %5, label %3
 
 3:                                                ; preds = %1
-  %4 = icmp sgt i32 %0, 10
+  %4 = icmp ugt i32 %0, 9
   br i1 %4, label %9, label %6
 
 5:                                                ; preds = %1
-  call void @putchar(i8 zeroext 48) #2
-  br label %23
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %22
 
 6:                                                ; preds = %9, %3
   %7 = phi i32 [ 1, %3 ], [ %11, %9 ]
   %8 = icmp eq i32 %7, 0
-  br i1 %8, label %23, label %14
+  br i1 %8, label %22, label %14
 
-9:                                                ; preds = %3, %9
+9:                                                ; preds = %9, %3
   %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
   %11 = mul i32 %10, 10
-  %12 = sdiv i32 %0, %11
-  %13 = icmp sgt i32 %12, 10
-  br i1 %13, label %9, label %6, !llvm.loop !6
+  %12 = udiv i32 %0, %11
+  %13 = icmp ugt i32 %12, 9
+  br i1 %13, label %9, label %6, !llvm.loop !11
 
-14:                                               ; preds = %6, %14
+14:                                               ; preds = %14, %6
   %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
-  %16 = sdiv i32 %0, %15
-  %17 = srem i32 %16, 10
+  %16 = udiv i32 %0, %15
+  %17 = urem i32 %16, 10
   %18 = trunc i32 %17 to i8
-  %19 = add i8 %18, 48
-  call void @putchar(i8 zeroext %19) #2
-  %20 = sdiv i32 %15, 10
-  %21 = add i32 %15, 9
-  %22 = icmp ult i32 %21, 19
-  br i1 %22, label %23, label %14, !llvm.loop !7
-
-23:                                               ; preds = %14, %6, %5
+  %19 = or i8 %18, 48
+  call void @putchar(i8 noundef zeroext %19) #3
+  %20 = udiv i32 %15, 10
+  %21 = icmp ult i32 %15, 10
+  br i1 %21, label %22, label %14, !llvm.loop !12
+
+22:                                               ; preds = %14, %6, %5
   ret void
 }
 
-; Function Attrs: nounwind sspstrong uwtable
-define void @printi(i32 %0) local_unnamed_addr #0 {
+; Function Attrs: nounwind uwtable
+define dso_local void @printi(i32 noundef %0) local_unnamed_addr #0 {
   br label %2
 
-2:                                                ; preds = %12, %1
-  %3 = phi i32 [ %0, %1 ], [ %14, %12 ]
+2:                                                ; preds = %14, %1
+  %3 = phi i32 [ %0, %1 ], [ %16, %14 ]
   %4 = icmp sgt i32 %3, 10
-  br i1 %4, label %5, label %12
+  br i1 %4, label %5, label %14
 
 5:                                                ; preds = %2
-  %6 = add i32 %3, -11
-  %7 = udiv i32 %6, 10
-  %8 = add i32 %3, -10
-  %9 = mul i32 %7, -10
-  %10 = add i32 %9, %8
-  %11 = add nuw nsw i32 %7, 1
-  br label %12
-
-12:                                               ; preds = %5, %2
-  %13 = phi i32 [ %3, %2 ], [ %10, %5 ]
-  %14 = phi i32 [ 0, %2 ], [ %11, %5 ]
-  %15 = trunc i32 %13 to i8
-  %16 = add i8 %15, 48
-  call void @putchar(i8 zeroext %16) #2
-  %17 = icmp eq i32 %14, 0
-  br i1 %17, label %18, label %2, !llvm.loop !8
-
-18:                                               ; preds = %12
+  %6 = add i32 %3, 9
+  %7 = call i32 @llvm.smin.i32(i32 %3, i32 20)
+  %8 = sub i32 %6, %7
+  %9 = udiv i32 %8, 10
+  %10 = add i32 %3, -10
+  %11 = mul i32 %9, -10
+  %12 = add i32 %11, %10
+  %13 = add nuw nsw i32 %9, 1
+  br label %14
+
+14:                                               ; preds = %5, %2
+  %15 = phi i32 [ %3, %2 ], [ %12, %5 ]
+  %16 = phi i32 [ 0, %2 ], [ %13, %5 ]
+  %17 = trunc i32 %15 to i8
+  %18 = add i8 %17, 48
+  call void @putchar(i8 noundef zeroext %18) #3
+  %19 = icmp eq i32 %16, 0
+  br i1 %19, label %20, label %2, !llvm.loop !13
+
+20:                                               ; preds = %14
   ret void
 }
 
-; Function Attrs: nounwind sspstrong uwtable
-define i32 @main() local_unnamed_addr #0 {
+; Function Attrs: nounwind uwtable
+define dso_local i32 @main() local_unnamed_addr #0 {
   br label %2
 
-1:                                                ; preds = %9
+1:                                                ; preds = %28
   ret i32 0
 
-2:                                                ; preds = %0, %9
-  %3 = phi i8 [ 1, %0 ], [ %10, %9 ]
-  %4 = phi i8 [ 0, %0 ], [ %11, %9 ]
-  %5 = phi i8 [ 0, %0 ], [ %3, %9 ]
+2:                                                ; preds = %28, %0
+  %3 = phi i8 [ 1, %0 ], [ %29, %28 ]
+  %4 = phi i8 [ 0, %0 ], [ %30, %28 ]
+  %5 = phi i8 [ 0, %0 ], [ %3, %28 ]
   %6 = zext i8 %3 to i32
-  call void @printi(i32 %6)
-  %7 = icmp eq i8 %4, 9
-  br i1 %7, label %9, label %8
-
-8:                                                ; preds = %2
-  call void @putchar(i8 zeroext 32) #2
-  br label %9
-
-9:                                                ; preds = %8, %2
-  %10 = add i8 %3, %5
-  %11 = add nuw nsw i8 %4, 1
-  %12 = icmp eq i8 %11, 10
-  br i1 %12, label %1, label %2, !llvm.loop !9
+  br label %7
+
+7:                                                ; preds = %19, %2
+  %8 = phi i32 [ %6, %2 ], [ %21, %19 ]
+  %9 = icmp ugt i32 %8, 10
+  br i1 %9, label %10, label %19
+
+10:                                               ; preds = %7
+  %11 = add nsw i32 %8, 9
+  %12 = call i32 @llvm.smin.i32(i32 %8, i32 20) #3
+  %13 = sub i32 %11, %12
+  %14 = udiv i32 %13, 10
+  %15 = add nsw i32 %8, -10
+  %16 = mul i32 %14, -10
+  %17 = add i32 %15, %16
+  %18 = add nuw nsw i32 %14, 1
+  br label %19
+
+19:                                               ; preds = %10, %7
+  %20 = phi i32 [ %8, %7 ], [ %17, %10 ]
+  %21 = phi i32 [ 0, %7 ], [ %18, %10 ]
+  %22 = trunc i32 %20 to i8
+  %23 = add i8 %22, 48
+  call void @putchar(i8 noundef zeroext %23) #3
+  %24 = icmp eq i32 %21, 0
+  br i1 %24, label %25, label %7, !llvm.loop !13
+
+25:                                               ; preds = %19
+  %26 = icmp eq i8 %4, 9
+  br i1 %26, label %28, label %27
+
+27:                                               ; preds = %25
+  call void @putchar(i8 noundef zeroext 32) #3
+  br label %28
+
+28:                                               ; preds = %27, %25
+  %29 = add i8 %3, %5
+  %30 = add nuw nsw i8 %4, 1
+  %31 = icmp eq i8 %30, 10
+  br i1 %31, label %1, label %2, !llvm.loop !14
 }
 
-attributes #0 = { nounwind sspstrong uwtable "disable-tail-calls"="false" "frame-pointer"="none" "less-precise-fpmad"="false" "min-legal-vector-width"="0" "no-infs-fp-math"="false" "no-jump-tables"="false" "no-nans-fp-math"="false" "no-signed-zeros-fp-math"="false" "no-trapping-math"="true" "stack-protector-buffer-size"="4" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" "unsafe-fp-math"="false" "use-soft-float"="false" }
-attributes #1 = { "disable-tail-calls"="false" "frame-pointer"="none" "less-precise-fpmad"="false" "no-infs-fp-math"="false" "no-nans-fp-math"="false" "no-signed-zeros-fp-math"="false" "no-trapping-math"="true" "stack-protector-buffer-size"="4" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" "unsafe-fp-math"="false" "use-soft-float"="false" }
-attributes #2 = { nounwind }
+; Function Attrs: nofree nosync nounwind readnone speculatable willreturn
+declare i32 @llvm.smin.i32(i32, i32) #2
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { nofree nosync nounwind readnone speculatable willreturn }
+attributes #3 = { nounwind }
 
-!llvm.module.flags = !{!0, !1}
-!llvm.ident = !{!2}
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
 
 !0 = !{i32 1, !"wchar_size", i32 4}
 !1 = !{i32 7, !"PIC Level", i32 2}
-!2 = !{!"clang version 12.0.1"}
-!3 = distinct !{!3, !4, !5}
-!4 = !{!"llvm.loop.mustprogress"}
-!5 = !{!"llvm.loop.unroll.disable"}
-!6 = distinct !{!6, !4, !5}
-!7 = distinct !{!7, !4, !5}
-!8 = distinct !{!8, !4, !5}
-!9 = distinct !{!9, !4, !5}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
+!13 = distinct !{!13, !9, !10}
+!14 = distinct !{!14, !9, !10}
@@ -0,0 +1,1585 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 22700 ops ~476335 steps
+frame widths:
+  main: 59 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l59 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%0_printf_0_0_hundreds
+        #B:%0_printf_0_0_tens?
+        #B:%0_printf_0_0_tens
+        #B:%0_printf_0_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens
+        #B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+        #%printf_0_0_hundreds_=_udiv_i8_0__i8_100(mult)
+        #%printf_0_0_over9_=_udiv_i8_0__i8_10(mult)
+        #%printf_0_0_tens_=_urem_i8_%printf_0_0_over9__i8_10(mult)
+        #%printf_0_0_ones_=_urem_i8_0__i8_10(mult)
+        #%printf_0_0_any_hundreds_=_icmp_ne_i8_%printf_0_0_hundreds__i8_0(mult)
+        #%printf_1_0_hundreds_=_udiv_i8_7__i8_100(mult)
+        #%printf_1_0_over9_=_udiv_i8_7__i8_10(mult)
+        #%printf_1_0_tens_=_urem_i8_%printf_1_0_over9__i8_10(mult)
+        #%printf_1_0_ones_=_urem_i8_7__i8_10(mult)
+        #%printf_2_0_hundreds_=_udiv_i8_42__i8_100(mult)
+        #%printf_2_0_over9_=_udiv_i8_42__i8_10(mult)
+        #%printf_2_0_tens_=_urem_i8_%printf_2_0_over9__i8_10(mult)
+        #%printf_2_0_ones_=_urem_i8_42__i8_10(mult)
+        #%printf_3_0_hundreds_=_udiv_i8_255__i8_100(mult)
+        #%printf_3_0_over9_=_udiv_i8_255__i8_10(mult)
+        #%printf_3_0_tens_=_urem_i8_%printf_3_0_over9__i8_10(mult)
+        #%printf_3_0_ones_=_urem_i8_255__i8_10(mult)
+        #%printf_4_0_hundreds_=_udiv_i8_42__i8_100(mult)
+        #%printf_4_0_over9_=_udiv_i8_42__i8_10(mult)
+        #%printf_4_0_tens_=_urem_i8_%printf_4_0_over9__i8_10(mult)
+        #%printf_4_0_ones_=_urem_i8_42__i8_10(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %printf_0_0_hundreds = udiv i8 0_ i8 100
+            
+            >>>>>>>>>>>>>>>>>>>>>>[-]
+            known to be 0
+            
+            %printf_0_0_over9 = udiv i8 0_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_0_tens = urem i8 %printf_0_0_over9_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_0_ones = urem i8 0_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_0_any_hundreds = icmp ne i8 %printf_0_0_hundreds_ i8 0
+            
+            >[-]
+            known to be 0
+            
+            br i1 %printf_0_0_any_hundreds_ label %0_printf_0_0_hundreds_ label %0_printf_0_0_tens?
+            
+            dup28/46/45 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >+
+                #B:main/%0_printf_0_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%0_printf_0_0_tens?_false
+            <<]
+        <]
+        >>#B:%0_printf_0_0_hundreds
+        [
+            -
+            %printf_0_0_hundreds_digit = add i8 %printf_0_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup24/47/48 <<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov47/24 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_0_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_tens?
+        [
+            -
+            %printf_0_0_any_tens = icmp ne i8 %printf_0_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>>>>>>>>>[-]
+            dup25/45/46 <<<[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov45/25 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_0_0_any_tens_ label %0_printf_0_0_tens_ label %0_printf_0_0_ones
+            
+            dup28/46/45 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%0_printf_0_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%0_printf_0_0_ones_false
+            <<<<]
+        >>]
+        >#B:%0_printf_0_0_tens
+        [
+            -
+            %printf_0_0_tens_digit = add i8 %printf_0_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>>[-]
+            dup26/47/48 <<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov47/26 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/56/58 <<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov58/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>>>>>>>+
+                <<[
+                    -
+                    mov55/56 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov56/55 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<<]
+            >>>>>>[-]
+            scratch must be clean
+            <<<<<<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >>>>>[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_0_0_tens_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones
+        [
+            -
+            %printf_0_0_ones_digit = add i8 %printf_0_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>>[-]
+            dup27/45/46 <[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov45/27 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov46/28 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/50/52 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov52/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov49/50 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov50/49 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_0_0_ones_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %printf_1_0_hundreds = udiv i8 7_ i8 100
+            
+            <<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            %printf_1_0_over9 = udiv i8 7_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_1_0_tens = urem i8 %printf_1_0_over9_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_1_0_ones = urem i8 7_ i8 10
+            
+            >[-]
+            known to be 7
+            +++++++
+            %printf_1_0_any_hundreds = icmp ne i8 %printf_1_0_hundreds_ i8 0
+            
+            <<<<[-]
+            known to be 0
+            
+            br i1 %printf_1_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_tens?
+            
+            dup28/46/45 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_tens?_false
+            <<<<<<]
+        >>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_hundreds
+        [
+            -
+            %printf_1_0_hundreds_digit = add i8 %printf_1_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>>>[-]
+            dup29/47/48 >[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov47/29 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_1_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_tens?
+        [
+            -
+            %printf_1_0_any_tens = icmp ne i8 %printf_1_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>>>>>[-]
+            dup30/45/46 >>[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov45/30 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_1_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones
+            
+            dup28/46/45 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_false
+            <<<<<<<<]
+        >>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_tens
+        [
+            -
+            %printf_1_0_tens_digit = add i8 %printf_1_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>>>>>[-]
+            dup31/47/48 >>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov47/31 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_1_0_tens_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones
+        [
+            -
+            %printf_1_0_ones_digit = add i8 %printf_1_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>>>>>[-]
+            dup32/45/46 >>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov45/32 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov46/28 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/50/52 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov52/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov49/50 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov50/49 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_1_0_ones_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %printf_2_0_hundreds = udiv i8 42_ i8 100
+            
+            <<<<<<<<<<<<[-]
+            known to be 0
+            
+            %printf_2_0_over9 = udiv i8 42_ i8 10
+            
+            >[-]
+            known to be 4
+            ++++
+            %printf_2_0_tens = urem i8 %printf_2_0_over9_ i8 10
+            
+            >[-]
+            known to be 4
+            ++++
+            %printf_2_0_ones = urem i8 42_ i8 10
+            
+            >[-]
+            known to be 2
+            ++
+            %printf_2_0_any_hundreds = icmp ne i8 %printf_2_0_hundreds_ i8 0
+            
+            <<<<<<<<[-]
+            known to be 0
+            
+            br i1 %printf_2_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?
+            
+            dup28/46/45 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?_false
+            <<<<<<<<<<]
+        >>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_hundreds
+        [
+            -
+            %printf_2_0_hundreds_digit = add i8 %printf_2_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>>>>[-]
+            dup33/47/48 >>>>>[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov47/33 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_2_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens?
+        [
+            -
+            %printf_2_0_any_tens = icmp ne i8 %printf_2_0_over9_ i8 0
+            
+            >>>>>>>>>>>>>>>[-]
+            dup34/45/46 >>>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov45/34 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_2_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+            
+            dup28/46/45 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_false
+            <<<<<<<<<<<<]
+        >>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_tens
+        [
+            -
+            %printf_2_0_tens_digit = add i8 %printf_2_0_tens_ i8 48
+            
+            >>>>>>>>>>>>>>[-]
+            dup35/47/48 >>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov47/35 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_2_0_tens_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones
+        [
+            -
+            %printf_2_0_ones_digit = add i8 %printf_2_0_ones_ i8 48
+            
+            >>>>>>>>>>>>>[-]
+            dup36/45/46 >>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov45/36 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov46/28 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/50/52 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov52/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov49/50 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov50/49 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_2_0_ones_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %printf_3_0_hundreds = udiv i8 255_ i8 100
+            
+            <<<<<<<<[-]
+            known to be 2
+            ++
+            %printf_3_0_over9 = udiv i8 255_ i8 10
+            
+            >[-]
+            known to be 25
+            +++++++++++++++++++++++++
+            scratch must be clean
+            >>>>>>>[
+            ]
+            %printf_3_0_tens = urem i8 %printf_3_0_over9_ i8 10
+            
+            <<<<<<[-]
+            known to be 5
+            +++++
+            %printf_3_0_ones = urem i8 255_ i8 10
+            
+            >[-]
+            known to be 5
+            +++++
+            %printf_3_0_any_hundreds = icmp ne i8 %printf_3_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<[-]
+            known to be 1
+            +
+            br i1 %printf_3_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?
+            
+            dup28/46/45 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?_false
+            <<<<<<<<<<<<<<]
+        >>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_hundreds
+        [
+            -
+            %printf_3_0_hundreds_digit = add i8 %printf_3_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>[-]
+            dup37/47/48 >>>>>>>>>[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov47/37 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_3_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens?
+        [
+            -
+            %printf_3_0_any_tens = icmp ne i8 %printf_3_0_over9_ i8 0
+            
+            >>>>>>>>>>>[-]
+            dup38/45/46 >>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov45/38 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_3_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+            
+            dup28/46/45 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_false
+            <<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_tens
+        [
+            -
+            %printf_3_0_tens_digit = add i8 %printf_3_0_tens_ i8 48
+            
+            >>>>>>>>>>[-]
+            dup39/47/48 >>>>>>>>>>>[->>>>>>>>+>+<<<<<<<<<]
+            mov47/39 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_3_0_tens_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones
+        [
+            -
+            %printf_3_0_ones_digit = add i8 %printf_3_0_ones_ i8 48
+            
+            >>>>>>>>>[-]
+            dup40/45/46 >>>>>>>>>>>>[->>>>>+>+<<<<<<]
+            mov45/40 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov46/28 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/50/52 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov52/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov49/50 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov50/49 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_3_0_ones_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_32
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %printf_4_0_hundreds = udiv i8 42_ i8 100
+            
+            <<<<[-]
+            known to be 0
+            
+            %printf_4_0_over9 = udiv i8 42_ i8 10
+            
+            >[-]
+            known to be 4
+            ++++
+            %printf_4_0_tens = urem i8 %printf_4_0_over9_ i8 10
+            
+            >[-]
+            known to be 4
+            ++++
+            %printf_4_0_ones = urem i8 42_ i8 10
+            
+            >[-]
+            known to be 2
+            ++
+            %printf_4_0_any_hundreds = icmp ne i8 %printf_4_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            br i1 %printf_4_0_any_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?
+            
+            dup28/46/45 [->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?_false
+            <<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_hundreds
+        [
+            -
+            %printf_4_0_hundreds_digit = add i8 %printf_4_0_hundreds_ i8 48
+            
+            >>>>>>>>[-]
+            dup41/47/48 >>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov47/41 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_4_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens?
+        [
+            -
+            %printf_4_0_any_tens = icmp ne i8 %printf_4_0_over9_ i8 0
+            
+            >>>>>>>[-]
+            dup42/45/46 >>>>>>>>>>>>>>[->>>+>+<<<<]
+            mov45/42 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            scratch must be clean
+            <[
+            ]
+            >[
+            ]
+            br i1 %printf_4_0_any_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens_ label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+            
+            dup28/46/45 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<]
+            mov46/28 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>>>>>>>>>+
+                #B:main/%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones_false
+            <<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>>>>>>>>>>>>]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_tens
+        [
+            -
+            %printf_4_0_tens_digit = add i8 %printf_4_0_tens_ i8 48
+            
+            >>>>>>[-]
+            dup43/47/48 >>>>>>>>>>>>>>>[->>>>+>+<<<<<]
+            mov47/43 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov48/28 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/52/54 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov54/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov51/52 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov52/51 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_4_0_tens_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones_printf_1_0_ones_printf_2_0_ones_printf_3_0_ones_printf_4_0_ones
+        [
+            -
+            %printf_4_0_ones_digit = add i8 %printf_4_0_ones_ i8 48
+            
+            >>>>>[-]
+            dup44/45/46 >>>>>>>>>>>>>>>>[->+>+<<]
+            mov45/44 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov46/28 <<[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup28/50/52 <<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov52/28 >>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov49/50 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov50/49 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            scratch must be clean
+            <<<<[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            >[
+            ]
+            call @putchar(i8 %printf_4_0_ones_digit)
+            
+            putchar intrinsic
+            dup28/45/46 <<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov45/28 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>>>>>>>>>]
+    <<<<<<<<<<<<<<<<<<<<<<]
+<]
@@ -0,0 +1,1417 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 20700 ops ~684146 steps
+frame widths:
+  main: 48 cells
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++
+r1 >
+
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+r1 >
+
+r1 >
+r4 >>>>
++++++++++++++++++++++++++++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%0_printf_0_0_hundreds
+        #B:%0_printf_0_0_tens?
+        #B:%0_printf_0_0_tens
+        #B:%0_printf_0_0_ones
+        #B:%0_printf_0_4_hundreds
+        #B:%0_printf_0_4_tens?
+        #B:%0_printf_0_4_tens
+        #B:%0_printf_0_4_ones
+        #B:%0_printf_0_6_hundreds
+        #B:%0_printf_0_6_tens?
+        #B:%0_printf_0_6_tens
+        #B:%0_printf_0_6_ones
+        #%1_=_call_@getchar()(mult)
+        #%2_=_add_i8_%1__i8_208(mult)
+        #%3_=_call_@getchar()(mult)
+        #%4_=_zext_i8_%2_to_i32(mult)
+        #%5_=_zext_i8_%3_to_i32(mult)
+        #%6_=_add_i8_%3__i8_208(mult)
+        #%7_=_mul_i8_%6__i8_%2(mult)
+        #%8_=_zext_i8_%7_to_i32(mult)
+        #%printf_0_0_hundreds_=_udiv_i8_%printf_0_0_byte__i8_100(mult)
+        #%printf_0_0_over9_=_udiv_i8_%printf_0_0_byte__i8_10(mult)
+        #%printf_0_0_tens_=_urem_i8_%printf_0_0_over9__i8_10(mult)
+        #%printf_0_0_ones_=_urem_i8_%printf_0_0_byte__i8_10(mult)
+        #%printf_0_4_hundreds_=_udiv_i8_%printf_0_4_byte__i8_100(mult)
+        #%printf_0_4_over9_=_udiv_i8_%printf_0_4_byte__i8_10(mult)
+        #%printf_0_4_tens_=_urem_i8_%printf_0_4_over9__i8_10(mult)
+        #%printf_0_4_ones_=_urem_i8_%printf_0_4_byte__i8_10(mult)
+        #%printf_0_6_hundreds_=_udiv_i8_%printf_0_6_byte__i8_100(mult)
+        #%printf_0_6_over9_=_udiv_i8_%printf_0_6_byte__i8_10(mult)
+        #%printf_0_6_tens_=_urem_i8_%printf_0_6_over9__i8_10(mult)
+        #%printf_0_6_ones_=_urem_i8_%printf_0_6_byte__i8_10(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = call @getchar()
+            
+            >>>>>>>>>>>>>>[-]
+            getchar intrinsic
+            ,
+            %2 = add i8 %1_ i8 208
+            
+            >[-]
+            dup16/36/37 <[->>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<]
+            mov36/16 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_208
+            >+++++++++++++
+            [
+                -
+                <++++++++++++++++
+            >]
+            mov37/17 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/41/43 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov43/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov40/41 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov41/40 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %3 = call @getchar()
+            
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            getchar intrinsic
+            ,
+            %4 = zext i8 %2 to i32
+            
+            >[-]
+            dup17/36/37 <<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            mov37/19 >[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            %5 = zext i8 %3 to i32
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup18/36/37 <<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov36/18 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            mov37/20 >[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %6 = add i8 %3_ i8 208
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup18/36/37 <<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov36/18 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_208
+            >+++++++++++++
+            [
+                -
+                <++++++++++++++++
+            >]
+            mov37/21 <<[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup21/45/47 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov47/21 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<[
+                -
+                >>>>>>>>+
+                <<[
+                    -
+                    mov44/45 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov45/44 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<<<]
+            >>>>>>[-]
+            %7 = mul i8 %6_ i8 %2
+            
+            <<<<<<<<<<<<<<<<<<<<<<[-]
+            dup21/36/37 <[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov36/21 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            dup17/38/39 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<]
+            mov38/17 >>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <[
+                -
+                mov37/43 <<[->>>>>>+<<<<<<]
+                >>>>>>[
+                    -
+                    <<<<<<+
+                    >>>>>+
+                    <<[
+                        -
+                        mov40/41 [->+<]
+                        <<<<<<<<<<<<<<<<<<+
+                        >>>>>>>>>>>>>>>>>>>>-
+                    <<]
+                    mov41/40 >[-<+>]
+                    >[
+                        -
+                        <<<<<<<<<<<<<<<<<<<<[-]
+                        >>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                    >>]
+                >]
+            <<<<]
+            <<[-]
+            >>>[-]
+            %8 = zext i8 %7 to i32
+            
+            <<<<<<<<<<<<<<<<<[-]
+            dup22/36/37 <[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov36/22 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            mov37/23 >[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            %printf_0_0_byte = trunc i32 %4 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<[-]
+            dup19/36/37 >>[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov36/19 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov37/17 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            %printf_0_0_hundreds = udiv i8 %printf_0_0_byte_ i8 100
+            
+            <<<<<<<<<<<<<[-]
+            dup17/36/37 <<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup38/41/42 <[->>>+>+<<<<]
+            mov42/38 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/43 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>>>]
+                mov43/37 >>>[-<<<<<<+>>>>>>]
+                dup38/41/42 <<<<<[->>>+>+<<<<]
+                mov42/38 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            %printf_0_0_over9 = udiv i8 %printf_0_0_byte_ i8 10
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup17/36/37 <<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup38/40/41 [->>+>+<<<]
+            mov41/38 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/42 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<<<-
+                >>>>>>>>>>>>>>]
+                mov42/37 >>>[-<<<<<+>>>>>]
+                dup38/40/41 <<<<[->>+>+<<<]
+                mov41/38 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %printf_0_0_tens = urem i8 %printf_0_0_over9_ i8 10
+            
+            <<<<<<<<<<<<<<[-]
+            dup25/36/37 <[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov36/25 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup38/40/41 [->>+>+<<<]
+            mov41/38 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/42 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov42/37 <<[-<<<<<+>>>>>]
+                dup38/40/41 <<<<[->>+>+<<<]
+                mov41/38 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov40/26 >[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<<-
+                >>>>>>>>>>>>>]
+            ]
+            mov39/26 [-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_0_ones = urem i8 %printf_0_0_byte_ i8 10
+            
+            <<<<<<<<<<<<<[-]
+            dup17/36/37 <<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup38/40/41 [->>+>+<<<]
+            mov41/38 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/42 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov42/37 <<[-<<<<<+>>>>>]
+                dup38/40/41 <<<<[->>+>+<<<]
+                mov41/38 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov40/27 >[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<<<<-
+                >>>>>>>>>>>>]
+            ]
+            mov39/27 [-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_0_any_hundreds = icmp ne i8 %printf_0_0_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup24/36/37 >>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov36/24 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_0_any_hundreds_ label %0_printf_0_0_hundreds_ label %0_printf_0_0_tens?
+            
+            dup17/37/36 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >+
+                #B:main/%0_printf_0_0_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>+
+                #B:main/%0_printf_0_0_tens?_false
+            <<]
+        <]
+        >>#B:%0_printf_0_0_hundreds
+        [
+            -
+            %printf_0_0_hundreds_digit = add i8 %printf_0_0_hundreds_ i8 48
+            
+            >>>>>>>>>>>>>[-]
+            dup24/38/39 >>>>>>>[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov38/24 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov39/17 <<[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/43/45 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov45/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov42/43 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov43/42 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_0_hundreds_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_0_tens?
+        [
+            -
+            %printf_0_0_any_tens = icmp ne i8 %printf_0_0_over9_ i8 0
+            
+            >>>>>>>>>>>>[-]
+            dup25/36/37 >>>>>>>>[->>>>>>>>>>>+>+<<<<<<<<<<<<]
+            mov36/25 >>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_0_any_tens_ label %0_printf_0_0_tens_ label %0_printf_0_0_ones
+            
+            dup17/37/36 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%0_printf_0_0_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%0_printf_0_0_ones_false
+            <<<<]
+        >>]
+        >#B:%0_printf_0_0_tens
+        [
+            -
+            %printf_0_0_tens_digit = add i8 %printf_0_0_tens_ i8 48
+            
+            >>>>>>>>>>>[-]
+            dup26/38/39 >>>>>>>>>[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov38/26 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov39/17 <<[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/43/45 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov45/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov42/43 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov43/42 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_0_tens_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_0_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_0_ones
+        [
+            -
+            %printf_0_0_ones_digit = add i8 %printf_0_0_ones_ i8 48
+            
+            >>>>>>>>>>[-]
+            dup27/36/37 >>>>>>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov36/27 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov37/17 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/41/43 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov43/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov40/41 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov41/40 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_0_ones_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32_ i8 42_ i8 32)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            ++++++++++
+            .
+            ----------
+            .
+            [-]
+            %printf_0_2_byte = trunc i32 %5 to i8
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup20/36/37 >>>[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+            mov36/20 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov37/17 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            call @putchar(i8 %printf_0_2_byte)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32_ i8 61_ i8 32)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            >>++++
+            [
+                -
+                <<+++++++
+            >>]
+            <<+
+            .
+            -----------------------------
+            .
+            [-]
+            %printf_0_4_byte = trunc i32 %8 to i8
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup23/36/37 >>>>>>[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov36/23 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov37/17 >[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            %printf_0_4_hundreds = udiv i8 %printf_0_4_byte_ i8 100
+            
+            <<<<<<<<<[-]
+            dup17/36/37 <<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_100
+            >++++++++++
+            [
+                -
+                <++++++++++
+            >]
+            dup38/41/42 <[->>>+>+<<<<]
+            mov42/38 >>>>[-<<<<+>>>>]
+            <<<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/43 [->>>>>>+<<<<<<]
+                >>>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<<<+
+                >>>>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<<<-
+                >>>>>>>>>>>>]
+                mov43/37 >>>[-<<<<<<+>>>>>>]
+                dup38/41/42 <<<<<[->>>+>+<<<<]
+                mov42/38 >>>>[-<<<<+>>>>]
+            <<<<<]
+            >[-]
+            >>>[-]
+            %printf_0_4_over9 = udiv i8 %printf_0_4_byte_ i8 10
+            
+            <<<<<<<<<<<<[-]
+            dup17/36/37 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup38/40/41 [->>+>+<<<]
+            mov41/38 >>>[-<<<+>>>]
+            <<<<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/42 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                <<<<<<<<<<<<<<<+
+                >>>>>>>>>>[
+                    [-]
+                    <<<<<<<<<<-
+                >>>>>>>>>>]
+                mov42/37 >>>[-<<<<<+>>>>>]
+                dup38/40/41 <<<<[->>+>+<<<]
+                mov41/38 >>>[-<<<+>>>]
+            <<<<]
+            >[-]
+            >>[-]
+            %printf_0_4_tens = urem i8 %printf_0_4_over9_ i8 10
+            
+            <<<<<<<<<<[-]
+            dup29/36/37 <[->>>>>>>+>+<<<<<<<<]
+            mov36/29 >>>>>>>[-<<<<<<<+>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup38/40/41 [->>+>+<<<]
+            mov41/38 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/42 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov42/37 <<[-<<<<<+>>>>>]
+                dup38/40/41 <<<<[->>+>+<<<]
+                mov41/38 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov40/30 >[-<<<<<<<<<<+>>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<<-
+                >>>>>>>>>]
+            ]
+            mov39/30 [-<<<<<<<<<+>>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_4_ones = urem i8 %printf_0_4_byte_ i8 10
+            
+            <<<<<<<<<[-]
+            dup17/36/37 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            dup38/40/41 [->>+>+<<<]
+            mov41/38 >>>[-<<<+>>>]
+            <<#op0
+            #op1
+            #neg
+            <<[
+                #subnu_tmpb
+                #subnu_tmp0
+                #subnu_tmp1
+                mov37/42 [->>>>>+<<<<<]
+                >>>>>>>+
+                <<<<[
+                    -
+                    <+
+                    >>>[
+                        -
+                        <<<-
+                        r1 >
+                    >>>]
+                    r1 >
+                    [
+                        l1 <
+                    ]
+                    l1 <
+                <<]
+                >>>>-
+                mov42/37 <<[-<<<<<+>>>>>]
+                dup38/40/41 <<<<[->>+>+<<<]
+                mov41/38 >>>[-<<<+>>>]
+            <<<<]
+            >>#neg
+            [
+                mov40/31 >[-<<<<<<<<<+>>>>>>>>>]
+                <[
+                    -
+                    <<<<<<<<-
+                >>>>>>>>]
+            ]
+            mov39/31 [-<<<<<<<<+>>>>>>>>]
+            <[-]
+            >>[-]
+            %printf_0_4_any_hundreds = icmp ne i8 %printf_0_4_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup28/36/37 >>>>>>>>>>>[->>>>>>>>+>+<<<<<<<<<]
+            mov36/28 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_4_any_hundreds_ label %0_printf_0_4_hundreds_ label %0_printf_0_4_tens?
+            
+            dup17/37/36 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%0_printf_0_4_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>+
+                #B:main/%0_printf_0_4_tens?_false
+            <<<<<<]
+        >>>>]
+        >#B:%0_printf_0_4_hundreds
+        [
+            -
+            %printf_0_4_hundreds_digit = add i8 %printf_0_4_hundreds_ i8 48
+            
+            >>>>>>>>>[-]
+            dup28/38/39 >>>>>>>>>>>[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov38/28 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov39/17 <<[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/43/45 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov45/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov42/43 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov43/42 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_4_hundreds_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_4_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_4_tens?
+        [
+            -
+            %printf_0_4_any_tens = icmp ne i8 %printf_0_4_over9_ i8 0
+            
+            >>>>>>>>[-]
+            dup29/36/37 >>>>>>>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov36/29 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_4_any_tens_ label %0_printf_0_4_tens_ label %0_printf_0_4_ones
+            
+            dup17/37/36 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>+
+                #B:main/%0_printf_0_4_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>+
+                #B:main/%0_printf_0_4_ones_false
+            <<<<<<<<]
+        >>>>>>]
+        >#B:%0_printf_0_4_tens
+        [
+            -
+            %printf_0_4_tens_digit = add i8 %printf_0_4_tens_ i8 48
+            
+            >>>>>>>[-]
+            dup30/38/39 >>>>>>>>>>>>>[->>>>>>>>+>+<<<<<<<<<]
+            mov38/30 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov39/17 <<[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/43/45 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov45/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov42/43 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov43/42 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_4_tens_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_4_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_4_ones
+        [
+            -
+            %printf_0_4_ones_digit = add i8 %printf_0_4_ones_ i8 48
+            
+            >>>>>>[-]
+            dup31/36/37 >>>>>>>>>>>>>>[->>>>>+>+<<<<<<]
+            mov36/31 >>>>>[-<<<<<+>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov37/17 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/41/43 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov43/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov40/41 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov41/40 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_4_ones_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 44_ i8 32)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            ------------
+            .
+            [-]
+            %printf_0_6_byte = trunc i32 0 to i8
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            %printf_0_6_hundreds = udiv i8 %printf_0_6_byte_ i8 100
+            
+            >>>>>>>>>>>>>>>[-]
+            known to be 0
+            
+            %printf_0_6_over9 = udiv i8 %printf_0_6_byte_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_6_tens = urem i8 %printf_0_6_over9_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_6_ones = urem i8 %printf_0_6_byte_ i8 10
+            
+            >[-]
+            known to be 0
+            
+            %printf_0_6_any_hundreds = icmp ne i8 %printf_0_6_hundreds_ i8 0
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            known to be 0
+            
+            br i1 %printf_0_6_any_hundreds_ label %0_printf_0_6_hundreds_ label %0_printf_0_6_tens?
+            
+            dup17/37/36 [->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>+
+                #B:main/%0_printf_0_6_hundreds_true
+            >>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>+
+                #B:main/%0_printf_0_6_tens?_false
+            <<<<<<<<<<]
+        >>>>>>>>]
+        >#B:%0_printf_0_6_hundreds
+        [
+            -
+            %printf_0_6_hundreds_digit = add i8 %printf_0_6_hundreds_ i8 48
+            
+            >>>>>[-]
+            dup32/38/39 >>>>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov38/32 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov39/17 <<[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/43/45 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov45/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov42/43 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov43/42 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_6_hundreds_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_6_tens
+            
+            <<<<<<<<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%0_printf_0_6_tens?
+        [
+            -
+            %printf_0_6_any_tens = icmp ne i8 %printf_0_6_over9_ i8 0
+            
+            >>>>[-]
+            dup33/36/37 >>>>>>>>>>>>>>>>[->>>+>+<<<<]
+            mov36/33 >>>[-<<<+>>>]
+            >[
+                [-]
+                <<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>]
+            br i1 %printf_0_6_any_tens_ label %0_printf_0_6_tens_ label %0_printf_0_6_ones
+            
+            dup17/37/36 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<<<<<<]
+            mov37/17 >>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+                >>>>>>>>>>>+
+                #B:main/%0_printf_0_6_tens_true
+            >>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>>>>>>+
+                #B:main/%0_printf_0_6_ones_false
+            <<<<<<<<<<<<]
+        >>>>>>>>>>]
+        >#B:%0_printf_0_6_tens
+        [
+            -
+            %printf_0_6_tens_digit = add i8 %printf_0_6_tens_ i8 48
+            
+            >>>[-]
+            dup34/38/39 >>>>>>>>>>>>>>>>>[->>>>+>+<<<<<]
+            mov38/34 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov39/17 <<[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/43/45 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov45/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov42/43 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov43/42 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_6_tens_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            br label %0_printf_0_6_ones
+            
+            <<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%0_printf_0_6_ones
+        [
+            -
+            %printf_0_6_ones_digit = add i8 %printf_0_6_ones_ i8 48
+            
+            >>[-]
+            dup35/36/37 >>>>>>>>>>>>>>>>>>[->+>+<<]
+            mov36/35 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov37/17 <<[-<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/41/43 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov43/17 >>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov40/41 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov41/40 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %printf_0_6_ones_digit)
+            
+            putchar intrinsic
+            dup17/36/37 <<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<]
+            mov36/17 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 32_ i8 122_ i8 101_ i8 114_ i8 111_ i8 10)
+            
+            putchar run intrinsic
+            ++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            >>+++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            <<.
+            ---------------------
+            .
+            +++++++++++++
+            .
+            ---
+            .
+            -----------------------------------------------------------------------------------------------------
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>>>>>>>>]
+    <<<<<<<<<<<<<<]
+<]
+clear globals:
+l27 <<<<<<<<<<<<<<<<<<<<<<<<<<
+[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
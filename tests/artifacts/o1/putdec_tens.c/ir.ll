@@ -0,0 +1,308 @@
+; ModuleID = './tests/artifacts/o1/putdec_tens.c/ir.bc'
+source_filename = "./tests/cases/putdec_tens.c"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-pc-linux-gnu"
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putstr(i8* nocapture noundef readonly %0) local_unnamed_addr #0 {
+  %2 = load i8, i8* %0, align 1, !tbaa !5
+  %3 = icmp eq i8 %2, 0
+  br i1 %3, label %10, label %4
+
+4:                                                ; preds = %4, %1
+  %5 = phi i8 [ %8, %4 ], [ %2, %1 ]
+  %6 = phi i8* [ %7, %4 ], [ %0, %1 ]
+  call void @putchar(i8 noundef zeroext %5) #3
+  %7 = getelementptr inbounds i8, i8* %6, i64 1
+  %8 = load i8, i8* %7, align 1, !tbaa !5
+  %9 = icmp eq i8 %8, 0
+  br i1 %9, label %10, label %4, !llvm.loop !8
+
+10:                                               ; preds = %4, %1
+  ret void
+}
+
+declare void @putchar(i8 noundef zeroext) local_unnamed_addr #1
+
+; Function Attrs: nounwind uwtable
+define dso_local void @putdec(i32 noundef %0) local_unnamed_addr #0 {
+  %2 = icmp eq i32 %0, 0
+  br i1 %2, label %5, label %3
+
+3:                                                ; preds = %1
+  %4 = icmp ugt i32 %0, 9
+  br i1 %4, label %9, label %6
+
+5:                                                ; preds = %1
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %22
+
+6:                                                ; preds = %9, %3
+  %7 = phi i32 [ 1, %3 ], [ %11, %9 ]
+  %8 = icmp eq i32 %7, 0
+  br i1 %8, label %22, label %14
+
+9:                                                ; preds = %9, %3
+  %10 = phi i32 [ %11, %9 ], [ 1, %3 ]
+  %11 = mul i32 %10, 10
+  %12 = udiv i32 %0, %11
+  %13 = icmp ugt i32 %12, 9
+  br i1 %13, label %9, label %6, !llvm.loop !11
+
+14:                                               ; preds = %14, %6
+  %15 = phi i32 [ %20, %14 ], [ %7, %6 ]
+  %16 = udiv i32 %0, %15
+  %17 = urem i32 %16, 10
+  %18 = trunc i32 %17 to i8
+  %19 = or i8 %18, 48
+  call void @putchar(i8 noundef zeroext %19) #3
+  %20 = udiv i32 %15, 10
+  %21 = icmp ult i32 %15, 10
+  br i1 %21, label %22, label %14, !llvm.loop !12
+
+22:                                               ; preds = %14, %6, %5
+  ret void
+}
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.start.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: argmemonly nofree nosync nounwind willreturn
+declare void @llvm.lifetime.end.p0i8(i64 immarg, i8* nocapture) #2
+
+; Function Attrs: nounwind uwtable
+define dso_local void @main() local_unnamed_addr #0 {
+  %1 = alloca i8, align 1
+  %2 = alloca i8, align 1
+  %3 = alloca i8, align 1
+  %4 = alloca i8, align 1
+  %5 = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %1)
+  store volatile i8 100, i8* %1, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %2)
+  store volatile i8 10, i8* %2, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %3)
+  store volatile i8 9, i8* %3, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %4)
+  store volatile i8 0, i8* %4, align 1, !tbaa !5
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* nonnull %5)
+  store volatile i8 -1, i8* %5, align 1, !tbaa !5
+  %6 = load volatile i8, i8* %1, align 1, !tbaa !5
+  %7 = zext i8 %6 to i32
+  %8 = icmp eq i8 %6, 0
+  br i1 %8, label %11, label %9
+
+9:                                                ; preds = %0
+  %10 = icmp ugt i8 %6, 9
+  br i1 %10, label %15, label %12
+
+11:                                               ; preds = %0
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %28
+
+12:                                               ; preds = %15, %9
+  %13 = phi i32 [ 1, %9 ], [ %17, %15 ]
+  %14 = icmp eq i32 %13, 0
+  br i1 %14, label %28, label %20
+
+15:                                               ; preds = %15, %9
+  %16 = phi i32 [ %17, %15 ], [ 1, %9 ]
+  %17 = mul i32 %16, 10
+  %18 = udiv i32 %7, %17
+  %19 = icmp ugt i32 %18, 9
+  br i1 %19, label %15, label %12, !llvm.loop !11
+
+20:                                               ; preds = %20, %12
+  %21 = phi i32 [ %26, %20 ], [ %13, %12 ]
+  %22 = udiv i32 %7, %21
+  %23 = urem i32 %22, 10
+  %24 = trunc i32 %23 to i8
+  %25 = or i8 %24, 48
+  call void @putchar(i8 noundef zeroext %25) #3
+  %26 = udiv i32 %21, 10
+  %27 = icmp ult i32 %21, 10
+  br i1 %27, label %28, label %20, !llvm.loop !12
+
+28:                                               ; preds = %20, %12, %11
+  call void @putchar(i8 noundef zeroext 44) #3
+  %29 = load volatile i8, i8* %2, align 1, !tbaa !5
+  %30 = zext i8 %29 to i32
+  %31 = icmp eq i8 %29, 0
+  br i1 %31, label %34, label %32
+
+32:                                               ; preds = %28
+  %33 = icmp ugt i8 %29, 9
+  br i1 %33, label %38, label %35
+
+34:                                               ; preds = %28
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %51
+
+35:                                               ; preds = %38, %32
+  %36 = phi i32 [ 1, %32 ], [ %40, %38 ]
+  %37 = icmp eq i32 %36, 0
+  br i1 %37, label %51, label %43
+
+38:                                               ; preds = %38, %32
+  %39 = phi i32 [ %40, %38 ], [ 1, %32 ]
+  %40 = mul i32 %39, 10
+  %41 = udiv i32 %30, %40
+  %42 = icmp ugt i32 %41, 9
+  br i1 %42, label %38, label %35, !llvm.loop !11
+
+43:                                               ; preds = %43, %35
+  %44 = phi i32 [ %49, %43 ], [ %36, %35 ]
+  %45 = udiv i32 %30, %44
+  %46 = urem i32 %45, 10
+  %47 = trunc i32 %46 to i8
+  %48 = or i8 %47, 48
+  call void @putchar(i8 noundef zeroext %48) #3
+  %49 = udiv i32 %44, 10
+  %50 = icmp ult i32 %44, 10
+  br i1 %50, label %51, label %43, !llvm.loop !12
+
+51:                                               ; preds = %43, %35, %34
+  call void @putchar(i8 noundef zeroext 44) #3
+  %52 = load volatile i8, i8* %3, align 1, !tbaa !5
+  %53 = zext i8 %52 to i32
+  %54 = icmp eq i8 %52, 0
+  br i1 %54, label %57, label %55
+
+55:                                               ; preds = %51
+  %56 = icmp ugt i8 %52, 9
+  br i1 %56, label %61, label %58
+
+57:                                               ; preds = %51
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %74
+
+58:                                               ; preds = %61, %55
+  %59 = phi i32 [ 1, %55 ], [ %63, %61 ]
+  %60 = icmp eq i32 %59, 0
+  br i1 %60, label %74, label %66
+
+61:                                               ; preds = %61, %55
+  %62 = phi i32 [ %63, %61 ], [ 1, %55 ]
+  %63 = mul i32 %62, 10
+  %64 = udiv i32 %53, %63
+  %65 = icmp ugt i32 %64, 9
+  br i1 %65, label %61, label %58, !llvm.loop !11
+
+66:                                               ; preds = %66, %58
+  %67 = phi i32 [ %72, %66 ], [ %59, %58 ]
+  %68 = udiv i32 %53, %67
+  %69 = urem i32 %68, 10
+  %70 = trunc i32 %69 to i8
+  %71 = or i8 %70, 48
+  call void @putchar(i8 noundef zeroext %71) #3
+  %72 = udiv i32 %67, 10
+  %73 = icmp ult i32 %67, 10
+  br i1 %73, label %74, label %66, !llvm.loop !12
+
+74:                                               ; preds = %66, %58, %57
+  call void @putchar(i8 noundef zeroext 44) #3
+  %75 = load volatile i8, i8* %4, align 1, !tbaa !5
+  %76 = zext i8 %75 to i32
+  %77 = icmp eq i8 %75, 0
+  br i1 %77, label %80, label %78
+
+78:                                               ; preds = %74
+  %79 = icmp ugt i8 %75, 9
+  br i1 %79, label %84, label %81
+
+80:                                               ; preds = %74
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %97
+
+81:                                               ; preds = %84, %78
+  %82 = phi i32 [ 1, %78 ], [ %86, %84 ]
+  %83 = icmp eq i32 %82, 0
+  br i1 %83, label %97, label %89
+
+84:                                               ; preds = %84, %78
+  %85 = phi i32 [ %86, %84 ], [ 1, %78 ]
+  %86 = mul i32 %85, 10
+  %87 = udiv i32 %76, %86
+  %88 = icmp ugt i32 %87, 9
+  br i1 %88, label %84, label %81, !llvm.loop !11
+
+89:                                               ; preds = %89, %81
+  %90 = phi i32 [ %95, %89 ], [ %82, %81 ]
+  %91 = udiv i32 %76, %90
+  %92 = urem i32 %91, 10
+  %93 = trunc i32 %92 to i8
+  %94 = or i8 %93, 48
+  call void @putchar(i8 noundef zeroext %94) #3
+  %95 = udiv i32 %90, 10
+  %96 = icmp ult i32 %90, 10
+  br i1 %96, label %97, label %89, !llvm.loop !12
+
+97:                                               ; preds = %89, %81, %80
+  call void @putchar(i8 noundef zeroext 44) #3
+  %98 = load volatile i8, i8* %5, align 1, !tbaa !5
+  %99 = zext i8 %98 to i32
+  %100 = icmp eq i8 %98, 0
+  br i1 %100, label %103, label %101
+
+101:                                              ; preds = %97
+  %102 = icmp ugt i8 %98, 9
+  br i1 %102, label %107, label %104
+
+103:                                              ; preds = %97
+  call void @putchar(i8 noundef zeroext 48) #3
+  br label %120
+
+104:                                              ; preds = %107, %101
+  %105 = phi i32 [ 1, %101 ], [ %109, %107 ]
+  %106 = icmp eq i32 %105, 0
+  br i1 %106, label %120, label %112
+
+107:                                              ; preds = %107, %101
+  %108 = phi i32 [ %109, %107 ], [ 1, %101 ]
+  %109 = mul i32 %108, 10
+  %110 = udiv i32 %99, %109
+  %111 = icmp ugt i32 %110, 9
+  br i1 %111, label %107, label %104, !llvm.loop !11
+
+112:                                              ; preds = %112, %104
+  %113 = phi i32 [ %118, %112 ], [ %105, %104 ]
+  %114 = udiv i32 %99, %113
+  %115 = urem i32 %114, 10
+  %116 = trunc i32 %115 to i8
+  %117 = or i8 %116, 48
+  call void @putchar(i8 noundef zeroext %117) #3
+  %118 = udiv i32 %113, 10
+  %119 = icmp ult i32 %113, 10
+  br i1 %119, label %120, label %112, !llvm.loop !12
+
+120:                                              ; preds = %112, %104, %103
+  call void @putchar(i8 noundef zeroext 44) #3
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %5)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %4)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %3)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %2)
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* nonnull %1)
+  ret void
+}
+
+attributes #0 = { nounwind uwtable "frame-pointer"="none" "min-legal-vector-width"="0" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #1 = { "frame-pointer"="none" "no-trapping-math"="true" "stack-protector-buffer-size"="8" "target-cpu"="x86-64" "target-features"="+cx8,+fxsr,+mmx,+sse,+sse2,+x87" "tune-cpu"="generic" }
+attributes #2 = { argmemonly nofree nosync nounwind willreturn }
+attributes #3 = { nounwind }
+
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
+
+!0 = !{i32 1, !"wchar_size", i32 4}
+!1 = !{i32 7, !"PIC Level", i32 2}
+!2 = !{i32 7, !"PIE Level", i32 2}
+!3 = !{i32 7, !"uwtable", i32 1}
+!4 = !{!"Debian clang version 14.0.6"}
+!5 = !{!6, !6, i64 0}
+!6 = !{!"omnipotent char", !7, i64 0}
+!7 = !{!"Simple C/C++ TBAA"}
+!8 = distinct !{!8, !9, !10}
+!9 = !{!"llvm.loop.mustprogress"}
+!10 = !{!"llvm.loop.unroll.disable"}
+!11 = distinct !{!11, !9, !10}
+!12 = distinct !{!12, !9, !10}
@@ -0,0 +1,205 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 608 ops ~2631 steps
+frame widths:
+  main: 13 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l13 <<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_icmp_eq_i8_%3__i8_121(mult)
+        #%6_=_icmp_eq_i8_%5__i8_121(mult)
+        #%8_=_add_i8_%7__i8_245(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 121_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>>>+++++++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            store volatile i8 110_ i8* %2_ align 1
+            
+            <<<<<[-]
+            >>>>>>++++++++++
+            [
+                -
+                <<<<<<+++++++++++
+            >>>>>>]
+            %3 = load volatile i8* %1_ align 1
+            
+            <<<<<[-]
+            known to be 121
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %4 = icmp eq i8 %3_ i8 121
+            
+            <<<[-]
+            known to be 1
+            +
+            %5 = load volatile i8* %2_ align 1
+            
+            <[-]
+            known to be 110
+            >>>>++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %6 = icmp eq i8 %5_ i8 121
+            
+            <<[-]
+            known to be 0
+            
+            %7 = select i1 %4_ i8 89_ i8 78
+            
+            <<[-]
+            known to be 89
+            >>>>++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>#constop_89
+            >>++++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            .
+            [-]
+            %8 = add i8 %7_ i8 245
+            
+            <[-]
+            known to be 78
+            >++++++
+            [
+                -
+                <+++++++++++++
+            >]
+            %9 = select i1 %6_ i8 89_ i8 %8
+            
+            <<<<[-]
+            known to be 78
+            >>>>++++++
+            [
+                -
+                <<<<+++++++++++++
+            >>>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_78
+            >++++++
+            [
+                -
+                <+++++++++++++
+            >]
+            <.
+            [-]
+            %10 = select i1 %4_ i8 49_ i8 48
+            
+            <<<<[-]
+            known to be 49
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++
+            >>>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_49
+            >+++++++
+            [
+                -
+                <+++++++
+            >]
+            <.
+            [-]
+            %11 = select i1 %6_ i8 49_ i8 48
+            
+            <<<<[-]
+            known to be 48
+            >>>>++++++
+            [
+                -
+                <<<<++++++++
+            >>>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
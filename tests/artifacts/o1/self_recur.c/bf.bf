@@ -0,0 +1,389 @@
+eof: 0
+blocks made up by bfcc:
+  lol/%call_term_for_0 from %5 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  lol calls lol (depth unbounded_ recursive)
+  main calls lol (depth unbounded)
+  max depth from main: unbounded
+cost (loops go round 8 times):
+  lol: 3551 ops ~62428 steps
+  main: 301 ops ~917 steps
+frame widths:
+  lol: 28 cells
+  main: 8 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#lol
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/lol
+            >>>-
+            l28 <<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_lol
+        #F:lol
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%call_term_for_0
+        #B:%7
+        #%2_=_trunc_i32_%0_to_i8(mult)
+        #%3_=_add_i8_%2__i8_97(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/12 <<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]
+            r2 >>
+            %2 = trunc i32 %0 to i8
+            
+            >>>>>>>>[-]
+            dup10/11/12 >>[->+>+<<]
+            mov11/10 >[-<+>]
+            mov12/8 >[-<<<<+>>>>]
+            %3 = add i8 %2_ i8 97
+            
+            <<<[-]
+            dup8/11/13 <[->>>+>>+<<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >#constop_97
+            >>++++++++
+            [
+                -
+                <<++++++++++++
+            >>]
+            <<+
+            mov13/9 >[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/16/18 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov18/9 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<<[
+                -
+                >>>>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<]
+            >>>[-]
+            call @putchar(i8 %3)
+            
+            putchar intrinsic
+            dup9/11/12 <<<<<<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            %4 = icmp slt i32 %0_ i32 5
+            
+            <<<<[-]
+            dup10/11/12 >>[->+>+<<]
+            mov11/10 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_5
+            +++++
+            >>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/19 <<<[->>>>>>>+<<<<<<<]
+            >>>>>>>>>+
+            <<<<<<[
+                -
+                <+
+                >>>>>[
+                    -
+                    <<<<<-
+                    r1 >
+                >>>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<[
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<[
+                    -
+                    >>>>>-
+                <<<<<]
+                >>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<]
+            >>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov13/22 <<<<[->>>>>>>>>+<<<<<<<<<]
+            >>>>>>>>>>>+
+            <<<<<<<[
+                -
+                <+
+                >>>>>>[
+                    -
+                    <<<<<<-
+                    r1 >
+                >>>>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<]
+            >>>>>>>-
+            <<<<<<<<[
+                >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                <<<<<<[
+                    -
+                    >>>>>>-
+                <<<<<<]
+                >>>>>>++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            <<<<<<]
+            >>>>>>#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov22/25 [->>>+<<<]
+            >>>>>+
+            <<<<<<<<[
+                -
+                
+                >>>>>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<]
+            >>>>>>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>]
+            br i1 %4_ label %5_ label %7
+            
+            dup8/12/11 <<<<<<<<<<<<<<<<<[->>>>+<+<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            <<<<<<<<+
+            >>>>>>>[
+                -
+                <<<<<<<-
+                >+
+                #B:lol/%5_true
+            >>>>>>]
+            <<<<<<<[
+                -
+                >>>+
+                #B:lol/%7_false
+            <<<]
+        <]
+        >>#B:%5
+        [
+            -
+            %6 = add i32 %0_ i32 1
+            
+            >>>[-]
+            dup10/13/14 >>[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov14/8 <[-<<<<<<+>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/17/19 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov19/8 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov16/17 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov17/16 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            call @lol(i32 %6)
+            
+            enable next block when we return
+            <<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 28
+            ret_pad_width 4
+            copy up arg 0
+            >>#arg_0
+            dup8/11/12 [->>>+>+<<<<]
+            mov11/8 >>>[-<<<+>>>]
+            mov12/33 >[->>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++++++++++
+            l1 <
+            dup0/35/36 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov36/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r28 
+            <<<<<<<#JUMP_PAD
+            +
+            >#caller
+            +
+            >>>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_lol
+            +
+            >#lol
+            +
+            >>#lol/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            br label %7
+            
+            >+
+        <]
+        >#B:%7
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l8 <<<<<<<<
+        ]
+        #mainloop_main
+        #F:lol
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            call @lol(i32 0)
+            
+            enable next block when we return
+            >#caller/%call_term_for_0
+            +
+            stack_width 8
+            ret_pad_width 4
+            copy up arg 0
+            >#arg_0
+            op_to_reg storing const value in temp address
+            #constop_0
+            
+            mov7/13 [->>>>>>+<<<<<<]
+            give callee a stack pointer
+            >>>>>>>#stack_ptr
+            +++++++++++++++
+            l1 <
+            dup0/15/16 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov16/0 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            r1 
+            r8 
+            <<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r7 >>>
+            #===FRAME_lol
+            +
+            >#lol
+            +
+            >>#lol/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            <<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >]
+    <<<<]
+<<]
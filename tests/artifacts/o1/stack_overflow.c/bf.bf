@@ -0,0 +1,484 @@
+eof: 0
+blocks made up by bfcc:
+  down/%call_term_for_0 from %4 after call 0 (call continuation)
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  down calls down (depth unbounded_ recursive)
+  main calls down (depth unbounded)
+  max depth from main: unbounded
+cost (loops go round 8 times):
+  down: 2919 ops ~72576 steps
+  main: 827 ops ~14554 steps
+frame widths:
+  down: 22 cells
+  main: 9 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+stack guard wall:
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+
+>++
+>+++
+>++++
+>+++++
+>++++++
+>+++++++
+>++++++++
+>+++++++++
+>++++++++++
+>+++++++++++
+>++++++++++++
+>+++++++++++++
+>++++++++++++++
+>+++++++++++++++
+>++++++++++++++++
+>+++++++++++++++++
+>++++++++++++++++++
+>+++++++++++++++++++
+>++++++++++++++++++++
+>+++++++++++++++++++++
+>++++++++++++++++++++++
+>+++++++++++++++++++++++
+>++++++++++++++++++++++++
+>+++++++++++++++++++++++++
+>++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++
+>++++++++++++++++++++++++++++
+>+++++++++++++++++++++++++++++
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+    >#down
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/down
+            >>>-
+            l22 <<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_down
+        #F:down
+        #F:main
+        #B:%1
+        #B:%ret_lading_pad
+        #B:%4
+        #B:%call_term_for_0
+        #B:%6
+        #%2_=_add_i8_%0__i8_97(mult)
+        #%0(mult)
+        <#B:%1
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/11 <<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
+            r2 >>
+            %2 = add i8 %0_ i8 97
+            
+            >>>>>>>>[-]
+            dup9/10/11 >[->+>+<<]
+            mov10/9 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            mov11/8 <[-<<<+>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/15/17 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov17/8 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov14/15 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov15/14 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %2)
+            
+            putchar intrinsic
+            dup8/10/11 <<<<<<[->>+>+<<<]
+            mov10/8 >>[-<<+>>]
+            >.
+            [-]
+            %3 = icmp ult i8 %0_ i8 20
+            
+            <<<[-]
+            dup9/10/11 >[->+>+<<]
+            mov10/9 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_20
+            >++++
+            [
+                -
+                <+++++
+            >]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov12/14 <[->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                
+                >>>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<[
+                [-]
+                <<<<<<+
+            >>>>>>]
+            br i1 %3_ label %4_ label %6
+            
+            dup8/11/10 <<<<<<[->>>+<+<<]
+            mov11/8 >>>[-<<<+>>>]
+            <<<<<<<+
+            >>>>>>[
+                -
+                <<<<<<-
+                >+
+                #B:down/%4_true
+            >>>>>]
+            <<<<<<[
+                -
+                >>>+
+                #B:down/%6_false
+            <<<]
+        <]
+        >>#B:%4
+        [
+            -
+            %5 = add i8 %0_ i8 1
+            
+            >>>[-]
+            dup9/12/13 >[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov13/8 <[-<<<<<+>>>>>]
+            >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup8/19/21 <<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov21/8 >>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<<<<[
+                -
+                >>>>>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<<<<+
+                    >>>>>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<[-]
+                    >>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<]
+            >>>>[-]
+            call @down(i8 %5)
+            
+            enable next block when we return
+            <<<<<<<<<<<<#caller/%call_term_for_0
+            +
+            stack_width 22
+            ret_pad_width 4
+            stack guard
+            >>>>+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                [
+                    <[-]
+                    mov51/50 >[-<+>]
+                    l1 <
+                    -
+                ]
+                r51 
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                .
+                +
+                .
+                -------------------
+                .
+                ++
+                .
+                ++++++++
+                .
+                ++++
+                .
+                +++++++
+                .
+                -----------------
+                .
+                +++++++++++++
+                .
+                ------------
+                .
+                ++++++
+                .
+                +++
+                .
+                ++++++++
+                .
+                [-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                copy up arg 0
+                #arg_0
+                dup8/11/12 <<[->>>+>+<<<<]
+                mov11/8 >>>[-<<<+>>>]
+                mov12/27 >[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+                give callee a stack pointer
+                >>>>>>>>>>>>>>>>#stack_ptr
+                +++++++++++++++++++++++++++++
+                l1 <
+                dup0/29/30 <<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+                mov30/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                r1 
+                r22 
+                <<<<<<<#JUMP_PAD
+                +
+                >#caller
+                +
+                >>>#caller/jump_pad_blk
+                +
+                r7 >>>
+                #===FRAME_down
+                +
+                >#down
+                +
+                >>#down/b0
+                +
+            >>>>>>>]
+        <<<<<]
+        >#B:%call_term_for_0
+        [
+            -
+            call @putchar(i8 46)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>#constop_46
+            >+++++
+            [
+                -
+                <+++++++++
+            >]
+            <+
+            .
+            [-]
+            br label %6
+            
+            <<<+
+        <]
+        >#B:%6
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            <<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l7 
+        >]
+    <<<<<<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l9 <<<<<<<<<
+        ]
+        #mainloop_main
+        #F:down
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            call @down(i8 0)
+            
+            enable next block when we return
+            >#caller/%call_term_for_0
+            +
+            stack_width 9
+            ret_pad_width 4
+            stack guard
+            >+
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                [
+                    <[-]
+                    mov38/37 >[-<+>]
+                    l1 <
+                    -
+                ]
+                r38 
+                +++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                .
+                +
+                .
+                -------------------
+                .
+                ++
+                .
+                ++++++++
+                .
+                ++++
+                .
+                +++++++
+                .
+                -----------------
+                .
+                +++++++++++++
+                .
+                ------------
+                .
+                ++++++
+                .
+                +++
+                .
+                ++++++++
+                .
+                [-]
+            >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+                -
+                copy up arg 0
+                >#arg_0
+                op_to_reg storing const value in temp address
+                #constop_0
+                
+                mov8/14 [->>>>>>+<<<<<<]
+                give callee a stack pointer
+                >>>>>>>#stack_ptr
+                ++++++++++++++++
+                l1 <
+                dup0/16/17 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<]
+                mov17/0 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                r1 
+                r9 
+                <<<<<<<#JUMP_PAD
+                +
+                >>#caller
+                +
+                >>#caller/jump_pad_blk
+                +
+                r7 >>>
+                #===FRAME_down
+                +
+                >#down
+                +
+                >>#down/b0
+                +
+            >>>>]
+        <<]
+        >#B:%call_term_for_0
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            <<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >]
+    <<<<]
+<<]
+clear stack guard wall:
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
+>[-]
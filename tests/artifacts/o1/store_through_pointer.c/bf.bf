@@ -0,0 +1,542 @@
+eof: 0
+blocks made up by bfcc:
+  main/%call_term_for_0 from %0 after call 0 (call continuation)
+  main/%call_term_for_1 from %0 after call 1 (call continuation)
+  main/%no_block0_call_for_main from %0 (entry shim)
+call graph:
+  twice (depth 1)
+  main calls twice (depth 2)
+  max depth from main: 2
+cost (loops go round 8 times):
+  twice: 2320 ops ~45545 steps
+  main: 2041 ops ~29005 steps
+frame widths:
+  twice: 26 cells
+  main: 19 cells
+r5 >>>>>
++++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>>#main
++
+>#main/b0
++
+<<<[
+    >#twice
+    [
+        >>>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/twice
+            >>>-
+            l26 <<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_twice
+        #F:twice
+        #F:main
+        #B:%2
+        #B:%ret_lading_pad
+        #%3_=_load_i8*_%0__align_1(mult)
+        #%4_=_add_i8_%3__i8_1(mult)
+        #%0(mult)
+        #%1(mult)
+        <#B:%2
+        [
+            -
+            copy up args
+            #arg_%0
+            l2 
+            mov0/9 <<<<<[->>>>>>>>>+<<<<<<<<<]
+            r2 
+            #arg_%1
+            l3 
+            mov0/11 <[->>>>>>>>>>>+<<<<<<<<<<<]
+            r3 >>>
+            store i8 %1_ i8* %0_ align 1
+            
+            dup8/9/10 >>>>>>>>[->+>+<<]
+            mov9/8 >[-<+>]
+            dup7/11/12 <<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/20 [->>+<<]
+            >>>>+
+            <<<<<<<<<<[
+                -
+                >>>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<]
+            >>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov20/15 [-<<<<<+>>>>>]
+                <<<<<+++++++++++++
+            >>>>>]
+            <[
+                if neg
+                <<<<+++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov10/16 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                mov16/15 >[-<+>]
+                mov12/16 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov16/12 >>>>[-<<<<+>>>>]
+            <<[
+                mov17/13 >>>[-<<<<+>>>>]
+                
+                mov14/15 <<<[->+<]
+                r1 >
+                -
+            ]
+            call @putchar(i8 %1)
+            
+            putchar intrinsic
+            dup8/9/10 <<<<<<[->+>+<<]
+            mov9/8 >[-<+>]
+            >.
+            [-]
+            %3 = load i8* %0_ align 1
+            
+            <<<<<[-]
+            dup7/9/10 >>[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/15/16 <<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov15/0 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov15/17 [->>+<<]
+            >>>>+
+            <<<<<<<<<[
+                -
+                >>>>>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov17/13 [-<<<<+>>>>]
+                <<<<+++++++++++
+            >>>>]
+            <[
+                if neg
+                <<<+++++++++++
+                >>>[
+                    -
+                    <<<-
+                >>>]
+            ]
+            drive left! choo choo
+            <<<[
+                mov12/11 <[-<+>]
+                mov13/12 >[-<+>]
+                
+                mov10/13 <<<[->>>+<<<]
+                l1 
+                >>-
+                <+
+            >]
+            get our bag
+            dup10/11/13 <<<[->+>>+<<<]
+            mov11/10 >[-<+>]
+            >[
+                mov14/11 >>[-<<<+>>>]
+                mov13/14 <[->+<]
+                mov12/13 <[->+<]
+                r1 >
+                -
+            ]
+            mov13/5 >[-<<<<<<<<+>>>>>>>>]
+            %4 = add i8 %3_ i8 1
+            
+            <<<<<<<[-]
+            dup5/9/10 <[->>>>+>+<<<<<]
+            mov9/5 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov10/6 <[-<<<<+>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup6/13/15 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov15/6 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov12/13 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov13/12 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            store i8 %4_ i8* %0_ align 1
+            
+            dup6/9/10 <<<<<<[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            dup7/11/12 <<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            #train_tmp
+            #train_ret
+            #train_ptr
+            #train_cargo
+            #stackptr
+            #stackptr_tmp
+            l1 
+            dup0/18/19 <<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov18/0 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            r1 >
+            #ptr_underflow
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov18/23 [->>>>>+<<<<<]
+            >>>>>>>+
+            <<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<<<<<]
+            >>>>>>>>>>>>>-
+            <<#subnu_to
+            [
+                if pos
+                mov23/15 [-<<<<<<<<+>>>>>>>>]
+                <<<<<<<<+++++++++++++
+            >>>>>>>>]
+            <<<<[
+                if neg
+                <<<<+++++++++++++
+                >>>>[
+                    -
+                    <<<<-
+                >>>>]
+            ]
+            mov10/16 <<<<<<<<<[->>>>>>+<<<<<<]
+            drive left! choo choo
+            >>>>>[
+                mov14/13 <[-<+>]
+                mov15/14 >[-<+>]
+                mov16/15 >[-<+>]
+                mov12/16 <<<<[->>>>+<<<<]
+                l1 
+                >>-
+                <+
+            >]
+            <<<[-]
+            mov16/12 >>>>[-<<<<+>>>>]
+            <<[
+                mov17/13 >>>[-<<<<+>>>>]
+                
+                mov14/15 <<<[->+<]
+                r1 >
+                -
+            ]
+            ret void
+            
+            zero all function allocs
+            <<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l8 <<<<
+        ]
+    <<]
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<<-
+            #dead_frame
+            >>-
+            #dead_fn_pad/main
+            >>-
+            l19 <<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:twice
+        #F:main
+        #B:%no_block0_call_for_main
+        #B:%ret_lading_pad
+        #B:%0
+        #B:%call_term_for_0
+        #B:%call_term_for_1
+        #alloc_%1
+        #%2_=_load_i8*_%1__align_1(mult)
+        #%3_=_add_i8_%2__i8_1(mult)
+        <#B:%no_block0_call_for_main
+        [
+            -
+            copy up args
+            br label %0
+            
+            >>+
+        <<]
+        >>#B:%0
+        [
+            -
+            %1 = alloca i8_ align 1
+            store i8 65_ i8* %1_ align 1
+            
+            >>>[-]
+            >>>++++++++
+            [
+                -
+                <<<++++++++
+            >>>]
+            <<<+
+            call @putchar(i8 65)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>#constop_65
+            ++++++++
+            [
+                -
+                >++++++++
+            <]
+            >+
+            .
+            [-]
+            call @twice(i8* %1_ i8 65)
+            
+            enable next block when we return
+            <<<<<<#caller/%call_term_for_0
+            +
+            stack_width 19
+            ret_pad_width 4
+            copy up arg 0
+            #arg_0
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/12/14 <<<<<<<[->>>>>>>>>>>>+>>+<<<<<<<<<<<<<<]
+            mov14/0 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            r1 
+            <<+++++++++
+            mov11/25 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            copy up arg 1
+            >#arg_1
+            op_to_reg storing const value in temp address
+            #constop_65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            mov12/24 [->>>>>>>>>>>>+<<<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++
+            l1 <
+            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r19 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_twice
+            +
+            >#twice
+            +
+            >>#twice/b0
+            +
+        >>]
+        >#B:%call_term_for_0
+        [
+            -
+            %2 = load i8* %1_ align 1
+            
+            >>>[-]
+            dup8/9/11 <[->+>>+<<<]
+            mov11/8 >>>[-<<<+>>>]
+            call @putchar(i8 %2)
+            
+            putchar intrinsic
+            dup9/11/12 <<[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            >.
+            [-]
+            %3 = add i8 %2_ i8 1
+            
+            <<[-]
+            dup9/11/12 <[->>+>+<<<]
+            mov11/9 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov12/10 <[-<<+>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/16/18 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov18/10 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @twice(i8* %1_ i8 %3)
+            
+            enable next block when we return
+            <<<<<<<<#caller/%call_term_for_1
+            +
+            stack_width 19
+            ret_pad_width 4
+            copy up arg 0
+            #arg_0
+            resolve alloca addr storing pointer value in temp address
+            l1 
+            dup0/12/13 <<<<<<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov13/0 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            r1 
+            <+++++++++
+            mov11/25 [->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]
+            copy up arg 1
+            #arg_1
+            dup10/13/14 <[->>>+>+<<<<]
+            mov13/10 >>>[-<<<+>>>]
+            mov14/24 >[->>>>>>>>>>+<<<<<<<<<<]
+            give callee a stack pointer
+            >>>>>>>>>>>>#stack_ptr
+            +++++++++++++++++++++++++++
+            l1 <
+            dup0/27/28 <<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov28/0 >>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            r1 
+            r19 
+            <<<<<<<<#JUMP_PAD
+            +
+            >>#caller
+            +
+            >>#caller/jump_pad_blk
+            +
+            r8 >>>>
+            #===FRAME_twice
+            +
+            >#twice
+            +
+            >>#twice/b0
+            +
+        >>>]
+        >#B:%call_term_for_1
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<-
+            #dead_frame
+            >>-
+            l1 <
+            <<[-]
+            r1 
+            l6 
+        >>]
+    <<<<<]
+<<]
@@ -0,0 +1,702 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 10017 ops ~270249 steps
+frame widths:
+  main: 48 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l48 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%5
+        #B:%19
+        #B:%23
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_icmp_eq_i8_%3__i8_0(mult)
+        #%6_=_phi_i8_{_i8_%15__%5_}__{_i8_0__%0_}(mult)
+        #%7_=_phi_i8_{_i8_%14__%5_}__{_i8_1__%0_}(mult)
+        #%8_=_phi_i8_{_i8_%7__%5_}__{_i8_0__%0_}(mult)
+        #%9_=_phi_i32_{_i32_%16__%5_}__{_i32_0__%0_}(mult)
+        #%13_=_add_i32_%9__i32_%12(mult)
+        #%14_=_add_i8_%7__i8_%8(mult)
+        #%15_=_add_i8_%6__i8_1(mult)
+        #%16_=_and_i32_%13__i32_255(mult)
+        #%20_=_add_i8_%6__i8_49(mult)
+        #%22_=_add_i8_%21__i8_48(mult)
+        #%24_=_phi_i8_{_i8_48__%0_}__{_i8_%22__%19_}(mult)
+        #%25_=_phi_i8_{_i8_0__%0_}__{_i8_%7__%19_}(mult)
+        #%26_=_phi_i8_{_i8_48__%0_}__{_i8_%20__%19_}(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 4_ i8* %1_ align 1
+            
+            >>>>>[-]
+            ++++
+            store volatile i8 1_ i8* %2_ align 1
+            
+            >[-]
+            +
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 4
+            ++++
+            %4 = icmp eq i8 %3_ i8 0
+            
+            >[-]
+            known to be 0
+            
+            br i1 %4_ label %23_ label %5
+            
+            dup10/25/24 [->>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<]
+            mov25/10 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %24 = phi i8 { i8 48_ %0 }_ { i8 %22_ %19 }
+            op_to_reg storing const value in temp address
+            >#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov26/28 <[->>+<<]
+            doing phi stuff
+            %25 = phi i8 { i8 0_ %0 }_ { i8 %7_ %19 }
+            op_to_reg storing const value in temp address
+            >>>#constop_0
+            
+            mov29/30 [->+<]
+            doing phi stuff
+            %26 = phi i8 { i8 48_ %0 }_ { i8 %20_ %19 }
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov31/33 <[->>+<<]
+            doing phi stuff
+            %6 = phi i8 { i8 %15_ %5 }_ { i8 0_ %0 }
+            op_to_reg storing const value in temp address
+            >>>#constop_0
+            
+            mov34/35 [->+<]
+            doing phi stuff
+            %7 = phi i8 { i8 %14_ %5 }_ { i8 1_ %0 }
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov36/37 [->+<]
+            doing phi stuff
+            %8 = phi i8 { i8 %7_ %5 }_ { i8 0_ %0 }
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            mov38/39 [->+<]
+            doing phi stuff
+            %9 = phi i32 { i32 %16_ %5 }_ { i32 0_ %0 }
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            mov40/41 [->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>>+
+                #B:main/%23_true
+                >>>>>>>>>>>>>>>[-]
+                mov28/21 >>>>>>>[-<<<<<<<+>>>>>>>]
+                <<<<<<[-]
+                mov30/22 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                <<<<<<<[-]
+                mov33/23 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+                >>[-]
+                >>[-]
+                >>[-]
+                >>[-]
+            <<<<<<<<<<<<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%5_false
+                >>>>>>>[-]
+                mov35/11 >>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<[-]
+                mov37/12 >>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<<[-]
+                mov39/13 >>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<<<<<<<<[-]
+                mov41/14 >>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<[-]
+                >>[-]
+                >>>[-]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        <]
+        >>#B:%5
+        [
+            -
+            %6 = phi i8 { i8 %15_ %5 }_ { i8 0_ %0 }
+            %7 = phi i8 { i8 %14_ %5 }_ { i8 1_ %0 }
+            %8 = phi i8 { i8 %7_ %5 }_ { i8 0_ %0 }
+            %9 = phi i32 { i32 %16_ %5 }_ { i32 0_ %0 }
+            %10 = load volatile i8* %2_ align 1
+            
+            >>>>>[-]
+            dup8/9/42 <[->+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov42/8 >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]
+            %11 = icmp ne i8 %6_ i8 %10
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup11/24/25 >[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov24/11 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            dup9/26/27 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov26/9 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/29 <[->>>>+<<<<]
+            >>>>>>+
+            <<<<[
+                -
+                >+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>]
+            %12 = zext i1 %11 to i32
+            
+            <<<<<<<<<<<<<<<<<<<[-]
+            dup10/24/25 >[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov24/10 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            mov25/9 >[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            %13 = add i32 %9_ i32 %12
+            
+            <<<<<<<<<<[-]
+            dup14/24/25 <[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            dup9/26/27 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov26/9 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            mov25/15 <[-<<<<<<<<<<+>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup15/29/31 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov31/15 >>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %14 = add i8 %7_ i8 %8
+            
+            <<<<<<<<<<<<[-]
+            dup12/24/25 <<<<[->>>>>>>>>>>>+>+<<<<<<<<<<<<<]
+            mov24/12 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            dup13/26/27 <<<<<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov26/13 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov25/16 <[-<<<<<<<<<+>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup16/29/31 <<<<<<<<<<<<[->>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov31/16 >>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %15 = add i8 %6_ i8 1
+            
+            <<<<<<<<<<<[-]
+            dup11/24/25 <<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov24/11 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov25/17 <[-<<<<<<<<+>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/28/30 <<<<<<<<<<[->>>>>>>>>>>+>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov30/17 >>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov27/28 [->+<]
+                    <<<<<<<<<<+
+                    >>>>>>>>>>>>-
+                <<]
+                mov28/27 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<[-]
+                    >>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %16 = and i32 %13_ i32 255
+            
+            <<<<<<<<<[-]
+            dup15/24/25 <<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov24/15 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov25/33 <<[->>>>>>>>+<<<<<<<<]
+            mov26/43 >[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>+
+            >>>>>>>>>>+
+            <<<<<<<<<<<<<<+
+            [
+                [-]
+                >>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup37/34/33 >>>>[-<<<+<+>>>>]
+                mov34/37 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >>>>>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup47/44/43 >>>>[-<<<+<+>>>>]
+                mov44/47 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<<<<<<[
+                    -
+                    mov46/28 >>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<]
+                >>>>>>>>>>[-]
+                dup32/29/30 <<<<<<<<<<<<<<[-<<<+>+>>]
+                mov29/32 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov29/28 >[-<+>]
+                >]
+                mov28/18 <<[-<<<<<<<<<<+>>>>>>>>>>]
+                continue?
+                dup33/28/29 >>>>>[-<<<<<+>+>>>>]
+                mov28/33 <<<<<[->>>>>+<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup43/28/29 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>+>>>>>>>>>>>>>>]
+                mov28/43 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>-
+            >>>>>>>>>>-
+            <<<<<<<<<<<<<[-]
+            %17 = load volatile i8* %1_ align 1
+            
+            <<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup7/9/24 <<[->>+>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]
+            mov24/7 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            %18 = icmp eq i8 %15_ i8 %17
+            
+            <<<<<<<<<<<<<<[-]
+            dup17/24/25 >>>>>>>[->>>>>>>+>+<<<<<<<<]
+            mov24/17 >>>>>>>[-<<<<<<<+>>>>>>>]
+            dup9/26/27 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<]
+            mov26/9 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            >#subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov27/29 [->>+<<]
+            >>>>+
+            <<<<<<[
+                -
+                >>>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<]
+            >>>>>>-
+            <<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>>>>>>]
+            br i1 %18_ label %19_ label %5
+            
+            dup10/25/24 <<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<+<<<<<<<<<<<<<<]
+            mov25/10 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            doing phi stuff
+            %6 = phi i8 { i8 %15_ %5 }_ { i8 0_ %0 }
+            dup17/26/27 <<<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov26/17 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov27/28 >[->+<]
+            doing phi stuff
+            %7 = phi i8 { i8 %14_ %5 }_ { i8 1_ %0 }
+            mov16/29 <<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            doing phi stuff
+            %8 = phi i8 { i8 %7_ %5 }_ { i8 0_ %0 }
+            dup12/30/31 <<<<[->>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<]
+            mov30/12 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            mov31/32 >[->+<]
+            doing phi stuff
+            %9 = phi i32 { i32 %16_ %5 }_ { i32 0_ %0 }
+            mov18/33 <<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%19_true
+                >>>>>>>>>>>>>>>>>>>>>>>[-]
+                >[-]
+                >>>[-]
+                >[-]
+            <<<<<<<<<]
+            <<<<<<<<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%5_false
+                >>>>>>>[-]
+                mov28/11 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<[-]
+                mov29/12 >>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<[-]
+                mov32/13 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+                <<<<<<<<<<<<<<<<<<[-]
+                mov33/14 >>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]
+        >]
+        >#B:%19
+        [
+            -
+            %20 = add i8 %6_ i8 49
+            
+            >>>>>>>>>>>>>>[-]
+            dup11/34/35 <<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<]
+            mov34/11 >>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_49
+            >+++++++
+            [
+                -
+                <+++++++
+            >]
+            mov35/19 <<[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup19/39/41 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov41/19 >>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov38/39 [->+<]
+                    <<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov39/38 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            %21 = trunc i32 %13 to i8
+            
+            <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+            dup15/24/25 >>>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov24/15 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov25/9 >[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            %22 = add i8 %21_ i8 48
+            
+            <<<<<[-]
+            dup9/24/25 <<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov24/9 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov25/20 <<[-<<<<<+>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/29/31 <<<<<<<<[->>>>>>>>>+>>+<<<<<<<<<<<]
+            >>>>>>>>>[
+                -
+                <-
+            >]
+            mov31/20 >>[-<<<<<<<<<<<+>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<<<<<+
+                    >>>>>>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<[-]
+                    >>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            br label %23
+            
+            doing phi stuff
+            %24 = phi i8 { i8 48_ %0 }_ { i8 %22_ %19 }
+            mov20/24 <<<<<<<<[->>>>+<<<<]
+            doing phi stuff
+            %25 = phi i8 { i8 0_ %0 }_ { i8 %7_ %19 }
+            dup12/25/26 <<<<<<<<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov25/12 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            mov26/27 >[->+<]
+            doing phi stuff
+            %26 = phi i8 { i8 48_ %0 }_ { i8 %20_ %19 }
+            mov19/28 <<<<<<<[->>>>>>>>>+<<<<<<<<<]
+            >>[-]
+            mov24/21 >>>[-<<<+>>>]
+            <<[-]
+            mov27/22 >>>>>[-<<<<<+>>>>>]
+            <<<<[-]
+            mov28/23 >>>>>[-<<<<<+>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<+
+        <]
+        >#B:%23
+        [
+            -
+            %24 = phi i8 { i8 48_ %0 }_ { i8 %22_ %19 }
+            %25 = phi i8 { i8 0_ %0 }_ { i8 %7_ %19 }
+            %26 = phi i8 { i8 48_ %0 }_ { i8 %20_ %19 }
+            call @putchar(i8 %26)
+            
+            putchar intrinsic
+            dup23/29/30 >>>>>>>>>>>>>>>>>[->>>>>>+>+<<<<<<<]
+            mov29/23 >>>>>>[-<<<<<<+>>>>>>]
+            >.
+            [-]
+            call @putchar(i8 %24)
+            
+            putchar intrinsic
+            dup21/24/25 <<<<<<<<<[->>>+>+<<<<]
+            mov24/21 >>>[-<<<+>>>]
+            >.
+            [-]
+            %27 = add i8 %25_ i8 65
+            
+            <<<<<<<<<<<<<<<<[-]
+            dup22/24/25 >>>>>>>>>>>>>[->>+>+<<<]
+            mov24/22 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            mov25/9 <[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/29/31 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov31/9 >>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            call @putchar(i8 %27)
+            
+            putchar intrinsic
+            dup9/24/25 <<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<]
+            mov24/9 >>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            >.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>]
+    <<<<<]
+<]
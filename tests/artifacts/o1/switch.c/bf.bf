@@ -0,0 +1,351 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 986 ops ~4535 steps
+frame widths:
+  main: 11 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l11 <<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #%2_=_load_volatile_i8*_%1__align_1(mult)
+        #%3_=_add_i8_%2__i8_159(mult)
+        #%4_=_icmp_ult_i8_%3__i8_3(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            store volatile i8 97_ i8* %1_ align 1
+            
+            >>[-]
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            %2 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 97
+            >>>>++++++++
+            [
+                -
+                <<<<++++++++++++
+            >>>>]
+            <<<<+
+            %3 = add i8 %2_ i8 159
+            
+            >[-]
+            known to be 0
+            
+            %4 = icmp ult i8 %3_ i8 3
+            
+            >[-]
+            known to be 1
+            +
+            %5 = add i8 %2_ i8 224
+            
+            <[-]
+            known to be 65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            %6 = select i1 %4_ i8 %5_ i8 63
+            
+            <[-]
+            known to be 65
+            >>>++++++++
+            [
+                -
+                <<<++++++++
+            >>>]
+            <<<+
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>#constop_65
+            >>++++++++
+            [
+                -
+                <<++++++++
+            >>]
+            <<+
+            .
+            [-]
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            store volatile i8 98_ i8* %1_ align 1
+            
+            <<<<[-]
+            >>>>+++++++
+            [
+                -
+                <<<<++++++++++++++
+            >>>>]
+            %7 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 98
+            >>>+++++++
+            [
+                -
+                <<<++++++++++++++
+            >>>]
+            %8 = add i8 %7_ i8 159
+            
+            <<[-]
+            known to be 1
+            +
+            %9 = icmp ult i8 %8_ i8 3
+            
+            >[-]
+            known to be 1
+            +
+            %10 = add i8 %7_ i8 224
+            
+            <[-]
+            known to be 66
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            %11 = select i1 %9_ i8 %10_ i8 63
+            
+            <<<[-]
+            known to be 66
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            call @putchar(i8 %11)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_66
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            store volatile i8 99_ i8* %1_ align 1
+            
+            <<<<[-]
+            >>>>+++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            %12 = load volatile i8* %1_ align 1
+            
+            <<<[-]
+            known to be 99
+            >>>+++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            %13 = add i8 %12_ i8 159
+            
+            <<[-]
+            known to be 2
+            ++
+            %14 = icmp ult i8 %13_ i8 3
+            
+            >[-]
+            known to be 1
+            +
+            %15 = add i8 %12_ i8 224
+            
+            <[-]
+            known to be 67
+            >>++++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<+
+            %16 = select i1 %14_ i8 %15_ i8 63
+            
+            <[-]
+            known to be 67
+            >>>++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            call @putchar(i8 %16)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>#constop_67
+            >++++++
+            [
+                -
+                <+++++++++++
+            >]
+            <+
+            .
+            [-]
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            store volatile i8 122_ i8* %1_ align 1
+            
+            <<<<[-]
+            >>>>+++++++++++
+            [
+                -
+                <<<<+++++++++++
+            >>>>]
+            <<<<+
+            %17 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 122
+            >>>+++++++++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            <<<+
+            %18 = add i8 %17_ i8 159
+            
+            >[-]
+            known to be 25
+            >>+++++
+            [
+                -
+                <<+++++
+            >>]
+            %19 = icmp ult i8 %18_ i8 3
+            
+            <[-]
+            known to be 0
+            
+            %20 = add i8 %17_ i8 224
+            
+            <[-]
+            known to be 90
+            >>+++++++++
+            [
+                -
+                <<++++++++++
+            >>]
+            %21 = select i1 %19_ i8 %20_ i8 63
+            
+            <<<[-]
+            known to be 63
+            >>>+++++++
+            [
+                -
+                <<<+++++++++
+            >>>]
+            call @putchar(i8 %21)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_63
+            >+++++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            call @putchar(i8 44)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
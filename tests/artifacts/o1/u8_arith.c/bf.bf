@@ -0,0 +1,686 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 9543 ops ~254347 steps
+frame widths:
+  main: 38 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l38 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%4
+        #B:%7
+        #B:%13
+        #B:%16
+        #B:%17
+        #B:%20
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%5__%4_}(mult)
+        #%3_=_add_i32_%2__i32_65(mult)
+        #%5_=_add_i32_%2__i32_1(mult)
+        #%6_=_icmp_eq_i32_%5__i32_16(mult)
+        #%8_=_phi_i32_{_i32_0__%1_}__{_i32_%11__%7_}(mult)
+        #%10_=_trunc_i32_%9_to_i8(mult)
+        #%11_=_add_i32_%8__i32_1(mult)
+        #%14_=_phi_i32_{_i32_%18__%17_}__{_i32_0__%4_}(mult)
+        #%15_=_add_i32_%14__i32_65(mult)
+        #%18_=_add_i32_%14__i32_1(mult)
+        #%21_=_phi_i32_{_i32_0__%13_}__{_i32_%24__%20_}(mult)
+        #%24_=_add_i32_%21__i32_1(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            br label %1
+            
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>#constop_0
+            
+            [-]
+            mov23/11 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<+
+        <<]
+        >>#B:%1
+        [
+            -
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            %3 = add i32 %2_ i32 65
+            
+            >>>>>>>>[-]
+            dup11/24/25 <[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov24/11 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            mov25/12 <[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup12/29/31 <<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov31/12 >>[-<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov28/29 [->+<]
+                    <<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>-
+                <<]
+                mov29/28 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            br label %7
+            
+            doing phi stuff
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %11_ %7 }
+            op_to_reg storing const value in temp address
+            <<<<<#constop_0
+            
+            <<<<<<<<[-]
+            mov23/15 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            <<<<<<<<<<<<<<<<<+
+        <<]
+        >#B:%4
+        [
+            -
+            %5 = add i32 %2_ i32 1
+            
+            >>>>>>>>[-]
+            dup11/24/25 <<[->>>>>>>>>>>>>+>+<<<<<<<<<<<<<<]
+            mov24/11 >>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov25/13 <[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup13/28/30 <<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov30/13 >>[-<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov27/28 [->+<]
+                    <<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>-
+                <<]
+                mov28/27 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %6 = icmp eq i32 %5_ i32 16
+            
+            <<<<<<<<<<<<<[-]
+            dup13/23/24 <[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov23/13 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_16
+            ++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>]
+            br i1 %6_ label %13_ label %1
+            
+            dup14/24/23 <<<<<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            doing phi stuff
+            %14 = phi i32 { i32 %18_ %17 }_ { i32 0_ %4 }
+            op_to_reg storing const value in temp address
+            >#constop_0
+            
+            mov25/26 [->+<]
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %5_ %4 }
+            dup13/27/28 <<<<<<<<<<<<[->>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<]
+            mov27/13 >>>>>>>>>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+            mov28/29 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<-
+                >>>>+
+                #B:main/%13_true
+                >>>>>>>>>>>[-]
+                mov26/18 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+                >>>[-]
+            <<<<<<]
+            <<<<<<<<<<<<<<<<<<<<[
+                -
+                >+
+                #B:main/%1_false
+                >>>>>>>[-]
+                mov29/11 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                <<<[-]
+            <<<<<<<<<<<<<<<<<<<<<<<]
+        >>]
+        >#B:%7
+        [
+            -
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %11_ %7 }
+            %9 = add i32 %3_ i32 %8
+            
+            >>>>>>>>[-]
+            dup12/30/32 <<[->>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<]
+            mov30/12 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            dup15/31/33 <<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<]
+            mov31/15 >>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            mov32/14 >[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup14/35/37 <<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov37/14 >>[-<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov34/35 [->+<]
+                    <<<<<<<<<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>>>>>>>>-
+                <<]
+                mov35/34 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %10 = trunc i32 %9 to i8
+            
+            <<<<<<<<<<<<<<<<<<[-]
+            dup14/23/24 <<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov23/14 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov24/16 >[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %10)
+            
+            putchar intrinsic
+            dup16/23/24 <<<<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov23/16 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >.
+            [-]
+            %11 = add i32 %8_ i32 1
+            
+            <<<<<<<[-]
+            dup15/23/24 <<[->>>>>>>>+>+<<<<<<<<<]
+            mov23/15 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov24/17 <[-<<<<<<<+>>>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup17/27/29 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov29/17 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov26/27 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov27/26 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %12 = icmp eq i32 %11_ i32 16
+            
+            <<<<<<<<<<<<[-]
+            dup17/23/24 >>>[->>>>>>+>+<<<<<<<]
+            mov23/17 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_16
+            ++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>]
+            br i1 %12_ label %4_ label %7
+            
+            dup14/24/23 <<<<<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            doing phi stuff
+            %8 = phi i32 { i32 0_ %1 }_ { i32 %11_ %7 }
+            dup17/25/26 <<<<<<<[->>>>>>>>+>+<<<<<<<<<]
+            mov25/17 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            mov26/27 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<-
+                >>+
+                #B:main/%4_true
+                >>>>>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>+
+                #B:main/%7_false
+                >>>>>>>>>[-]
+                mov27/15 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>]
+        >#B:%13
+        [
+            -
+            %14 = phi i32 { i32 %18_ %17 }_ { i32 0_ %4 }
+            %15 = add i32 %14_ i32 65
+            
+            >>>>>>>>>>>>[-]
+            dup18/28/29 <[->>>>>>>>>>+>+<<<<<<<<<<<]
+            mov28/18 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_65
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            mov29/19 <[-<<<<<<<<<<+>>>>>>>>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup19/33/35 <<<<<<<<<<<<<[->>>>>>>>>>>>>>+>>+<<<<<<<<<<<<<<<<]
+            >>>>>>>>>>>>>>[
+                -
+                <-
+            >]
+            mov35/19 >>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]
+            <<<<<[
+                -
+                >>>>+
+                <<[
+                    -
+                    mov32/33 [->+<]
+                    <<<<<<<<<<<<<+
+                    >>>>>>>>>>>>>>>-
+                <<]
+                mov33/32 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<[-]
+                    >>>>>>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<]
+            >>[-]
+            br label %20
+            
+            doing phi stuff
+            %21 = phi i32 { i32 0_ %13 }_ { i32 %24_ %20 }
+            op_to_reg storing const value in temp address
+            <<<<<<<<<#constop_0
+            
+            <<[-]
+            mov23/21 >>[-<<+>>]
+            <<<<<<<<<<<<<+
+        <<<]
+        >#B:%16
+        [
+            -
+            ret void
+            
+            zero all function allocs
+            >>>[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<<<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >>>>]
+        >#B:%17
+        [
+            -
+            %18 = add i32 %14_ i32 1
+            
+            >>>>>>>>>>>[-]
+            dup18/24/25 <<[->>>>>>+>+<<<<<<<]
+            mov24/18 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov25/20 <[-<<<<<+>>>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup20/28/30 <<<<<<<[->>>>>>>>+>>+<<<<<<<<<<]
+            >>>>>>>>[
+                -
+                <-
+            >]
+            mov30/20 >>[-<<<<<<<<<<+>>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov27/28 [->+<]
+                    <<<<<<<+
+                    >>>>>>>>>-
+                <<]
+                mov28/27 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<[-]
+                    >>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %19 = icmp eq i32 %18_ i32 16
+            
+            <<<<<<<<<<<<<[-]
+            dup20/23/24 >>>>>>[->>>+>+<<<<]
+            mov23/20 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_16
+            ++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>]
+            br i1 %19_ label %16_ label %13
+            
+            dup14/24/23 <<<<<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            doing phi stuff
+            %14 = phi i32 { i32 %18_ %17 }_ { i32 0_ %4 }
+            dup20/25/26 <<<<[->>>>>+>+<<<<<<]
+            mov25/20 >>>>>[-<<<<<+>>>>>]
+            mov26/27 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<-
+                >>>>>+
+                #B:main/%16_true
+                >>>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>+
+                #B:main/%13_false
+                >>>>>>>>>>>[-]
+                mov27/18 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>]
+        >#B:%20
+        [
+            -
+            %21 = phi i32 { i32 0_ %13 }_ { i32 %24_ %20 }
+            %22 = sub i32 %15_ i32 %21
+            
+            >>>>[-]
+            dup19/28/29 >>>>>[->>>>>>>>>+>+<<<<<<<<<<]
+            mov28/19 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            dup21/30/31 <<<<<<<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov30/21 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov29/14 <[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]
+            >>[
+                -
+                >>+
+                <<<<<<<<<<<<<<<<<<<[
+                    -
+                    mov14/32 [->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]
+                    >>>>>>>>>>>>>>>>>>>-
+                <<<<<<<<<<<<<<<<<<<]
+                mov32/14 >>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]
+                >[
+                    -
+                    <<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>>>>>>>>>>>>>>>>>>]
+            <<]
+            %23 = trunc i32 %22 to i8
+            
+            <<<<<<<<<<<<<<<[-]
+            dup14/23/24 <<[->>>>>>>>>+>+<<<<<<<<<<]
+            mov23/14 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov24/16 >[-<<<<<<<<+>>>>>>>>]
+            call @putchar(i8 %23)
+            
+            putchar intrinsic
+            dup16/23/24 <<<<<<<<[->>>>>>>+>+<<<<<<<<]
+            mov23/16 >>>>>>>[-<<<<<<<+>>>>>>>]
+            >.
+            [-]
+            %24 = add i32 %21_ i32 1
+            
+            <<[-]
+            dup21/23/24 <[->>+>+<<<]
+            mov23/21 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov24/22 <[-<<+>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup22/27/29 <<<<[->>>>>+>>+<<<<<<<]
+            >>>>>[
+                -
+                <-
+            >]
+            mov29/22 >>[-<<<<<<<+>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov26/27 [->+<]
+                    <<<<+
+                    >>>>>>-
+                <<]
+                mov27/26 >[-<+>]
+                >[
+                    -
+                    <<<<<<[-]
+                    >>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %25 = icmp eq i32 %24_ i32 16
+            
+            <<<<<<<<<<<<[-]
+            dup22/23/24 >>>>>>>>[->+>+<<]
+            mov23/22 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_16
+            ++++++++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov25/27 [->>+<<]
+            >>>>+
+            <<<<<[
+                -
+                >>+
+                >[
+                    -
+                    <-
+                    r1 >
+                >]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<]
+            >>>>>-
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>[
+                [-]
+                <<<<<<<<<<<<<[-]
+            >>>>>>>>>>>>>]
+            <[
+                [-]
+                <<<<<<<<<<<<[-]
+            >>>>>>>>>>>>]
+            br i1 %25_ label %17_ label %20
+            
+            dup14/24/23 <<<<<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]
+            mov24/14 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+            doing phi stuff
+            %21 = phi i32 { i32 0_ %13 }_ { i32 %24_ %20 }
+            dup22/25/26 <<[->>>+>+<<<<]
+            mov25/22 >>>[-<<<+>>>]
+            mov26/27 >[->+<]
+            <<<<<<<<<<<<<<<<<<<<<<<+
+            >>>>>>>>>>>>>>>>>>>>[
+                -
+                <<<<<<<<<<<<<<<<<<<<-
+                >>>>>>+
+                #B:main/%17_true
+                >>>>>>>>>>>>>>>>>>[-]
+            <<<<]
+            <<<<<<<<<<<<<<<<<<<<[
+                -
+                >>>>>>>+
+                #B:main/%20_false
+                >>>>>>>>>>>[-]
+                mov27/21 >>>>>>[-<<<<<<+>>>>>>]
+            <<<<<<<<<<<<<<<<<<<<<<<<]
+        >>>>>>>]
+    <<<<<<<<<]
+<]
@@ -0,0 +1,355 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 2988 ops ~108821 steps
+frame widths:
+  main: 32 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l32 <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%9
+        #%2_=_phi_i32_{_i32_0__%0_}__{_i32_%7__%1_}(mult)
+        #%3_=_phi_i32_{_i32_0__%0_}__{_i32_%6__%1_}(mult)
+        #%4_=_trunc_i32_%3_to_i8(mult)
+        #%5_=_add_i8_%4__i8_48(mult)
+        #%6_=_add_i32_%2__i32_1(mult)
+        #%7_=_and_i32_%6__i32_255(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            br label %1
+            
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %7_ %1 }
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>#constop_0
+            
+            mov12/13 [->+<]
+            doing phi stuff
+            %3 = phi i32 { i32 0_ %0 }_ { i32 %6_ %1 }
+            op_to_reg storing const value in temp address
+            >>#constop_0
+            
+            mov14/15 [->+<]
+            <<<<<<<<[-]
+            mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<[-]
+            mov15/7 >>>>>>>>[-<<<<<<<<+>>>>>>>>]
+            <<<<<<<<<<<+
+        <<]
+        >>#B:%1
+        [
+            -
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %7_ %1 }
+            %3 = phi i32 { i32 0_ %0 }_ { i32 %6_ %1 }
+            %4 = trunc i32 %3 to i8
+            
+            >>>>[-]
+            dup7/16/17 <[->>>>>>>>>+>+<<<<<<<<<<]
+            mov16/7 >>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]
+            mov17/8 >[-<<<<<<<<<+>>>>>>>>>]
+            %5 = add i8 %4_ i8 48
+            
+            <<<<<<<<[-]
+            dup8/12/13 <[->>>>+>+<<<<<]
+            mov12/8 >>>>[-<<<<+>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_48
+            >++++++
+            [
+                -
+                <++++++++
+            >]
+            mov13/9 <<[-<<<<+>>>>]
+            >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup9/19/21 <<<<<<<<<[->>>>>>>>>>+>>+<<<<<<<<<<<<]
+            >>>>>>>>>>[
+                -
+                <-
+            >]
+            mov21/9 >>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<[
+                -
+                >>>>>>+
+                <<[
+                    -
+                    mov18/19 [->+<]
+                    <<<<<<<<<+
+                    >>>>>>>>>>>-
+                <<]
+                mov19/18 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<<<<[-]
+                    >>>>>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<<<<]
+            >>>>[-]
+            call @putchar(i8 %5)
+            
+            putchar intrinsic
+            dup9/12/13 <<<<<<<<<[->>>+>+<<<<]
+            mov12/9 >>>[-<<<+>>>]
+            >.
+            [-]
+            %6 = add i32 %2_ i32 1
+            
+            <<<[-]
+            dup6/12/13 <<<<[->>>>>>+>+<<<<<<<]
+            mov12/6 >>>>>>[-<<<<<<+>>>>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_1
+            +
+            mov13/10 <[-<<<+>>>]
+            >>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup10/16/18 <<<<<[->>>>>>+>>+<<<<<<<<]
+            >>>>>>[
+                -
+                <-
+            >]
+            mov18/10 >>[-<<<<<<<<+>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov15/16 [->+<]
+                    <<<<<+
+                    >>>>>>>-
+                <<]
+                mov16/15 >[-<+>]
+                >[
+                    -
+                    <<<<<<<[-]
+                    >>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %7 = and i32 %6_ i32 255
+            
+            <<<<[-]
+            dup10/12/13 <[->>+>+<<<]
+            mov12/10 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            >+++++++++++++++
+            [
+                -
+                <+++++++++++++++++
+            >]
+            #dub_scratch
+            #loop_ctrl
+            #nth
+            #op0div
+            #op1div
+            #op0_v
+            #op0_0
+            #op0_1
+            #op0_h
+            #op0_l
+            #op1_v
+            #op1_0
+            #op1_1
+            #op1_h
+            #op1_l
+            mov13/22 <<[->>>>>>>>>+<<<<<<<<<]
+            mov14/27 >[->>>>>>>>>>>>>+<<<<<<<<<<<<<]
+            >>>>>>>>>>+
+            >>>>>+
+            <<<<<<<<<<+
+            [
+                [-]
+                >>>[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup26/23/22 >>>>[-<<<+<+>>>>]
+                mov23/26 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                >[
+                    -
+                    >>>+
+                    <<<[
+                        -
+                        >>>>+
+                    <<<]
+                    >[
+                    <]
+                <]
+                dup31/28/27 >>>>[-<<<+<+>>>>]
+                mov28/31 <<<[->>>+<<<]
+                >>>[
+                    -
+                    <-
+                >]
+                <<<<<<[
+                    -
+                    mov30/16 >>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>]
+                <<<<<]
+                >>>>>[-]
+                dup20/17/18 <<<<<<<<<<[-<<<+>+>>]
+                mov17/20 <<<[->>>+<<<]
+                >[
+                    -
+                    <<[
+                        -
+                        >++
+                    <]
+                    mov17/16 >[-<+>]
+                >]
+                mov16/11 <<[-<<<<<+>>>>>]
+                continue?
+                dup22/16/17 >>>>>>[-<<<<<<+>+>>>>>]
+                mov16/22 <<<<<<[->>>>>>+<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                dup27/16/17 >>>>>>>>>>[-<<<<<<<<<<<+>+>>>>>>>>>>]
+                mov16/27 <<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]
+                >[
+                    [-]
+                    >>+
+                <<]
+                >>>+
+            <]
+            >>>>>-
+            >>>>>-
+            <<<<<<<<<[-]
+            %8 = icmp ult i32 %7_ i32 10
+            
+            <<<<<<<<<<<<[-]
+            dup11/12/13 >>>[->+>+<<]
+            mov12/11 >[-<+>]
+            op_to_reg storing const value in temp address
+            >>#constop_10
+            ++++++++++
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov14/15 [->+<]
+            >>>+
+            <<<<[
+                -
+                
+                >>[
+                    -
+                    
+                    r1 >
+                ]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<]
+            >>>>-
+            <<[
+                [-]
+                <<<<<<<+
+            >>>>>>>]
+            br i1 %8_ label %1_ label %9
+            
+            dup8/13/12 <<<<<<<[->>>>>+<+<<<<]
+            mov13/8 >>>>>[-<<<<<+>>>>>]
+            doing phi stuff
+            %2 = phi i32 { i32 0_ %0 }_ { i32 %7_ %1 }
+            dup11/14/15 <<[->>>+>+<<<<]
+            mov14/11 >>>[-<<<+>>>]
+            mov15/16 >[->+<]
+            doing phi stuff
+            %3 = phi i32 { i32 0_ %0 }_ { i32 %6_ %1 }
+            dup10/17/18 <<<<<[->>>>>>>+>+<<<<<<<<]
+            mov17/10 >>>>>>>[-<<<<<<<+>>>>>>>]
+            mov18/19 >[->+<]
+            <<<<<<<<<<<<<<<+
+            >>>>>>>>>[
+                -
+                <<<<<<<<<-
+                >+
+                #B:main/%1_true
+                >>[-]
+                mov16/6 >>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+                <<<<<<<<<[-]
+                mov19/7 >>>>>>>>>>>>[-<<<<<<<<<<<<+>>>>>>>>>>>>]
+            <<<<<<<]
+            <<<<<<<<<[
+                -
+                >>+
+                #B:main/%9_false
+                >>>>>>>>>>>[-]
+                >>>[-]
+            <<<<<<<<<<<<<<<<]
+        >]
+        >#B:%9
+        [
+            -
+            call @putchar(i8 33)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>>>>>>>>>>#constop_33
+            >++++
+            [
+                -
+                <++++++++
+            >]
+            <+
+            .
+            [-]
+            ret i32 0
+            
+            zero all function allocs
+            <<<<<<<<<<<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+    <<<<]
+<]
@@ -0,0 +1,144 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 328 ops ~1266 steps
+frame widths:
+  main: 12 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l12 <<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_load_volatile_i8*_%2__align_1(mult)
+        #%5_=_mul_i8_%3__i8_100(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 2_ i8* %1_ align 1
+            
+            >>[-]
+            ++
+            store volatile i8 2_ i8* %2_ align 1
+            
+            >[-]
+            ++
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 2
+            ++
+            %4 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 2
+            ++
+            %5 = mul i8 %3_ i8 100
+            
+            >[-]
+            known to be 200
+            >++++++++++
+            [
+                -
+                <++++++++++++++++++++
+            >]
+            %6 = mul i8 %4_ i8 50
+            
+            <<<[-]
+            known to be 100
+            >>>>++++++++++
+            [
+                -
+                <<<<++++++++++
+            >>>>]
+            %7 = add i8 %6_ i8 %5
+            
+            <<<[-]
+            known to be 44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >>++++
+            [
+                -
+                <<+++++++++++
+            >>]
+            <<.
+            [-]
+            %8 = add i8 %7_ i8 250
+            
+            <<<[-]
+            known to be 38
+            >>>++++++
+            [
+                -
+                <<<++++++
+            >>>]
+            <<<++
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>#constop_38
+            >++++++
+            [
+                -
+                <++++++
+            >]
+            <++
+            .
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
@@ -0,0 +1,176 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 436 ops ~1822 steps
+frame widths:
+  main: 12 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l12 <<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #alloc_%1
+        #alloc_%2
+        #%3_=_load_volatile_i8*_%1__align_1(mult)
+        #%4_=_load_volatile_i8*_%2__align_1(mult)
+        #%5_=_sub_i8_%3__i8_%4(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            %1 = alloca i8_ align 1
+            %2 = alloca i8_ align 1
+            store volatile i8 3_ i8* %1_ align 1
+            
+            >>[-]
+            +++
+            store volatile i8 5_ i8* %2_ align 1
+            
+            >[-]
+            +++++
+            %3 = load volatile i8* %1_ align 1
+            
+            >[-]
+            known to be 3
+            +++
+            %4 = load volatile i8* %2_ align 1
+            
+            >[-]
+            known to be 5
+            +++++
+            %5 = sub i8 %3_ i8 %4
+            
+            >[-]
+            known to be 254
+            >++++++++++++++
+            [
+                -
+                <++++++++++++++++++
+            >]
+            <++
+            %6 = add i8 %5_ i8 107
+            
+            <<[-]
+            known to be 105
+            >>>>+++++++
+            [
+                -
+                <<<<+++++++++++++++
+            >>>>]
+            call @putchar(i8 %6)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_105
+            >+++++++
+            [
+                -
+                <<+++++++++++++++
+            >>]
+            <<.
+            [-]
+            %7 = mul i8 %4_ i8 60
+            
+            <<<[-]
+            known to be 44
+            >>>++++
+            [
+                -
+                <<<+++++++++++
+            >>>]
+            call @putchar(i8 %7)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_44
+            >++++
+            [
+                -
+                <+++++++++++
+            >]
+            <.
+            [-]
+            %8 = shl i8 %4_ i8 6
+            
+            <<<[-]
+            known to be 64
+            >>>++++++++
+            [
+                -
+                <<<++++++++
+            >>>]
+            call @putchar(i8 %8)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_64
+            >++++++++
+            [
+                -
+                <++++++++
+            >]
+            <.
+            [-]
+            %9 = add i8 %5_ i8 56
+            
+            <<<[-]
+            known to be 54
+            >>>++++++
+            [
+                -
+                <<<+++++++++
+            >>>]
+            call @putchar(i8 %9)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            #constop_54
+            >++++++
+            [
+                -
+                <+++++++++
+            >]
+            <.
+            [-]
+            ret void
+            
+            zero all function allocs
+            <<<<<[-]
+            >[-]
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 <<
+        ]
+    <]
+<]
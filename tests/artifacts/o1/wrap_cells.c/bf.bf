@@ -0,0 +1,195 @@
+eof: 0
+blocks made up by bfcc:
+call graph:
+  main (depth 1)
+  max depth from main: 1
+cost (loops go round 8 times):
+  main: 1324 ops ~29044 steps
+frame widths:
+  main: 20 cells
+r4 >>>>
+++++
+r1 >
+runtime init:
+#===TOP_FRAME
++
+>#main
++
+>#main/b0
++
+<<[
+    >#main
+    [
+        >>#B:%ret_lading_pad
+        [
+            <<<-
+            #dead_frame
+            >-
+            #dead_fn_pad/main
+            >>-
+            l20 <<<<<<<<<<<<<<<<<<<<
+        ]
+        #mainloop_main
+        #F:main
+        #B:%0
+        #B:%ret_lading_pad
+        #B:%1
+        #B:%5
+        #%2_=_phi_i8_{_i8_3__%0_}__{_i8_%3__%1_}(mult)
+        #%3_=_add_i8_%2__i8_255(mult)
+        #%4_=_icmp_eq_i8_%3__i8_253(mult)
+        <#B:%0
+        [
+            -
+            copy up args
+            br label %1
+            
+            doing phi stuff
+            %2 = phi i8 { i8 3_ %0 }_ { i8 %3_ %1 }
+            op_to_reg storing const value in temp address
+            >>>>>>>#constop_3
+            +++
+            <<<[-]
+            mov9/6 >>>[-<<<+>>>]
+            <<<<<+
+        <<]
+        >>#B:%1
+        [
+            -
+            %2 = phi i8 { i8 3_ %0 }_ { i8 %3_ %1 }
+            call @putchar(i8 97)
+            
+            putchar intrinsic
+            op_to_reg storing const value in temp address
+            >>>>>>#constop_97
+            >++++++++
+            [
+                -
+                <++++++++++++
+            >]
+            <+
+            .
+            [-]
+            %3 = add i8 %2_ i8 255
+            
+            <<<[-]
+            dup6/9/10 <[->>>+>+<<<<]
+            mov9/6 >>>[-<<<+>>>]
+            op_to_reg storing const value in temp address
+            >>#constop_255
+            +++++++++++++++
+            [
+                -
+                >+++++++++++++++++
+            <]
+            mov10/7 <[-<<<+>>>]
+            >>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+            dup7/14/16 <<<<<<[->>>>>>>+>>+<<<<<<<<<]
+            >>>>>>>[
+                -
+                <-
+            >]
+            mov16/7 >>[-<<<<<<<<<+>>>>>>>>>]
+            <<<<[
+                -
+                >>>+
+                <<[
+                    -
+                    mov13/14 [->+<]
+                    <<<<<<+
+                    >>>>>>>>-
+                <<]
+                mov14/13 >[-<+>]
+                >[
+                    -
+                    <<<<<<<<[-]
+                    >>>>>>+++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++++
+                >>]
+            <<<]
+            >[-]
+            %4 = icmp eq i8 %3_ i8 253
+            
+            <<<<<[-]
+            dup7/9/10 <[->>+>+<<<]
+            mov9/7 >>[-<<+>>]
+            op_to_reg storing const value in temp address
+            >>#constop_253
+            >++++++++++++++
+            [
+                -
+                <++++++++++++++++++
+            >]
+            <+
+            #subnu_tmpb
+            #subnu_tmp0
+            #subnu_tmp1
+            mov11/17 [->>>>>>+<<<<<<]
+            >>>>>>>>+
+            <<<<<<<<<[
+                -
+                >>>+
+                >>>>[
+                    -
+                    <<<<-
+                    r1 >
+                >>>>]
+                r1 >
+                [
+                    l1 <
+                ]
+                l1 <
+            <<<<<<<]
+            >>>>>>>>>-
+            <<<<<<<<<<<+
+            >>>>>>>>>[
+                [-]
+                <<<<<<<<<[-]
+            >>>>>>>>>]
+            <<<<[
+                [-]
+                <<<<<[-]
+            >>>>>]
+            br i1 %4_ label %5_ label %1
+            
+            dup8/10/9 <<<<<[->>+<+<]
+            mov10/8 >>[-<<+>>]
+            doing phi stuff
+            %2 = phi i8 { i8 3_ %0 }_ { i8 %3_ %1 }
+            dup7/11/12 <<<[->>>>+>+<<<<<]
+            mov11/7 >>>>[-<<<<+>>>>]
+            mov12/13 >[->+<]
+            <<<<<<<<<+
+            >>>>>>[
+                -
+                <<<<<<-
+                >>+
+                #B:main/%5_true
+                >>>>>>>>[-]
+            <<<<]
+            <<<<<<[
+                -
+                >+
+                #B:main/%1_false
+                >>[-]
+                mov13/6 >>>>>>>[-<<<<<<<+>>>>>>>]
+            <<<<<<<<<<]
+        >]
+        >#B:%5
+        [
+            -
+            ret i32 0
+            
+            zero all function allocs
+            >[-]
+            >[-]
+            >[-]
+            <<<<<<<<-
+            #dead_frame
+            >-
+            l1 <
+            <[-]
+            r1 
+            l5 
+        >]
+    <<<<]
+<]
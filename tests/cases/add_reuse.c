@@ -0,0 +1,15 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "add reuse", "output": "BCA" }
+int main() {
+  volatile uint8_t va = 'A';
+  uint8_t a = va;
+
+  // a feeds both adds and still has to be there for the last putchar
+  uint8_t b = a + 1;
+  uint8_t c = a + 2;
+
+  putchar(b);
+  putchar(c);
+  putchar(a);
+};
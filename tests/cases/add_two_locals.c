@@ -0,0 +1,19 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "add two locals", "output": "C2AC" }
+int main() {
+  volatile uint8_t va = 2;
+  volatile uint8_t vb = 'A';
+  uint8_t a = va;
+  uint8_t b = vb;
+
+  uint8_t sum = a + b;
+  putchar(sum);
+
+  // the constant on the left after canonicalizing
+  putchar('0' + a);
+
+  // neither side is used up by the add
+  putchar(b);
+  putchar(b + a);
+};
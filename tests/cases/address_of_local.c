@@ -0,0 +1,17 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "address of local", "output": "qqr" }
+int main() {
+  volatile uint8_t vx = 'q';
+  uint8_t x = vx;
+
+  // p is a local of its own holding x's address
+  uint8_t *p = &x;
+  uint8_t **pp = &p;
+
+  putchar(*p);
+  putchar(**pp);
+
+  x = x + 1;
+  putchar(*p);
+};
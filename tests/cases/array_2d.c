@@ -0,0 +1,9 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "array 2d", "output": "", "levels": ["o0"], "compile_error": "only flat arrays of integers can be indexed" }
+int main() {
+  char grid[2][2];
+  volatile uint8_t i = 1;
+  grid[i][i] = 'x';
+  putchar(grid[i][i]);
+};
@@ -0,0 +1,14 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "array fill", "output": "abcd" }
+int main() {
+  uint8_t buf[4];
+
+  for (uint8_t i = 0; i < 4; i++) {
+    buf[i] = 'a' + i;
+  }
+
+  for (uint8_t i = 0; i < 4; i++) {
+    putchar(buf[i]);
+  }
+};
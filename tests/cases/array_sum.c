@@ -0,0 +1,17 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "array sum", "output": "A" }
+int main() {
+  uint8_t arr[4];
+  arr[0] = 10;
+  arr[1] = 20;
+  arr[2] = 30;
+  arr[3] = 5;
+
+  uint8_t sum = 0;
+  for (uint8_t i = 0; i < 4; i++) {
+    sum += arr[i];
+  }
+
+  putchar(sum);
+};
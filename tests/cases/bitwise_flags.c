@@ -0,0 +1,15 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "bitwise flags", "output": "10CEC" }
+int main() {
+  uint8_t a = 'C';
+  uint8_t b = 6;
+  // low bit of something odd and something even
+  putchar('0' + (a & 1));
+  putchar('0' + (b & 1));
+  // the high bit set and taken back off again
+  putchar((uint8_t)(a | 0x80) - 0x80);
+  putchar(a ^ b);
+  // both registers still there afterwards
+  putchar(a ^ b ^ b);
+};
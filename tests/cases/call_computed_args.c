@@ -0,0 +1,20 @@
+#include "stdfuck.h"
+
+__attribute__((noinline)) void show(uint8_t x) { putchar(x); }
+
+__attribute__((noinline)) void both(uint8_t x, uint8_t y) {
+  putchar(x);
+  putchar(y);
+  putchar(x - y + '0');
+}
+
+// TEST:{ "name": "call_computed_args", "output": "CCB1BA1" }
+void main(void) {
+  volatile uint8_t va = 'B';
+  uint8_t a = va;
+  uint8_t b = a + 1;
+
+  show(b);
+  both(b, a);
+  both(a, 'A');
+};
@@ -0,0 +1,25 @@
+#include "stdfuck.h"
+
+__attribute__((noinline)) uint8_t add(uint8_t x, uint8_t y) { return x + y; }
+
+// more than one block so it's a real call instead of getting pasted in
+__attribute__((noinline)) uint8_t shout(uint8_t c) {
+  if (c == '?') {
+    putchar('!');
+  }
+  return c + 1;
+}
+
+// TEST:{ "name": "call_return", "output": "AC!@cA" }
+void main(void) {
+  volatile uint8_t one = 1;
+  volatile uint8_t q = '?';
+  uint8_t a = add(30, 35);
+  putchar(a);
+  // feeding one call's result into the next
+  putchar(add(add(one, a), 1));
+
+  putchar(shout(q));
+  putchar(shout(shout('a')));
+  putchar(a);
+};
@@ -0,0 +1,28 @@
+#include "stdfuck.h"
+
+__attribute__((noinline)) void f(void) { putchar('f'); }
+
+// a callee that calls too, so frames stack up past one deep
+__attribute__((noinline)) void g(void) {
+  putchar('g');
+  f();
+}
+
+// TEST:{ "name": "call_sequence", "output": "fgffffgff" }
+void main(void) {
+  f();
+  g();
+  f();
+
+  // the same callees again from other blocks
+  volatile uint8_t c = 'y';
+  for (uint8_t i = 0; i < 2; i++) {
+    if (c == 'y') {
+      f();
+    } else {
+      g();
+    }
+    f();
+    c = 'n';
+  }
+};
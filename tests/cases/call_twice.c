@@ -0,0 +1,13 @@
+#include "stdfuck.h"
+
+// noinline so -O1 keeps the args as registers instead of folding them away
+__attribute__((noinline)) void show(uint8_t a, uint8_t b) {
+  putchar(a + b);
+  putchar(a);
+}
+
+// TEST:{ "name": "call helper twice", "output": "A@b`" }
+int main() {
+  show('@', 1);
+  show('`', 2);
+};
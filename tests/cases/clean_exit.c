@@ -0,0 +1,22 @@
+#include "stdfuck.h"
+
+uint8_t counts[3] = {1, 2, 3};
+
+// more than one block so it's a real call with a value coming back
+__attribute__((noinline)) uint8_t pick(uint8_t i) {
+  if (i == 0) {
+    return 'z';
+  }
+  return counts[i - 1] + 'a';
+}
+
+// main hands back a value, globals and returned values are all still sitting
+// on the tape until the epilogue clears them, and the interpreter refuses to
+// finish on anything but an all zero tape
+// TEST:{ "name": "clean_exit", "output": "cz" }
+int main() {
+  volatile uint8_t i = 2;
+  putchar(pick(i));
+  putchar(pick(0));
+  return 7;
+};
@@ -0,0 +1,24 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "cond reuse", "output": "T-UV,-W," }
+int main() {
+  volatile uint8_t c = 'A';
+  for (uint8_t i = 0; i < 2; i += 1) {
+    // one compare deciding two branches and a select
+    uint8_t big = c < 'a';
+
+    if (big) {
+      putchar('T');
+    }
+
+    putchar('-');
+
+    if (big) {
+      putchar('U');
+    }
+
+    putchar(big ? 'V' : 'W');
+    putchar(',');
+    c = 'a';
+  }
+};
@@ -0,0 +1,15 @@
+#include "stdfuck.h"
+
+// -g fills this with llvm.dbg calls and -O1 with lifetimes for the array,
+// none of which should turn into anything
+// TEST:{ "name": "debug info", "output": "cba", "input": "abc", "cflags": "-g" }
+int main() {
+  uint8_t buf[3];
+  for (uint8_t i = 0; i < 3; i++) {
+    buf[i] = getchar();
+  }
+  for (uint8_t i = 3; i > 0; i--) {
+    putchar(buf[i - 1]);
+  }
+  return 0;
+}
@@ -2,7 +2,7 @@
 
 // TEST:{ "name": "div", "output": "ZMIGFEDDCCCCBBBBBBBBBBBBBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"}
 void main(void) {
-  for (int i = 1; i < 60; i++) {
+  for (unsigned int i = 1; i < 60; i++) {
     putchar('A' + (25 / i));
   }
 };
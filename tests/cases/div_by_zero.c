@@ -0,0 +1,8 @@
+#include "stdfuck.h"
+
+// -O1 is free to throw the whole thing away, it's undefined after all
+// TEST:{ "name": "div by zero", "output": "", "levels": ["o0"], "compile_error": "division by constant zero" }
+int main() {
+  uint8_t x = 47;
+  putchar(x / 0);
+};
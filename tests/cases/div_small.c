@@ -0,0 +1,10 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "div small", "output": "P7A" }
+int main() {
+  uint8_t x = 47;
+  putchar('A' + x / 3);
+  putchar('0' + x % 10);
+  // divisor bigger than the dividend
+  putchar('A' + x / 100);
+};
@@ -0,0 +1,9 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "echo until eof 0", "input": "hey you", "eof": 0, "output": "hey you" }
+int main() {
+  uint8_t c;
+  while ((c = getchar()) != EOF) {
+    putchar(c);
+  }
+};
@@ -0,0 +1,9 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "echo until eof 255", "input": "hey you", "eof": 255, "output": "hey you" }
+int main() {
+  uint8_t c;
+  while ((c = getchar()) != EOF) {
+    putchar(c);
+  }
+};
@@ -0,0 +1,10 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "empty strings", "output": "\n[]x" }
+void main(void) {
+  // "" is a zeroinitializer rather than an array of one 0
+  puts("");
+  printf("");
+  printf("[%s]", "");
+  putchar('x');
+};
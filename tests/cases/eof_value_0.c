@@ -0,0 +1,10 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "eof value 0", "eof": 0, "output": "0" }
+int main() {
+  if (EOF == 0) {
+    putchar('0');
+  } else {
+    putchar('?');
+  }
+};
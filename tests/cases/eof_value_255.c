@@ -0,0 +1,12 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "eof value 255", "eof": 255, "output": "255" }
+int main() {
+  if (EOF == 255) {
+    putchar('2');
+    putchar('5');
+    putchar('5');
+  } else {
+    putchar('?');
+  }
+};
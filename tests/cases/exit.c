@@ -0,0 +1,15 @@
+#include "stdfuck.h"
+
+// leaving from inside an if inside a loop, nothing after it gets to run
+// TEST:{ "name": "exit", "output": "abc", "input": "abcxde" }
+int main() {
+  uint8_t c;
+  while ((c = getchar())) {
+    if (c == 'x') {
+      exit(0);
+    }
+    putchar(c);
+  }
+  putchar('!');
+  return 0;
+}
@@ -0,0 +1,18 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "builtin expect", "output": "yn" }
+int main() {
+  volatile uint8_t a = 3;
+
+  if (__builtin_expect(a == 3, 1)) {
+    putchar('y');
+  } else {
+    putchar('x');
+  }
+
+  if (__builtin_expect(a > 5, 0)) {
+    putchar('x');
+  } else {
+    putchar('n');
+  }
+};
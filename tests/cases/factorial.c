@@ -0,0 +1,17 @@
+#include "stdfuck.h"
+
+// every activation gets a frame of its own so n survives the call under it
+__attribute__((noinline)) uint8_t fact(uint8_t n) {
+  if (n < 2) {
+    return 1;
+  }
+  return n * fact(n - 1);
+}
+
+// TEST:{ "name": "factorial", "output": "xY" }
+void main(void) {
+  volatile uint8_t n = 5;
+  putchar(fact(n));
+  n = 4;
+  putchar(fact(n) + 'A');
+};
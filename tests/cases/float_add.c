@@ -0,0 +1,10 @@
+#include "stdfuck.h"
+
+// there's nothing to build floating point out of, bfcc should say so up
+// front instead of falling over halfway through
+// TEST:{ "name": "float_add", "output": "", "compile_error": "unsupported instruction" }
+int main() {
+  volatile float f = 1.5f;
+  f = f + 2.5f;
+  putchar((uint8_t)f);
+};
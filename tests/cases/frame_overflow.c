@@ -0,0 +1,18 @@
+#include "stdfuck.h"
+
+// volatile so -O1 can't just throw all of them away
+#define L(n) volatile uint8_t v##n = 1;
+#define L10(n) L(n##0) L(n##1) L(n##2) L(n##3) L(n##4) \
+  L(n##5) L(n##6) L(n##7) L(n##8) L(n##9)
+#define L100(n) L10(n##0) L10(n##1) L10(n##2) L10(n##3) L10(n##4) \
+  L10(n##5) L10(n##6) L10(n##7) L10(n##8) L10(n##9)
+
+// way more locals than a frame can address
+// TEST:{ "name": "frame overflow", "output": "", "compile_error": "frame overflow in main" }
+void main(void) {
+  L100(1)
+  L100(2)
+  L100(3)
+
+  putchar(v100);
+};
@@ -0,0 +1,11 @@
+#include "stdfuck.h"
+
+const char digits[] = "0123456789";
+
+// TEST:{ "name": "global lookup table", "output": "74" }
+int main() {
+  volatile uint8_t x = 47;
+
+  putchar(digits[x / 10 % 10]);
+  putchar(digits[x % 10]);
+};
@@ -0,0 +1,21 @@
+#include "stdfuck.h"
+
+// comparisons one right after the other in the same block, each one's
+// scratch has to be back to 0 before the next borrows it
+// TEST:{ "name": "icmp_back_to_back", "output": "1020", "check_scratch": true }
+int main() {
+  volatile uint8_t va = 'a';
+  volatile uint8_t vb = 'b';
+  uint8_t a = va;
+  uint8_t b = vb;
+
+  uint8_t lt = a < b;
+  uint8_t gt = a > b;
+  uint8_t eq = a == b;
+  uint8_t le = b <= a;
+
+  putchar('0' + lt);
+  putchar('0' + gt);
+  putchar('0' + (lt + eq) * 2);
+  putchar('0' + le);
+};
@@ -0,0 +1,24 @@
+#include "stdfuck.h"
+
+void check(uint8_t x) {
+  if (x == 3) {
+    putchar('=');
+  }
+
+  if (x != 0) {
+    putchar('!');
+  }
+
+  // compared twice and still the same afterwards
+  putchar('0' + x);
+}
+
+// TEST:{ "name": "icmp eq ne", "output": "=!3!50" }
+int main() {
+  volatile uint8_t x = 3;
+  check(x);
+  x = 5;
+  check(x);
+  x = 0;
+  check(x);
+};
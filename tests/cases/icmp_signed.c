@@ -0,0 +1,40 @@
+#include "stdfuck.h"
+
+// -1 is 255 by the time it's in a cell, the signed compares still have to put
+// it below 0
+__attribute__((noinline)) void sign(signed char x) {
+  if (x > -1) {
+    putchar('+');
+  } else {
+    putchar('-');
+  }
+
+  if (x < -100) {
+    putchar('<');
+  }
+
+  if (x >= 127) {
+    putchar('>');
+  }
+
+  putchar(',');
+}
+
+// TEST:{ "name": "icmp signed", "output": "321,-,+,-<,+,+>," }
+int main() {
+  volatile signed char start = 3;
+  volatile uint8_t step = 1;
+  signed char i = start;
+
+  while (i > 0) {
+    putchar('0' + i);
+    i = i - step;
+  }
+  putchar(',');
+
+  sign(-1);
+  sign(5);
+  sign(-128);
+  sign(0);
+  sign(127);
+};
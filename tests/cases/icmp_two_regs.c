@@ -0,0 +1,23 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "icmp two registers", "output": "<!=af" }
+int main() {
+  uint8_t a = 3 * 32 + 1;
+  uint8_t b = a + 5;
+
+  if (a < b) {
+    putchar('<');
+  }
+
+  if (b > a) {
+    putchar('!');
+  }
+
+  if (a != b) {
+    putchar('=');
+  }
+
+  // both sides are still around after being compared
+  putchar(a);
+  putchar(b);
+};
@@ -0,0 +1,16 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "icmp against zero", "output": "ynny2" }
+int main() {
+  volatile uint8_t zero = 0;
+  volatile uint8_t seven = 7;
+
+  putchar(zero == 0 ? 'y' : 'n');
+  putchar(seven == 0 ? 'y' : 'n');
+  putchar(0 != zero ? 'y' : 'n');
+  putchar(0 != seven ? 'y' : 'n');
+
+  // results feeding into more math
+  uint8_t both = (zero == 0) + (seven != 0);
+  putchar('0' + both);
+};
@@ -0,0 +1,12 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "icmp zext", "output": "1AC0" }
+int main() {
+  volatile uint8_t c = 'y';
+  volatile uint8_t d = 'n';
+  int yes = c == 'y';
+  putchar('0' + yes);
+  putchar('A' + (d == 'y') * 3);
+  putchar('A' + yes + (c > d) + (d < 'a'));
+  putchar('0' + (yes & (d == 'y')));
+};
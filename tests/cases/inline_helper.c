@@ -0,0 +1,12 @@
+#include "stdfuck.h"
+
+void put_digit(uint8_t d) {
+  putchar('0' + d);
+}
+
+// TEST:{ "name": "inline helper", "output": "0123456789" }
+int main() {
+  for (uint8_t i = 0; i < 10; i++) {
+    put_digit(i);
+  }
+};
@@ -0,0 +1,8 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "int 300", "output": ",", "warning": "constant 300 doesn't fit in a cell" }
+int main() {
+  // 300 is past a cell, so this goes through as 44 with a warning
+  volatile int x = 300;
+  putchar(x);
+}
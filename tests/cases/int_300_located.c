@@ -0,0 +1,9 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "int 300 located", "output": ",", "cflags": "-g", "warning": "int_300_located.c:7)" }
+int main() {
+  // same as int_300.c but built with -g, so the warning says which line
+  // the constant is on
+  volatile int x = 300;
+  putchar(x);
+}
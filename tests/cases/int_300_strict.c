@@ -0,0 +1,8 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "int 300 strict", "output": "", "strict_consts": true, "compile_error": "constant 300 doesn't fit in a cell" }
+int main() {
+  // same as int_300.c but with strict_consts 300 is an error
+  volatile int x = 300;
+  putchar(x);
+}
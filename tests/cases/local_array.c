@@ -0,0 +1,12 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "local array", "output": "xaxa" }
+int main() {
+  char buf[4];
+  buf[2] = 'x';
+  buf[0] = 'a';
+  putchar(buf[2]);
+  putchar(buf[0]);
+  putchar(buf[2]);
+  putchar(buf[0]);
+};
@@ -0,0 +1,9 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "local array runtime index", "output": "x", "levels": ["o0"] }
+int main() {
+  char buf[4];
+  volatile uint8_t i = 2;
+  buf[i] = 'x';
+  putchar(buf[i]);
+};
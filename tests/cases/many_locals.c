@@ -0,0 +1,58 @@
+#include "stdfuck.h"
+
+// a frame wide enough that the one after it has to start well past 16
+__attribute__((noinline)) void row(uint8_t shift) {
+  volatile uint8_t a = 'a';
+  volatile uint8_t b = 'b';
+  volatile uint8_t c = 'c';
+  volatile uint8_t d = 'd';
+  volatile uint8_t e = 'e';
+  volatile uint8_t f = 'f';
+  volatile uint8_t g = 'g';
+  volatile uint8_t h = 'h';
+  volatile uint8_t i = 'i';
+  volatile uint8_t j = 'j';
+  volatile uint8_t k = 'k';
+  volatile uint8_t l = 'l';
+  volatile uint8_t m = 'm';
+  volatile uint8_t n = 'n';
+  volatile uint8_t o = 'o';
+  volatile uint8_t p = 'p';
+  volatile uint8_t q = 'q';
+  volatile uint8_t r = 'r';
+  volatile uint8_t s = 's';
+  volatile uint8_t t = 't';
+
+  putchar(a - shift);
+  putchar(b - shift);
+  putchar(c - shift);
+  putchar(d - shift);
+  putchar(e - shift);
+  putchar(f - shift);
+  putchar(g - shift);
+  putchar(h - shift);
+  putchar(i - shift);
+  putchar(j - shift);
+  putchar(k - shift);
+  putchar(l - shift);
+  putchar(m - shift);
+  putchar(n - shift);
+  putchar(o - shift);
+  putchar(p - shift);
+  putchar(q - shift);
+  putchar(r - shift);
+  putchar(s - shift);
+  putchar(t - shift);
+}
+
+// TEST:{ "name": "many_locals", "output": "abcdefghijklmnopqrst\nABCDEFGHIJKLMNOPQRST\nabcdefghijklmnopqrst" }
+void main(void) {
+  volatile uint8_t before = '\n';
+  volatile uint8_t space = ' ';
+
+  row(0);
+  putchar(before);
+  row(space);
+  putchar(before);
+  row(0);
+};
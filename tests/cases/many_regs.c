@@ -0,0 +1,54 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "many_regs", "output": "pimponmlkjihgfedcba" }
+void main(void) {
+  // volatile so every one of them stays its own register
+  volatile uint8_t c = 'a';
+  uint8_t v0 = c++;
+  uint8_t v1 = c++;
+  uint8_t v2 = c++;
+  uint8_t v3 = c++;
+  uint8_t v4 = c++;
+  uint8_t v5 = c++;
+  uint8_t v6 = c++;
+  uint8_t v7 = c++;
+  uint8_t v8 = c++;
+  uint8_t v9 = c++;
+  uint8_t v10 = c++;
+  uint8_t v11 = c++;
+  uint8_t v12 = c++;
+  uint8_t v13 = c++;
+  uint8_t v14 = c++;
+  uint8_t v15 = c++;
+
+  if (v3 > v2) {
+    putchar(v15);
+  } else {
+    putchar(v0);
+  }
+  if (v7 == 'h') {
+    putchar(v8);
+  }
+  if (v10 < v1) {
+    putchar('!');
+  } else {
+    putchar(v12);
+  }
+
+  putchar(v15);
+  putchar(v14);
+  putchar(v13);
+  putchar(v12);
+  putchar(v11);
+  putchar(v10);
+  putchar(v9);
+  putchar(v8);
+  putchar(v7);
+  putchar(v6);
+  putchar(v5);
+  putchar(v4);
+  putchar(v3);
+  putchar(v2);
+  putchar(v1);
+  putchar(v0);
+};
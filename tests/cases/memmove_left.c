@@ -0,0 +1,13 @@
+#include "stdfuck.h"
+
+uint8_t word[4] = {'a', 'b', 'c', 'd'};
+
+// TEST:{ "name": "memmove shift left", "output": "bcdd" }
+void main(void) {
+  memmove(word, word + 1, 3);
+
+  putchar(word[0]);
+  putchar(word[1]);
+  putchar(word[2]);
+  putchar(word[3]);
+};
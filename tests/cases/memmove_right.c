@@ -0,0 +1,14 @@
+#include "stdfuck.h"
+
+uint8_t word[4] = {'a', 'b', 'c', 'd'};
+
+// TEST:{ "name": "memmove shift right", "output": "aabc" }
+void main(void) {
+  // copying front to back would smear the first byte all the way along
+  memmove(word + 1, word, 3);
+
+  putchar(word[0]);
+  putchar(word[1]);
+  putchar(word[2]);
+  putchar(word[3]);
+};
@@ -0,0 +1,12 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "mul operands", "output": "***0" }
+int main() {
+  uint8_t a = 6;
+  uint8_t b = 7;
+  putchar(6 * 7);
+  putchar(a * b);
+  putchar(a * 7);
+  // neither operand got used up by the multiplies
+  putchar(a + b + 35);
+};
@@ -0,0 +1,25 @@
+#include "stdfuck.h"
+
+// clang keeps the source's names for values and blocks (%entry, %if.then,
+// %i...) instead of numbering them
+__attribute__((noinline)) uint8_t bump(uint8_t by) {
+  uint8_t total = 0;
+  for (uint8_t i = 0; i < by; i++) {
+    total += 2;
+  }
+  return total;
+}
+
+// TEST:{ "name": "named_values", "output": "abcF", "cflags": "-fno-discard-value-names" }
+void main(void) {
+  volatile uint8_t last = 'c';
+
+  for (uint8_t c = 'a'; c <= last; c++) {
+    if (c == 'z') {
+      putchar('!');
+    } else {
+      putchar(c);
+    }
+  }
+  putchar('@' + bump(3));
+};
@@ -0,0 +1,16 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "negative_const", "output": "aDzyx", "levels": ["o0"] }
+void main(void) {
+  volatile uint8_t c = 'b';
+  // clang stores this as i8 -1
+  uint8_t none = -1;
+  // the gep and its [3 x i8] end up in the comments right next to the code
+  uint8_t word[3] = {'x', 'y', 'z'};
+
+  putchar(c - 1);
+  putchar(c - 30);
+  for (uint8_t i = 3; i != 0 && c != none; i -= 1) {
+    putchar(word[i - 1]);
+  }
+};
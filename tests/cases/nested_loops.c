@@ -0,0 +1,22 @@
+#include "stdfuck.h"
+
+// loops inside loops with a branch in the middle, every block is entered and
+// left through brackets so the head has to be where codegen thinks it is at
+// each one
+// TEST:{ "name": "nested loops", "output": "0 e1 eo2 eoe3 ", "check_scratch": true }
+int main() {
+  volatile uint8_t vn = 4;
+  uint8_t n = vn;
+  for (uint8_t i = 0; i < n; i++) {
+    for (uint8_t j = 0; j <= i; j++) {
+      if (j == i) {
+        putchar('0' + j);
+      } else if (j & 1) {
+        putchar('o');
+      } else {
+        putchar('e');
+      }
+    }
+    putchar(' ');
+  }
+};
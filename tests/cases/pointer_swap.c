@@ -0,0 +1,25 @@
+#include "stdfuck.h"
+
+__attribute__((noinline)) void swap(uint8_t *a, uint8_t *b) {
+  uint8_t t = *a;
+  *a = *b;
+  *b = t;
+}
+
+// TEST:{ "name": "pointer swap", "output": "xyyxyx" }
+int main() {
+  uint8_t x = 'x';
+  uint8_t y = 'y';
+  uint8_t *p = &x;
+
+  putchar(*p);
+  putchar(y);
+
+  swap(&x, &y);
+  putchar(x);
+  putchar(y);
+
+  // both the pointer and what it points at are intact after a read
+  putchar(*p);
+  putchar(*p == x ? y : '!');
+};
@@ -0,0 +1,17 @@
+#include "stdfuck.h"
+
+// the argument is still there to print a second time
+// TEST:{ "name": "print_u8", "output": "0 7 42 255 42", "input": "", "check_scratch": true }
+int main() {
+  uint8_t x = 42;
+  print_u8(0);
+  putchar(' ');
+  print_u8(7);
+  putchar(' ');
+  print_u8(x);
+  putchar(' ');
+  print_u8(255);
+  putchar(' ');
+  print_u8(x);
+  return 0;
+}
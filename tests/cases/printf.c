@@ -0,0 +1,10 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "printf", "output": "7 * 6 = 42, 0 zero\n", "input": "76" }
+int main() {
+  uint8_t a = getchar() - '0';
+  uint8_t b = getchar();
+  uint8_t p = a * (b - '0');
+  printf("%d * %c = %d, %d %s\n", a, b, p, 0, "zero");
+  return 0;
+}
@@ -0,0 +1,21 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "putdec tens", "output": "100,10,9,0,255," }
+void main(void) {
+  volatile uint8_t a = 100;
+  volatile uint8_t b = 10;
+  volatile uint8_t c = 9;
+  volatile uint8_t d = 0;
+  volatile uint8_t e = 255;
+
+  putdec(a);
+  putchar(',');
+  putdec(b);
+  putchar(',');
+  putdec(c);
+  putchar(',');
+  putdec(d);
+  putchar(',');
+  putdec(e);
+  putchar(',');
+};
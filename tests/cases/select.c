@@ -0,0 +1,16 @@
+#include "stdfuck.h"
+
+// volatile so -O1 can't fold it away and has to pick with a select
+// TEST:{ "name": "select", "output": "YN10" }
+int main() {
+  volatile uint8_t vc = 'y';
+  volatile uint8_t vd = 'n';
+  uint8_t c = vc == 'y';
+  uint8_t d = vd == 'y';
+  uint8_t x = c ? 'Y' : 'N';
+  putchar(x);
+  putchar(d ? 'Y' : x - 'Y' + 'N');
+  // the conditions are still around afterwards
+  putchar('0' + c);
+  putchar('0' + d);
+};
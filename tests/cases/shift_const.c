@@ -0,0 +1,12 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "shift const", "output": "dMd" }
+int main() {
+  uint8_t x = 25;
+  uint8_t n = 2;
+  uint8_t l = x << 2;
+  putchar(l);
+  putchar('A' + (l >> 3));
+  // and by an amount that's only known at runtime
+  putchar(x << n);
+};
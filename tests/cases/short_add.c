@@ -0,0 +1,21 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "short add", "output": ",dXd" }
+int main() {
+  unsigned short a = 200;
+  unsigned short b = 100;
+
+  // 300 carries into the high byte, the low one is 44
+  unsigned short s = a + b;
+  putchar(s);
+
+  // and borrows it back
+  unsigned short d = s - a;
+  putchar(d);
+
+  unsigned short t = s + s;
+  putchar(t);
+
+  unsigned short u = t - 500;
+  putchar(u);
+};
@@ -0,0 +1,13 @@
+#include "stdfuck.h"
+
+// not a tail call so it stays recursive at any -O
+void down(uint8_t n) {
+  putchar('a' + n);
+  if (n < 20) {
+    down(n + 1);
+    putchar('.');
+  }
+}
+
+// TEST:{ "name": "stack_overflow", "output": "abcSTACKOVERFLOW", "stack_limit": 3 }
+void main(void) { down(0); };
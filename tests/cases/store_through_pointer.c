@@ -0,0 +1,21 @@
+#include "stdfuck.h"
+
+// the pointer is used again after each store so it has to come out whole
+__attribute__((noinline)) void twice(uint8_t *p, uint8_t v) {
+  *p = v;
+  putchar(*p);
+  *p = *p + 1;
+}
+
+// TEST:{ "name": "store through pointer", "output": "AABC" }
+int main() {
+  uint8_t x;
+  uint8_t *p = &x;
+
+  *p = 65;
+  putchar(x);
+
+  twice(p, x);
+  putchar(x);
+  twice(&x, x + 1);
+};
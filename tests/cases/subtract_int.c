@@ -0,0 +1,9 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "subtract int", "output": "ii" }
+int main() {
+  int a = 'n';
+  int x = a - 5;
+  putchar(x);
+  putchar(a - x + 100);
+};
@@ -0,0 +1,7 @@
+#include "stdfuck.h"
+
+// LETTER comes from suite.json, as does only building at -O0
+// TEST:{ "name": "letter", "output": "h" }
+int main() {
+  putchar(LETTER);
+};
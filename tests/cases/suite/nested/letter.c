@@ -0,0 +1,8 @@
+#include "stdfuck.h"
+
+// same name as ../letter.c, the nested suite.json's LETTER and levels win
+// over the outer one's and its tape still carries through
+// TEST:{ "name": "letter", "output": "i" }
+int main() {
+  putchar(LETTER);
+};
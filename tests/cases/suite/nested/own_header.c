@@ -0,0 +1,7 @@
+#include "stdfuck.h"
+
+// the header beats both suite.json files
+// TEST:{ "name": "own header", "output": "j", "cflags": "-DLETTER=106", "levels": ["o1"] }
+int main() {
+  putchar(LETTER);
+};
@@ -0,0 +1,27 @@
+#include "stdfuck.h"
+
+// At -O1 everything here lives in phis, several to a block, and a and b swap
+// places through them every time around
+// TEST:{ "name": "sum loop", "output": "43D" }
+int main() {
+  volatile uint8_t len = 4;
+  volatile uint8_t skip = 1;
+  uint8_t n = 0;
+  uint8_t xs = 0;
+  uint8_t a = 0;
+  uint8_t b = 1;
+
+  while (n != len) {
+    xs += n != skip;
+
+    uint8_t tmp = a;
+    a = b;
+    b = tmp + b;
+
+    n++;
+  }
+
+  putchar('0' + n);
+  putchar('0' + xs);
+  putchar('A' + a);
+};
@@ -0,0 +1,35 @@
+#include "stdfuck.h"
+
+void say(uint8_t c) {
+  switch (c) {
+  case 'a':
+    putchar('A');
+    break;
+  case 'b':
+    putchar('B');
+    break;
+  case 'c':
+    putchar('C');
+    break;
+  default:
+    putchar('?');
+  }
+
+  // nothing but a default, still a switch at -O0
+  switch (c) {
+  default:
+    putchar(',');
+  }
+}
+
+// TEST:{ "name": "switch", "output": "A,B,C,?," }
+int main() {
+  volatile uint8_t c = 'a';
+  say(c);
+  c = 'b';
+  say(c);
+  c = 'c';
+  say(c);
+  c = 'z';
+  say(c);
+};
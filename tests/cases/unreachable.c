@@ -0,0 +1,24 @@
+#include "stdfuck.h"
+
+void say(uint8_t c) {
+  if (c == 'q') {
+    putchar('!');
+    __builtin_unreachable();
+  }
+
+  putchar(c);
+}
+
+// getting there anyway ends the program, two calls deep. -O1 is free to
+// assume the arm never runs and drop it, so only -O0 can say what happens
+// TEST:{ "name": "unreachable", "output": "ab!", "levels": ["o0"] }
+int main() {
+  volatile uint8_t c = 'a';
+  say(c);
+  c = 'b';
+  say(c);
+  c = 'q';
+  say(c);
+  c = 'c';
+  say(c);
+};
@@ -0,0 +1,13 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "unused locals", "output": "ok" }
+void main(void) {
+  // only ever written, these shouldn't take up any cells
+  uint8_t unused = 5;
+  uint8_t also = unused + 1;
+  also = also * 2;
+
+  uint8_t used = 'o';
+  putchar(used);
+  putchar(used - 4);
+};
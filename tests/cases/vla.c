@@ -0,0 +1,16 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "vla", "output": "", "compile_error": "variable length arrays aren't supported" }
+int main() {
+  volatile uint8_t vn = 3;
+  uint8_t n = vn;
+  uint8_t buf[n];
+
+  for (uint8_t i = 0; i < n; i++) {
+    buf[i] = 'a' + i;
+  }
+
+  for (uint8_t i = 0; i < n; i++) {
+    putchar(buf[i]);
+  }
+};
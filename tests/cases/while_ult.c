@@ -0,0 +1,15 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "while ult", "output": "0123456789!" }
+int main() {
+  unsigned char i = 0;
+  while (i < 10) {
+    putchar('0' + i);
+    i += 1;
+  }
+
+  // compares the other way round and with the bound on the left
+  if (10 <= i && i >= 10 && !(i > 10)) {
+    putchar('!');
+  }
+};
@@ -0,0 +1,8 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "wide constant", "output": "A", "warning": "constant 321 doesn't fit in a cell, truncated to 65" }
+int main() {
+  // 321 won't fit in a cell, it wraps around to 65
+  volatile int x = 321;
+  putchar(x);
+}
@@ -0,0 +1,15 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "wrap_add", "output": ",&", "wrap_safe": true }
+void main(void) {
+  // volatile so none of it folds away
+  volatile uint8_t va = 2;
+  volatile uint8_t vb = 2;
+  uint8_t a = va;
+  uint8_t b = vb;
+
+  // 200 + 100 goes around to 44
+  uint8_t s = a * 100 + b * 50;
+  putchar(s);
+  putchar(s + 250);
+};
@@ -0,0 +1,16 @@
+#include "stdfuck.h"
+
+// TEST:{ "name": "wrap_arith", "output": "i,@6", "wrap_safe": true }
+void main(void) {
+  // volatile so none of it folds away
+  volatile uint8_t va = 3;
+  volatile uint8_t vb = 5;
+  uint8_t a = va;
+  uint8_t b = vb;
+
+  uint8_t d = a - b;
+  putchar(d + 10 + 'a');
+  putchar(b * 60);
+  putchar(b << 6);
+  putchar(d - 200);
+};
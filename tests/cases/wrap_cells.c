@@ -0,0 +1,13 @@
+#include "stdfuck.h"
+
+// counting down through 0, whatever bfcc makes of that is fine to wrap on a
+// wrapping interpreter
+// TEST:{ "name": "wrap cells", "output": "aaaaaa", "input": "", "wrap_cells": true }
+int main() {
+  uint8_t x = 3;
+  while (x != 253) {
+    putchar('a');
+    x--;
+  }
+  return 0;
+}
@@ -0,0 +1,10 @@
+#include "stdfuck.h"
+
+// no flags, 200 + 100 still has to go around to 44 without the cell ever
+// going past 255
+// TEST:{ "name": "wrap_default", "output": "," }
+int main() {
+  volatile uint8_t a = 200;
+  volatile uint8_t b = 100;
+  putchar((a + b) & 0xFF);
+};
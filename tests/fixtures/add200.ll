@@ -0,0 +1,16 @@
+; adding 200 to a byte, once widened to i32 where it's positive and once at i8
+; where it reads as -56, both just count up
+declare zeroext i8 @getchar()
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+  %c = call zeroext i8 @getchar()
+  %w = zext i8 %c to i32
+  %r = add i32 %w, 200
+  %t = trunc i32 %r to i8
+  call void @putchar(i8 zeroext %t)
+  %x = call zeroext i8 @getchar()
+  %y = add i8 %x, 200
+  call void @putchar(i8 zeroext %y)
+  ret i32 0
+}
@@ -0,0 +1,9 @@
+; printf with a conversion bfcc can't do
+@.fmt = private unnamed_addr constant [4 x i8] c"%x\0A\00", align 1
+
+declare i32 @printf(i8*, ...)
+
+define i32 @main() {
+  %r = call i32 (i8*, ...) @printf(i8* getelementptr inbounds ([4 x i8], [4 x i8]* @.fmt, i64 0, i64 0), i32 10)
+  ret i32 0
+}
@@ -0,0 +1,24 @@
+; a run of values each only living until the next one's made, then a second
+; block with its own, and %a held across both
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  %p = alloca i8
+  store volatile i8 109, i8* %p
+  %a = load volatile i8, i8* %p
+  br label %up
+up:
+  %b = add i8 %a, 1
+  %c = add i8 %b, 1
+  %d = add i8 %c, 1
+  %e = add i8 %d, 1
+  call void @putchar(i8 %e)
+  br label %down
+down:
+  %f = sub i8 %a, 1
+  %g = sub i8 %f, 1
+  %h = sub i8 %g, 1
+  call void @putchar(i8 %h)
+  ret i32 0
+}
@@ -0,0 +1,16 @@
+; constants too big to be worth spelling out one + at a time
+declare void @putchar(i8 zeroext)
+
+define i8 @f(i8 %a) {
+  %x = add i8 %a, 120
+  ret i8 %x
+}
+
+define i32 @main() {
+  %p = alloca i8
+  store i8 122, i8* %p
+  %v = load volatile i8, i8* %p
+  call void @putchar(i8 %v)
+  call void @putchar(i8 65)
+  ret i32 0
+}
@@ -0,0 +1,14 @@
+; reads and prints between arithmetic, none of it a real call
+declare void @putchar(i8 zeroext)
+declare zeroext i8 @getchar()
+
+define i32 @main() {
+  %a = call i8 @getchar()
+  %b = add i8 %a, 1
+  call void @putchar(i8 %b)
+  %c = call i8 @getchar()
+  call void @putchar(i8 %c)
+  %d = add i8 %c, 2
+  call void @putchar(i8 %d)
+  ret i32 0
+}
@@ -0,0 +1,47 @@
+; exit from an if inside a loop, in main and two calls deep, with a phi after
+; the exit that no longer hears from its block
+declare zeroext i8 @getchar()
+declare void @putchar(i8 zeroext)
+declare void @exit(i32)
+
+define void @check(i8 %c) {
+  %q = icmp eq i8 %c, 113
+  br i1 %q, label %quit, label %ok
+
+quit:
+  call void @putchar(i8 zeroext 33)
+  call void @exit(i32 0)
+  unreachable
+
+ok:
+  ret void
+}
+
+define i32 @main() {
+entry:
+  br label %loop
+
+loop:
+  %c = call zeroext i8 @getchar()
+  %end = icmp eq i8 %c, 0
+  br i1 %end, label %done, label %body
+
+body:
+  call void @check(i8 %c)
+  %x = icmp eq i8 %c, 120
+  br i1 %x, label %leave, label %print
+
+leave:
+  %s = zext i8 %c to i32
+  call void @exit(i32 %s)
+  br label %print
+
+print:
+  %p = phi i8 [ %c, %body ], [ 63, %leave ]
+  call void @putchar(i8 zeroext %p)
+  br label %loop
+
+done:
+  call void @putchar(i8 zeroext 46)
+  ret i32 0
+}
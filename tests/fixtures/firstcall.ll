@@ -0,0 +1,26 @@
+; main calls before anything else and loops back to a block that calls too,
+; so both the entry block and a branch target call
+declare void @putchar(i8 zeroext)
+declare zeroext i8 @getchar()
+
+define void @say(i8 %c) {
+  call void @putchar(i8 %c)
+  ret void
+}
+
+define i32 @main() {
+entry:
+  call void @say(i8 62)
+  br label %loop
+
+loop:
+  %n = phi i8 [ 3, %entry ], [ %m, %loop ]
+  %c = add i8 %n, 48
+  call void @say(i8 %c)
+  %m = sub i8 %n, 1
+  %done = icmp eq i8 %m, 0
+  br i1 %done, label %end, label %loop
+
+end:
+  ret i32 0
+}
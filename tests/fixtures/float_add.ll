@@ -0,0 +1,11 @@
+; floating point, which bfcc has no way of building
+declare void @putchar(i8 zeroext)
+define i32 @main() {
+  %f = alloca float
+  store volatile float 1.5, float* %f
+  %v = load volatile float, float* %f
+  %s = fadd float %v, 2.5
+  %r = fptoui float %s to i8
+  call void @putchar(i8 zeroext %r)
+  ret i32 0
+}
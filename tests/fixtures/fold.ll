@@ -0,0 +1,15 @@
+; nothing here depends on input, every value is known before it runs.
+; 200 + 100 wraps around to 44 like it would in an i8
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+  %a = add i8 65, 1
+  %b = mul i8 %a, 3
+  %c = add i8 200, 100
+  %d = icmp ugt i8 %b, %c
+  %e = zext i1 %d to i8
+  %f = add i8 %e, 48
+  call void @putchar(i8 %c)
+  call void @putchar(i8 %f)
+  ret i32 0
+}
@@ -0,0 +1,8 @@
+; a function compiled on its own, run straight off a tape with its args
+; already in place
+define i8 @f(i8 %a, i8 %b) {
+  %s = add i8 %a, %b
+  %m = mul i8 %s, 3
+  %d = sub i8 %m, %b
+  ret i8 %d
+}
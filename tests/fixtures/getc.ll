@@ -0,0 +1,11 @@
+; getc the way old code calls it, the stream doesn't matter
+declare void @putchar(i8 zeroext)
+declare i32 @getc(i8*)
+
+define i32 @main() {
+  %c = call i32 @getc(i8* null)
+  %t = trunc i32 %c to i8
+  %d = add i8 %t, 1
+  call void @putchar(i8 %d)
+  ret i32 0
+}
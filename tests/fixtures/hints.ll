@@ -0,0 +1,43 @@
+; what clang -g -O1 leaves around a plain program: debug info, lifetimes of a
+; local and a noalias scope from inlining
+declare zeroext i8 @getchar()
+declare void @putchar(i8 zeroext)
+declare void @llvm.dbg.declare(metadata, metadata, metadata)
+declare void @llvm.dbg.value(metadata, metadata, metadata)
+declare void @llvm.lifetime.start.p0i8(i64, i8* nocapture)
+declare void @llvm.lifetime.end.p0i8(i64, i8* nocapture)
+declare void @llvm.experimental.noalias.scope.decl(metadata)
+
+define i32 @main() !dbg !6 {
+  %slot = alloca i8, align 1
+  call void @llvm.lifetime.start.p0i8(i64 1, i8* %slot)
+  call void @llvm.dbg.declare(metadata i8* %slot, metadata !10, metadata !DIExpression()), !dbg !12
+  call void @llvm.experimental.noalias.scope.decl(metadata !13)
+  %c = call zeroext i8 @getchar(), !dbg !12
+  call void @llvm.dbg.value(metadata i8 %c, metadata !10, metadata !DIExpression()), !dbg !12
+  store i8 %c, i8* %slot, align 1, !dbg !12
+  %v = load i8, i8* %slot, align 1, !dbg !12
+  %n = add i8 %v, 1, !dbg !12
+  call void @putchar(i8 zeroext %n), !dbg !12
+  call void @llvm.lifetime.end.p0i8(i64 1, i8* %slot)
+  ret i32 0, !dbg !12
+}
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!3, !4}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "clang", isOptimized: true, runtimeVersion: 0, emissionKind: FullDebug, enums: !2)
+!1 = !DIFile(filename: "hints.c", directory: "/")
+!2 = !{}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = !{i32 7, !"Dwarf Version", i32 4}
+!6 = distinct !DISubprogram(name: "main", scope: !1, file: !1, line: 1, type: !7, scopeLine: 1, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !0, retainedNodes: !2)
+!7 = !DISubroutineType(types: !8)
+!8 = !{!9}
+!9 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!10 = !DILocalVariable(name: "c", scope: !6, file: !1, line: 2, type: !11)
+!11 = !DIBasicType(name: "unsigned char", size: 8, encoding: DW_ATE_unsigned_char)
+!12 = !DILocation(line: 2, column: 3, scope: !6)
+!13 = !{!14}
+!14 = distinct !{!14, !15, !"inlined: %slot"}
+!15 = distinct !{!15, !"inlined"}
@@ -0,0 +1,11 @@
+; a computed goto, no way to turn that into block masks
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  indirectbr i8* blockaddress(@main, %there), [label %there]
+
+there:
+  call void @putchar(i8 zeroext 33)
+  ret i32 0
+}
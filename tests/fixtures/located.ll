@@ -0,0 +1,24 @@
+; built with -g, the float add should be blamed on the line it came from
+declare void @putchar(i8 zeroext)
+
+define i32 @main() !dbg !6 {
+entry:
+  %f = fadd float 1.0, 2.0, !dbg !10
+  %c = fptoui float %f to i8, !dbg !10
+  call void @putchar(i8 zeroext %c), !dbg !11
+  ret i32 0, !dbg !11
+}
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!3}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "clang", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, enums: !2)
+!1 = !DIFile(filename: "foo.c", directory: "/")
+!2 = !{}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!6 = distinct !DISubprogram(name: "main", scope: !1, file: !1, line: 10, type: !7, scopeLine: 10, spFlags: DISPFlagDefinition, unit: !0, retainedNodes: !2)
+!7 = !DISubroutineType(types: !8)
+!8 = !{!9}
+!9 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!10 = !DILocation(line: 12, column: 3, scope: !6)
+!11 = !DILocation(line: 13, column: 3, scope: !6)
@@ -0,0 +1,22 @@
+; a loop swapping %a and %b through its header's phis, and the exit reading
+; them through phis of its own. The back edge writes exactly what the exit
+; edge reads, so the exit only sees the right values if the edge not taken
+; never got to write.
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  br label %loop
+loop:
+  %a = phi i8 [70, %entry], [%b, %loop]
+  %b = phi i8 [69, %entry], [%a, %loop]
+  %n = phi i8 [0, %entry], [1, %loop]
+  %again = icmp eq i8 %n, 0
+  br i1 %again, label %loop, label %out
+out:
+  %r = phi i8 [%a, %loop]
+  %s = phi i8 [%b, %loop]
+  call void @putchar(i8 %r)
+  call void @putchar(i8 %s)
+  ret i32 0
+}
@@ -0,0 +1,43 @@
+; nested loops with a branchy body, the head has to come out of every [ ] where
+; the renderer thinks it is
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  %p = alloca i8
+  store volatile i8 4, i8* %p
+  %n = load volatile i8, i8* %p
+  br label %outer
+outer:
+  %i = phi i8 [0, %entry], [%i1, %outer_end]
+  %ok = icmp ult i8 %i, %n
+  br i1 %ok, label %inner, label %done
+inner:
+  %j = phi i8 [0, %outer], [%j1, %next]
+  %same = icmp eq i8 %j, %i
+  br i1 %same, label %digit, label %notsame
+digit:
+  %d = add i8 %j, 48
+  call void @putchar(i8 %d)
+  br label %next
+notsame:
+  %odd = and i8 %j, 1
+  %isodd = icmp ne i8 %odd, 0
+  br i1 %isodd, label %o, label %e
+o:
+  call void @putchar(i8 111)
+  br label %next
+e:
+  call void @putchar(i8 101)
+  br label %next
+next:
+  %j1 = add i8 %j, 1
+  %more = icmp ule i8 %j1, %i
+  br i1 %more, label %inner, label %outer_end
+outer_end:
+  call void @putchar(i8 32)
+  %i1 = add i8 %i, 1
+  br label %outer
+done:
+  ret i32 0
+}
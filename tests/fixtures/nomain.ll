@@ -0,0 +1,5 @@
+; a library with nothing to start from
+define i8 @inc(i8 %a) {
+  %b = add i8 %a, 1
+  ret i8 %b
+}
@@ -0,0 +1,27 @@
+; a multi-block function handing a value read out of a global back to main
+@counts = global [3 x i8] [i8 1, i8 2, i8 3]
+declare void @putchar(i8 zeroext)
+define i8 @pick(i8 %i) noinline {
+entry:
+  %c = icmp eq i8 %i, 0
+  br i1 %c, label %a, label %b
+a:
+  ret i8 122
+b:
+  %j = sub i8 %i, 1
+  %k = zext i8 %j to i64
+  %p = getelementptr inbounds [3 x i8], [3 x i8]* @counts, i64 0, i64 %k
+  %v = load i8, i8* %p
+  %y = add i8 %v, 97
+  ret i8 %y
+}
+define i32 @main() {
+  %p = alloca i8
+  store volatile i8 2, i8* %p
+  %n = load volatile i8, i8* %p
+  %r = call i8 @pick(i8 %n)
+  call void @putchar(i8 zeroext %r)
+  %z = call i8 @pick(i8 0)
+  call void @putchar(i8 zeroext %z)
+  ret i32 7
+}
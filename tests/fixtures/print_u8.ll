@@ -0,0 +1,20 @@
+; print_u8 on a value read in, then on constants with one, two and three digits
+declare zeroext i8 @getchar()
+declare void @putchar(i8 zeroext)
+declare void @print_u8(i8 zeroext)
+
+define i32 @main() {
+  %a = call zeroext i8 @getchar()
+  call void @print_u8(i8 zeroext %a)
+  call void @putchar(i8 zeroext 32)
+  call void @print_u8(i8 zeroext 0)
+  call void @putchar(i8 zeroext 32)
+  call void @print_u8(i8 zeroext 7)
+  call void @putchar(i8 zeroext 32)
+  call void @print_u8(i8 zeroext 42)
+  call void @putchar(i8 zeroext 32)
+  call void @print_u8(i8 zeroext 255)
+  call void @putchar(i8 zeroext 32)
+  call void @print_u8(i8 zeroext %a)
+  ret i32 0
+}
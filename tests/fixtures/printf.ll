@@ -0,0 +1,17 @@
+; printf of a computed number between some text, a %c and a %s
+@.fmt = private unnamed_addr constant [16 x i8] c"%c=%d, %s %d%%\0A\00", align 1
+@.str = private unnamed_addr constant [3 x i8] c"ok\00", align 1
+
+declare zeroext i8 @getchar()
+declare i32 @printf(i8*, ...)
+
+define i32 @main() {
+  %a = call zeroext i8 @getchar()
+  %b = call zeroext i8 @getchar()
+  %s = add i8 %a, %b
+  %w = zext i8 %s to i32
+  %c = zext i8 %a to i32
+  %k = sub i8 %b, 48
+  %r = call i32 (i8*, ...) @printf(i8* getelementptr inbounds ([16 x i8], [16 x i8]* @.fmt, i64 0, i64 0), i32 %c, i32 %w, i8* getelementptr inbounds ([3 x i8], [3 x i8]* @.str, i64 0, i64 0), i8 %k)
+  ret i32 0
+}
@@ -0,0 +1,11 @@
+; two string literals through puts, one of them used for its result
+@.str = private unnamed_addr constant [3 x i8] c"hi\00", align 1
+@.str.1 = private unnamed_addr constant [4 x i8] c"yo!\00", align 1
+
+declare i32 @puts(i8*)
+
+define i32 @main() {
+  %a = call i32 @puts(i8* getelementptr inbounds ([3 x i8], [3 x i8]* @.str, i64 0, i64 0))
+  %b = call i32 @puts(i8* getelementptr inbounds ([4 x i8], [4 x i8]* @.str.1, i64 0, i64 0))
+  ret i32 %b
+}
@@ -0,0 +1,27 @@
+; the store that changes %a is in a block of its own, the load after the join
+; can't know whether it ran. A second load right after it can still reuse it
+declare zeroext i8 @getchar()
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  %a = alloca i8
+  store i8 65, i8* %a
+  %x = load i8, i8* %a
+  %c = call zeroext i8 @getchar()
+  %q = icmp eq i8 %c, 113
+  br i1 %q, label %set, label %next
+
+set:
+  store i8 66, i8* %a
+  br label %next
+
+next:
+  %y = load i8, i8* %a
+  %z = load i8, i8* %a
+  call void @putchar(i8 zeroext %x)
+  call void @putchar(i8 zeroext %y)
+  call void @putchar(i8 zeroext %z)
+  store i8 0, i8* %a
+  ret i32 0
+}
@@ -0,0 +1,20 @@
+; a call to something that isn't an intrinsic could write anywhere as far as
+; load reuse knows
+declare void @putchar(i8 zeroext)
+
+define void @other() {
+  call void @putchar(i8 zeroext 33)
+  ret void
+}
+
+define i32 @main() {
+  %a = alloca i8
+  store i8 65, i8* %a
+  %x = load i8, i8* %a
+  call void @other()
+  %y = load i8, i8* %a
+  call void @putchar(i8 zeroext %x)
+  call void @putchar(i8 zeroext %y)
+  store i8 0, i8* %a
+  ret i32 0
+}
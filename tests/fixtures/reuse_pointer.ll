@@ -0,0 +1,18 @@
+; a store through a pointer loaded back out of memory, bfcc can't see that it
+; lands on %a so the load after it has to read %a again
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+  %a = alloca i8
+  %pp = alloca i8*
+  store i8* %a, i8** %pp
+  store i8 65, i8* %a
+  %x = load i8, i8* %a
+  %p = load i8*, i8** %pp
+  store i8 66, i8* %p
+  %y = load i8, i8* %a
+  call void @putchar(i8 zeroext %x)
+  call void @putchar(i8 zeroext %y)
+  store i8 0, i8* %a
+  ret i32 0
+}
@@ -0,0 +1,12 @@
+; a volatile char, knock it down to upper case, add a !
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+  %p = alloca i8
+  store volatile i8 113, i8* %p
+  %c = load volatile i8, i8* %p
+  %u = sub i8 %c, 32
+  call void @putchar(i8 zeroext %u)
+  call void @putchar(i8 zeroext 33)
+  ret i32 0
+}
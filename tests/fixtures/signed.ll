@@ -0,0 +1,32 @@
+; built with -g, the sdiv of a loaded cell might be dividing a negative number
+; while the one of a zext can't be
+declare void @putchar(i8 zeroext)
+
+define i32 @main() !dbg !6 {
+entry:
+  %p = alloca i8
+  store volatile i8 200, i8* %p
+  %a = load volatile i8, i8* %p
+  %b = sdiv i8 %a, 2, !dbg !10
+  call void @putchar(i8 zeroext %b), !dbg !10
+  %w = zext i8 %a to i32
+  %q = sdiv i32 %w, 2, !dbg !11
+  %r = srem i32 %q, 10, !dbg !11
+  %c = trunc i32 %r to i8
+  call void @putchar(i8 zeroext %c), !dbg !11
+  ret i32 0
+}
+
+!llvm.dbg.cu = !{!0}
+!llvm.module.flags = !{!3}
+
+!0 = distinct !DICompileUnit(language: DW_LANG_C99, file: !1, producer: "clang", isOptimized: false, runtimeVersion: 0, emissionKind: FullDebug, enums: !2)
+!1 = !DIFile(filename: "foo.c", directory: "/")
+!2 = !{}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!6 = distinct !DISubprogram(name: "main", scope: !1, file: !1, line: 10, type: !7, scopeLine: 10, spFlags: DISPFlagDefinition, unit: !0, retainedNodes: !2)
+!7 = !DISubroutineType(types: !8)
+!8 = !{!9}
+!9 = !DIBasicType(name: "int", size: 32, encoding: DW_ATE_signed)
+!10 = !DILocation(line: 12, column: 3, scope: !6)
+!11 = !DILocation(line: 13, column: 3, scope: !6)
@@ -0,0 +1,14 @@
+; prints a little and then flips a value forever, only a step limit gets it out
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  call void @putchar(i8 zeroext 104)
+  call void @putchar(i8 zeroext 105)
+  br label %loop
+
+loop:
+  %n = phi i8 [ 0, %entry ], [ %next, %loop ]
+  %next = sub i8 1, %n
+  br label %loop
+}
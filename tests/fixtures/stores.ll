@@ -0,0 +1,21 @@
+; what -O0 makes of `char c = get(); c = c + 1; putchar(c); putchar(c);`,
+; a store and a load back around every statement
+declare void @putchar(i8 zeroext)
+
+define i8 @get() {
+  ret i8 97
+}
+
+define i32 @main() {
+  %c = alloca i8
+  %1 = call i8 @get()
+  store i8 %1, i8* %c
+  %2 = load i8, i8* %c
+  %3 = add i8 %2, 1
+  store i8 %3, i8* %c
+  %4 = load i8, i8* %c
+  call void @putchar(i8 %4)
+  %5 = load i8, i8* %c
+  call void @putchar(i8 %5)
+  ret i32 0
+}
@@ -0,0 +1,19 @@
+; blocks one after the other with only a br between them, the way -O0 leaves
+; them, and a phi with the one way in
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  %v = alloca i8
+  store volatile i8 97, i8* %v
+  %a = load volatile i8, i8* %v
+  br label %next
+next:
+  %p = phi i8 [ %a, %entry ]
+  %b = add i8 %p, 1
+  br label %last
+last:
+  %c = add i8 %b, 1
+  call void @putchar(i8 %c)
+  ret i32 0
+}
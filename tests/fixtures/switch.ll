@@ -0,0 +1,27 @@
+; two cases share a block whose phi lists the switch block once per edge, the
+; rest go to their own blocks or the default, all joined again by one phi
+declare zeroext i8 @getchar()
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  %c = call zeroext i8 @getchar()
+  switch i8 %c, label %other [ i8 97, label %ab
+                               i8 98, label %ab
+                               i8 120, label %x ]
+
+ab:
+  %v = phi i8 [ %c, %entry ], [ %c, %entry ]
+  br label %done
+
+x:
+  br label %done
+
+other:
+  br label %done
+
+done:
+  %p = phi i8 [ %v, %ab ], [ 50, %x ], [ 51, %other ]
+  call void @putchar(i8 zeroext %p)
+  ret i32 0
+}
@@ -0,0 +1,13 @@
+; a switch with nothing but a default, straight on to it with the phi hearing
+; from the switch block as before
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  switch i8 7, label %only []
+
+only:
+  %p = phi i8 [ 100, %entry ]
+  call void @putchar(i8 zeroext %p)
+  ret i32 0
+}
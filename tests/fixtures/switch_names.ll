@@ -0,0 +1,26 @@
+; the default already has the name the second compare's block would get
+declare void @putchar(i8 zeroext)
+
+define i32 @main() {
+entry:
+  %p = alloca i8
+  store i8 98, i8* %p
+  %c = load volatile i8, i8* %p
+  switch i8 %c, label %entry.sw.1 [ i8 97, label %a
+                                    i8 98, label %b ]
+
+a:
+  call void @putchar(i8 zeroext 97)
+  br label %done
+
+b:
+  call void @putchar(i8 zeroext 98)
+  br label %done
+
+entry.sw.1:
+  call void @putchar(i8 zeroext 100)
+  br label %done
+
+done:
+  ret i32 0
+}
@@ -0,0 +1,25 @@
+; main calls @used, nothing calls @unused except itself and nothing else
+; calls @only_unused_calls
+declare void @putchar(i8 zeroext)
+
+define void @used(i8 %c) noinline {
+  call void @putchar(i8 %c)
+  ret void
+}
+
+define void @only_unused_calls() noinline {
+  call void @putchar(i8 63)
+  ret void
+}
+
+define void @unused(i8 %n) noinline {
+  call void @only_unused_calls()
+  call void @unused(i8 %n)
+  ret void
+}
+
+define i32 @main() {
+  call void @used(i8 104)
+  call void @used(i8 105)
+  ret i32 0
+}
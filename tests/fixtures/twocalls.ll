@@ -0,0 +1,26 @@
+; two calls in the one block, and a block already named like the ones the
+; split makes
+declare void @putchar(i8 zeroext)
+declare zeroext i8 @getchar()
+
+define void @say(i8 %c) {
+  call void @putchar(i8 %c)
+  ret void
+}
+
+define i32 @main() {
+entry:
+  call void @say(i8 104)
+  call void @say(i8 105)
+  %c = call i8 @getchar()
+  %q = icmp eq i8 %c, 113
+  br i1 %q, label %call_term_for_0, label %other
+
+other:
+  call void @say(i8 63)
+  br label %call_term_for_0
+
+call_term_for_0:
+  call void @say(i8 33)
+  ret i32 0
+}
@@ -1,41 +1,245 @@
 use std::env;
-use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
-use std::path::Path;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 use std::time;
 
-extern crate termion;
-use termion::{color, style};
-
 extern crate serde;
 extern crate serde_json;
 use serde::Deserialize;
 
-mod bfcc;
+extern crate brainfuq;
+
+mod fuzz;
+
+use brainfuq::bfcc;
+use brainfuq::term;
+use brainfuq::bf::{
+	bf_bytecode, eof_of, exec, exec_at, exec_ops, exec_wrapping,
+	infinite_loops, op_offsets, trace, COps, ExecResult, InterpErr,
+	TraceConfig, TraceOn,
+};
+use term::Status;
 
 // The tests file structure is roughly. Artifacts are indeded to be plain text
 // and checked in
 //
 // /tests/
 // |- cases/
-// |  \- <test name>.c : c based tests to be compiled and executed
+// |  |- <test name>.c : c based tests to be compiled and executed
+// |  \- <suite>/ : nested as deep as you like
+// |     |- suite.json : optional defaults for everything in here, see Suite
+// |     \- <test name>.c
 // \- artifacts/
 //    \- <build info>/ : like o0 or o1 for opt levels
-//       \- <test name>/
-//          |- ir.ll
-//          |- stats
-//          \- bf.bf
+//       |- <test name>/
+//       |  |- ir.ll
+//       |  |- info
+//       |  |- bf.bf
+//       |  |- bf.min.bf : bf.bf with the comments stripped
+//       |  \- map.json : which cell is what, only kept for a failed case
+//       \- <suite>/ : mirroring cases/
+//          \- <test name>/
 
 #[derive(Deserialize)]
 struct TestCase {
 	name: String,
 	output: String,
-	// input: Option<String>,
+	input: Option<String>,
+	// build with this eof convention instead of the default one
+	eof: Option<u8>,
+	skip: Option<bool>,
+	// the case is expected to be rejected by bfcc with an error containing this
+	compile_error: Option<String>,
+	// bfcc should still compile it but warn with something containing this
+	warning: Option<String>,
+	// build with a stack guard this many calls deep
+	stack_limit: Option<usize>,
+	// false builds without Options::wrap_safe. true makes sure a wrapping
+	// interpreter never has to wrap either
+	wrap_safe: Option<bool>,
+	// build with Options::strict_consts
+	strict_consts: Option<bool>,
+	// run it with cells going around from 255 to 0 and back instead of that
+	// being an error, the tape running off the left end still is one
+	wrap_cells: Option<bool>,
+	// give up on running it after this many steps instead of STEP_LIMIT
+	max_steps: Option<usize>,
+	// build with Options::check_scratch even without --check-scratch
+	check_scratch: Option<bool>,
+	// the rest can come from a suite.json too, see Suite
+	cflags: Option<String>,
+	levels: Option<Vec<String>>,
+	tape: Option<usize>,
+}
+
+// Defaults for every case in a directory and the directories under it, read
+// from its suite.json. A nearer suite.json beats one further up and a case's
+// own TEST header beats them all.
+#[derive(Deserialize, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct Suite {
+	// handed to clang after the opt level
+	cflags: Option<String>,
+	// which of o0 and o1 to build the cases at, both when not given
+	levels: Option<Vec<String>>,
 	skip: Option<bool>,
+	// cells the cases may use, running over fails them
+	tape: Option<usize>,
+}
+
+impl Suite {
+	fn under(&self, inner: Suite) -> Suite {
+		Suite {
+			cflags: inner.cflags.or_else(|| self.cflags.clone()),
+			levels: inner.levels.or_else(|| self.levels.clone()),
+			skip: inner.skip.or(self.skip),
+			tape: inner.tape.or(self.tape),
+		}
+	}
+
+	fn apply(&self, mut info: TestCase) -> TestCase {
+		let own = Suite {
+			cflags: info.cflags.take(),
+			levels: info.levels.take(),
+			skip: info.skip.take(),
+			tape: info.tape.take(),
+		};
+		let all = self.under(own);
+
+		info.cflags = all.cflags;
+		info.levels = all.levels;
+		info.skip = all.skip;
+		info.tape = all.tape;
+		info
+	}
+}
+
+// Which of the outer suite.json, the inner one and the case's own header wins
+// for each setting. Suite is verify's own so this test lives here instead of
+// in tests/api.rs.
+#[test]
+fn suite_defaults_layer() {
+	// (name, outer, inner, header, what the case ends up with)
+	const SUITE_CASES: &[(&str, &str, &str, &str, &str)] = &[
+		("nothing set", "{}", "{}", "{}", "{}"),
+		(
+			"outer fills in",
+			r#"{"cflags": "-DA", "levels": ["o0"], "skip": true, "tape": 10}"#,
+			"{}",
+			"{}",
+			r#"{"cflags": "-DA", "levels": ["o0"], "skip": true, "tape": 10}"#,
+		),
+		(
+			"inner over outer",
+			r#"{"cflags": "-DA", "tape": 10}"#,
+			r#"{"cflags": "-DB", "levels": ["o1"]}"#,
+			"{}",
+			r#"{"cflags": "-DB", "levels": ["o1"], "tape": 10}"#,
+		),
+		(
+			"header over both",
+			r#"{"cflags": "-DA", "skip": true}"#,
+			r#"{"cflags": "-DB", "tape": 10}"#,
+			r#"{"cflags": "-DC", "skip": false}"#,
+			r#"{"cflags": "-DC", "skip": false, "tape": 10}"#,
+		),
+		(
+			"header only",
+			"{}",
+			"{}",
+			r#"{"levels": ["o0", "o1"], "tape": 3}"#,
+			r#"{"levels": ["o0", "o1"], "tape": 3}"#,
+		),
+	];
+
+	for (name, outer, inner, own, expected) in SUITE_CASES.iter() {
+		let suite = |json: &str| serde_json::from_str::<Suite>(json).unwrap();
+
+		let mut header: serde_json::Value = serde_json::from_str(own).unwrap();
+		header["name"] = name.to_string().into();
+		header["output"] = "".into();
+
+		let info = Suite::default()
+			.under(suite(outer))
+			.under(suite(inner))
+			.apply(serde_json::from_value(header).unwrap());
+
+		let got = Suite {
+			cflags: info.cflags,
+			levels: info.levels,
+			skip: info.skip,
+			tape: info.tape,
+		};
+		assert_eq!(got, suite(expected), "{}", name);
+	}
+}
+
+// a case found somewhere under tests/cases, its name already prefixed with
+// the directories it's in
+struct Case {
+	path: PathBuf,
+	// relative to tests/cases, empty for the ones right in it
+	dir: PathBuf,
+	info: TestCase,
+}
+
+const CASE_DIR: &str = "./tests/cases";
+
+fn header(content: &str) -> Result<TestCase, String> {
+	let from = content.find("TEST:").ok_or("no TEST: header")? + 5;
+	let to = content[from..].find("\n").map_or(content.len(), |to| to + from);
+	serde_json::from_str(&content[from..to]).map_err(|e| e.to_string())
+}
+
+fn discover(dir: &Path, rel: &Path, suite: &Suite, found: &mut Vec<Case>) {
+	let suite = match fs::read_to_string(dir.join("suite.json")) {
+		Ok(json) => suite.under(serde_json::from_str(&json).unwrap_or_else(|e| {
+			panic!("bad {}: {}", dir.join("suite.json").display(), e)
+		})),
+		Err(_) => suite.clone(),
+	};
+
+	let mut entries = fs::read_dir(dir)
+		.unwrap()
+		.map(|r| r.unwrap().path())
+		.collect::<Vec<_>>();
+	entries.sort();
+
+	for path in entries {
+		let file_name = path.file_name().unwrap();
+
+		if path.is_dir() {
+			discover(&path, &rel.join(file_name), &suite, found);
+			continue;
+		}
+
+		if path.extension().is_none_or(|e| e != "c") {
+			continue;
+		}
+
+		let content = fs::read_to_string(&path).unwrap();
+		let mut info = header(&content)
+			.unwrap_or_else(|e| panic!("bad {}: {}", path.display(), e));
+		info = suite.apply(info);
+
+		let prefix = rel.to_str().unwrap().replace("\\", "/");
+		if !prefix.is_empty() {
+			info.name = format!("{}/{}", prefix, info.name);
+		}
+
+		found.push(Case {
+			dir: rel.to_path_buf(),
+			path,
+			info,
+		});
+	}
 }
 
 fn compile_ir(flags: &str, from: &str, to: &str) -> Result<(), String> {
@@ -44,7 +248,7 @@ fn compile_ir(flags: &str, from: &str, to: &str) -> Result<(), String> {
 	let res = Command::new("clang")
 		.args(args)
 		.output()
-		.map_err(|e| e.to_string())
+		.map_err(|e| toolchain_missing("clang", e))
 		.and_then(|o| match o.status.success() {
 			true => Ok(()),
 			false => Err(std::str::from_utf8(o.stderr.as_slice())
@@ -52,369 +256,855 @@ fn compile_ir(flags: &str, from: &str, to: &str) -> Result<(), String> {
 				.to_string()),
 		});
 
-	// be nice and write out the human readable ir too
-	Command::new("llvm-dis").args([to]).output().unwrap();
+	// be nice and write out the human readable ir too, not the end of the world
+	// if llvm-dis isn't around though
+	if let Err(e) = Command::new("llvm-dis").args([to]).output() {
+		eprintln!("{}", toolchain_missing("llvm-dis", e));
+	}
 
 	res
 }
 
-fn compile_bf(path: &Path, target: &Path) -> String {
-	let code_out = bfcc::compile(path);
+// windows folks usually get clang from the official LLVM installer or as
+// clang-cl through visual studio, neither of which puts a plain `clang` on the
+// PATH by default so point them in the right direction.
+fn toolchain_missing(tool: &str, e: io::Error) -> String {
+	if cfg!(windows) {
+		format!(
+			"failed to run {}.exe: {} (install LLVM for Windows or the VS \
+			 \"C++ Clang tools\" component and make sure {}.exe is on PATH, \
+			 clang-cl.exe won't do)",
+			tool, e, tool
+		)
+	} else {
+		format!("failed to run {}: {} (is it installed and on PATH?)", tool, e)
+	}
+}
+
+fn compile_bf(
+	path: &Path,
+	target: &Path,
+	opts: &bfcc::Options,
+) -> Result<bfcc::Compiled, bfcc::CompileError> {
+	let out = bfcc::compile(path, opts)?;
 
 	let mut file = File::create(target).unwrap();
-	file.write_all(code_out.as_bytes()).unwrap();
+	file.write_all(out.code.as_bytes()).unwrap();
 
-	code_out
+	// same program without the comments next to it, mostly to see how much
+	// of bf.bf they are
+	let min = bfcc::strip_comments(&out.code);
+	fs::write(target.with_extension("min.bf"), min).unwrap();
+
+	Ok(out)
+}
+
+// f's panic message instead of the panic, and nothing printed for it
+fn without_backtrace<T, F: FnOnce() -> T + std::panic::UnwindSafe>(f: F) -> Result<T, String> {
+	let hook = std::panic::take_hook();
+	std::panic::set_hook(Box::new(|_| {}));
+	let res = std::panic::catch_unwind(f);
+	std::panic::set_hook(hook);
+
+	res.map_err(|e| {
+		if let Some(s) = e.downcast_ref::<&str>() {
+			s.to_string()
+		} else if let Some(s) = e.downcast_ref::<String>() {
+			s.clone()
+		} else {
+			"no message".to_string()
+		}
+	})
+}
+
+// where a case's expected output comes from
+#[derive(PartialEq)]
+enum Oracle {
+	// the output written down in the case's TEST header
+	Header,
+	// whatever the case prints when clang builds it for this machine. The
+	// header still has to agree, a stale one fails the case too
+	Native,
+}
+
+struct Config {
+	oracle: Oracle,
+	// only run cases with these names, everything when empty
+	only: Vec<String>,
+	// how many generated programs to throw at bfcc after the cases
+	fuzz: u64,
+	// where the generated programs start, the clock when not given
+	seed: Option<u64>,
+	// a loop that could never exit fails the case even if it's never entered,
+	// otherwise it's only a warning
+	strict_loops: bool,
+	// build every case with Options::check_scratch
+	check_scratch: bool,
+	// build every case with Options::opt
+	opt: bool,
+}
+
+fn parse_args() -> Config {
+	let mut config = Config {
+		oracle: Oracle::Header,
+		only: vec![],
+		fuzz: 0,
+		seed: None,
+		strict_loops: false,
+		check_scratch: false,
+		opt: false,
+	};
+
+	let number = |flag: &str, v: Option<String>| {
+		v.as_deref().and_then(|v| v.parse().ok()).unwrap_or_else(|| {
+			eprintln!("{} takes a number", flag);
+			process::exit(1);
+		})
+	};
+
+	let mut args = env::args().skip(1);
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--oracle" => {
+				config.oracle = match args.next().as_deref() {
+					Some("native") => Oracle::Native,
+					Some("header") => Oracle::Header,
+					o => {
+						eprintln!(
+							"unknown oracle {}, try native or header",
+							o.unwrap_or("(nothing)")
+						);
+						process::exit(1);
+					}
+				}
+			}
+			"--strict-loops" => config.strict_loops = true,
+			"--check-scratch" => config.check_scratch = true,
+			"--opt" => config.opt = true,
+			"--fuzz" => config.fuzz = number("--fuzz", args.next()),
+			"--seed" => config.seed = Some(number("--seed", args.next())),
+			_ => config.only.push(arg),
+		}
+	}
+
+	config
+}
+
+// a native build has a real libc behind putchar and getchar, only stdfuck.h's
+// EOF is left dangling. libc's -1 lands on 255 once it's in a uint8_t.
+const NATIVE_SHIM: &str = "unsigned char __bfcc_eof(void) { return 255; }\n";
+
+// native binaries get this long before they're assumed to be stuck
+const NATIVE_TIMEOUT: time::Duration = time::Duration::from_secs(10);
+
+// scratch space for native builds, gone again once verify is done
+fn native_dir() -> std::path::PathBuf {
+	env::temp_dir().join(format!("bfcc-verify-{}", process::id()))
+}
+
+fn run_native(cflags: &str, source: &str, input: &str) -> Result<String, String> {
+	let dir = native_dir();
+	fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+	let shim = dir.join("shim.c");
+	if !shim.exists() {
+		fs::write(&shim, NATIVE_SHIM).map_err(|e| e.to_string())?;
+	}
+
+	let bin = dir.join(
+		Path::new(source).file_stem().unwrap().to_str().unwrap().to_string()
+			+ &cflags.replace(" ", ""),
+	);
+
+	let mut args = cflags.split(" ").collect::<Vec<&str>>();
+	args.append(&mut vec![
+		"-I",
+		".",
+		source,
+		shim.to_str().unwrap(),
+		"-o",
+		bin.to_str().unwrap(),
+	]);
+	let cc = Command::new("clang")
+		.args(args)
+		.output()
+		.map_err(|e| toolchain_missing("clang", e))?;
+	if !cc.status.success() {
+		return Err(String::from_utf8_lossy(&cc.stderr).to_string());
+	}
+
+	let res = run_with_timeout(&bin, input.as_bytes(), NATIVE_TIMEOUT);
+	let _ = fs::remove_file(&bin);
+	res
+}
+
+fn run_with_timeout(
+	bin: &Path,
+	input: &[u8],
+	timeout: time::Duration,
+) -> Result<String, String> {
+	let mut child = Command::new(bin)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.spawn()
+		.map_err(|e| format!("failed to run {}: {}", bin.display(), e))?;
+
+	// the case may well never read it so don't care if it hangs up early
+	let mut stdin = child.stdin.take().unwrap();
+	let input = input.to_vec();
+	thread::spawn(move || stdin.write_all(&input));
+
+	// read on the side, a chatty child would block on a full pipe otherwise
+	let mut stdout = child.stdout.take().unwrap();
+	let reader = thread::spawn(move || {
+		let mut out = vec![];
+		stdout.read_to_end(&mut out).map(|_| out)
+	});
+
+	let start = time::Instant::now();
+	loop {
+		match child.try_wait().map_err(|e| e.to_string())? {
+			Some(_) => break,
+			None if start.elapsed() > timeout => {
+				let _ = child.kill();
+				let _ = child.wait();
+				return Err(format!("timed out after {:?}", timeout));
+			}
+			None => thread::sleep(time::Duration::from_millis(5)),
+		}
+	}
+
+	let out = reader.join().unwrap().map_err(|e| e.to_string())?;
+
+	// the bf interpreter hands out cells as chars so match that
+	Ok(out.iter().map(|b| *b as char).collect())
 }
 
 const ARTIFACT_DIR: &str = "./tests/artifacts";
 
-fn run_test(case: &fs::DirEntry, info: TestCase, cflags: &str, name: &str) {
-	if env::args().len() > 1 && env::args().find(|x| x == &info.name).is_none()
-	{
+// well past the slowest case but still only a minute or so of a miscompiled
+// loop going around before the run moves on
+const STEP_LIMIT: usize = 1_000_000_000;
+
+// picked by name, or by the suite it's in
+fn wanted(config: &Config, name: &str) -> bool {
+	config.only.is_empty()
+		|| config
+			.only
+			.iter()
+			.any(|o| o == name || name.starts_with(&format!("{}/", o.trim_end_matches('/'))))
+}
+
+fn run_test(case: &Case, opt: &str, name: &str, config: &Config) {
+	let info = &case.info;
+
+	if !wanted(config, &info.name) {
 		return;
 	}
 
-	let artifacts = format!(
-		"{}/{}/{}",
-		ARTIFACT_DIR,
-		name,
-		case.file_name().into_string().unwrap(),
-	);
-	fs::create_dir_all(Path::new(&artifacts)).unwrap();
+	if let Some(levels) = &info.levels {
+		if !levels.iter().any(|l| l == name) {
+			return;
+		}
+	}
+
+	let cflags = match &info.cflags {
+		Some(extra) => format!("{} {}", opt, extra),
+		None => opt.to_string(),
+	};
+	let cflags = cflags.as_str();
+
+	let artifacts = Path::new(ARTIFACT_DIR)
+		.join(name)
+		.join(&case.dir)
+		.join(case.path.file_name().unwrap());
+	fs::create_dir_all(&artifacts).unwrap();
+	let artifacts = artifacts.to_str().unwrap().to_string();
 
 	if info.skip.unwrap_or(false) {
-		println!(
-			"{}{} skip {} {}",
-			color::Fg(color::Yellow),
-			style::Invert,
-			style::Reset,
-			info.name
-		);
+		println!("{}", term::status(Status::Skip, &info.name));
 		return;
 	}
 
-	print!(
-		"{}{} test {} {}",
-		color::Fg(color::LightYellow),
-		style::Invert,
-		style::Reset,
-		info.name
-	);
+	print!("{}", term::status(Status::Test, &info.name));
 	io::stdout().flush().unwrap();
 
-	let source = format!("{}", case.path().as_path().to_str().unwrap());
+	let source = format!("{}", case.path.to_str().unwrap());
 	let target = format!("{}/ir.bc", artifacts);
 
 	let cc = compile_ir(cflags, &source, &target);
 	if cc.is_err() {
 		println!(
-			"\r{}{} fail {} {}",
-			color::Fg(color::Red),
-			style::Invert,
-			style::Reset,
-			info.name
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
 		);
 		println!("{}", cc.unwrap_err());
 		return;
 	}
 
+	let mut opts = bfcc::Options::default();
+	if let Some(eof) = info.eof {
+		opts.eof_value = eof;
+	}
+	if let Some(limit) = info.stack_limit {
+		opts.stack_limit = limit;
+	}
+	opts.wrap_safe = info.wrap_safe.unwrap_or(true);
+	opts.strict_consts = info.strict_consts.unwrap_or(false);
+	opts.check_scratch =
+		config.check_scratch || info.check_scratch.unwrap_or(false);
+	opts.opt = config.opt;
+
 	let bfout = format!("{}/bf.bf", artifacts);
-	let bf_code = compile_bf(Path::new(&target), Path::new(&bfout));
+	// bfcc panicking is it not handling the case too, so that gets the same
+	// treatment as the constructs it turns down rather than a backtrace
+	let compiled = match without_backtrace(|| {
+		compile_bf(Path::new(&target), Path::new(&bfout), &opts)
+	}) {
+		Ok(compiled) => compiled,
+		Err(why) => {
+			println!(
+				"{}{}",
+				term::rewind(),
+				term::status(Status::Unsupported, &info.name)
+			);
+			println!("bfcc panicked: {}", why);
+			return;
+		}
+	};
+
+	let bf_code = match (compiled, &info.compile_error) {
+		(Ok(code), None) => code,
+		(Err(e), Some(expected)) if e.to_string().contains(expected) => {
+			println!(
+				"{}{} {}",
+				term::rewind(),
+				term::status(Status::Pass, &info.name),
+				term::dim(&format!("(rejected: {})", e)),
+			);
+			return;
+		}
+		(Err(e), None) => {
+			println!(
+				"{}{}",
+				term::rewind(),
+				term::status(Status::Unsupported, &info.name)
+			);
+			println!("{}", e);
+			return;
+		}
+		(res, expected) => {
+			println!();
+			println!("COMPILE ERROR MISMATCH");
+			println!("---");
+			println!("expected: {}", expected.as_deref().unwrap_or("no error"));
+			println!(
+				"  actual: {}",
+				res.err().map_or("no error".to_string(), |e| e.to_string())
+			);
+			println!("---");
+			println!("source: {}", source);
+			println!(
+				"{}{}",
+				term::rewind(),
+				failed(&info.name)
+			);
+			return;
+		}
+	};
 
-	let bfbc = bf_bytecode(&bf_code);
+	// which cell is what for poking at a failure, a pass cleans it up again
+	let mappath = format!("{}/map.json", artifacts);
+	fs::write(&mappath, serde_json::to_string_pretty(&bf_code.map).unwrap())
+		.unwrap();
+
+	let warnings = bf_code
+		.warnings
+		.iter()
+		.map(|w| w.to_string())
+		.collect::<Vec<_>>();
+
+	let warned = match &info.warning {
+		Some(expected) => warnings.iter().any(|w| w.contains(expected)),
+		None => warnings.is_empty(),
+	};
+
+	if !warned {
+		println!();
+		println!("WARNING MISMATCH");
+		println!("---");
+		println!("expected: {}", info.warning.as_deref().unwrap_or("none"));
+		for w in warnings.iter() {
+			println!("  actual: {}", w);
+		}
+		println!("---");
+		println!("source: {}", source);
+		println!(
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
+		);
+		return;
+	}
+
+	// the ops dump has to carry everything, rendering it back has to give the
+	// exact same code
+	let rendered = serde_json::to_string(&bf_code.ops)
+		.map_err(|e| e.to_string())
+		.and_then(|j| {
+			serde_json::from_str::<bfcc::OpsDump>(&j).map_err(|e| e.to_string())
+		})
+		.and_then(|d| bfcc::render_ops(&d));
+
+	if rendered.as_ref() != Ok(&bf_code.code) {
+		println!();
+		println!("OPS ROUNDTRIP MISMATCH");
+		if let Err(e) = &rendered {
+			println!("{}", e);
+		}
+		println!("source: {}", source);
+		println!(
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
+		);
+		return;
+	}
+
+	let bfbc = bf_bytecode(&bf_code.code);
+
+	let input = info.input.as_deref().unwrap_or("").as_bytes();
+	let eof = eof_of(&bf_code.code);
+
+	// check_scratch's empty loops never exit on purpose, only actually running
+	// into one says anything
+	let stuck = match opts.check_scratch {
+		true => vec![],
+		false => infinite_loops(&bfbc),
+	};
+
+	for i in stuck {
+		let e = InterpErr::ProvablyInfinite { op_index: i };
+		if config.strict_loops {
+			println!();
+			println!("EXECUTE ERROR");
+			println!("{}", e);
+			println!(
+				"{}{}",
+				term::rewind(),
+				failed(&info.name)
+			);
+			return;
+		}
+
+		println!("\n{}", term::dim(&format!("warning: {} once entered", e)));
+	}
+
+	// a wrap_cells case runs the way most interpreters out there would, which
+	// only the plain executor knows how to do
+	let wrap_cells = info.wrap_cells.unwrap_or(false);
+	let limit = Some(info.max_steps.unwrap_or(STEP_LIMIT));
+	let result = match wrap_cells {
+		true => exec_wrapping(bfbc.clone(), input, eof, limit).map_err(|e| (e, None)),
+		false => exec_at(bfbc.clone(), input, eof, limit).map_err(|(e, pc)| (e, Some(pc))),
+	};
+	let failed_at = result.as_ref().err().and_then(|(_, pc)| *pc);
+	let result = result.map_err(|(e, _)| e);
+
+	// the packed executor is the one that counts, the plain one keeps it
+	// honest
+	let reference = match wrap_cells {
+		true => Ok(()),
+		false => same_as_reference(&result, bfbc.clone(), input, eof, limit),
+	};
+	if let Err(e) = reference {
+		println!();
+		println!("{}", e);
+		println!(
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
+		);
+
+		return;
+	}
 
-	let result = exec(bfbc);
 	if result.is_err() {
-		print!("\n");
+		println!();
 		println!("EXECUTE ERROR");
 		println!("{}", result.err().unwrap());
+		if let Some(pc) = failed_at {
+			println!("{}", blame(&bf_code.code, &bf_code.src_map, pc));
+		}
 		println!(
-			"\r{}{} fail {} {}",
-			color::Fg(color::Red),
-			style::Invert,
-			style::Reset,
-			info.name
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
 		);
 
 		return;
 	}
 
 	let result = result.unwrap();
-	if result.output != info.output {
-		print!("\n");
+
+	let expected = match config.oracle {
+		Oracle::Header => info.output.clone(),
+		// a native build has no such limit to run into
+		Oracle::Native if info.stack_limit.is_some() => info.output.clone(),
+		Oracle::Native => {
+			match run_native(cflags, &source, info.input.as_deref().unwrap_or("")) {
+				Ok(native) => native,
+				Err(e) => {
+					println!();
+					println!("NATIVE ERROR");
+					println!("{}", e);
+					println!(
+						"{}{}",
+						term::rewind(),
+						failed(&info.name)
+					);
+					return;
+				}
+			}
+		}
+	};
+
+	if expected != info.output {
+		println!();
+		println!("STALE EXPECTATION");
+		println!("---");
+		println!("  header: {}", info.output);
+		println!("  native: {}", expected);
+		println!("---");
+		println!("source: {}", source);
+		println!(
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
+		);
+
+		return;
+	}
+
+	if result.output != expected {
+		println!();
 		println!("OUTPUT MISMATCH");
 		println!("---");
-		println!("expected: {}", info.output);
+		println!("expected: {}", expected);
 		println!("  actual: {}", result.output);
 		println!("---");
+		// the first putchar that got it wrong, if it's not just short
+		let wrong = expected
+			.chars()
+			.zip(result.output.chars())
+			.position(|(e, a)| e != a)
+			.or(match result.output.len() > expected.len() {
+				true => Some(expected.chars().count()),
+				false => None,
+			});
+		if let Some(pc) = wrong.and_then(|n| nth_putchar(bfbc, input, eof, n)) {
+			println!("{}", blame(&bf_code.code, &bf_code.src_map, pc));
+		}
 		println!("source: {}", source);
 		println!("target: {}", bfout);
 		println!(
-			"\r{}{} fail {} {}",
-			color::Fg(color::Red),
-			style::Invert,
-			style::Reset,
-			info.name
+			"{}{}",
+			term::rewind(),
+			failed(&info.name)
 		);
 
 		return;
 	}
 
+	if let Some(tape) = info.tape {
+		if result.cells > tape {
+			println!();
+			println!("TAPE OVERRUN");
+			println!("used {} cells, only {} to go around", result.cells, tape);
+			println!("source: {}", source);
+			println!(
+				"{}{}",
+				term::rewind(),
+				failed(&info.name)
+			);
+
+			return;
+		}
+	}
+
+	if info.wrap_safe.unwrap_or(false) {
+		let wrapped = exec_wrapping(bfbc, input, eof, None);
+		let clean = match &wrapped {
+			Ok(w) => w.wraps == 0 && w.output == result.output,
+			Err(_) => false,
+		};
+
+		if !clean {
+			println!();
+			println!("WRAPPED");
+			match wrapped {
+				Ok(w) => println!("{} wraps, {:?}", w.wraps, w.output),
+				Err(e) => println!("{}", e),
+			}
+			println!("source: {}", source);
+			println!(
+				"{}{}",
+				term::rewind(),
+				failed(&info.name)
+			);
+
+			return;
+		}
+	}
+
 	let mut stats = File::create(Path::new(&format!("{}/info", artifacts)))
 		.unwrap();
 
 	stats
 		.write_all(format!("steps: {}\n", result.steps).as_bytes())
 		.unwrap();
+	stats
+		.write_all(
+			format!("max_cell: {}\n", result.cells.saturating_sub(1)).as_bytes(),
+		)
+		.unwrap();
 
-	println!(
-		"\r{}{} pass {} {} {}({} ms){}",
-		color::Fg(color::Green),
-		style::Invert,
-		style::Reset,
-		info.name,
-		color::Fg(color::LightBlack),
-		0, // TODO
-		style::Reset
-	);
-}
+	let (before, after) = bf_code.peephole;
+	stats
+		.write_all(format!("peephole: {} -> {} ops\n", before, after).as_bytes())
+		.unwrap();
 
-fn main() {
-	let mut cases = fs::read_dir("./tests/cases")
-		.unwrap()
-		.map(|r| r.unwrap())
-		.collect::<Vec<_>>();
-	cases.sort_by_key(|dir| dir.path());
+	// keeps bfcc's cost model honest, it should land somewhere near what
+	// actually ran
+	let estimate = bf_code.costs.iter().map(|c| c.steps).sum::<usize>();
+	stats
+		.write_all(
+			format!(
+				"estimate: {} ({:.2}x of {} run)\n",
+				estimate,
+				estimate as f64 / result.chars as f64,
+				result.chars
+			)
+			.as_bytes(),
+		)
+		.unwrap();
+
+	fs::remove_file(&mappath).ok();
 
 	println!(
-		"{}{} section {} {}",
-		color::Fg(color::Blue),
-		style::Invert,
-		style::Reset,
-		"-O0 no opt",
+		"{}{} {}",
+		term::rewind(),
+		term::status(Status::Pass, &info.name),
+		term::dim(&format!("({} ms)", 0)), // TODO
 	);
+}
 
-	for case in cases.iter() {
-		let content = fs::read_to_string(case.path()).unwrap();
+// The instruction bfcc built the op at pc for, going by its source map
+fn blame(code: &str, map: &[bfcc::SrcSpan], pc: usize) -> String {
+	let is_op = |c: &char| "+-<>[].,".contains(*c);
+	let op = match op_offsets(code).get(pc) {
+		Some(at) => code[..*at].chars().filter(is_op).count(),
+		None => code.chars().filter(is_op).count(),
+	};
+
+	match bfcc::src_at(map, op) {
+		Some(span) => format!("at op {}, from {}", op, span),
+		None => format!("at op {}, outside of any instruction", op),
+	}
+}
 
-		let from = content.find("TEST:").unwrap() + 5;
-		let to = content[from..].find("\n").unwrap() + from;
-		let info: TestCase = serde_json::from_str(&content[from..to]).unwrap();
+// pc of the nth putchar the program gets to
+fn nth_putchar(ops: Vec<COps>, input: &[u8], eof: u8, n: usize) -> Option<usize> {
+	let config = TraceConfig {
+		on: TraceOn::Putchar,
+		max_records: n + 2,
+	};
+	let mut records = vec![];
+	trace(ops, input, eof, None, &config, &mut records).ok();
+
+	String::from_utf8_lossy(&records)
+		.lines()
+		.nth(n)?
+		.split(' ')
+		.find_map(|f| f.strip_prefix("pc="))?
+		.parse()
+		.ok()
+}
 
-		run_test(case, info, "-O0", "o0");
-	}
+// everything that fails goes through here so main can exit with a status
+// scripts and CI notice
+static FAILURES: AtomicUsize = AtomicUsize::new(0);
 
-	println!(
-		"{}{} test {} {}",
-		color::Fg(color::Blue),
-		style::Invert,
-		style::Reset,
-		"-O1 opt level 1",
-	);
+fn failed(name: &str) -> String {
+	FAILURES.fetch_add(1, Ordering::SeqCst);
+	term::status(Status::Fail, name)
+}
 
-	for case in cases.iter() {
-		let content = fs::read_to_string(case.path()).unwrap();
+fn main() {
+	let config = parse_args();
 
-		let from = content.find("TEST:").unwrap() + 5;
-		let to = content[from..].find("\n").unwrap() + from;
-		let info: TestCase = serde_json::from_str(&content[from..to]).unwrap();
 
-		run_test(case, info, "-O1", "o1");
-	}
-}
+	let mut cases = vec![];
+	discover(Path::new(CASE_DIR), Path::new(""), &Suite::default(), &mut cases);
 
-#[derive(Debug)]
-enum InterpErr {
-	IntOverflow,
-	IntUnderflow,
-	MemOverflow,
-	MemUnderflow,
-	ExitMemNonZero,
-}
+	println!("{}", term::status(Status::Section, "-O0 no opt"));
 
-impl fmt::Display for InterpErr {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				IntOverflow => "cell value overflow (undefined behavior)",
-				IntUnderflow => "cell value underflow (undefined behavior)",
-				MemOverflow => "ran out of memory",
-				MemUnderflow =>
-					"decrement memory pointer past first cell (undefined behavior)",
-				ExitMemNonZero => "all memory must be zeroed at program exit",
-			}
-		)
+	for case in cases.iter() {
+		run_test(case, "-O0", "o0", &config);
 	}
-}
 
-struct ExecResult {
-	output: String,
-	steps: usize,
-}
+	println!("{}", term::status(Status::Section, "-O1 opt level 1"));
 
-#[derive(Clone, Copy, Debug)]
-enum COps {
-	Add(i32),
-	Mov(i64),
-	Putchar,
-	JmpIfZ(u64),
-	JmpIfNZ(u64),
-	//Loop(Vec<COps>)
-}
+	for case in cases.iter() {
+		run_test(case, "-O1", "o1", &config);
+	}
 
-fn bf_bytecode(code: &str) -> Vec<COps> {
-	let mut opsout = Vec::<COps>::new();
-
-	let chars: Vec<char> = code.chars().collect();
-
-	for c in chars.iter() {
-		opsout.push(match c {
-			'+' => COps::Add(1),
-			'-' => COps::Add(-1),
-			'>' => COps::Mov(1),
-			'<' => COps::Mov(-1),
-			'[' => COps::JmpIfZ(0),
-			']' => COps::JmpIfNZ(0),
-			'.' => COps::Putchar,
-			',' => panic!("TODO"),
-			_ => continue,
-		})
+	if config.fuzz > 0 {
+		fuzz(&config);
 	}
 
-	// combine similar
-	let mut into = vec![opsout[0]];
-	for op in opsout.iter().skip(1) {
-		let repl = match (into[into.len() - 1], op) {
-			(COps::Add(a), COps::Add(b)) => Some(COps::Add(a + b)),
-			(COps::Mov(a), COps::Mov(b)) => Some(COps::Mov(a + b)),
-			_ => None,
-		};
+	let _ = fs::remove_dir_all(native_dir());
 
-		if repl.is_some() {
-			let l = into.len();
-			into[l - 1] = repl.unwrap();
-		} else {
-			into.push(*op);
-		}
+	if FAILURES.load(Ordering::SeqCst) > 0 {
+		process::exit(1);
 	}
-	let mut opsout = into;
-
-	// actually resolve ops
-	opsout = opsout
-		.iter()
-		.enumerate()
-		.map(|(i, op)| match op {
-			COps::JmpIfZ(_) => {
-				let mut d = 1;
-				for j in (i + 1)..opsout.len() {
-					d += match opsout[j] {
-						COps::JmpIfZ(_) => 1,
-						COps::JmpIfNZ(_) => -1,
-						_ => 0,
-					};
-
-					if d == 0
-						&& match opsout[j] {
-							COps::JmpIfNZ(_) => true,
-							_ => false,
-						} {
-						return COps::JmpIfZ(j as u64);
-					}
-				}
+}
 
-				panic!("unbalanced?");
-			}
-			COps::JmpIfNZ(_) => {
-				let mut d = 1;
-				for j in (0..i).rev() {
-					d += match opsout[j] {
-						COps::JmpIfNZ(_) => 1,
-						COps::JmpIfZ(_) => -1,
-						_ => 0,
-					};
-
-					if d == 0
-						&& match opsout[j] {
-							COps::JmpIfZ(_) => true,
-							_ => false,
-						} {
-						return COps::JmpIfNZ(j as u64);
-					}
-				}
+fn same_as_reference(
+	result: &Result<ExecResult, InterpErr>,
+	ops: Vec<COps>,
+	input: &[u8],
+	eof: u8,
+	limit: Option<usize>,
+) -> Result<(), String> {
+	let reference = exec_ops(ops, input, eof, limit);
+	if result == &reference {
+		return Ok(());
+	}
 
-				panic!("unbalanced?");
-			}
-			_ => *op,
-		})
-		.collect();
+	let show = |r: &Result<ExecResult, InterpErr>| match r {
+		Ok(r) => format!("{} steps, {:?}", r.steps, r.output),
+		Err(e) => format!("{}", e),
+	};
 
-	opsout
+	Err(format!(
+		"EXECUTOR MISMATCH\n   packed: {}\nreference: {}",
+		show(result),
+		show(&reference)
+	))
 }
 
-fn exec(ops: Vec<COps>) -> Result<ExecResult, InterpErr> {
-	let mut pc = 0;
-	let mut mp = 0;
-	let mut steps = 0;
+const FOUND_DIR: &str = "./tests/found";
 
-	let mut mem: [u8; 10000] = [0; 10000];
+// generated programs are small and every loop in them is counted so anything
+// running this long has gone off the rails
+const FUZZ_STEP_LIMIT: usize = 100_000_000;
 
-	let mut output: Vec<char> = vec![];
+// Generated programs through both bfcc and a native build, the native output
+// is always the oracle here. Program i comes from seed + i so any failure can
+// be rerun on its own with --seed <its seed> --fuzz 1.
+fn fuzz(config: &Config) {
+	let seed = config.seed.unwrap_or_else(|| {
+		time::SystemTime::now()
+			.duration_since(time::UNIX_EPOCH)
+			.unwrap()
+			.as_secs()
+	});
 
-	while pc < ops.len() {
-		match ops[pc] {
-			COps::Putchar => output.push(mem[mp] as char),
+	println!(
+		"{}",
+		term::status(
+			Status::Section,
+			&format!("fuzz {} programs from seed {}", config.fuzz, seed)
+		)
+	);
 
-			COps::Add(n) => {
-				let v = mem[mp] as isize + n as isize;
-				if v > 255 {
-					return Err(InterpErr::IntOverflow);
-				} else if v < 0 {
-					return Err(InterpErr::IntUnderflow);
+	for i in 0..config.fuzz {
+		let seed = seed.wrapping_add(i);
+		let source = fuzz::program(seed);
+
+		for (cflags, name) in [("-O0", "o0"), ("-O1", "o1")].iter() {
+			let label = format!("fuzz {} {}", seed, name);
+
+			print!("{}", term::status(Status::Test, &label));
+			io::stdout().flush().unwrap();
+
+			match fuzz_one(&source, seed, cflags) {
+				Ok(()) => println!(
+					"{}{}",
+					term::rewind(),
+					term::status(Status::Pass, &label)
+				),
+				Err(e) => {
+					let found = format!("{}/fuzz_{}.c", FOUND_DIR, seed);
+					fs::create_dir_all(FOUND_DIR).unwrap();
+					fs::write(
+						&found,
+						format!(
+							"{}\n// {}:\n{}\n",
+							source,
+							cflags,
+							e.lines()
+								.map(|l| format!("// {}", l))
+								.collect::<Vec<_>>()
+								.join("\n")
+						),
+					)
+					.unwrap();
+
+					println!();
+					println!("{}", e);
+					println!("saved: {}", found);
+					println!(
+						"{}{}",
+						term::rewind(),
+						failed(&label)
+					);
+
+					// one saved copy per program is plenty
+					break;
 				}
-				mem[mp] = v as u8;
 			}
+		}
+	}
+}
 
-			COps::Mov(n) => {
-				let to = mp as isize + n as isize;
-				if to >= mem.len() as isize {
-					return Err(InterpErr::MemOverflow);
-				}
+fn fuzz_one(source: &str, seed: u64, cflags: &str) -> Result<(), String> {
+	let dir = native_dir();
+	fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
 
-				if to < 0 {
-					return Err(InterpErr::MemUnderflow);
-				}
+	let c = dir.join(format!("fuzz_{}.c", seed));
+	let bc = dir.join(format!("fuzz_{}.bc", seed));
+	let bf = dir.join(format!("fuzz_{}.bf", seed));
+	fs::write(&c, source).map_err(|e| e.to_string())?;
 
-				mp = to as usize;
-			}
+	compile_ir(cflags, c.to_str().unwrap(), bc.to_str().unwrap())
+		.map_err(|e| format!("CLANG ERROR\n{}", e))?;
 
-			COps::JmpIfZ(a) => {
-				if mem[mp] == 0 {
-					pc = a as usize;
-				}
-			}
+	// bfcc still panics on plenty, that's a finding like any other
+	let opts = bfcc::Options::default();
+	let compiled = std::panic::catch_unwind(|| compile_bf(&bc, &bf, &opts))
+		.map_err(|_| "BFCC PANICKED (see above)".to_string())?
+		.map_err(|e| format!("COMPILE ERROR\n{}", e))?;
 
-			COps::JmpIfNZ(a) => {
-				if mem[mp] != 0 {
-					pc = a as usize;
-				}
-			}
-		};
+	let ops = bf_bytecode(&compiled.code);
+	let eof = eof_of(&compiled.code);
 
-		pc += 1;
-		steps += 1;
-	}
+	let result = exec(ops.clone(), &[], eof, Some(FUZZ_STEP_LIMIT));
+	same_as_reference(&result, ops, &[], eof, Some(FUZZ_STEP_LIMIT))?;
+	let result = result.map_err(|e| format!("EXECUTE ERROR\n{}", e))?;
 
-	for i in mem {
-		if i != 0 {
-			return Err(InterpErr::ExitMemNonZero);
-		}
+	let native = run_native(cflags, c.to_str().unwrap(), "")
+		.map_err(|e| format!("NATIVE ERROR\n{}", e))?;
+
+	if result.output != native {
+		return Err(format!(
+			"OUTPUT MISMATCH\nexpected: {:?}\n  actual: {:?}",
+			native, result.output
+		));
 	}
 
-	Ok(ExecResult {
-		output: output.iter().collect(),
-		steps: steps,
-	})
+	Ok(())
 }